@@ -0,0 +1,248 @@
+/*
+ *  Crate: GUI-Sys
+ * Module: Linux - D-Bus
+ */
+
+//! Linux D-Bus bindings.
+//!
+//! # Dependencies
+//! * System Library: `libdbus-1`.
+//!     * Only a handful of items are used, so they are declared here (rather than depending on `libdbus-sys` and its build script).
+//!
+//! # Documentation
+//! * <https://dbus.freedesktop.org/doc/api/html/>
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+use crate::common::c_types::*;
+
+// ================================================================================================================================ //
+// Macros
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+// ================================================================================================================================ //
+// Types
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+#[repr(C)]
+pub struct DBusConnection {
+    _opaque: [u8; 0],
+}
+
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+#[repr(C)]
+pub struct DBusMessage {
+    _opaque: [u8; 0],
+}
+
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusPendingCall.html>
+#[repr(C)]
+pub struct DBusPendingCall {
+    _opaque: [u8; 0],
+}
+
+/// <https://dbus.freedesktop.org/doc/api/html/structDBusError.html>\
+/// The five single-bit `dummy` fields share a single `unsigned int`.
+#[repr(C)]
+pub struct DBusError {
+    pub name: *const c_char,
+    pub message: *const c_char,
+    pub dummy: c_uint,
+    pub padding1: *mut c_void,
+}
+
+/// <https://dbus.freedesktop.org/doc/api/html/structDBusMessageIter.html>\
+/// Only ever initialized by `libdbus`, so its fields are private.
+#[repr(C)]
+pub struct DBusMessageIter {
+    dummy1: *mut c_void,
+    dummy2: *mut c_void,
+    dummy3: u32,
+    dummy4: c_int,
+    dummy5: c_int,
+    dummy6: c_int,
+    dummy7: c_int,
+    dummy8: c_int,
+    dummy9: c_int,
+    dummy10: c_int,
+    dummy11: c_int,
+    pad1: c_int,
+    pad2: *mut c_void,
+    pad3: *mut c_void,
+}
+
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusTypes.html>
+pub type dbus_bool_t = u32;
+
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusBus.html>
+pub type DBusBusType = c_int;
+
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+pub type DBusDispatchStatus = c_int;
+
+// ================================================================================================================================ //
+// Functions
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[link(name = "dbus-1")]
+extern "C" {
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusThreads.html>
+    pub fn dbus_threads_init_default() -> dbus_bool_t;
+
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusErrors.html>
+    pub fn dbus_error_init(error: *mut DBusError);
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusErrors.html>
+    pub fn dbus_error_free(error: *mut DBusError);
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusErrors.html>
+    pub fn dbus_error_is_set(error: *const DBusError) -> dbus_bool_t;
+
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusBus.html>
+    pub fn dbus_bus_get_private(
+        bus_type: DBusBusType,
+        error: *mut DBusError,
+    ) -> *mut DBusConnection;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusBus.html>
+    pub fn dbus_bus_add_match(
+        connection: *mut DBusConnection,
+        rule: *const c_char,
+        error: *mut DBusError,
+    );
+
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_set_exit_on_disconnect(
+        connection: *mut DBusConnection,
+        exit_on_disconnect: dbus_bool_t,
+    );
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_close(connection: *mut DBusConnection);
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_unref(connection: *mut DBusConnection);
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_get_unix_fd(
+        connection: *mut DBusConnection,
+        fd: *mut c_int,
+    ) -> dbus_bool_t;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_read_write(
+        connection: *mut DBusConnection,
+        timeout_milliseconds: c_int,
+    ) -> dbus_bool_t;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_pop_message(connection: *mut DBusConnection) -> *mut DBusMessage;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_borrow_message(connection: *mut DBusConnection) -> *mut DBusMessage;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_return_message(
+        connection: *mut DBusConnection,
+        message: *mut DBusMessage,
+    );
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_steal_borrowed_message(
+        connection: *mut DBusConnection,
+        message: *mut DBusMessage,
+    );
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_dispatch(connection: *mut DBusConnection) -> DBusDispatchStatus;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_send(
+        connection: *mut DBusConnection,
+        message: *mut DBusMessage,
+        client_serial: *mut u32,
+    ) -> dbus_bool_t;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_send_with_reply(
+        connection: *mut DBusConnection,
+        message: *mut DBusMessage,
+        pending_return: *mut *mut DBusPendingCall,
+        timeout_milliseconds: c_int,
+    ) -> dbus_bool_t;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_send_with_reply_and_block(
+        connection: *mut DBusConnection,
+        message: *mut DBusMessage,
+        timeout_milliseconds: c_int,
+        error: *mut DBusError,
+    ) -> *mut DBusMessage;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusConnection.html>
+    pub fn dbus_connection_flush(connection: *mut DBusConnection);
+
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_new_method_call(
+        destination: *const c_char,
+        path: *const c_char,
+        iface: *const c_char,
+        method: *const c_char,
+    ) -> *mut DBusMessage;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_unref(message: *mut DBusMessage);
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_is_signal(
+        message: *mut DBusMessage,
+        iface: *const c_char,
+        signal_name: *const c_char,
+    ) -> dbus_bool_t;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_get_path(message: *mut DBusMessage) -> *const c_char;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_get_reply_serial(message: *mut DBusMessage) -> u32;
+
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusPendingCall.html>
+    pub fn dbus_pending_call_get_completed(pending: *mut DBusPendingCall) -> dbus_bool_t;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusPendingCall.html>
+    pub fn dbus_pending_call_steal_reply(pending: *mut DBusPendingCall) -> *mut DBusMessage;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusPendingCall.html>
+    pub fn dbus_pending_call_cancel(pending: *mut DBusPendingCall);
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusPendingCall.html>
+    pub fn dbus_pending_call_unref(pending: *mut DBusPendingCall);
+
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_iter_init(
+        message: *mut DBusMessage,
+        iter: *mut DBusMessageIter,
+    ) -> dbus_bool_t;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_iter_init_append(message: *mut DBusMessage, iter: *mut DBusMessageIter);
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_iter_append_basic(
+        iter: *mut DBusMessageIter,
+        type_: c_int,
+        value: *const c_void,
+    ) -> dbus_bool_t;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_iter_get_arg_type(iter: *mut DBusMessageIter) -> c_int;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_iter_get_basic(iter: *mut DBusMessageIter, value: *mut c_void);
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_iter_next(iter: *mut DBusMessageIter) -> dbus_bool_t;
+    /// <https://dbus.freedesktop.org/doc/api/html/group__DBusMessage.html>
+    pub fn dbus_message_iter_recurse(iter: *mut DBusMessageIter, sub: *mut DBusMessageIter);
+}
+
+// ================================================================================================================================ //
+// Constants
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusBus.html>
+pub const DBUS_BUS_SESSION: DBusBusType = 0;
+
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusProtocol.html>
+pub const DBUS_TYPE_INVALID: c_int = 0;
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusProtocol.html>
+pub const DBUS_TYPE_ARRAY: c_int = b'a' as c_int;
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusProtocol.html>
+pub const DBUS_TYPE_BOOLEAN: c_int = b'b' as c_int;
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusProtocol.html>
+pub const DBUS_TYPE_INT32: c_int = b'i' as c_int;
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusProtocol.html>
+pub const DBUS_TYPE_UINT32: c_int = b'u' as c_int;
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusProtocol.html>
+pub const DBUS_TYPE_STRING: c_int = b's' as c_int;
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusProtocol.html>
+pub const DBUS_TYPE_OBJECT_PATH: c_int = b'o' as c_int;
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusProtocol.html>
+pub const DBUS_TYPE_VARIANT: c_int = b'v' as c_int;
+/// <https://dbus.freedesktop.org/doc/api/html/group__DBusProtocol.html>
+pub const DBUS_TYPE_STRUCT: c_int = b'r' as c_int;
+
+// ================================================================================================================================ //
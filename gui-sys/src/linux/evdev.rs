@@ -0,0 +1,216 @@
+/*
+ *  Crate: GUI-Sys
+ * Module: Linux - Evdev
+ */
+
+//! Linux Input Subsystem (evdev) bindings.
+//!
+//! # Dependencies
+//! * <https://crates.io/crates/libc>
+//!     * The Event Codes and `ioctl` requests are C macros, so the used items are declared here.
+//!
+//! # Documentation
+//! * <https://www.kernel.org/doc/html/latest/input/input.html>
+//! * <https://www.kernel.org/doc/html/latest/input/event-codes.html>
+//! * <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+//! * <https://www.kernel.org/doc/html/latest/input/uinput.html>
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+use crate::common::c_types::*;
+
+// ================================================================================================================================ //
+// Macros
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+// Macro-Definitions taken from `<asm-generic/ioctl.h>` and `<linux/input.h>`
+
+/// <https://www.kernel.org/doc/html/latest/userspace-api/ioctl/ioctl-decoding.html>
+const fn _IOC(dir: u32, ty: u8, nr: u32, size: usize) -> c_ulong {
+    // #define _IOC(dir,type,nr,size) (((dir) << 30) | ((size) << 16) | ((type) << 8) | (nr))
+    ((dir << 30) | ((size as u32) << 16) | ((ty as u32) << 8) | nr) as c_ulong
+}
+
+/// <https://www.kernel.org/doc/html/latest/userspace-api/ioctl/ioctl-decoding.html>
+const _IOC_NONE: u32 = 0;
+
+/// <https://www.kernel.org/doc/html/latest/userspace-api/ioctl/ioctl-decoding.html>
+const _IOC_WRITE: u32 = 1;
+
+/// <https://www.kernel.org/doc/html/latest/userspace-api/ioctl/ioctl-decoding.html>
+const _IOC_READ: u32 = 2;
+
+/// <https://www.kernel.org/doc/html/latest/input/input-programming.html>
+pub const fn EVIOCGNAME(len: usize) -> c_ulong {
+    // #define EVIOCGNAME(len) _IOC(_IOC_READ, 'E', 0x06, len)
+    _IOC(_IOC_READ, b'E', 0x06, len)
+}
+
+/// <https://www.kernel.org/doc/html/latest/input/input-programming.html>
+pub const fn EVIOCGKEY(len: usize) -> c_ulong {
+    // #define EVIOCGKEY(len) _IOC(_IOC_READ, 'E', 0x18, len)
+    _IOC(_IOC_READ, b'E', 0x18, len)
+}
+
+/// <https://www.kernel.org/doc/html/latest/input/input-programming.html>
+pub const fn EVIOCGBIT(ev: u32, len: usize) -> c_ulong {
+    // #define EVIOCGBIT(ev,len) _IOC(_IOC_READ, 'E', 0x20 + (ev), len)
+    _IOC(_IOC_READ, b'E', 0x20 + ev, len)
+}
+
+/// <https://www.kernel.org/doc/html/latest/input/input-programming.html>
+pub const fn EVIOCGABS(abs: u32) -> c_ulong {
+    // #define EVIOCGABS(abs) _IOR('E', 0x40 + (abs), struct input_absinfo)
+    let size = ::core::mem::size_of::<input_absinfo>();
+    _IOC(_IOC_READ, b'E', 0x40 + abs, size)
+}
+
+/// <https://www.kernel.org/doc/html/latest/input/uinput.html>
+// #define UI_DEV_CREATE _IO(UINPUT_IOCTL_BASE, 1)
+pub const UI_DEV_CREATE: c_ulong = _IOC(_IOC_NONE, b'U', 1, 0);
+
+/// <https://www.kernel.org/doc/html/latest/input/uinput.html>
+// #define UI_DEV_DESTROY _IO(UINPUT_IOCTL_BASE, 2)
+pub const UI_DEV_DESTROY: c_ulong = _IOC(_IOC_NONE, b'U', 2, 0);
+
+/// <https://www.kernel.org/doc/html/latest/input/uinput.html>
+// #define UI_DEV_SETUP _IOW(UINPUT_IOCTL_BASE, 3, struct uinput_setup)
+pub const UI_DEV_SETUP: c_ulong = _IOC(_IOC_WRITE, b'U', 3, ::core::mem::size_of::<uinput_setup>());
+
+/// <https://www.kernel.org/doc/html/latest/input/uinput.html>
+// #define UI_ABS_SETUP _IOW(UINPUT_IOCTL_BASE, 4, struct uinput_abs_setup)
+pub const UI_ABS_SETUP: c_ulong = _IOC(
+    _IOC_WRITE,
+    b'U',
+    4,
+    ::core::mem::size_of::<uinput_abs_setup>(),
+);
+
+/// <https://www.kernel.org/doc/html/latest/input/uinput.html>
+// #define UI_SET_EVBIT _IOW(UINPUT_IOCTL_BASE, 100, int)
+pub const UI_SET_EVBIT: c_ulong = _IOC(_IOC_WRITE, b'U', 100, ::core::mem::size_of::<c_int>());
+
+/// <https://www.kernel.org/doc/html/latest/input/uinput.html>
+// #define UI_SET_KEYBIT _IOW(UINPUT_IOCTL_BASE, 101, int)
+pub const UI_SET_KEYBIT: c_ulong = _IOC(_IOC_WRITE, b'U', 101, ::core::mem::size_of::<c_int>());
+
+/// <https://www.kernel.org/doc/html/latest/input/uinput.html>
+// #define UI_SET_ABSBIT _IOW(UINPUT_IOCTL_BASE, 103, int)
+pub const UI_SET_ABSBIT: c_ulong = _IOC(_IOC_WRITE, b'U', 103, ::core::mem::size_of::<c_int>());
+
+// ================================================================================================================================ //
+// Types
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://www.kernel.org/doc/html/latest/input/input.html#event-interface>
+pub use ::libc::input_event;
+
+/// <https://www.kernel.org/doc/html/latest/input/input-programming.html>
+pub use ::libc::input_absinfo;
+
+/// <https://www.kernel.org/doc/html/latest/input/input-programming.html>
+pub use ::libc::input_id;
+
+/// <https://www.kernel.org/doc/html/latest/input/uinput.html>
+pub use ::libc::uinput_abs_setup;
+
+/// <https://www.kernel.org/doc/html/latest/input/uinput.html>
+pub use ::libc::uinput_setup;
+
+// ================================================================================================================================ //
+// Functions
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+// ================================================================================================================================ //
+// Constants
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#event-types>
+pub const EV_SYN: u16 = 0x00;
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#event-types>
+pub const EV_KEY: u16 = 0x01;
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#event-types>
+pub const EV_ABS: u16 = 0x03;
+
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#ev-syn>
+pub const SYN_REPORT: u16 = 0;
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#ev-syn>
+pub const SYN_DROPPED: u16 = 3;
+
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#ev-key>
+pub const KEY_MAX: u16 = 0x2ff;
+/// <https://www.kernel.org/doc/html/latest/input/input-programming.html>
+pub const BUS_USB: u16 = 0x03;
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#ev-abs>
+pub const ABS_MAX: u16 = 0x3f;
+
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#ev-key>
+pub const BTN_LEFT: u16 = 0x110;
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#ev-key>
+pub const BTN_RIGHT: u16 = 0x111;
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#ev-key>
+pub const BTN_MIDDLE: u16 = 0x112;
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#ev-key>
+pub const BTN_SIDE: u16 = 0x113;
+/// <https://www.kernel.org/doc/html/latest/input/event-codes.html#ev-key>
+pub const BTN_EXTRA: u16 = 0x114;
+
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_GAMEPAD: u16 = 0x130;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_SOUTH: u16 = 0x130;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_EAST: u16 = 0x131;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_NORTH: u16 = 0x133;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_WEST: u16 = 0x134;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_TL: u16 = 0x136;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_TR: u16 = 0x137;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_TL2: u16 = 0x138;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_TR2: u16 = 0x139;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_SELECT: u16 = 0x13a;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_START: u16 = 0x13b;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_MODE: u16 = 0x13c;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_THUMBL: u16 = 0x13d;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_THUMBR: u16 = 0x13e;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_DPAD_UP: u16 = 0x220;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_DPAD_DOWN: u16 = 0x221;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_DPAD_LEFT: u16 = 0x222;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const BTN_DPAD_RIGHT: u16 = 0x223;
+
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const ABS_X: u16 = 0x00;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const ABS_Y: u16 = 0x01;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const ABS_Z: u16 = 0x02;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const ABS_RX: u16 = 0x03;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const ABS_RY: u16 = 0x04;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const ABS_RZ: u16 = 0x05;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const ABS_GAS: u16 = 0x09;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const ABS_BRAKE: u16 = 0x0a;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const ABS_HAT0X: u16 = 0x10;
+/// <https://www.kernel.org/doc/html/latest/input/gamepad.html>
+pub const ABS_HAT0Y: u16 = 0x11;
+
+// ================================================================================================================================ //
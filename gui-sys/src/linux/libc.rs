@@ -66,6 +66,9 @@ pub union epoll_data_t {
     pub u64: u64,
 }
 
+/// <https://man7.org/linux/man-pages/man2/poll.2.html#DESCRIPTION>
+pub use ::libc::pollfd;
+
 // ================================================================================================================================ //
 // Functions
 // -------------------------------------------------------------------------------------------------------------------------------- //
@@ -94,10 +97,31 @@ pub use ::libc::epoll_ctl;
 /// <https://man7.org/linux/man-pages/man2/epoll_wait.2.html>
 pub use ::libc::epoll_wait;
 
+/// <https://man7.org/linux/man-pages/man2/poll.2.html>
+pub use ::libc::poll;
+
+/// <https://man7.org/linux/man-pages/man2/ioctl.2.html>
+pub use ::libc::ioctl;
+
+/// <https://man7.org/linux/man-pages/man2/inotify_init1.2.html>
+pub use ::libc::inotify_init1;
+
+/// <https://man7.org/linux/man-pages/man2/inotify_add_watch.2.html>
+pub use ::libc::inotify_add_watch;
+
+/// <https://man7.org/linux/man-pages/man2/mmap.2.html>
+pub use ::libc::mmap;
+
+/// <https://man7.org/linux/man-pages/man2/munmap.2.html>
+pub use ::libc::munmap;
+
 // ================================================================================================================================ //
 // Constants
 // -------------------------------------------------------------------------------------------------------------------------------- //
 
+/// <https://man7.org/linux/man-pages/man2/poll.2.html#DESCRIPTION>
+pub use ::libc::POLLIN;
+
 /// <https://man7.org/linux/man-pages/man2/eventfd.2.html#DESCRIPTION>
 pub use ::libc::EFD_CLOEXEC;
 /// <https://man7.org/linux/man-pages/man2/eventfd.2.html#DESCRIPTION>
@@ -122,4 +146,35 @@ pub use ::libc::EPOLL_CTL_DEL;
 /// <https://man7.org/linux/man-pages/man2/epoll_ctl.2.html#DESCRIPTION>
 pub use ::libc::EPOLL_CTL_MOD;
 
+/// <https://man7.org/linux/man-pages/man2/open.2.html#DESCRIPTION>
+pub use ::libc::O_CLOEXEC;
+/// <https://man7.org/linux/man-pages/man2/open.2.html#DESCRIPTION>
+pub use ::libc::O_NONBLOCK;
+/// <https://man7.org/linux/man-pages/man2/open.2.html#DESCRIPTION>
+pub use ::libc::O_RDONLY;
+/// <https://man7.org/linux/man-pages/man2/open.2.html#DESCRIPTION>
+pub use ::libc::O_WRONLY;
+
+/// <https://man7.org/linux/man-pages/man2/inotify_init1.2.html#DESCRIPTION>
+pub use ::libc::IN_CLOEXEC;
+/// <https://man7.org/linux/man-pages/man2/inotify_init1.2.html#DESCRIPTION>
+pub use ::libc::IN_NONBLOCK;
+
+/// <https://man7.org/linux/man-pages/man7/inotify.7.html#DESCRIPTION>
+pub use ::libc::IN_ATTRIB;
+/// <https://man7.org/linux/man-pages/man7/inotify.7.html#DESCRIPTION>
+pub use ::libc::IN_CREATE;
+/// <https://man7.org/linux/man-pages/man7/inotify.7.html#DESCRIPTION>
+pub use ::libc::IN_DELETE;
+
+/// <https://man7.org/linux/man-pages/man3/errno.3.html#ERRORS>
+pub use ::libc::ECONNREFUSED;
+
+/// <https://man7.org/linux/man-pages/man2/mmap.2.html#DESCRIPTION>
+pub use ::libc::MAP_FAILED;
+/// <https://man7.org/linux/man-pages/man2/mmap.2.html#DESCRIPTION>
+pub use ::libc::MAP_PRIVATE;
+/// <https://man7.org/linux/man-pages/man2/mmap.2.html#DESCRIPTION>
+pub use ::libc::PROT_READ;
+
 // ================================================================================================================================ //
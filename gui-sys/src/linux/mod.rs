@@ -11,8 +11,29 @@
 pub mod libc;
 pub use self::libc::*;
 
+#[cfg(feature = "x11")]
 #[macro_use]
 pub mod xcb;
+#[cfg(feature = "x11")]
 pub use self::xcb::*;
 
+#[cfg(feature = "xkbcommon")]
+#[macro_use]
+pub mod xkbcommon;
+#[cfg(feature = "xkbcommon")]
+pub use self::xkbcommon::*;
+
+pub mod evdev;
+pub use self::evdev::*;
+
+#[cfg(feature = "dbus")]
+pub mod dbus;
+#[cfg(feature = "dbus")]
+pub use self::dbus::*;
+
+#[cfg(feature = "wayland")]
+pub mod wayland;
+#[cfg(feature = "wayland")]
+pub use self::wayland::*;
+
 // ================================================================================================================================ //
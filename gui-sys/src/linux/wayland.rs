@@ -0,0 +1,1315 @@
+/*
+ *  Crate: GUI-Sys
+ * Module: Linux - Wayland
+ */
+
+//! Linux Wayland bindings.
+//!
+//! # Dependencies
+//! * System Libraries: `libwayland-client`.
+//!     * The core protocol's request wrappers are `static inline` functions in the C headers, so they are re-implemented here.
+//!     * The `xdg-shell` protocol is not part of `libwayland-client`, so its interfaces are declared here (as `wayland-scanner` would generate them).
+//!
+//! # Documentation
+//! * <https://wayland.freedesktop.org/docs/html/apb.html>
+//! * <https://wayland.app/protocols/wayland>
+//! * <https://wayland.app/protocols/xdg-shell>
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+use crate::common::c_types::*;
+
+use ::core::ptr::{null, null_mut};
+
+// ================================================================================================================================ //
+// Macros
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.freedesktop.org/docs/html/apb.html#Client-wayland-util_8h>
+// #define wl_fixed_to_double(f) ((double)(f) / 256.0)
+pub const fn wl_fixed_to_double(f: wl_fixed_t) -> c_double {
+    f as c_double / 256.0
+}
+
+// ================================================================================================================================ //
+// Types
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+#[repr(C)]
+pub struct wl_display {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__proxy>
+#[repr(C)]
+pub struct wl_proxy {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/wayland#wl_registry>
+#[repr(C)]
+pub struct wl_registry {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/wayland#wl_compositor>
+#[repr(C)]
+pub struct wl_compositor {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/wayland#wl_surface>
+#[repr(C)]
+pub struct wl_surface {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/wayland#wl_buffer>
+#[repr(C)]
+pub struct wl_buffer {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/wayland#wl_seat>
+#[repr(C)]
+pub struct wl_seat {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/wayland#wl_pointer>
+#[repr(C)]
+pub struct wl_pointer {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/wayland#wl_keyboard>
+#[repr(C)]
+pub struct wl_keyboard {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/wayland#wl_output>
+#[repr(C)]
+pub struct wl_output {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_wm_base>
+#[repr(C)]
+pub struct xdg_wm_base {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface>
+#[repr(C)]
+pub struct xdg_surface {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel>
+#[repr(C)]
+pub struct xdg_toplevel {
+    _opaque: [u8; 0],
+}
+
+/// <https://wayland.freedesktop.org/docs/html/apb.html#Client-wayland-util_8h>
+pub type wl_fixed_t = i32;
+
+/// <https://wayland.freedesktop.org/docs/html/apb.html#Client-structwl__array>
+#[repr(C)]
+pub struct wl_array {
+    pub size: usize,
+    pub alloc: usize,
+    pub data: *mut c_void,
+}
+
+/// <https://wayland.freedesktop.org/docs/html/apb.html#Client-structwl__message>
+#[repr(C)]
+pub struct wl_message {
+    pub name: *const c_char,
+    pub signature: *const c_char,
+    pub types: *const *const wl_interface,
+}
+
+/// <https://wayland.freedesktop.org/docs/html/apb.html#Client-structwl__interface>
+#[repr(C)]
+pub struct wl_interface {
+    pub name: *const c_char,
+    pub version: c_int,
+    pub method_count: c_int,
+    pub methods: *const wl_message,
+    pub event_count: c_int,
+    pub events: *const wl_message,
+}
+
+// Interfaces (and their Messages) are immutable descriptions of a protocol, so they may be shared across threads.
+unsafe impl Sync for wl_message {}
+unsafe impl Sync for wl_interface {}
+
+/// The `types` of a `wl_message`, which is an array of pointers that must be shareable to be placed in a `static`.
+#[repr(transparent)]
+pub struct wl_message_types<const N: usize>(pub [*const wl_interface; N]);
+
+unsafe impl<const N: usize> Sync for wl_message_types<N> {}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/wayland#wl_registry:event:global>
+#[repr(C)]
+pub struct wl_registry_listener {
+    pub global: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            registry: *mut wl_registry,
+            name: u32,
+            interface: *const c_char,
+            version: u32,
+        ),
+    >,
+    pub global_remove:
+        Option<unsafe extern "C" fn(data: *mut c_void, registry: *mut wl_registry, name: u32)>,
+}
+
+/// <https://wayland.app/protocols/wayland#wl_seat:event:capabilities>
+#[repr(C)]
+pub struct wl_seat_listener {
+    pub capabilities:
+        Option<unsafe extern "C" fn(data: *mut c_void, seat: *mut wl_seat, capabilities: u32)>,
+    pub name:
+        Option<unsafe extern "C" fn(data: *mut c_void, seat: *mut wl_seat, name: *const c_char)>,
+}
+
+/// <https://wayland.app/protocols/wayland#wl_pointer:event:enter>
+#[repr(C)]
+pub struct wl_pointer_listener {
+    pub enter: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            pointer: *mut wl_pointer,
+            serial: u32,
+            surface: *mut wl_surface,
+            surface_x: wl_fixed_t,
+            surface_y: wl_fixed_t,
+        ),
+    >,
+    pub leave: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            pointer: *mut wl_pointer,
+            serial: u32,
+            surface: *mut wl_surface,
+        ),
+    >,
+    pub motion: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            pointer: *mut wl_pointer,
+            time: u32,
+            surface_x: wl_fixed_t,
+            surface_y: wl_fixed_t,
+        ),
+    >,
+    pub button: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            pointer: *mut wl_pointer,
+            serial: u32,
+            time: u32,
+            button: u32,
+            state: u32,
+        ),
+    >,
+    pub axis: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            pointer: *mut wl_pointer,
+            time: u32,
+            axis: u32,
+            value: wl_fixed_t,
+        ),
+    >,
+    pub frame: Option<unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer)>,
+    pub axis_source:
+        Option<unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, axis_source: u32)>,
+    pub axis_stop: Option<
+        unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, time: u32, axis: u32),
+    >,
+    pub axis_discrete: Option<
+        unsafe extern "C" fn(data: *mut c_void, pointer: *mut wl_pointer, axis: u32, discrete: i32),
+    >,
+}
+
+/// <https://wayland.app/protocols/wayland#wl_keyboard:event:keymap>
+#[repr(C)]
+pub struct wl_keyboard_listener {
+    pub keymap: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            keyboard: *mut wl_keyboard,
+            format: u32,
+            fd: i32,
+            size: u32,
+        ),
+    >,
+    pub enter: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            keyboard: *mut wl_keyboard,
+            serial: u32,
+            surface: *mut wl_surface,
+            keys: *mut wl_array,
+        ),
+    >,
+    pub leave: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            keyboard: *mut wl_keyboard,
+            serial: u32,
+            surface: *mut wl_surface,
+        ),
+    >,
+    pub key: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            keyboard: *mut wl_keyboard,
+            serial: u32,
+            time: u32,
+            key: u32,
+            state: u32,
+        ),
+    >,
+    pub modifiers: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            keyboard: *mut wl_keyboard,
+            serial: u32,
+            mods_depressed: u32,
+            mods_latched: u32,
+            mods_locked: u32,
+            group: u32,
+        ),
+    >,
+    pub repeat_info: Option<
+        unsafe extern "C" fn(data: *mut c_void, keyboard: *mut wl_keyboard, rate: i32, delay: i32),
+    >,
+}
+
+/// <https://wayland.app/protocols/wayland#wl_output:event:geometry>
+#[repr(C)]
+pub struct wl_output_listener {
+    pub geometry: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            output: *mut wl_output,
+            x: i32,
+            y: i32,
+            physical_width: i32,
+            physical_height: i32,
+            subpixel: i32,
+            make: *const c_char,
+            model: *const c_char,
+            transform: i32,
+        ),
+    >,
+    pub mode: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            output: *mut wl_output,
+            flags: u32,
+            width: i32,
+            height: i32,
+            refresh: i32,
+        ),
+    >,
+    pub done: Option<unsafe extern "C" fn(data: *mut c_void, output: *mut wl_output)>,
+    pub scale: Option<unsafe extern "C" fn(data: *mut c_void, output: *mut wl_output, factor: i32)>,
+    pub name: Option<
+        unsafe extern "C" fn(data: *mut c_void, output: *mut wl_output, name: *const c_char),
+    >,
+    pub description: Option<
+        unsafe extern "C" fn(data: *mut c_void, output: *mut wl_output, description: *const c_char),
+    >,
+}
+
+/// <https://wayland.app/protocols/wayland#wl_surface:event:enter>
+#[repr(C)]
+pub struct wl_surface_listener {
+    pub enter: Option<
+        unsafe extern "C" fn(data: *mut c_void, surface: *mut wl_surface, output: *mut wl_output),
+    >,
+    pub leave: Option<
+        unsafe extern "C" fn(data: *mut c_void, surface: *mut wl_surface, output: *mut wl_output),
+    >,
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_wm_base:event:ping>
+#[repr(C)]
+pub struct xdg_wm_base_listener {
+    pub ping:
+        Option<unsafe extern "C" fn(data: *mut c_void, wm_base: *mut xdg_wm_base, serial: u32)>,
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface:event:configure>
+#[repr(C)]
+pub struct xdg_surface_listener {
+    pub configure:
+        Option<unsafe extern "C" fn(data: *mut c_void, surface: *mut xdg_surface, serial: u32)>,
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:event:configure>
+#[repr(C)]
+pub struct xdg_toplevel_listener {
+    pub configure: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            toplevel: *mut xdg_toplevel,
+            width: i32,
+            height: i32,
+            states: *mut wl_array,
+        ),
+    >,
+    pub close: Option<unsafe extern "C" fn(data: *mut c_void, toplevel: *mut xdg_toplevel)>,
+    pub configure_bounds: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            toplevel: *mut xdg_toplevel,
+            width: i32,
+            height: i32,
+        ),
+    >,
+    pub wm_capabilities: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            toplevel: *mut xdg_toplevel,
+            capabilities: *mut wl_array,
+        ),
+    >,
+}
+
+// ================================================================================================================================ //
+// Functions
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[link(name = "wayland-client")]
+extern "C" {
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+    pub fn wl_display_connect(name: *const c_char) -> *mut wl_display;
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+    pub fn wl_display_disconnect(display: *mut wl_display);
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+    pub fn wl_display_get_fd(display: *mut wl_display) -> c_int;
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+    pub fn wl_display_get_error(display: *mut wl_display) -> c_int;
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+    pub fn wl_display_flush(display: *mut wl_display) -> c_int;
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+    pub fn wl_display_roundtrip(display: *mut wl_display) -> c_int;
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+    pub fn wl_display_dispatch_pending(display: *mut wl_display) -> c_int;
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+    pub fn wl_display_prepare_read(display: *mut wl_display) -> c_int;
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+    pub fn wl_display_read_events(display: *mut wl_display) -> c_int;
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__display>
+    pub fn wl_display_cancel_read(display: *mut wl_display);
+
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__proxy>
+    pub fn wl_proxy_marshal_flags(
+        proxy: *mut wl_proxy,
+        opcode: u32,
+        interface: *const wl_interface,
+        version: u32,
+        flags: u32,
+        ...
+    ) -> *mut wl_proxy;
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__proxy>
+    pub fn wl_proxy_add_listener(
+        proxy: *mut wl_proxy,
+        implementation: *mut Option<unsafe extern "C" fn()>,
+        data: *mut c_void,
+    ) -> c_int;
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__proxy>
+    pub fn wl_proxy_destroy(proxy: *mut wl_proxy);
+    /// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__proxy>
+    pub fn wl_proxy_get_version(proxy: *mut wl_proxy) -> u32;
+
+    /// <https://wayland.app/protocols/wayland#wl_registry>
+    pub static wl_registry_interface: wl_interface;
+    /// <https://wayland.app/protocols/wayland#wl_compositor>
+    pub static wl_compositor_interface: wl_interface;
+    /// <https://wayland.app/protocols/wayland#wl_surface>
+    pub static wl_surface_interface: wl_interface;
+    /// <https://wayland.app/protocols/wayland#wl_seat>
+    pub static wl_seat_interface: wl_interface;
+    /// <https://wayland.app/protocols/wayland#wl_pointer>
+    pub static wl_pointer_interface: wl_interface;
+    /// <https://wayland.app/protocols/wayland#wl_keyboard>
+    pub static wl_keyboard_interface: wl_interface;
+    /// <https://wayland.app/protocols/wayland#wl_output>
+    pub static wl_output_interface: wl_interface;
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Internal function that casts any protocol object into its underlying `wl_proxy`.
+#[inline]
+fn proxy<T>(object: *mut T) -> *mut wl_proxy {
+    object as *mut wl_proxy
+}
+
+/// Internal function that returns the protocol version of any protocol object.
+#[inline]
+unsafe fn version<T>(object: *mut T) -> u32 {
+    wl_proxy_get_version(proxy(object))
+}
+
+/// Internal function that attaches a listener to any protocol object.
+#[inline]
+unsafe fn add_listener<T, L>(object: *mut T, listener: *const L, data: *mut c_void) -> c_int {
+    wl_proxy_add_listener(proxy(object), listener as *mut _, data)
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/wayland#wl_display:request:get_registry>
+pub unsafe fn wl_display_get_registry(display: *mut wl_display) -> *mut wl_registry {
+    let version = version(display);
+    let interface = &wl_registry_interface;
+    wl_proxy_marshal_flags(
+        proxy(display),
+        WL_DISPLAY_GET_REGISTRY,
+        interface,
+        version,
+        0,
+        null_mut::<c_void>(),
+    ) as *mut _
+}
+
+/// <https://wayland.app/protocols/wayland#wl_registry>
+pub unsafe fn wl_registry_add_listener(
+    registry: *mut wl_registry,
+    listener: *const wl_registry_listener,
+    data: *mut c_void,
+) -> c_int {
+    add_listener(registry, listener, data)
+}
+
+/// <https://wayland.app/protocols/wayland#wl_registry:request:bind>
+pub unsafe fn wl_registry_bind(
+    registry: *mut wl_registry,
+    name: u32,
+    interface: *const wl_interface,
+    version: u32,
+) -> *mut c_void {
+    let flags = 0;
+    let iface_name = (*interface).name;
+    wl_proxy_marshal_flags(
+        proxy(registry),
+        WL_REGISTRY_BIND,
+        interface,
+        version,
+        flags,
+        name,
+        iface_name,
+        version,
+        null_mut::<c_void>(),
+    ) as *mut _
+}
+
+/// <https://wayland.app/protocols/wayland#wl_registry>
+pub unsafe fn wl_registry_destroy(registry: *mut wl_registry) {
+    wl_proxy_destroy(proxy(registry))
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/wayland#wl_compositor:request:create_surface>
+pub unsafe fn wl_compositor_create_surface(compositor: *mut wl_compositor) -> *mut wl_surface {
+    let version = version(compositor);
+    let interface = &wl_surface_interface;
+    wl_proxy_marshal_flags(
+        proxy(compositor),
+        WL_COMPOSITOR_CREATE_SURFACE,
+        interface,
+        version,
+        0,
+        null_mut::<c_void>(),
+    ) as *mut _
+}
+
+/// <https://wayland.app/protocols/wayland#wl_compositor>
+pub unsafe fn wl_compositor_destroy(compositor: *mut wl_compositor) {
+    wl_proxy_destroy(proxy(compositor))
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/wayland#wl_surface>
+pub unsafe fn wl_surface_add_listener(
+    surface: *mut wl_surface,
+    listener: *const wl_surface_listener,
+    data: *mut c_void,
+) -> c_int {
+    add_listener(surface, listener, data)
+}
+
+/// <https://wayland.app/protocols/wayland#wl_surface:request:destroy>
+pub unsafe fn wl_surface_destroy(surface: *mut wl_surface) {
+    let version = version(surface);
+    wl_proxy_marshal_flags(
+        proxy(surface),
+        WL_SURFACE_DESTROY,
+        null(),
+        version,
+        WL_MARSHAL_FLAG_DESTROY,
+    );
+}
+
+/// <https://wayland.app/protocols/wayland#wl_surface:request:attach>
+pub unsafe fn wl_surface_attach(surface: *mut wl_surface, buffer: *mut wl_buffer, x: i32, y: i32) {
+    let version = version(surface);
+    wl_proxy_marshal_flags(
+        proxy(surface),
+        WL_SURFACE_ATTACH,
+        null(),
+        version,
+        0,
+        buffer,
+        x,
+        y,
+    );
+}
+
+/// <https://wayland.app/protocols/wayland#wl_surface:request:commit>
+pub unsafe fn wl_surface_commit(surface: *mut wl_surface) {
+    let version = version(surface);
+    wl_proxy_marshal_flags(proxy(surface), WL_SURFACE_COMMIT, null(), version, 0);
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/wayland#wl_seat>
+pub unsafe fn wl_seat_add_listener(
+    seat: *mut wl_seat,
+    listener: *const wl_seat_listener,
+    data: *mut c_void,
+) -> c_int {
+    add_listener(seat, listener, data)
+}
+
+/// <https://wayland.app/protocols/wayland#wl_seat:request:get_pointer>
+pub unsafe fn wl_seat_get_pointer(seat: *mut wl_seat) -> *mut wl_pointer {
+    let version = version(seat);
+    let interface = &wl_pointer_interface;
+    wl_proxy_marshal_flags(
+        proxy(seat),
+        WL_SEAT_GET_POINTER,
+        interface,
+        version,
+        0,
+        null_mut::<c_void>(),
+    ) as *mut _
+}
+
+/// <https://wayland.app/protocols/wayland#wl_seat:request:get_keyboard>
+pub unsafe fn wl_seat_get_keyboard(seat: *mut wl_seat) -> *mut wl_keyboard {
+    let version = version(seat);
+    let interface = &wl_keyboard_interface;
+    wl_proxy_marshal_flags(
+        proxy(seat),
+        WL_SEAT_GET_KEYBOARD,
+        interface,
+        version,
+        0,
+        null_mut::<c_void>(),
+    ) as *mut _
+}
+
+/// <https://wayland.app/protocols/wayland#wl_seat:request:release>
+pub unsafe fn wl_seat_release(seat: *mut wl_seat) {
+    let version = version(seat);
+    wl_proxy_marshal_flags(
+        proxy(seat),
+        WL_SEAT_RELEASE,
+        null(),
+        version,
+        WL_MARSHAL_FLAG_DESTROY,
+    );
+}
+
+/// <https://wayland.app/protocols/wayland#wl_seat>
+pub unsafe fn wl_seat_destroy(seat: *mut wl_seat) {
+    wl_proxy_destroy(proxy(seat))
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/wayland#wl_pointer>
+pub unsafe fn wl_pointer_add_listener(
+    pointer: *mut wl_pointer,
+    listener: *const wl_pointer_listener,
+    data: *mut c_void,
+) -> c_int {
+    add_listener(pointer, listener, data)
+}
+
+/// <https://wayland.app/protocols/wayland#wl_pointer:request:release>
+pub unsafe fn wl_pointer_release(pointer: *mut wl_pointer) {
+    let version = version(pointer);
+    wl_proxy_marshal_flags(
+        proxy(pointer),
+        WL_POINTER_RELEASE,
+        null(),
+        version,
+        WL_MARSHAL_FLAG_DESTROY,
+    );
+}
+
+/// <https://wayland.app/protocols/wayland#wl_pointer>
+pub unsafe fn wl_pointer_destroy(pointer: *mut wl_pointer) {
+    wl_proxy_destroy(proxy(pointer))
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/wayland#wl_keyboard>
+pub unsafe fn wl_keyboard_add_listener(
+    keyboard: *mut wl_keyboard,
+    listener: *const wl_keyboard_listener,
+    data: *mut c_void,
+) -> c_int {
+    add_listener(keyboard, listener, data)
+}
+
+/// <https://wayland.app/protocols/wayland#wl_keyboard:request:release>
+pub unsafe fn wl_keyboard_release(keyboard: *mut wl_keyboard) {
+    let version = version(keyboard);
+    wl_proxy_marshal_flags(
+        proxy(keyboard),
+        WL_KEYBOARD_RELEASE,
+        null(),
+        version,
+        WL_MARSHAL_FLAG_DESTROY,
+    );
+}
+
+/// <https://wayland.app/protocols/wayland#wl_keyboard>
+pub unsafe fn wl_keyboard_destroy(keyboard: *mut wl_keyboard) {
+    wl_proxy_destroy(proxy(keyboard))
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/wayland#wl_output>
+pub unsafe fn wl_output_add_listener(
+    output: *mut wl_output,
+    listener: *const wl_output_listener,
+    data: *mut c_void,
+) -> c_int {
+    add_listener(output, listener, data)
+}
+
+/// <https://wayland.app/protocols/wayland#wl_output:request:release>
+pub unsafe fn wl_output_release(output: *mut wl_output) {
+    let version = version(output);
+    wl_proxy_marshal_flags(
+        proxy(output),
+        WL_OUTPUT_RELEASE,
+        null(),
+        version,
+        WL_MARSHAL_FLAG_DESTROY,
+    );
+}
+
+/// <https://wayland.app/protocols/wayland#wl_output>
+pub unsafe fn wl_output_destroy(output: *mut wl_output) {
+    wl_proxy_destroy(proxy(output))
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_wm_base>
+pub unsafe fn xdg_wm_base_add_listener(
+    wm_base: *mut xdg_wm_base,
+    listener: *const xdg_wm_base_listener,
+    data: *mut c_void,
+) -> c_int {
+    add_listener(wm_base, listener, data)
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_wm_base:request:destroy>
+pub unsafe fn xdg_wm_base_destroy(wm_base: *mut xdg_wm_base) {
+    let version = version(wm_base);
+    wl_proxy_marshal_flags(
+        proxy(wm_base),
+        XDG_WM_BASE_DESTROY,
+        null(),
+        version,
+        WL_MARSHAL_FLAG_DESTROY,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_wm_base:request:get_xdg_surface>
+pub unsafe fn xdg_wm_base_get_xdg_surface(
+    wm_base: *mut xdg_wm_base,
+    surface: *mut wl_surface,
+) -> *mut xdg_surface {
+    let version = version(wm_base);
+    let interface = &xdg_surface_interface;
+    wl_proxy_marshal_flags(
+        proxy(wm_base),
+        XDG_WM_BASE_GET_XDG_SURFACE,
+        interface,
+        version,
+        0,
+        null_mut::<c_void>(),
+        surface,
+    ) as *mut _
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_wm_base:request:pong>
+pub unsafe fn xdg_wm_base_pong(wm_base: *mut xdg_wm_base, serial: u32) {
+    let version = version(wm_base);
+    wl_proxy_marshal_flags(proxy(wm_base), XDG_WM_BASE_PONG, null(), version, 0, serial);
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface>
+pub unsafe fn xdg_surface_add_listener(
+    surface: *mut xdg_surface,
+    listener: *const xdg_surface_listener,
+    data: *mut c_void,
+) -> c_int {
+    add_listener(surface, listener, data)
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface:request:destroy>
+pub unsafe fn xdg_surface_destroy(surface: *mut xdg_surface) {
+    let version = version(surface);
+    wl_proxy_marshal_flags(
+        proxy(surface),
+        XDG_SURFACE_DESTROY,
+        null(),
+        version,
+        WL_MARSHAL_FLAG_DESTROY,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface:request:get_toplevel>
+pub unsafe fn xdg_surface_get_toplevel(surface: *mut xdg_surface) -> *mut xdg_toplevel {
+    let version = version(surface);
+    let interface = &xdg_toplevel_interface;
+    wl_proxy_marshal_flags(
+        proxy(surface),
+        XDG_SURFACE_GET_TOPLEVEL,
+        interface,
+        version,
+        0,
+        null_mut::<c_void>(),
+    ) as *mut _
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface:request:set_window_geometry>
+pub unsafe fn xdg_surface_set_window_geometry(
+    surface: *mut xdg_surface,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+) {
+    let version = version(surface);
+    wl_proxy_marshal_flags(
+        proxy(surface),
+        XDG_SURFACE_SET_WINDOW_GEOMETRY,
+        null(),
+        version,
+        0,
+        x,
+        y,
+        width,
+        height,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface:request:ack_configure>
+pub unsafe fn xdg_surface_ack_configure(surface: *mut xdg_surface, serial: u32) {
+    let version = version(surface);
+    wl_proxy_marshal_flags(
+        proxy(surface),
+        XDG_SURFACE_ACK_CONFIGURE,
+        null(),
+        version,
+        0,
+        serial,
+    );
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel>
+pub unsafe fn xdg_toplevel_add_listener(
+    toplevel: *mut xdg_toplevel,
+    listener: *const xdg_toplevel_listener,
+    data: *mut c_void,
+) -> c_int {
+    add_listener(toplevel, listener, data)
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:destroy>
+pub unsafe fn xdg_toplevel_destroy(toplevel: *mut xdg_toplevel) {
+    let version = version(toplevel);
+    wl_proxy_marshal_flags(
+        proxy(toplevel),
+        XDG_TOPLEVEL_DESTROY,
+        null(),
+        version,
+        WL_MARSHAL_FLAG_DESTROY,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_title>
+pub unsafe fn xdg_toplevel_set_title(toplevel: *mut xdg_toplevel, title: *const c_char) {
+    let version = version(toplevel);
+    wl_proxy_marshal_flags(
+        proxy(toplevel),
+        XDG_TOPLEVEL_SET_TITLE,
+        null(),
+        version,
+        0,
+        title,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_app_id>
+pub unsafe fn xdg_toplevel_set_app_id(toplevel: *mut xdg_toplevel, app_id: *const c_char) {
+    let version = version(toplevel);
+    wl_proxy_marshal_flags(
+        proxy(toplevel),
+        XDG_TOPLEVEL_SET_APP_ID,
+        null(),
+        version,
+        0,
+        app_id,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_max_size>
+pub unsafe fn xdg_toplevel_set_max_size(toplevel: *mut xdg_toplevel, width: i32, height: i32) {
+    let version = version(toplevel);
+    wl_proxy_marshal_flags(
+        proxy(toplevel),
+        XDG_TOPLEVEL_SET_MAX_SIZE,
+        null(),
+        version,
+        0,
+        width,
+        height,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_min_size>
+pub unsafe fn xdg_toplevel_set_min_size(toplevel: *mut xdg_toplevel, width: i32, height: i32) {
+    let version = version(toplevel);
+    wl_proxy_marshal_flags(
+        proxy(toplevel),
+        XDG_TOPLEVEL_SET_MIN_SIZE,
+        null(),
+        version,
+        0,
+        width,
+        height,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_maximized>
+pub unsafe fn xdg_toplevel_set_maximized(toplevel: *mut xdg_toplevel) {
+    let version = version(toplevel);
+    wl_proxy_marshal_flags(
+        proxy(toplevel),
+        XDG_TOPLEVEL_SET_MAXIMIZED,
+        null(),
+        version,
+        0,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:unset_maximized>
+pub unsafe fn xdg_toplevel_unset_maximized(toplevel: *mut xdg_toplevel) {
+    let version = version(toplevel);
+    wl_proxy_marshal_flags(
+        proxy(toplevel),
+        XDG_TOPLEVEL_UNSET_MAXIMIZED,
+        null(),
+        version,
+        0,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_fullscreen>
+pub unsafe fn xdg_toplevel_set_fullscreen(toplevel: *mut xdg_toplevel, output: *mut wl_output) {
+    let version = version(toplevel);
+    wl_proxy_marshal_flags(
+        proxy(toplevel),
+        XDG_TOPLEVEL_SET_FULLSCREEN,
+        null(),
+        version,
+        0,
+        output,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:unset_fullscreen>
+pub unsafe fn xdg_toplevel_unset_fullscreen(toplevel: *mut xdg_toplevel) {
+    let version = version(toplevel);
+    wl_proxy_marshal_flags(
+        proxy(toplevel),
+        XDG_TOPLEVEL_UNSET_FULLSCREEN,
+        null(),
+        version,
+        0,
+    );
+}
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_minimized>
+pub unsafe fn xdg_toplevel_set_minimized(toplevel: *mut xdg_toplevel) {
+    let version = version(toplevel);
+    wl_proxy_marshal_flags(
+        proxy(toplevel),
+        XDG_TOPLEVEL_SET_MINIMIZED,
+        null(),
+        version,
+        0,
+    );
+}
+
+// ================================================================================================================================ //
+// Interfaces
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Internal function that declares a `wl_message` from NULL-terminated strings.
+const fn message(
+    name: &'static [u8],
+    signature: &'static [u8],
+    types: *const *const wl_interface,
+) -> wl_message {
+    wl_message {
+        name: name.as_ptr() as *const c_char,
+        signature: signature.as_ptr() as *const c_char,
+        types,
+    }
+}
+
+/// Internal function that declares a `wl_interface` from NULL-terminated strings and message lists.
+const fn interface(
+    name: &'static [u8],
+    version: c_int,
+    methods: &'static [wl_message],
+    events: &'static [wl_message],
+) -> wl_interface {
+    wl_interface {
+        name: name.as_ptr() as *const c_char,
+        version,
+        method_count: methods.len() as c_int,
+        methods: methods.as_ptr(),
+        event_count: events.len() as c_int,
+        events: events.as_ptr(),
+    }
+}
+
+/// Types for messages without object arguments.
+static NULL_TYPES: wl_message_types<4> = wl_message_types([null(); 4]);
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_wm_base>
+pub static xdg_wm_base_interface: wl_interface = interface(
+    b"xdg_wm_base\0",
+    6,
+    &XDG_WM_BASE_REQUESTS,
+    &XDG_WM_BASE_EVENTS,
+);
+
+/// Types for `xdg_wm_base.create_positioner`.
+static XDG_WM_BASE_CREATE_POSITIONER_TYPES: wl_message_types<1> =
+    wl_message_types([&xdg_positioner_interface]);
+/// Types for `xdg_wm_base.get_xdg_surface`.
+static XDG_WM_BASE_GET_XDG_SURFACE_TYPES: wl_message_types<2> =
+    wl_message_types([&xdg_surface_interface, unsafe { &wl_surface_interface }]);
+
+/// Requests of `xdg_wm_base`.
+#[rustfmt::skip]
+static XDG_WM_BASE_REQUESTS: [wl_message; 4] = [
+    message(b"destroy\0",           b"\0",   NULL_TYPES.0.as_ptr()),
+    message(b"create_positioner\0", b"n\0",  XDG_WM_BASE_CREATE_POSITIONER_TYPES.0.as_ptr()),
+    message(b"get_xdg_surface\0",   b"no\0", XDG_WM_BASE_GET_XDG_SURFACE_TYPES.0.as_ptr()),
+    message(b"pong\0",              b"u\0",  NULL_TYPES.0.as_ptr()),
+];
+
+/// Events of `xdg_wm_base`.
+#[rustfmt::skip]
+static XDG_WM_BASE_EVENTS: [wl_message; 1] = [
+    message(b"ping\0", b"u\0", NULL_TYPES.0.as_ptr()),
+];
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_positioner>
+pub static xdg_positioner_interface: wl_interface =
+    interface(b"xdg_positioner\0", 6, &XDG_POSITIONER_REQUESTS, &[]);
+
+/// Requests of `xdg_positioner`.
+#[rustfmt::skip]
+static XDG_POSITIONER_REQUESTS: [wl_message; 10] = [
+    message(b"destroy\0",                   b"\0",     NULL_TYPES.0.as_ptr()),
+    message(b"set_size\0",                  b"ii\0",   NULL_TYPES.0.as_ptr()),
+    message(b"set_anchor_rect\0",           b"iiii\0", NULL_TYPES.0.as_ptr()),
+    message(b"set_anchor\0",                b"u\0",    NULL_TYPES.0.as_ptr()),
+    message(b"set_gravity\0",               b"u\0",    NULL_TYPES.0.as_ptr()),
+    message(b"set_constraint_adjustment\0", b"u\0",    NULL_TYPES.0.as_ptr()),
+    message(b"set_offset\0",                b"ii\0",   NULL_TYPES.0.as_ptr()),
+    message(b"set_reactive\0",              b"3\0",    NULL_TYPES.0.as_ptr()),
+    message(b"set_parent_size\0",           b"3ii\0",  NULL_TYPES.0.as_ptr()),
+    message(b"set_parent_configure\0",      b"3u\0",   NULL_TYPES.0.as_ptr()),
+];
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface>
+pub static xdg_surface_interface: wl_interface = interface(
+    b"xdg_surface\0",
+    6,
+    &XDG_SURFACE_REQUESTS,
+    &XDG_SURFACE_EVENTS,
+);
+
+/// Types for `xdg_surface.get_toplevel`.
+static XDG_SURFACE_GET_TOPLEVEL_TYPES: wl_message_types<1> =
+    wl_message_types([&xdg_toplevel_interface]);
+/// Types for `xdg_surface.get_popup`.
+static XDG_SURFACE_GET_POPUP_TYPES: wl_message_types<3> = wl_message_types([
+    &xdg_popup_interface,
+    &xdg_surface_interface,
+    &xdg_positioner_interface,
+]);
+
+/// Requests of `xdg_surface`.
+#[rustfmt::skip]
+static XDG_SURFACE_REQUESTS: [wl_message; 5] = [
+    message(b"destroy\0",             b"\0",     NULL_TYPES.0.as_ptr()),
+    message(b"get_toplevel\0",        b"n\0",    XDG_SURFACE_GET_TOPLEVEL_TYPES.0.as_ptr()),
+    message(b"get_popup\0",           b"n?oo\0", XDG_SURFACE_GET_POPUP_TYPES.0.as_ptr()),
+    message(b"set_window_geometry\0", b"iiii\0", NULL_TYPES.0.as_ptr()),
+    message(b"ack_configure\0",       b"u\0",    NULL_TYPES.0.as_ptr()),
+];
+
+/// Events of `xdg_surface`.
+#[rustfmt::skip]
+static XDG_SURFACE_EVENTS: [wl_message; 1] = [
+    message(b"configure\0", b"u\0", NULL_TYPES.0.as_ptr()),
+];
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel>
+pub static xdg_toplevel_interface: wl_interface = interface(
+    b"xdg_toplevel\0",
+    6,
+    &XDG_TOPLEVEL_REQUESTS,
+    &XDG_TOPLEVEL_EVENTS,
+);
+
+/// Types for `xdg_toplevel.set_parent`.
+static XDG_TOPLEVEL_SET_PARENT_TYPES: wl_message_types<1> =
+    wl_message_types([&xdg_toplevel_interface]);
+/// Types for `xdg_toplevel.show_window_menu`.
+static XDG_TOPLEVEL_SHOW_WINDOW_MENU_TYPES: wl_message_types<4> =
+    wl_message_types([unsafe { &wl_seat_interface }, null(), null(), null()]);
+/// Types for `xdg_toplevel.move`.
+static XDG_TOPLEVEL_MOVE_TYPES: wl_message_types<2> =
+    wl_message_types([unsafe { &wl_seat_interface }, null()]);
+/// Types for `xdg_toplevel.resize`.
+static XDG_TOPLEVEL_RESIZE_TYPES: wl_message_types<3> =
+    wl_message_types([unsafe { &wl_seat_interface }, null(), null()]);
+/// Types for `xdg_toplevel.set_fullscreen`.
+static XDG_TOPLEVEL_SET_FULLSCREEN_TYPES: wl_message_types<1> =
+    wl_message_types([unsafe { &wl_output_interface }]);
+
+/// Requests of `xdg_toplevel`.
+#[rustfmt::skip]
+static XDG_TOPLEVEL_REQUESTS: [wl_message; 14] = [
+    message(b"destroy\0",          b"\0",     NULL_TYPES.0.as_ptr()),
+    message(b"set_parent\0",       b"?o\0",   XDG_TOPLEVEL_SET_PARENT_TYPES.0.as_ptr()),
+    message(b"set_title\0",        b"s\0",    NULL_TYPES.0.as_ptr()),
+    message(b"set_app_id\0",       b"s\0",    NULL_TYPES.0.as_ptr()),
+    message(b"show_window_menu\0", b"ouii\0", XDG_TOPLEVEL_SHOW_WINDOW_MENU_TYPES.0.as_ptr()),
+    message(b"move\0",             b"ou\0",   XDG_TOPLEVEL_MOVE_TYPES.0.as_ptr()),
+    message(b"resize\0",           b"ouu\0",  XDG_TOPLEVEL_RESIZE_TYPES.0.as_ptr()),
+    message(b"set_max_size\0",     b"ii\0",   NULL_TYPES.0.as_ptr()),
+    message(b"set_min_size\0",     b"ii\0",   NULL_TYPES.0.as_ptr()),
+    message(b"set_maximized\0",    b"\0",     NULL_TYPES.0.as_ptr()),
+    message(b"unset_maximized\0",  b"\0",     NULL_TYPES.0.as_ptr()),
+    message(b"set_fullscreen\0",   b"?o\0",   XDG_TOPLEVEL_SET_FULLSCREEN_TYPES.0.as_ptr()),
+    message(b"unset_fullscreen\0", b"\0",     NULL_TYPES.0.as_ptr()),
+    message(b"set_minimized\0",    b"\0",     NULL_TYPES.0.as_ptr()),
+];
+
+/// Events of `xdg_toplevel`.
+#[rustfmt::skip]
+static XDG_TOPLEVEL_EVENTS: [wl_message; 4] = [
+    message(b"configure\0",        b"iia\0", NULL_TYPES.0.as_ptr()),
+    message(b"close\0",            b"\0",    NULL_TYPES.0.as_ptr()),
+    message(b"configure_bounds\0", b"4ii\0", NULL_TYPES.0.as_ptr()),
+    message(b"wm_capabilities\0",  b"5a\0",  NULL_TYPES.0.as_ptr()),
+];
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_popup>
+pub static xdg_popup_interface: wl_interface =
+    interface(b"xdg_popup\0", 6, &XDG_POPUP_REQUESTS, &XDG_POPUP_EVENTS);
+
+/// Types for `xdg_popup.grab`.
+static XDG_POPUP_GRAB_TYPES: wl_message_types<2> =
+    wl_message_types([unsafe { &wl_seat_interface }, null()]);
+/// Types for `xdg_popup.reposition`.
+static XDG_POPUP_REPOSITION_TYPES: wl_message_types<2> =
+    wl_message_types([&xdg_positioner_interface, null()]);
+
+/// Requests of `xdg_popup`.
+#[rustfmt::skip]
+static XDG_POPUP_REQUESTS: [wl_message; 3] = [
+    message(b"destroy\0",    b"\0",    NULL_TYPES.0.as_ptr()),
+    message(b"grab\0",       b"ou\0",  XDG_POPUP_GRAB_TYPES.0.as_ptr()),
+    message(b"reposition\0", b"3ou\0", XDG_POPUP_REPOSITION_TYPES.0.as_ptr()),
+];
+
+/// Events of `xdg_popup`.
+#[rustfmt::skip]
+static XDG_POPUP_EVENTS: [wl_message; 3] = [
+    message(b"configure\0",    b"iiii\0", NULL_TYPES.0.as_ptr()),
+    message(b"popup_done\0",   b"\0",     NULL_TYPES.0.as_ptr()),
+    message(b"repositioned\0", b"3u\0",   NULL_TYPES.0.as_ptr()),
+];
+
+// ================================================================================================================================ //
+// Constants
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://wayland.freedesktop.org/docs/html/apb.html#Client-classwl__proxy>
+pub const WL_MARSHAL_FLAG_DESTROY: u32 = 1 << 0;
+
+/// <https://wayland.app/protocols/wayland#wl_display:request:get_registry>
+pub const WL_DISPLAY_GET_REGISTRY: u32 = 1;
+
+/// <https://wayland.app/protocols/wayland#wl_registry:request:bind>
+pub const WL_REGISTRY_BIND: u32 = 0;
+
+/// <https://wayland.app/protocols/wayland#wl_compositor:request:create_surface>
+pub const WL_COMPOSITOR_CREATE_SURFACE: u32 = 0;
+
+/// <https://wayland.app/protocols/wayland#wl_surface:request:destroy>
+pub const WL_SURFACE_DESTROY: u32 = 0;
+/// <https://wayland.app/protocols/wayland#wl_surface:request:attach>
+pub const WL_SURFACE_ATTACH: u32 = 1;
+/// <https://wayland.app/protocols/wayland#wl_surface:request:commit>
+pub const WL_SURFACE_COMMIT: u32 = 6;
+
+/// <https://wayland.app/protocols/wayland#wl_seat:request:get_pointer>
+pub const WL_SEAT_GET_POINTER: u32 = 0;
+/// <https://wayland.app/protocols/wayland#wl_seat:request:get_keyboard>
+pub const WL_SEAT_GET_KEYBOARD: u32 = 1;
+/// <https://wayland.app/protocols/wayland#wl_seat:request:release>
+pub const WL_SEAT_RELEASE: u32 = 3;
+/// <https://wayland.app/protocols/wayland#wl_seat:request:release>
+pub const WL_SEAT_RELEASE_SINCE_VERSION: u32 = 5;
+
+/// <https://wayland.app/protocols/wayland#wl_seat:enum:capability>
+pub const WL_SEAT_CAPABILITY_POINTER: u32 = 1;
+/// <https://wayland.app/protocols/wayland#wl_seat:enum:capability>
+pub const WL_SEAT_CAPABILITY_KEYBOARD: u32 = 2;
+
+/// <https://wayland.app/protocols/wayland#wl_pointer:request:release>
+pub const WL_POINTER_RELEASE: u32 = 1;
+/// <https://wayland.app/protocols/wayland#wl_pointer:request:release>
+pub const WL_POINTER_RELEASE_SINCE_VERSION: u32 = 3;
+
+/// <https://wayland.app/protocols/wayland#wl_pointer:enum:button_state>
+pub const WL_POINTER_BUTTON_STATE_RELEASED: u32 = 0;
+/// <https://wayland.app/protocols/wayland#wl_pointer:enum:button_state>
+pub const WL_POINTER_BUTTON_STATE_PRESSED: u32 = 1;
+
+/// <https://wayland.app/protocols/wayland#wl_pointer:enum:axis>
+pub const WL_POINTER_AXIS_VERTICAL_SCROLL: u32 = 0;
+/// <https://wayland.app/protocols/wayland#wl_pointer:enum:axis>
+pub const WL_POINTER_AXIS_HORIZONTAL_SCROLL: u32 = 1;
+
+/// <https://wayland.app/protocols/wayland#wl_keyboard:request:release>
+pub const WL_KEYBOARD_RELEASE: u32 = 0;
+/// <https://wayland.app/protocols/wayland#wl_keyboard:request:release>
+pub const WL_KEYBOARD_RELEASE_SINCE_VERSION: u32 = 3;
+
+/// <https://wayland.app/protocols/wayland#wl_keyboard:enum:keymap_format>
+pub const WL_KEYBOARD_KEYMAP_FORMAT_XKB_V1: u32 = 1;
+
+/// <https://wayland.app/protocols/wayland#wl_keyboard:enum:key_state>
+pub const WL_KEYBOARD_KEY_STATE_RELEASED: u32 = 0;
+/// <https://wayland.app/protocols/wayland#wl_keyboard:enum:key_state>
+pub const WL_KEYBOARD_KEY_STATE_PRESSED: u32 = 1;
+
+/// <https://wayland.app/protocols/wayland#wl_output:request:release>
+pub const WL_OUTPUT_RELEASE: u32 = 0;
+/// <https://wayland.app/protocols/wayland#wl_output:request:release>
+pub const WL_OUTPUT_RELEASE_SINCE_VERSION: u32 = 3;
+
+/// <https://wayland.app/protocols/wayland#wl_output:enum:mode>
+pub const WL_OUTPUT_MODE_CURRENT: u32 = 1;
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_wm_base:request:destroy>
+pub const XDG_WM_BASE_DESTROY: u32 = 0;
+/// <https://wayland.app/protocols/xdg-shell#xdg_wm_base:request:get_xdg_surface>
+pub const XDG_WM_BASE_GET_XDG_SURFACE: u32 = 2;
+/// <https://wayland.app/protocols/xdg-shell#xdg_wm_base:request:pong>
+pub const XDG_WM_BASE_PONG: u32 = 3;
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface:request:destroy>
+pub const XDG_SURFACE_DESTROY: u32 = 0;
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface:request:get_toplevel>
+pub const XDG_SURFACE_GET_TOPLEVEL: u32 = 1;
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface:request:set_window_geometry>
+pub const XDG_SURFACE_SET_WINDOW_GEOMETRY: u32 = 3;
+/// <https://wayland.app/protocols/xdg-shell#xdg_surface:request:ack_configure>
+pub const XDG_SURFACE_ACK_CONFIGURE: u32 = 4;
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:destroy>
+pub const XDG_TOPLEVEL_DESTROY: u32 = 0;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_title>
+pub const XDG_TOPLEVEL_SET_TITLE: u32 = 2;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_app_id>
+pub const XDG_TOPLEVEL_SET_APP_ID: u32 = 3;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_max_size>
+pub const XDG_TOPLEVEL_SET_MAX_SIZE: u32 = 7;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_min_size>
+pub const XDG_TOPLEVEL_SET_MIN_SIZE: u32 = 8;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_maximized>
+pub const XDG_TOPLEVEL_SET_MAXIMIZED: u32 = 9;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:unset_maximized>
+pub const XDG_TOPLEVEL_UNSET_MAXIMIZED: u32 = 10;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_fullscreen>
+pub const XDG_TOPLEVEL_SET_FULLSCREEN: u32 = 11;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:unset_fullscreen>
+pub const XDG_TOPLEVEL_UNSET_FULLSCREEN: u32 = 12;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:request:set_minimized>
+pub const XDG_TOPLEVEL_SET_MINIMIZED: u32 = 13;
+
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:enum:state>
+pub const XDG_TOPLEVEL_STATE_MAXIMIZED: u32 = 1;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:enum:state>
+pub const XDG_TOPLEVEL_STATE_FULLSCREEN: u32 = 2;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:enum:state>
+pub const XDG_TOPLEVEL_STATE_RESIZING: u32 = 3;
+/// <https://wayland.app/protocols/xdg-shell#xdg_toplevel:enum:state>
+pub const XDG_TOPLEVEL_STATE_ACTIVATED: u32 = 4;
+
+// ================================================================================================================================ //
@@ -57,6 +57,18 @@ pub use ::xcb_sys::xcb_void_cookie_t;
 /// ...
 pub use ::xcb_sys::xcb_atom_t;
 
+/// ...
+pub use ::xcb_sys::xcb_timestamp_t;
+
+/// ...
+pub use ::xcb_sys::xcb_keycode_t;
+
+/// ...
+pub use ::xcb_sys::xcb_keysym_t;
+
+/// ...
+pub use ::xcb_sys::xcb_button_t;
+
 /// ...
 pub use ::xcb_sys::xcb_client_message_data_t;
 
@@ -128,6 +140,9 @@ pub use ::xcb_sys::xcb_get_window_attributes_reply_t;
 /// ...
 pub use ::xcb_sys::xcb_get_window_attributes_request_t;
 
+/// ...
+pub use ::xcb_sys::xcb_get_input_focus_reply_t;
+
 /// ...
 pub use ::xcb_sys::xcb_get_property_reply_t;
 
@@ -146,6 +161,18 @@ pub use ::xcb_sys::xcb_translate_coordinates_request_t;
 /// ...
 pub use ::xcb_sys::xcb_query_tree_reply_t;
 
+/// ...
+pub use ::xcb_sys::xcb_get_keyboard_mapping_reply_t;
+
+/// ...
+pub use ::xcb_sys::xcb_query_keymap_reply_t;
+
+/// ...
+pub use ::xcb_sys::xcb_query_pointer_reply_t;
+
+/// <https://www.x.org/releases/current/doc/kbproto/xkbproto.html>
+pub use ::xcb_sys::xcb_xkb_state_notify_event_t;
+
 /// ...
 pub use ::xcb_sys::xcb_point_t;
 
@@ -164,9 +191,74 @@ pub use ::xcb_sys::xcb_ewmh_connection_t;
 /// ...
 pub use ::xcb_sys::xcb_ewmh_get_extents_reply_t;
 
+/// ...
+pub use ::xcb_sys::xcb_ewmh_get_atoms_reply_t;
+
+/// ...
+pub use ::xcb_sys::xcb_ewmh_wm_state_action_t;
+
 /// ...
 pub use ::xcb_sys::xcb_icccm_get_text_property_reply_t;
 
+/// ...
+pub use ::xcb_sys::xcb_icccm_size_hints_t;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_get_atom_name.3.xhtml>
+pub use ::xcb_sys::xcb_get_atom_name_reply_t;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_get_selection_owner.3.xhtml>
+pub use ::xcb_sys::xcb_get_selection_owner_reply_t;
+
+/// <https://gitlab.freedesktop.org/xorg/proto/xorgproto/-/blob/master/randrproto.txt>
+pub use ::xcb_sys::xcb_randr_crtc_t;
+pub use ::xcb_sys::xcb_randr_get_monitors_reply_t;
+pub use ::xcb_sys::xcb_randr_mode_info_t;
+pub use ::xcb_sys::xcb_randr_mode_t;
+pub use ::xcb_sys::xcb_randr_monitor_info_iterator_t;
+pub use ::xcb_sys::xcb_randr_monitor_info_t;
+pub use ::xcb_sys::xcb_randr_output_t;
+
+/// ...
+pub use ::xcb_sys::xcb_ewmh_get_workarea_reply_t;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_create_cursor.3.xhtml>
+pub use ::xcb_sys::xcb_cursor_t;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_create_pixmap.3.xhtml>
+pub use ::xcb_sys::xcb_pixmap_t;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_grab_pointer.3.xhtml>
+pub use ::xcb_sys::xcb_grab_pointer_reply_t;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_query_extension.3.xhtml>
+pub use ::xcb_sys::xcb_query_extension_reply_t;
+
+/// <https://gitlab.freedesktop.org/xorg/proto/xorgproto/-/blob/master/renderproto.txt>
+pub use ::xcb_sys::xcb_render_pictforminfo_iterator_t;
+pub use ::xcb_sys::xcb_render_pictforminfo_t;
+pub use ::xcb_sys::xcb_render_pictformat_t;
+pub use ::xcb_sys::xcb_render_query_pict_formats_reply_t;
+pub use ::xcb_sys::xcb_render_query_version_reply_t;
+
+/// <https://gitlab.freedesktop.org/xorg/proto/xorgproto/-/blob/master/specs/XI2proto.txt>
+pub use ::xcb_sys::xcb_input_button_press_event_t;
+pub use ::xcb_sys::xcb_input_device_class_iterator_t;
+pub use ::xcb_sys::xcb_input_device_class_t;
+pub use ::xcb_sys::xcb_input_event_mask_t;
+pub use ::xcb_sys::xcb_input_fp1616_t;
+pub use ::xcb_sys::xcb_input_fp3232_t;
+pub use ::xcb_sys::xcb_input_motion_event_t;
+pub use ::xcb_sys::xcb_input_raw_motion_event_t;
+pub use ::xcb_sys::xcb_input_scroll_class_t;
+pub use ::xcb_sys::xcb_input_touch_begin_event_t;
+pub use ::xcb_sys::xcb_input_valuator_class_t;
+pub use ::xcb_sys::xcb_input_xi_device_info_iterator_t;
+pub use ::xcb_sys::xcb_input_xi_device_info_t;
+pub use ::xcb_sys::xcb_input_xi_get_client_pointer_reply_t;
+pub use ::xcb_sys::xcb_input_xi_query_device_reply_t;
+pub use ::xcb_sys::xcb_input_xi_query_pointer_reply_t;
+pub use ::xcb_sys::xcb_input_xi_query_version_reply_t;
+
 // ================================================================================================================================ //
 // Functions
 // -------------------------------------------------------------------------------------------------------------------------------- //
@@ -243,6 +335,26 @@ pub use ::xcb_sys::xcb_get_property_value;
 pub use ::xcb_sys::xcb_get_property_value_end;
 pub use ::xcb_sys::xcb_get_property_value_length;
 
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_delete_property.3.xhtml>
+pub use ::xcb_sys::xcb_delete_property;
+pub use ::xcb_sys::xcb_delete_property_checked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_set_selection_owner.3.xhtml>
+pub use ::xcb_sys::xcb_set_selection_owner;
+pub use ::xcb_sys::xcb_set_selection_owner_checked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_get_selection_owner.3.xhtml>
+pub use ::xcb_sys::xcb_get_selection_owner;
+pub use ::xcb_sys::xcb_get_selection_owner_reply;
+pub use ::xcb_sys::xcb_get_selection_owner_unchecked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_convert_selection.3.xhtml>
+pub use ::xcb_sys::xcb_convert_selection;
+pub use ::xcb_sys::xcb_convert_selection_checked;
+
+/// <https://xcb.freedesktop.org/PublicApi/#xcb_get_maximum_request_length>
+pub use ::xcb_sys::xcb_get_maximum_request_length;
+
 /// <https://www.x.org/releases/current/doc/man/man3/xcb_map_window.3.xhtml>
 pub use ::xcb_sys::xcb_map_window;
 pub use ::xcb_sys::xcb_map_window_checked;
@@ -254,6 +366,7 @@ pub use ::xcb_sys::xcb_unmap_window_checked;
 /// <https://www.x.org/releases/current/doc/man/man3/xcb_get_input_focus.3.xhtml>
 pub use ::xcb_sys::xcb_get_input_focus;
 pub use ::xcb_sys::xcb_get_input_focus_unchecked;
+pub use ::xcb_sys::xcb_get_input_focus_reply;
 
 /// <https://www.x.org/releases/current/doc/man/man3/xcb_set_input_focus.3.xhtml>
 pub use ::xcb_sys::xcb_set_input_focus;
@@ -263,6 +376,9 @@ pub use ::xcb_sys::xcb_set_input_focus_checked;
 pub use ::xcb_sys::xcb_circulate_window;
 pub use ::xcb_sys::xcb_circulate_window_checked;
 
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_clear_area.3.xhtml>
+pub use ::xcb_sys::xcb_clear_area;
+
 /// <https://www.x.org/releases/current/doc/man/man3/xcb_send_event.3.xhtml>
 pub use ::xcb_sys::xcb_send_event;
 pub use ::xcb_sys::xcb_send_event_checked;
@@ -286,6 +402,29 @@ pub use ::xcb_sys::xcb_query_tree;
 pub use ::xcb_sys::xcb_query_tree_reply;
 pub use ::xcb_sys::xcb_query_tree_unchecked;
 
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_get_keyboard_mapping.3.xhtml>
+pub use ::xcb_sys::xcb_get_keyboard_mapping;
+pub use ::xcb_sys::xcb_get_keyboard_mapping_keysyms;
+pub use ::xcb_sys::xcb_get_keyboard_mapping_keysyms_length;
+pub use ::xcb_sys::xcb_get_keyboard_mapping_reply;
+pub use ::xcb_sys::xcb_get_keyboard_mapping_unchecked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_query_keymap.3.xhtml>
+pub use ::xcb_sys::xcb_query_keymap;
+pub use ::xcb_sys::xcb_query_keymap_reply;
+pub use ::xcb_sys::xcb_query_keymap_unchecked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_query_pointer.3.xhtml>
+pub use ::xcb_sys::xcb_query_pointer;
+pub use ::xcb_sys::xcb_query_pointer_reply;
+pub use ::xcb_sys::xcb_query_pointer_unchecked;
+
+/// <https://www.x.org/releases/current/doc/kbproto/xkbproto.html>
+pub use ::xcb_sys::xcb_xkb_select_events;
+pub use ::xcb_sys::xcb_xkb_select_events_checked;
+pub use ::xcb_sys::xcb_xkb_per_client_flags;
+pub use ::xcb_sys::xcb_xkb_per_client_flags_reply;
+
 /// ...
 pub use ::xcb_sys::xcb_ewmh_init_atoms;
 pub use ::xcb_sys::xcb_ewmh_init_atoms_replies;
@@ -300,6 +439,28 @@ pub use ::xcb_sys::xcb_ewmh_get_frame_extents_from_reply;
 pub use ::xcb_sys::xcb_ewmh_get_frame_extents_reply;
 pub use ::xcb_sys::xcb_ewmh_get_frame_extents_unchecked;
 
+/// ...
+pub use ::xcb_sys::xcb_ewmh_get_wm_state;
+pub use ::xcb_sys::xcb_ewmh_get_wm_state_reply;
+pub use ::xcb_sys::xcb_ewmh_get_wm_state_unchecked;
+
+/// ...
+pub use ::xcb_sys::xcb_ewmh_get_atoms_reply_wipe;
+
+/// ...
+pub use ::xcb_sys::xcb_ewmh_get_wm_allowed_actions;
+pub use ::xcb_sys::xcb_ewmh_get_wm_allowed_actions_reply;
+pub use ::xcb_sys::xcb_ewmh_get_wm_allowed_actions_unchecked;
+
+/// ...
+pub use ::xcb_sys::xcb_ewmh_get_current_desktop;
+pub use ::xcb_sys::xcb_ewmh_get_current_desktop_reply;
+
+/// ...
+pub use ::xcb_sys::xcb_ewmh_get_workarea;
+pub use ::xcb_sys::xcb_ewmh_get_workarea_reply;
+pub use ::xcb_sys::xcb_ewmh_get_workarea_reply_wipe;
+
 /// ...
 pub use ::xcb_sys::xcb_icccm_set_wm_name;
 pub use ::xcb_sys::xcb_icccm_set_wm_name_checked;
@@ -309,6 +470,151 @@ pub use ::xcb_sys::xcb_icccm_get_wm_name;
 pub use ::xcb_sys::xcb_icccm_get_wm_name_reply;
 pub use ::xcb_sys::xcb_icccm_get_wm_name_unchecked;
 
+/// ...
+pub use ::xcb_sys::xcb_icccm_set_wm_normal_hints;
+pub use ::xcb_sys::xcb_icccm_set_wm_normal_hints_checked;
+
+/// ...
+pub use ::xcb_sys::xcb_icccm_get_wm_normal_hints;
+pub use ::xcb_sys::xcb_icccm_get_wm_normal_hints_reply;
+pub use ::xcb_sys::xcb_icccm_get_wm_normal_hints_unchecked;
+
+/// ...
+pub use ::xcb_sys::xcb_icccm_size_hints_set_max_size;
+pub use ::xcb_sys::xcb_icccm_size_hints_set_min_size;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_get_atom_name.3.xhtml>
+pub use ::xcb_sys::xcb_get_atom_name;
+pub use ::xcb_sys::xcb_get_atom_name_name;
+pub use ::xcb_sys::xcb_get_atom_name_name_length;
+pub use ::xcb_sys::xcb_get_atom_name_reply;
+pub use ::xcb_sys::xcb_get_atom_name_unchecked;
+
+/// <https://gitlab.freedesktop.org/xorg/proto/xorgproto/-/blob/master/randrproto.txt>
+pub use ::xcb_sys::xcb_randr_get_crtc_info;
+pub use ::xcb_sys::xcb_randr_get_crtc_info_outputs;
+pub use ::xcb_sys::xcb_randr_get_crtc_info_outputs_length;
+pub use ::xcb_sys::xcb_randr_get_crtc_info_reply;
+pub use ::xcb_sys::xcb_randr_get_monitors;
+pub use ::xcb_sys::xcb_randr_get_monitors_monitors_iterator;
+pub use ::xcb_sys::xcb_randr_get_monitors_reply;
+pub use ::xcb_sys::xcb_randr_get_monitors_unchecked;
+pub use ::xcb_sys::xcb_randr_get_output_info;
+pub use ::xcb_sys::xcb_randr_get_output_info_modes;
+pub use ::xcb_sys::xcb_randr_get_output_info_modes_length;
+pub use ::xcb_sys::xcb_randr_get_output_info_reply;
+pub use ::xcb_sys::xcb_randr_get_screen_resources_current;
+pub use ::xcb_sys::xcb_randr_get_screen_resources_current_modes;
+pub use ::xcb_sys::xcb_randr_get_screen_resources_current_modes_length;
+pub use ::xcb_sys::xcb_randr_get_screen_resources_current_reply;
+pub use ::xcb_sys::xcb_randr_monitor_info_next;
+pub use ::xcb_sys::xcb_randr_monitor_info_outputs;
+pub use ::xcb_sys::xcb_randr_monitor_info_outputs_length;
+pub use ::xcb_sys::xcb_randr_select_input;
+pub use ::xcb_sys::xcb_randr_select_input_checked;
+pub use ::xcb_sys::xcb_randr_set_crtc_config;
+pub use ::xcb_sys::xcb_randr_set_crtc_config_reply;
+
+/// <https://xcb.freedesktop.org/ProtocolExtensionApi/>
+pub use ::xcb_sys::xcb_get_extension_data;
+pub use ::xcb_sys::xcb_randr_id;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_query_extension.3.xhtml>
+pub use ::xcb_sys::xcb_query_extension;
+pub use ::xcb_sys::xcb_query_extension_reply;
+pub use ::xcb_sys::xcb_query_extension_unchecked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_open_font.3.xhtml>
+pub use ::xcb_sys::xcb_open_font;
+pub use ::xcb_sys::xcb_open_font_checked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_close_font.3.xhtml>
+pub use ::xcb_sys::xcb_close_font;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_create_pixmap.3.xhtml>
+pub use ::xcb_sys::xcb_create_pixmap;
+pub use ::xcb_sys::xcb_create_pixmap_checked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_free_pixmap.3.xhtml>
+pub use ::xcb_sys::xcb_free_pixmap;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_create_gc.3.xhtml>
+pub use ::xcb_sys::xcb_create_gc;
+pub use ::xcb_sys::xcb_create_gc_checked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_free_gc.3.xhtml>
+pub use ::xcb_sys::xcb_free_gc;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_put_image.3.xhtml>
+pub use ::xcb_sys::xcb_put_image;
+pub use ::xcb_sys::xcb_put_image_checked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_create_cursor.3.xhtml>
+pub use ::xcb_sys::xcb_create_cursor;
+pub use ::xcb_sys::xcb_create_cursor_checked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_create_glyph_cursor.3.xhtml>
+pub use ::xcb_sys::xcb_create_glyph_cursor;
+pub use ::xcb_sys::xcb_create_glyph_cursor_checked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_free_cursor.3.xhtml>
+pub use ::xcb_sys::xcb_free_cursor;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_grab_pointer.3.xhtml>
+pub use ::xcb_sys::xcb_grab_pointer;
+pub use ::xcb_sys::xcb_grab_pointer_reply;
+pub use ::xcb_sys::xcb_grab_pointer_unchecked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_ungrab_pointer.3.xhtml>
+pub use ::xcb_sys::xcb_ungrab_pointer;
+pub use ::xcb_sys::xcb_ungrab_pointer_checked;
+
+/// <https://www.x.org/releases/current/doc/man/man3/xcb_warp_pointer.3.xhtml>
+pub use ::xcb_sys::xcb_warp_pointer;
+pub use ::xcb_sys::xcb_warp_pointer_checked;
+
+/// <https://gitlab.freedesktop.org/xorg/proto/xorgproto/-/blob/master/renderproto.txt>
+pub use ::xcb_sys::xcb_render_create_cursor;
+pub use ::xcb_sys::xcb_render_create_cursor_checked;
+pub use ::xcb_sys::xcb_render_create_picture;
+pub use ::xcb_sys::xcb_render_create_picture_checked;
+pub use ::xcb_sys::xcb_render_free_picture;
+pub use ::xcb_sys::xcb_render_pictforminfo_next;
+pub use ::xcb_sys::xcb_render_query_pict_formats;
+pub use ::xcb_sys::xcb_render_query_pict_formats_formats_iterator;
+pub use ::xcb_sys::xcb_render_query_pict_formats_reply;
+pub use ::xcb_sys::xcb_render_query_version;
+pub use ::xcb_sys::xcb_render_query_version_reply;
+
+/// <https://gitlab.freedesktop.org/xorg/proto/xorgproto/-/blob/master/specs/XI2proto.txt>
+pub use ::xcb_sys::xcb_input_button_press_axisvalues;
+pub use ::xcb_sys::xcb_input_button_press_axisvalues_length;
+pub use ::xcb_sys::xcb_input_button_press_button_mask;
+pub use ::xcb_sys::xcb_input_button_press_button_mask_length;
+pub use ::xcb_sys::xcb_input_button_press_valuator_mask;
+pub use ::xcb_sys::xcb_input_button_press_valuator_mask_length;
+pub use ::xcb_sys::xcb_input_device_class_next;
+pub use ::xcb_sys::xcb_input_raw_button_press_axisvalues_raw;
+pub use ::xcb_sys::xcb_input_raw_button_press_axisvalues_raw_length;
+pub use ::xcb_sys::xcb_input_raw_button_press_valuator_mask;
+pub use ::xcb_sys::xcb_input_raw_button_press_valuator_mask_length;
+pub use ::xcb_sys::xcb_input_xi_device_info_classes_iterator;
+pub use ::xcb_sys::xcb_input_xi_device_info_name;
+pub use ::xcb_sys::xcb_input_xi_device_info_next;
+pub use ::xcb_sys::xcb_input_xi_get_client_pointer;
+pub use ::xcb_sys::xcb_input_xi_get_client_pointer_reply;
+pub use ::xcb_sys::xcb_input_xi_query_device;
+pub use ::xcb_sys::xcb_input_xi_query_device_infos_iterator;
+pub use ::xcb_sys::xcb_input_xi_query_device_reply;
+pub use ::xcb_sys::xcb_input_xi_query_pointer;
+pub use ::xcb_sys::xcb_input_xi_query_pointer_buttons;
+pub use ::xcb_sys::xcb_input_xi_query_pointer_buttons_length;
+pub use ::xcb_sys::xcb_input_xi_query_pointer_reply;
+pub use ::xcb_sys::xcb_input_xi_query_version;
+pub use ::xcb_sys::xcb_input_xi_query_version_reply;
+pub use ::xcb_sys::xcb_input_xi_select_events;
+pub use ::xcb_sys::xcb_input_xi_select_events_checked;
+
 // ================================================================================================================================ //
 // Constants
 // -------------------------------------------------------------------------------------------------------------------------------- //
@@ -349,6 +655,9 @@ pub use ::xcb_sys::XCB_PROP_MODE_APPEND;
 pub use ::xcb_sys::XCB_PROP_MODE_PREPEND;
 pub use ::xcb_sys::XCB_PROP_MODE_REPLACE;
 
+pub use ::xcb_sys::XCB_PROPERTY_DELETE;
+pub use ::xcb_sys::XCB_PROPERTY_NEW_VALUE;
+
 pub use ::xcb_sys::XCB_CONFIG_WINDOW_BORDER_WIDTH;
 pub use ::xcb_sys::XCB_CONFIG_WINDOW_HEIGHT;
 pub use ::xcb_sys::XCB_CONFIG_WINDOW_SIBLING;
@@ -395,6 +704,8 @@ pub use ::xcb_sys::XCB_INPUT_FOCUS_NONE;
 pub use ::xcb_sys::XCB_INPUT_FOCUS_PARENT;
 pub use ::xcb_sys::XCB_INPUT_FOCUS_POINTER_ROOT;
 
+pub use ::xcb_sys::XCB_MAP_STATE_VIEWABLE;
+
 pub use ::xcb_sys::XCB_CURRENT_TIME;
 
 pub use ::xcb_sys::XCB_CIRCULATE_LOWER_HIGHEST;
@@ -550,4 +861,98 @@ pub use ::xcb_sys::XCB_KEY_BUT_MASK_MOD_4;
 pub use ::xcb_sys::XCB_KEY_BUT_MASK_MOD_5;
 pub use ::xcb_sys::XCB_KEY_BUT_MASK_SHIFT;
 
+pub use ::xcb_sys::XCB_MAPPING_KEYBOARD;
+pub use ::xcb_sys::XCB_MAPPING_MODIFIER;
+pub use ::xcb_sys::XCB_MAPPING_POINTER;
+
+pub use ::xcb_sys::XCB_BUTTON_INDEX_1;
+pub use ::xcb_sys::XCB_BUTTON_INDEX_2;
+pub use ::xcb_sys::XCB_BUTTON_INDEX_3;
+pub use ::xcb_sys::XCB_BUTTON_INDEX_4;
+pub use ::xcb_sys::XCB_BUTTON_INDEX_5;
+
+pub use ::xcb_sys::XCB_XKB_EVENT_TYPE_MAP_NOTIFY;
+pub use ::xcb_sys::XCB_XKB_EVENT_TYPE_NEW_KEYBOARD_NOTIFY;
+pub use ::xcb_sys::XCB_XKB_EVENT_TYPE_STATE_NOTIFY;
+
+pub use ::xcb_sys::XCB_XKB_MAP_NOTIFY;
+pub use ::xcb_sys::XCB_XKB_NEW_KEYBOARD_NOTIFY;
+pub use ::xcb_sys::XCB_XKB_STATE_NOTIFY;
+
+pub use ::xcb_sys::XCB_XKB_PER_CLIENT_FLAG_DETECTABLE_AUTO_REPEAT;
+
+pub use ::xcb_sys::XCB_EWMH_WM_STATE_ADD;
+pub use ::xcb_sys::XCB_EWMH_WM_STATE_REMOVE;
+pub use ::xcb_sys::XCB_EWMH_WM_STATE_TOGGLE;
+
+pub use ::xcb_sys::XCB_ICCCM_WM_STATE_ICONIC;
+pub use ::xcb_sys::XCB_ICCCM_WM_STATE_NORMAL;
+pub use ::xcb_sys::XCB_ICCCM_WM_STATE_WITHDRAWN;
+
+pub use ::xcb_sys::XCB_ICCCM_SIZE_HINT_P_MAX_SIZE;
+pub use ::xcb_sys::XCB_ICCCM_SIZE_HINT_P_MIN_SIZE;
+
+pub use ::xcb_sys::XCB_GRAB_MODE_ASYNC;
+pub use ::xcb_sys::XCB_GRAB_MODE_SYNC;
+
+pub use ::xcb_sys::XCB_GRAB_STATUS_ALREADY_GRABBED;
+pub use ::xcb_sys::XCB_GRAB_STATUS_FROZEN;
+pub use ::xcb_sys::XCB_GRAB_STATUS_INVALID_TIME;
+pub use ::xcb_sys::XCB_GRAB_STATUS_NOT_VIEWABLE;
+pub use ::xcb_sys::XCB_GRAB_STATUS_SUCCESS;
+
+pub use ::xcb_sys::XCB_NOTIFY_MODE_GRAB;
+pub use ::xcb_sys::XCB_NOTIFY_MODE_NORMAL;
+pub use ::xcb_sys::XCB_NOTIFY_MODE_UNGRAB;
+pub use ::xcb_sys::XCB_NOTIFY_MODE_WHILE_GRABBED;
+
+pub use ::xcb_sys::XCB_IMAGE_FORMAT_XY_BITMAP;
+pub use ::xcb_sys::XCB_IMAGE_FORMAT_XY_PIXMAP;
+pub use ::xcb_sys::XCB_IMAGE_FORMAT_Z_PIXMAP;
+
+pub use ::xcb_sys::XCB_RENDER_PICT_TYPE_DIRECT;
+pub use ::xcb_sys::XCB_RENDER_PICT_TYPE_INDEXED;
+
+pub use ::xcb_sys::XCB_INPUT_DEVICE_ALL;
+pub use ::xcb_sys::XCB_INPUT_DEVICE_ALL_MASTER;
+
+pub use ::xcb_sys::XCB_INPUT_XI_EVENT_MASK_DEVICE_CHANGED;
+pub use ::xcb_sys::XCB_INPUT_XI_EVENT_MASK_MOTION;
+pub use ::xcb_sys::XCB_INPUT_XI_EVENT_MASK_RAW_MOTION;
+pub use ::xcb_sys::XCB_INPUT_XI_EVENT_MASK_TOUCH_BEGIN;
+pub use ::xcb_sys::XCB_INPUT_XI_EVENT_MASK_TOUCH_END;
+pub use ::xcb_sys::XCB_INPUT_XI_EVENT_MASK_TOUCH_UPDATE;
+
+pub use ::xcb_sys::XCB_INPUT_DEVICE_CHANGED;
+pub use ::xcb_sys::XCB_INPUT_MOTION;
+pub use ::xcb_sys::XCB_INPUT_RAW_MOTION;
+pub use ::xcb_sys::XCB_INPUT_TOUCH_BEGIN;
+pub use ::xcb_sys::XCB_INPUT_TOUCH_END;
+pub use ::xcb_sys::XCB_INPUT_TOUCH_UPDATE;
+
+pub use ::xcb_sys::XCB_INPUT_DEVICE_CLASS_TYPE_SCROLL;
+pub use ::xcb_sys::XCB_INPUT_DEVICE_CLASS_TYPE_VALUATOR;
+
+pub use ::xcb_sys::XCB_INPUT_DEVICE_TYPE_MASTER_POINTER;
+
+pub use ::xcb_sys::XCB_INPUT_SCROLL_TYPE_HORIZONTAL;
+pub use ::xcb_sys::XCB_INPUT_SCROLL_TYPE_VERTICAL;
+
+pub use ::xcb_sys::XCB_RANDR_MODE_FLAG_DOUBLE_SCAN;
+pub use ::xcb_sys::XCB_RANDR_MODE_FLAG_INTERLACE;
+
+pub use ::xcb_sys::XCB_RANDR_ROTATION_ROTATE_0;
+pub use ::xcb_sys::XCB_RANDR_ROTATION_ROTATE_180;
+pub use ::xcb_sys::XCB_RANDR_ROTATION_ROTATE_270;
+pub use ::xcb_sys::XCB_RANDR_ROTATION_ROTATE_90;
+
+pub use ::xcb_sys::XCB_RANDR_SET_CONFIG_SUCCESS;
+
+pub use ::xcb_sys::XCB_RANDR_NOTIFY_MASK_CRTC_CHANGE;
+pub use ::xcb_sys::XCB_RANDR_NOTIFY_MASK_OUTPUT_CHANGE;
+pub use ::xcb_sys::XCB_RANDR_NOTIFY_MASK_SCREEN_CHANGE;
+
+pub use ::xcb_sys::XCB_RANDR_NOTIFY;
+pub use ::xcb_sys::XCB_RANDR_SCREEN_CHANGE_NOTIFY;
+
 // ================================================================================================================================ //
@@ -0,0 +1,289 @@
+/*
+ *  Crate: GUI-Sys
+ * Module: Linux - XKBCommon
+ */
+
+//! Linux XKBCommon bindings.
+//!
+//! # Dependencies
+//! * System Libraries: `libxkbcommon`, and `libxkbcommon-x11` (with the `x11` feature).
+//!     * There are no maintained `-sys` crates that cover the X11 and Compose APIs, so the used items are declared here.
+//!
+//! # Documentation
+//! * <https://xkbcommon.org/doc/current/>
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+use crate::common::c_types::*;
+
+#[cfg(feature = "x11")]
+use ::xcb_sys::xcb_connection_t;
+
+// ================================================================================================================================ //
+// Macros
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+// ================================================================================================================================ //
+// Types
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://xkbcommon.org/doc/current/group__context.html>
+#[repr(C)]
+pub struct xkb_context {
+    _opaque: [u8; 0],
+}
+
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+#[repr(C)]
+pub struct xkb_keymap {
+    _opaque: [u8; 0],
+}
+
+/// <https://xkbcommon.org/doc/current/group__state.html>
+#[repr(C)]
+pub struct xkb_state {
+    _opaque: [u8; 0],
+}
+
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+#[repr(C)]
+pub struct xkb_compose_table {
+    _opaque: [u8; 0],
+}
+
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+#[repr(C)]
+pub struct xkb_compose_state {
+    _opaque: [u8; 0],
+}
+
+/// <https://xkbcommon.org/doc/current/group__components.html>
+pub type xkb_keycode_t = u32;
+
+/// <https://xkbcommon.org/doc/current/group__components.html>
+pub type xkb_keysym_t = u32;
+
+/// <https://xkbcommon.org/doc/current/group__components.html>
+pub type xkb_layout_index_t = u32;
+
+/// <https://xkbcommon.org/doc/current/group__components.html>
+pub type xkb_level_index_t = u32;
+
+/// <https://xkbcommon.org/doc/current/group__components.html>
+pub type xkb_mod_mask_t = u32;
+
+/// <https://xkbcommon.org/doc/current/group__context.html>
+pub type xkb_context_flags = c_int;
+
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+pub type xkb_keymap_compile_flags = c_int;
+
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+pub type xkb_keymap_format = c_int;
+
+/// <https://xkbcommon.org/doc/current/group__state.html>
+pub type xkb_key_direction = c_int;
+
+/// <https://xkbcommon.org/doc/current/group__state.html>
+pub type xkb_state_component = c_int;
+
+/// <https://xkbcommon.org/doc/current/group__x11.html>
+#[cfg(feature = "x11")]
+pub type xkb_x11_setup_xkb_extension_flags = c_int;
+
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub type xkb_compose_compile_flags = c_int;
+
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub type xkb_compose_state_flags = c_int;
+
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub type xkb_compose_status = c_int;
+
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub type xkb_compose_feed_result = c_int;
+
+// ================================================================================================================================ //
+// Functions
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[link(name = "xkbcommon")]
+extern "C" {
+    /// <https://xkbcommon.org/doc/current/group__context.html>
+    pub fn xkb_context_new(flags: xkb_context_flags) -> *mut xkb_context;
+    /// <https://xkbcommon.org/doc/current/group__context.html>
+    pub fn xkb_context_unref(context: *mut xkb_context);
+
+    /// <https://xkbcommon.org/doc/current/group__keymap.html>
+    pub fn xkb_keymap_new_from_string(
+        context: *mut xkb_context,
+        string: *const c_char,
+        format: xkb_keymap_format,
+        flags: xkb_keymap_compile_flags,
+    ) -> *mut xkb_keymap;
+    /// <https://xkbcommon.org/doc/current/group__keymap.html>
+    pub fn xkb_keymap_unref(keymap: *mut xkb_keymap);
+    /// <https://xkbcommon.org/doc/current/group__keymap.html>
+    pub fn xkb_keymap_key_get_syms_by_level(
+        keymap: *mut xkb_keymap,
+        key: xkb_keycode_t,
+        layout: xkb_layout_index_t,
+        level: xkb_level_index_t,
+        syms_out: *mut *const xkb_keysym_t,
+    ) -> c_int;
+
+    /// <https://xkbcommon.org/doc/current/group__state.html>
+    pub fn xkb_state_new(keymap: *mut xkb_keymap) -> *mut xkb_state;
+    /// <https://xkbcommon.org/doc/current/group__state.html>
+    pub fn xkb_state_unref(state: *mut xkb_state);
+    /// <https://xkbcommon.org/doc/current/group__state.html>
+    pub fn xkb_state_update_mask(
+        state: *mut xkb_state,
+        depressed_mods: xkb_mod_mask_t,
+        latched_mods: xkb_mod_mask_t,
+        locked_mods: xkb_mod_mask_t,
+        depressed_layout: xkb_layout_index_t,
+        latched_layout: xkb_layout_index_t,
+        locked_layout: xkb_layout_index_t,
+    ) -> c_int;
+    /// <https://xkbcommon.org/doc/current/group__state.html>
+    pub fn xkb_state_key_get_one_sym(state: *mut xkb_state, key: xkb_keycode_t) -> xkb_keysym_t;
+    /// <https://xkbcommon.org/doc/current/group__state.html>
+    pub fn xkb_state_key_get_utf32(state: *mut xkb_state, key: xkb_keycode_t) -> u32;
+    /// <https://xkbcommon.org/doc/current/group__state.html>
+    pub fn xkb_state_mod_name_is_active(
+        state: *mut xkb_state,
+        name: *const c_char,
+        type_: xkb_state_component,
+    ) -> c_int;
+
+    /// <https://xkbcommon.org/doc/current/group__keysyms.html>
+    pub fn xkb_keysym_to_utf32(keysym: xkb_keysym_t) -> u32;
+
+    /// <https://xkbcommon.org/doc/current/group__compose.html>
+    pub fn xkb_compose_table_new_from_locale(
+        context: *mut xkb_context,
+        locale: *const c_char,
+        flags: xkb_compose_compile_flags,
+    ) -> *mut xkb_compose_table;
+    /// <https://xkbcommon.org/doc/current/group__compose.html>
+    pub fn xkb_compose_table_unref(table: *mut xkb_compose_table);
+    /// <https://xkbcommon.org/doc/current/group__compose.html>
+    pub fn xkb_compose_state_new(
+        table: *mut xkb_compose_table,
+        flags: xkb_compose_state_flags,
+    ) -> *mut xkb_compose_state;
+    /// <https://xkbcommon.org/doc/current/group__compose.html>
+    pub fn xkb_compose_state_unref(state: *mut xkb_compose_state);
+    /// <https://xkbcommon.org/doc/current/group__compose.html>
+    pub fn xkb_compose_state_feed(
+        state: *mut xkb_compose_state,
+        keysym: xkb_keysym_t,
+    ) -> xkb_compose_feed_result;
+    /// <https://xkbcommon.org/doc/current/group__compose.html>
+    pub fn xkb_compose_state_reset(state: *mut xkb_compose_state);
+    /// <https://xkbcommon.org/doc/current/group__compose.html>
+    pub fn xkb_compose_state_get_status(state: *mut xkb_compose_state) -> xkb_compose_status;
+    /// <https://xkbcommon.org/doc/current/group__compose.html>
+    pub fn xkb_compose_state_get_utf8(
+        state: *mut xkb_compose_state,
+        buffer: *mut c_char,
+        size: usize,
+    ) -> c_int;
+}
+
+#[cfg(feature = "x11")]
+#[link(name = "xkbcommon-x11")]
+extern "C" {
+    /// <https://xkbcommon.org/doc/current/group__x11.html>
+    pub fn xkb_x11_setup_xkb_extension(
+        connection: *mut xcb_connection_t,
+        major_xkb_version: u16,
+        minor_xkb_version: u16,
+        flags: xkb_x11_setup_xkb_extension_flags,
+        major_xkb_version_out: *mut u16,
+        minor_xkb_version_out: *mut u16,
+        base_event_out: *mut u8,
+        base_error_out: *mut u8,
+    ) -> c_int;
+    /// <https://xkbcommon.org/doc/current/group__x11.html>
+    pub fn xkb_x11_get_core_keyboard_device_id(connection: *mut xcb_connection_t) -> i32;
+    /// <https://xkbcommon.org/doc/current/group__x11.html>
+    pub fn xkb_x11_keymap_new_from_device(
+        context: *mut xkb_context,
+        connection: *mut xcb_connection_t,
+        device_id: i32,
+        flags: xkb_keymap_compile_flags,
+    ) -> *mut xkb_keymap;
+    /// <https://xkbcommon.org/doc/current/group__x11.html>
+    pub fn xkb_x11_state_new_from_device(
+        keymap: *mut xkb_keymap,
+        connection: *mut xcb_connection_t,
+        device_id: i32,
+    ) -> *mut xkb_state;
+}
+
+// ================================================================================================================================ //
+// Constants
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// <https://xkbcommon.org/doc/current/group__context.html>
+pub const XKB_CONTEXT_NO_FLAGS: xkb_context_flags = 0;
+
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+pub const XKB_KEYMAP_COMPILE_NO_FLAGS: xkb_keymap_compile_flags = 0;
+
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+pub const XKB_KEYMAP_FORMAT_TEXT_V1: xkb_keymap_format = 1;
+
+/// <https://xkbcommon.org/doc/current/group__state.html>
+pub const XKB_KEY_UP: xkb_key_direction = 0;
+/// <https://xkbcommon.org/doc/current/group__state.html>
+pub const XKB_KEY_DOWN: xkb_key_direction = 1;
+
+/// <https://xkbcommon.org/doc/current/group__state.html>
+pub const XKB_STATE_MODS_EFFECTIVE: xkb_state_component = 1 << 3;
+
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+pub const XKB_MOD_NAME_SHIFT: &::std::ffi::CStr = c"Shift";
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+pub const XKB_MOD_NAME_CAPS: &::std::ffi::CStr = c"Lock";
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+pub const XKB_MOD_NAME_CTRL: &::std::ffi::CStr = c"Control";
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+pub const XKB_MOD_NAME_ALT: &::std::ffi::CStr = c"Mod1";
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+pub const XKB_MOD_NAME_NUM: &::std::ffi::CStr = c"Mod2";
+/// <https://xkbcommon.org/doc/current/group__keymap.html>
+pub const XKB_MOD_NAME_LOGO: &::std::ffi::CStr = c"Mod4";
+
+/// <https://xkbcommon.org/doc/current/group__x11.html>
+#[cfg(feature = "x11")]
+pub const XKB_X11_MIN_MAJOR_XKB_VERSION: u16 = 1;
+/// <https://xkbcommon.org/doc/current/group__x11.html>
+#[cfg(feature = "x11")]
+pub const XKB_X11_MIN_MINOR_XKB_VERSION: u16 = 0;
+/// <https://xkbcommon.org/doc/current/group__x11.html>
+#[cfg(feature = "x11")]
+pub const XKB_X11_SETUP_XKB_EXTENSION_NO_FLAGS: xkb_x11_setup_xkb_extension_flags = 0;
+
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub const XKB_COMPOSE_COMPILE_NO_FLAGS: xkb_compose_compile_flags = 0;
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub const XKB_COMPOSE_STATE_NO_FLAGS: xkb_compose_state_flags = 0;
+
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub const XKB_COMPOSE_NOTHING: xkb_compose_status = 0;
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub const XKB_COMPOSE_COMPOSING: xkb_compose_status = 1;
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub const XKB_COMPOSE_COMPOSED: xkb_compose_status = 2;
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub const XKB_COMPOSE_CANCELLED: xkb_compose_status = 3;
+
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub const XKB_COMPOSE_FEED_IGNORED: xkb_compose_feed_result = 0;
+/// <https://xkbcommon.org/doc/current/group__compose.html>
+pub const XKB_COMPOSE_FEED_ACCEPTED: xkb_compose_feed_result = 1;
+
+// ================================================================================================================================ //
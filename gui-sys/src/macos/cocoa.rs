@@ -101,6 +101,9 @@ pub use ::cocoa::appkit::NSWindowStyleMask;
 /// <https://developer.apple.com/documentation/appkit/nsbackingstoretype?language=objc>
 pub use ::cocoa::appkit::NSBackingStoreType;
 
+/// <https://developer.apple.com/documentation/appkit/nsevent/modifierflags?language=objc>
+pub use ::cocoa::appkit::NSEventModifierFlags;
+
 // ================================================================================================================================ //
 // Functions
 // -------------------------------------------------------------------------------------------------------------------------------- //
@@ -204,6 +207,16 @@ pub mod ns_event {
     pub unsafe fn keyCode(this: *mut NSEvent) -> c_ushort {
         msg_send![this, keyCode]
     }
+
+    /// <https://developer.apple.com/documentation/appkit/nsevent/1535211-modifierflags?language=objc>
+    pub unsafe fn modifierFlags(this: *mut NSEvent) -> NSEventModifierFlags {
+        msg_send![this, modifierFlags]
+    }
+
+    /// <https://developer.apple.com/documentation/appkit/nsevent/1528049-isarepeat?language=objc>
+    pub unsafe fn isARepeat(this: *mut NSEvent) -> BOOL {
+        msg_send![this, isARepeat]
+    }
 }
 
 // -------------------------------------------------------------------------------------------------------------------------------- //
@@ -392,6 +405,11 @@ pub mod ns_window {
     pub unsafe fn mouseLocationOutsideOfEventStream(this: *mut NSWindow) -> NSPoint {
         msg_send![this, mouseLocationOutsideOfEventStream]
     }
+
+    /// <https://developer.apple.com/documentation/appkit/nswindow/1419459-backingscalefactor?language=objc>
+    pub unsafe fn backingScaleFactor(this: *mut NSWindow) -> CGFloat {
+        msg_send![this, backingScaleFactor]
+    }
 }
 
 /// <https://developer.apple.com/documentation/appkit/nsscreen?language=objc>
@@ -417,6 +435,11 @@ pub mod ns_screen {
     pub unsafe fn localizedName(this: *mut NSScreen) -> *mut NSString {
         msg_send![this, localizedName]
     }
+
+    /// <https://developer.apple.com/documentation/appkit/nsscreen/1388385-backingscalefactor?language=objc>
+    pub unsafe fn backingScaleFactor(this: *mut NSScreen) -> CGFloat {
+        msg_send![this, backingScaleFactor]
+    }
 }
 
 /// <https://developer.apple.com/documentation/foundation/nsarray?language=objc>
@@ -84,6 +84,18 @@ pub const fn GET_WHEEL_DELTA_WPARAM(wParam: WPARAM) -> i16 {
     HIWORD(wParam as DWORD) as i16
 }
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-get_xbutton_wparam>
+pub const fn GET_XBUTTON_WPARAM(wParam: WPARAM) -> WORD {
+    // #define GET_XBUTTON_WPARAM(wParam) (HIWORD(wParam))
+    HIWORD(wParam as DWORD)
+}
+
+/// <https://learn.microsoft.com/en-us/previous-versions/windows/desktop/inputmsg/get-pointerid-wparam>
+pub const fn GET_POINTERID_WPARAM(wParam: WPARAM) -> WORD {
+    // #define GET_POINTERID_WPARAM(wParam) (LOWORD(wParam))
+    LOWORD(wParam as DWORD)
+}
+
 // ================================================================================================================================ //
 // Types
 // -------------------------------------------------------------------------------------------------------------------------------- //
@@ -157,9 +169,48 @@ pub use ::windows_sys::Win32::UI::WindowsAndMessaging::HHOOK;
 /// <https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#HLOCAL>
 pub type HLOCAL = HANDLE;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#HGLOBAL>
+pub type HGLOBAL = HANDLE;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#ATOM>
 pub type ATOM = WORD;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/shell/wm-dropfiles>
+pub use ::windows_sys::Win32::UI::Shell::HDROP;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#HCURSOR>
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::HCURSOR;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#HBITMAP>
+pub use ::windows_sys::Win32::Graphics::Gdi::HBITMAP;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-input>
+pub use ::windows_sys::Win32::UI::Input::HRAWINPUT;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-iconinfo>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::ICONINFO;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinputdevice>\
+pub use ::windows_sys::Win32::UI::Input::RAWINPUTDEVICE;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinput>\
+pub use ::windows_sys::Win32::UI::Input::RAWINPUT;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinputheader>\
+pub use ::windows_sys::Win32::UI::Input::RAWINPUTHEADER;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawmouse>\
+pub use ::windows_sys::Win32::UI::Input::RAWMOUSE;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-pointer_info>\
+pub use ::windows_sys::Win32::UI::Input::Pointer::POINTER_INFO;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-pointer_pen_info>\
+pub use ::windows_sys::Win32::UI::Input::Pointer::POINTER_PEN_INFO;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ne-winuser-tagpointer_input_type>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::POINTER_INPUT_TYPE;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/api/windef/ns-windef-point>\
 pub use ::windows_sys::Win32::Foundation::POINT;
 
@@ -205,6 +256,9 @@ pub use ::windows_sys::Win32::Graphics::Gdi::MONITORINFOEXA;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-monitorinfoexw>\
 pub use ::windows_sys::Win32::Graphics::Gdi::MONITORINFOEXW;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-devmodew>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DEVMODEW;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/debug/system-error-codes>\
 pub use ::windows_sys::Win32::Foundation::WIN32_ERROR;
 
@@ -256,6 +310,31 @@ pub use ::windows_sys::Win32::Graphics::Gdi::PAINTSTRUCT;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/timeapi/ns-timeapi-timecaps>
 pub use ::windows_sys::Win32::Media::TIMECAPS;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-input>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::INPUT;
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::INPUT_0;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-keybdinput>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::KEYBDINPUT;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEINPUT;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#members>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSE_EVENT_FLAGS;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-trackmouseevent>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::TRACKMOUSEEVENT;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/intl/input-method-manager>\
+pub use ::windows_sys::Win32::Globalization::HIMC;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/ns-imm-compositionform>\
+pub use ::windows_sys::Win32::UI::Input::Ime::COMPOSITIONFORM;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/ns-imm-candidateform>\
+pub use ::windows_sys::Win32::UI::Input::Ime::CANDIDATEFORM;
+
 // ================================================================================================================================ //
 // Functions
 // -------------------------------------------------------------------------------------------------------------------------------- //
@@ -275,6 +354,54 @@ pub use ::windows_sys::Win32::System::Diagnostics::Debug::FormatMessageW;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-localfree>\
 pub use ::windows_sys::Win32::System::Memory::LocalFree;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalalloc>\
+pub use ::windows_sys::Win32::System::Memory::GlobalAlloc;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalfree>\
+pub use ::windows_sys::Win32::Foundation::GlobalFree;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globallock>\
+pub use ::windows_sys::Win32::System::Memory::GlobalLock;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalunlock>\
+pub use ::windows_sys::Win32::System::Memory::GlobalUnlock;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalsize>\
+pub use ::windows_sys::Win32::System::Memory::GlobalSize;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-openclipboard>\
+pub use ::windows_sys::Win32::System::DataExchange::OpenClipboard;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-closeclipboard>\
+pub use ::windows_sys::Win32::System::DataExchange::CloseClipboard;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-emptyclipboard>\
+pub use ::windows_sys::Win32::System::DataExchange::EmptyClipboard;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getclipboarddata>\
+pub use ::windows_sys::Win32::System::DataExchange::GetClipboardData;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setclipboarddata>\
+pub use ::windows_sys::Win32::System::DataExchange::SetClipboardData;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isclipboardformatavailable>\
+pub use ::windows_sys::Win32::System::DataExchange::IsClipboardFormatAvailable;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclipboardformatw>\
+pub use ::windows_sys::Win32::System::DataExchange::RegisterClipboardFormatW;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-dragacceptfiles>\
+pub use ::windows_sys::Win32::UI::Shell::DragAcceptFiles;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-dragqueryfilew>\
+pub use ::windows_sys::Win32::UI::Shell::DragQueryFileW;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-dragquerypoint>\
+pub use ::windows_sys::Win32::UI::Shell::DragQueryPoint;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-dragfinish>\
+pub use ::windows_sys::Win32::UI::Shell::DragFinish;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getcurrentthreadid>\
 pub use ::windows_sys::Win32::System::Threading::GetCurrentThreadId;
 
@@ -323,6 +450,69 @@ pub use ::windows_sys::Win32::Graphics::Gdi::ScreenToClient;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-clienttoscreen>\
 pub use ::windows_sys::Win32::Graphics::Gdi::ClientToScreen;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcursorpos>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::SetCursorPos;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getcursorpos>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::GetCursorPos;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getsystemmetrics>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::GetSystemMetrics;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getsystemmetrics>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::SM_SWAPBUTTON;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcursor>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::SetCursor;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-clipcursor>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::ClipCursor;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-windowfrompoint>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::WindowFromPoint;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconindirect>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::CreateIconIndirect;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-destroyicon>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::DestroyIcon;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createbitmap>\
+pub use ::windows_sys::Win32::Graphics::Gdi::CreateBitmap;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-deleteobject>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DeleteObject;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerrawinputdevices>\
+pub use ::windows_sys::Win32::UI::Input::RegisterRawInputDevices;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getrawinputdata>\
+pub use ::windows_sys::Win32::UI::Input::GetRawInputData;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-sendinput>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::SendInput;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-mapvirtualkeyw>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MapVirtualKeyW;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getkeystate>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::GetKeyState;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getasynckeystate>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::GetAsyncKeyState;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-trackmouseevent>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::TrackMouseEvent;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getpointertype>\
+pub use ::windows_sys::Win32::UI::Input::Pointer::GetPointerType;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getpointerinfo>\
+pub use ::windows_sys::Win32::UI::Input::Pointer::GetPointerInfo;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getpointerpeninfo>\
+pub use ::windows_sys::Win32::UI::Input::Pointer::GetPointerPenInfo;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-adjustwindowrect>\
 pub use ::windows_sys::Win32::UI::WindowsAndMessaging::AdjustWindowRect;
 
@@ -335,6 +525,21 @@ pub use ::windows_sys::Win32::UI::HiDpi::AdjustWindowRectExForDpi;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforwindow>\
 pub use ::windows_sys::Win32::UI::HiDpi::GetDpiForWindow;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/nf-shellscalingapi-getdpiformonitor>\
+pub use ::windows_sys::Win32::UI::HiDpi::GetDpiForMonitor;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/ne-shellscalingapi-monitor_dpi_type>\
+pub use ::windows_sys::Win32::UI::HiDpi::MDT_EFFECTIVE_DPI;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/ne-shellscalingapi-monitor_dpi_type>\
+pub use ::windows_sys::Win32::UI::HiDpi::MDT_RAW_DPI;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setprocessdpiawarenesscontext>\
+pub use ::windows_sys::Win32::UI::HiDpi::SetProcessDpiAwarenessContext;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/hidpi/dpi-awareness-context>\
+pub use ::windows_sys::Win32::UI::HiDpi::DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmessagea>\
 pub use ::windows_sys::Win32::UI::WindowsAndMessaging::GetMessageA;
 
@@ -377,6 +582,12 @@ pub use ::windows_sys::Win32::UI::WindowsAndMessaging::PostThreadMessageW;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postquitmessage>\
 pub use ::windows_sys::Win32::UI::WindowsAndMessaging::PostQuitMessage;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-settimer>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::SetTimer;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-killtimer>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::KillTimer;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclassa>\
 pub use ::windows_sys::Win32::UI::WindowsAndMessaging::RegisterClassA;
 
@@ -452,6 +663,12 @@ pub use ::windows_sys::Win32::Graphics::Gdi::GetMonitorInfoW;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumdisplaymonitors>\
 pub use ::windows_sys::Win32::Graphics::Gdi::EnumDisplayMonitors;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumdisplaysettingsw>\
+pub use ::windows_sys::Win32::Graphics::Gdi::EnumDisplaySettingsW;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-changedisplaysettingsexw>\
+pub use ::windows_sys::Win32::Graphics::Gdi::ChangeDisplaySettingsExW;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-monitorfrompoint>\
 pub use ::windows_sys::Win32::Graphics::Gdi::MonitorFromPoint;
 
@@ -521,6 +738,24 @@ pub use ::windows_sys::Win32::Media::timeGetDevCaps;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-swapbuffers>
 pub use ::windows_sys::Win32::Graphics::OpenGL::SwapBuffers;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/nf-imm-immgetcontext>\
+pub use ::windows_sys::Win32::UI::Input::Ime::ImmGetContext;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/nf-imm-immreleasecontext>\
+pub use ::windows_sys::Win32::UI::Input::Ime::ImmReleaseContext;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/nf-imm-immassociatecontextex>\
+pub use ::windows_sys::Win32::UI::Input::Ime::ImmAssociateContextEx;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/nf-imm-immgetcompositionstringw>\
+pub use ::windows_sys::Win32::UI::Input::Ime::ImmGetCompositionStringW;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/nf-imm-immsetcompositionwindow>\
+pub use ::windows_sys::Win32::UI::Input::Ime::ImmSetCompositionWindow;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/nf-imm-immsetcandidatewindow>\
+pub use ::windows_sys::Win32::UI::Input::Ime::ImmSetCandidateWindow;
+
 // ================================================================================================================================ //
 // Constants
 // -------------------------------------------------------------------------------------------------------------------------------- //
@@ -530,6 +765,12 @@ pub use ::windows_sys::Win32::Foundation::FALSE;
 /// <https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#BOOL>
 pub use ::windows_sys::Win32::Foundation::TRUE;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-globalalloc#GMEM_MOVEABLE>\
+pub use ::windows_sys::Win32::System::Memory::GMEM_MOVEABLE;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/dataxchg/standard-clipboard-formats#CF_UNICODETEXT>\
+pub use ::windows_sys::Win32::System::SystemServices::CF_UNICODETEXT;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessage#FORMAT_MESSAGE_ALLOCATE_BUFFER>\
 pub use ::windows_sys::Win32::System::Diagnostics::Debug::FORMAT_MESSAGE_ALLOCATE_BUFFER;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessage#FORMAT_MESSAGE_FROM_SYSTEM>\
@@ -1015,6 +1256,8 @@ pub use ::windows_sys::Win32::UI::WindowsAndMessaging::WM_MOUSEFIRST;
 pub use ::windows_sys::Win32::UI::WindowsAndMessaging::WM_MOUSEHWHEEL;
 /// <...>\
 pub use ::windows_sys::Win32::UI::WindowsAndMessaging::WM_MOUSELAST;
+/// <https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mouseleave>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::WM_MOUSELEAVE;
 /// <https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mousemove>\
 pub use ::windows_sys::Win32::UI::WindowsAndMessaging::WM_MOUSEMOVE;
 /// <https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mousewheel>\
@@ -1467,6 +1710,32 @@ pub use ::windows_sys::Win32::Graphics::Gdi::MONITOR_DEFAULTTONULL;
 /// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-monitorfrompoint#MONITOR_DEFAULTTOPRIMARY>\
 pub use ::windows_sys::Win32::Graphics::Gdi::MONITOR_DEFAULTTOPRIMARY;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumdisplaysettingsw#ENUM_CURRENT_SETTINGS>\
+pub use ::windows_sys::Win32::Graphics::Gdi::ENUM_CURRENT_SETTINGS;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-changedisplaysettingsexw#CDS_FULLSCREEN>\
+pub use ::windows_sys::Win32::Graphics::Gdi::CDS_FULLSCREEN;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-changedisplaysettingsexw#DISP_CHANGE_SUCCESSFUL>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DISP_CHANGE_SUCCESSFUL;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-devmodew#DM_BITSPERPEL>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DM_BITSPERPEL;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-devmodew#DM_PELSWIDTH>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DM_PELSWIDTH;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-devmodew#DM_PELSHEIGHT>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DM_PELSHEIGHT;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-devmodew#DM_DISPLAYFREQUENCY>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DM_DISPLAYFREQUENCY;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-devmodew#DMDO_DEFAULT>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DMDO_DEFAULT;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-devmodew#DMDO_90>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DMDO_90;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-devmodew#DMDO_180>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DMDO_180;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-devmodew#DMDO_270>\
+pub use ::windows_sys::Win32::Graphics::Gdi::DMDO_270;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mousewheel#parameters>\
 pub use ::windows_sys::Win32::UI::WindowsAndMessaging::WHEEL_DELTA;
 
@@ -1485,6 +1754,53 @@ pub use ::windows_sys::Win32::System::SystemServices::MK_XBUTTON1;
 /// <https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mousewheel#MK_XBUTTON2>\
 pub use ::windows_sys::Win32::System::SystemServices::MK_XBUTTON2;
 
+/// <https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-xbuttondown#parameters>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::XBUTTON1;
+/// <https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-xbuttondown#parameters>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::XBUTTON2;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-trackmouseevent#TME_LEAVE>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::TME_LEAVE;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-input#INPUT_KEYBOARD>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::INPUT_KEYBOARD;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-input#INPUT_MOUSE>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::INPUT_MOUSE;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-keybdinput#KEYEVENTF_KEYUP>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::KEYEVENTF_KEYUP;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#MOUSEEVENTF_HWHEEL>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEEVENTF_HWHEEL;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#MOUSEEVENTF_LEFTDOWN>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEEVENTF_LEFTDOWN;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#MOUSEEVENTF_LEFTUP>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEEVENTF_LEFTUP;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#MOUSEEVENTF_MIDDLEDOWN>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEEVENTF_MIDDLEDOWN;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#MOUSEEVENTF_MIDDLEUP>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEEVENTF_MIDDLEUP;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#MOUSEEVENTF_RIGHTDOWN>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEEVENTF_RIGHTDOWN;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#MOUSEEVENTF_RIGHTUP>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEEVENTF_RIGHTUP;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#MOUSEEVENTF_WHEEL>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEEVENTF_WHEEL;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#MOUSEEVENTF_XDOWN>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEEVENTF_XDOWN;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-mouseinput#MOUSEEVENTF_XUP>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MOUSEEVENTF_XUP;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-mapvirtualkeyw#MAPVK_VK_TO_VSC>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MAPVK_VK_TO_VSC;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-mapvirtualkeyw#MAPVK_VSC_TO_VK_EX>\
+pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::MAPVK_VSC_TO_VK_EX;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/inputdev/about-keyboard-input#keystroke-message-flags>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::KF_EXTENDED;
+/// <https://learn.microsoft.com/en-us/windows/win32/inputdev/about-keyboard-input#keystroke-message-flags>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::KF_REPEAT;
+
 /// <https://learn.microsoft.com/en-us/windows/win32/inputdev/virtual-key-codes#VK_0>\
 pub use ::windows_sys::Win32::UI::Input::KeyboardAndMouse::VK_0;
 /// <https://learn.microsoft.com/en-us/windows/win32/inputdev/virtual-key-codes#VK_1>\
@@ -2013,4 +2329,73 @@ pub use ::windows_sys::Win32::UI::WindowsAndMessaging::WVR_VALIDRECTS;
 /// <https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-nccalcsize#return-value>\
 pub use ::windows_sys::Win32::UI::WindowsAndMessaging::WVR_VREDRAW;
 
+// Raw Input Constants
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getrawinputdata#parameters>\
+pub use ::windows_sys::Win32::UI::Input::RID_INPUT;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawinputheader#members>\
+pub use ::windows_sys::Win32::UI::Input::RIM_TYPEMOUSE;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawmouse#members>\
+pub const MOUSE_MOVE_ABSOLUTE: u16 = 0x01;
+
+/// <https://learn.microsoft.com/en-us/windows-hardware/drivers/hid/hid-usages#usage-page>\
+pub const HID_USAGE_PAGE_GENERIC: u16 = 0x01;
+
+/// <https://learn.microsoft.com/en-us/windows-hardware/drivers/hid/hid-usages#usage-id>\
+pub const HID_USAGE_GENERIC_MOUSE: u16 = 0x02;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-nchittest#return-value>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::HTCLIENT;
+
+// Pointer Input Constants
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ne-winuser-tagpointer_input_type>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::PT_PEN;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/winuser/ne-winuser-tagpointer_input_type>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::PT_TOUCH;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/inputmsg/pointer-flags-contants>\
+pub use ::windows_sys::Win32::UI::Input::Pointer::POINTER_FLAG_CANCELED;
+/// <https://learn.microsoft.com/en-us/windows/win32/inputmsg/pointer-flags-contants>\
+pub use ::windows_sys::Win32::UI::Input::Pointer::POINTER_FLAG_INCONTACT;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/inputmsg/pen-flags-constants>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::PEN_FLAG_BARREL;
+/// <https://learn.microsoft.com/en-us/windows/win32/inputmsg/pen-flags-constants>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::PEN_FLAG_ERASER;
+/// <https://learn.microsoft.com/en-us/windows/win32/inputmsg/pen-flags-constants>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::PEN_FLAG_INVERTED;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/inputmsg/pen-mask-constants>\
+pub use ::windows_sys::Win32::UI::WindowsAndMessaging::PEN_MASK_PRESSURE;
+
+// Input Method Constants
+
+/// <https://learn.microsoft.com/en-us/windows/win32/intl/wm-ime-composition#parameters>\
+pub use ::windows_sys::Win32::UI::Input::Ime::GCS_COMPATTR;
+/// <https://learn.microsoft.com/en-us/windows/win32/intl/wm-ime-composition#parameters>\
+pub use ::windows_sys::Win32::UI::Input::Ime::GCS_COMPSTR;
+/// <https://learn.microsoft.com/en-us/windows/win32/intl/wm-ime-composition#parameters>\
+pub use ::windows_sys::Win32::UI::Input::Ime::GCS_CURSORPOS;
+/// <https://learn.microsoft.com/en-us/windows/win32/intl/wm-ime-composition#parameters>\
+pub use ::windows_sys::Win32::UI::Input::Ime::GCS_RESULTSTR;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/intl/ime-attribute-constants>\
+pub use ::windows_sys::Win32::UI::Input::Ime::ATTR_TARGET_CONVERTED;
+/// <https://learn.microsoft.com/en-us/windows/win32/intl/ime-attribute-constants>\
+pub use ::windows_sys::Win32::UI::Input::Ime::ATTR_TARGET_NOTCONVERTED;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/ns-imm-compositionform#members>\
+pub use ::windows_sys::Win32::UI::Input::Ime::CFS_EXCLUDE;
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/ns-imm-compositionform#members>\
+pub use ::windows_sys::Win32::UI::Input::Ime::CFS_POINT;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/api/imm/nf-imm-immassociatecontextex#parameters>\
+pub use ::windows_sys::Win32::UI::Input::Ime::IACE_DEFAULT;
+
+/// <https://learn.microsoft.com/en-us/windows/win32/intl/wm-ime-setcontext#parameters>\
+pub use ::windows_sys::Win32::UI::Input::Ime::ISC_SHOWUICOMPOSITIONWINDOW;
+
 // ================================================================================================================================ //
@@ -26,6 +26,7 @@ pub fn main() {
     let settings = GameEngineSettings {
         fps: 60.0,
         vsync: true,
+        match_refresh_rate: true,
         fullscreen: false,
         width: 256.0 * 3.0,
         height: 256.0 * 3.0,
@@ -16,7 +16,7 @@ mod utils;
 // ================================================================================================================================ //
 
 use rand::Rng;
-use rge::{EngineState, Game, GameEngine, GameEngineSettings, inputs::Input};
+use rge::{EngineState, Game, GameEngine, GameEngineSettings};
 
 #[allow(unused_imports)]
 use rge::{glsl, rgba, vec2, vec3, vec4, Vertex, VertexUV};
@@ -30,6 +30,7 @@ pub fn main() {
     let settings = GameEngineSettings {
         fps: 0.0,
         vsync: true,
+        match_refresh_rate: false,
         fullscreen: true,
         width: 640.0 * 2.0,
         height: 360.0 * 2.0,
@@ -186,7 +187,7 @@ impl Game for App {
             self.update_particles();
         }
 
-        !engine.inputs.contains(Input::KeyPress { keycode: KC_ESCAPE, pressed: true })
+        !engine.inputs.contains_key(KC_ESCAPE, true)
     }
 
     fn render(&mut self, engine: &mut EngineState) -> bool {
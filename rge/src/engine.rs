@@ -24,7 +24,8 @@ pub use wyn::{
 };
 
 use std::{
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Range},
+    path::PathBuf,
     sync::{Condvar, Mutex},
 };
 
@@ -80,7 +81,8 @@ pub struct EngineState {
     /// The user may change these during the Render callback.
     pub render: RenderSettings,
 
-    /// Information about the connected Screens/Monitors.
+    /// Information about the connected Screens/Monitors.\
+    /// Only refreshed when the Screens change (see `Input::ScreensChanged`).
     pub screens: Vec<ScreenInfo>,
 
     /// The Inputs that were queued between Update calls.
@@ -236,7 +238,7 @@ impl<'a> EventHandler for GameEngine<'a> {
             |game, state| {
                 if let Some(window) = state.wyn_window.as_ref() {
                     if window.handle() == handle {
-                        state.window = self::read_window(events, window);
+                        state.window = self::read_window(events, window, &state.window);
                     } else {
                         return true;
                     }
@@ -290,12 +292,48 @@ impl<'a> EventHandler for GameEngine<'a> {
         inputs.events.push(input);
     }
 
+    fn scale_factor_changed(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        scale_factor: ScaleFactor,
+    ) {
+        let input = Input::ScaleFactorChanged { scale_factor };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn screens_changed(&self, events: &EventLoop) {
+        let screens = self::collect_screens(events);
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.screens = screens;
+        inputs.events.push(Input::ScreensChanged);
+    }
+
     fn cursor_move(&self, _events: &EventLoop, _handle: WindowHandle, point: Point) {
         let input = Input::CursorMove { point };
         let mut inputs = self.inputs.lock().unwrap();
         inputs.events.push(input);
     }
 
+    fn cursor_enter(&self, _events: &EventLoop, _handle: WindowHandle) {
+        let input = Input::CursorEnter;
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn cursor_leave(&self, _events: &EventLoop, _handle: WindowHandle) {
+        let input = Input::CursorLeave;
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn raw_motion(&self, _events: &EventLoop, delta_x: f64, delta_y: f64) {
+        let input = Input::RawMotion { delta_x, delta_y };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
     fn scroll_wheel(&self, _events: &EventLoop, _handle: WindowHandle, delta_x: f64, delta_y: f64) {
         let input = Input::ScrollWheel { delta_x, delta_y };
         let mut inputs = self.inputs.lock().unwrap();
@@ -314,14 +352,68 @@ impl<'a> EventHandler for GameEngine<'a> {
         inputs.events.push(input);
     }
 
+    fn touch_begin(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        let phase = TouchPhase::Begin;
+        let input = Input::Touch { id, phase, point };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn touch_move(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        let phase = TouchPhase::Move;
+        let input = Input::Touch { id, phase, point };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn touch_end(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        let phase = TouchPhase::End;
+        let input = Input::Touch { id, phase, point };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn touch_cancel(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        let phase = TouchPhase::Cancel;
+        let input = Input::Touch { id, phase, point };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn pen(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        point: Point,
+        pressure: f64,
+        tilt: (f64, f64),
+        buttons: PenButtons,
+    ) {
+        let input = Input::Pen {
+            point,
+            pressure,
+            tilt,
+            buttons,
+        };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
     fn key_press(
         &self,
         _events: &EventLoop,
         _handle: WindowHandle,
-        keycode: KeyCode,
+        event: KeyEvent,
         pressed: bool,
     ) {
-        let input = Input::KeyPress { keycode, pressed };
+        let input = Input::KeyPress {
+            keycode: event.key,
+            scancode: event.scancode,
+            modifiers: event.modifiers,
+            repeat: event.repeat,
+            location: event.location,
+            pressed,
+        };
         let mut inputs = self.inputs.lock().unwrap();
         inputs.events.push(input);
     }
@@ -331,6 +423,88 @@ impl<'a> EventHandler for GameEngine<'a> {
         let mut inputs = self.inputs.lock().unwrap();
         inputs.events.push(input);
     }
+
+    fn ime_preedit(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        text: String,
+        cursor_range: Option<Range<usize>>,
+    ) {
+        let input = Input::ImePreedit { text, cursor_range };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn ime_commit(&self, _events: &EventLoop, _handle: WindowHandle, text: String) {
+        let input = Input::ImeCommit { text };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn file_drop_hover(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+        let input = Input::FileDropHover { paths, point };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn file_drop(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+        let input = Input::FileDrop { paths, point };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn file_drop_cancel(&self, _events: &EventLoop, _handle: WindowHandle) {
+        let input = Input::FileDropCancel;
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn gamepad_connected(&self, _events: &EventLoop, id: GamepadId) {
+        let input = Input::GamepadConnected { id };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn gamepad_disconnected(&self, _events: &EventLoop, id: GamepadId) {
+        let input = Input::GamepadDisconnected { id };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn gamepad_button(
+        &self,
+        _events: &EventLoop,
+        id: GamepadId,
+        button: GamepadButton,
+        pressed: bool,
+    ) {
+        let input = Input::GamepadButton {
+            id,
+            button,
+            pressed,
+        };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
+
+    fn gamepad_axis(&self, _events: &EventLoop, id: GamepadId, axis: GamepadAxis, value: f32) {
+        let input = Input::GamepadAxis { id, axis, value };
+        let mut inputs = self.inputs.lock().unwrap();
+        inputs.events.push(input);
+    }
 }
 
 // ================================================================================================================================ //
@@ -356,10 +530,12 @@ impl<'a> GameEngine<'a> {
             self.start_shutdown(events);
         });
 
+        let fps = self.target_fps(events);
         self.write_state(|_game, state| {
             state.timer.toggle_vsync(self.init_settings.vsync);
-            state.timer.reset_fps(self.init_settings.fps);
+            state.timer.reset_fps(fps);
             state.timer.reset_epoch(std::time::Instant::now());
+            state.screens = self::collect_screens(events);
         });
 
         while events.is_running() {
@@ -369,18 +545,21 @@ impl<'a> GameEngine<'a> {
                 state.inputs = {
                     let mut lock = self.inputs.lock().unwrap();
 
-                    let screens = Screen::collect(events)
-                        .into_iter()
-                        .map(|screen| screen.info(events))
-                        .collect();
+                    // The Screens are only queried again when they change.
+                    if lock.events.contains(&Input::ScreensChanged) {
+                        state.screens = lock.screens.clone();
+                    }
+                    let screens = state.screens.clone();
 
-                    let xinput: XInputControllers = XInputController::collect(events).unwrap();
+                    let keyboard = self::collect_keyboard(events, state.wyn_window.as_ref());
+                    let gamepads = Gamepad::collect(events);
                     let events: Vec<Input> = lock.events.clone();
                     lock.events.clear();
 
                     Inputs {
                         events,
-                        xinput,
+                        keyboard,
+                        gamepads,
                         screens,
                     }
                 };
@@ -404,6 +583,17 @@ impl<'a> GameEngine<'a> {
         }
     }
 
+    /// Returns the Frames-Per-Second to update at, which is the Refresh Rate of the primary Screen if it should be matched.
+    fn target_fps(&self, events: &EventLoop) -> f64 {
+        let refresh_rate = Screen::primary(events).refresh_rate(events);
+
+        if self.init_settings.match_refresh_rate && refresh_rate.is_finite() && refresh_rate > 0.0 {
+            refresh_rate
+        } else {
+            self.init_settings.fps
+        }
+    }
+
     /// Waits until the Rendered-Update Count is greater-than or equal-to count.
     fn await_render(&self, count: usize) -> bool {
         if let Ok(rendered) = self.vsync_mutex.lock() {
@@ -455,10 +645,15 @@ fn write_window(events: &EventLoop, window: &Window, settings: &WindowSettings)
             window.set_style(events, WindowStyle::Captioned).unwrap();
         }
     }
+
+    // The Input Method settings cannot be read back, but are ignored by the Window while they are unchanged.
+    window.set_ime_allowed(events, settings.ime_allowed).unwrap();
+    window.set_ime_position(events, settings.ime_position).unwrap();
 }
 
-/// Reads the Window-Settings of a Window.
-fn read_window(events: &EventLoop, window: &Window) -> WindowSettings {
+/// Reads the Window-Settings of a Window.\
+/// Settings that cannot be read back from the Window are kept from the previous ones.
+fn read_window(events: &EventLoop, window: &Window, previous: &WindowSettings) -> WindowSettings {
     let name = window.name(events).unwrap();
     let rect = window.content_rect(events).unwrap();
     let fullscreen = window.is_fullscreen(events);
@@ -467,6 +662,25 @@ fn read_window(events: &EventLoop, window: &Window) -> WindowSettings {
         name,
         rect,
         fullscreen,
+        ime_allowed: previous.ime_allowed,
+        ime_position: previous.ime_position,
+    }
+}
+
+/// Reads the information of every connected Screen.
+fn collect_screens(events: &EventLoop) -> Vec<ScreenInfo> {
+    Screen::collect(events)
+        .into_iter()
+        .map(|screen| screen.info(events))
+        .collect()
+}
+
+/// Queries the state of the Keyboard and Mouse, with the cursor relative to the Window (if there is one).
+fn collect_keyboard(events: &EventLoop, window: Option<&Window>) -> KeyboardState {
+    KeyboardState {
+        keys: events.pressed_keys(),
+        buttons: events.mouse_buttons(),
+        cursor: window.and_then(|window| events.cursor_position(window.handle())),
     }
 }
 
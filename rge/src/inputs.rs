@@ -11,28 +11,50 @@
 use super::*;
 
 pub use wyn::inputs::*;
-pub use wyn::types::{Coord, Extent, Point, Rect, Size};
+pub use wyn::types::{Coord, Extent, Point, Rect, ScaleFactor, Size};
+
+use std::ops::Range;
+use std::path::PathBuf;
 
 // ================================================================================================================================ //
 
 /// A variant of all possible input-types.
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, PartialEq)]
 pub enum Input {
     /// The Window's focus has changed.
     WindowFocus {
         /// Focused or Unfocused flag.
         focused: bool,
     },
+    /// The Window's Scale Factor has changed (e.g. it was moved to a Screen with a different DPI).
+    ScaleFactorChanged {
+        /// The ratio of physical pixels to logical pixels.
+        scale_factor: ScaleFactor,
+    },
+    /// The connected Screens changed (e.g. a Monitor was connected, or its resolution changed).\
+    /// `Inputs::screens` holds the new Screens.
+    ScreensChanged,
     /// A Mouse-Cursor was moved in the Window.
     CursorMove {
         /// The position of the cursor, in pixel-coordinates.
         point: Point,
     },
+    /// The Mouse-Cursor entered the Window.
+    CursorEnter,
+    /// The Mouse-Cursor left the Window.
+    CursorLeave,
+    /// The Mouse was moved, regardless of the cursor (e.g. for First-Person cameras).
+    RawMotion {
+        /// The unaccelerated horizontal motion.
+        delta_x: f64,
+        /// The unaccelerated vertical motion.
+        delta_y: f64,
+    },
     /// A Scroll-Wheel was scrolled in the Window.
     ScrollWheel {
-        /// The horizontal scroll units.
+        /// The horizontal scroll units (fractional for high-resolution Wheels and Touchpads).
         delta_x: f64,
-        /// The vertical scroll units.
+        /// The vertical scroll units (fractional for high-resolution Wheels and Touchpads).
         delta_y: f64,
     },
     /// A Mouse-Button was pressed/released in the Window.
@@ -42,10 +64,39 @@ pub enum Input {
         /// Pressed or Released flag.
         pressed: bool,
     },
+    /// A finger touched, moved across, or was lifted off the Window (on a Touchscreen).\
+    /// Several fingers may touch at once, each with its own `id`.
+    Touch {
+        /// The ID of the touch, kept from its `Begin` until its `End` (or `Cancel`).
+        id: TouchId,
+        /// The phase of the touch.
+        phase: TouchPhase,
+        /// The position of the finger, in pixel-coordinates.
+        point: Point,
+    },
+    /// A Pen (Stylus) moved over the Window, touched it, or pressed its Buttons.
+    Pen {
+        /// The position of the Pen, in pixel-coordinates.
+        point: Point,
+        /// The pressure of the tip, from `0.0` to `1.0`.
+        pressure: f64,
+        /// The horizontal and vertical angle away from upright, in degrees.
+        tilt: (f64, f64),
+        /// The Buttons that are pressed, including the tip.
+        buttons: PenButtons,
+    },
     /// A Keyboard-Key was pressed/released in the Window.
     KeyPress {
-        /// The key-code.
+        /// The key-code, in the current keyboard layout.
         keycode: KeyCode,
+        /// The physical key that was pressed.
+        scancode: ScanCode,
+        /// The Modifiers at the time of the press/release, including the key itself if it is a Modifier Key.
+        modifiers: Modifiers,
+        /// Whether or not the press was generated by Auto-Repeat, while the key is held down.
+        repeat: bool,
+        /// Where the key is on the keyboard.
+        location: KeyLocation,
         /// Pressed or Release flag.
         pressed: bool,
     },
@@ -54,6 +105,88 @@ pub enum Input {
         /// The character-code.
         character: char,
     },
+    /// The text being composed with an Input Method changed (an empty text ends the composition).
+    ImePreedit {
+        /// The text being composed.
+        text: String,
+        /// The byte range of the text that is highlighted (an empty range is a caret), if any.
+        cursor_range: Option<Range<usize>>,
+    },
+    /// An Input Method committed text to the Window.
+    ImeCommit {
+        /// The committed text.
+        text: String,
+    },
+    /// Files are being dragged over the Window.
+    FileDropHover {
+        /// The paths of the files.
+        paths: Vec<PathBuf>,
+        /// The position of the cursor, in pixel-coordinates.
+        point: Point,
+    },
+    /// Files were dropped on the Window.
+    FileDrop {
+        /// The paths of the files.
+        paths: Vec<PathBuf>,
+        /// The position of the cursor, in pixel-coordinates.
+        point: Point,
+    },
+    /// Files that were being dragged over the Window left it, or the drag was cancelled.
+    FileDropCancel,
+    /// A Gamepad was connected.
+    GamepadConnected {
+        /// The Gamepad's ID.
+        id: GamepadId,
+    },
+    /// A Gamepad was disconnected.
+    GamepadDisconnected {
+        /// The Gamepad's ID.
+        id: GamepadId,
+    },
+    /// A Gamepad-Button was pressed/released.
+    GamepadButton {
+        /// The Gamepad's ID.
+        id: GamepadId,
+        /// The button.
+        button: GamepadButton,
+        /// Pressed or Released flag.
+        pressed: bool,
+    },
+    /// A Gamepad-Axis was moved.
+    GamepadAxis {
+        /// The Gamepad's ID.
+        id: GamepadId,
+        /// The axis.
+        axis: GamepadAxis,
+        /// The normalized value of the axis.
+        value: f32,
+    },
+}
+
+/// A snapshot of the Keyboard and Mouse, queried right before the Update.\
+/// Unlike the Input Events, it stays correct when the Window loses Focus while Keys are held down.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct KeyboardState {
+    /// The Keys held down.
+    pub keys: Vec<KeyCode>,
+
+    /// The Mouse-Buttons held down.
+    pub buttons: Vec<MouseButton>,
+
+    /// The position of the cursor relative to the Window, in pixel-coordinates (if known).
+    pub cursor: Option<Point>,
+}
+
+impl KeyboardState {
+    /// Returns whether or not the Key is held down.
+    pub fn is_key_down(&self, keycode: KeyCode) -> bool {
+        self.keys.contains(&keycode)
+    }
+
+    /// Returns whether or not the Mouse-Button is held down.
+    pub fn is_button_down(&self, button: MouseButton) -> bool {
+        self.buttons.contains(&button)
+    }
 }
 
 /// A collection of Inputs.
@@ -62,8 +195,11 @@ pub struct Inputs {
     /// The Input Events, in order of occurrence.
     pub events: Vec<Input>,
 
-    /// The XInput Controllers.
-    pub xinput: XInputControllers,
+    /// The state of the Keyboard and Mouse, as of the Update.
+    pub keyboard: KeyboardState,
+
+    /// The connected Gamepads.
+    pub gamepads: Vec<Gamepad>,
 
     /// Information about the connected Screens.
     pub screens: Vec<ScreenInfo>,
@@ -79,6 +215,24 @@ impl Inputs {
     pub fn count(&self, input: Input) -> usize {
         self.events.iter().filter(|event| **event == input).count()
     }
+
+    /// Returns whether or not the Key was pressed/released, regardless of the other details of the `KeyPress`.
+    pub fn contains_key(&self, keycode: KeyCode, pressed: bool) -> bool {
+        self.count_key(keycode, pressed) > 0
+    }
+
+    /// Counts how many times the Key was pressed/released, regardless of the other details of the `KeyPress`.
+    pub fn count_key(&self, keycode: KeyCode, pressed: bool) -> usize {
+        let is_key = |event: &&Input| match **event {
+            Input::KeyPress {
+                keycode: code,
+                pressed: press,
+                ..
+            } => (code == keycode) && (press == pressed),
+            _ => false,
+        };
+        self.events.iter().filter(is_key).count()
+    }
 }
 
 // ================================================================================================================================ //
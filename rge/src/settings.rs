@@ -25,6 +25,9 @@ pub struct GameEngineSettings {
     /// VSYNC toggle (On: No tearing | Off: May tear).
     pub vsync: bool,
 
+    /// Replaces `fps` with the Refresh Rate of the primary Screen (if it is known).
+    pub match_refresh_rate: bool,
+
     /// FULLSCREEN toggle.
     pub fullscreen: bool,
 
@@ -48,6 +51,12 @@ pub struct WindowSettings {
 
     /// Whether or Not the Window is Fullscreen.
     pub fullscreen: bool,
+
+    /// Whether or Not Input Methods may compose text in the Window (see `Input::ImePreedit`).
+    pub ime_allowed: bool,
+
+    /// The area being edited with an Input Method, relative to the Window (its candidate window is placed next to it).
+    pub ime_position: Rect,
 }
 
 // ================================================================================================================================ //
@@ -12,15 +12,19 @@ use super::*;
 
 // ================================================================================================================================ //
 
-#[cfg(target_os = "windows")]
+#[cfg(feature = "headless")]
+/// OS-specific Surface Extension.
+pub type NativeSurfaceExt = ext::HeadlessSurface;
+
+#[cfg(all(target_os = "windows", not(feature = "headless")))]
 /// OS-specific Surface Extension.
 pub type NativeSurfaceExt = khr::Win32Surface;
 
-#[cfg(target_os = "linux")]
+#[cfg(all(target_os = "linux", not(feature = "headless")))]
 /// OS-specific Surface Extension.
 pub type NativeSurfaceExt = khr::XcbSurface;
 
-#[cfg(target_os = "macos")]
+#[cfg(all(target_os = "macos", not(feature = "headless")))]
 /// OS-specific Surface Extension.
 pub type NativeSurfaceExt = ext::MetalSurface;
 
@@ -33,7 +37,8 @@ pub struct InstanceExts {
 
     /// Windows: <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_KHR_win32_surface.html>\
     ///   Linux: <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_KHR_xcb_surface.html>\
-    ///   MacOS: <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_EXT_metal_surface.html>
+    ///   MacOS: <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_EXT_metal_surface.html>\
+    /// Headless: <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_EXT_headless_surface.html>
     pub native_surface: NativeSurfaceExt,
 
     /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_EXT_debug_utils.html>
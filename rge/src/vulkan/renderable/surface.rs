@@ -47,7 +47,7 @@ impl Surface {
 
 // -------------------------------------------------------------------------------------------------------------------------------- //
 
-#[cfg(target_os = "windows")]
+#[cfg(all(target_os = "windows", not(feature = "headless")))]
 impl Surface {
     /// Creates a new Vulkan Surface associated with the given Window.
     pub fn new(context: &VulkanContext, window: WindowHandle) -> Self {
@@ -105,6 +105,48 @@ impl Surface {
 
 // -------------------------------------------------------------------------------------------------------------------------------- //
 
+#[cfg(feature = "headless")]
+impl Surface {
+    /// Size of every Headless Surface, since Headless Windows have no Framebuffer to query.
+    pub const HEADLESS_SIZE: vk::Extent2D = vk::Extent2D {
+        width: 640,
+        height: 480,
+    };
+
+    /// Creates a new Vulkan Surface associated with the given Window.
+    pub fn new(context: &VulkanContext, window: WindowHandle) -> Self {
+        let exts_ref = unsafe { context.exts() };
+        let nat_surface_ptr = NonNull::from(&exts_ref.native_surface);
+        let khr_surface_ptr = NonNull::from(&exts_ref.surface);
+
+        let create_info = vk::HeadlessSurfaceCreateInfoEXT::default();
+
+        let res = unsafe {
+            exts_ref
+                .native_surface
+                .create_headless_surface(&create_info, None)
+        };
+        let handle = res.unwrap();
+
+        let size = Self::HEADLESS_SIZE;
+
+        Self {
+            size,
+            handle,
+            window,
+            khr_surface_ptr,
+            nat_surface_ptr,
+        }
+    }
+
+    /// Returns the Current (non-cached) size of the Window associated with this Surface.
+    pub fn real_size(&self) -> vk::Extent2D {
+        Self::HEADLESS_SIZE
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
 impl Drop for Surface {
     fn drop(&mut self) {
         unsafe { self.surface_ext().destroy_surface(self.handle, None) };
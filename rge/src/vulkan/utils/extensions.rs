@@ -40,11 +40,13 @@ impl Extensions {
     pub fn required_instance() -> Vec<NtString<'static>> {
         vec![
             SURFACE,
-            #[cfg(target_os = "windows")]
+            #[cfg(feature = "headless")]
+            HEADLESS_SURFACE,
+            #[cfg(all(target_os = "windows", not(feature = "headless")))]
             WIN32_SURFACE,
-            #[cfg(target_os = "linux")]
+            #[cfg(all(target_os = "linux", not(feature = "headless")))]
             XCB_SURFACE,
-            #[cfg(target_os = "macos")]
+            #[cfg(all(target_os = "macos", not(feature = "headless")))]
             METAL_SURFACE,
             #[cfg(debug_assertions)]
             DEBUG_UTILS,
@@ -119,6 +121,9 @@ mod names {
     /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_EXT_metal_surface.html>
     pub const METAL_SURFACE: NtString = NtString::from_bytes(b"VK_EXT_metal_surface\0");
 
+    /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_EXT_headless_surface.html>
+    pub const HEADLESS_SURFACE: NtString = NtString::from_bytes(b"VK_EXT_headless_surface\0");
+
     /// <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VK_EXT_debug_utils.html>
     pub const DEBUG_UTILS: NtString = NtString::from_bytes(b"VK_EXT_debug_utils\0");
 
@@ -99,6 +99,7 @@ fn test_main() {
     let settings = GameEngineSettings {
         fps: FPS,
         vsync: VSYNC,
+        match_refresh_rate: false,
         fullscreen: FULLSCREEN,
         width: WINDOW_SIZE.0,
         height: WINDOW_SIZE.1,
@@ -245,56 +246,25 @@ impl Game for App {
 
         // -------------------------------- //
 
-        let spacebar_pressed = engine.inputs.contains(Input::KeyPress {
-            keycode: KC_SPACE,
-            pressed: true,
-        });
+        let spacebar_pressed = engine.inputs.contains_key(KC_SPACE, true);
 
-        let up_presses = engine.inputs.count(Input::KeyPress {
-            keycode: KC_UP,
-            pressed: true,
-        });
+        let up_presses = engine.inputs.count_key(KC_UP, true);
 
-        let down_presses = engine.inputs.count(Input::KeyPress {
-            keycode: KC_DOWN,
-            pressed: true,
-        });
+        let down_presses = engine.inputs.count_key(KC_DOWN, true);
 
-        let backspace_pressed = engine.inputs.contains(Input::KeyPress {
-            keycode: KC_BACKSPACE,
-            pressed: true,
-        });
+        let backspace_pressed = engine.inputs.contains_key(KC_BACKSPACE, true);
 
-        let shift_pressed = engine.inputs.contains(Input::KeyPress {
-            keycode: KC_SHIFT,
-            pressed: true,
-        }) || engine.inputs.contains(Input::KeyPress {
-            keycode: KC_LSHIFT,
-            pressed: true,
-        }) || engine.inputs.contains(Input::KeyPress {
-            keycode: KC_RSHIFT,
-            pressed: true,
-        });
+        let shift_pressed = engine.inputs.contains_key(KC_SHIFT, true)
+            || engine.inputs.contains_key(KC_LSHIFT, true)
+            || engine.inputs.contains_key(KC_RSHIFT, true);
 
-        let delete_pressed = engine.inputs.contains(Input::KeyPress {
-            keycode: KC_DELETE,
-            pressed: true,
-        });
+        let delete_pressed = engine.inputs.contains_key(KC_DELETE, true);
 
-        let escape_pressed = engine.inputs.contains(Input::KeyPress {
-            keycode: KC_ESCAPE,
-            pressed: true,
-        });
+        let escape_pressed = engine.inputs.contains_key(KC_ESCAPE, true);
 
-        let enter_pressed = engine.inputs.contains(Input::KeyPress {
-            keycode: KC_ENTER,
-            pressed: true,
-        });
+        let enter_pressed = engine.inputs.contains_key(KC_ENTER, true);
 
-        let period_pressed = engine.inputs.contains(Input::KeyPress {
-            keycode: KC_PERIOD,
-            pressed: true,
-        });
+        let period_pressed = engine.inputs.contains_key(KC_PERIOD, true);
 
         for input in engine.inputs.events.iter() {
             match input {
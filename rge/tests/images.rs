@@ -46,6 +46,7 @@ fn test_main() {
     let settings = GameEngineSettings {
         fps: 60.0,
         vsync: true,
+        match_refresh_rate: false,
         fullscreen: false,
         width: 256.0 * 3.0,
         height: 256.0 * 3.0,
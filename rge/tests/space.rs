@@ -59,6 +59,7 @@ fn test_main() {
     let settings = GameEngineSettings {
         fps: 60.0,
         vsync: false,
+        match_refresh_rate: false,
         fullscreen: false,
         width: 640.0 * 1.5,
         height: 480.0 * 1.5,
@@ -305,30 +306,35 @@ impl Game for App {
                 Input::KeyPress {
                     keycode: KC_UP,
                     pressed,
+                    ..
                 } => {
                     self.kc_up = *pressed;
                 }
                 Input::KeyPress {
                     keycode: KC_DOWN,
                     pressed,
+                    ..
                 } => {
                     self.kc_down = *pressed;
                 }
                 Input::KeyPress {
                     keycode: KC_LEFT,
                     pressed,
+                    ..
                 } => {
                     self.kc_left = *pressed;
                 }
                 Input::KeyPress {
                     keycode: KC_RIGHT,
                     pressed,
+                    ..
                 } => {
                     self.kc_right = *pressed;
                 }
                 Input::KeyPress {
                     keycode: KC_ESCAPE,
                     pressed: true,
+                    ..
                 } => {
                     self.fullscreen = !self.fullscreen;
                 }
@@ -47,6 +47,7 @@ fn test_main() {
     let settings = GameEngineSettings {
         fps: 2.0,
         vsync: false,
+        match_refresh_rate: false,
         fullscreen: false,
         width: 256.0,
         height: 256.0,
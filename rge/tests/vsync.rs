@@ -41,6 +41,7 @@ fn test_main() {
     let settings = GameEngineSettings {
         fps: 0.0,
         vsync: true,
+        match_refresh_rate: false,
         fullscreen: false,
         width: 256.0,
         height: 256.0,
@@ -0,0 +1,17 @@
+/*
+ *  Crate: Wyn
+ * Module: Cocoa - Cursor
+ */
+
+//! Types for changing the appearance of the Mouse-Cursor.
+//!
+//! Cursors are not implemented yet, so changing the Cursor has no effect.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+pub use crate::common::cursor::*;
+
+// ================================================================================================================================ //
@@ -10,9 +10,11 @@
 #[allow(unused_imports)]
 use super::*;
 
-use super::events::EventHandler;
+use super::clipboard::LocalClipboards;
+use super::events::{Capability, EventHandler};
 use super::inputs::*;
 use super::types::*;
+use super::window::WindowHandle;
 
 use std::sync::{Mutex, MutexGuard, TryLockError};
 
@@ -33,6 +35,9 @@ pub struct EventLoop<'a> {
     /// The Obj-C shared application.
     pub(crate) app: *mut sys::NSApplication,
 
+    /// The in-memory Clipboards, until the Pasteboard is implemented.
+    pub(crate) clipboards: LocalClipboards,
+
     #[allow(unused)]
     /// Lock that guarantees Exclusive-Access to the Event Thread.
     lock: MutexGuard<'a, ()>,
@@ -52,7 +57,7 @@ static EVENTLOOP_MUTEX: Mutex<()> = Mutex::new(());
 /// ## SAFETY
 /// Should only ever be accessed by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.\
 /// Treat as Mutable in `EventLoopGuard` code, and Immutable elsewhere.
-static mut EVENTLOOP: Option<&'static EventLoop<'static>> = None;
+pub(crate) static mut EVENTLOOP: Option<&'static EventLoop<'static>> = None;
 
 /// Drop-Guard for an `EventLoop`.\
 /// Initializes the static `EventLoop` on creation, and Removes it when dropped.
@@ -157,10 +162,13 @@ impl<'a> EventLoop<'a> {
         let delegate = wyn_delegate::new();
         unsafe { sys::ns_application::setDelegate_(app, delegate) };
 
+        let clipboards = LocalClipboards::default();
+
         Self {
             handler,
             delegate,
             app,
+            clipboards,
             lock,
         }
     }
@@ -212,6 +220,30 @@ impl EventLoop<'_> {
         sys::NO != unsafe { sys::ns_application::running(self.app) }
     }
 
+    /// Returns whether or not the optional functionality is supported by this Event Loop.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::WindowRedraw => false,
+            Capability::WindowVisibility => false,
+            Capability::CharacterInput => false,
+            Capability::Gamepads => false,
+            Capability::Clipboard => false,
+            Capability::PrimarySelection => false,
+            Capability::FileDrop => false,
+            Capability::FileDropHover => false,
+            Capability::CursorControl => false,
+            Capability::RawMotion => false,
+            Capability::ScaleFactorChanged => false,
+            Capability::ScreensChanged => false,
+            Capability::InputMethod => false,
+            Capability::VideoModes => false,
+            Capability::InputState => false,
+            Capability::CursorEnterLeave => false,
+            Capability::Touch => false,
+            Capability::Pen => false,
+        }
+    }
+
     /// If an Event/Message Loop is running, then request it to terminate.
     pub fn request_stop(&self) {
         unsafe {
@@ -224,6 +256,33 @@ impl EventLoop<'_> {
 
 // ================================================================================================================================ //
 
+/// Functionality relating to querying the current state of the Keyboard and Mouse.
+///
+/// The Cocoa backend does not query the Keyboard and Mouse yet.
+impl EventLoop<'_> {
+    /// Returns whether or not the Key is currently held down.
+    pub fn key_state(&self, _key: KeyCode) -> bool {
+        false
+    }
+
+    /// Returns every Key that is currently held down.
+    pub fn pressed_keys(&self) -> Vec<KeyCode> {
+        Vec::new()
+    }
+
+    /// Returns every Mouse-Button that is currently held down.
+    pub fn mouse_buttons(&self) -> Vec<MouseButton> {
+        Vec::new()
+    }
+
+    /// Returns the position of the Mouse-Cursor, relative to the Inner-Content of the Window.
+    pub fn cursor_position(&self, _handle: WindowHandle) -> Option<Point> {
+        None
+    }
+}
+
+// ================================================================================================================================ //
+
 #[allow(non_snake_case)]
 /// `WynDelegate : NSObject <NSApplicationDelegate, NSWindowDelegate, NSResponder>`
 pub mod wyn_delegate {
@@ -537,7 +596,7 @@ pub mod wyn_delegate {
             if let Some(events) = unsafe { EVENTLOOP } {
                 let ns_window = unsafe { sys::ns_event::window(event) };
                 let ns_button = unsafe { sys::ns_event::buttonNumber(event) };
-                let button = MouseButton(ns_button);
+                let button = MouseButton::from_native(ns_button);
                 events.handler.button_press(events, ns_window, button, true);
             }
         };
@@ -555,7 +614,7 @@ pub mod wyn_delegate {
             if let Some(events) = unsafe { EVENTLOOP } {
                 let ns_window = unsafe { sys::ns_event::window(event) };
                 let ns_button = unsafe { sys::ns_event::buttonNumber(event) };
-                let button = MouseButton(ns_button);
+                let button = MouseButton::from_native(ns_button);
                 events.handler.button_press(events, ns_window, button, true);
             }
         };
@@ -572,7 +631,7 @@ pub mod wyn_delegate {
             if let Some(events) = unsafe { EVENTLOOP } {
                 let ns_window = unsafe { sys::ns_event::window(event) };
                 let ns_button = unsafe { sys::ns_event::buttonNumber(event) };
-                let button = MouseButton(ns_button);
+                let button = MouseButton::from_native(ns_button);
                 events.handler.button_press(events, ns_window, button, true);
             }
         };
@@ -589,7 +648,7 @@ pub mod wyn_delegate {
             if let Some(events) = unsafe { EVENTLOOP } {
                 let ns_window = unsafe { sys::ns_event::window(event) };
                 let ns_button = unsafe { sys::ns_event::buttonNumber(event) };
-                let button = MouseButton(ns_button);
+                let button = MouseButton::from_native(ns_button);
                 events
                     .handler
                     .button_press(events, ns_window, button, false);
@@ -608,7 +667,7 @@ pub mod wyn_delegate {
             if let Some(events) = unsafe { EVENTLOOP } {
                 let ns_window = unsafe { sys::ns_event::window(event) };
                 let ns_button = unsafe { sys::ns_event::buttonNumber(event) };
-                let button = MouseButton(ns_button);
+                let button = MouseButton::from_native(ns_button);
                 events
                     .handler
                     .button_press(events, ns_window, button, false);
@@ -627,7 +686,7 @@ pub mod wyn_delegate {
             if let Some(events) = unsafe { EVENTLOOP } {
                 let ns_window = unsafe { sys::ns_event::window(event) };
                 let ns_button = unsafe { sys::ns_event::buttonNumber(event) };
-                let button = MouseButton(ns_button);
+                let button = MouseButton::from_native(ns_button);
                 events
                     .handler
                     .button_press(events, ns_window, button, false);
@@ -738,9 +797,8 @@ pub mod wyn_delegate {
         let func = || {
             if let Some(events) = unsafe { EVENTLOOP } {
                 let ns_window = unsafe { sys::ns_event::window(event) };
-                let ns_key = unsafe { sys::ns_event::keyCode(event) };
-                let keycode = KeyCode(ns_key);
-                events.handler.key_press(events, ns_window, keycode, true)
+                let key_event = key_event(event, true);
+                events.handler.key_press(events, ns_window, key_event, true)
             }
         };
 
@@ -755,9 +813,10 @@ pub mod wyn_delegate {
         let func = || {
             if let Some(events) = unsafe { EVENTLOOP } {
                 let ns_window = unsafe { sys::ns_event::window(event) };
-                let ns_key = unsafe { sys::ns_event::keyCode(event) };
-                let keycode = KeyCode(ns_key);
-                events.handler.key_press(events, ns_window, keycode, false)
+                let key_event = key_event(event, false);
+                events
+                    .handler
+                    .key_press(events, ns_window, key_event, false)
             }
         };
 
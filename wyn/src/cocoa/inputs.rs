@@ -10,6 +10,8 @@
 #[allow(unused_imports)]
 use super::*;
 
+use super::event_loop::EventLoop;
+
 // ================================================================================================================================ //
 
 /// Native OS Representation for Mouse Buttons.
@@ -20,14 +22,184 @@ pub type NativeKeyCode = sys::c_ushort;
 
 // -------------------------------------------------------------------------------------------------------------------------------- //
 
-/// A button on a mouse.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
-#[repr(transparent)]
-pub struct MouseButton(pub NativeMouseButton);
+pub use crate::common::inputs::*;
+
+/// Translation table between `MouseButton`s and Native Mouse Buttons.
+#[rustfmt::skip]
+pub(crate) const MOUSE_MAP: &[(MouseButton, NativeMouseButton)] = &[
+    (MouseButton::Left,   0), // NSEvent.buttonNumber
+    (MouseButton::Right,  1),
+    (MouseButton::Middle, 2),
+    (MouseButton::X1,     3),
+    (MouseButton::X2,     4),
+];
+
+// Cocoa only has sided modifiers (e.g. `kVK_Shift`), so the generic `Key::Shift`/`Key::Control`/`Key::Alt` have no Key Code.
+
+/// Translation table between `Key`s and Native Key Codes.
+#[rustfmt::skip]
+pub(crate) const KEY_MAP: &[(Key, NativeKeyCode)] = &[
+    (Key::Backspace,       0x33),  // kVK_Delete
+    (Key::Tab,             0x30),  // kVK_Tab
+    (Key::Clear,           0x47),  // kVK_ANSI_KeypadClear
+    (Key::Enter,           0x24),  // kVK_Return
+    (Key::CapsLock,        0x39),  // kVK_CapsLock
+    (Key::Escape,          0x35),  // kVK_Escape
+    (Key::Space,           0x31),  // kVK_Space
+    (Key::PageUp,          0x74),  // kVK_PageUp
+    (Key::PageDown,        0x79),  // kVK_PageDown
+    (Key::End,             0x77),  // kVK_End
+    (Key::Home,            0x73),  // kVK_Home
+    (Key::Left,            0x7b),  // kVK_LeftArrow
+    (Key::Up,              0x7e),  // kVK_UpArrow
+    (Key::Right,           0x7c),  // kVK_RightArrow
+    (Key::Down,            0x7d),  // kVK_DownArrow
+    (Key::Delete,          0x75),  // kVK_ForwardDelete
+    (Key::Help,            0x72),  // kVK_Help
+    (Key::Num0,            0x1d),  // kVK_ANSI_0
+    (Key::Num1,            0x12),  // kVK_ANSI_1
+    (Key::Num2,            0x13),  // kVK_ANSI_2
+    (Key::Num3,            0x14),  // kVK_ANSI_3
+    (Key::Num4,            0x15),  // kVK_ANSI_4
+    (Key::Num5,            0x17),  // kVK_ANSI_5
+    (Key::Num6,            0x16),  // kVK_ANSI_6
+    (Key::Num7,            0x1a),  // kVK_ANSI_7
+    (Key::Num8,            0x1c),  // kVK_ANSI_8
+    (Key::Num9,            0x19),  // kVK_ANSI_9
+    (Key::A,               0x00),  // kVK_ANSI_A
+    (Key::B,               0x0b),  // kVK_ANSI_B
+    (Key::C,               0x08),  // kVK_ANSI_C
+    (Key::D,               0x02),  // kVK_ANSI_D
+    (Key::E,               0x0e),  // kVK_ANSI_E
+    (Key::F,               0x03),  // kVK_ANSI_F
+    (Key::G,               0x05),  // kVK_ANSI_G
+    (Key::H,               0x04),  // kVK_ANSI_H
+    (Key::I,               0x22),  // kVK_ANSI_I
+    (Key::J,               0x26),  // kVK_ANSI_J
+    (Key::K,               0x28),  // kVK_ANSI_K
+    (Key::L,               0x25),  // kVK_ANSI_L
+    (Key::M,               0x2e),  // kVK_ANSI_M
+    (Key::N,               0x2d),  // kVK_ANSI_N
+    (Key::O,               0x1f),  // kVK_ANSI_O
+    (Key::P,               0x23),  // kVK_ANSI_P
+    (Key::Q,               0x0c),  // kVK_ANSI_Q
+    (Key::R,               0x0f),  // kVK_ANSI_R
+    (Key::S,               0x01),  // kVK_ANSI_S
+    (Key::T,               0x11),  // kVK_ANSI_T
+    (Key::U,               0x20),  // kVK_ANSI_U
+    (Key::V,               0x09),  // kVK_ANSI_V
+    (Key::W,               0x0d),  // kVK_ANSI_W
+    (Key::X,               0x07),  // kVK_ANSI_X
+    (Key::Y,               0x10),  // kVK_ANSI_Y
+    (Key::Z,               0x06),  // kVK_ANSI_Z
+    (Key::LStart,          0x37),  // kVK_Command
+    (Key::RStart,          0x36),  // kVK_RightCommand
+    (Key::Numpad0,         0x52),  // kVK_ANSI_Keypad0
+    (Key::Numpad1,         0x53),  // kVK_ANSI_Keypad1
+    (Key::Numpad2,         0x54),  // kVK_ANSI_Keypad2
+    (Key::Numpad3,         0x55),  // kVK_ANSI_Keypad3
+    (Key::Numpad4,         0x56),  // kVK_ANSI_Keypad4
+    (Key::Numpad5,         0x57),  // kVK_ANSI_Keypad5
+    (Key::Numpad6,         0x58),  // kVK_ANSI_Keypad6
+    (Key::Numpad7,         0x59),  // kVK_ANSI_Keypad7
+    (Key::Numpad8,         0x5b),  // kVK_ANSI_Keypad8
+    (Key::Numpad9,         0x5c),  // kVK_ANSI_Keypad9
+    (Key::Multiply,        0x43),  // kVK_ANSI_KeypadMultiply
+    (Key::Add,             0x45),  // kVK_ANSI_KeypadPlus
+    (Key::Subtract,        0x4e),  // kVK_ANSI_KeypadMinus
+    (Key::Decimal,         0x41),  // kVK_ANSI_KeypadDecimal
+    (Key::Divide,          0x4b),  // kVK_ANSI_KeypadDivide
+    (Key::F1,              0x7a),  // kVK_F1
+    (Key::F2,              0x78),  // kVK_F2
+    (Key::F3,              0x63),  // kVK_F3
+    (Key::F4,              0x76),  // kVK_F4
+    (Key::F5,              0x60),  // kVK_F5
+    (Key::F6,              0x61),  // kVK_F6
+    (Key::F7,              0x62),  // kVK_F7
+    (Key::F8,              0x64),  // kVK_F8
+    (Key::F9,              0x65),  // kVK_F9
+    (Key::F10,             0x6d),  // kVK_F10
+    (Key::F11,             0x67),  // kVK_F11
+    (Key::F12,             0x6f),  // kVK_F12
+    (Key::F13,             0x69),  // kVK_F13
+    (Key::F14,             0x6b),  // kVK_F14
+    (Key::F15,             0x71),  // kVK_F15
+    (Key::F16,             0x6a),  // kVK_F16
+    (Key::F17,             0x40),  // kVK_F17
+    (Key::F18,             0x4f),  // kVK_F18
+    (Key::F19,             0x50),  // kVK_F19
+    (Key::F20,             0x5a),  // kVK_F20
+    (Key::LShift,          0x38),  // kVK_Shift
+    (Key::RShift,          0x3c),  // kVK_RightShift
+    (Key::LControl,        0x3b),  // kVK_Control
+    (Key::RControl,        0x3e),  // kVK_RightControl
+    (Key::LAlt,            0x3a),  // kVK_Option
+    (Key::RAlt,            0x3d),  // kVK_RightOption
+    (Key::VolumeMute,      0x4a),  // kVK_Mute
+    (Key::VolumeDown,      0x49),  // kVK_VolumeDown
+    (Key::VolumeUp,        0x48),  // kVK_VolumeUp
+    (Key::Colon,           0x29),  // kVK_ANSI_Semicolon
+    (Key::Plus,            0x18),  // kVK_ANSI_Equal
+    (Key::Comma,           0x2b),  // kVK_ANSI_Comma
+    (Key::Minus,           0x1b),  // kVK_ANSI_Minus
+    (Key::Period,          0x2f),  // kVK_ANSI_Period
+    (Key::ForwardSlash,    0x2c),  // kVK_ANSI_Slash
+    (Key::Backtick,        0x32),  // kVK_ANSI_Grave
+    (Key::LBracket,        0x21),  // kVK_ANSI_LeftBracket
+    (Key::Backslash,       0x2a),  // kVK_ANSI_Backslash
+    (Key::RBracket,        0x1e),  // kVK_ANSI_RightBracket
+    (Key::Quote,           0x27),  // kVK_ANSI_Quote
+];
+
+// ================================================================================================================================ //
+
+/// Translates a `keyDown`/`keyUp` event into a `KeyEvent`.\
+/// Cocoa Key Codes are already physical, so they double as the Scan-Code.
+pub(crate) fn key_event(event: *mut sys::NSEvent, pressed: bool) -> KeyEvent {
+    let ns_key = unsafe { sys::ns_event::keyCode(event) };
+    let flags = unsafe { sys::ns_event::modifierFlags(event) };
+    let repeat = pressed && sys::NO != unsafe { sys::ns_event::isARepeat(event) };
+
+    let mut modifiers = Modifiers::NONE;
+    modifiers.set(
+        Modifiers::SHIFT,
+        flags.contains(sys::NSEventModifierFlags::NSShiftKeyMask),
+    );
+    modifiers.set(
+        Modifiers::CONTROL,
+        flags.contains(sys::NSEventModifierFlags::NSControlKeyMask),
+    );
+    modifiers.set(
+        Modifiers::ALT,
+        flags.contains(sys::NSEventModifierFlags::NSAlternateKeyMask),
+    );
+    modifiers.set(
+        Modifiers::SUPER,
+        flags.contains(sys::NSEventModifierFlags::NSCommandKeyMask),
+    );
+    modifiers.set(
+        Modifiers::CAPS_LOCK,
+        flags.contains(sys::NSEventModifierFlags::NSAlphaShiftKeyMask),
+    );
+
+    let key = Key::from_native(ns_key);
+    KeyEvent {
+        scancode: ns_key as ScanCode,
+        key,
+        modifiers,
+        repeat,
+        location: KeyLocation::of(key),
+    }
+}
+
+// ================================================================================================================================ //
 
-/// A key on a keyboard.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
-#[repr(transparent)]
-pub struct KeyCode(pub NativeKeyCode);
+impl Gamepad {
+    /// Returns a snapshot of every connected Gamepad.\
+    /// Gamepads are not yet supported on this platform, so the list is always empty.
+    pub fn collect(_events: &EventLoop) -> Vec<Gamepad> {
+        Vec::new()
+    }
+}
 
 // ================================================================================================================================ //
@@ -29,4 +29,8 @@ pub mod types;
 
 pub mod inputs;
 
+pub mod clipboard;
+
+pub mod cursor;
+
 // ================================================================================================================================ //
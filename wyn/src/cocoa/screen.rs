@@ -11,7 +11,9 @@
 use super::*;
 
 use super::event_loop::EventLoop;
-use super::types::Rect;
+use super::types::{Rect, ScaleFactor};
+
+pub use crate::common::screen::*;
 
 // ================================================================================================================================ //
 
@@ -44,6 +46,11 @@ impl Screen {
             String::from_utf8_lossy(slice).to_string()
         }
     }
+
+    /// Returns the Scale Factor of the Screen (`2.0` on Retina Screens).
+    pub fn scale_factor(&self, _events: &EventLoop) -> ScaleFactor {
+        unsafe { sys::ns_screen::backingScaleFactor(self.0) }
+    }
 }
 
 // ================================================================================================================================ //
@@ -10,8 +10,10 @@
 #[allow(unused_imports)]
 use super::*;
 
+use super::cursor::Cursor;
 use super::event_loop::EventLoop;
-use super::types::Rect;
+use super::screen::{Screen, VideoMode};
+use super::types::{Point, Rect, ScaleFactor};
 
 use std::borrow::Cow;
 
@@ -235,6 +237,16 @@ impl Window {
 
 // ================================================================================================================================ //
 
+/// Scale Factor
+impl Window {
+    /// Returns the Scale Factor of the Window, from the Screen it is on.
+    pub fn scale_factor(&self, _events: &EventLoop) -> ScaleFactor {
+        unsafe { sys::ns_window::backingScaleFactor(self.handle()) }
+    }
+}
+
+// ================================================================================================================================ //
+
 /// Minimize - Maximize - Fullscreen - Restore
 impl Window {
     // ---------------------------------------------------------------- //
@@ -268,6 +280,16 @@ impl Window {
         todo!()
     }
 
+    /// Switches the Screen to the Video Mode, and Fullscreens the Window over it. (Exclusive Fullscreen)
+    pub fn fullscreen_exclusive(
+        &self,
+        _events: &EventLoop,
+        _screen: &Screen,
+        _mode: &VideoMode,
+    ) -> bool {
+        todo!()
+    }
+
     /// Returns whether or not the Window is Fullscreened.
     pub fn is_fullscreen(&self, _events: &EventLoop) -> bool {
         todo!()
@@ -290,6 +312,50 @@ impl Window {
 
 // ================================================================================================================================ //
 
+/// Cursor
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Sets the appearance of the Mouse-Cursor while it is over the Window.\
+    /// Has no effect, as Cursors are not implemented yet.
+    pub fn set_cursor(&self, _events: &EventLoop, _cursor: &Cursor) {}
+
+    /// Shows or hides the Mouse-Cursor while it is over the Window.\
+    /// Has no effect, as Cursors are not implemented yet.
+    pub fn set_cursor_visible(&self, _events: &EventLoop, _visible: bool) {}
+
+    // ---------------------------------------------------------------- //
+
+    /// Moves the Mouse-Cursor to the point, relative to the Inner-Content of the Window.\
+    /// Has no effect, as Cursors are not implemented yet.
+    pub fn warp_cursor(&self, _events: &EventLoop, _point: Point) {}
+
+    /// Grabs the Mouse-Cursor, confining it to the Inner-Content of the Window (or releases it).\
+    /// Has no effect, as Cursors are not implemented yet.
+    pub fn grab_cursor(&self, _events: &EventLoop, _grab: bool) {}
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Input Method
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Allows (or disallows) Input Methods to compose text in the Window.\
+    /// Has no effect, as Input Methods are not implemented yet.
+    pub fn set_ime_allowed(&self, _events: &EventLoop, _allowed: bool) {}
+
+    /// Sets the area being edited, relative to the Inner-Content of the Window.\
+    /// Has no effect, as Input Methods are not implemented yet.
+    pub fn set_ime_position(&self, _events: &EventLoop, _rect: Rect) {}
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
 /// The appearance and actions of a Window.
 #[allow(unused)]
 pub struct WindowType {
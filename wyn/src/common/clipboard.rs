@@ -0,0 +1,81 @@
+/*
+ *  Crate: Wyn
+ * Module: Common - Clipboard
+ */
+
+//! Platform-independent types for reading and writing the system Clipboards.
+//!
+//! Clipboard contents are identified by MIME type (e.g. `"image/png"`), and text is always exchanged as UTF-8.\
+//! Each platform translates MIME types to and from its own formats (X11 Targets, or Win32 Clipboard Formats).
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use std::sync::Mutex;
+
+// ================================================================================================================================ //
+
+/// The MIME type of UTF-8 text.
+pub const MIME_TEXT: &str = "text/plain;charset=utf-8";
+
+/// A system Clipboard, which is shared with other applications.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Clipboard {
+    /// The Clipboard used by Cut/Copy/Paste (the `CLIPBOARD` selection on X11).
+    #[default]
+    Standard,
+
+    /// The most recently selected text, which is pasted with the Middle Mouse-Button (the `PRIMARY` selection on X11).\
+    /// Only supported if `EventLoop::supports(Capability::PrimarySelection)`; otherwise, it is always empty.
+    Primary,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl Clipboard {
+    /// The index of the Clipboard, for per-Clipboard tables.
+    #[allow(unused)]
+    pub(crate) fn index(self) -> usize {
+        match self {
+            Self::Standard => 0,
+            Self::Primary => 1,
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Clipboard contents that are kept in memory, for platforms whose Clipboards are not shared with other applications.\
+/// Only the Standard Clipboard is kept; the Primary Selection is always empty.
+#[derive(Default)]
+pub(crate) struct LocalClipboards {
+    /// The MIME type and data on the Standard Clipboard.
+    contents: Mutex<Option<(String, Vec<u8>)>>,
+}
+
+impl LocalClipboards {
+    /// Returns the data on the Clipboard, if it holds the given MIME type.
+    pub(crate) fn get(&self, clipboard: Clipboard, mime: &str) -> Option<Vec<u8>> {
+        if clipboard == Clipboard::Primary {
+            return None;
+        }
+
+        match &*self.contents.lock().unwrap() {
+            Some((kind, data)) if kind == mime => Some(data.clone()),
+            _ => None,
+        }
+    }
+
+    /// Replaces the contents of the Clipboard.
+    pub(crate) fn set(&self, clipboard: Clipboard, mime: &str, data: &[u8]) {
+        if clipboard == Clipboard::Primary {
+            return;
+        }
+
+        *self.contents.lock().unwrap() = Some((mime.to_owned(), data.to_vec()));
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,177 @@
+/*
+ *  Crate: Wyn
+ * Module: Common - Cursor
+ */
+
+//! Platform-independent types for changing the appearance of the Mouse-Cursor.
+//!
+//! Each platform provides the `Window` functions that apply them (along with hiding, warping, and grabbing the Cursor).
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+// ================================================================================================================================ //
+
+/// A standard Cursor shape, provided by the platform's Cursor Theme.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum CursorShape {
+    /// The default Arrow.
+    #[default]
+    Arrow,
+    /// An I-Beam, over selectable text.
+    Text,
+    /// A Crosshair, for precise selection.
+    Crosshair,
+    /// A Pointing Hand, over links.
+    Hand,
+    /// An Hourglass/Spinner, while the application is busy.
+    Wait,
+    /// An Arrow with a Question Mark.
+    Help,
+    /// A Slashed Circle, over something that cannot be interacted with.
+    NotAllowed,
+    /// Four Arrows, for moving an object.
+    Move,
+    /// A Horizontal Double-Arrow, for resizing left/right.
+    ResizeHorizontal,
+    /// A Vertical Double-Arrow, for resizing up/down.
+    ResizeVertical,
+    /// A Diagonal Double-Arrow, for resizing from the top-left/bottom-right corners.
+    ResizeDiagonal,
+    /// An Anti-Diagonal Double-Arrow, for resizing from the top-right/bottom-left corners.
+    ResizeAntiDiagonal,
+}
+
+impl CursorShape {
+    /// Every Cursor shape.
+    pub const ALL: [CursorShape; 12] = [
+        Self::Arrow,
+        Self::Text,
+        Self::Crosshair,
+        Self::Hand,
+        Self::Wait,
+        Self::Help,
+        Self::NotAllowed,
+        Self::Move,
+        Self::ResizeHorizontal,
+        Self::ResizeVertical,
+        Self::ResizeDiagonal,
+        Self::ResizeAntiDiagonal,
+    ];
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// A custom Cursor image, made of RGBA pixels (8 bits per channel, in rows from top to bottom).\
+/// The Hotspot is the pixel that points at the position of the Cursor.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct CursorImage {
+    /// The width of the image, in pixels.
+    width: u32,
+
+    /// The height of the image, in pixels.
+    height: u32,
+
+    /// The horizontal position of the Hotspot, from the left edge.
+    hotspot_x: u32,
+
+    /// The vertical position of the Hotspot, from the top edge.
+    hotspot_y: u32,
+
+    /// The RGBA pixels (`width * height * 4` bytes).
+    rgba: Vec<u8>,
+}
+
+impl CursorImage {
+    /// Constructs a custom Cursor image.\
+    /// Returns `None` if the image is empty, the Hotspot lies outside of it, or there are not exactly `width * height * 4` bytes.
+    pub fn new(width: u32, height: u32, hotspot: (u32, u32), rgba: Vec<u8>) -> Option<Self> {
+        let (hotspot_x, hotspot_y) = hotspot;
+        let len = (width as usize) * (height as usize) * 4;
+
+        let empty = width == 0 || height == 0;
+        let outside = hotspot_x >= width || hotspot_y >= height;
+        if empty || outside || rgba.len() != len {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            hotspot_x,
+            hotspot_y,
+            rgba,
+        })
+    }
+
+    /// The width of the image, in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The height of the image, in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The position of the Hotspot, from the top-left corner.
+    pub fn hotspot(&self) -> (u32, u32) {
+        (self.hotspot_x, self.hotspot_y)
+    }
+
+    /// The RGBA pixels.
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    /// Iterates over the pixels, packed as `0xAARRGGBB` values.\
+    /// X11 expects the color channels to be premultiplied by the alpha channel, while Win32 does not.
+    #[allow(unused)]
+    pub(crate) fn argb(&self, premultiplied: bool) -> impl Iterator<Item = u32> + '_ {
+        self.rgba.chunks_exact(4).map(move |px| {
+            let alpha = u32::from(px[3]);
+            let channel = |value: u8| {
+                if premultiplied {
+                    (u32::from(value) * alpha + 127) / 255
+                } else {
+                    u32::from(value)
+                }
+            };
+            (alpha << 24) | (channel(px[0]) << 16) | (channel(px[1]) << 8) | channel(px[2])
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The appearance of the Mouse-Cursor while it is over a Window.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Cursor {
+    /// A standard shape from the platform's Cursor Theme.
+    Shape(CursorShape),
+
+    /// A custom image.
+    Custom(CursorImage),
+}
+
+impl Default for Cursor {
+    fn default() -> Self {
+        Self::Shape(CursorShape::default())
+    }
+}
+
+impl From<CursorShape> for Cursor {
+    fn from(shape: CursorShape) -> Self {
+        Self::Shape(shape)
+    }
+}
+
+impl From<CursorImage> for Cursor {
+    fn from(image: CursorImage) -> Self {
+        Self::Custom(image)
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,388 @@
+/*
+ *  Crate: Wyn
+ * Module: Common - Events
+ */
+
+//! Provides the platform-independent Event Handler callback functions.
+//!
+//! Every platform reports events through the same trait.\
+//! Callbacks that a platform (or a particular setup of a platform) cannot provide are simply never called,
+//! and can be queried at runtime with `EventLoop::supports`.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::event_loop::EventLoop;
+use super::inputs::{
+    Gamepad, GamepadAxis, GamepadButton, GamepadId, KeyEvent, MouseButton, PenButtons, TouchId,
+    TouchPhase,
+};
+use super::types::{Point, ScaleFactor};
+use super::window::WindowHandle;
+
+use std::ops::Range;
+use std::path::PathBuf;
+
+// ================================================================================================================================ //
+
+#[allow(unused_variables)]
+/// Trait for responding to events.
+///
+/// The `self` parameter must be an immutable reference for the following reasons:
+/// * **Thread-Safety:** The trait object is allowed to be shared across multiple threads.
+/// * **Reentrancy:** Some platforms (e.g. Windows) use re-entrant message callbacks.
+///
+/// Because Rust does not allow multiple `&mut` to the same object simultaneously, passing `&mut self` could lead to Undefined Behavior.
+pub trait EventHandler: Sync {
+    /// The Event Loop is about to start running.
+    fn start(&self, events: &EventLoop) {}
+
+    /// The Event Loop has stopped running.
+    fn stop(&self, events: &EventLoop) {}
+
+    /// A Window was opened, and its handle is available to use.
+    fn window_open(&self, events: &EventLoop, handle: WindowHandle) {}
+
+    /// A Window is about to close, and its handle must be given up.
+    fn window_close(&self, events: &EventLoop, handle: WindowHandle) {}
+
+    /// A Window needs its contents redrawn.\
+    /// Requires `Capability::WindowRedraw`.
+    fn window_redraw(&self, events: &EventLoop, handle: WindowHandle) {}
+
+    /// A Window was repositioned (moved/resized).
+    fn window_reposition(&self, events: &EventLoop, handle: WindowHandle) {}
+
+    /// The Scale Factor of a Window changed (e.g. it was moved to a Screen with a different DPI, or the user changed a setting).\
+    /// Requires `Capability::ScaleFactorChanged`.
+    fn scale_factor_changed(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        scale_factor: ScaleFactor,
+    ) {
+    }
+
+    /// Screens were connected, disconnected, moved, or changed their resolution (e.g. their Video Mode was switched).\
+    /// `Screen::collect` reports the new set of Screens.\
+    /// Requires `Capability::ScreensChanged`.
+    fn screens_changed(&self, events: &EventLoop) {}
+
+    /// A Window was shown or hidden.\
+    /// Requires `Capability::WindowVisibility`.
+    fn window_visibility(&self, events: &EventLoop, handle: WindowHandle, visible: bool) {}
+
+    /// A Window was focused/unfocused.
+    fn window_focus(&self, events: &EventLoop, handle: WindowHandle, focused: bool) {}
+
+    /// A Mouse-Cursor was moved across the Window.
+    fn cursor_move(&self, events: &EventLoop, handle: WindowHandle, point: Point) {}
+
+    /// A Mouse-Cursor entered the Window.\
+    /// Requires `Capability::CursorEnterLeave`.
+    fn cursor_enter(&self, events: &EventLoop, handle: WindowHandle) {}
+
+    /// A Mouse-Cursor left the Window.\
+    /// Requires `Capability::CursorEnterLeave`.
+    fn cursor_leave(&self, events: &EventLoop, handle: WindowHandle) {}
+
+    /// A Mouse was moved by the given (unaccelerated) amounts, regardless of the Cursor's position or Window.\
+    /// Keeps being reported while the Cursor is grabbed or stopped at the edge of the Screen (e.g. for First-Person cameras).\
+    /// Requires `Capability::RawMotion`.
+    fn raw_motion(&self, events: &EventLoop, delta_x: f64, delta_y: f64) {}
+
+    /// A Scroll-Wheel was scrolled horizontally/vertically on the Window.\
+    /// The deltas are in notches of a Scroll-Wheel (positive is up/right), and are fractional for high-resolution Wheels and Touchpads.
+    fn scroll_wheel(&self, events: &EventLoop, handle: WindowHandle, delta_x: f64, delta_y: f64) {}
+
+    /// A Mouse-Button was pressed/released on the Window.
+    fn button_press(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        button: MouseButton,
+        pressed: bool,
+    ) {
+    }
+
+    /// A finger touched the Window, on a Touchscreen.\
+    /// The touch keeps its `id` until `touch_end` (or `touch_cancel`), while other fingers may touch at the same time.\
+    /// Requires `Capability::Touch`.
+    fn touch_begin(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {}
+
+    /// A finger moved across the Window.\
+    /// Requires `Capability::Touch`.
+    fn touch_move(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {}
+
+    /// A finger was lifted off the Window.\
+    /// Requires `Capability::Touch`.
+    fn touch_end(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {}
+
+    /// A touch was taken over by the system (e.g. for a gesture), so whatever it started should be undone.\
+    /// Requires `Capability::Touch`.
+    fn touch_cancel(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {}
+
+    /// A Pen (Stylus) moved over the Window, touched it, or pressed its Buttons.\
+    /// `pressure` ranges from `0.0` to `1.0`, and `tilt` is the horizontal/vertical angle away from upright (in degrees, from `-90.0` to `90.0`).\
+    /// Requires `Capability::Pen`.
+    fn pen(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        point: Point,
+        pressure: f64,
+        tilt: (f64, f64),
+        buttons: PenButtons,
+    ) {
+    }
+
+    /// A Key was pressed/released in the Window.\
+    /// Holding a Key down reports further presses with `event.repeat` set, without releases in between.
+    fn key_press(&self, events: &EventLoop, handle: WindowHandle, event: KeyEvent, pressed: bool) {}
+
+    /// A Character was input in the Window.\
+    /// Requires `Capability::CharacterInput`.
+    fn character_input(&self, events: &EventLoop, handle: WindowHandle, character: char) {}
+
+    /// The text being composed with an Input Method (e.g. for CJK languages) changed.\
+    /// `cursor_range` is the byte range of `text` that the Input Method highlights (an empty range is a caret), if any.\
+    /// An empty `text` means the composition ended, because it was committed or cancelled.\
+    /// Requires `Capability::InputMethod`, and is only reported while the Window allows it (see `Window::set_ime_allowed`).
+    fn ime_preedit(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        text: String,
+        cursor_range: Option<Range<usize>>,
+    ) {
+    }
+
+    /// An Input Method committed text to the Window (e.g. the user confirmed a composition).\
+    /// Committed text is not reported through `character_input` as well.\
+    /// Requires `Capability::InputMethod`, and is only reported while the Window allows it (see `Window::set_ime_allowed`).
+    fn ime_commit(&self, events: &EventLoop, handle: WindowHandle, text: String) {}
+
+    /// Files are being dragged over the Window, and would be dropped at the given point.\
+    /// Reported again whenever the cursor moves, until the files are dropped or the drag leaves the Window.\
+    /// Requires `Capability::FileDropHover`.
+    fn file_drop_hover(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+    }
+
+    /// Files were dropped on the Window at the given point.\
+    /// Requires `Capability::FileDrop`.
+    fn file_drop(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+    }
+
+    /// Files that were being dragged over the Window left it, or the drag was cancelled.\
+    /// Requires `Capability::FileDropHover`.
+    fn file_drop_cancel(&self, events: &EventLoop, handle: WindowHandle) {}
+
+    /// A Gamepad was connected.\
+    /// Requires `Capability::Gamepads`.
+    fn gamepad_connected(&self, events: &EventLoop, id: GamepadId) {}
+
+    /// A Gamepad was disconnected.\
+    /// Requires `Capability::Gamepads`.
+    fn gamepad_disconnected(&self, events: &EventLoop, id: GamepadId) {}
+
+    /// A Gamepad Button was pressed/released.\
+    /// Requires `Capability::Gamepads`.
+    fn gamepad_button(
+        &self,
+        events: &EventLoop,
+        id: GamepadId,
+        button: GamepadButton,
+        pressed: bool,
+    ) {
+    }
+
+    /// A Gamepad Axis was moved.\
+    /// Requires `Capability::Gamepads`.
+    fn gamepad_axis(&self, events: &EventLoop, id: GamepadId, axis: GamepadAxis, value: f32) {}
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Optional functionality, which is not available on every platform (or with every setup of a platform).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Capability {
+    /// `EventHandler::window_redraw` is reported.
+    WindowRedraw,
+
+    /// `EventHandler::window_visibility` is reported.
+    WindowVisibility,
+
+    /// `EventHandler::character_input` is reported.
+    CharacterInput,
+
+    /// `Gamepad::collect` can report connected Gamepads, and the `EventHandler::gamepad_*` callbacks are reported.
+    Gamepads,
+
+    /// `Clipboard::Standard` is shared with other applications.
+    Clipboard,
+
+    /// `Clipboard::Primary` is shared with other applications.
+    PrimarySelection,
+
+    /// `EventHandler::file_drop` is reported.
+    FileDrop,
+
+    /// `EventHandler::file_drop_hover` and `EventHandler::file_drop_cancel` are reported.
+    FileDropHover,
+
+    /// `Window::set_cursor`, `Window::set_cursor_visible`, `Window::warp_cursor`, and `Window::grab_cursor` take effect.
+    CursorControl,
+
+    /// `EventHandler::raw_motion` is reported.
+    RawMotion,
+
+    /// `EventHandler::scale_factor_changed` is reported.
+    ScaleFactorChanged,
+
+    /// `EventHandler::screens_changed` is reported.
+    ScreensChanged,
+
+    /// `EventHandler::ime_preedit` and `EventHandler::ime_commit` are reported,
+    /// and `Window::set_ime_position` moves the candidate window of the Input Method.
+    InputMethod,
+
+    /// `Screen::video_modes` reports the Video Modes of Screens, and `Window::fullscreen_exclusive` switches between them.
+    VideoModes,
+
+    /// `EventLoop::key_state`, `EventLoop::pressed_keys`, `EventLoop::mouse_buttons`, and `EventLoop::cursor_position`
+    /// query the current state of the Keyboard and Mouse.
+    InputState,
+
+    /// `EventHandler::cursor_enter` and `EventHandler::cursor_leave` are reported.
+    CursorEnterLeave,
+
+    /// `EventHandler::touch_begin`, `EventHandler::touch_move`, `EventHandler::touch_end`, and `EventHandler::touch_cancel`
+    /// are reported from Touchscreens.
+    Touch,
+
+    /// `EventHandler::pen` is reported from Pens (Styluses).
+    Pen,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// A change in the set of connected Gamepads, or in the state of one of them.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum GamepadUpdate {
+    /// A Gamepad was connected, with the given initial state.
+    Connected(Gamepad),
+
+    /// A Gamepad was disconnected.
+    Disconnected(GamepadId),
+
+    /// The state of a Gamepad changed from the first snapshot to the second.
+    Changed(Gamepad, Gamepad),
+}
+
+impl GamepadUpdate {
+    /// Compares two lists of Gamepad snapshots, and returns the changes between them (in order).
+    pub(crate) fn diff(old: &[Gamepad], new: &[Gamepad]) -> Vec<Self> {
+        let find = |list: &[Gamepad], id| list.iter().find(|gamepad| gamepad.id() == id).copied();
+
+        let removed = old
+            .iter()
+            .filter(|gamepad| find(new, gamepad.id()).is_none())
+            .map(|gamepad| Self::Disconnected(gamepad.id()));
+
+        // Only the Packet-Number needs to be compared, since it changes whenever the state changes.
+        let updated = new
+            .iter()
+            .filter_map(|&gamepad| match find(old, gamepad.id()) {
+                None => Some(Self::Connected(gamepad)),
+                Some(prev) if prev.packet() != gamepad.packet() => {
+                    Some(Self::Changed(prev, gamepad))
+                }
+                Some(_) => None,
+            });
+
+        removed.chain(updated).collect()
+    }
+
+    /// Reports the change to the Event Handler.\
+    /// Must not be called while holding any lock that the Event Handler might need (e.g. through `Gamepad::collect`).
+    pub(crate) fn dispatch(self, events: &EventLoop) {
+        let handler = events.handler;
+
+        match self {
+            Self::Connected(gamepad) => handler.gamepad_connected(events, gamepad.id()),
+            Self::Disconnected(id) => handler.gamepad_disconnected(events, id),
+            Self::Changed(prev, next) => {
+                let id = next.id();
+
+                for button in GamepadButton::ALL {
+                    let pressed = next.button(button);
+                    if prev.button(button) != pressed {
+                        handler.gamepad_button(events, id, button, pressed);
+                    }
+                }
+
+                for axis in GamepadAxis::ALL {
+                    let value = next.axis(axis);
+                    if prev.axis(axis) != value {
+                        handler.gamepad_axis(events, id, axis, value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// A single touch on a Window, read from the native events.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TouchUpdate {
+    /// The Window that was touched.
+    pub handle: WindowHandle,
+
+    /// The phase of the touch, which selects the callback that reports it.
+    pub phase: TouchPhase,
+
+    /// The ID of the touch.
+    pub id: TouchId,
+
+    /// The position of the finger.
+    pub point: Point,
+}
+
+impl TouchUpdate {
+    /// Reports the touch to the `EventHandler::touch_*` callback of its phase.
+    pub(crate) fn dispatch(self, events: &EventLoop) {
+        let handler = events.handler;
+        let Self {
+            handle,
+            phase,
+            id,
+            point,
+        } = self;
+
+        match phase {
+            TouchPhase::Begin => handler.touch_begin(events, handle, id, point),
+            TouchPhase::Move => handler.touch_move(events, handle, id, point),
+            TouchPhase::End => handler.touch_end(events, handle, id, point),
+            TouchPhase::Cancel => handler.touch_cancel(events, handle, id, point),
+        }
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,1057 @@
+/*
+ *  Crate: Wyn
+ * Module: Common - Inputs
+ */
+
+//! Platform-independent types for handling User-Input, such as through Mice/Pointers, Keyboards, Touchscreens and Pens.
+//!
+//! Each platform provides the tables that translate between these types and its native codes.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use crate::inputs::{NativeKeyCode, NativeMouseButton, KEY_MAP, MOUSE_MAP};
+
+// ================================================================================================================================ //
+
+/// A button on a mouse.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MouseButton {
+    /// The Left (Primary) Mouse-Button.
+    Left,
+    /// The Middle Mouse-Button (usually the Scroll-Wheel).
+    Middle,
+    /// The Right (Secondary) Mouse-Button.
+    Right,
+    /// The first side Mouse-Button (usually "Back").
+    X1,
+    /// The second side Mouse-Button (usually "Forward").
+    X2,
+    /// Any other Mouse-Button, identified by its native code.
+    Other(NativeMouseButton),
+}
+
+// The Key variants do not have doc comments, as the name itself is the documentation.
+
+/// A key on a keyboard.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[allow(missing_docs)]
+#[allow(clippy::missing_docs_in_private_items)]
+pub enum Key {
+    Backspace,
+    Tab,
+    Clear,
+    Enter,
+    Shift,
+    Control,
+    Alt,
+    Pause,
+    CapsLock,
+    Escape,
+    Space,
+    PageUp,
+    PageDown,
+    End,
+    Home,
+    Left,
+    Up,
+    Right,
+    Down,
+    PrintScreen,
+    Insert,
+    Delete,
+    Help,
+    Num0,
+    Num1,
+    Num2,
+    Num3,
+    Num4,
+    Num5,
+    Num6,
+    Num7,
+    Num8,
+    Num9,
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    LStart,
+    RStart,
+    Menu,
+    Numpad0,
+    Numpad1,
+    Numpad2,
+    Numpad3,
+    Numpad4,
+    Numpad5,
+    Numpad6,
+    Numpad7,
+    Numpad8,
+    Numpad9,
+    Multiply,
+    Add,
+    Separator,
+    Subtract,
+    Decimal,
+    Divide,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+    NumLock,
+    ScrollLock,
+    LShift,
+    RShift,
+    LControl,
+    RControl,
+    LAlt,
+    RAlt,
+    VolumeMute,
+    VolumeDown,
+    VolumeUp,
+    Colon,
+    Plus,
+    Comma,
+    Minus,
+    Period,
+    ForwardSlash,
+    Backtick,
+    LBracket,
+    Backslash,
+    RBracket,
+    Quote,
+    /// Any other Key, identified by its native code.
+    Other(NativeKeyCode),
+}
+
+/// Alias for `Key`, matching the naming of the `KC_*` constants.
+pub type KeyCode = Key;
+
+pub use kc_constants::*;
+pub use mb_constants::*;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl MouseButton {
+    /// Converts a native OS Mouse-Button into a `MouseButton`.
+    pub fn from_native(native: NativeMouseButton) -> Self {
+        MOUSE_MAP
+            .iter()
+            .find(|(_, code)| *code == native)
+            .map(|(button, _)| *button)
+            .unwrap_or(Self::Other(native))
+    }
+
+    /// Returns the native OS representation of the Mouse-Button.\
+    /// Returns `None` if the platform has no equivalent for this button.
+    pub fn native(self) -> Option<NativeMouseButton> {
+        match self {
+            Self::Other(native) => Some(native),
+            button => MOUSE_MAP
+                .iter()
+                .find(|(other, _)| *other == button)
+                .map(|(_, code)| *code),
+        }
+    }
+}
+
+impl Key {
+    /// Every `Key` (except `Key::Other`), in declaration order.
+    pub const ALL: [Key; 124] = [
+        Key::Backspace,
+        Key::Tab,
+        Key::Clear,
+        Key::Enter,
+        Key::Shift,
+        Key::Control,
+        Key::Alt,
+        Key::Pause,
+        Key::CapsLock,
+        Key::Escape,
+        Key::Space,
+        Key::PageUp,
+        Key::PageDown,
+        Key::End,
+        Key::Home,
+        Key::Left,
+        Key::Up,
+        Key::Right,
+        Key::Down,
+        Key::PrintScreen,
+        Key::Insert,
+        Key::Delete,
+        Key::Help,
+        Key::Num0,
+        Key::Num1,
+        Key::Num2,
+        Key::Num3,
+        Key::Num4,
+        Key::Num5,
+        Key::Num6,
+        Key::Num7,
+        Key::Num8,
+        Key::Num9,
+        Key::A,
+        Key::B,
+        Key::C,
+        Key::D,
+        Key::E,
+        Key::F,
+        Key::G,
+        Key::H,
+        Key::I,
+        Key::J,
+        Key::K,
+        Key::L,
+        Key::M,
+        Key::N,
+        Key::O,
+        Key::P,
+        Key::Q,
+        Key::R,
+        Key::S,
+        Key::T,
+        Key::U,
+        Key::V,
+        Key::W,
+        Key::X,
+        Key::Y,
+        Key::Z,
+        Key::LStart,
+        Key::RStart,
+        Key::Menu,
+        Key::Numpad0,
+        Key::Numpad1,
+        Key::Numpad2,
+        Key::Numpad3,
+        Key::Numpad4,
+        Key::Numpad5,
+        Key::Numpad6,
+        Key::Numpad7,
+        Key::Numpad8,
+        Key::Numpad9,
+        Key::Multiply,
+        Key::Add,
+        Key::Separator,
+        Key::Subtract,
+        Key::Decimal,
+        Key::Divide,
+        Key::F1,
+        Key::F2,
+        Key::F3,
+        Key::F4,
+        Key::F5,
+        Key::F6,
+        Key::F7,
+        Key::F8,
+        Key::F9,
+        Key::F10,
+        Key::F11,
+        Key::F12,
+        Key::F13,
+        Key::F14,
+        Key::F15,
+        Key::F16,
+        Key::F17,
+        Key::F18,
+        Key::F19,
+        Key::F20,
+        Key::F21,
+        Key::F22,
+        Key::F23,
+        Key::F24,
+        Key::NumLock,
+        Key::ScrollLock,
+        Key::LShift,
+        Key::RShift,
+        Key::LControl,
+        Key::RControl,
+        Key::LAlt,
+        Key::RAlt,
+        Key::VolumeMute,
+        Key::VolumeDown,
+        Key::VolumeUp,
+        Key::Colon,
+        Key::Plus,
+        Key::Comma,
+        Key::Minus,
+        Key::Period,
+        Key::ForwardSlash,
+        Key::Backtick,
+        Key::LBracket,
+        Key::Backslash,
+        Key::RBracket,
+        Key::Quote,
+    ];
+
+    /// Converts a native OS Key-Code into a `Key`.
+    pub fn from_native(native: NativeKeyCode) -> Self {
+        KEY_MAP
+            .iter()
+            .find(|(_, code)| *code == native)
+            .map(|(key, _)| *key)
+            .unwrap_or(Self::Other(native))
+    }
+
+    /// Returns the native OS representation of the Key.\
+    /// Returns `None` if the platform has no equivalent for this key.
+    pub fn native(self) -> Option<NativeKeyCode> {
+        match self {
+            Self::Other(native) => Some(native),
+            key => KEY_MAP
+                .iter()
+                .find(|(other, _)| *other == key)
+                .map(|(_, code)| *code),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+// The Mouse-Button and Key-Code constants do not have doc comments, as the name itself is the documentation.
+
+#[rustfmt::skip]
+#[allow(missing_docs)]
+#[allow(clippy::missing_docs_in_private_items)]
+mod mb_constants {
+    use super::MouseButton;
+
+    pub const MB_LEFT   : MouseButton = MouseButton::Left;
+    pub const MB_MIDDLE : MouseButton = MouseButton::Middle;
+    pub const MB_RIGHT  : MouseButton = MouseButton::Right;
+    pub const MB_X1     : MouseButton = MouseButton::X1;
+    pub const MB_X2     : MouseButton = MouseButton::X2;
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[rustfmt::skip]
+#[allow(missing_docs)]
+#[allow(clippy::missing_docs_in_private_items)]
+mod kc_constants {
+    use super::Key;
+
+    pub const KC_BACKSPACE    : Key = Key::Backspace;
+    pub const KC_TAB          : Key = Key::Tab;
+    pub const KC_CLEAR        : Key = Key::Clear;
+    pub const KC_ENTER        : Key = Key::Enter;
+    pub const KC_SHIFT        : Key = Key::Shift;
+    pub const KC_CONTROL      : Key = Key::Control;
+    pub const KC_ALT          : Key = Key::Alt;
+    pub const KC_PAUSE        : Key = Key::Pause;
+    pub const KC_CAPSLOCK     : Key = Key::CapsLock;
+    pub const KC_ESCAPE       : Key = Key::Escape;
+    pub const KC_SPACE        : Key = Key::Space;
+    pub const KC_PAGEUP       : Key = Key::PageUp;
+    pub const KC_PAGEDOWN     : Key = Key::PageDown;
+    pub const KC_END          : Key = Key::End;
+    pub const KC_HOME         : Key = Key::Home;
+    pub const KC_LEFT         : Key = Key::Left;
+    pub const KC_UP           : Key = Key::Up;
+    pub const KC_RIGHT        : Key = Key::Right;
+    pub const KC_DOWN         : Key = Key::Down;
+    pub const KC_PRINTSCREEN  : Key = Key::PrintScreen;
+    pub const KC_INSERT       : Key = Key::Insert;
+    pub const KC_DELETE       : Key = Key::Delete;
+    pub const KC_HELP         : Key = Key::Help;
+    pub const KC_NUM0         : Key = Key::Num0;
+    pub const KC_NUM1         : Key = Key::Num1;
+    pub const KC_NUM2         : Key = Key::Num2;
+    pub const KC_NUM3         : Key = Key::Num3;
+    pub const KC_NUM4         : Key = Key::Num4;
+    pub const KC_NUM5         : Key = Key::Num5;
+    pub const KC_NUM6         : Key = Key::Num6;
+    pub const KC_NUM7         : Key = Key::Num7;
+    pub const KC_NUM8         : Key = Key::Num8;
+    pub const KC_NUM9         : Key = Key::Num9;
+    pub const KC_A            : Key = Key::A;
+    pub const KC_B            : Key = Key::B;
+    pub const KC_C            : Key = Key::C;
+    pub const KC_D            : Key = Key::D;
+    pub const KC_E            : Key = Key::E;
+    pub const KC_F            : Key = Key::F;
+    pub const KC_G            : Key = Key::G;
+    pub const KC_H            : Key = Key::H;
+    pub const KC_I            : Key = Key::I;
+    pub const KC_J            : Key = Key::J;
+    pub const KC_K            : Key = Key::K;
+    pub const KC_L            : Key = Key::L;
+    pub const KC_M            : Key = Key::M;
+    pub const KC_N            : Key = Key::N;
+    pub const KC_O            : Key = Key::O;
+    pub const KC_P            : Key = Key::P;
+    pub const KC_Q            : Key = Key::Q;
+    pub const KC_R            : Key = Key::R;
+    pub const KC_S            : Key = Key::S;
+    pub const KC_T            : Key = Key::T;
+    pub const KC_U            : Key = Key::U;
+    pub const KC_V            : Key = Key::V;
+    pub const KC_W            : Key = Key::W;
+    pub const KC_X            : Key = Key::X;
+    pub const KC_Y            : Key = Key::Y;
+    pub const KC_Z            : Key = Key::Z;
+    pub const KC_LSTART       : Key = Key::LStart;
+    pub const KC_RSTART       : Key = Key::RStart;
+    pub const KC_MENU         : Key = Key::Menu;
+    pub const KC_NUMPAD0      : Key = Key::Numpad0;
+    pub const KC_NUMPAD1      : Key = Key::Numpad1;
+    pub const KC_NUMPAD2      : Key = Key::Numpad2;
+    pub const KC_NUMPAD3      : Key = Key::Numpad3;
+    pub const KC_NUMPAD4      : Key = Key::Numpad4;
+    pub const KC_NUMPAD5      : Key = Key::Numpad5;
+    pub const KC_NUMPAD6      : Key = Key::Numpad6;
+    pub const KC_NUMPAD7      : Key = Key::Numpad7;
+    pub const KC_NUMPAD8      : Key = Key::Numpad8;
+    pub const KC_NUMPAD9      : Key = Key::Numpad9;
+    pub const KC_MULTIPLY     : Key = Key::Multiply;
+    pub const KC_ADD          : Key = Key::Add;
+    pub const KC_SEPARATOR    : Key = Key::Separator;
+    pub const KC_SUBTRACT     : Key = Key::Subtract;
+    pub const KC_DECIMAL      : Key = Key::Decimal;
+    pub const KC_DIVIDE       : Key = Key::Divide;
+    pub const KC_F1           : Key = Key::F1;
+    pub const KC_F2           : Key = Key::F2;
+    pub const KC_F3           : Key = Key::F3;
+    pub const KC_F4           : Key = Key::F4;
+    pub const KC_F5           : Key = Key::F5;
+    pub const KC_F6           : Key = Key::F6;
+    pub const KC_F7           : Key = Key::F7;
+    pub const KC_F8           : Key = Key::F8;
+    pub const KC_F9           : Key = Key::F9;
+    pub const KC_F10          : Key = Key::F10;
+    pub const KC_F11          : Key = Key::F11;
+    pub const KC_F12          : Key = Key::F12;
+    pub const KC_F13          : Key = Key::F13;
+    pub const KC_F14          : Key = Key::F14;
+    pub const KC_F15          : Key = Key::F15;
+    pub const KC_F16          : Key = Key::F16;
+    pub const KC_F17          : Key = Key::F17;
+    pub const KC_F18          : Key = Key::F18;
+    pub const KC_F19          : Key = Key::F19;
+    pub const KC_F20          : Key = Key::F20;
+    pub const KC_F21          : Key = Key::F21;
+    pub const KC_F22          : Key = Key::F22;
+    pub const KC_F23          : Key = Key::F23;
+    pub const KC_F24          : Key = Key::F24;
+    pub const KC_NUMLOCK      : Key = Key::NumLock;
+    pub const KC_SCROLLLOCK   : Key = Key::ScrollLock;
+    pub const KC_LSHIFT       : Key = Key::LShift;
+    pub const KC_RSHIFT       : Key = Key::RShift;
+    pub const KC_LCONTROL     : Key = Key::LControl;
+    pub const KC_RCONTROL     : Key = Key::RControl;
+    pub const KC_LALT         : Key = Key::LAlt;
+    pub const KC_RALT         : Key = Key::RAlt;
+    pub const KC_VOLUMEMUTE   : Key = Key::VolumeMute;
+    pub const KC_VOLUMEDOWN   : Key = Key::VolumeDown;
+    pub const KC_VOLUMEUP     : Key = Key::VolumeUp;
+    pub const KC_COLON        : Key = Key::Colon;
+    pub const KC_PLUS         : Key = Key::Plus;
+    pub const KC_COMMA        : Key = Key::Comma;
+    pub const KC_MINUS        : Key = Key::Minus;
+    pub const KC_PERIOD       : Key = Key::Period;
+    pub const KC_FORWARDSLASH : Key = Key::ForwardSlash;
+    pub const KC_BACKTICK     : Key = Key::Backtick;
+    pub const KC_LBRACKET     : Key = Key::LBracket;
+    pub const KC_BACKSLASH    : Key = Key::Backslash;
+    pub const KC_RBRACKET     : Key = Key::RBracket;
+    pub const KC_QUOTE        : Key = Key::Quote;
+}
+
+// ================================================================================================================================ //
+
+/// The physical key on a keyboard, identified by its native Scan-Code (independent of the keyboard layout).\
+/// These are X11/XKB Keycodes on Linux, and Set-1 Scan-Codes on Windows (with `0xE000` added for Extended keys).
+pub type ScanCode = u32;
+
+/// Where a Key is on the keyboard, for Keys that appear more than once (e.g. Shift, or the digits on the Numpad).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum KeyLocation {
+    /// The Key only appears once (or the platform cannot tell).
+    #[default]
+    Standard,
+    /// The Key on the left side of the keyboard (e.g. Left-Shift).
+    Left,
+    /// The Key on the right side of the keyboard (e.g. Right-Shift).
+    Right,
+    /// The Key on the Numpad (e.g. Numpad-Enter).
+    Numpad,
+}
+
+/// A set of Modifier Keys that are held, and of Lock Keys that are toggled on.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers(u8);
+
+/// A Key press or release, as reported by `EventHandler::key_press`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct KeyEvent {
+    /// The physical key that was pressed.
+    pub scancode: ScanCode,
+
+    /// The key in the current keyboard layout.
+    pub key: Key,
+
+    /// The Modifiers at the time of the event, including the key itself if it is a Modifier Key.
+    pub modifiers: Modifiers,
+
+    /// Whether or not the press was generated by Auto-Repeat, while the key is held down.
+    pub repeat: bool,
+
+    /// Where the key is on the keyboard.
+    pub location: KeyLocation,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl KeyLocation {
+    /// Every `KeyLocation`, in declaration order.
+    pub const ALL: [KeyLocation; 4] = [Self::Standard, Self::Left, Self::Right, Self::Numpad];
+
+    /// Returns the location that is implied by the Key itself (e.g. `Left` for `Key::LShift`).
+    pub fn of(key: Key) -> Self {
+        match key {
+            Key::LShift | Key::LControl | Key::LAlt | Key::LStart => Self::Left,
+            Key::RShift | Key::RControl | Key::RAlt | Key::RStart => Self::Right,
+
+            Key::Numpad0
+            | Key::Numpad1
+            | Key::Numpad2
+            | Key::Numpad3
+            | Key::Numpad4
+            | Key::Numpad5
+            | Key::Numpad6
+            | Key::Numpad7
+            | Key::Numpad8
+            | Key::Numpad9
+            | Key::Multiply
+            | Key::Add
+            | Key::Separator
+            | Key::Subtract
+            | Key::Decimal
+            | Key::Divide => Self::Numpad,
+
+            _ => Self::Standard,
+        }
+    }
+}
+
+impl Modifiers {
+    /// No Modifiers.
+    pub const NONE: Self = Self(0);
+    /// Either Shift key is held.
+    pub const SHIFT: Self = Self(1 << 0);
+    /// Either Control key is held.
+    pub const CONTROL: Self = Self(1 << 1);
+    /// Either Alt key is held.
+    pub const ALT: Self = Self(1 << 2);
+    /// Either Super (Start/Windows/Command) key is held.
+    pub const SUPER: Self = Self(1 << 3);
+    /// Caps-Lock is toggled on.
+    pub const CAPS_LOCK: Self = Self(1 << 4);
+    /// Num-Lock is toggled on.
+    pub const NUM_LOCK: Self = Self(1 << 5);
+
+    /// Every single Modifier, along with its name.
+    pub const NAMED: [(&'static str, Self); 6] = [
+        ("SHIFT", Self::SHIFT),
+        ("CONTROL", Self::CONTROL),
+        ("ALT", Self::ALT),
+        ("SUPER", Self::SUPER),
+        ("CAPS_LOCK", Self::CAPS_LOCK),
+        ("NUM_LOCK", Self::NUM_LOCK),
+    ];
+
+    /// Returns whether or not no Modifiers are in the set.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the set with every Modifier in either set (like `|`, but usable in constants).
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns whether or not every Modifier in `other` is in the set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Adds (or removes) every Modifier in `other` to the set.
+    pub fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.0 |= other.0;
+        } else {
+            self.0 &= !other.0;
+        }
+    }
+
+    /// Returns the Modifier that the Key itself holds (if it is a Modifier Key).\
+    /// Lock Keys are not included, since pressing them only toggles their state.
+    pub fn of(key: Key) -> Self {
+        match key {
+            Key::Shift | Key::LShift | Key::RShift => Self::SHIFT,
+            Key::Control | Key::LControl | Key::RControl => Self::CONTROL,
+            Key::Alt | Key::LAlt | Key::RAlt => Self::ALT,
+            Key::LStart | Key::RStart => Self::SUPER,
+            _ => Self::NONE,
+        }
+    }
+}
+
+impl std::ops::BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl std::ops::BitOrAssign for Modifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl Debug for Modifiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names = Self::NAMED
+            .iter()
+            .filter(|(_, flag)| self.contains(*flag))
+            .map(|(name, _)| *name);
+
+        write!(f, "Modifiers(")?;
+        if let Some(name) = names.next() {
+            write!(f, "{name}")?;
+            for name in names {
+                write!(f, " | {name}")?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+impl KeyEvent {
+    /// Creates the event of a plain Key press, without a Scan-Code, Modifiers or Auto-Repeat.\
+    /// The location is the one implied by the Key itself.
+    pub fn new(key: Key) -> Self {
+        Self {
+            scancode: 0,
+            key,
+            modifiers: Modifiers::NONE,
+            repeat: false,
+            location: KeyLocation::of(key),
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Identifies a touch (a single finger) on a Touchscreen, from its `EventHandler::touch_begin` until it ends.\
+/// Identifiers may be reused by later touches.
+pub type TouchId = u32;
+
+/// The phase of a touch, matching the `EventHandler::touch_*` callback that reported it.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TouchPhase {
+    /// The finger touched the screen (`EventHandler::touch_begin`).
+    Begin,
+    /// The finger moved across the screen (`EventHandler::touch_move`).
+    Move,
+    /// The finger was lifted off the screen (`EventHandler::touch_end`).
+    End,
+    /// The touch was taken over by the system, e.g. for a gesture (`EventHandler::touch_cancel`).
+    Cancel,
+}
+
+/// A set of the Buttons of a Pen (Stylus) that are pressed, including its tip.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PenButtons(u8);
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TouchPhase {
+    /// Every `TouchPhase`, in declaration order.
+    pub const ALL: [TouchPhase; 4] = [Self::Begin, Self::Move, Self::End, Self::Cancel];
+}
+
+impl PenButtons {
+    /// No Buttons.
+    pub const NONE: Self = Self(0);
+    /// The tip of the Pen touches the surface.
+    pub const TIP: Self = Self(1 << 0);
+    /// The Barrel-Button (on the side of the Pen) is pressed.
+    pub const BARREL: Self = Self(1 << 1);
+    /// The Pen is used as an Eraser (e.g. it is held upside-down).
+    pub const ERASER: Self = Self(1 << 2);
+
+    /// Every single Button, along with its name.
+    pub const NAMED: [(&'static str, Self); 3] = [
+        ("TIP", Self::TIP),
+        ("BARREL", Self::BARREL),
+        ("ERASER", Self::ERASER),
+    ];
+
+    /// Returns whether or not no Buttons are in the set.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the set with every Button in either set (like `|`, but usable in constants).
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns whether or not every Button in `other` is in the set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Adds (or removes) every Button in `other` to the set.
+    pub fn set(&mut self, other: Self, value: bool) {
+        if value {
+            self.0 |= other.0;
+        } else {
+            self.0 &= !other.0;
+        }
+    }
+}
+
+impl std::ops::BitOr for PenButtons {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl std::ops::BitOrAssign for PenButtons {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl Debug for PenButtons {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names = Self::NAMED
+            .iter()
+            .filter(|(_, flag)| self.contains(*flag))
+            .map(|(name, _)| *name);
+
+        write!(f, "PenButtons(")?;
+        if let Some(name) = names.next() {
+            write!(f, "{name}")?;
+            for name in names {
+                write!(f, " | {name}")?;
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Identifies a Gamepad for as long as it stays connected.
+pub type GamepadId = u32;
+
+/// A button on a Gamepad, using the standard (Xbox-style) layout.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GamepadButton {
+    /// The bottom face button.
+    A,
+    /// The right face button.
+    B,
+    /// The left face button.
+    X,
+    /// The top face button.
+    Y,
+    /// The left center button (also known as SELECT).
+    Back,
+    /// The right center button.
+    Start,
+    /// The D-Pad Up button.
+    DpadUp,
+    /// The D-Pad Down button.
+    DpadDown,
+    /// The D-Pad Left button.
+    DpadLeft,
+    /// The D-Pad Right button.
+    DpadRight,
+    /// The Left Bumper.
+    BumperL,
+    /// The Right Bumper.
+    BumperR,
+    /// The Left Thumbstick button.
+    ThumbL,
+    /// The Right Thumbstick button.
+    ThumbR,
+}
+
+/// An analog axis on a Gamepad, using the standard (Xbox-style) layout.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GamepadAxis {
+    /// The horizontal [-1..1] axis of the Left Stick (right is positive).
+    StickLX,
+    /// The vertical [-1..1] axis of the Left Stick (up is positive).
+    StickLY,
+    /// The horizontal [-1..1] axis of the Right Stick (right is positive).
+    StickRX,
+    /// The vertical [-1..1] axis of the Right Stick (up is positive).
+    StickRY,
+    /// The [0..1] axis of the Left Trigger.
+    TriggerL,
+    /// The [0..1] axis of the Right Trigger.
+    TriggerR,
+}
+
+impl GamepadButton {
+    /// Every `GamepadButton`, in declaration order.
+    pub const ALL: [GamepadButton; 14] = [
+        GamepadButton::A,
+        GamepadButton::B,
+        GamepadButton::X,
+        GamepadButton::Y,
+        GamepadButton::Back,
+        GamepadButton::Start,
+        GamepadButton::DpadUp,
+        GamepadButton::DpadDown,
+        GamepadButton::DpadLeft,
+        GamepadButton::DpadRight,
+        GamepadButton::BumperL,
+        GamepadButton::BumperR,
+        GamepadButton::ThumbL,
+        GamepadButton::ThumbR,
+    ];
+}
+
+impl GamepadAxis {
+    /// Every `GamepadAxis`, in declaration order.
+    pub const ALL: [GamepadAxis; 6] = [
+        GamepadAxis::StickLX,
+        GamepadAxis::StickLY,
+        GamepadAxis::StickRX,
+        GamepadAxis::StickRY,
+        GamepadAxis::TriggerL,
+        GamepadAxis::TriggerR,
+    ];
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// A snapshot of the state of a connected Gamepad.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Gamepad {
+    /// The ID of the Gamepad.
+    id: GamepadId,
+
+    /// The pressed buttons, as a bit-set indexed by `GamepadButton`.
+    buttons: u32,
+
+    /// The normalized axes, indexed by `GamepadAxis`.
+    axes: [f32; 6],
+
+    /// The Packet-Number, which changes whenever the state changes.
+    packet: u32,
+}
+
+/// The normalized value a Trigger must exceed to register as pressed (matches `XINPUT_GAMEPAD_TRIGGER_THRESHOLD`).
+const TRIGGER_THRESHOLD: f32 = 30.0 / 255.0;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl Gamepad {
+    /// Constructs a new Gamepad snapshot, with nothing pressed.
+    pub(crate) fn new(id: GamepadId) -> Self {
+        Self {
+            id,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the state of a button.
+    pub(crate) fn set_button(&mut self, button: GamepadButton, pressed: bool) {
+        let bit = 1 << button as u32;
+        match pressed {
+            true => self.buttons |= bit,
+            false => self.buttons &= !bit,
+        }
+    }
+
+    /// Sets the normalized value of an axis.
+    pub(crate) fn set_axis(&mut self, axis: GamepadAxis, value: f32) {
+        self.axes[axis as usize] = value;
+    }
+
+    /// Sets the Packet-Number.
+    pub(crate) fn set_packet(&mut self, packet: u32) {
+        self.packet = packet;
+    }
+}
+
+impl Gamepad {
+    /// The ID of the Gamepad.
+    pub fn id(&self) -> GamepadId {
+        self.id
+    }
+
+    /// Returns the state of any button.
+    pub fn button(&self, button: GamepadButton) -> bool {
+        self.buttons & (1 << button as u32) != 0
+    }
+
+    /// Returns the normalized value of any axis.
+    pub fn axis(&self, axis: GamepadAxis) -> f32 {
+        self.axes[axis as usize]
+    }
+
+    /// Returns the state of the A Button.
+    pub fn a(&self) -> bool {
+        self.button(GamepadButton::A)
+    }
+
+    /// Returns the state of the B Button.
+    pub fn b(&self) -> bool {
+        self.button(GamepadButton::B)
+    }
+
+    /// Returns the state of the X Button.
+    pub fn x(&self) -> bool {
+        self.button(GamepadButton::X)
+    }
+
+    /// Returns the state of the Y Button.
+    pub fn y(&self) -> bool {
+        self.button(GamepadButton::Y)
+    }
+
+    /// Returns the state of the BACK Button.
+    pub fn back(&self) -> bool {
+        self.button(GamepadButton::Back)
+    }
+
+    /// Returns the state of the START Button.
+    pub fn start(&self) -> bool {
+        self.button(GamepadButton::Start)
+    }
+
+    /// Returns the state of the D-Pad Up Button.
+    pub fn dpad_u(&self) -> bool {
+        self.button(GamepadButton::DpadUp)
+    }
+
+    /// Returns the state of the D-Pad Down Button.
+    pub fn dpad_d(&self) -> bool {
+        self.button(GamepadButton::DpadDown)
+    }
+
+    /// Returns the state of the D-Pad Left Button.
+    pub fn dpad_l(&self) -> bool {
+        self.button(GamepadButton::DpadLeft)
+    }
+
+    /// Returns the state of the D-Pad Right Button.
+    pub fn dpad_r(&self) -> bool {
+        self.button(GamepadButton::DpadRight)
+    }
+
+    /// Returns the state of the Left Bumper.
+    pub fn bumper_l(&self) -> bool {
+        self.button(GamepadButton::BumperL)
+    }
+
+    /// Returns the state of the Right Bumper.
+    pub fn bumper_r(&self) -> bool {
+        self.button(GamepadButton::BumperR)
+    }
+
+    /// Returns the state of the Left Trigger.
+    /// The float is a normalized [0..1] value indicating how far the trigger was pressed.
+    /// The boolean indicates whether or not the register-threshold was exceeded.
+    pub fn trigger_l(&self) -> (f32, bool) {
+        let value = self.axis(GamepadAxis::TriggerL);
+        (value, value > TRIGGER_THRESHOLD)
+    }
+
+    /// Returns the state of the Right Trigger.
+    /// The float is a normalized [0..1] value indicating how far the trigger was pressed.
+    /// The boolean indicates whether or not the register-threshold was exceeded.
+    pub fn trigger_r(&self) -> (f32, bool) {
+        let value = self.axis(GamepadAxis::TriggerR);
+        (value, value > TRIGGER_THRESHOLD)
+    }
+
+    /// Returns the state of the Left Thumbstick Button.
+    pub fn thumb_l(&self) -> bool {
+        self.button(GamepadButton::ThumbL)
+    }
+
+    /// Returns the state of the Right Thumbstick Button.
+    pub fn thumb_r(&self) -> bool {
+        self.button(GamepadButton::ThumbR)
+    }
+
+    /// Returns the normalized [-1..1] values indicating the (x, y) coordinates of the Left Stick.
+    pub fn stick_l(&self) -> (f32, f32) {
+        (
+            self.axis(GamepadAxis::StickLX),
+            self.axis(GamepadAxis::StickLY),
+        )
+    }
+
+    /// Returns the normalized [-1..1] values indicating the (x, y) coordinates of the Right Stick.
+    pub fn stick_r(&self) -> (f32, f32) {
+        (
+            self.axis(GamepadAxis::StickRX),
+            self.axis(GamepadAxis::StickRY),
+        )
+    }
+
+    /// The Packet-Number associated with this Gamepad.
+    pub fn packet(&self) -> u32 {
+        self.packet
+    }
+}
+
+// ================================================================================================================================ //
@@ -23,8 +23,20 @@ pub(crate) use self::nonzero::{NonNull, NonZero};
 
 pub(crate) mod types;
 
+pub(crate) mod inputs;
+
+pub(crate) mod events;
+
+pub(crate) mod clipboard;
+
+pub(crate) mod cursor;
+
+pub(crate) mod screen;
+
 pub mod tasks;
 
+pub mod recording;
+
 // ================================================================================================================================ //
 
 /// A type alias for `Box`ed `Panic`s.\
@@ -0,0 +1,1364 @@
+/*
+ *  Crate: Wyn
+ * Module: Common - Recording
+ */
+
+//! Recording the callbacks delivered to an `EventHandler`, and replaying them later.
+//!
+//! A `Recorder` wraps an Event Handler, and writes every callback it receives (along with a timestamp and a Window ID) as a line of text.\
+//! A `ReplayEventLoop` reads such a `Recording`, and plays it back into any Event Handler, at the original or an accelerated speed.
+//!
+//! Windows are identified by the order in which they were opened, rather than by their handles,
+//! so a Recording can be replayed in a later run (or with a different backend, such as the Headless one).\
+//! Keys and Buttons without a platform-independent name are stored by their native code, which only makes sense on the same platform.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::event_loop::{self, EventLoop};
+use super::events::EventHandler;
+use super::inputs::{
+    GamepadAxis, GamepadButton, GamepadId, Key, KeyEvent, KeyLocation, Modifiers, MouseButton,
+    PenButtons, TouchId,
+};
+use super::types::{Point, ScaleFactor};
+use super::window::WindowHandle;
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::ops::{BitOr, Range};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// ================================================================================================================================ //
+
+/// The first line of every Recording, which identifies the format.
+const HEADER: &str = "# wyn-recording 2";
+
+/// How often the replay thread checks whether the Event Loop is still running, while it waits.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+// ================================================================================================================================ //
+
+/// Identifies a Window within a Recording.\
+/// Windows are numbered from `1`, in the order they were opened.
+pub type WindowId = u32;
+
+/// A single `EventHandler` callback, as it is stored in a Recording.
+#[derive(Clone, PartialEq, Debug)]
+pub enum RecordedEvent {
+    /// `EventHandler::start`.
+    Start,
+    /// `EventHandler::stop`.
+    Stop,
+    /// `EventHandler::window_open`.
+    WindowOpen(WindowId),
+    /// `EventHandler::window_close`.
+    WindowClose(WindowId),
+    /// `EventHandler::window_redraw`.
+    WindowRedraw(WindowId),
+    /// `EventHandler::window_reposition`.
+    WindowReposition(WindowId),
+    /// `EventHandler::scale_factor_changed`.
+    ScaleFactorChanged(WindowId, ScaleFactor),
+    /// `EventHandler::screens_changed`.
+    ScreensChanged,
+    /// `EventHandler::window_visibility`.
+    WindowVisibility(WindowId, bool),
+    /// `EventHandler::window_focus`.
+    WindowFocus(WindowId, bool),
+    /// `EventHandler::cursor_move`.
+    CursorMove(WindowId, Point),
+    /// `EventHandler::cursor_enter`.
+    CursorEnter(WindowId),
+    /// `EventHandler::cursor_leave`.
+    CursorLeave(WindowId),
+    /// `EventHandler::raw_motion`.
+    RawMotion(f64, f64),
+    /// `EventHandler::scroll_wheel`.
+    ScrollWheel(WindowId, f64, f64),
+    /// `EventHandler::button_press`.
+    ButtonPress(WindowId, MouseButton, bool),
+    /// `EventHandler::touch_begin`.
+    TouchBegin(WindowId, TouchId, Point),
+    /// `EventHandler::touch_move`.
+    TouchMove(WindowId, TouchId, Point),
+    /// `EventHandler::touch_end`.
+    TouchEnd(WindowId, TouchId, Point),
+    /// `EventHandler::touch_cancel`.
+    TouchCancel(WindowId, TouchId, Point),
+    /// `EventHandler::pen`.
+    Pen(WindowId, Point, f64, (f64, f64), PenButtons),
+    /// `EventHandler::key_press`.
+    KeyPress(WindowId, KeyEvent, bool),
+    /// `EventHandler::character_input`.
+    CharacterInput(WindowId, char),
+    /// `EventHandler::ime_preedit`.
+    ImePreedit(WindowId, String, Option<Range<usize>>),
+    /// `EventHandler::ime_commit`.
+    ImeCommit(WindowId, String),
+    /// `EventHandler::file_drop_hover`.
+    FileDropHover(WindowId, Vec<PathBuf>, Point),
+    /// `EventHandler::file_drop`.
+    FileDrop(WindowId, Vec<PathBuf>, Point),
+    /// `EventHandler::file_drop_cancel`.
+    FileDropCancel(WindowId),
+    /// `EventHandler::gamepad_connected`.
+    GamepadConnected(GamepadId),
+    /// `EventHandler::gamepad_disconnected`.
+    GamepadDisconnected(GamepadId),
+    /// `EventHandler::gamepad_button`.
+    GamepadButton(GamepadId, GamepadButton, bool),
+    /// `EventHandler::gamepad_axis`.
+    GamepadAxis(GamepadId, GamepadAxis, f32),
+}
+
+/// A `RecordedEvent`, along with the time at which it was delivered.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Record {
+    /// The time since the Recording began.
+    pub time: Duration,
+    /// The callback that was delivered.
+    pub event: RecordedEvent,
+}
+
+/// A list of `Record`s, in the order they were delivered.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Recording {
+    /// The recorded callbacks.
+    pub records: Vec<Record>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl RecordedEvent {
+    /// Returns the name of the callback, as it is written in a Recording.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Stop => "stop",
+            Self::WindowOpen(..) => "window_open",
+            Self::WindowClose(..) => "window_close",
+            Self::WindowRedraw(..) => "window_redraw",
+            Self::WindowReposition(..) => "window_reposition",
+            Self::ScaleFactorChanged(..) => "scale_factor_changed",
+            Self::ScreensChanged => "screens_changed",
+            Self::WindowVisibility(..) => "window_visibility",
+            Self::WindowFocus(..) => "window_focus",
+            Self::CursorMove(..) => "cursor_move",
+            Self::CursorEnter(..) => "cursor_enter",
+            Self::CursorLeave(..) => "cursor_leave",
+            Self::RawMotion(..) => "raw_motion",
+            Self::ScrollWheel(..) => "scroll_wheel",
+            Self::ButtonPress(..) => "button_press",
+            Self::TouchBegin(..) => "touch_begin",
+            Self::TouchMove(..) => "touch_move",
+            Self::TouchEnd(..) => "touch_end",
+            Self::TouchCancel(..) => "touch_cancel",
+            Self::Pen(..) => "pen",
+            Self::KeyPress(..) => "key_press",
+            Self::CharacterInput(..) => "character_input",
+            Self::ImePreedit(..) => "ime_preedit",
+            Self::ImeCommit(..) => "ime_commit",
+            Self::FileDropHover(..) => "file_drop_hover",
+            Self::FileDrop(..) => "file_drop",
+            Self::FileDropCancel(..) => "file_drop_cancel",
+            Self::GamepadConnected(..) => "gamepad_connected",
+            Self::GamepadDisconnected(..) => "gamepad_disconnected",
+            Self::GamepadButton(..) => "gamepad_button",
+            Self::GamepadAxis(..) => "gamepad_axis",
+        }
+    }
+
+    /// Parses the callback name and its arguments.
+    fn parse(name: &str, args: &mut Args) -> Result<Self, String> {
+        let event = match name {
+            "start" => Self::Start,
+            "stop" => Self::Stop,
+            "window_open" => Self::WindowOpen(args.parse()?),
+            "window_close" => Self::WindowClose(args.parse()?),
+            "window_redraw" => Self::WindowRedraw(args.parse()?),
+            "window_reposition" => Self::WindowReposition(args.parse()?),
+            "scale_factor_changed" => Self::ScaleFactorChanged(args.parse()?, args.parse()?),
+            "screens_changed" => Self::ScreensChanged,
+            "window_visibility" => Self::WindowVisibility(args.parse()?, args.parse()?),
+            "window_focus" => Self::WindowFocus(args.parse()?, args.parse()?),
+            "cursor_move" => {
+                Self::CursorMove(args.parse()?, Point::new(args.parse()?, args.parse()?))
+            }
+            "cursor_enter" => Self::CursorEnter(args.parse()?),
+            "cursor_leave" => Self::CursorLeave(args.parse()?),
+            "raw_motion" => Self::RawMotion(args.parse()?, args.parse()?),
+            "scroll_wheel" => Self::ScrollWheel(args.parse()?, args.parse()?, args.parse()?),
+            "button_press" => Self::ButtonPress(args.parse()?, args.button()?, args.parse()?),
+            "touch_begin" => Self::TouchBegin(args.parse()?, args.parse()?, args.point()?),
+            "touch_move" => Self::TouchMove(args.parse()?, args.parse()?, args.point()?),
+            "touch_end" => Self::TouchEnd(args.parse()?, args.parse()?, args.point()?),
+            "touch_cancel" => Self::TouchCancel(args.parse()?, args.parse()?, args.point()?),
+            "pen" => Self::Pen(
+                args.parse()?,
+                args.point()?,
+                args.parse()?,
+                (args.parse()?, args.parse()?),
+                args.flags(&PenButtons::NAMED, "pen button")?,
+            ),
+            "key_press" => {
+                let id = args.parse()?;
+                let key = args.key()?;
+                let pressed = args.parse()?;
+                let event = KeyEvent {
+                    scancode: args.parse()?,
+                    key,
+                    location: args.named(&KeyLocation::ALL)?,
+                    modifiers: args.flags(&Modifiers::NAMED, "modifier")?,
+                    repeat: args.parse()?,
+                };
+                Self::KeyPress(id, event, pressed)
+            }
+            "character_input" => Self::CharacterInput(args.parse()?, args.character()?),
+            "ime_preedit" => Self::ImePreedit(args.parse()?, args.text()?, args.range()?),
+            "ime_commit" => Self::ImeCommit(args.parse()?, args.text()?),
+            "file_drop_hover" => Self::FileDropHover(
+                args.parse()?,
+                args.paths()?,
+                Point::new(args.parse()?, args.parse()?),
+            ),
+            "file_drop" => Self::FileDrop(
+                args.parse()?,
+                args.paths()?,
+                Point::new(args.parse()?, args.parse()?),
+            ),
+            "file_drop_cancel" => Self::FileDropCancel(args.parse()?),
+            "gamepad_connected" => Self::GamepadConnected(args.parse()?),
+            "gamepad_disconnected" => Self::GamepadDisconnected(args.parse()?),
+            "gamepad_button" => {
+                Self::GamepadButton(args.parse()?, args.gamepad_button()?, args.parse()?)
+            }
+            "gamepad_axis" => Self::GamepadAxis(args.parse()?, args.gamepad_axis()?, args.parse()?),
+            _ => return Err(format!("unknown callback `{name}`")),
+        };
+        Ok(event)
+    }
+
+    /// Delivers the callback to the Event Handler, if its Window is among the live `windows`.\
+    /// `Start`, `Stop`, and `WindowOpen` are not delivered, as they are reported by the Event Loop itself.
+    fn dispatch(self, events: &EventLoop, windows: &Windows) {
+        let handler = events.handler;
+
+        match self {
+            Self::Start | Self::Stop | Self::WindowOpen(_) => {}
+            Self::WindowClose(id) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.window_close(events, handle);
+                }
+            }
+            Self::WindowRedraw(id) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.window_redraw(events, handle);
+                }
+            }
+            Self::WindowReposition(id) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.window_reposition(events, handle);
+                }
+            }
+            Self::ScaleFactorChanged(id, scale_factor) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.scale_factor_changed(events, handle, scale_factor);
+                }
+            }
+            Self::ScreensChanged => handler.screens_changed(events),
+            Self::WindowVisibility(id, visible) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.window_visibility(events, handle, visible);
+                }
+            }
+            Self::WindowFocus(id, focused) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.window_focus(events, handle, focused);
+                }
+            }
+            Self::CursorMove(id, point) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.cursor_move(events, handle, point);
+                }
+            }
+            Self::CursorEnter(id) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.cursor_enter(events, handle);
+                }
+            }
+            Self::CursorLeave(id) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.cursor_leave(events, handle);
+                }
+            }
+            Self::RawMotion(delta_x, delta_y) => handler.raw_motion(events, delta_x, delta_y),
+            Self::ScrollWheel(id, delta_x, delta_y) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.scroll_wheel(events, handle, delta_x, delta_y);
+                }
+            }
+            Self::ButtonPress(id, button, pressed) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.button_press(events, handle, button, pressed);
+                }
+            }
+            Self::TouchBegin(id, touch, point) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.touch_begin(events, handle, touch, point);
+                }
+            }
+            Self::TouchMove(id, touch, point) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.touch_move(events, handle, touch, point);
+                }
+            }
+            Self::TouchEnd(id, touch, point) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.touch_end(events, handle, touch, point);
+                }
+            }
+            Self::TouchCancel(id, touch, point) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.touch_cancel(events, handle, touch, point);
+                }
+            }
+            Self::Pen(id, point, pressure, tilt, buttons) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.pen(events, handle, point, pressure, tilt, buttons);
+                }
+            }
+            Self::KeyPress(id, event, pressed) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.key_press(events, handle, event, pressed);
+                }
+            }
+            Self::CharacterInput(id, character) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.character_input(events, handle, character);
+                }
+            }
+            Self::ImePreedit(id, text, cursor_range) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.ime_preedit(events, handle, text, cursor_range);
+                }
+            }
+            Self::ImeCommit(id, text) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.ime_commit(events, handle, text);
+                }
+            }
+            Self::FileDropHover(id, paths, point) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.file_drop_hover(events, handle, paths, point);
+                }
+            }
+            Self::FileDrop(id, paths, point) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.file_drop(events, handle, paths, point);
+                }
+            }
+            Self::FileDropCancel(id) => {
+                if let Some(handle) = windows.handle(id) {
+                    handler.file_drop_cancel(events, handle);
+                }
+            }
+            Self::GamepadConnected(id) => handler.gamepad_connected(events, id),
+            Self::GamepadDisconnected(id) => handler.gamepad_disconnected(events, id),
+            Self::GamepadButton(id, button, pressed) => {
+                handler.gamepad_button(events, id, button, pressed)
+            }
+            Self::GamepadAxis(id, axis, value) => handler.gamepad_axis(events, id, axis, value),
+        }
+    }
+}
+
+impl Display for RecordedEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())?;
+
+        // Keys and Buttons are written by their variant names, e.g. `A` or `Other(65)`.
+        match *self {
+            Self::Start | Self::Stop | Self::ScreensChanged => Ok(()),
+            Self::WindowOpen(id) | Self::WindowClose(id) => write!(f, " {id}"),
+            Self::WindowRedraw(id) | Self::WindowReposition(id) => write!(f, " {id}"),
+            Self::CursorEnter(id) | Self::CursorLeave(id) => write!(f, " {id}"),
+            Self::ScaleFactorChanged(id, scale_factor) => write!(f, " {id} {scale_factor}"),
+            Self::WindowVisibility(id, flag) | Self::WindowFocus(id, flag) => {
+                write!(f, " {id} {flag}")
+            }
+            Self::CursorMove(id, point) => write!(f, " {id} {} {}", point.x, point.y),
+            Self::RawMotion(delta_x, delta_y) => write!(f, " {delta_x} {delta_y}"),
+            Self::ScrollWheel(id, delta_x, delta_y) => write!(f, " {id} {delta_x} {delta_y}"),
+            Self::ButtonPress(id, button, pressed) => write!(f, " {id} {button:?} {pressed}"),
+            Self::TouchBegin(id, touch, point)
+            | Self::TouchMove(id, touch, point)
+            | Self::TouchEnd(id, touch, point)
+            | Self::TouchCancel(id, touch, point) => {
+                write!(f, " {id} {touch} {} {}", point.x, point.y)
+            }
+            // Flags (Modifiers and Pen Buttons) are written by their names joined with `|` (or `-` for none), e.g. `SHIFT|CONTROL`.
+            Self::Pen(id, point, pressure, (tilt_x, tilt_y), buttons) => {
+                write!(
+                    f,
+                    " {id} {} {} {pressure} {tilt_x} {tilt_y} ",
+                    point.x, point.y
+                )?;
+                write_flags(f, buttons, &PenButtons::NAMED)
+            }
+            Self::KeyPress(id, event, pressed) => {
+                let KeyEvent {
+                    scancode,
+                    key,
+                    modifiers,
+                    repeat,
+                    location,
+                } = event;
+                write!(f, " {id} {key:?} {pressed} {scancode} {location:?} ")?;
+                write_flags(f, modifiers, &Modifiers::NAMED)?;
+                write!(f, " {repeat}")
+            }
+            Self::CharacterInput(id, character) => write!(f, " {id} U+{:04X}", character as u32),
+            // Text is quoted (and escaped), so that it is a single argument even when it is empty.
+            Self::ImePreedit(id, ref text, ref cursor_range) => {
+                write!(f, " {id} \"{}\"", escape_text(text))?;
+                match cursor_range {
+                    Some(range) => write!(f, " {}..{}", range.start, range.end),
+                    None => write!(f, " -"),
+                }
+            }
+            Self::ImeCommit(id, ref text) => write!(f, " {id} \"{}\"", escape_text(text)),
+            // Paths are written as their count, followed by each path (escaped, so that it is a single argument).
+            Self::FileDropHover(id, ref paths, point) | Self::FileDrop(id, ref paths, point) => {
+                write!(f, " {id} {}", paths.len())?;
+                for path in paths {
+                    write!(f, " {}", escape_path(path))?;
+                }
+                write!(f, " {} {}", point.x, point.y)
+            }
+            Self::FileDropCancel(id) => write!(f, " {id}"),
+            Self::GamepadConnected(id) | Self::GamepadDisconnected(id) => write!(f, " {id}"),
+            Self::GamepadButton(id, button, pressed) => write!(f, " {id} {button:?} {pressed}"),
+            Self::GamepadAxis(id, axis, value) => write!(f, " {id} {axis:?} {value}"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.time.as_micros(), self.event)
+    }
+}
+
+impl Record {
+    /// Parses a single line of a Recording.
+    fn parse(line: &str) -> Result<Self, String> {
+        let mut args = Args(line.split_whitespace());
+
+        let time = Duration::from_micros(args.parse()?);
+        let name = args.next()?;
+        let event = RecordedEvent::parse(name, &mut args)?;
+
+        match args.0.next() {
+            Some(extra) => Err(format!("unexpected argument `{extra}`")),
+            None => Ok(Self { time, event }),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl Recording {
+    /// Reads a Recording, in the format written by a `Recorder`.\
+    /// Empty lines, and lines starting with `#`, are ignored.
+    pub fn read(reader: impl BufRead) -> io::Result<Self> {
+        let mut records = Vec::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let record = Record::parse(line).map_err(|msg| {
+                let msg = format!("Recording line {}: {msg}", index + 1);
+                io::Error::new(io::ErrorKind::InvalidData, msg)
+            })?;
+            records.push(record);
+        }
+
+        Ok(Self { records })
+    }
+
+    /// Reads a Recording from the file at the given path.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+
+    /// Writes the Recording, in the same format as a `Recorder`.
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{HEADER}")?;
+        for record in &self.records {
+            writeln!(writer, "{record}")?;
+        }
+        writer.flush()
+    }
+
+    /// Writes the Recording to the file at the given path, replacing it if it already exists.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.write(BufWriter::new(File::create(path)?))
+    }
+}
+
+// ================================================================================================================================ //
+
+/// The whitespace-separated arguments of a line in a Recording.
+struct Args<'a>(std::str::SplitWhitespace<'a>);
+
+impl<'a> Args<'a> {
+    /// Returns the next argument.
+    fn next(&mut self) -> Result<&'a str, String> {
+        self.0
+            .next()
+            .ok_or_else(|| String::from("missing argument"))
+    }
+
+    /// Parses the next argument with `FromStr`.
+    fn parse<T: std::str::FromStr>(&mut self) -> Result<T, String> {
+        let arg = self.next()?;
+        arg.parse().map_err(|_| format!("invalid argument `{arg}`"))
+    }
+
+    /// Parses the next argument as the name of a variant in `all`.
+    fn named<T: Debug + Copy>(&mut self, all: &[T]) -> Result<T, String> {
+        let arg = self.next()?;
+        all.iter()
+            .copied()
+            .find(|item| format!("{item:?}") == arg)
+            .ok_or_else(|| format!("unknown name `{arg}`"))
+    }
+
+    /// Parses the next argument as the name of a variant in `all`, or as `Other(<native>)`.
+    fn named_or_other<T: Debug + Copy, N: std::str::FromStr>(
+        &mut self,
+        all: &[T],
+        other: impl FnOnce(N) -> T,
+    ) -> Result<T, String> {
+        let arg = match self.0.clone().next() {
+            Some(arg) => arg,
+            None => return self.named(all),
+        };
+
+        match arg.strip_prefix("Other(").and_then(|s| s.strip_suffix(')')) {
+            Some(native) => {
+                self.0.next();
+                let native = native
+                    .parse()
+                    .map_err(|_| format!("invalid native code `{arg}`"))?;
+                Ok(other(native))
+            }
+            None => self.named(all),
+        }
+    }
+
+    /// Parses the next argument as a `MouseButton`.
+    fn button(&mut self) -> Result<MouseButton, String> {
+        let all = [
+            MouseButton::Left,
+            MouseButton::Middle,
+            MouseButton::Right,
+            MouseButton::X1,
+            MouseButton::X2,
+        ];
+        self.named_or_other(&all, MouseButton::Other)
+    }
+
+    /// Parses the next argument as a `Key`.
+    fn key(&mut self) -> Result<Key, String> {
+        self.named_or_other(&Key::ALL, Key::Other)
+    }
+
+    /// Parses the next argument as a `GamepadButton`.
+    fn gamepad_button(&mut self) -> Result<GamepadButton, String> {
+        self.named(&GamepadButton::ALL)
+    }
+
+    /// Parses the next argument as a `GamepadAxis`.
+    fn gamepad_axis(&mut self) -> Result<GamepadAxis, String> {
+        self.named(&GamepadAxis::ALL)
+    }
+
+    /// Parses a list of paths, written as their count followed by each (escaped) path.
+    fn paths(&mut self) -> Result<Vec<PathBuf>, String> {
+        let count: usize = self.parse()?;
+        (0..count)
+            .map(|_| {
+                let arg = self.next()?;
+                unescape_path(arg).ok_or_else(|| format!("invalid path `{arg}`"))
+            })
+            .collect()
+    }
+
+    /// Parses the next argument as a character, written as its code point (e.g. `U+0061`).
+    fn character(&mut self) -> Result<char, String> {
+        let arg = self.next()?;
+        arg.strip_prefix("U+")
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .and_then(char::from_u32)
+            .ok_or_else(|| format!("invalid character `{arg}`"))
+    }
+
+    /// Parses the next argument as quoted (and escaped) text, e.g. `"a%20b"`.
+    fn text(&mut self) -> Result<String, String> {
+        let arg = self.next()?;
+        arg.strip_prefix('"')
+            .and_then(|quoted| quoted.strip_suffix('"'))
+            .and_then(unescape_text)
+            .ok_or_else(|| format!("invalid text `{arg}`"))
+    }
+
+    /// Parses the next two arguments as the coordinates of a `Point`.
+    fn point(&mut self) -> Result<Point, String> {
+        Ok(Point::new(self.parse()?, self.parse()?))
+    }
+
+    /// Parses the next argument as a set of flags (e.g. `Modifiers`), written by their names joined with `|` (or `-` for none).
+    fn flags<T>(&mut self, named: &[(&str, T)], kind: &str) -> Result<T, String>
+    where
+        T: Copy + Default + BitOr<Output = T>,
+    {
+        let arg = self.next()?;
+        if arg == "-" {
+            return Ok(T::default());
+        }
+
+        arg.split('|').try_fold(T::default(), |flags, name| {
+            named
+                .iter()
+                .find(|(other, _)| *other == name)
+                .map(|(_, flag)| flags | *flag)
+                .ok_or_else(|| format!("unknown {kind} `{name}`"))
+        })
+    }
+
+    /// Parses the next argument as a byte range (e.g. `0..3`), or as `-` for no range.
+    fn range(&mut self) -> Result<Option<Range<usize>>, String> {
+        let arg = self.next()?;
+        if arg == "-" {
+            return Ok(None);
+        }
+
+        let range = arg.split_once("..").and_then(|(start, end)| {
+            let start = start.parse().ok()?;
+            let end = end.parse().ok()?;
+            Some(start..end)
+        });
+        range
+            .map(Some)
+            .ok_or_else(|| format!("invalid range `{arg}`"))
+    }
+}
+
+// ---------------------------------------------------------------- //
+
+/// Writes a set of flags (e.g. `Modifiers`) as their names joined with `|` (or `-` for none), as a single argument.
+fn write_flags<T>(
+    f: &mut std::fmt::Formatter<'_>,
+    flags: T,
+    named: &[(&str, T)],
+) -> std::fmt::Result
+where
+    T: Copy + Default + Eq + BitOr<Output = T>,
+{
+    if flags == T::default() {
+        return write!(f, "-");
+    }
+
+    // A flag is in the set if adding it changes nothing.
+    let names: Vec<_> = named
+        .iter()
+        .filter(|(_, flag)| flags | *flag == flags)
+        .map(|(name, _)| *name)
+        .collect();
+    write!(f, "{}", names.join("|"))
+}
+
+/// Escapes a path, so that it is written as a single argument.\
+/// Paths that are not valid Unicode are converted lossily.
+fn escape_path(path: &Path) -> String {
+    escape_text(&path.to_string_lossy())
+}
+
+/// Reverses `escape_path`, returning `None` if the escapes are malformed.
+fn unescape_path(arg: &str) -> Option<PathBuf> {
+    unescape_text(arg).map(PathBuf::from)
+}
+
+/// Escapes text, so that it is written as a single argument.\
+/// Whitespace, control characters, and `%` are percent-encoded.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::new();
+    for character in text.chars() {
+        if character.is_whitespace() || character.is_control() || character == '%' {
+            let mut buf = [0; 4];
+            for byte in character.encode_utf8(&mut buf).bytes() {
+                escaped.push_str(&format!("%{byte:02X}"));
+            }
+        } else {
+            escaped.push(character);
+        }
+    }
+    escaped
+}
+
+/// Reverses `escape_text`, returning `None` if the escapes are malformed.
+fn unescape_text(arg: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(arg.len());
+    let mut rest = arg.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+// ================================================================================================================================ //
+
+/// The handles of every Window opened so far, indexed by `WindowId - 1`.
+#[derive(Clone, Default)]
+struct Windows(Vec<WindowHandle>);
+
+// The handles are only compared, or passed back to the Event Handler on the Event Thread.
+unsafe impl Send for Windows {}
+
+impl Windows {
+    /// Returns the ID of the Window, assigning it the next ID if it has not been seen before.
+    fn id(&mut self, handle: WindowHandle) -> WindowId {
+        let index = match self.0.iter().position(|&window| window == handle) {
+            Some(index) => index,
+            None => {
+                self.0.push(handle);
+                self.0.len() - 1
+            }
+        };
+        index as WindowId + 1
+    }
+
+    /// Returns the handle of the Window with the given ID, if it has been opened.
+    fn handle(&self, id: WindowId) -> Option<WindowHandle> {
+        let index = (id as usize).checked_sub(1)?;
+        self.0.get(index).copied()
+    }
+}
+
+// ================================================================================================================================ //
+
+/// An Event Handler that records every callback, before forwarding it to another Event Handler.
+pub struct Recorder<'a> {
+    /// The Event Handler that receives the callbacks.
+    handler: &'a dyn EventHandler,
+
+    /// The time at which the Recording began.
+    begin: Instant,
+
+    /// The output, and the Windows that have been seen so far.
+    state: Mutex<RecorderState<'a>>,
+}
+
+/// The mutable state of a `Recorder`.
+struct RecorderState<'a> {
+    /// Where the Records are written.
+    output: Box<dyn Write + Send + 'a>,
+
+    /// The Windows that have been seen so far.
+    windows: Windows,
+
+    /// The first error that occurred while writing, if any.
+    error: Option<io::Error>,
+}
+
+impl<'a> Recorder<'a> {
+    /// Creates a new `Recorder`, which forwards callbacks to the Event Handler, and writes them to the output.\
+    /// Callbacks are timed relative to this call.
+    pub fn new(handler: &'a dyn EventHandler, output: impl Write + Send + 'a) -> Self {
+        let mut output: Box<dyn Write + Send + 'a> = Box::new(output);
+        let error = writeln!(output, "{HEADER}").err();
+
+        let state = RecorderState {
+            output,
+            windows: Windows::default(),
+            error,
+        };
+
+        Self {
+            handler,
+            begin: Instant::now(),
+            state: Mutex::new(state),
+        }
+    }
+
+    /// Creates a new `Recorder`, which writes to the file at the given path (replacing it if it already exists).
+    pub fn create(handler: &'a dyn EventHandler, path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::create(path)?;
+        Ok(Self::new(handler, BufWriter::new(file)))
+    }
+
+    /// Flushes the output, and returns the first error that occurred while recording (if any).
+    pub fn finish(self) -> io::Result<()> {
+        let mut state = self.state.into_inner().unwrap();
+        match state.error.take() {
+            Some(err) => Err(err),
+            None => state.output.flush(),
+        }
+    }
+
+    /// Writes a Record for a callback that does not belong to a Window.
+    fn record(&self, event: RecordedEvent) {
+        let mut state = self.state.lock().unwrap();
+        self.write(&mut state, event);
+    }
+
+    /// Writes a Record for a callback that belongs to a Window, assigning the Window an ID if it has not been seen before.
+    fn record_window(&self, handle: WindowHandle, event: impl FnOnce(WindowId) -> RecordedEvent) {
+        let mut state = self.state.lock().unwrap();
+        let id = state.windows.id(handle);
+        self.write(&mut state, event(id));
+    }
+
+    /// Writes the Record, keeping the first error (if any).
+    fn write(&self, state: &mut RecorderState, event: RecordedEvent) {
+        let time = self.begin.elapsed();
+        let record = Record { time, event };
+
+        if let Err(err) = writeln!(state.output, "{record}") {
+            state.error.get_or_insert(err);
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+// Callbacks are recorded before they are forwarded, so that nested callbacks are recorded in the order they began.
+
+impl EventHandler for Recorder<'_> {
+    fn start(&self, events: &EventLoop) {
+        self.record(RecordedEvent::Start);
+        self.handler.start(events);
+    }
+
+    fn stop(&self, events: &EventLoop) {
+        self.record(RecordedEvent::Stop);
+        self.handler.stop(events);
+    }
+
+    fn window_open(&self, events: &EventLoop, handle: WindowHandle) {
+        self.record_window(handle, RecordedEvent::WindowOpen);
+        self.handler.window_open(events, handle);
+    }
+
+    fn window_close(&self, events: &EventLoop, handle: WindowHandle) {
+        self.record_window(handle, RecordedEvent::WindowClose);
+        self.handler.window_close(events, handle);
+    }
+
+    fn window_redraw(&self, events: &EventLoop, handle: WindowHandle) {
+        self.record_window(handle, RecordedEvent::WindowRedraw);
+        self.handler.window_redraw(events, handle);
+    }
+
+    fn window_reposition(&self, events: &EventLoop, handle: WindowHandle) {
+        self.record_window(handle, RecordedEvent::WindowReposition);
+        self.handler.window_reposition(events, handle);
+    }
+
+    fn scale_factor_changed(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        scale_factor: ScaleFactor,
+    ) {
+        self.record_window(handle, |id| {
+            RecordedEvent::ScaleFactorChanged(id, scale_factor)
+        });
+        self.handler
+            .scale_factor_changed(events, handle, scale_factor);
+    }
+
+    fn screens_changed(&self, events: &EventLoop) {
+        self.record(RecordedEvent::ScreensChanged);
+        self.handler.screens_changed(events);
+    }
+
+    fn window_visibility(&self, events: &EventLoop, handle: WindowHandle, visible: bool) {
+        self.record_window(handle, |id| RecordedEvent::WindowVisibility(id, visible));
+        self.handler.window_visibility(events, handle, visible);
+    }
+
+    fn window_focus(&self, events: &EventLoop, handle: WindowHandle, focused: bool) {
+        self.record_window(handle, |id| RecordedEvent::WindowFocus(id, focused));
+        self.handler.window_focus(events, handle, focused);
+    }
+
+    fn cursor_move(&self, events: &EventLoop, handle: WindowHandle, point: Point) {
+        self.record_window(handle, |id| RecordedEvent::CursorMove(id, point));
+        self.handler.cursor_move(events, handle, point);
+    }
+
+    fn cursor_enter(&self, events: &EventLoop, handle: WindowHandle) {
+        self.record_window(handle, RecordedEvent::CursorEnter);
+        self.handler.cursor_enter(events, handle);
+    }
+
+    fn cursor_leave(&self, events: &EventLoop, handle: WindowHandle) {
+        self.record_window(handle, RecordedEvent::CursorLeave);
+        self.handler.cursor_leave(events, handle);
+    }
+
+    fn raw_motion(&self, events: &EventLoop, delta_x: f64, delta_y: f64) {
+        self.record(RecordedEvent::RawMotion(delta_x, delta_y));
+        self.handler.raw_motion(events, delta_x, delta_y);
+    }
+
+    fn scroll_wheel(&self, events: &EventLoop, handle: WindowHandle, delta_x: f64, delta_y: f64) {
+        self.record_window(handle, |id| {
+            RecordedEvent::ScrollWheel(id, delta_x, delta_y)
+        });
+        self.handler.scroll_wheel(events, handle, delta_x, delta_y);
+    }
+
+    fn button_press(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        button: MouseButton,
+        pressed: bool,
+    ) {
+        self.record_window(handle, |id| RecordedEvent::ButtonPress(id, button, pressed));
+        self.handler.button_press(events, handle, button, pressed);
+    }
+
+    fn touch_begin(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {
+        self.record_window(handle, |window| {
+            RecordedEvent::TouchBegin(window, id, point)
+        });
+        self.handler.touch_begin(events, handle, id, point);
+    }
+
+    fn touch_move(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {
+        self.record_window(handle, |window| RecordedEvent::TouchMove(window, id, point));
+        self.handler.touch_move(events, handle, id, point);
+    }
+
+    fn touch_end(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {
+        self.record_window(handle, |window| RecordedEvent::TouchEnd(window, id, point));
+        self.handler.touch_end(events, handle, id, point);
+    }
+
+    fn touch_cancel(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {
+        self.record_window(handle, |window| {
+            RecordedEvent::TouchCancel(window, id, point)
+        });
+        self.handler.touch_cancel(events, handle, id, point);
+    }
+
+    fn pen(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        point: Point,
+        pressure: f64,
+        tilt: (f64, f64),
+        buttons: PenButtons,
+    ) {
+        self.record_window(handle, |id| {
+            RecordedEvent::Pen(id, point, pressure, tilt, buttons)
+        });
+        self.handler
+            .pen(events, handle, point, pressure, tilt, buttons);
+    }
+
+    fn key_press(&self, events: &EventLoop, handle: WindowHandle, event: KeyEvent, pressed: bool) {
+        self.record_window(handle, |id| RecordedEvent::KeyPress(id, event, pressed));
+        self.handler.key_press(events, handle, event, pressed);
+    }
+
+    fn character_input(&self, events: &EventLoop, handle: WindowHandle, character: char) {
+        self.record_window(handle, |id| RecordedEvent::CharacterInput(id, character));
+        self.handler.character_input(events, handle, character);
+    }
+
+    fn ime_preedit(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        text: String,
+        cursor_range: Option<Range<usize>>,
+    ) {
+        self.record_window(handle, |id| {
+            RecordedEvent::ImePreedit(id, text.clone(), cursor_range.clone())
+        });
+        self.handler.ime_preedit(events, handle, text, cursor_range);
+    }
+
+    fn ime_commit(&self, events: &EventLoop, handle: WindowHandle, text: String) {
+        self.record_window(handle, |id| RecordedEvent::ImeCommit(id, text.clone()));
+        self.handler.ime_commit(events, handle, text);
+    }
+
+    fn file_drop_hover(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+        self.record_window(handle, |id| {
+            RecordedEvent::FileDropHover(id, paths.clone(), point)
+        });
+        self.handler.file_drop_hover(events, handle, paths, point);
+    }
+
+    fn file_drop(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+        self.record_window(handle, |id| {
+            RecordedEvent::FileDrop(id, paths.clone(), point)
+        });
+        self.handler.file_drop(events, handle, paths, point);
+    }
+
+    fn file_drop_cancel(&self, events: &EventLoop, handle: WindowHandle) {
+        self.record_window(handle, RecordedEvent::FileDropCancel);
+        self.handler.file_drop_cancel(events, handle);
+    }
+
+    fn gamepad_connected(&self, events: &EventLoop, id: GamepadId) {
+        self.record(RecordedEvent::GamepadConnected(id));
+        self.handler.gamepad_connected(events, id);
+    }
+
+    fn gamepad_disconnected(&self, events: &EventLoop, id: GamepadId) {
+        self.record(RecordedEvent::GamepadDisconnected(id));
+        self.handler.gamepad_disconnected(events, id);
+    }
+
+    fn gamepad_button(
+        &self,
+        events: &EventLoop,
+        id: GamepadId,
+        button: GamepadButton,
+        pressed: bool,
+    ) {
+        self.record(RecordedEvent::GamepadButton(id, button, pressed));
+        self.handler.gamepad_button(events, id, button, pressed);
+    }
+
+    fn gamepad_axis(&self, events: &EventLoop, id: GamepadId, axis: GamepadAxis, value: f32) {
+        self.record(RecordedEvent::GamepadAxis(id, axis, value));
+        self.handler.gamepad_axis(events, id, axis, value);
+    }
+}
+
+// ================================================================================================================================ //
+
+/// An Event Handler that plays a Recording back into another Event Handler, alongside the live callbacks of a real Event Loop.
+///
+/// The Event Loop must be created with the `ReplayEventLoop` as its Event Handler, and then run with `ReplayEventLoop::run`.\
+/// Live callbacks are forwarded as usual, so the Event Handler can open its Windows (and run its tasks) normally.\
+/// The n-th Window it opens stands in for the Window with ID `n` in the Recording;
+/// recorded callbacks for Windows that have not been opened yet are skipped.
+pub struct ReplayEventLoop<'a> {
+    /// The Event Handler that receives the callbacks.
+    handler: &'a dyn EventHandler,
+
+    /// The callbacks to replay.
+    recording: Recording,
+
+    /// The factor by which the Recording is sped up.
+    speed: f64,
+
+    /// The live Windows that have been opened so far.
+    windows: Mutex<Windows>,
+}
+
+impl<'a> ReplayEventLoop<'a> {
+    /// Creates a new `ReplayEventLoop`, which plays the Recording back into the Event Handler.\
+    /// A `speed` of `1.0` replays at the original speed, `2.0` replays twice as fast,
+    /// and `f64::INFINITY` replays every callback as soon as possible.
+    ///
+    /// # Panics
+    /// Panics if the speed is not positive.
+    pub fn new(handler: &'a dyn EventHandler, recording: Recording, speed: f64) -> Self {
+        assert!(speed > 0.0, "The replay speed must be positive.");
+
+        Self {
+            handler,
+            recording,
+            speed,
+            windows: Mutex::new(Windows::default()),
+        }
+    }
+
+    /// Runs the Event Loop, while the Recording is played back from another thread.\
+    /// The Event Loop is stopped when the Recording reaches its `stop` callback (if it has one).
+    ///
+    /// # Panics
+    /// Panics if the Event Loop was not created with this `ReplayEventLoop` as its Event Handler.
+    pub fn run(&self, events: &EventLoop) {
+        let handler = events.handler as *const dyn EventHandler as *const ();
+        assert!(
+            std::ptr::eq(handler, self as *const Self as *const ()),
+            "The Event Loop must be created with the ReplayEventLoop as its Event Handler."
+        );
+
+        std::thread::scope(|scope| {
+            let replay_thread = scope.spawn(|| self.replay(events));
+            events.run();
+            replay_thread.join().unwrap();
+        });
+    }
+
+    /// Plays the Recording back, one callback at a time, until it ends or the Event Loop stops.
+    fn replay(&self, events: &EventLoop) {
+        if !events.await_startup() {
+            return;
+        }
+
+        let begin = Instant::now();
+
+        for record in &self.recording.records {
+            let due = begin + record.time.div_f64(self.speed);
+            loop {
+                if !events.is_running() {
+                    return;
+                }
+                let now = Instant::now();
+                if now >= due {
+                    break;
+                }
+                std::thread::sleep(POLL_INTERVAL.min(due - now));
+            }
+
+            let event = record.event.clone();
+            match event {
+                RecordedEvent::Start | RecordedEvent::WindowOpen(_) => continue,
+                RecordedEvent::Stop => {
+                    events.request_stop();
+                    return;
+                }
+                _ => {}
+            }
+
+            let windows = self.windows.lock().unwrap().clone();
+            let fut = events.execute_discard(move || unsafe {
+                // SAFETY: The task runs on the Event Thread, while the Event Loop is running.
+                let events = event_loop::EVENTLOOP.unwrap();
+                event.dispatch(events, &windows);
+            });
+
+            // Tasks are not run in order, so each callback must be delivered before the next one is sent.
+            while fut.wait_timeout(POLL_INTERVAL).is_pending() {
+                if !events.is_running() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for ReplayEventLoop<'_> {
+    fn start(&self, events: &EventLoop) {
+        self.handler.start(events);
+    }
+
+    fn stop(&self, events: &EventLoop) {
+        self.handler.stop(events);
+    }
+
+    fn window_open(&self, events: &EventLoop, handle: WindowHandle) {
+        self.windows.lock().unwrap().id(handle);
+        self.handler.window_open(events, handle);
+    }
+
+    fn window_close(&self, events: &EventLoop, handle: WindowHandle) {
+        self.handler.window_close(events, handle);
+    }
+
+    fn window_redraw(&self, events: &EventLoop, handle: WindowHandle) {
+        self.handler.window_redraw(events, handle);
+    }
+
+    fn window_reposition(&self, events: &EventLoop, handle: WindowHandle) {
+        self.handler.window_reposition(events, handle);
+    }
+
+    fn scale_factor_changed(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        scale_factor: ScaleFactor,
+    ) {
+        self.handler
+            .scale_factor_changed(events, handle, scale_factor);
+    }
+
+    fn screens_changed(&self, events: &EventLoop) {
+        self.handler.screens_changed(events);
+    }
+
+    fn window_visibility(&self, events: &EventLoop, handle: WindowHandle, visible: bool) {
+        self.handler.window_visibility(events, handle, visible);
+    }
+
+    fn window_focus(&self, events: &EventLoop, handle: WindowHandle, focused: bool) {
+        self.handler.window_focus(events, handle, focused);
+    }
+
+    fn cursor_move(&self, events: &EventLoop, handle: WindowHandle, point: Point) {
+        self.handler.cursor_move(events, handle, point);
+    }
+
+    fn cursor_enter(&self, events: &EventLoop, handle: WindowHandle) {
+        self.handler.cursor_enter(events, handle);
+    }
+
+    fn cursor_leave(&self, events: &EventLoop, handle: WindowHandle) {
+        self.handler.cursor_leave(events, handle);
+    }
+
+    fn raw_motion(&self, events: &EventLoop, delta_x: f64, delta_y: f64) {
+        self.handler.raw_motion(events, delta_x, delta_y);
+    }
+
+    fn scroll_wheel(&self, events: &EventLoop, handle: WindowHandle, delta_x: f64, delta_y: f64) {
+        self.handler.scroll_wheel(events, handle, delta_x, delta_y);
+    }
+
+    fn button_press(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        button: MouseButton,
+        pressed: bool,
+    ) {
+        self.handler.button_press(events, handle, button, pressed);
+    }
+
+    fn touch_begin(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {
+        self.handler.touch_begin(events, handle, id, point);
+    }
+
+    fn touch_move(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {
+        self.handler.touch_move(events, handle, id, point);
+    }
+
+    fn touch_end(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {
+        self.handler.touch_end(events, handle, id, point);
+    }
+
+    fn touch_cancel(&self, events: &EventLoop, handle: WindowHandle, id: TouchId, point: Point) {
+        self.handler.touch_cancel(events, handle, id, point);
+    }
+
+    fn pen(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        point: Point,
+        pressure: f64,
+        tilt: (f64, f64),
+        buttons: PenButtons,
+    ) {
+        self.handler
+            .pen(events, handle, point, pressure, tilt, buttons);
+    }
+
+    fn key_press(&self, events: &EventLoop, handle: WindowHandle, event: KeyEvent, pressed: bool) {
+        self.handler.key_press(events, handle, event, pressed);
+    }
+
+    fn character_input(&self, events: &EventLoop, handle: WindowHandle, character: char) {
+        self.handler.character_input(events, handle, character);
+    }
+
+    fn ime_preedit(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        text: String,
+        cursor_range: Option<Range<usize>>,
+    ) {
+        self.handler.ime_preedit(events, handle, text, cursor_range);
+    }
+
+    fn ime_commit(&self, events: &EventLoop, handle: WindowHandle, text: String) {
+        self.handler.ime_commit(events, handle, text);
+    }
+
+    fn file_drop_hover(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+        self.handler.file_drop_hover(events, handle, paths, point);
+    }
+
+    fn file_drop(
+        &self,
+        events: &EventLoop,
+        handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+        self.handler.file_drop(events, handle, paths, point);
+    }
+
+    fn file_drop_cancel(&self, events: &EventLoop, handle: WindowHandle) {
+        self.handler.file_drop_cancel(events, handle);
+    }
+
+    fn gamepad_connected(&self, events: &EventLoop, id: GamepadId) {
+        self.handler.gamepad_connected(events, id);
+    }
+
+    fn gamepad_disconnected(&self, events: &EventLoop, id: GamepadId) {
+        self.handler.gamepad_disconnected(events, id);
+    }
+
+    fn gamepad_button(
+        &self,
+        events: &EventLoop,
+        id: GamepadId,
+        button: GamepadButton,
+        pressed: bool,
+    ) {
+        self.handler.gamepad_button(events, id, button, pressed);
+    }
+
+    fn gamepad_axis(&self, events: &EventLoop, id: GamepadId, axis: GamepadAxis, value: f32) {
+        self.handler.gamepad_axis(events, id, axis, value);
+    }
+}
+
+// ================================================================================================================================ //
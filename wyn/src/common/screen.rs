@@ -0,0 +1,97 @@
+/*
+ *  Crate: Wyn
+ * Module: Common - Screen
+ */
+
+//! Platform-independent types for describing the Video Modes and orientation of Screens.
+//!
+//! Each platform provides the `Screen` functions that report them, and the `Window` functions that switch Video Modes.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::types::Size;
+
+use std::sync::Mutex;
+
+// ================================================================================================================================ //
+
+/// A resolution and refresh rate that a Screen can be driven at.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct VideoMode {
+    /// The resolution, in Physical Pixels.
+    pub size: Size,
+
+    /// The refresh rate, in Hertz (`0.0` if it is unknown).
+    pub refresh_rate: f64,
+
+    /// The number of bits per pixel (`0` if it is unknown).
+    pub bit_depth: u32,
+}
+
+impl VideoMode {
+    /// Returns the Video Mode from the list that is closest to this one.\
+    /// Only Video Modes with the same resolution are considered, preferring the same bit depth and the closest refresh rate.
+    pub fn closest_in<'a>(&self, modes: &'a [VideoMode]) -> Option<&'a VideoMode> {
+        modes
+            .iter()
+            .filter(|mode| mode.size == self.size)
+            .min_by(|a, b| {
+                let key = |mode: &VideoMode| {
+                    let depth = u32::from(mode.bit_depth != self.bit_depth);
+                    (depth, (mode.refresh_rate - self.refresh_rate).abs())
+                };
+                key(a)
+                    .partial_cmp(&key(b))
+                    .unwrap_or(core::cmp::Ordering::Equal)
+            })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The rotation of a Screen, clockwise from its natural orientation.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Rotation {
+    /// The natural orientation.
+    #[default]
+    Deg0,
+    /// Rotated a quarter-turn clockwise.
+    Deg90,
+    /// Upside-down.
+    Deg180,
+    /// Rotated a quarter-turn counter-clockwise.
+    Deg270,
+}
+
+impl Rotation {
+    /// Returns whether or not the width and height of the Screen are swapped, compared to its natural orientation.
+    pub fn is_sideways(&self) -> bool {
+        matches!(self, Self::Deg90 | Self::Deg270)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The information of every Screen, as it was last reported.\
+/// Platforms that send the same change several times (e.g. once per Window) use it to only report actual changes.
+#[derive(Default)]
+pub(crate) struct ScreenSnapshot<T>(Mutex<Vec<T>>);
+
+impl<T: PartialEq> ScreenSnapshot<T> {
+    /// Replaces the snapshot, returning whether or not it changed.
+    #[allow(unused)]
+    pub(crate) fn update(&self, infos: Vec<T>) -> bool {
+        let mut snapshot = self.0.lock().unwrap();
+        if *snapshot == infos {
+            false
+        } else {
+            *snapshot = infos;
+            true
+        }
+    }
+}
+
+// ================================================================================================================================ //
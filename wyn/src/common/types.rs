@@ -4,6 +4,10 @@
  */
 
 //! Basic types for handling Points, Sizes, and Rectangles.
+//!
+//! Wyn reports (and expects) every position and size in Physical Pixels, as they are on the Screen.\
+//! On HiDPI Screens, a Scale Factor (e.g. `2.0`) is applied to UIs, so that they are not rendered tiny.
+//! Logical Pixels are Physical Pixels divided by the Scale Factor, and are converted with `to_logical`/`to_physical`.
 
 // ================================================================================================================================ //
 
@@ -13,6 +17,9 @@ pub type Coord = f64;
 /// Type for 2-D Extents.
 pub type Extent = f64;
 
+/// Type for the ratio of Physical Pixels to Logical Pixels (`1.0` at 96 DPI).
+pub type ScaleFactor = f64;
+
 // ================================================================================================================================ //
 
 /// A 2-Dimensional (x, y) point.
@@ -29,6 +36,16 @@ impl Point {
     pub const fn new(x: Coord, y: Coord) -> Self {
         Self { x, y }
     }
+
+    /// Converts this Point from Physical Pixels to Logical Pixels.
+    pub fn to_logical(&self, scale_factor: ScaleFactor) -> Self {
+        Self::new(self.x / scale_factor, self.y / scale_factor)
+    }
+
+    /// Converts this Point from Logical Pixels to Physical Pixels.
+    pub fn to_physical(&self, scale_factor: ScaleFactor) -> Self {
+        Self::new(self.x * scale_factor, self.y * scale_factor)
+    }
 }
 
 // ================================================================================================================================ //
@@ -47,6 +64,16 @@ impl Size {
     pub const fn new(w: Extent, h: Extent) -> Self {
         Self { w, h }
     }
+
+    /// Converts this Size from Physical Pixels to Logical Pixels.
+    pub fn to_logical(&self, scale_factor: ScaleFactor) -> Self {
+        Self::new(self.w / scale_factor, self.h / scale_factor)
+    }
+
+    /// Converts this Size from Logical Pixels to Physical Pixels.
+    pub fn to_physical(&self, scale_factor: ScaleFactor) -> Self {
+        Self::new(self.w * scale_factor, self.h * scale_factor)
+    }
 }
 
 // ================================================================================================================================ //
@@ -90,6 +117,30 @@ impl Rect {
     pub fn aspect(&self) -> f64 {
         self.size.w / self.size.h
     }
+
+    /// Returns whether or not the Point lies within this Rect (including its Top-Left edges, excluding its Bottom-Right edges).
+    pub fn contains(&self, point: Point) -> bool {
+        let inside_x = point.x >= self.origin.x && point.x < self.origin.x + self.size.w;
+        let inside_y = point.y >= self.origin.y && point.y < self.origin.y + self.size.h;
+
+        inside_x && inside_y
+    }
+
+    /// Converts this Rect from Physical Pixels to Logical Pixels.
+    pub fn to_logical(&self, scale_factor: ScaleFactor) -> Self {
+        Self {
+            origin: self.origin.to_logical(scale_factor),
+            size: self.size.to_logical(scale_factor),
+        }
+    }
+
+    /// Converts this Rect from Logical Pixels to Physical Pixels.
+    pub fn to_physical(&self, scale_factor: ScaleFactor) -> Self {
+        Self {
+            origin: self.origin.to_physical(scale_factor),
+            size: self.size.to_physical(scale_factor),
+        }
+    }
 }
 
 // ================================================================================================================================ //
@@ -0,0 +1,48 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless - Clipboard
+ */
+
+//! Reading and writing the Clipboard.
+//!
+//! The Headless backend keeps its Clipboard in memory, so it is only shared within the process.\
+//! The Primary Selection is not supported, so it is always empty.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::*;
+use super::event_loop::EventLoop;
+
+pub use crate::common::clipboard::*;
+
+// ================================================================================================================================ //
+
+impl Clipboard {
+    /// Returns the text on the Clipboard, or `None` if it is empty (or does not hold text).
+    pub fn get_text(self, events: &EventLoop) -> HeadlessResult<Option<String>> {
+        let data = events.clipboards.get(self, MIME_TEXT);
+        Ok(data.map(|data| String::from_utf8_lossy(&data).into_owned()))
+    }
+
+    /// Places the text on the Clipboard, replacing its previous contents.
+    pub fn set_text(self, events: &EventLoop, text: &str) -> HeadlessResult<()> {
+        events.clipboards.set(self, MIME_TEXT, text.as_bytes());
+        Ok(())
+    }
+
+    /// Returns the data of the given MIME type on the Clipboard, or `None` if it does not hold that type.
+    pub fn get(self, events: &EventLoop, mime: &str) -> HeadlessResult<Option<Vec<u8>>> {
+        Ok(events.clipboards.get(self, mime))
+    }
+
+    /// Places data of the given MIME type on the Clipboard, replacing its previous contents.
+    pub fn set(self, events: &EventLoop, mime: &str, data: &[u8]) -> HeadlessResult<()> {
+        events.clipboards.set(self, mime, data);
+        Ok(())
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,17 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless - Cursor
+ */
+
+//! Types for changing the appearance of the Mouse-Cursor.
+//!
+//! The Headless backend has no Cursor to draw, so the appearance of each Window's Cursor is only kept in memory.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+pub use crate::common::cursor::*;
+
+// ================================================================================================================================ //
@@ -0,0 +1,40 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless - Errors
+ */
+
+//! Errors reported by the Headless backend.
+//!
+//! There is no display server that could fail, so the only errors are requests on Windows that no longer exist.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use std::error::Error;
+
+// ================================================================================================================================ //
+
+/// Errors reported by the Headless backend.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum HeadlessError {
+    /// The Window was already closed.
+    WindowClosed,
+}
+
+/// A `Result` where the error type is a `HeadlessError`.
+pub type HeadlessResult<T> = Result<T, HeadlessError>;
+
+impl Error for HeadlessError {}
+
+impl Display for HeadlessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WindowClosed => write!(f, "Headless Error (The Window was already closed)"),
+        }
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,933 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless - Event Loop
+ */
+
+//! The Event Loop for the Headless backend.
+//!
+//! Requests on Windows are applied to the in-memory state immediately (from any thread),
+//! while the events they cause are queued as `HeadlessEvent`s, and reported to the `EventHandler` by the Event Thread,
+//! in the same way a display server would report them.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::clipboard::LocalClipboards;
+use super::errors::*;
+use super::events::{Capability, EventHandler, GamepadUpdate, HeadlessInput, TouchUpdate};
+use super::inputs::*;
+use super::screen::{VideoMode, SCREEN_RECT, SCREEN_VIDEO_MODES};
+use super::types::{Point, Rect};
+use super::window::{HeadlessWindow, Window, WindowHandle};
+use crate::tasks::{ExecFuture, Task};
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard, TryLockError};
+use std::thread::ThreadId;
+
+// ================================================================================================================================ //
+
+/// Holds the state/context required to run a native Event/Message Loop.\
+/// Only one `EventLoop` may exist at any given moment, and it may or may not be running.
+pub struct EventLoop<'a> {
+    /// The Event Handler that responds to events.
+    pub(crate) handler: &'a dyn EventHandler,
+
+    /// A list of callback functions awaiting to be executed.
+    pub(crate) tasks: Mutex<Vec<Task>>,
+
+    /// Flag to indicate whether the Event Loop is running or not.
+    running: AtomicBool,
+
+    /// Condition Variable for other threads to await Event Loop startup/termination.
+    condvar: Condvar,
+
+    /// The Thread-ID for the Event Thread.
+    thread_id: ThreadId,
+
+    /// The in-memory state of every Window and Gamepad.
+    pub(crate) state: Mutex<HeadlessState>,
+
+    /// The requests that wake the Event Thread while it waits.
+    signals: Mutex<HeadlessSignals>,
+
+    /// Condition Variable for waking the Event Thread.
+    signals_condvar: Condvar,
+
+    /// The in-memory Clipboards.
+    pub(crate) clipboards: LocalClipboards,
+
+    #[allow(unused)]
+    /// Lock that guarantees Exclusive-Access to the Event Thread.
+    lock: MutexGuard<'a, ()>,
+}
+
+// [These trait implementations are implicit, but noted here for clarity]
+// impl !Send for EventLoop<'_> {}
+// impl Sync for EventLoop<'_> {}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Mutex to prevent creating multiple `EventLoop` objects at the same time.
+static EVENTLOOP_MUTEX: Mutex<()> = Mutex::new(());
+
+/// Reference to the currently running EventLoop.\
+/// Lifetime is tied to an `EventLoopGuard` object in the `EventLoop::run` function.\
+/// ## SAFETY
+/// Should only ever be accessed by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.\
+/// Treat as Mutable in `EventLoopGuard` code, and Immutable elsewhere.
+pub(crate) static mut EVENTLOOP: Option<&'static EventLoop<'static>> = None;
+
+/// Drop-Guard for an `EventLoop`.\
+/// Initializes the static `EventLoop` on creation, and Removes it when dropped.
+struct EventLoopGuard<'a> {
+    /// The actual `EventLoop` whose reference is temporarily placed at static scope.
+    events: &'a EventLoop<'a>,
+}
+
+impl<'a> EventLoopGuard<'a> {
+    /// Constructs a new `EventLoopGuard`.
+    /// ## PANICS
+    /// * Panics if the Calling Thread is not the Event Thread.
+    fn new(events: &'a EventLoop<'a>) -> Self {
+        if !events.is_this_thread() {
+            panic!("Attempted to run Event Loop while not on the Event Thread!");
+        }
+
+        // `transmute` in order to cast to `'static` lifetime.
+        // SAFETY: This reference is tied to the lifetime of this object, and is removed when this object is dropped.
+        let static_ref = unsafe { transmute(events) };
+
+        // SAFETY: We are on the Event Thread and have Exclusive-Access (via the EventLoop's lock).
+        let _ = unsafe { EVENTLOOP.insert(static_ref) };
+
+        Self { events }
+    }
+}
+
+impl<'a> Drop for EventLoopGuard<'a> {
+    fn drop(&mut self) {
+        self.events.set_running(false);
+
+        // SAFETY: We are on the Event Thread and have Exclusive-Access (via the EventLoop's lock).
+        let _ = unsafe { EVENTLOOP.take() };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The `panic` that was caught in an `EventHandler` callback.\
+/// Lifetime is tied to an `EventLoopGuard` object in the `EventLoop::run` function.\
+/// ## SAFETY
+/// Should only ever be accessed by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.
+static mut PANIC: Option<BoxedPanic> = None;
+
+/// Flag to indicate a Panic is occuring to other threads.
+static PANIC_FLAG: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether or not there was a Panic stored.
+fn is_panicking() -> bool {
+    PANIC_FLAG.load(Ordering::Acquire)
+}
+
+/// Stores a `panic`, so that other threads waiting on the Event Loop are woken up before it resumes.\
+/// If a second `panic` is attempted to be stored while a previous one is stored, the process will abort.
+/// ## SAFETY
+/// Should only ever be called by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.
+unsafe fn store_panic(err: BoxedPanic) {
+    match &mut PANIC {
+        // There was not a previous `panic`, so store this one and tell the Event Loop to shut down.
+        None => {
+            let _ = PANIC.insert(err);
+            PANIC_FLAG.store(true, Ordering::Release);
+
+            if let Some(events) = EVENTLOOP {
+                events.request_stop();
+                events.condvar.notify_all();
+            }
+        }
+        // There was already a previous `panic`, so abort the process.
+        Some(_err) => std::process::abort(),
+    };
+}
+
+/// Resumes a `panic` that was caught earlier, if there is one.
+/// ## SAFETY
+/// Should only ever be called by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.
+unsafe fn resume_if_panicking() {
+    if let Some(err) = PANIC.take() {
+        std::panic::resume_unwind(err);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl<'a> EventLoop<'a> {
+    /// Constructs a new `EventLoop`, using the provided `EventHandler`.\
+    /// Every `EventLoop` starts out with no Windows and no Gamepads.
+    /// ## PANICS
+    /// * Panics if another `EventLoop` object exists and holds the Event Thread lock.
+    /// * Panics if a previous `EventLoop` panicked while holding the Event Thread lock.
+    pub fn new(handler: &'a dyn EventHandler) -> HeadlessResult<Self> {
+        let lock = match EVENTLOOP_MUTEX.try_lock() {
+            Ok(lock) => lock,
+            Err(TryLockError::WouldBlock) => {
+                panic!("Attempted to create multiple Event Loops simultaneously!")
+            }
+            Err(TryLockError::Poisoned(_)) => {
+                panic!("Attempted to create a poisoned Event Loop after panic!")
+            }
+        };
+
+        let tasks = Mutex::new(Vec::new());
+        let running = AtomicBool::new(false);
+        let condvar = Condvar::new();
+        let thread_id = std::thread::current().id();
+
+        let state = Mutex::new(HeadlessState::new());
+        let signals = Mutex::new(HeadlessSignals::default());
+        let signals_condvar = Condvar::new();
+        let clipboards = LocalClipboards::default();
+
+        Ok(Self {
+            handler,
+            tasks,
+            running,
+            condvar,
+            thread_id,
+            state,
+            signals,
+            signals_condvar,
+            clipboards,
+            lock,
+        })
+    }
+}
+
+// ================================================================================================================================ //
+
+impl EventLoop<'_> {
+    /// Starts a native Event/Message loop.
+    /// ## PANICS
+    /// * Panics if the Calling Thread is not the Event Thread.
+    /// * Panics if User-Code panics in an `EventHandler` callback.
+    pub fn run(&self) {
+        // SAFETY: This function is called only on the Event Thread.
+        let guard = EventLoopGuard::new(self);
+
+        // SAFETY: This function is called while holding an `EventLoopGuard`.
+        let func = || unsafe { guard.events.internal_run() };
+
+        // Catch the `panic` so that other threads waiting on the Event Loop are woken up before it resumes.
+        if let Err(err) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(func)) {
+            // SAFETY: This function is called while holding an `EventLoopGuard`.
+            unsafe { store_panic(err) };
+        }
+
+        drop(guard);
+
+        // SAFETY: This function is called on the Event Thread, which still holds the EVENTLOOP_MUTEX.
+        unsafe { resume_if_panicking() };
+    }
+
+    /// The internal native Event/Message Loop.
+    /// ## SAFETY
+    /// * Must be called on the Event Thread to function properly.
+    /// * Must be called while holding an `EventLoopGuard` to ensure the validity of the static EVENTLOOP.
+    unsafe fn internal_run(&self) {
+        self.handler.start(self);
+        self.set_running(true);
+
+        loop {
+            // Tasks and Events may have been queued before waiting (e.g. by User-Code in `start`, or by other Tasks).
+            self.clear_tasks();
+            self.dispatch_queue();
+
+            if !self.wait_signals() {
+                break;
+            }
+        }
+
+        self.handler.stop(self);
+    }
+
+    /// Sleeps the Event Thread until it is signalled.\
+    /// Returns `false` once the Event Loop should terminate.
+    fn wait_signals(&self) -> bool {
+        let guard = self.signals.lock().unwrap();
+        let mut signals = self
+            .signals_condvar
+            .wait_while(guard, |signals| !signals.exec && !signals.quit)
+            .unwrap();
+
+        // The signals are consumed, so that a stopped Event Loop can be run again.
+        let quit = signals.quit;
+        *signals = HeadlessSignals::default();
+
+        !quit
+    }
+
+    /// Reports every queued `HeadlessEvent` to the Event Handler, until the queue is empty.
+    fn dispatch_queue(&self) {
+        loop {
+            // The lock must be released before dispatching, since the Event Handler may call into Windows.
+            let queue = std::mem::take(&mut self.state.lock().unwrap().queue);
+            if queue.is_empty() {
+                break;
+            }
+
+            for event in queue {
+                self.dispatch_event(event);
+            }
+        }
+    }
+
+    /// Reports a single `HeadlessEvent` to the Event Handler.\
+    /// Events on Windows that were closed after the event was queued are dropped.
+    fn dispatch_event(&self, event: HeadlessEvent) {
+        match event {
+            HeadlessEvent::WindowRedraw(handle) if self.is_open(handle) => {
+                self.handler.window_redraw(self, handle);
+            }
+            HeadlessEvent::WindowReposition(handle) if self.is_open(handle) => {
+                self.handler.window_reposition(self, handle);
+            }
+            HeadlessEvent::WindowVisibility(handle, visible) if self.is_open(handle) => {
+                self.handler.window_visibility(self, handle, visible);
+            }
+            HeadlessEvent::WindowFocus(handle, focused) if self.is_open(handle) => {
+                self.handler.window_focus(self, handle, focused);
+            }
+            HeadlessEvent::ScreensChanged => {
+                self.handler.screens_changed(self);
+            }
+            HeadlessEvent::Input(input) => {
+                self.dispatch_input(input);
+            }
+            _ => {}
+        }
+    }
+
+    /// Reports a single injected `HeadlessInput` to the Event Handler.\
+    /// Keyboard and Mouse Inputs are applied to the state that `EventLoop::key_state` (and the like) query beforehand,
+    /// as a display server has done by the time it reports them.
+    fn dispatch_input(&self, input: HeadlessInput) {
+        self.state.lock().unwrap().update_devices(&input);
+
+        match input {
+            HeadlessInput::WindowClose(handle) => {
+                let _ = Window::internal_close(self, handle);
+            }
+            HeadlessInput::ScaleFactorChanged(handle, scale_factor) => {
+                let changed = {
+                    let mut state = self.state.lock().unwrap();
+                    let window = state.window_mut(handle);
+                    window.is_some_and(|window| window.set_scale_factor(scale_factor))
+                };
+
+                // Like a display server, only actual changes are reported.
+                if changed {
+                    self.handler
+                        .scale_factor_changed(self, handle, scale_factor);
+                }
+            }
+            HeadlessInput::CursorMove(handle, point) if self.is_open(handle) => {
+                self.handler.cursor_move(self, handle, point);
+            }
+            HeadlessInput::CursorEnter(handle) if self.is_open(handle) => {
+                self.handler.cursor_enter(self, handle);
+            }
+            HeadlessInput::CursorLeave(handle) if self.is_open(handle) => {
+                self.handler.cursor_leave(self, handle);
+            }
+            HeadlessInput::RawMotion(delta_x, delta_y) => {
+                self.handler.raw_motion(self, delta_x, delta_y);
+            }
+            HeadlessInput::ScrollWheel(handle, delta_x, delta_y) if self.is_open(handle) => {
+                self.handler.scroll_wheel(self, handle, delta_x, delta_y);
+            }
+            HeadlessInput::ButtonPress(handle, button, pressed) if self.is_open(handle) => {
+                self.handler.button_press(self, handle, button, pressed);
+            }
+            HeadlessInput::Touch(handle, phase, id, point) if self.is_open(handle) => {
+                let touch = TouchUpdate {
+                    handle,
+                    phase,
+                    id,
+                    point,
+                };
+                touch.dispatch(self);
+            }
+            HeadlessInput::Pen(handle, point, pressure, tilt, buttons) if self.is_open(handle) => {
+                self.handler
+                    .pen(self, handle, point, pressure, tilt, buttons);
+            }
+            HeadlessInput::KeyPress(handle, event, pressed) if self.is_open(handle) => {
+                self.handler.key_press(self, handle, event, pressed);
+            }
+            HeadlessInput::CharacterInput(handle, character) if self.is_open(handle) => {
+                self.handler.character_input(self, handle, character);
+            }
+            HeadlessInput::ImePreedit(handle, text, cursor_range) if self.allows_ime(handle) => {
+                self.handler.ime_preedit(self, handle, text, cursor_range);
+            }
+            HeadlessInput::ImeCommit(handle, text) if self.allows_ime(handle) => {
+                self.handler.ime_commit(self, handle, text);
+            }
+            HeadlessInput::FileDropHover(handle, paths, point) if self.is_open(handle) => {
+                self.handler.file_drop_hover(self, handle, paths, point);
+            }
+            HeadlessInput::FileDrop(handle, paths, point) if self.is_open(handle) => {
+                self.handler.file_drop(self, handle, paths, point);
+            }
+            HeadlessInput::FileDropCancel(handle) if self.is_open(handle) => {
+                self.handler.file_drop_cancel(self, handle);
+            }
+            // Inputs on closed Windows fall through as well, and are ignored here.
+            input => {
+                // The lock must be released before dispatching, since the Event Handler may collect the Gamepads.
+                let update = self.state.lock().unwrap().update_gamepad(input);
+                if let Some(update) = update {
+                    update.dispatch(self);
+                }
+            }
+        }
+    }
+
+    /// Returns whether or not the Window is still open.
+    fn is_open(&self, handle: WindowHandle) -> bool {
+        self.state.lock().unwrap().window_mut(handle).is_some()
+    }
+
+    /// Returns whether or not the Window is still open, and allows Input Methods.
+    fn allows_ime(&self, handle: WindowHandle) -> bool {
+        let mut state = self.state.lock().unwrap();
+        state
+            .window_mut(handle)
+            .is_some_and(|window| window.is_ime_allowed())
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventLoop<'_> {
+    /// Returns a boolean indicating whether or not the Calling Thread is the Event Thread.
+    pub fn is_this_thread(&self) -> bool {
+        self.thread_id == std::thread::current().id()
+    }
+
+    /// Returns a boolean indicating whether or not the Event Thread is running an Event/Message Loop.
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Acquire)
+    }
+
+    /// Returns whether or not the optional functionality is supported by this Event Loop.\
+    /// Everything can be injected into the Headless backend, so everything but the shared Clipboards is supported.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::WindowRedraw => true,
+            Capability::WindowVisibility => true,
+            Capability::CharacterInput => true,
+            Capability::Gamepads => true,
+            // The Clipboards are kept in memory, so they are only shared within the process.
+            Capability::Clipboard => false,
+            Capability::PrimarySelection => false,
+            Capability::FileDrop => true,
+            Capability::FileDropHover => true,
+            Capability::CursorControl => true,
+            Capability::RawMotion => true,
+            Capability::ScaleFactorChanged => true,
+            Capability::ScreensChanged => true,
+            Capability::InputMethod => true,
+            Capability::VideoModes => true,
+            Capability::InputState => true,
+            Capability::CursorEnterLeave => true,
+            Capability::Touch => true,
+            Capability::Pen => true,
+        }
+    }
+
+    /// If an Event/Message Loop is running, then request it to terminate.
+    pub fn request_stop(&self) {
+        self.signal(|signals| signals.quit = true);
+    }
+
+    /// Sleep the current thread until the Event Loop is running or it panicked.\
+    /// Returns `false` if the Event Thread panicked.
+    pub fn await_startup(&self) -> bool {
+        /// Dummy Mutex for `CondVar` functions.
+        static MUTEX: Mutex<()> = Mutex::new(());
+
+        let guard = MUTEX.lock().unwrap();
+        let _guard = self.condvar.wait_while(guard, |_| {
+            !is_panicking() && !self.running.load(Ordering::Acquire)
+        });
+
+        !is_panicking()
+    }
+
+    /// Sleep the current thread until the Event Loop is not running or it panicked.\
+    /// Returns `false` if the Event Thread panicked.
+    pub fn await_termination(&self) -> bool {
+        /// Dummy Mutex for `CondVar` functions.
+        static MUTEX: Mutex<()> = Mutex::new(());
+
+        let guard = MUTEX.lock().unwrap();
+        let _guard = self.condvar.wait_while(guard, |_| {
+            !is_panicking() && self.running.load(Ordering::Acquire)
+        });
+
+        !is_panicking()
+    }
+
+    /// Atomically sets the Running-Flag, and wakes any threads awaiting a change.
+    fn set_running(&self, status: bool) {
+        self.running.store(status, Ordering::Release);
+        self.condvar.notify_all();
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Functionality relating to executing code on the Event Thread.
+///
+/// Sometimes, there are functions will only work if executed on the Main/Event Thread.
+/// Normally, said thread is under control of the library, and user-code can only execute during `EventHandler` callbacks (which can be limiting).
+/// These functions provide a way for users to temporarily take control of the Main/Event Thread
+/// from a separate thread, allowing them to execute any code that must be run on said thread.
+impl EventLoop<'_> {
+    /// Executes the provided callback function on the Event Thread, and returns the result.
+    pub fn execute<T, F>(&self, callback: F) -> ExecFuture<T>
+    where
+        T: Send + 'static,
+        F: Send + 'static + FnOnce() -> T,
+    {
+        if self.is_this_thread() {
+            let val = callback();
+            ExecFuture::new_sync(Some(val))
+        } else {
+            let (fut_recv, fut_send) = ExecFuture::new_async(None);
+
+            let task = Box::new(move || {
+                let val = callback();
+                fut_send.notify(val);
+            });
+
+            {
+                let mut tasks = self.tasks.lock().unwrap();
+                tasks.push(task);
+            }
+
+            self.signal_tasks();
+
+            fut_recv
+        }
+    }
+
+    /// Executes the provided callback function on the Event Thread, but discards the result.\
+    /// Because the return value is discarded, this removes some limitations (such as requiring `T` to be `Send + 'static`).
+    pub fn execute_discard<T, F>(&self, callback: F) -> ExecFuture<()>
+    where
+        F: Send + 'static + FnOnce() -> T,
+    {
+        if self.is_this_thread() {
+            let _ = callback();
+            ExecFuture::new_sync(Some(()))
+        } else {
+            let (fut_recv, fut_send) = ExecFuture::new_async(None);
+
+            let task = Box::new(move || {
+                let _ = callback();
+                fut_send.notify(());
+            });
+
+            {
+                let mut tasks = self.tasks.lock().unwrap();
+                tasks.push(task);
+            }
+
+            self.signal_tasks();
+
+            fut_recv
+        }
+    }
+
+    /// Executes the provided callback function on the Event Thread, but discards the result.\
+    /// Because the return value is discarded, this removes some limitations (such as requiring `T` to be `Send + 'static`).\
+    /// By not synchronizing the Calling Thread with the Event Thread, a lot of synchronization overhead is removed.\
+    /// This function will queue the callback function and immediately return, even if run on the Event Thread.
+    pub fn execute_detached<T, F>(&self, callback: F)
+    where
+        F: Send + 'static + FnOnce() -> T,
+    {
+        let task = Box::new(move || {
+            let _ = callback();
+        });
+
+        {
+            let mut tasks = self.tasks.lock().unwrap();
+            tasks.push(task);
+        }
+
+        self.signal_tasks();
+    }
+
+    /// Signals to the Event Thread that there are Tasks that need executed.
+    fn signal_tasks(&self) {
+        self.signal(|signals| signals.exec = true);
+    }
+
+    /// If there are any Tasks waiting to be executed, remove them from the list and call them.
+    fn clear_tasks(&self) {
+        // Must be called from the Event Thread.
+        assert!(self.is_this_thread());
+
+        // Cannot hold the Tasks-lock while executing a task, otherwise a user-callback might cause a deadlock.
+        // As such, the lock must be re-acquired/released for each task.
+        let get_task = || {
+            let mut tasks = self.tasks.lock().unwrap();
+            tasks.pop()
+        };
+
+        // Remove all tasks from the list.
+        while let Some(task) = get_task() {
+            task();
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Headless-exclusive functionality.
+impl EventLoop<'_> {
+    /// Injects a synthetic User-Input, which is reported to the Event Handler by the Event Thread.\
+    /// Inputs are reported in the order they were injected, after any events that were already queued.\
+    /// May be called from any thread (including from within `EventHandler` callbacks), even before the Event Loop is running.
+    pub fn inject(&self, input: HeadlessInput) {
+        self.state
+            .lock()
+            .unwrap()
+            .queue
+            .push(HeadlessEvent::Input(input));
+        self.wake();
+    }
+
+    /// Wakes the Event Thread, so that queued `HeadlessEvent`s are reported without delay.
+    pub(crate) fn wake(&self) {
+        self.signal(|signals| signals.exec = true);
+    }
+
+    /// Updates the signals, and wakes the Event Thread to handle them.
+    fn signal(&self, update: impl FnOnce(&mut HeadlessSignals)) {
+        update(&mut self.signals.lock().unwrap());
+        self.signals_condvar.notify_one();
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Functionality relating to querying the current state of the Keyboard and Mouse.
+///
+/// The state follows the injected Keyboard and Mouse Inputs, so Keys and Mouse-Buttons are held down until they are released.
+impl EventLoop<'_> {
+    /// Returns whether or not the Key is currently held down.
+    pub fn key_state(&self, key: KeyCode) -> bool {
+        self.state.lock().unwrap().keys.contains(&key)
+    }
+
+    /// Returns every Key that is currently held down, in the order they were pressed.
+    pub fn pressed_keys(&self) -> Vec<KeyCode> {
+        self.state.lock().unwrap().keys.clone()
+    }
+
+    /// Returns every Mouse-Button that is currently held down, in the order they were pressed.
+    pub fn mouse_buttons(&self) -> Vec<MouseButton> {
+        self.state.lock().unwrap().buttons.clone()
+    }
+
+    /// Returns the position of the Mouse-Cursor, relative to the Inner-Content of the Window.\
+    /// The Cursor does not need to be over the Window, but `None` is returned if it was never moved (or the Window is closed).
+    pub fn cursor_position(&self, handle: WindowHandle) -> Option<Point> {
+        let mut state = self.state.lock().unwrap();
+        let cursor = state.cursor?;
+        let origin = state.window_mut(handle)?.content().origin;
+        Some(Point::new(cursor.x - origin.x, cursor.y - origin.y))
+    }
+}
+
+// ================================================================================================================================ //
+// ================================================================================================================================ //
+// ================================================================================================================================ //
+
+/// The requests that wake the Event Thread while it waits.
+#[derive(Clone, Copy, Default)]
+struct HeadlessSignals {
+    /// There are Tasks or `HeadlessEvent`s waiting to be handled.
+    exec: bool,
+
+    /// The Event Loop should terminate.
+    quit: bool,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// An event waiting to be reported to the Event Handler.
+#[derive(Clone, Debug)]
+pub(crate) enum HeadlessEvent {
+    /// A Window needs its contents redrawn.
+    WindowRedraw(WindowHandle),
+
+    /// A Window was repositioned (moved/resized).
+    WindowReposition(WindowHandle),
+
+    /// A Window was shown or hidden.
+    WindowVisibility(WindowHandle, bool),
+
+    /// A Window was focused/unfocused.
+    WindowFocus(WindowHandle, bool),
+
+    /// The virtual Screen changed its resolution.
+    ScreensChanged,
+
+    /// A synthetic User-Input was injected.
+    Input(HeadlessInput),
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The in-memory state of every Window and Gamepad, standing in for a display server.
+pub(crate) struct HeadlessState {
+    /// The open Windows, in the order they were opened.
+    pub(crate) windows: Vec<HeadlessWindow>,
+
+    /// The last Window Handle that was assigned (Handles are never reused).
+    last_handle: WindowHandle,
+
+    /// The Focused Window, if any.
+    focus: Option<WindowHandle>,
+
+    /// The connected Gamepads, in the order they were connected.
+    pub(crate) gamepads: Vec<Gamepad>,
+
+    /// The events waiting to be reported to the Event Handler.
+    pub(crate) queue: Vec<HeadlessEvent>,
+
+    /// The Exclusive Fullscreen Window, along with the Video Mode it switched the virtual Screen to.
+    exclusive: Option<(WindowHandle, VideoMode)>,
+
+    /// The Keys held down, in the order they were pressed.
+    keys: Vec<Key>,
+
+    /// The Mouse-Buttons held down, in the order they were pressed.
+    buttons: Vec<MouseButton>,
+
+    /// The position of the Mouse-Cursor on the virtual Screen, once it was moved.
+    cursor: Option<Point>,
+}
+
+// ---------------------------------------------------------------- //
+
+impl HeadlessState {
+    /// Constructs a new `HeadlessState`, with no Windows and no Gamepads.
+    fn new() -> Self {
+        Self {
+            windows: Vec::new(),
+            last_handle: 0,
+            focus: None,
+            gamepads: Vec::new(),
+            queue: Vec::new(),
+            exclusive: None,
+            keys: Vec::new(),
+            buttons: Vec::new(),
+            cursor: None,
+        }
+    }
+
+    /// Assigns a new Window Handle.
+    pub(crate) fn next_handle(&mut self) -> WindowHandle {
+        self.last_handle = self
+            .last_handle
+            .checked_add(1)
+            .expect("Ran out of Window Handles.");
+        self.last_handle
+    }
+
+    /// Returns the state of the Window, if it is still open.
+    pub(crate) fn window_mut(&mut self, handle: WindowHandle) -> Option<&mut HeadlessWindow> {
+        self.windows
+            .iter_mut()
+            .find(|window| window.handle == handle)
+    }
+
+    /// Removes the Window, if it is still open.\
+    /// A closing Window loses its Focus without reporting it, as its handle is given up.
+    pub(crate) fn remove_window(&mut self, handle: WindowHandle) -> Option<HeadlessWindow> {
+        if self.focus == Some(handle) {
+            self.focus = None;
+        }
+        self.release_video_mode(handle);
+
+        let index = self
+            .windows
+            .iter()
+            .position(|window| window.handle == handle)?;
+        Some(self.windows.remove(index))
+    }
+
+    /// Returns the current Video Mode of the virtual Screen.
+    pub(crate) fn video_mode(&self) -> VideoMode {
+        self.exclusive
+            .map_or(SCREEN_VIDEO_MODES[0], |(_, mode)| mode)
+    }
+
+    /// Returns the Bounding-Rectangle of the virtual Screen, which is resized by its current Video Mode.
+    pub(crate) fn screen_rect(&self) -> Rect {
+        let size = self.video_mode().size;
+        Rect {
+            origin: SCREEN_RECT.origin,
+            size,
+        }
+    }
+
+    /// Switches the virtual Screen to a Video Mode, on behalf of an Exclusive Fullscreen Window.
+    pub(crate) fn switch_video_mode(&mut self, handle: WindowHandle, mode: VideoMode) {
+        let previous = self.video_mode();
+        self.exclusive = Some((handle, mode));
+        self.queue_screens_changed(previous);
+    }
+
+    /// Switches the virtual Screen back to its default Video Mode, if the Window is the Exclusive Fullscreen Window.
+    pub(crate) fn release_video_mode(&mut self, handle: WindowHandle) {
+        if self
+            .exclusive
+            .is_some_and(|(exclusive, _)| exclusive == handle)
+        {
+            let previous = self.video_mode();
+            self.exclusive = None;
+            self.queue_screens_changed(previous);
+        }
+    }
+
+    /// Queues `HeadlessEvent::ScreensChanged`, if the virtual Screen is no longer in the previous Video Mode.
+    fn queue_screens_changed(&mut self, previous: VideoMode) {
+        if self.video_mode() != previous {
+            self.queue.push(HeadlessEvent::ScreensChanged);
+        }
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Returns the Focused Window, if any.
+    pub(crate) fn focus(&self) -> Option<WindowHandle> {
+        self.focus
+    }
+
+    /// Moves the Focus to another Window (or to no Window), queueing the changes.
+    pub(crate) fn set_focus(&mut self, focus: Option<WindowHandle>) {
+        if self.focus == focus {
+            return;
+        }
+
+        if let Some(handle) = self.focus {
+            self.queue.push(HeadlessEvent::WindowFocus(handle, false));
+        }
+        if let Some(handle) = focus {
+            self.queue.push(HeadlessEvent::WindowFocus(handle, true));
+        }
+
+        self.focus = focus;
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Applies an injected Keyboard or Mouse Input to the held Keys, held Mouse-Buttons, and the Mouse-Cursor.\
+    /// Like the Event Handler, the state ignores Inputs on closed Windows.
+    fn update_devices(&mut self, input: &HeadlessInput) {
+        match *input {
+            HeadlessInput::CursorMove(handle, point) => {
+                if let Some(window) = self.window_mut(handle) {
+                    let origin = window.content().origin;
+                    self.cursor = Some(Point::new(origin.x + point.x, origin.y + point.y));
+                }
+            }
+            HeadlessInput::ButtonPress(handle, button, pressed)
+                if self.window_mut(handle).is_some() =>
+            {
+                update_held(&mut self.buttons, button, pressed);
+            }
+            HeadlessInput::KeyPress(handle, event, pressed)
+                if self.window_mut(handle).is_some() =>
+            {
+                update_held(&mut self.keys, event.key, pressed);
+            }
+            _ => {}
+        }
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Applies an injected Gamepad Input to the connected Gamepads, returning the change to report (if any).
+    fn update_gamepad(&mut self, input: HeadlessInput) -> Option<GamepadUpdate> {
+        match input {
+            HeadlessInput::GamepadConnected(id) => {
+                if self.gamepads.iter().any(|gamepad| gamepad.id() == id) {
+                    return None;
+                }
+
+                let gamepad = Gamepad::new(id);
+                self.gamepads.push(gamepad);
+                Some(GamepadUpdate::Connected(gamepad))
+            }
+            HeadlessInput::GamepadDisconnected(id) => {
+                let index = self
+                    .gamepads
+                    .iter()
+                    .position(|gamepad| gamepad.id() == id)?;
+                self.gamepads.remove(index);
+                Some(GamepadUpdate::Disconnected(id))
+            }
+            HeadlessInput::GamepadButton(id, button, pressed) => {
+                self.change_gamepad(id, |gamepad| gamepad.set_button(button, pressed))
+            }
+            HeadlessInput::GamepadAxis(id, axis, value) => {
+                self.change_gamepad(id, |gamepad| gamepad.set_axis(axis, value))
+            }
+            _ => None,
+        }
+    }
+
+    /// Changes the state of a connected Gamepad, returning the change to report.
+    fn change_gamepad(
+        &mut self,
+        id: GamepadId,
+        change: impl FnOnce(&mut Gamepad),
+    ) -> Option<GamepadUpdate> {
+        let gamepad = self
+            .gamepads
+            .iter_mut()
+            .find(|gamepad| gamepad.id() == id)?;
+        let prev = *gamepad;
+
+        // The Packet-Number changes with every Input, like on the native backends.
+        change(gamepad);
+        gamepad.set_packet(prev.packet().wrapping_add(1));
+
+        Some(GamepadUpdate::Changed(prev, *gamepad))
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Adds a pressed Key (or Mouse-Button) to the held ones, or removes a released one.\
+/// Repeated presses of a held Key do not add it again.
+fn update_held<T: PartialEq>(held: &mut Vec<T>, item: T, pressed: bool) {
+    if !pressed {
+        held.retain(|other| *other != item);
+    } else if !held.contains(&item) {
+        held.push(item);
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,98 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless - Events
+ */
+
+//! Provides Event Handler callback functions, and the synthetic inputs that can be injected into a Headless Event Loop.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+pub use crate::common::events::*;
+
+use super::inputs::{
+    GamepadAxis, GamepadButton, GamepadId, KeyEvent, MouseButton, PenButtons, TouchId, TouchPhase,
+};
+use super::types::{Point, ScaleFactor};
+use super::window::WindowHandle;
+
+use std::ops::Range;
+use std::path::PathBuf;
+
+// ================================================================================================================================ //
+
+/// A synthetic User-Input, injected into the Event Loop with `EventLoop::inject`.
+///
+/// Inputs on Windows that are closed (and Gamepads that are not connected) are ignored, like a display server would.
+#[derive(Clone, PartialEq, Debug)]
+pub enum HeadlessInput {
+    /// The user asked to close a Window (e.g. through its Close-Button).
+    WindowClose(WindowHandle),
+
+    /// The Scale Factor of a Window changed (e.g. it was moved to a Screen with a different DPI).
+    ScaleFactorChanged(WindowHandle, ScaleFactor),
+
+    /// A Mouse-Cursor was moved across a Window.
+    CursorMove(WindowHandle, Point),
+
+    /// A Mouse-Cursor entered a Window.
+    CursorEnter(WindowHandle),
+
+    /// A Mouse-Cursor left a Window.
+    CursorLeave(WindowHandle),
+
+    /// A Mouse was moved by the given (unaccelerated) amounts.
+    RawMotion(f64, f64),
+
+    /// A Scroll-Wheel was scrolled horizontally/vertically on a Window, by (possibly fractional) notches.
+    ScrollWheel(WindowHandle, f64, f64),
+
+    /// A Mouse-Button was pressed/released on a Window.
+    ButtonPress(WindowHandle, MouseButton, bool),
+
+    /// A finger touched, moved across, or was lifted off a Window, reported to the `EventHandler::touch_*` callback of the phase.
+    Touch(WindowHandle, TouchPhase, TouchId, Point),
+
+    /// A Pen moved over a Window, with its pressure, tilt and pressed Buttons.
+    Pen(WindowHandle, Point, f64, (f64, f64), PenButtons),
+
+    /// A Key was pressed/released in a Window.\
+    /// The event is reported as-is, so Modifiers and Auto-Repeat are up to the injector.
+    KeyPress(WindowHandle, KeyEvent, bool),
+
+    /// A Character was input in a Window.
+    CharacterInput(WindowHandle, char),
+
+    /// The text being composed with an Input Method in a Window changed, with the highlighted byte range (if any).\
+    /// Ignored unless the Window allows Input Methods.
+    ImePreedit(WindowHandle, String, Option<Range<usize>>),
+
+    /// An Input Method committed text to a Window.\
+    /// Ignored unless the Window allows Input Methods.
+    ImeCommit(WindowHandle, String),
+
+    /// Files were dragged over a Window, to the given point.
+    FileDropHover(WindowHandle, Vec<PathBuf>, Point),
+
+    /// Files were dropped on a Window, at the given point.
+    FileDrop(WindowHandle, Vec<PathBuf>, Point),
+
+    /// Files that were dragged over a Window left it.
+    FileDropCancel(WindowHandle),
+
+    /// A Gamepad was connected, with nothing pressed.
+    GamepadConnected(GamepadId),
+
+    /// A Gamepad was disconnected.
+    GamepadDisconnected(GamepadId),
+
+    /// A Gamepad Button was pressed/released.
+    GamepadButton(GamepadId, GamepadButton, bool),
+
+    /// A Gamepad Axis was moved to a normalized value.
+    GamepadAxis(GamepadId, GamepadAxis, f32),
+}
+
+// ================================================================================================================================ //
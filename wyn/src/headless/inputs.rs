@@ -0,0 +1,53 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless - Inputs
+ */
+
+//! Types for handling User-Input, such as through Mice/Pointers and Keyboards.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::event_loop::EventLoop;
+
+// ================================================================================================================================ //
+
+/// Native OS Representation for Mouse Buttons.\
+/// Headless Mouse Buttons are numbered from `1`, in the same order as on X11.
+pub type NativeMouseButton = u32;
+
+/// Native OS Representation for Key Codes.\
+/// Injected Key Presses carry a `Key`, so Native Key Codes only appear in `Key::Other`.
+pub type NativeKeyCode = u32;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+pub use crate::common::inputs::*;
+
+/// Translation table between `MouseButton`s and Native Mouse Buttons.
+#[rustfmt::skip]
+pub(crate) const MOUSE_MAP: &[(MouseButton, NativeMouseButton)] = &[
+    (MouseButton::Left,   1),
+    (MouseButton::Middle, 2),
+    (MouseButton::Right,  3),
+    (MouseButton::X1,     8),
+    (MouseButton::X2,     9),
+];
+
+/// Translation table between `Key`s and Native Key Codes.\
+/// There is no keyboard layout to translate through, so the table is empty.
+pub(crate) const KEY_MAP: &[(Key, NativeKeyCode)] = &[];
+
+// ================================================================================================================================ //
+
+impl Gamepad {
+    /// Returns a snapshot of every connected Gamepad.\
+    /// Gamepads are connected (and updated) with `EventLoop::inject`.
+    pub fn collect(events: &EventLoop) -> Vec<Gamepad> {
+        events.state.lock().unwrap().gamepads.clone()
+    }
+}
+
+// ================================================================================================================================ //
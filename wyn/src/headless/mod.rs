@@ -0,0 +1,39 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless
+ */
+
+//! The implementation of Wyn without a display server, keeping every Window and Screen in memory.
+//!
+//! Selected by the `headless` feature, in place of the platform's backend.\
+//! Input never comes from real devices, and is instead injected with `EventLoop::inject`,
+//! which allows applications (and Wyn itself) to be tested with plain `cargo test`.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+// ================================================================================================================================ //
+
+pub mod errors;
+
+pub mod events;
+
+pub mod event_loop;
+
+pub mod screen;
+
+pub mod window;
+
+pub mod types;
+
+pub mod inputs;
+
+pub mod clipboard;
+
+pub mod cursor;
+
+pub mod testing;
+
+// ================================================================================================================================ //
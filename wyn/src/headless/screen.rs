@@ -0,0 +1,207 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless - Screen
+ */
+
+//! Functionality for querying the state of Monitors/Screens.
+//!
+//! The Headless backend provides a fixed set of virtual Screens (currently, a single one).\
+//! The virtual Screen has no panels, and is resized while an Exclusive Fullscreen Window switched its Video Mode.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::event_loop::EventLoop;
+use super::types::{Point, Rect, ScaleFactor, Size};
+
+pub use crate::common::screen::*;
+
+// ================================================================================================================================ //
+
+/// Native OS Representation for Screens.
+pub type NativeScreen = u32;
+
+/// Nonzero wrapper for Native Screens.
+type NonzeroScreen = NonZero<NativeScreen>;
+
+/// Wrapper type for Screen Handles.
+pub struct Screen(NonzeroScreen);
+
+/// Holds all information about a Screen.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct ScreenInfo {
+    /// The Bounding-Rectangle.
+    pub rect: Rect,
+
+    /// The Textual Name.
+    pub name: String,
+
+    /// The Scale Factor, which UIs on the Screen should be scaled by.
+    pub scale_factor: ScaleFactor,
+
+    /// The Refresh Rate of the current Video Mode, in Hertz (`0.0` if it is unknown).
+    pub refresh_rate: f64,
+
+    /// The Work Area, which excludes panels and taskbars.
+    pub work_area: Rect,
+
+    /// The Physical Size, in millimeters (zero if it is unknown).
+    pub size_mm: Size,
+
+    /// The Rotation.
+    pub rotation: Rotation,
+
+    /// The supported Video Modes.
+    pub video_modes: Vec<VideoMode>,
+}
+
+/// The Bounding-Rectangle of the virtual Screen.
+pub(crate) const SCREEN_RECT: Rect = Rect {
+    origin: Point { x: 0.0, y: 0.0 },
+    size: Size {
+        w: 1920.0,
+        h: 1080.0,
+    },
+};
+
+/// The Scale Factor of the virtual Screen.\
+/// Windows can be given other Scale Factors with `HeadlessInput::ScaleFactorChanged`.
+pub(crate) const SCREEN_SCALE_FACTOR: ScaleFactor = 1.0;
+
+/// The Physical Size of the virtual Screen, in millimeters (so that it is 96 DPI at its default Video Mode).
+const SCREEN_SIZE_MM: Size = Size {
+    w: 508.0,
+    h: 285.75,
+};
+
+/// The Video Modes of the virtual Screen, the first of which is its default.
+pub(crate) const SCREEN_VIDEO_MODES: &[VideoMode] = &[
+    VideoMode {
+        size: SCREEN_RECT.size,
+        refresh_rate: 60.0,
+        bit_depth: 32,
+    },
+    VideoMode {
+        size: SCREEN_RECT.size,
+        refresh_rate: 144.0,
+        bit_depth: 32,
+    },
+    VideoMode {
+        size: Size {
+            w: 1280.0,
+            h: 720.0,
+        },
+        refresh_rate: 60.0,
+        bit_depth: 32,
+    },
+    VideoMode {
+        size: Size { w: 800.0, h: 600.0 },
+        refresh_rate: 60.0,
+        bit_depth: 32,
+    },
+];
+
+/// The virtual Screens, as (Native Screen, Textual Name).
+const SCREENS: &[(NativeScreen, &str)] = &[(1, "HEADLESS-1")];
+
+// ================================================================================================================================ //
+
+impl Screen {
+    /// Returns the bounding-rectangle of the Screen.
+    pub fn rect(&self, events: &EventLoop) -> Rect {
+        events.state.lock().unwrap().screen_rect()
+    }
+
+    /// Returns the textual-name of the Screen.
+    pub fn name(&self, _events: &EventLoop) -> String {
+        self.internal_screen().1.to_owned()
+    }
+
+    /// Returns the Scale Factor of the Screen.
+    pub fn scale_factor(&self, _events: &EventLoop) -> ScaleFactor {
+        SCREEN_SCALE_FACTOR
+    }
+
+    /// Returns the Refresh Rate of the Screen's current Video Mode, in Hertz.
+    pub fn refresh_rate(&self, events: &EventLoop) -> f64 {
+        events.state.lock().unwrap().video_mode().refresh_rate
+    }
+
+    /// Returns the Work Area of the Screen, which is the whole Screen (as it has no panels).
+    pub fn work_area(&self, events: &EventLoop) -> Rect {
+        self.rect(events)
+    }
+
+    /// Returns the Physical Size of the Screen, in millimeters.
+    pub fn size_mm(&self, _events: &EventLoop) -> Size {
+        SCREEN_SIZE_MM
+    }
+
+    /// Returns the Rotation of the Screen.
+    pub fn rotation(&self, _events: &EventLoop) -> Rotation {
+        Rotation::Deg0
+    }
+
+    /// Returns the Video Modes that the Screen supports.
+    pub fn video_modes(&self, _events: &EventLoop) -> Vec<VideoMode> {
+        SCREEN_VIDEO_MODES.to_vec()
+    }
+
+    /// Queries all the Information about the Screen.
+    pub fn info(&self, events: &EventLoop) -> ScreenInfo {
+        ScreenInfo {
+            rect: self.rect(events),
+            name: self.name(events),
+            scale_factor: self.scale_factor(events),
+            refresh_rate: self.refresh_rate(events),
+            work_area: self.work_area(events),
+            size_mm: self.size_mm(events),
+            rotation: self.rotation(events),
+            video_modes: self.video_modes(events),
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+impl Screen {
+    /// Creates a new Screen object with the given handle.
+    pub(crate) fn new(handle: NativeScreen) -> Option<Screen> {
+        NonzeroScreen::new(handle).map(Self)
+    }
+
+    /// Returns the virtual Screen that every Window occupies.
+    pub(crate) fn virtual_screen() -> Self {
+        Screen::new(SCREENS[0].0).expect("The virtual Screen should always exist.")
+    }
+
+    /// Returns the Primary Screen.
+    pub fn primary(_events: &EventLoop) -> Self {
+        Self::virtual_screen()
+    }
+
+    /// Collects a list of all the Screens.
+    pub fn collect(_events: &EventLoop) -> Vec<Self> {
+        SCREENS
+            .iter()
+            .filter_map(|&(handle, _)| Screen::new(handle))
+            .collect()
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Headless-exclusive functionality.
+impl Screen {
+    /// Internal function that retrieves the entry of the virtual Screen.
+    fn internal_screen(&self) -> &'static (NativeScreen, &'static str) {
+        SCREENS
+            .iter()
+            .find(|(handle, _)| *handle == self.0.get())
+            .expect("The virtual Screen should always exist.")
+    }
+}
+
+// ================================================================================================================================ //
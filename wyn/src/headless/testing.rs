@@ -0,0 +1,89 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless - Testing
+ */
+
+//! Utilities for driving Windows from automated tests, by posting synthetic User-Input events to them.
+//!
+//! This mirrors the `testing` module of the other backends, so the same tests can run with or without a display server.\
+//! Each event is injected with `EventLoop::inject`, and is reported to the `EventHandler` by the Event Thread.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::*;
+use super::event_loop::EventLoop;
+use super::events::HeadlessInput;
+use super::inputs::*;
+use super::types::Point;
+use super::window::WindowHandle;
+
+// ================================================================================================================================ //
+
+/// Posts a synthetic Cursor-Move event to the Window, where the `point` is relative to the Window's Content.
+pub fn cursor_move(events: &EventLoop, handle: WindowHandle, point: Point) -> HeadlessResult<()> {
+    send_input(events, handle, HeadlessInput::CursorMove(handle, point))
+}
+
+/// Posts a synthetic Scroll-Wheel event to the Window.
+pub fn scroll_wheel(
+    events: &EventLoop,
+    handle: WindowHandle,
+    delta_x: f64,
+    delta_y: f64,
+) -> HeadlessResult<()> {
+    send_input(
+        events,
+        handle,
+        HeadlessInput::ScrollWheel(handle, delta_x, delta_y),
+    )
+}
+
+/// Posts a synthetic Button-Press (or Button-Release) event to the Window.
+pub fn button_press(
+    events: &EventLoop,
+    handle: WindowHandle,
+    button: MouseButton,
+    pressed: bool,
+) -> HeadlessResult<()> {
+    send_input(
+        events,
+        handle,
+        HeadlessInput::ButtonPress(handle, button, pressed),
+    )
+}
+
+/// Posts a synthetic Key-Press (or Key-Release) event to the Window.
+pub fn key_press(
+    events: &EventLoop,
+    handle: WindowHandle,
+    key: Key,
+    pressed: bool,
+) -> HeadlessResult<()> {
+    send_input(
+        events,
+        handle,
+        HeadlessInput::KeyPress(handle, KeyEvent::new(key), pressed),
+    )
+}
+
+// ================================================================================================================================ //
+
+/// Injects the Input, if the Window is still open.
+fn send_input(
+    events: &EventLoop,
+    handle: WindowHandle,
+    input: HeadlessInput,
+) -> HeadlessResult<()> {
+    let is_open = events.state.lock().unwrap().window_mut(handle).is_some();
+    if !is_open {
+        return Err(HeadlessError::WindowClosed);
+    }
+
+    events.inject(input);
+    Ok(())
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,34 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless - Types
+ */
+
+//! Native types for handling Points, Sizes, and Rectangles.
+//!
+//! Nothing is passed to a display server, so the Native types are the Wyn types themselves.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+pub use crate::common::types::*;
+
+// ================================================================================================================================ //
+
+/// Native type for (x, y) Coordinates.
+pub type NativeCoord = Coord;
+
+/// Native type for (w, h) Extents.
+pub type NativeExtent = Extent;
+
+/// Native type for 2-D Points.
+pub type NativePoint = Point;
+
+/// Native type for 2-D Sizes.
+pub type NativeSize = Size;
+
+/// Native type for 2-D Rectangles.
+pub type NativeRect = Rect;
+
+// ================================================================================================================================ //
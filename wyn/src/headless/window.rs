@@ -0,0 +1,829 @@
+/*
+ *  Crate: Wyn
+ * Module: Headless - Window
+ */
+
+//! Functionality pertaining to manipulating windows on the (virtual) desktop.
+//!
+//! Headless Windows only exist in memory, but otherwise behave like the Windows of a display server:
+//! requests are applied immediately, and the events they cause are reported to the `EventHandler` by the Event Thread.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::cursor::Cursor;
+use super::errors::*;
+use super::event_loop::{EventLoop, HeadlessEvent, HeadlessState};
+use super::events::HeadlessInput;
+use super::screen::{Screen, VideoMode, SCREEN_RECT, SCREEN_SCALE_FACTOR, SCREEN_VIDEO_MODES};
+use super::types::{Extent, Point, Rect, ScaleFactor, Size};
+
+// ================================================================================================================================ //
+
+/// Underlying OS Handle to a Window.\
+/// Headless Windows are numbered from `1`, in the order they were opened.
+pub type WindowHandle = u32;
+
+/// Nonzero wrapper for Window Handles.
+type NonZeroWindowHandle = NonZero<WindowHandle>;
+
+/// Wrapper for Window objects.
+#[repr(transparent)]
+pub struct Window(NonZeroWindowHandle);
+
+/// The size of newly opened Windows.
+const DEFAULT_SIZE: Size = Size { w: 640.0, h: 480.0 };
+
+// ================================================================================================================================ //
+
+impl Window {
+    /// Returns the Native OS Handle to this Window.
+    pub fn handle(&self) -> WindowHandle {
+        self.0.get()
+    }
+}
+
+impl TryFrom<WindowHandle> for Window {
+    type Error = ();
+
+    fn try_from(handle: WindowHandle) -> Result<Self, Self::Error> {
+        match NonZeroWindowHandle::new(handle) {
+            Some(handle) => Ok(Self(handle)),
+            None => Err(()),
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Open - Close
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Opens a new Window.\
+    /// New Windows are hidden, and centered on the Screen.
+    pub fn open(events: &EventLoop) -> HeadlessResult<Window> {
+        // SAFETY: Must be called on the Event Thread.
+        let fut = events.execute(move || unsafe {
+            let events = event_loop::EVENTLOOP.unwrap();
+
+            let window = Self::internal_open(events);
+            events.handler.window_open(events, window.handle());
+            Ok(window)
+        });
+        fut.wait()
+    }
+
+    /// Returns whether or not the Window is Open.
+    pub fn is_open(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |_| ()).is_ok()
+    }
+
+    /// Adds a new Window to the in-memory state.
+    fn internal_open(events: &EventLoop) -> Window {
+        let mut state = events.state.lock().unwrap();
+
+        let handle = state.next_handle();
+        state.windows.push(HeadlessWindow::new(handle));
+
+        Window::try_from(handle).expect("Invalid Window Handle.")
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Closes the Window.
+    pub fn close(self, events: &EventLoop) -> HeadlessResult<()> {
+        // SAFETY: Must be called on the Event Thread.
+        let fut = events.execute(move || unsafe {
+            let events = event_loop::EVENTLOOP.unwrap();
+            Self::internal_close(events, self.handle())
+        });
+        fut.wait()
+    }
+
+    /// Returns whether or not the Window is Closed.
+    pub fn is_closed(&self, events: &EventLoop) -> bool {
+        !self.is_open(events)
+    }
+
+    /// Reports the Window as closing to the Event Handler, then removes it from the in-memory state.\
+    /// Closing a Window again while it is closing (e.g. from `EventHandler::window_close`) does nothing.
+    pub(crate) fn internal_close(events: &EventLoop, handle: WindowHandle) -> HeadlessResult<()> {
+        let closing = {
+            let mut state = events.state.lock().unwrap();
+            let window = state
+                .window_mut(handle)
+                .ok_or(HeadlessError::WindowClosed)?;
+            std::mem::replace(&mut window.closing, true)
+        };
+
+        if !closing {
+            // Removes the window, just in case the User callback `panic`s.
+            let closer = defer(|| {
+                let _ = events.state.lock().unwrap().remove_window(handle);
+            });
+
+            events.handler.window_close(events, handle);
+
+            drop(closer);
+        }
+
+        Ok(())
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Focus - Show - Hide
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Makes the Window visible and gives it focus.
+    pub fn focus(&self, events: &EventLoop) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].set_visible(true, &mut state.queue);
+            state.set_focus(Some(state.windows[index].handle));
+        })
+    }
+
+    /// Returns whether or not the Window is Focused.
+    pub fn is_focused(&self, events: &EventLoop) -> bool {
+        events.state.lock().unwrap().focus() == Some(self.handle())
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Makes the Window visible.
+    pub fn show(&self, events: &EventLoop) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].set_visible(true, &mut state.queue);
+        })
+    }
+
+    /// Returns whether or not the Window is Visible.
+    pub fn is_visible(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.visible)
+            .unwrap_or(false)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Makes the Window invisible.\
+    /// Hiding the Focused Window also removes its Focus.
+    pub fn hide(&self, events: &EventLoop) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].set_visible(false, &mut state.queue);
+            state.unfocus(state.windows[index].handle);
+        })
+    }
+
+    /// Returns whether or not the Window is Hidden.
+    pub fn is_hidden(&self, events: &EventLoop) -> bool {
+        !self.is_visible(events)
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Minimize - Maximize - Fullscreen - Restore
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Minimizes the Window.\
+    /// Minimizing the Focused Window also removes its Focus.
+    pub fn minimize(&self, events: &EventLoop) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].minimized = true;
+            state.unfocus(state.windows[index].handle);
+        })
+    }
+
+    /// Returns whether or not the Window is Minimized.
+    pub fn is_minimized(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.minimized)
+            .unwrap_or(false)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Maximizes the Window, so that its Outer-Border covers the Screen.
+    pub fn maximize(&self, events: &EventLoop) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.release_video_mode(self.handle());
+            let screen_rect = state.screen_rect();
+
+            let window = &mut state.windows[index];
+            window.save_normal();
+            window.minimized = false;
+            window.fullscreen = false;
+            window.maximized = true;
+
+            let rect = window.border_to_content(screen_rect);
+            window.set_content(rect, &mut state.queue);
+        })
+    }
+
+    /// Returns whether or not the Window is Maximized.
+    pub fn is_maximized(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.maximized)
+            .unwrap_or(false)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Fullscreens the Window, so that its Inner-Content covers the Screen. (Borderless Fullscreen)
+    pub fn fullscreen(&self, events: &EventLoop) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.release_video_mode(self.handle());
+            let screen_rect = state.screen_rect();
+
+            let window = &mut state.windows[index];
+            window.save_normal();
+            window.minimized = false;
+            window.maximized = false;
+            window.fullscreen = true;
+
+            window.set_content(screen_rect, &mut state.queue);
+        })
+    }
+
+    /// Switches the Screen to the Video Mode, and Fullscreens the Window over it. (Exclusive Fullscreen)\
+    /// The closest Video Mode of the Screen with the same resolution is used, and `false` is returned if there is none.\
+    /// The previous Video Mode is restored once the Window leaves Fullscreen, or is closed.
+    pub fn fullscreen_exclusive(
+        &self,
+        events: &EventLoop,
+        _screen: &Screen,
+        mode: &VideoMode,
+    ) -> HeadlessResult<bool> {
+        let mode = mode.closest_in(SCREEN_VIDEO_MODES).copied();
+
+        self.internal_update(events, |state, index| {
+            let Some(mode) = mode else {
+                return false;
+            };
+            state.switch_video_mode(self.handle(), mode);
+            let screen_rect = state.screen_rect();
+
+            let window = &mut state.windows[index];
+            window.save_normal();
+            window.minimized = false;
+            window.maximized = false;
+            window.fullscreen = true;
+
+            window.set_content(screen_rect, &mut state.queue);
+            true
+        })
+    }
+
+    /// Returns whether or not the Window is Fullscreened.
+    pub fn is_fullscreen(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.fullscreen)
+            .unwrap_or(false)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Restores the Window from Minimized/Maximized/Fullscreen state.\
+    /// A Minimized Window is restored to the state it had before it was Minimized.\
+    /// Leaving Exclusive Fullscreen also restores the Video Mode of the Screen.
+    pub fn restore(&self, events: &EventLoop) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            let window = &mut state.windows[index];
+
+            if window.minimized {
+                window.minimized = false;
+            } else if window.maximized || window.fullscreen {
+                window.maximized = false;
+                window.fullscreen = false;
+
+                let rect = window.normal;
+                window.set_content(rect, &mut state.queue);
+                state.release_video_mode(self.handle());
+            }
+        })
+    }
+
+    /// Returns whether or not the Window isn't Minimized, Maximized, or Fullscreened.
+    pub fn is_normal(&self, events: &EventLoop) -> bool {
+        !self.is_minimized(events) && !self.is_maximized(events) && !self.is_fullscreen(events)
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Rename
+impl Window {
+    /// Sets the Name of the Window.
+    pub fn rename(&self, events: &EventLoop, name: &str) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].name = name.to_owned();
+        })
+    }
+
+    /// Gets the Name of the Window.
+    pub fn name(&self, events: &EventLoop) -> HeadlessResult<String> {
+        self.internal_with(events, |window| window.name.clone())
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Reposition
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Sets the rectangle of the Inner-Content of the Window.\
+    /// Repositioning a Maximized or Fullscreened Window returns it to the normal state.
+    pub fn reposition_content(&self, events: &EventLoop, rect: Rect) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.release_video_mode(self.handle());
+            state.windows[index].reposition(rect, &mut state.queue);
+        })
+    }
+
+    /// Sets the rectangle of the Outer-Border of the Window.\
+    /// Repositioning a Maximized or Fullscreened Window returns it to the normal state.
+    pub fn reposition_border(&self, events: &EventLoop, rect: Rect) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.release_video_mode(self.handle());
+
+            let window = &mut state.windows[index];
+            window.maximized = false;
+            window.fullscreen = false;
+
+            let rect = window.border_to_content(rect);
+            window.reposition(rect, &mut state.queue);
+        })
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Gets the rectangle of the Inner-Content of the Window.
+    pub fn content_rect(&self, events: &EventLoop) -> HeadlessResult<Rect> {
+        self.internal_with(events, |window| window.content)
+    }
+
+    /// Gets the rectangle of the Outer-Border of the Window.
+    pub fn border_rect(&self, events: &EventLoop) -> HeadlessResult<Rect> {
+        self.internal_with(events, |window| window.content_to_border(window.content))
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// The appearance and actions of a Window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WindowType {
+    /// The Window Style.
+    pub style: WindowStyle,
+    /// The Window Actions.
+    pub actions: WindowActions,
+}
+
+/// The visual appearance of a Window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WindowStyle {
+    /// The Window has a Titlebar.
+    Captioned,
+    /// The Window has a Thin Frame.
+    Bordered,
+    /// The Window has No Frame.
+    Borderless,
+}
+
+/// The possible user-interactions on a Window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WindowActions {
+    /// Whether or not the user can move the window.
+    pub movable: bool,
+    /// Whether or not the user can close the window.
+    pub closeable: bool,
+    /// Whether or not the user can resize the window.
+    pub resizable: bool,
+}
+
+// ---------------------------------------------------------------- //
+
+/// Styles - Actions
+impl Window {
+    /// Sets the Style of a Window.\
+    /// The Inner-Content keeps its rectangle, while the Outer-Border changes to fit the new frame.
+    pub fn set_style(&self, events: &EventLoop, style: WindowStyle) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].wtype.style = style;
+        })
+    }
+
+    /// Gets the Style of a Window.
+    pub fn style(&self, events: &EventLoop) -> HeadlessResult<WindowStyle> {
+        self.internal_with(events, |window| window.wtype.style)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Sets the Actions of a Window.\
+    /// The Actions are only recorded, since there is no user to restrict.
+    pub fn set_actions(&self, events: &EventLoop, actions: WindowActions) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].wtype.actions = actions;
+        })
+    }
+
+    /// Gets the Actions of a Window.
+    pub fn actions(&self, events: &EventLoop) -> HeadlessResult<WindowActions> {
+        self.internal_with(events, |window| window.wtype.actions)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Sets the Type of a Window.
+    pub fn set_type(&self, events: &EventLoop, wtype: WindowType) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].wtype = wtype;
+        })
+    }
+
+    /// Gets the Type of a Window.
+    pub fn get_type(&self, events: &EventLoop) -> HeadlessResult<WindowType> {
+        self.internal_with(events, |window| window.wtype)
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Screens
+impl Window {
+    /// Returns the the closest Screen the Window is currently occupying.\
+    /// There is only a single virtual Screen, which every Window occupies.
+    pub fn screen(&self, _events: &EventLoop) -> Screen {
+        Screen::virtual_screen()
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Scale Factor
+impl Window {
+    /// Returns the Scale Factor of the Window, which its UI should be scaled by.\
+    /// Starts out as the Scale Factor of the virtual Screen, until another one is injected.
+    pub fn scale_factor(&self, events: &EventLoop) -> ScaleFactor {
+        self.internal_with(events, |window| window.scale_factor)
+            .unwrap_or(SCREEN_SCALE_FACTOR)
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Redraw
+impl Window {
+    /// Requests the Window to redraw its contents.
+    pub fn request_redraw(&self, events: &EventLoop) {
+        let _ = self.internal_update(events, |state, index| {
+            let handle = state.windows[index].handle;
+            state.queue.push(HeadlessEvent::WindowRedraw(handle));
+        });
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Cursor
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Sets the appearance of the Mouse-Cursor while it is over the Window.
+    pub fn set_cursor(&self, events: &EventLoop, cursor: &Cursor) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].cursor = cursor.clone();
+        })
+    }
+
+    /// Shows or hides the Mouse-Cursor while it is over the Window.
+    pub fn set_cursor_visible(&self, events: &EventLoop, visible: bool) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].cursor_visible = visible;
+        })
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Moves the Mouse-Cursor to the point, relative to the Inner-Content of the Window.\
+    /// The move is reported to the Event Handler, as if the user had moved the Cursor.
+    pub fn warp_cursor(&self, events: &EventLoop, point: Point) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            let input = HeadlessInput::CursorMove(state.windows[index].handle, point);
+            state.queue.push(HeadlessEvent::Input(input));
+        })
+    }
+
+    /// Grabs the Mouse-Cursor, confining it to the Inner-Content of the Window (or releases it).\
+    /// Combined with hiding the Cursor and `EventHandler::raw_motion`, this suits First-Person camera controls.
+    pub fn grab_cursor(&self, events: &EventLoop, grab: bool) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].cursor_grabbed = grab;
+        })
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Input Method
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Allows (or disallows) Input Methods to compose text in the Window.\
+    /// Input Methods are disallowed by default, since they would intercept Key presses (e.g. for game controls).
+    pub fn set_ime_allowed(&self, events: &EventLoop, allowed: bool) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].ime_allowed = allowed;
+        })
+    }
+
+    /// Sets the area being edited, relative to the Inner-Content of the Window.\
+    /// The Input Method places its candidate window next to it, without covering it.
+    pub fn set_ime_position(&self, events: &EventLoop, rect: Rect) -> HeadlessResult<()> {
+        self.internal_update(events, |state, index| {
+            state.windows[index].ime_position = rect;
+        })
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Headless-exclusive functionality.
+impl Window {
+    /// Gets the appearance of the Mouse-Cursor while it is over the Window.
+    pub fn cursor(&self, events: &EventLoop) -> HeadlessResult<Cursor> {
+        self.internal_with(events, |window| window.cursor.clone())
+    }
+
+    /// Returns whether or not the Mouse-Cursor is shown while it is over the Window.
+    pub fn is_cursor_visible(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.cursor_visible)
+            .unwrap_or(false)
+    }
+
+    /// Returns whether or not the Mouse-Cursor is grabbed by the Window.
+    pub fn is_cursor_grabbed(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.cursor_grabbed)
+            .unwrap_or(false)
+    }
+
+    /// Returns whether or not the Window allows Input Methods.
+    pub fn is_ime_allowed(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.ime_allowed)
+            .unwrap_or(false)
+    }
+
+    /// Gets the area being edited, as set with `Window::set_ime_position`.
+    pub fn ime_position(&self, events: &EventLoop) -> HeadlessResult<Rect> {
+        self.internal_with(events, |window| window.ime_position)
+    }
+
+    /// Runs the callback on the in-memory state of the Window, if it is still open.
+    fn internal_with<T>(
+        &self,
+        events: &EventLoop,
+        callback: impl FnOnce(&HeadlessWindow) -> T,
+    ) -> HeadlessResult<T> {
+        let mut state = events.state.lock().unwrap();
+        let window = state.window_mut(self.handle());
+        window
+            .map(|window| callback(window))
+            .ok_or(HeadlessError::WindowClosed)
+    }
+
+    /// Runs the callback on the in-memory state (along with the index of the Window), if the Window is still open.\
+    /// The Event Thread is woken afterwards, to report any events that were queued.
+    fn internal_update<T>(
+        &self,
+        events: &EventLoop,
+        callback: impl FnOnce(&mut HeadlessState, usize) -> T,
+    ) -> HeadlessResult<T> {
+        let res = {
+            let mut state = events.state.lock().unwrap();
+            let index = state
+                .windows
+                .iter()
+                .position(|window| window.handle == self.handle());
+
+            match index {
+                Some(index) => Ok(callback(&mut state, index)),
+                None => Err(HeadlessError::WindowClosed),
+            }
+        };
+
+        events.wake();
+        res
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The Type of newly opened Windows.
+const DEFAULT_TYPE: WindowType = WindowType {
+    style: WindowStyle::Captioned,
+    actions: WindowActions {
+        movable: true,
+        closeable: true,
+        resizable: true,
+    },
+};
+
+/// The thickness of the frame around the Inner-Content of a Window, on each side (left, top, right, bottom).
+type Frame = (Extent, Extent, Extent, Extent);
+
+/// The in-memory state of a Window.
+pub(crate) struct HeadlessWindow {
+    /// The Window Handle.
+    pub(crate) handle: WindowHandle,
+
+    /// Whether or not the Window is being closed (and is reporting it to the Event Handler).
+    closing: bool,
+
+    /// The Name (Title).
+    name: String,
+
+    /// The rectangle of the Inner-Content.
+    content: Rect,
+
+    /// The rectangle of the Inner-Content to restore to, while Maximized or Fullscreened.
+    normal: Rect,
+
+    /// Whether or not the Window is Visible.
+    visible: bool,
+
+    /// Whether or not the Window is Minimized.
+    minimized: bool,
+
+    /// Whether or not the Window is Maximized.
+    maximized: bool,
+
+    /// Whether or not the Window is Fullscreened.
+    fullscreen: bool,
+
+    /// The Style and Actions.
+    wtype: WindowType,
+
+    /// The appearance of the Mouse-Cursor over the Window.
+    cursor: Cursor,
+
+    /// Whether or not the Mouse-Cursor is shown over the Window.
+    cursor_visible: bool,
+
+    /// Whether or not the Mouse-Cursor is grabbed by the Window.
+    cursor_grabbed: bool,
+
+    /// The Scale Factor.
+    scale_factor: ScaleFactor,
+
+    /// Whether or not Input Methods are allowed.
+    ime_allowed: bool,
+
+    /// The area being edited with an Input Method, relative to the Inner-Content.
+    ime_position: Rect,
+}
+
+// ---------------------------------------------------------------- //
+
+impl HeadlessWindow {
+    /// Constructs the state of a newly opened Window.
+    fn new(handle: WindowHandle) -> Self {
+        let rect = Rect::new_centered(SCREEN_RECT.center(), DEFAULT_SIZE);
+
+        Self {
+            handle,
+            closing: false,
+            name: String::new(),
+            content: rect,
+            normal: rect,
+            visible: false,
+            minimized: false,
+            maximized: false,
+            fullscreen: false,
+            wtype: DEFAULT_TYPE,
+            cursor: Cursor::default(),
+            cursor_visible: true,
+            cursor_grabbed: false,
+            scale_factor: SCREEN_SCALE_FACTOR,
+            ime_allowed: false,
+            ime_position: Rect::default(),
+        }
+    }
+
+    /// Returns the rectangle of the Inner-Content.
+    pub(crate) fn content(&self) -> Rect {
+        self.content
+    }
+
+    /// Returns whether or not Input Methods are allowed.
+    pub(crate) fn is_ime_allowed(&self) -> bool {
+        self.ime_allowed
+    }
+
+    /// Changes the Scale Factor, returning whether or not it differed.
+    pub(crate) fn set_scale_factor(&mut self, scale_factor: ScaleFactor) -> bool {
+        let changed = self.scale_factor != scale_factor;
+        self.scale_factor = scale_factor;
+        changed
+    }
+
+    /// Shows or hides the Window, queueing the change.\
+    /// A Window that is shown also needs its contents drawn.
+    fn set_visible(&mut self, visible: bool, queue: &mut Vec<HeadlessEvent>) {
+        if self.visible != visible {
+            self.visible = visible;
+            queue.push(HeadlessEvent::WindowVisibility(self.handle, visible));
+
+            if visible {
+                queue.push(HeadlessEvent::WindowRedraw(self.handle));
+            }
+        }
+    }
+
+    /// Moves the Inner-Content, queueing the change.
+    fn set_content(&mut self, rect: Rect, queue: &mut Vec<HeadlessEvent>) {
+        if self.content != rect {
+            self.content = rect;
+            queue.push(HeadlessEvent::WindowReposition(self.handle));
+        }
+    }
+
+    /// Moves the Inner-Content as requested by User-Code, returning the Window to the normal state.
+    fn reposition(&mut self, rect: Rect, queue: &mut Vec<HeadlessEvent>) {
+        self.maximized = false;
+        self.fullscreen = false;
+        self.normal = rect;
+        self.set_content(rect, queue);
+    }
+
+    /// Remembers the Inner-Content to restore to, unless the Window is already Maximized or Fullscreened.
+    fn save_normal(&mut self) {
+        if !self.maximized && !self.fullscreen {
+            self.normal = self.content;
+        }
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// The frame around the Inner-Content, which depends on the Style.\
+    /// Fullscreened Windows have no frame.
+    #[rustfmt::skip]
+    fn frame(&self) -> Frame {
+        if self.fullscreen {
+            return (0.0, 0.0, 0.0, 0.0);
+        }
+
+        match self.wtype.style {
+            WindowStyle::Captioned  => (1.0, 24.0, 1.0, 1.0),
+            WindowStyle::Bordered   => (1.0,  1.0, 1.0, 1.0),
+            WindowStyle::Borderless => (0.0,  0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Converts a rectangle of the Inner-Content into a rectangle of the Outer-Border.
+    fn content_to_border(&self, rect: Rect) -> Rect {
+        let (left, top, right, bottom) = self.frame();
+
+        let origin = Point::new(rect.origin.x - left, rect.origin.y - top);
+        let size = Size::new(rect.size.w + left + right, rect.size.h + top + bottom);
+        Rect { origin, size }
+    }
+
+    /// Converts a rectangle of the Outer-Border into a rectangle of the Inner-Content.
+    fn border_to_content(&self, rect: Rect) -> Rect {
+        let (left, top, right, bottom) = self.frame();
+
+        let origin = Point::new(rect.origin.x + left, rect.origin.y + top);
+        let size = Size::new(rect.size.w - left - right, rect.size.h - top - bottom);
+        Rect { origin, size }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl HeadlessState {
+    /// Removes the Focus from the Window, if it has it.
+    fn unfocus(&mut self, handle: WindowHandle) {
+        if self.focus() == Some(handle) {
+            self.set_focus(None);
+        }
+    }
+}
+
+// ================================================================================================================================ //
@@ -11,8 +11,24 @@
 //! ### Features
 //! * Manipulate windows on the desktop.
 //! * Run native platform event/message loops.
-//! * Receive user-inputs (such as mouse, keyboard, and controller inputs).
+//! * Receive user-inputs (such as mouse, keyboard, and controller inputs, or files dropped onto windows).
+//! * Change, hide, warp, and grab the mouse-cursor (and receive raw mouse motion).
 //! * Query the state of windows and monitors.
+//! * Read and write the Clipboard (text, or any MIME type).
+//!
+//! ### Backends
+//! On Linux, the X11 backend is used by default.\
+//! Enabling the `wayland` feature selects the Wayland backend instead, which connects to the compositor named by `WAYLAND_DISPLAY`.
+//!
+//! Enabling the `headless` feature selects the Headless backend on every platform, which keeps all Windows and Screens in memory.\
+//! It needs no display server, and User-Input is injected with `EventLoop::inject`, so it is suited to running tests (e.g. in CI).
+//!
+//! ### Testing
+//! The `testing` module posts synthetic User-Input (keys, buttons, motion, and scrolling) to a Window,
+//! so that applications can be tested automatically (e.g. under Xvfb). It is available on X11, Win32, and the Headless backend.
+//!
+//! The `recording` module records every callback delivered to an `EventHandler` (with a `Recorder`),
+//! and replays a Recording into any `EventHandler` (with a `ReplayEventLoop`), e.g. to reproduce a bug report.
 
 // ================================================================================================================================ //
 
@@ -39,23 +55,66 @@ pub use self::common::*;
 // -------------------------------------------------------------------------------------------------------------------------------- //
 
 cfg_if! {
-    if #[cfg(target_os = "windows")]
+    if #[cfg(feature = "headless")]
+    {
+        mod headless;
+        pub use self::headless::*;
+        pub use self::headless::types;
+        pub use self::headless::inputs;
+        pub use self::headless::events;
+        pub use self::headless::clipboard;
+        pub use self::headless::cursor;
+        pub use self::headless::screen;
+    }
+    else if #[cfg(target_os = "windows")]
     {
         mod win32;
         pub use self::win32::*;
         pub use self::win32::types;
+        pub use self::win32::inputs;
+        pub use self::win32::events;
+        pub use self::win32::clipboard;
+        pub use self::win32::cursor;
+        pub use self::win32::screen;
+    }
+    else if #[cfg(all(target_os = "linux", feature = "wayland"))]
+    {
+        pub(crate) mod linux;
+        mod wayland;
+        pub use self::wayland::*;
+        pub use self::wayland::types;
+        pub use self::wayland::inputs;
+        pub use self::wayland::events;
+        pub use self::wayland::clipboard;
+        pub use self::wayland::cursor;
+        pub use self::wayland::screen;
     }
-    else if #[cfg(target_os = "linux")]
+    else if #[cfg(all(target_os = "linux", feature = "x11"))]
     {
+        pub(crate) mod linux;
         mod x11;
         pub use self::x11::*;
         pub use self::x11::types;
+        pub use self::x11::inputs;
+        pub use self::x11::events;
+        pub use self::x11::clipboard;
+        pub use self::x11::cursor;
+        pub use self::x11::screen;
     }
     else if #[cfg(target_os = "macos")]
     {
         mod cocoa;
         pub use self::cocoa::*;
         pub use self::cocoa::types;
+        pub use self::cocoa::inputs;
+        pub use self::cocoa::events;
+        pub use self::cocoa::clipboard;
+        pub use self::cocoa::cursor;
+        pub use self::cocoa::screen;
+    }
+    else
+    {
+        compile_error!("Wyn has no backend for this platform: enable the `x11`, `wayland`, or `headless` feature.");
     }
 }
 
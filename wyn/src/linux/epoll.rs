@@ -0,0 +1,169 @@
+/*
+ *  Crate: Wyn
+ * Module: Linux - Epoll
+ */
+
+//! Waiting on the Display Connection, alongside the File Descriptors used for signalling the Event Thread.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use std::io::ErrorKind;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+
+// ================================================================================================================================ //
+
+/// Watches multiple File Descriptors and can wait until any of them have data to read.
+pub(crate) struct Epoller {
+    /// The File Descriptor that can call `epoll` functions.
+    epoll_fd: OwnedFd,
+    /// Signals the Event Loop should Quit.
+    quit_fd: OwnedFd,
+    /// Signals the Event Loop should Execute a user-callback.
+    exec_fd: OwnedFd,
+    /// Signals the Display Connection (X11 or Wayland) may have new events.
+    conn_fd: RawFd,
+}
+
+/// The result of waiting on a set of File Descriptors.
+pub(crate) enum EpollResult {
+    /// Waiting failed, with the given `errno` value.
+    Failure(i32),
+    /// Some other registered File Descriptor has data.
+    Other(RawFd),
+    /// The Display Connection has data.
+    Conn,
+    /// The `execute` command was signaled.
+    Exec,
+    /// The `quit` command was signaled.
+    Quit,
+}
+
+impl Epoller {
+    /// Constructs a new Epoller and associated File Descriptors.
+    pub fn new(conn_fd: RawFd) -> Self {
+        let epoll_fd = unsafe { OwnedFd::from_raw_fd(sys::epoll_create1(0)) };
+        let quit_fd = unsafe { OwnedFd::from_raw_fd(sys::eventfd(0, sys::EFD_NONBLOCK)) };
+        let exec_fd = unsafe { OwnedFd::from_raw_fd(sys::eventfd(0, sys::EFD_NONBLOCK)) };
+
+        let this = Self {
+            epoll_fd,
+            quit_fd,
+            exec_fd,
+            conn_fd,
+        };
+
+        this.register(this.conn_fd.as_raw_fd());
+        this.register(this.exec_fd.as_raw_fd());
+        this.register(this.quit_fd.as_raw_fd());
+
+        this
+    }
+
+    /// Registers a File Descriptor to be available for watching.
+    pub(crate) fn register(&self, fd: RawFd) {
+        let mut event = sys::epoll_event {
+            events: sys::EPOLLIN as u32,
+            data: sys::epoll_data_t { fd },
+        };
+
+        let res = unsafe {
+            sys::epoll_ctl(
+                self.epoll_fd.as_raw_fd(),
+                sys::EPOLL_CTL_ADD,
+                fd,
+                event.as_libc_mut(),
+            )
+        };
+        assert_ne!(res, -1);
+    }
+
+    /// Unregisters a previously registered File Descriptor.
+    pub(crate) fn unregister(&self, fd: RawFd) {
+        let res = unsafe {
+            sys::epoll_ctl(
+                self.epoll_fd.as_raw_fd(),
+                sys::EPOLL_CTL_DEL,
+                fd,
+                null_mut(),
+            )
+        };
+        assert_ne!(res, -1);
+    }
+}
+
+impl Epoller {
+    /// Writes `data` into an EventFd.
+    fn write(&self, fd: RawFd, data: u64) {
+        let res = unsafe { sys::write(fd, void_of!(data), 8) };
+        assert_ne!(res, -1)
+    }
+
+    /// Reads `data` from an EventFd.
+    fn read(&self, fd: RawFd) -> u64 {
+        let mut data = 0u64;
+        let res = unsafe { sys::read(fd, void_of_mut!(data), 8) };
+        assert_eq!(res, 8);
+        data
+    }
+
+    /// Signals the ExecFd.
+    pub fn signal_exec(&self) {
+        self.write(self.exec_fd.as_raw_fd(), 1)
+    }
+
+    /// Resets the ExecFd.
+    fn reset_exec(&self) {
+        let _ = self.read(self.exec_fd.as_raw_fd());
+    }
+
+    /// Signals the QuitFd.
+    pub fn signal_quit(&self) {
+        self.write(self.quit_fd.as_raw_fd(), 1)
+    }
+
+    /// Signals the QuitFd.
+    fn reset_quit(&self) {
+        let _ = self.read(self.quit_fd.as_raw_fd());
+    }
+
+    /// Waits for one of the File Descriptors to be ready, and returns which one has data.\
+    /// Waiting is resumed if it is interrupted by a signal.
+    pub(crate) fn wait(&self) -> EpollResult {
+        let mut event: sys::epoll_event = unsafe { zeroed() };
+
+        loop {
+            let count =
+                unsafe { sys::epoll_wait(self.epoll_fd.as_raw_fd(), event.as_libc_mut(), 1, -1) };
+            match count {
+                1 => break,
+                -1 => {
+                    let err = std::io::Error::last_os_error();
+                    if err.kind() != ErrorKind::Interrupted {
+                        return EpollResult::Failure(err.raw_os_error().unwrap_or_default());
+                    }
+                }
+                // No event was written, so keep waiting.
+                _ => {}
+            }
+        }
+
+        let fd = unsafe { event.data.fd };
+
+        if fd == self.conn_fd.as_raw_fd() {
+            EpollResult::Conn
+        } else if fd == self.exec_fd.as_raw_fd() {
+            self.reset_exec();
+            EpollResult::Exec
+        } else if fd == self.quit_fd.as_raw_fd() {
+            self.reset_quit();
+            EpollResult::Quit
+        } else {
+            EpollResult::Other(fd)
+        }
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,617 @@
+/*
+ *  Crate: Wyn
+ * Module: Linux - Evdev
+ */
+
+//! Functionality for reading Gamepads through the Linux Input Subsystem (evdev).
+//!
+//! Neither X11 nor Wayland have a concept of Gamepads, so they are read directly from the `/dev/input/event*` device nodes.\
+//! Hot-plugging is detected by watching `/dev/input` with `inotify`.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::epoll::Epoller;
+use crate::common::events::GamepadUpdate;
+use crate::common::inputs::{Gamepad, GamepadAxis, GamepadButton, GamepadId};
+
+use std::io::ErrorKind;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+// ================================================================================================================================ //
+
+/// The directory containing the evdev device nodes.
+const INPUT_DIR: &str = "/dev/input";
+
+/// The file-name prefix of evdev device nodes.
+const DEVICE_PREFIX: &str = "event";
+
+/// Translation table between evdev Button Codes and `GamepadButton`s, following the kernel's standard Gamepad layout.
+#[rustfmt::skip]
+const BUTTON_MAP: &[(u16, GamepadButton)] = &[
+    (sys::BTN_SOUTH,      GamepadButton::A),
+    (sys::BTN_EAST,       GamepadButton::B),
+    (sys::BTN_WEST,       GamepadButton::X),
+    (sys::BTN_NORTH,      GamepadButton::Y),
+    (sys::BTN_SELECT,     GamepadButton::Back),
+    (sys::BTN_START,      GamepadButton::Start),
+    (sys::BTN_DPAD_UP,    GamepadButton::DpadUp),
+    (sys::BTN_DPAD_DOWN,  GamepadButton::DpadDown),
+    (sys::BTN_DPAD_LEFT,  GamepadButton::DpadLeft),
+    (sys::BTN_DPAD_RIGHT, GamepadButton::DpadRight),
+    (sys::BTN_TL,         GamepadButton::BumperL),
+    (sys::BTN_TR,         GamepadButton::BumperR),
+    (sys::BTN_THUMBL,     GamepadButton::ThumbL),
+    (sys::BTN_THUMBR,     GamepadButton::ThumbR),
+];
+
+/// Translation table between evdev Absolute Axis Codes and `GamepadAxis`es.\
+/// Triggers are reported as `ABS_Z`/`ABS_RZ` by most drivers, and as `ABS_BRAKE`/`ABS_GAS` by some others.
+#[rustfmt::skip]
+const AXIS_MAP: &[(u16, GamepadAxis)] = &[
+    (sys::ABS_X,     GamepadAxis::StickLX),
+    (sys::ABS_Y,     GamepadAxis::StickLY),
+    (sys::ABS_RX,    GamepadAxis::StickRX),
+    (sys::ABS_RY,    GamepadAxis::StickRY),
+    (sys::ABS_Z,     GamepadAxis::TriggerL),
+    (sys::ABS_BRAKE, GamepadAxis::TriggerL),
+    (sys::ABS_RZ,    GamepadAxis::TriggerR),
+    (sys::ABS_GAS,   GamepadAxis::TriggerR),
+];
+
+/// Digital Trigger Buttons, reported by Gamepads without analog Triggers.
+#[rustfmt::skip]
+const TRIGGER_MAP: &[(u16, GamepadAxis)] = &[
+    (sys::BTN_TL2, GamepadAxis::TriggerL),
+    (sys::BTN_TR2, GamepadAxis::TriggerR),
+];
+
+/// The number of bytes in a bit-set that can hold every Key Code.
+const KEY_BITS_LEN: usize = sys::KEY_MAX as usize / 8 + 1;
+
+/// A bit-set of Key Codes, as filled in by `EVIOCGBIT` and `EVIOCGKEY`.
+type KeyBits = [u8; KEY_BITS_LEN];
+
+/// Returns whether or not the Key Code is set in the bit-set.
+fn test_bit(bits: &KeyBits, code: u16) -> bool {
+    bits[code as usize / 8] & (1 << (code % 8)) != 0
+}
+
+// ================================================================================================================================ //
+
+/// The range reported by an Absolute Axis.
+#[derive(Clone, Copy)]
+struct AxisRange {
+    /// The evdev Absolute Axis Code.
+    code: u16,
+    /// The minimum reported value.
+    minimum: i32,
+    /// The maximum reported value.
+    maximum: i32,
+    /// The dead zone around the center (or the minimum, for Triggers), within which values are noise.
+    flat: i32,
+}
+
+/// An opened evdev device that identifies as a Gamepad.
+pub(crate) struct GamepadDevice {
+    /// The path of the device node.
+    path: PathBuf,
+
+    /// The opened (non-blocking) device node.
+    fd: OwnedFd,
+
+    /// The ranges of the Absolute Axes the device supports.
+    ranges: Vec<AxisRange>,
+
+    /// The state being built up from events, until the next `SYN_REPORT`.
+    pending: Gamepad,
+
+    /// The state as of the last `SYN_REPORT`.
+    state: Gamepad,
+
+    /// Whether or not the kernel's event buffer overflowed, and events are being discarded until the next `SYN_REPORT`.
+    dropped: bool,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl GamepadDevice {
+    /// Opens a device node, if it is accessible and identifies as a Gamepad.
+    fn open(path: &Path, id: GamepadId) -> Option<Self> {
+        let c_path = CString::new(path.as_os_str().as_bytes()).ok()?;
+        let flags = sys::O_RDONLY | sys::O_NONBLOCK | sys::O_CLOEXEC;
+
+        let raw_fd = unsafe { sys::open(c_path.as_ptr(), flags) };
+        if raw_fd == -1 {
+            return None;
+        }
+
+        // SAFETY: The File Descriptor was just opened, and is owned by nothing else.
+        let fd = unsafe { OwnedFd::from_raw_fd(raw_fd) };
+
+        // Any device that reports the first Gamepad button is a Gamepad (joysticks and keyboards are not).
+        let mut key_bits: KeyBits = [0; KEY_BITS_LEN];
+        let request = sys::EVIOCGBIT(sys::EV_KEY as u32, KEY_BITS_LEN);
+        let res = unsafe { sys::ioctl(fd.as_raw_fd(), request as _, key_bits.as_mut_ptr()) };
+        if res == -1 || !test_bit(&key_bits, sys::BTN_GAMEPAD) {
+            return None;
+        }
+
+        let codes = AXIS_MAP.iter().map(|&(code, _)| code);
+        let hats = [sys::ABS_HAT0X, sys::ABS_HAT0Y];
+        let ranges = codes
+            .chain(hats)
+            .filter_map(|code| {
+                let info = Self::query_axis(&fd, code)?;
+                let (minimum, maximum) = (info.minimum, info.maximum);
+                (minimum < maximum).then_some(AxisRange {
+                    code,
+                    minimum,
+                    maximum,
+                    flat: info.flat.max(0),
+                })
+            })
+            .collect();
+
+        let mut this = Self {
+            path: path.to_owned(),
+            fd,
+            ranges,
+            pending: Gamepad::new(id),
+            state: Gamepad::new(id),
+            dropped: false,
+        };
+
+        this.resync();
+        this.state = this.pending;
+
+        Some(this)
+    }
+
+    /// Queries the current state of an Absolute Axis.
+    fn query_axis(fd: &OwnedFd, code: u16) -> Option<sys::input_absinfo> {
+        let mut info: sys::input_absinfo = unsafe { zeroed() };
+        let request = sys::EVIOCGABS(code as u32);
+        let res = unsafe { sys::ioctl(fd.as_raw_fd(), request as _, addr_of_mut!(info)) };
+        (res != -1).then_some(info)
+    }
+
+    /// Re-reads the full state of the device, after events were lost.
+    fn resync(&mut self) {
+        let mut key_bits: KeyBits = [0; KEY_BITS_LEN];
+        let request = sys::EVIOCGKEY(KEY_BITS_LEN);
+        let res = unsafe { sys::ioctl(self.fd.as_raw_fd(), request as _, key_bits.as_mut_ptr()) };
+        if res != -1 {
+            let buttons = BUTTON_MAP.iter().map(|&(code, _)| code);
+            let triggers = TRIGGER_MAP.iter().map(|&(code, _)| code);
+            for code in buttons.chain(triggers) {
+                self.handle_key(code, test_bit(&key_bits, code) as i32);
+            }
+        }
+
+        let codes: Vec<u16> = self.ranges.iter().map(|range| range.code).collect();
+        for code in codes {
+            if let Some(info) = Self::query_axis(&self.fd, code) {
+                self.handle_abs(code, info.value);
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl GamepadDevice {
+    /// Reads all pending events from the device, adding an update for every completed report.\
+    /// Returns `false` if the device was disconnected.
+    fn read(&mut self, updates: &mut Vec<GamepadUpdate>) -> bool {
+        const EVENT_SIZE: usize = size_of::<sys::input_event>();
+
+        let mut events: [sys::input_event; 32] = unsafe { zeroed() };
+        let size = events.len() * EVENT_SIZE;
+        loop {
+            let buffer = events.as_mut_ptr() as *mut c_void;
+            let res = unsafe { sys::read(self.fd.as_raw_fd(), buffer, size) };
+
+            match res {
+                -1 => return std::io::Error::last_os_error().kind() == ErrorKind::WouldBlock,
+                0 => return false,
+                len => {
+                    let count = len as usize / EVENT_SIZE;
+                    for event in &events[..count] {
+                        if let Some(update) = self.handle_event(event) {
+                            updates.push(update);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Applies a single event to the pending state.\
+    /// Returns an update when a report is completed.
+    fn handle_event(&mut self, event: &sys::input_event) -> Option<GamepadUpdate> {
+        let value = event.value;
+        match (event.type_, event.code) {
+            (sys::EV_SYN, sys::SYN_DROPPED) => self.dropped = true,
+            (sys::EV_SYN, sys::SYN_REPORT) => {
+                if self.dropped {
+                    self.dropped = false;
+                    self.resync();
+                }
+
+                let prev = self.state;
+                self.pending.set_packet(prev.packet().wrapping_add(1));
+                self.state = self.pending;
+                return Some(GamepadUpdate::Changed(prev, self.state));
+            }
+            _ if self.dropped => {}
+            (sys::EV_KEY, code) => self.handle_key(code, value),
+            (sys::EV_ABS, code) => self.handle_abs(code, value),
+            _ => {}
+        }
+        None
+    }
+
+    /// Applies the value of a Button to the pending state.
+    fn handle_key(&mut self, code: u16, value: i32) {
+        let pressed = value != 0;
+
+        if let Some(&(_, button)) = BUTTON_MAP.iter().find(|(c, _)| *c == code) {
+            self.pending.set_button(button, pressed);
+        }
+
+        // Digital Triggers are only used when the matching analog Trigger is missing.
+        if let Some(&(_, axis)) = TRIGGER_MAP.iter().find(|(c, _)| *c == code) {
+            let analog = AXIS_MAP
+                .iter()
+                .any(|&(c, a)| a == axis && self.range(c).is_some());
+            if !analog {
+                self.pending.set_axis(axis, pressed as u8 as f32);
+            }
+        }
+    }
+
+    /// Applies the value of an Absolute Axis to the pending state.
+    fn handle_abs(&mut self, code: u16, value: i32) {
+        let range = match self.range(code) {
+            Some(range) => range,
+            None => return,
+        };
+
+        // Normalized to [0..1].
+        let unit = (value - range.minimum) as f32 / (range.maximum - range.minimum) as f32;
+        let unit = unit.clamp(0.0, 1.0);
+
+        match code {
+            // The D-Pad is reported as a Hat by most drivers, with up/left being negative.
+            sys::ABS_HAT0X => {
+                self.pending.set_button(GamepadButton::DpadLeft, value < 0);
+                self.pending.set_button(GamepadButton::DpadRight, value > 0);
+            }
+            sys::ABS_HAT0Y => {
+                self.pending.set_button(GamepadButton::DpadUp, value < 0);
+                self.pending.set_button(GamepadButton::DpadDown, value > 0);
+            }
+            _ => {
+                let axis = match AXIS_MAP.iter().find(|(c, _)| *c == code) {
+                    Some(&(_, axis)) => axis,
+                    None => return,
+                };
+
+                // Values within the dead zone of the device are at rest (centered, or released for Triggers).
+                let center = range.minimum + (range.maximum - range.minimum) / 2;
+                let unit = match axis {
+                    GamepadAxis::TriggerL | GamepadAxis::TriggerR => {
+                        if value - range.minimum <= range.flat {
+                            0.0
+                        } else {
+                            unit
+                        }
+                    }
+                    _ if (value - center).abs() <= range.flat => 0.5,
+                    _ => unit,
+                };
+
+                // evdev reports down as positive, but `Gamepad` reports up as positive (like XInput).
+                let value = match axis {
+                    GamepadAxis::StickLX | GamepadAxis::StickRX => unit * 2.0 - 1.0,
+                    GamepadAxis::StickLY | GamepadAxis::StickRY => 1.0 - unit * 2.0,
+                    GamepadAxis::TriggerL | GamepadAxis::TriggerR => unit,
+                };
+                self.pending.set_axis(axis, value);
+            }
+        }
+    }
+
+    /// Returns the range of an Absolute Axis, if the device supports it.
+    fn range(&self, code: u16) -> Option<AxisRange> {
+        self.ranges.iter().find(|range| range.code == code).copied()
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Keeps track of all connected Gamepads.
+pub(crate) struct GamepadMonitor {
+    /// Watches the device directory for added/removed device nodes.\
+    /// `None` if the device directory cannot be watched, in which case no Gamepads are reported.
+    inotify: Option<OwnedFd>,
+
+    /// The currently connected Gamepads.
+    devices: Vec<GamepadDevice>,
+
+    /// The ID for the next connected Gamepad.
+    next_id: GamepadId,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl GamepadMonitor {
+    /// Starts watching for Gamepads, registering all File Descriptors with the `Epoller`.\
+    /// Gamepads that are already connected are not reported as updates.
+    pub(crate) fn new(epoller: &Epoller) -> Self {
+        let mut this = Self {
+            inotify: None,
+            devices: Vec::new(),
+            next_id: 0,
+        };
+
+        let raw_fd = unsafe { sys::inotify_init1(sys::IN_NONBLOCK | sys::IN_CLOEXEC) };
+        if raw_fd == -1 {
+            return this;
+        }
+
+        // SAFETY: The File Descriptor was just opened, and is owned by nothing else.
+        let inotify = unsafe { OwnedFd::from_raw_fd(raw_fd) };
+
+        // Device nodes are usually created before udev grants access to them, so attribute changes are watched as well.
+        let c_dir = CString::new(INPUT_DIR).unwrap();
+        let mask = sys::IN_CREATE | sys::IN_DELETE | sys::IN_ATTRIB;
+        let res = unsafe { sys::inotify_add_watch(inotify.as_raw_fd(), c_dir.as_ptr(), mask) };
+        if res == -1 {
+            return this;
+        }
+
+        epoller.register(inotify.as_raw_fd());
+        this.inotify = Some(inotify);
+        this.rescan(epoller, &mut Vec::new());
+        this
+    }
+
+    /// Returns whether or not Gamepads can be detected.
+    pub(crate) fn is_available(&self) -> bool {
+        self.inotify.is_some()
+    }
+
+    /// Returns a snapshot of every connected Gamepad.
+    pub(crate) fn collect(&self) -> Vec<Gamepad> {
+        self.devices.iter().map(|device| device.state).collect()
+    }
+
+    /// Processes a File Descriptor that was signaled by the `Epoller`, and returns the resulting updates.\
+    /// Returns `None` if the File Descriptor does not belong to the monitor.
+    pub(crate) fn process(&mut self, epoller: &Epoller, fd: RawFd) -> Option<Vec<GamepadUpdate>> {
+        let mut updates = Vec::new();
+
+        if let Some(inotify) = &self.inotify {
+            if inotify.as_raw_fd() == fd {
+                Self::drain(inotify);
+                self.rescan(epoller, &mut updates);
+                return Some(updates);
+            }
+        }
+
+        match self
+            .devices
+            .iter()
+            .position(|device| device.fd.as_raw_fd() == fd)
+        {
+            Some(index) => {
+                if !self.devices[index].read(&mut updates) {
+                    self.remove(epoller, index, &mut updates);
+                }
+                Some(updates)
+            }
+            None => None,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl GamepadMonitor {
+    /// Discards all pending notifications, since the directory is rescanned as a whole.
+    fn drain(inotify: &OwnedFd) {
+        let mut buffer = [0u8; 4096];
+        loop {
+            let res = unsafe {
+                sys::read(
+                    inotify.as_raw_fd(),
+                    buffer.as_mut_ptr() as *mut c_void,
+                    buffer.len(),
+                )
+            };
+            if res <= 0 {
+                break;
+            }
+        }
+    }
+
+    /// Removes Gamepads whose device nodes were deleted, and opens any new Gamepads.
+    fn rescan(&mut self, epoller: &Epoller, updates: &mut Vec<GamepadUpdate>) {
+        let mut index = 0;
+        while index < self.devices.len() {
+            match self.devices[index].path.exists() {
+                true => index += 1,
+                false => self.remove(epoller, index, updates),
+            }
+        }
+
+        let entries = match std::fs::read_dir(INPUT_DIR) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .as_bytes()
+                    .starts_with(DEVICE_PREFIX.as_bytes())
+            })
+            .map(|entry| entry.path())
+            .filter(|path| !self.devices.iter().any(|device| device.path == *path))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            if let Some(device) = GamepadDevice::open(&path, self.next_id) {
+                epoller.register(device.fd.as_raw_fd());
+                updates.push(GamepadUpdate::Connected(device.state));
+                self.devices.push(device);
+                self.next_id += 1;
+            }
+        }
+    }
+
+    /// Stops watching a Gamepad, and closes its device node.
+    fn remove(&mut self, epoller: &Epoller, index: usize, updates: &mut Vec<GamepadUpdate>) {
+        let device = self.devices.remove(index);
+        epoller.unregister(device.fd.as_raw_fd());
+        updates.push(GamepadUpdate::Disconnected(device.state.id()));
+    }
+}
+
+// ================================================================================================================================ //
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a device with the given Axis ranges (code, minimum, maximum, flat), which reads from `/dev/null`.
+    fn device(ranges: &[(u16, i32, i32, i32)]) -> GamepadDevice {
+        let file = std::fs::File::open("/dev/null").unwrap();
+        let ranges = ranges
+            .iter()
+            .map(|&(code, minimum, maximum, flat)| AxisRange {
+                code,
+                minimum,
+                maximum,
+                flat,
+            })
+            .collect();
+
+        GamepadDevice {
+            path: PathBuf::from("/dev/null"),
+            fd: OwnedFd::from(file),
+            ranges,
+            pending: Gamepad::new(0),
+            state: Gamepad::new(0),
+            dropped: false,
+        }
+    }
+
+    #[test]
+    fn handle_abs_normalizes() {
+        let mut device = device(&[
+            (sys::ABS_X, -32768, 32767, 0),
+            (sys::ABS_Y, -32768, 32767, 0),
+            (sys::ABS_Z, 0, 255, 0),
+        ]);
+        let axis = |device: &GamepadDevice, axis| device.pending.axis(axis);
+
+        device.handle_abs(sys::ABS_X, 32767);
+        assert_eq!(axis(&device, GamepadAxis::StickLX), 1.0);
+        device.handle_abs(sys::ABS_X, -32768);
+        assert_eq!(axis(&device, GamepadAxis::StickLX), -1.0);
+
+        // Down is positive for evdev, but negative for `Gamepad`.
+        device.handle_abs(sys::ABS_Y, -32768);
+        assert_eq!(axis(&device, GamepadAxis::StickLY), 1.0);
+        device.handle_abs(sys::ABS_Y, 32767);
+        assert_eq!(axis(&device, GamepadAxis::StickLY), -1.0);
+
+        device.handle_abs(sys::ABS_Z, 0);
+        assert_eq!(axis(&device, GamepadAxis::TriggerL), 0.0);
+        device.handle_abs(sys::ABS_Z, 255);
+        assert_eq!(axis(&device, GamepadAxis::TriggerL), 1.0);
+
+        // Values outside of the range are clamped, and unsupported Axes are ignored.
+        device.handle_abs(sys::ABS_Z, 1000);
+        assert_eq!(axis(&device, GamepadAxis::TriggerL), 1.0);
+        device.handle_abs(sys::ABS_RX, 32767);
+        assert_eq!(axis(&device, GamepadAxis::StickRX), 0.0);
+    }
+
+    #[test]
+    fn handle_abs_dead_zone() {
+        let mut device = device(&[(sys::ABS_X, -100, 100, 10), (sys::ABS_Z, 0, 255, 15)]);
+        let axis = |device: &GamepadDevice, axis| device.pending.axis(axis);
+
+        device.handle_abs(sys::ABS_X, 50);
+        assert_eq!(axis(&device, GamepadAxis::StickLX), 0.5);
+        device.handle_abs(sys::ABS_X, -10);
+        assert_eq!(axis(&device, GamepadAxis::StickLX), 0.0);
+        device.handle_abs(sys::ABS_X, 5);
+        assert_eq!(axis(&device, GamepadAxis::StickLX), 0.0);
+        device.handle_abs(sys::ABS_X, -100);
+        assert_eq!(axis(&device, GamepadAxis::StickLX), -1.0);
+
+        device.handle_abs(sys::ABS_Z, 15);
+        assert_eq!(axis(&device, GamepadAxis::TriggerL), 0.0);
+        device.handle_abs(sys::ABS_Z, 255);
+        assert_eq!(axis(&device, GamepadAxis::TriggerL), 1.0);
+    }
+
+    #[test]
+    fn hat_to_dpad() {
+        let mut device = device(&[(sys::ABS_HAT0X, -1, 1, 0), (sys::ABS_HAT0Y, -1, 1, 0)]);
+        let dpad = |device: &GamepadDevice| {
+            [
+                GamepadButton::DpadUp,
+                GamepadButton::DpadDown,
+                GamepadButton::DpadLeft,
+                GamepadButton::DpadRight,
+            ]
+            .map(|button| device.pending.button(button))
+        };
+
+        device.handle_abs(sys::ABS_HAT0X, -1);
+        assert_eq!(dpad(&device), [false, false, true, false]);
+        device.handle_abs(sys::ABS_HAT0Y, 1);
+        assert_eq!(dpad(&device), [false, true, true, false]);
+        device.handle_abs(sys::ABS_HAT0X, 1);
+        assert_eq!(dpad(&device), [false, true, false, true]);
+        device.handle_abs(sys::ABS_HAT0Y, -1);
+        assert_eq!(dpad(&device), [true, false, false, true]);
+        device.handle_abs(sys::ABS_HAT0X, 0);
+        device.handle_abs(sys::ABS_HAT0Y, 0);
+        assert_eq!(dpad(&device), [false, false, false, false]);
+    }
+
+    #[test]
+    fn digital_trigger_fallback() {
+        // Without an analog Trigger, the Trigger Button fully presses the Trigger Axis.
+        let mut digital = device(&[]);
+        digital.handle_key(sys::BTN_TL2, 1);
+        assert_eq!(digital.pending.axis(GamepadAxis::TriggerL), 1.0);
+        digital.handle_key(sys::BTN_TL2, 0);
+        assert_eq!(digital.pending.axis(GamepadAxis::TriggerL), 0.0);
+
+        // With an analog Trigger (`ABS_Z` or `ABS_BRAKE`), the Trigger Button is ignored.
+        let mut analog = device(&[(sys::ABS_BRAKE, 0, 255, 0), (sys::ABS_GAS, 0, 255, 0)]);
+        analog.handle_key(sys::BTN_TL2, 1);
+        analog.handle_key(sys::BTN_TR2, 1);
+        assert_eq!(analog.pending.axis(GamepadAxis::TriggerL), 0.0);
+        assert_eq!(analog.pending.axis(GamepadAxis::TriggerR), 0.0);
+        analog.handle_abs(sys::ABS_BRAKE, 255);
+        assert_eq!(analog.pending.axis(GamepadAxis::TriggerL), 1.0);
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,21 @@
+/*
+ *  Crate: Wyn
+ * Module: Linux
+ */
+
+//! Internally-used items shared between the Linux backends (X11 and Wayland).
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+// ================================================================================================================================ //
+
+pub(crate) mod epoll;
+
+pub(crate) mod evdev;
+
+pub(crate) mod xkb;
+
+// ================================================================================================================================ //
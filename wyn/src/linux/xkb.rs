@@ -0,0 +1,422 @@
+/*
+ *  Crate: Wyn
+ * Module: Linux - XKB
+ */
+
+//! Keyboard handling through `libxkbcommon`, shared by the X11 and Wayland backends.
+//!
+//! Both backends report Hardware Keycodes, which are translated into KeySyms (for `Key`s) and text (for Character Input).\
+//! Only the source of the Keymap and the Modifier State differs between them.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use crate::common::inputs::{Key, KeyLocation, Modifiers};
+
+// ================================================================================================================================ //
+
+// XKB only has sided modifiers (e.g. `XK_Shift_L`), so the generic `Key::Shift`/`Key::Control`/`Key::Alt` have no KeySym.
+// They are still reported in key events (see `translate_keysyms`), to match Win32.
+
+/// Translation table between `Key`s and KeySyms, shared by the X11 and Wayland backends.
+#[rustfmt::skip]
+pub(crate) const KEY_MAP: &[(Key, sys::xkb_keysym_t)] = &[
+    (Key::Backspace,       0xff08),      // XK_BackSpace
+    (Key::Tab,             0xff09),      // XK_Tab
+    (Key::Clear,           0xff0b),      // XK_Clear
+    (Key::Enter,           0xff0d),      // XK_Return
+    (Key::Pause,           0xff13),      // XK_Pause
+    (Key::CapsLock,        0xffe5),      // XK_Caps_Lock
+    (Key::Escape,          0xff1b),      // XK_Escape
+    (Key::Space,           0x0020),      // XK_space
+    (Key::PageUp,          0xff55),      // XK_Prior
+    (Key::PageDown,        0xff56),      // XK_Next
+    (Key::End,             0xff57),      // XK_End
+    (Key::Home,            0xff50),      // XK_Home
+    (Key::Left,            0xff51),      // XK_Left
+    (Key::Up,              0xff52),      // XK_Up
+    (Key::Right,           0xff53),      // XK_Right
+    (Key::Down,            0xff54),      // XK_Down
+    (Key::PrintScreen,     0xff61),      // XK_Print
+    (Key::Insert,          0xff63),      // XK_Insert
+    (Key::Delete,          0xffff),      // XK_Delete
+    (Key::Help,            0xff6a),      // XK_Help
+    (Key::Num0,            0x0030),      // XK_0
+    (Key::Num1,            0x0031),      // XK_1
+    (Key::Num2,            0x0032),      // XK_2
+    (Key::Num3,            0x0033),      // XK_3
+    (Key::Num4,            0x0034),      // XK_4
+    (Key::Num5,            0x0035),      // XK_5
+    (Key::Num6,            0x0036),      // XK_6
+    (Key::Num7,            0x0037),      // XK_7
+    (Key::Num8,            0x0038),      // XK_8
+    (Key::Num9,            0x0039),      // XK_9
+    (Key::A,               0x0041),      // XK_A
+    (Key::B,               0x0042),      // XK_B
+    (Key::C,               0x0043),      // XK_C
+    (Key::D,               0x0044),      // XK_D
+    (Key::E,               0x0045),      // XK_E
+    (Key::F,               0x0046),      // XK_F
+    (Key::G,               0x0047),      // XK_G
+    (Key::H,               0x0048),      // XK_H
+    (Key::I,               0x0049),      // XK_I
+    (Key::J,               0x004a),      // XK_J
+    (Key::K,               0x004b),      // XK_K
+    (Key::L,               0x004c),      // XK_L
+    (Key::M,               0x004d),      // XK_M
+    (Key::N,               0x004e),      // XK_N
+    (Key::O,               0x004f),      // XK_O
+    (Key::P,               0x0050),      // XK_P
+    (Key::Q,               0x0051),      // XK_Q
+    (Key::R,               0x0052),      // XK_R
+    (Key::S,               0x0053),      // XK_S
+    (Key::T,               0x0054),      // XK_T
+    (Key::U,               0x0055),      // XK_U
+    (Key::V,               0x0056),      // XK_V
+    (Key::W,               0x0057),      // XK_W
+    (Key::X,               0x0058),      // XK_X
+    (Key::Y,               0x0059),      // XK_Y
+    (Key::Z,               0x005a),      // XK_Z
+    (Key::LStart,          0xffeb),      // XK_Super_L
+    (Key::RStart,          0xffec),      // XK_Super_R
+    (Key::Menu,            0xff67),      // XK_Menu
+    (Key::Numpad0,         0xffb0),      // XK_KP_0
+    (Key::Numpad1,         0xffb1),      // XK_KP_1
+    (Key::Numpad2,         0xffb2),      // XK_KP_2
+    (Key::Numpad3,         0xffb3),      // XK_KP_3
+    (Key::Numpad4,         0xffb4),      // XK_KP_4
+    (Key::Numpad5,         0xffb5),      // XK_KP_5
+    (Key::Numpad6,         0xffb6),      // XK_KP_6
+    (Key::Numpad7,         0xffb7),      // XK_KP_7
+    (Key::Numpad8,         0xffb8),      // XK_KP_8
+    (Key::Numpad9,         0xffb9),      // XK_KP_9
+    (Key::Multiply,        0xffaa),      // XK_KP_Multiply
+    (Key::Add,             0xffab),      // XK_KP_Add
+    (Key::Separator,       0xffac),      // XK_KP_Separator
+    (Key::Subtract,        0xffad),      // XK_KP_Subtract
+    (Key::Decimal,         0xffae),      // XK_KP_Decimal
+    (Key::Divide,          0xffaf),      // XK_KP_Divide
+    (Key::F1,              0xffbe),      // XK_F1
+    (Key::F2,              0xffbf),      // XK_F2
+    (Key::F3,              0xffc0),      // XK_F3
+    (Key::F4,              0xffc1),      // XK_F4
+    (Key::F5,              0xffc2),      // XK_F5
+    (Key::F6,              0xffc3),      // XK_F6
+    (Key::F7,              0xffc4),      // XK_F7
+    (Key::F8,              0xffc5),      // XK_F8
+    (Key::F9,              0xffc6),      // XK_F9
+    (Key::F10,             0xffc7),      // XK_F10
+    (Key::F11,             0xffc8),      // XK_F11
+    (Key::F12,             0xffc9),      // XK_F12
+    (Key::F13,             0xffca),      // XK_F13
+    (Key::F14,             0xffcb),      // XK_F14
+    (Key::F15,             0xffcc),      // XK_F15
+    (Key::F16,             0xffcd),      // XK_F16
+    (Key::F17,             0xffce),      // XK_F17
+    (Key::F18,             0xffcf),      // XK_F18
+    (Key::F19,             0xffd0),      // XK_F19
+    (Key::F20,             0xffd1),      // XK_F20
+    (Key::F21,             0xffd2),      // XK_F21
+    (Key::F22,             0xffd3),      // XK_F22
+    (Key::F23,             0xffd4),      // XK_F23
+    (Key::F24,             0xffd5),      // XK_F24
+    (Key::NumLock,         0xff7f),      // XK_Num_Lock
+    (Key::ScrollLock,      0xff14),      // XK_Scroll_Lock
+    (Key::LShift,          0xffe1),      // XK_Shift_L
+    (Key::RShift,          0xffe2),      // XK_Shift_R
+    (Key::LControl,        0xffe3),      // XK_Control_L
+    (Key::RControl,        0xffe4),      // XK_Control_R
+    (Key::LAlt,            0xffe9),      // XK_Alt_L
+    (Key::RAlt,            0xffea),      // XK_Alt_R
+    (Key::VolumeMute,      0x1008ff12),  // XF86XK_AudioMute
+    (Key::VolumeDown,      0x1008ff11),  // XF86XK_AudioLowerVolume
+    (Key::VolumeUp,        0x1008ff13),  // XF86XK_AudioRaiseVolume
+    (Key::Colon,           0x003b),      // XK_semicolon
+    (Key::Plus,            0x003d),      // XK_equal
+    (Key::Comma,           0x002c),      // XK_comma
+    (Key::Minus,           0x002d),      // XK_minus
+    (Key::Period,          0x002e),      // XK_period
+    (Key::ForwardSlash,    0x002f),      // XK_slash
+    (Key::Backtick,        0x0060),      // XK_grave
+    (Key::LBracket,        0x005b),      // XK_bracketleft
+    (Key::Backslash,       0x005c),      // XK_backslash
+    (Key::RBracket,        0x005d),      // XK_bracketright
+    (Key::Quote,           0x0027),      // XK_apostrophe
+];
+
+/// Translates the KeySyms of the first two Shift Levels of a key into a `Key`.
+pub(crate) fn translate_keysyms(first: sys::xkb_keysym_t, second: sys::xkb_keysym_t) -> Key {
+    // Keypad keys only report digits in the second level (the first level is e.g. `XK_KP_Home`),
+    // while Win32 always reports `VK_NUMPAD*` for them, so prefer the second level for those keys.
+    let keysym = match second {
+        0xffac | 0xffae | 0xffb0..=0xffb9 => second,
+        _ => first,
+    };
+
+    match keysym {
+        // Lowercase Latin letters (`XK_a`-`XK_z`) map to their uppercase KeySyms.
+        0x0061..=0x007a => Key::from_native(keysym - 0x20),
+
+        0xffe1 | 0xffe2 => Key::Shift,
+        0xffe3 | 0xffe4 => Key::Control,
+        0xffe7..=0xffea => Key::Alt,
+
+        _ => Key::from_native(keysym),
+    }
+}
+
+/// Returns where the key of a KeySym is on the keyboard.\
+/// Sided modifiers (e.g. `XK_Shift_L`) alternate between left and right, and Keypad KeySyms all start with `XK_KP_`.
+pub(crate) fn keysym_location(keysym: sys::xkb_keysym_t) -> KeyLocation {
+    match keysym {
+        // `XK_Caps_Lock` and `XK_Shift_Lock` sit in the middle of the sided modifiers.
+        0xffe5 | 0xffe6 => KeyLocation::Standard,
+        0xffe1..=0xffee if keysym % 2 == 1 => KeyLocation::Left,
+        0xffe1..=0xffee => KeyLocation::Right,
+        0xff80..=0xffbd => KeyLocation::Numpad,
+        _ => KeyLocation::Standard,
+    }
+}
+
+// ================================================================================================================================ //
+
+/// An XKB Keyboard, which translates key presses into `Key`s and text (including Compose sequences and Dead Keys).\
+/// The Keymap and State are provided by the backend, as they are obtained differently on X11 and Wayland.
+pub(crate) struct XkbKeyboard {
+    /// The XKB Context.
+    context: *mut sys::xkb_context,
+
+    /// The compiled Keymap (may be NULL, until one is provided).
+    keymap: *mut sys::xkb_keymap,
+
+    /// The Modifier/Layout state (may be NULL, until one is provided).
+    state: *mut sys::xkb_state,
+
+    /// The Compose Table for the current locale (may be NULL, if the locale has none).
+    compose_table: *mut sys::xkb_compose_table,
+
+    /// The in-progress Compose sequence (may be NULL, if there is no Compose Table).
+    compose_state: *mut sys::xkb_compose_state,
+}
+
+impl XkbKeyboard {
+    /// Creates the XKB Context and the Compose State, without a Keymap.\
+    /// Returns `None` if the XKB Context could not be created.
+    pub(crate) fn new() -> Option<Self> {
+        let context = unsafe { sys::xkb_context_new(sys::XKB_CONTEXT_NO_FLAGS) };
+        if context.is_null() {
+            return None;
+        }
+
+        // A missing Compose Table is not an error, it only disables Compose sequences and Dead Keys.
+        let locale = Self::locale();
+        let compose_table = unsafe {
+            sys::xkb_compose_table_new_from_locale(
+                context,
+                locale.as_ptr(),
+                sys::XKB_COMPOSE_COMPILE_NO_FLAGS,
+            )
+        };
+        let compose_state = if compose_table.is_null() {
+            null_mut()
+        } else {
+            unsafe { sys::xkb_compose_state_new(compose_table, sys::XKB_COMPOSE_STATE_NO_FLAGS) }
+        };
+
+        Some(Self {
+            context,
+            keymap: null_mut(),
+            state: null_mut(),
+            compose_table,
+            compose_state,
+        })
+    }
+
+    /// Returns the XKB Context, for compiling Keymaps.
+    pub(crate) fn context(&self) -> *mut sys::xkb_context {
+        self.context
+    }
+
+    /// Replaces the Keymap and State, taking ownership of both.
+    pub(crate) fn set_keymap(&mut self, keymap: *mut sys::xkb_keymap, state: *mut sys::xkb_state) {
+        // SAFETY: The `unref` functions ignore NULL pointers.
+        unsafe {
+            sys::xkb_state_unref(self.state);
+            sys::xkb_keymap_unref(self.keymap);
+        }
+        self.keymap = keymap;
+        self.state = state;
+    }
+
+    /// Updates the Modifier/Layout state, as reported by the Display Server.
+    pub(crate) fn update_mask(
+        &mut self,
+        depressed_mods: sys::xkb_mod_mask_t,
+        latched_mods: sys::xkb_mod_mask_t,
+        locked_mods: sys::xkb_mod_mask_t,
+        depressed_layout: sys::xkb_layout_index_t,
+        latched_layout: sys::xkb_layout_index_t,
+        locked_layout: sys::xkb_layout_index_t,
+    ) {
+        if self.state.is_null() {
+            return;
+        }
+
+        unsafe {
+            sys::xkb_state_update_mask(
+                self.state,
+                depressed_mods,
+                latched_mods,
+                locked_mods,
+                depressed_layout,
+                latched_layout,
+                locked_layout,
+            )
+        };
+    }
+
+    /// Translates a Hardware Keycode into a `Key`, independently of the current Modifiers.
+    pub(crate) fn key(&self, keycode: sys::xkb_keycode_t) -> Key {
+        let level = |level: sys::xkb_level_index_t| -> sys::xkb_keysym_t {
+            if self.keymap.is_null() {
+                return 0;
+            }
+
+            let mut syms = null();
+            let count = unsafe {
+                sys::xkb_keymap_key_get_syms_by_level(
+                    self.keymap,
+                    keycode,
+                    0,
+                    level,
+                    addr_of_mut!(syms),
+                )
+            };
+            if count > 0 && !syms.is_null() {
+                unsafe { *syms }
+            } else {
+                0
+            }
+        };
+
+        translate_keysyms(level(0), level(1))
+    }
+
+    /// Returns the KeySym of a key, in the current Layout and with the current Modifiers (`0` if there is none).
+    pub(crate) fn keysym(&self, keycode: sys::xkb_keycode_t) -> sys::xkb_keysym_t {
+        if self.state.is_null() {
+            return 0;
+        }
+
+        unsafe { sys::xkb_state_key_get_one_sym(self.state, keycode) }
+    }
+
+    /// Returns the Modifiers of the current state.\
+    /// Only the Wayland backend needs it, since X11 events carry their own Modifier Mask.
+    #[allow(unused)]
+    pub(crate) fn modifiers(&self) -> Modifiers {
+        let mut modifiers = Modifiers::NONE;
+        if self.state.is_null() {
+            return modifiers;
+        }
+
+        let names = [
+            (Modifiers::SHIFT, sys::XKB_MOD_NAME_SHIFT),
+            (Modifiers::CAPS_LOCK, sys::XKB_MOD_NAME_CAPS),
+            (Modifiers::CONTROL, sys::XKB_MOD_NAME_CTRL),
+            (Modifiers::ALT, sys::XKB_MOD_NAME_ALT),
+            (Modifiers::NUM_LOCK, sys::XKB_MOD_NAME_NUM),
+            (Modifiers::SUPER, sys::XKB_MOD_NAME_LOGO),
+        ];
+        for (flag, name) in names {
+            let active = unsafe {
+                sys::xkb_state_mod_name_is_active(
+                    self.state,
+                    name.as_ptr(),
+                    sys::XKB_STATE_MODS_EFFECTIVE,
+                )
+            };
+            modifiers.set(flag, active > 0);
+        }
+        modifiers
+    }
+
+    /// Feeds a key press through the Keyboard State and the Compose State, returning the text it produced (if any).
+    pub(crate) fn key_text(&mut self, keycode: sys::xkb_keycode_t) -> String {
+        if self.state.is_null() {
+            return String::new();
+        }
+
+        if !self.compose_state.is_null() {
+            let keysym = unsafe { sys::xkb_state_key_get_one_sym(self.state, keycode) };
+            let feed = unsafe { sys::xkb_compose_state_feed(self.compose_state, keysym) };
+
+            if feed == sys::XKB_COMPOSE_FEED_ACCEPTED {
+                match unsafe { sys::xkb_compose_state_get_status(self.compose_state) } {
+                    // A Compose sequence (or Dead Key) is in progress, so nothing is produced yet.
+                    sys::XKB_COMPOSE_COMPOSING => return String::new(),
+
+                    sys::XKB_COMPOSE_COMPOSED => {
+                        let mut buffer = [0 as c_char; 64];
+                        let len = unsafe {
+                            sys::xkb_compose_state_get_utf8(
+                                self.compose_state,
+                                buffer.as_mut_ptr(),
+                                buffer.len(),
+                            )
+                        };
+                        unsafe { sys::xkb_compose_state_reset(self.compose_state) };
+
+                        // The returned length excludes the NULL-terminator, and may exceed the buffer if truncated.
+                        let len = (len.max(0) as usize).min(buffer.len() - 1);
+                        let bytes = unsafe {
+                            core::slice::from_raw_parts(buffer.as_ptr() as *const u8, len)
+                        };
+                        return String::from_utf8_lossy(bytes).into_owned();
+                    }
+
+                    sys::XKB_COMPOSE_CANCELLED => {
+                        unsafe { sys::xkb_compose_state_reset(self.compose_state) };
+                        return String::new();
+                    }
+
+                    _ => {}
+                }
+            }
+        }
+
+        let utf32 = unsafe { sys::xkb_state_key_get_utf32(self.state, keycode) };
+        char::from_u32(utf32)
+            .filter(|chr| *chr != '\0')
+            .map(String::from)
+            .unwrap_or_default()
+    }
+
+    /// Internal function that determines the locale used for Compose sequences, following the usual precedence.
+    fn locale() -> CString {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .find_map(|var| std::env::var(var).ok().filter(|val| !val.is_empty()))
+            .unwrap_or_else(|| String::from("C"));
+
+        // Environment variables cannot contain NULL bytes on Unix.
+        CString::new(locale).expect("Locale should not contain NULL bytes.")
+    }
+}
+
+impl Drop for XkbKeyboard {
+    fn drop(&mut self) {
+        // SAFETY: The `unref` functions ignore NULL pointers.
+        unsafe {
+            sys::xkb_compose_state_unref(self.compose_state);
+            sys::xkb_compose_table_unref(self.compose_table);
+            sys::xkb_state_unref(self.state);
+            sys::xkb_keymap_unref(self.keymap);
+            sys::xkb_context_unref(self.context);
+        }
+    }
+}
+
+// ================================================================================================================================ //
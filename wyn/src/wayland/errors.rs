@@ -0,0 +1,77 @@
+/*
+ *  Crate: Wyn
+ * Module: Wayland - Errors
+ */
+
+//! Errors reported by the Wayland backend.
+//!
+//! Wayland requests do not fail individually: a Protocol Error is fatal, and closes the whole connection.\
+//! As such, the only errors are those of the connection itself.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use std::error::Error;
+
+// ================================================================================================================================ //
+
+/// Native error-code for Wayland Connection Errors (an `errno` value).
+pub(crate) type NativeWlConnectionCode = ::std::os::raw::c_int;
+
+/// Nonzero-type for native Wayland Connection Errors.
+pub(crate) type NonzeroWlConnectionCode = NonZero<NativeWlConnectionCode>;
+
+/// A wrapper for Wayland Connection Errors.
+#[repr(transparent)]
+pub struct WlConnectionError(NonzeroWlConnectionCode);
+
+/// A result for Wayland Connections.
+pub type WlConnectionResult<T> = Result<T, WlConnectionError>;
+
+impl WlConnectionError {
+    /// Attempts to construct a new `WlConnectionError`.
+    pub(crate) fn new(code: NativeWlConnectionCode) -> Option<Self> {
+        NonzeroWlConnectionCode::new(code).map(Self)
+    }
+
+    /// Constructs a `WlConnectionError` from the `errno` of the last failed call.\
+    /// Falls back to `ECONNREFUSED`, in case the call failed without setting `errno`.
+    pub(crate) fn last_os_error() -> Self {
+        let code = std::io::Error::last_os_error().raw_os_error();
+        code.and_then(Self::new).unwrap_or(Self(
+            NonzeroWlConnectionCode::new(sys::ECONNREFUSED).unwrap(),
+        ))
+    }
+
+    /// Returns the Error Code (`errno`) associated with this error.
+    pub fn code(&self) -> NativeWlConnectionCode {
+        self.0.get()
+    }
+
+    /// Returns the Description of this error.
+    pub fn description(&self) -> String {
+        std::io::Error::from_raw_os_error(self.code()).to_string()
+    }
+}
+
+impl Error for WlConnectionError {}
+
+impl Debug for WlConnectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = self.code();
+        let desc = self.description();
+        write!(f, "Wayland Connection Error ({desc}) [{code}]")
+    }
+}
+
+impl Display for WlConnectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = self.code();
+        let desc = self.description();
+        write!(f, "Wayland Connection Error ({desc}) [{code}]")
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,1004 @@
+/*
+ *  Crate: Wyn
+ * Module: Wayland - Event Loop
+ */
+
+//! The Event Loop for the Wayland backend.
+//!
+//! Wayland delivers events through listener callbacks, while `libwayland-client` is dispatching.\
+//! The listeners only update the client-side state and queue `WlEvent`s, which are reported to the `EventHandler`
+//! once dispatching finished, so that User-Code never runs inside `libwayland-client` (or while the state is locked).
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::clipboard::LocalClipboards;
+use super::errors::*;
+use super::events::{Capability, EventHandler, GamepadUpdate};
+use super::inputs::*;
+use super::screen::{WlOutput, OUTPUT_LISTENER};
+use super::types::*;
+use super::window::{Window, WindowHandle, WlWindow};
+use crate::linux::epoll::{EpollResult, Epoller};
+use crate::linux::evdev::GamepadMonitor;
+use crate::linux::xkb::XkbKeyboard;
+use crate::tasks::{ExecFuture, Task};
+
+use std::ffi::CStr;
+use std::os::fd::RawFd;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard, TryLockError};
+use std::thread::ThreadId;
+
+// ================================================================================================================================ //
+
+/// Holds the state/context required to run a native Event/Message Loop.\
+/// Only one `EventLoop` may exist at any given moment, and it may or may not be running.
+/// ## Notes
+/// On some platforms, it is required to run the `EventLoop` on the Main Thread.
+pub struct EventLoop<'a> {
+    /// The Event Handler that responds to events.
+    pub(crate) handler: &'a dyn EventHandler,
+
+    /// A list of callback functions awaiting to be executed.
+    pub(crate) tasks: Mutex<Vec<Task>>,
+
+    /// Flag to indicate whether the Event Loop is running or not.
+    running: AtomicBool,
+
+    /// Condition Variable for other threads to await Event Loop startup/termination.
+    condvar: Condvar,
+
+    /// The Thread-ID for the Event Thread.
+    thread_id: ThreadId,
+
+    /// The client-side state of all Wayland objects, shared with the listener callbacks.\
+    /// Boxed, so that its address (passed to the listeners) is stable, and dropped before the connection.
+    pub(crate) state: Box<Mutex<WlState>>,
+
+    /// The connected Gamepads.
+    pub(crate) gamepads: Mutex<GamepadMonitor>,
+
+    /// Allows signalling from other threads while waiting on events.
+    pub(crate) epoller: Epoller,
+
+    /// Wayland Display Connection.
+    pub(crate) connection: WlConnection,
+
+    /// The in-memory Clipboards, until the Data-Device protocol is implemented.
+    pub(crate) clipboards: LocalClipboards,
+
+    #[allow(unused)]
+    /// Lock that guarantees Exclusive-Access to the Event Thread.
+    lock: MutexGuard<'a, ()>,
+}
+
+// [These trait implementations are implicit, but noted here for clarity]
+// impl !Send for EventLoop<'_> {}
+unsafe impl Sync for EventLoop<'_> {}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Mutex to prevent creating multiple `EventLoop` objects at the same time.
+static EVENTLOOP_MUTEX: Mutex<()> = Mutex::new(());
+
+/// Reference to the currently running EventLoop.\
+/// Lifetime is tied to an `EventLoopGuard` object in the `EventLoop::run` function.\
+/// ## SAFETY
+/// Should only ever be accessed by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.\
+/// Treat as Mutable in `EventLoopGuard` code, and Immutable elsewhere.
+pub(crate) static mut EVENTLOOP: Option<&'static EventLoop<'static>> = None;
+
+/// Drop-Guard for an `EventLoop`.\
+/// Initializes the static `EventLoop` on creation, and Removes it when dropped.
+struct EventLoopGuard<'a> {
+    /// The actual `EventLoop` whose reference is temporarily placed at static scope.
+    events: &'a EventLoop<'a>,
+}
+
+impl<'a> EventLoopGuard<'a> {
+    /// Constructs a new `EventLoopGuard`.
+    /// ## PANICS
+    /// * Panics if the Calling Thread is not the Event Thread.
+    fn new(events: &'a EventLoop<'a>) -> Self {
+        if !events.is_this_thread() {
+            panic!("Attempted to run Event Loop while not on the Event Thread!");
+        }
+
+        // `transmute` in order to cast to `'static` lifetime.
+        // SAFETY: This reference is tied to the lifetime of this object, and is removed when this object is dropped.
+        let static_ref = unsafe { transmute(events) };
+
+        // SAFETY: We are on the Event Thread and have Exclusive-Access (via the EventLoop's lock).
+        let _ = unsafe { EVENTLOOP.insert(static_ref) };
+
+        Self { events }
+    }
+}
+
+impl<'a> Drop for EventLoopGuard<'a> {
+    fn drop(&mut self) {
+        self.events.set_running(false);
+
+        // SAFETY: We are on the Event Thread and have Exclusive-Access (via the EventLoop's lock).
+        let _ = unsafe { EVENTLOOP.take() };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The `panic` that was caught to cross FFI-boundaries.\
+/// Lifetime is tied to an `EventLoopGuard` object in the `EventLoop::run` function.\
+/// ## SAFETY
+/// Should only ever be accessed by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.
+static mut PANIC: Option<BoxedPanic> = None;
+
+/// Flag to indicate a Panic is occuring to other threads.
+static PANIC_FLAG: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether or not there was a Panic stored.
+fn is_panicking() -> bool {
+    PANIC_FLAG.load(Ordering::Acquire)
+}
+
+/// Stores a `panic` to carry across FFI-boundaries.\
+/// If a second `panic` is attempted to be stored while a previous one is stored, the process will abort.
+/// ## SAFETY
+/// Should only ever be called by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.
+unsafe fn store_panic(err: BoxedPanic) {
+    match &mut PANIC {
+        // There was not a previous `panic`, so store this one and tell the Event Loop to shut down.
+        None => {
+            let _ = PANIC.insert(err);
+            PANIC_FLAG.store(true, Ordering::Release);
+
+            if let Some(events) = EVENTLOOP {
+                events.request_stop();
+                events.condvar.notify_all();
+            }
+        }
+        // There was already a previous `panic`, so abort the process.
+        Some(_err) => std::process::abort(),
+    };
+}
+
+/// Resumes a `panic` that was caught earlier, if there is one.
+/// ## SAFETY
+/// Should only ever be called by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.
+unsafe fn resume_if_panicking() {
+    if let Some(err) = PANIC.take() {
+        std::panic::resume_unwind(err);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl<'a> EventLoop<'a> {
+    /// Constructs a new `EventLoop`, using the provided `EventHandler`.\
+    /// Returns an error if the Wayland Compositor cannot be connected to (e.g. `WAYLAND_DISPLAY` is not set).
+    /// ## PANICS
+    /// * Panics if another `EventLoop` object exists and holds the Event Thread lock.
+    /// * Panics if a previous `EventLoop` panicked while holding the Event Thread lock.
+    /// * Panics if the Wayland Compositor does not support `xdg-shell`.
+    pub fn new(handler: &'a dyn EventHandler) -> WlConnectionResult<Self> {
+        let lock = match EVENTLOOP_MUTEX.try_lock() {
+            Ok(lock) => lock,
+            Err(TryLockError::WouldBlock) => {
+                panic!("Attempted to create multiple Event Loops simultaneously!")
+            }
+            Err(TryLockError::Poisoned(_)) => {
+                panic!("Attempted to create a poisoned Event Loop after panic!")
+            }
+        };
+
+        let tasks = Mutex::new(Vec::new());
+        let running = AtomicBool::new(false);
+        let condvar = Condvar::new();
+        let thread_id = std::thread::current().id();
+
+        let connection = WlConnection::new()?;
+        let epoller = Epoller::new(connection.fd());
+        let state = Box::new(Mutex::new(WlState::new()));
+        let gamepads = Mutex::new(GamepadMonitor::new(&epoller));
+        let clipboards = LocalClipboards::default();
+
+        let this = Self {
+            handler,
+            tasks,
+            running,
+            condvar,
+            thread_id,
+            state,
+            gamepads,
+            epoller,
+            connection,
+            clipboards,
+            lock,
+        };
+
+        let registry = unsafe { sys::wl_display_get_registry(this.connection.display) };
+        assert!(!registry.is_null());
+        unsafe {
+            sys::wl_registry_add_listener(registry, &REGISTRY_LISTENER, this.listener_data())
+        };
+        this.state.lock().unwrap().registry = registry;
+
+        // The first roundtrip binds the globals, and the second one receives their initial state (Outputs, Seat).
+        this.connection.roundtrip()?;
+        this.connection.roundtrip()?;
+
+        {
+            let mut state = this.state.lock().unwrap();
+
+            // The initial state of the Outputs is not a change.
+            state.queue.clear();
+            assert!(
+                !state.compositor.is_null(),
+                "The Wayland Compositor does not provide `wl_compositor`!"
+            );
+            assert!(
+                !state.wm_base.is_null(),
+                "The Wayland Compositor does not support `xdg-shell`!"
+            );
+        }
+
+        Ok(this)
+    }
+}
+
+impl Drop for EventLoop<'_> {
+    fn drop(&mut self) {}
+}
+
+// ================================================================================================================================ //
+
+impl EventLoop<'_> {
+    /// Starts a native Event/Message loop.
+    /// ## PANICS
+    /// * Panics if the Calling Thread is not the Event Thread.
+    /// * Panics if User-Code panics in an `EventHandler` callback.
+    pub fn run(&self) {
+        // SAFETY: This function is called only on the Event Thread.
+        let guard = EventLoopGuard::new(self);
+
+        // SAFETY: This function is called while holding an `EventLoopGuard`.
+        let func = || unsafe { guard.events.internal_run() };
+
+        // Catch the `panic` so that other threads waiting on the Event Loop are woken up before it resumes.
+        if let Err(err) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(func)) {
+            // SAFETY: This function is called while holding an `EventLoopGuard`.
+            unsafe { store_panic(err) };
+        }
+
+        drop(guard);
+
+        // SAFETY: This function is called on the Event Thread, which still holds the EVENTLOOP_MUTEX.
+        unsafe { resume_if_panicking() };
+    }
+
+    /// The internal native Event/Message Loop.
+    /// ## SAFETY
+    /// * Must be called on the Event Thread to function properly.
+    /// * Must be called while holding an `EventLoopGuard` to ensure the validity of the static EVENTLOOP.
+    unsafe fn internal_run(&self) {
+        self.handler.start(self);
+        self.set_running(true);
+
+        let on_exec = || self.clear_tasks();
+        let on_other = |fd| {
+            // The lock must be released before dispatching, since the Event Handler may collect the Gamepads.
+            // File Descriptors that are not watched by the Gamepad Monitor are ignored.
+            let updates = self.gamepads.lock().unwrap().process(&self.epoller, fd);
+            for update in updates.into_iter().flatten() {
+                update.dispatch(self);
+            }
+        };
+
+        // Gamepads that were connected before the Event Loop started are reported first.
+        for gamepad in Gamepad::collect(self) {
+            GamepadUpdate::Connected(gamepad).dispatch(self);
+        }
+
+        let status = loop {
+            // Events may have been queued outside of dispatching (e.g. by User-Code in `start`, or by Tasks).
+            self.dispatch_queue();
+
+            match self
+                .connection
+                .wait_events(&self.epoller, on_exec, on_other)
+            {
+                Ok(true) => {}
+                Ok(false) => break self.connection.status(),
+                Err(err) => break Err(err),
+            }
+        };
+        status.unwrap();
+
+        self.handler.stop(self);
+    }
+
+    /// Reports every queued `WlEvent` to the Event Handler, until the queue is empty.
+    fn dispatch_queue(&self) {
+        loop {
+            // The lock must be released before dispatching, since the Event Handler may call into Windows.
+            let queue = std::mem::take(&mut self.state.lock().unwrap().queue);
+            if queue.is_empty() {
+                break;
+            }
+
+            for event in queue {
+                self.dispatch_event(event);
+            }
+        }
+    }
+
+    /// Reports a single `WlEvent` to the Event Handler.
+    fn dispatch_event(&self, event: WlEvent) {
+        match event {
+            WlEvent::WindowClose(handle) => {
+                // Closes window, just in case the User callback `panic`s.
+                let closer = defer(|| Window::internal_destroy(self, handle));
+
+                self.handler.window_close(self, handle);
+
+                drop(closer);
+            }
+            WlEvent::WindowRedraw(handle) => {
+                self.handler.window_redraw(self, handle);
+            }
+            WlEvent::WindowReposition(handle) => {
+                self.handler.window_reposition(self, handle);
+            }
+            WlEvent::WindowFocus(handle, focused) => {
+                self.handler.window_focus(self, handle, focused);
+            }
+            WlEvent::CursorMove(handle, point) => {
+                self.handler.cursor_move(self, handle, point);
+            }
+            WlEvent::CursorEnter(handle, true) => {
+                self.handler.cursor_enter(self, handle);
+            }
+            WlEvent::CursorEnter(handle, false) => {
+                self.handler.cursor_leave(self, handle);
+            }
+            WlEvent::ScrollWheel(handle, delta_x, delta_y) => {
+                self.handler.scroll_wheel(self, handle, delta_x, delta_y);
+            }
+            WlEvent::ButtonPress(handle, button, pressed) => {
+                self.handler.button_press(self, handle, button, pressed);
+            }
+            WlEvent::KeyPress(handle, event, pressed) => {
+                self.handler.key_press(self, handle, event, pressed);
+            }
+            WlEvent::CharacterInput(handle, character) => {
+                self.handler.character_input(self, handle, character);
+            }
+            WlEvent::ScreensChanged => {
+                self.handler.screens_changed(self);
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventLoop<'_> {
+    /// Returns a boolean indicating whether or not the Calling Thread is the Event Thread.
+    pub fn is_this_thread(&self) -> bool {
+        self.thread_id == std::thread::current().id()
+    }
+
+    /// Returns a boolean indicating whether or not the Event Thread is running an Event/Message Loop.
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::Acquire) && self.connection.status().is_ok()
+    }
+
+    /// Returns whether or not the optional functionality is supported by this Event Loop.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::WindowRedraw => true,
+            // The Compositor does not report whether a Window is actually visible.
+            Capability::WindowVisibility => false,
+            // Key and Character Input require an XKB Context.
+            Capability::CharacterInput => self.state.lock().unwrap().xkb.is_some(),
+            // Gamepads require access to the evdev device directory.
+            Capability::Gamepads => self.gamepads.lock().unwrap().is_available(),
+            // The Wayland Data-Device protocol (Clipboards and Drag-and-Drop) is not implemented yet.
+            Capability::Clipboard => false,
+            Capability::PrimarySelection => false,
+            Capability::FileDrop => false,
+            Capability::FileDropHover => false,
+            // The Cursor-Shape, Pointer-Constraints, and Relative-Pointer protocols are not implemented yet.
+            Capability::CursorControl => false,
+            Capability::RawMotion => false,
+            // Surfaces are always drawn at a Scale Factor of `1.0`, and scaled up by the Compositor.
+            Capability::ScaleFactorChanged => false,
+            Capability::ScreensChanged => true,
+            Capability::InputMethod => false,
+            // Compositors do not let clients switch Video Modes, so Exclusive Fullscreen is Borderless.
+            Capability::VideoModes => false,
+            Capability::InputState => false,
+            Capability::CursorEnterLeave => true,
+            Capability::Touch => false,
+            Capability::Pen => false,
+        }
+    }
+
+    /// If an Event/Message Loop is running, then request it to terminate.
+    pub fn request_stop(&self) {
+        self.epoller.signal_quit();
+    }
+
+    /// Sleep the current thread until the Event Loop is running or it panicked.\
+    /// Returns `false` if the Event Thread panicked.
+    pub fn await_startup(&self) -> bool {
+        /// Dummy Mutex for `CondVar` functions.
+        static MUTEX: Mutex<()> = Mutex::new(());
+
+        let guard = MUTEX.lock().unwrap();
+        let _guard = self.condvar.wait_while(guard, |_| {
+            !is_panicking() && !self.running.load(Ordering::Acquire)
+        });
+
+        !is_panicking()
+    }
+
+    /// Sleep the current thread until the Event Loop is not running or it panicked.\
+    /// Returns `false` if the Event Thread panicked.
+    pub fn await_termination(&self) -> bool {
+        /// Dummy Mutex for `CondVar` functions.
+        static MUTEX: Mutex<()> = Mutex::new(());
+
+        let guard = MUTEX.lock().unwrap();
+        let _guard = self.condvar.wait_while(guard, |_| {
+            !is_panicking() && self.running.load(Ordering::Acquire)
+        });
+
+        !is_panicking()
+    }
+
+    /// Atomically sets the Running-Flag, and wakes any threads awaiting a change.
+    fn set_running(&self, status: bool) {
+        self.running.store(status, Ordering::Release);
+        self.condvar.notify_all();
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Functionality relating to querying the current state of the Keyboard and Mouse.
+///
+/// Wayland clients only learn about the Keyboard and Mouse through events, and cannot query them.
+impl EventLoop<'_> {
+    /// Returns whether or not the Key is currently held down.
+    pub fn key_state(&self, _key: KeyCode) -> bool {
+        false
+    }
+
+    /// Returns every Key that is currently held down.
+    pub fn pressed_keys(&self) -> Vec<KeyCode> {
+        Vec::new()
+    }
+
+    /// Returns every Mouse-Button that is currently held down.
+    pub fn mouse_buttons(&self) -> Vec<MouseButton> {
+        Vec::new()
+    }
+
+    /// Returns the position of the Mouse-Cursor, relative to the Inner-Content of the Window.
+    pub fn cursor_position(&self, _handle: WindowHandle) -> Option<Point> {
+        None
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Functionality relating to executing code on the Event Thread.
+///
+/// Sometimes, there are functions will only work if executed on the Main/Event Thread.
+/// Normally, said thread is under control of the library, and user-code can only execute during `EventHandler` callbacks (which can be limiting).
+/// These functions provide a way for users to temporarily take control of the Main/Event Thread
+/// from a separate thread, allowing them to execute any code that must be run on said thread.
+impl EventLoop<'_> {
+    /// Executes the provided callback function on the Event Thread, and returns the result.
+    pub fn execute<T, F>(&self, callback: F) -> ExecFuture<T>
+    where
+        T: Send + 'static,
+        F: Send + 'static + FnOnce() -> T,
+    {
+        if self.is_this_thread() {
+            let val = callback();
+            ExecFuture::new_sync(Some(val))
+        } else {
+            let (fut_recv, fut_send) = ExecFuture::new_async(None);
+
+            let task = Box::new(move || {
+                let val = callback();
+                fut_send.notify(val);
+            });
+
+            {
+                let mut tasks = self.tasks.lock().unwrap();
+                tasks.push(task);
+            }
+
+            self.signal_tasks();
+
+            fut_recv
+        }
+    }
+
+    /// Executes the provided callback function on the Event Thread, but discards the result.\
+    /// Because the return value is discarded, this removes some limitations (such as requiring `T` to be `Send + 'static`).
+    pub fn execute_discard<T, F>(&self, callback: F) -> ExecFuture<()>
+    where
+        F: Send + 'static + FnOnce() -> T,
+    {
+        if self.is_this_thread() {
+            let _ = callback();
+            ExecFuture::new_sync(Some(()))
+        } else {
+            let (fut_recv, fut_send) = ExecFuture::new_async(None);
+
+            let task = Box::new(move || {
+                let _ = callback();
+                fut_send.notify(());
+            });
+
+            {
+                let mut tasks = self.tasks.lock().unwrap();
+                tasks.push(task);
+            }
+
+            self.signal_tasks();
+
+            fut_recv
+        }
+    }
+
+    /// Executes the provided callback function on the Event Thread, but discards the result.\
+    /// Because the return value is discarded, this removes some limitations (such as requiring `T` to be `Send + 'static`).\
+    /// By not synchronizing the Calling Thread with the Event Thread, a lot of synchronization overhead is removed.\
+    /// This function will queue the callback function and immediately return, even if run on the Event Thread.
+    pub fn execute_detached<T, F>(&self, callback: F)
+    where
+        F: Send + 'static + FnOnce() -> T,
+    {
+        let task = Box::new(move || {
+            let _ = callback();
+        });
+
+        {
+            let mut tasks = self.tasks.lock().unwrap();
+            tasks.push(task);
+        }
+
+        self.signal_tasks();
+    }
+
+    /// Signals to the Event Thread that there are Tasks that need executed.
+    fn signal_tasks(&self) {
+        self.epoller.signal_exec();
+    }
+
+    /// If there are any Tasks waiting to be executed, remove them from the list and call them.
+    fn clear_tasks(&self) {
+        // Must be called from the Event Thread.
+        assert!(self.is_this_thread());
+
+        // Cannot hold the Tasks-lock while executing a task, otherwise a user-callback might cause a deadlock.
+        // As such, the lock must be re-acquired/released for each task.
+        let get_task = || {
+            let mut tasks = self.tasks.lock().unwrap();
+            tasks.pop()
+        };
+
+        // Remove all tasks from the list.
+        while let Some(task) = get_task() {
+            task();
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Wayland-exclusive functionality.
+impl EventLoop<'_> {
+    /// Returns the user-data pointer passed to every listener, which points to the shared `WlState`.
+    pub(crate) fn listener_data(&self) -> *mut c_void {
+        &*self.state as *const Mutex<WlState> as *mut c_void
+    }
+
+    /// Wakes the Event Thread, so that `WlEvent`s queued by another thread are reported without delay.
+    pub(crate) fn wake(&self) {
+        if !self.is_this_thread() {
+            self.signal_tasks();
+        }
+    }
+}
+
+// ================================================================================================================================ //
+// ================================================================================================================================ //
+// ================================================================================================================================ //
+// ================================================================================================================================ //
+// ================================================================================================================================ //
+
+/// Wrapper for Wayland Display Connections.
+pub(crate) struct WlConnection {
+    /// The underlying connection.
+    pub(crate) display: *mut sys::wl_display,
+}
+
+// ---------------------------------------------------------------- //
+
+impl WlConnection {
+    /// Opens a new Wayland Display Connection, to the Compositor named by `WAYLAND_DISPLAY`.
+    fn new() -> WlConnectionResult<Self> {
+        let display = unsafe { sys::wl_display_connect(null()) };
+        if display.is_null() {
+            return Err(WlConnectionError::last_os_error());
+        }
+
+        Ok(Self { display })
+    }
+}
+
+impl Drop for WlConnection {
+    fn drop(&mut self) {
+        unsafe { sys::wl_display_disconnect(self.display) };
+    }
+}
+
+// ---------------------------------------------------------------- //
+
+impl WlConnection {
+    /// Retrieves the File Descriptor for the connection.
+    pub(crate) fn fd(&self) -> RawFd {
+        let fd = unsafe { sys::wl_display_get_fd(self.display) };
+        assert_ne!(fd, -1);
+        fd
+    }
+
+    /// Retrieves the Status of this connection.
+    pub(crate) fn status(&self) -> WlConnectionResult<()> {
+        let res = unsafe { sys::wl_display_get_error(self.display) };
+        match WlConnectionError::new(res) {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Flushes requests sent over the connection.\
+    /// Failures are not reported here, as they are fatal to the connection, and reported by `status` instead.
+    pub(crate) fn flush(&self) {
+        let _ = unsafe { sys::wl_display_flush(self.display) };
+    }
+
+    /// Blocks until the Compositor processed every request sent so far, dispatching all the events received meanwhile.\
+    /// Must not be called while the `WlState` is locked, since the listeners lock it.
+    pub(crate) fn roundtrip(&self) -> WlConnectionResult<()> {
+        let res = unsafe { sys::wl_display_roundtrip(self.display) };
+        match res {
+            -1 => self.status(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Waits for events, and dispatches them to the listeners.\
+    /// The `on_exec` callback is invoked on the calling thread whenever the `Epoller` signals that Tasks are pending.\
+    /// The `on_other` callback is invoked on the calling thread whenever any other registered File Descriptor has data.\
+    /// Returns `false` once the Event Loop should terminate, or an error if waiting on the File Descriptors failed.
+    fn wait_events(
+        &self,
+        epoller: &Epoller,
+        on_exec: impl Fn(),
+        on_other: impl Fn(RawFd),
+    ) -> WlConnectionResult<bool> {
+        // Events may already be queued (e.g. read during a roundtrip), which won't signal the fd again.
+        if unsafe { sys::wl_display_prepare_read(self.display) } != 0 {
+            let res = unsafe { sys::wl_display_dispatch_pending(self.display) };
+            return Ok(res != -1);
+        }
+        self.flush();
+
+        // The read must be cancelled before running any callbacks, as those may issue a roundtrip themselves.
+        let res = epoller.wait();
+        if !matches!(res, EpollResult::Conn) {
+            unsafe { sys::wl_display_cancel_read(self.display) };
+        }
+
+        match res {
+            EpollResult::Failure(code) => match WlConnectionError::new(code) {
+                Some(err) => Err(err),
+                None => Ok(false),
+            },
+            EpollResult::Other(fd) => {
+                on_other(fd);
+                Ok(true)
+            }
+            EpollResult::Quit => Ok(false),
+            EpollResult::Exec => {
+                on_exec();
+                Ok(true)
+            }
+            EpollResult::Conn => {
+                // A failed read is fatal to the connection, and reported by `status`.
+                if unsafe { sys::wl_display_read_events(self.display) } == -1 {
+                    return Ok(false);
+                }
+                let res = unsafe { sys::wl_display_dispatch_pending(self.display) };
+                Ok(res != -1)
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// An event produced by a listener callback, which is reported to the Event Handler once dispatching finished.
+pub(crate) enum WlEvent {
+    /// The Compositor requested the Window to close.
+    WindowClose(WindowHandle),
+
+    /// The Window must be redrawn.
+    WindowRedraw(WindowHandle),
+
+    /// The Window was resized.
+    WindowReposition(WindowHandle),
+
+    /// The Window gained/lost Keyboard Focus.
+    WindowFocus(WindowHandle, bool),
+
+    /// The Cursor moved over the Window.
+    CursorMove(WindowHandle, Point),
+
+    /// The Cursor entered/left the Window.
+    CursorEnter(WindowHandle, bool),
+
+    /// The Scroll Wheel moved over the Window.
+    ScrollWheel(WindowHandle, f64, f64),
+
+    /// A Mouse Button was pressed/released over the Window.
+    ButtonPress(WindowHandle, MouseButton, bool),
+
+    /// A Key was pressed/released while the Window was focused.
+    KeyPress(WindowHandle, KeyEvent, bool),
+
+    /// Text was typed while the Window was focused.
+    CharacterInput(WindowHandle, char),
+
+    /// An Output was connected, disconnected, or changed.
+    ScreensChanged,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The client-side state of all Wayland objects.\
+/// Wayland cannot be queried, so everything the Compositor reports is tracked here by the listener callbacks.
+pub(crate) struct WlState {
+    /// The Registry of Globals.
+    pub(crate) registry: *mut sys::wl_registry,
+
+    /// The Compositor Global, which creates Surfaces.
+    pub(crate) compositor: *mut sys::wl_compositor,
+
+    /// The `xdg-shell` Global, which turns Surfaces into Windows.
+    pub(crate) wm_base: *mut sys::xdg_wm_base,
+
+    /// The Seat Global (only the first Seat is used).
+    pub(crate) seat: *mut sys::wl_seat,
+
+    /// The Pointer of the Seat, if it has one.
+    pub(crate) pointer: *mut sys::wl_pointer,
+
+    /// The Keyboard of the Seat, if it has one.
+    pub(crate) keyboard: *mut sys::wl_keyboard,
+
+    /// The Outputs (Screens), in the order they were announced.
+    pub(crate) outputs: Vec<WlOutput>,
+
+    /// The open Windows.
+    pub(crate) windows: Vec<WlWindow>,
+
+    /// The XKB Keyboard State, used for Key and Character Input (if an XKB Context could be created).
+    pub(crate) xkb: Option<XkbKeyboard>,
+
+    /// The Window the Pointer is over, or NULL.
+    pub(crate) pointer_focus: WindowHandle,
+
+    /// The Window with Keyboard Focus, or NULL.
+    pub(crate) keyboard_focus: WindowHandle,
+
+    /// The events awaiting to be reported to the Event Handler.
+    pub(crate) queue: Vec<WlEvent>,
+}
+
+impl WlState {
+    /// Constructs an empty `WlState`, to be filled in by the Registry.
+    fn new() -> Self {
+        Self {
+            registry: null_mut(),
+            compositor: null_mut(),
+            wm_base: null_mut(),
+            seat: null_mut(),
+            pointer: null_mut(),
+            keyboard: null_mut(),
+            outputs: Vec::new(),
+            windows: Vec::new(),
+            xkb: XkbKeyboard::new(),
+            pointer_focus: null_mut(),
+            keyboard_focus: null_mut(),
+            queue: Vec::new(),
+        }
+    }
+
+    /// Locks the shared `WlState`, from the user-data pointer of a listener callback.
+    /// ## SAFETY
+    /// The pointer must have been returned by `EventLoop::listener_data`, for an `EventLoop` that is still alive.
+    pub(crate) unsafe fn from_data<'a>(data: *mut c_void) -> MutexGuard<'a, WlState> {
+        (*(data as *const Mutex<WlState>)).lock().unwrap()
+    }
+
+    /// Returns the client-side state of an open Window.
+    pub(crate) fn window(&self, surface: WindowHandle) -> Option<&WlWindow> {
+        self.windows.iter().find(|window| window.surface == surface)
+    }
+
+    /// Returns the client-side state of an open Window, for modifying it.
+    pub(crate) fn window_mut(&mut self, surface: WindowHandle) -> Option<&mut WlWindow> {
+        self.windows
+            .iter_mut()
+            .find(|window| window.surface == surface)
+    }
+
+    /// Queues `WlEvent::ScreensChanged`, unless it is already queued (e.g. when several Outputs change at once).
+    pub(crate) fn queue_screens_changed(&mut self) {
+        let queued = self
+            .queue
+            .iter()
+            .any(|event| matches!(event, WlEvent::ScreensChanged));
+        if !queued {
+            self.queue.push(WlEvent::ScreensChanged);
+        }
+    }
+
+    /// Removes an open Window, clearing any focus it had.
+    pub(crate) fn remove_window(&mut self, surface: WindowHandle) -> Option<WlWindow> {
+        if self.pointer_focus == surface {
+            self.pointer_focus = null_mut();
+        }
+        if self.keyboard_focus == surface {
+            self.keyboard_focus = null_mut();
+        }
+
+        let index = self
+            .windows
+            .iter()
+            .position(|window| window.surface == surface)?;
+        Some(self.windows.remove(index))
+    }
+}
+
+impl Drop for WlState {
+    fn drop(&mut self) {
+        for window in self.windows.drain(..) {
+            window.destroy();
+        }
+        for output in self.outputs.drain(..) {
+            output.release();
+        }
+
+        unsafe {
+            if !self.pointer.is_null() {
+                release_pointer(self.pointer);
+            }
+            if !self.keyboard.is_null() {
+                release_keyboard(self.keyboard);
+            }
+            if !self.seat.is_null() {
+                release_seat(self.seat);
+            }
+            if !self.wm_base.is_null() {
+                sys::xdg_wm_base_destroy(self.wm_base);
+            }
+            if !self.compositor.is_null() {
+                sys::wl_compositor_destroy(self.compositor);
+            }
+            if !self.registry.is_null() {
+                sys::wl_registry_destroy(self.registry);
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The highest supported version of `wl_compositor`.
+const COMPOSITOR_VERSION: u32 = 4;
+
+/// The highest supported version of `xdg_wm_base`.
+const WM_BASE_VERSION: u32 = 6;
+
+/// The highest supported version of `wl_seat`.
+const SEAT_VERSION: u32 = 5;
+
+/// The highest supported version of `wl_output`.
+const OUTPUT_VERSION: u32 = 4;
+
+/// Listener for the Registry of Globals.
+static REGISTRY_LISTENER: sys::wl_registry_listener = sys::wl_registry_listener {
+    global: Some(registry_global),
+    global_remove: Some(registry_global_remove),
+};
+
+/// Listener for the `xdg-shell` Global.
+static WM_BASE_LISTENER: sys::xdg_wm_base_listener = sys::xdg_wm_base_listener {
+    ping: Some(wm_base_ping),
+};
+
+/// Binds the Globals that Wyn makes use of, as they are announced.
+unsafe extern "C" fn registry_global(
+    data: *mut c_void,
+    registry: *mut sys::wl_registry,
+    name: u32,
+    interface: *const c_char,
+    version: u32,
+) {
+    let mut state = WlState::from_data(data);
+
+    match CStr::from_ptr(interface).to_bytes() {
+        b"wl_compositor" if state.compositor.is_null() => {
+            let version = version.min(COMPOSITOR_VERSION);
+            let interface = &sys::wl_compositor_interface;
+            state.compositor = sys::wl_registry_bind(registry, name, interface, version) as _;
+        }
+        b"xdg_wm_base" if state.wm_base.is_null() => {
+            let version = version.min(WM_BASE_VERSION);
+            let interface = &sys::xdg_wm_base_interface;
+            state.wm_base = sys::wl_registry_bind(registry, name, interface, version) as _;
+            sys::xdg_wm_base_add_listener(state.wm_base, &WM_BASE_LISTENER, data);
+        }
+        b"wl_seat" if state.seat.is_null() => {
+            let version = version.min(SEAT_VERSION);
+            let interface = &sys::wl_seat_interface;
+            state.seat = sys::wl_registry_bind(registry, name, interface, version) as _;
+            sys::wl_seat_add_listener(state.seat, &SEAT_LISTENER, data);
+        }
+        b"wl_output" => {
+            let version = version.min(OUTPUT_VERSION);
+            let interface = &sys::wl_output_interface;
+            let output = sys::wl_registry_bind(registry, name, interface, version) as _;
+            sys::wl_output_add_listener(output, &OUTPUT_LISTENER, data);
+            state.outputs.push(WlOutput::new(name, output));
+        }
+        _ => {}
+    }
+}
+
+/// Forgets about Outputs that were disconnected, and reports it.\
+/// The other Globals Wyn binds are not expected to be removed during the lifetime of a Compositor.
+unsafe extern "C" fn registry_global_remove(
+    data: *mut c_void,
+    _registry: *mut sys::wl_registry,
+    name: u32,
+) {
+    let mut state = WlState::from_data(data);
+
+    if let Some(index) = state
+        .outputs
+        .iter()
+        .position(|output| output.global == name)
+    {
+        state.outputs.remove(index).release();
+        state.queue_screens_changed();
+    }
+}
+
+/// Answers the Compositor's liveness checks.
+unsafe extern "C" fn wm_base_ping(_data: *mut c_void, wm_base: *mut sys::xdg_wm_base, serial: u32) {
+    sys::xdg_wm_base_pong(wm_base, serial);
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,15 @@
+/*
+ *  Crate: Wyn
+ * Module: Wayland - Events
+ */
+
+//! Provides Event Handler callback functions.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+pub use crate::common::events::*;
+
+// ================================================================================================================================ //
@@ -0,0 +1,457 @@
+/*
+ *  Crate: Wyn
+ * Module: Wayland - Inputs
+ */
+
+//! Types for handling User-Input, such as through Mice/Pointers and Keyboards.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::event_loop::{EventLoop, WlEvent, WlState};
+use super::types::{NativePoint, Point};
+
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+// ================================================================================================================================ //
+
+/// Native OS Representation for Mouse Buttons.\
+/// Wayland reports the Linux Input Event Codes of the buttons (e.g. `BTN_LEFT`).
+pub type NativeMouseButton = u32;
+
+/// Native OS Representation for Key Codes.\
+/// Wayland Keycodes are translated into KeySyms through the Keymap sent by the Compositor.
+pub type NativeKeyCode = sys::xkb_keysym_t;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+pub use crate::common::inputs::*;
+
+/// Translation table between `MouseButton`s and Native Mouse Buttons.
+#[rustfmt::skip]
+pub(crate) const MOUSE_MAP: &[(MouseButton, NativeMouseButton)] = &[
+    (MouseButton::Left,   sys::BTN_LEFT as _),   // 0x110
+    (MouseButton::Middle, sys::BTN_MIDDLE as _), // 0x112
+    (MouseButton::Right,  sys::BTN_RIGHT as _),  // 0x111
+    (MouseButton::X1,     sys::BTN_SIDE as _),   // 0x113
+    (MouseButton::X2,     sys::BTN_EXTRA as _),  // 0x114
+];
+
+// The translation table between `Key`s and Native Key Codes is shared with the X11 backend.
+use crate::linux::xkb::keysym_location;
+pub(crate) use crate::linux::xkb::KEY_MAP;
+
+/// The distance the Compositor reports for a single notch of the Scroll Wheel (following libinput).
+const SCROLL_STEP: f64 = 10.0;
+
+/// The offset between Linux Input Event Codes (sent by Wayland) and XKB Keycodes.
+const XKB_KEYCODE_OFFSET: u32 = 8;
+
+// ================================================================================================================================ //
+
+impl Gamepad {
+    /// Returns a snapshot of every connected Gamepad.\
+    /// The ID of a Gamepad is assigned when it connects, and is not reused.
+    pub fn collect(events: &EventLoop) -> Vec<Gamepad> {
+        events.gamepads.lock().unwrap().collect()
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Releases a bound Seat.
+pub(crate) unsafe fn release_seat(seat: *mut sys::wl_seat) {
+    let version = sys::wl_proxy_get_version(seat as *mut sys::wl_proxy);
+    if version >= sys::WL_SEAT_RELEASE_SINCE_VERSION {
+        sys::wl_seat_release(seat);
+    } else {
+        sys::wl_seat_destroy(seat);
+    }
+}
+
+/// Releases the Pointer of a Seat.
+pub(crate) unsafe fn release_pointer(pointer: *mut sys::wl_pointer) {
+    let version = sys::wl_proxy_get_version(pointer as *mut sys::wl_proxy);
+    if version >= sys::WL_POINTER_RELEASE_SINCE_VERSION {
+        sys::wl_pointer_release(pointer);
+    } else {
+        sys::wl_pointer_destroy(pointer);
+    }
+}
+
+/// Releases the Keyboard of a Seat.
+pub(crate) unsafe fn release_keyboard(keyboard: *mut sys::wl_keyboard) {
+    let version = sys::wl_proxy_get_version(keyboard as *mut sys::wl_proxy);
+    if version >= sys::WL_KEYBOARD_RELEASE_SINCE_VERSION {
+        sys::wl_keyboard_release(keyboard);
+    } else {
+        sys::wl_keyboard_destroy(keyboard);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Listener for the Seat.
+pub(crate) static SEAT_LISTENER: sys::wl_seat_listener = sys::wl_seat_listener {
+    capabilities: Some(seat_capabilities),
+    name: Some(seat_name),
+};
+
+/// Acquires/Releases the Pointer and Keyboard, as they are added to/removed from the Seat.
+unsafe extern "C" fn seat_capabilities(
+    data: *mut c_void,
+    seat: *mut sys::wl_seat,
+    capabilities: u32,
+) {
+    let mut state = WlState::from_data(data);
+
+    let has_pointer = (capabilities & sys::WL_SEAT_CAPABILITY_POINTER) != 0;
+    if has_pointer && state.pointer.is_null() {
+        state.pointer = sys::wl_seat_get_pointer(seat);
+        sys::wl_pointer_add_listener(state.pointer, &POINTER_LISTENER, data);
+    } else if !has_pointer && !state.pointer.is_null() {
+        release_pointer(state.pointer);
+        state.pointer = null_mut();
+        state.pointer_focus = null_mut();
+    }
+
+    let has_keyboard = (capabilities & sys::WL_SEAT_CAPABILITY_KEYBOARD) != 0;
+    if has_keyboard && state.keyboard.is_null() {
+        state.keyboard = sys::wl_seat_get_keyboard(seat);
+        sys::wl_keyboard_add_listener(state.keyboard, &KEYBOARD_LISTENER, data);
+    } else if !has_keyboard && !state.keyboard.is_null() {
+        release_keyboard(state.keyboard);
+        state.keyboard = null_mut();
+        state.keyboard_focus = null_mut();
+    }
+}
+
+/// Ignored, as only the first Seat is used.
+unsafe extern "C" fn seat_name(_data: *mut c_void, _seat: *mut sys::wl_seat, _name: *const c_char) {
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Listener for the Pointer.
+static POINTER_LISTENER: sys::wl_pointer_listener = sys::wl_pointer_listener {
+    enter: Some(pointer_enter),
+    leave: Some(pointer_leave),
+    motion: Some(pointer_motion),
+    button: Some(pointer_button),
+    axis: Some(pointer_axis),
+    frame: Some(pointer_frame),
+    axis_source: Some(pointer_axis_source),
+    axis_stop: Some(pointer_axis_stop),
+    axis_discrete: Some(pointer_axis_discrete),
+};
+
+/// Converts Surface-Local coordinates into a `Point`.
+fn surface_point(x: sys::wl_fixed_t, y: sys::wl_fixed_t) -> Point {
+    // Fractional coordinates are truncated, like on every other backend.
+    let x = sys::wl_fixed_to_double(x) as i32;
+    let y = sys::wl_fixed_to_double(y) as i32;
+    Point::from(NativePoint { x, y })
+}
+
+/// The Pointer entered a Window.
+unsafe extern "C" fn pointer_enter(
+    data: *mut c_void,
+    _pointer: *mut sys::wl_pointer,
+    _serial: u32,
+    surface: *mut sys::wl_surface,
+    x: sys::wl_fixed_t,
+    y: sys::wl_fixed_t,
+) {
+    let mut state = WlState::from_data(data);
+
+    state.pointer_focus = surface;
+    if !surface.is_null() {
+        state.queue.push(WlEvent::CursorEnter(surface, true));
+        state
+            .queue
+            .push(WlEvent::CursorMove(surface, surface_point(x, y)));
+    }
+}
+
+/// The Pointer left a Window.
+unsafe extern "C" fn pointer_leave(
+    data: *mut c_void,
+    _pointer: *mut sys::wl_pointer,
+    _serial: u32,
+    surface: *mut sys::wl_surface,
+) {
+    let mut state = WlState::from_data(data);
+
+    state.pointer_focus = null_mut();
+    if !surface.is_null() {
+        state.queue.push(WlEvent::CursorEnter(surface, false));
+    }
+}
+
+/// The Pointer moved over the focused Window.
+unsafe extern "C" fn pointer_motion(
+    data: *mut c_void,
+    _pointer: *mut sys::wl_pointer,
+    _time: u32,
+    x: sys::wl_fixed_t,
+    y: sys::wl_fixed_t,
+) {
+    let mut state = WlState::from_data(data);
+
+    let surface = state.pointer_focus;
+    if !surface.is_null() {
+        state
+            .queue
+            .push(WlEvent::CursorMove(surface, surface_point(x, y)));
+    }
+}
+
+/// A Mouse Button was pressed/released over the focused Window.
+unsafe extern "C" fn pointer_button(
+    data: *mut c_void,
+    _pointer: *mut sys::wl_pointer,
+    _serial: u32,
+    _time: u32,
+    button: u32,
+    button_state: u32,
+) {
+    let mut state = WlState::from_data(data);
+
+    let surface = state.pointer_focus;
+    if !surface.is_null() {
+        let button = MouseButton::from_native(button);
+        let pressed = button_state == sys::WL_POINTER_BUTTON_STATE_PRESSED;
+        state
+            .queue
+            .push(WlEvent::ButtonPress(surface, button, pressed));
+    }
+}
+
+/// The Scroll Wheel moved over the focused Window.\
+/// Deltas are converted into notches, with the same signs as the other backends (Up and Right are positive).
+unsafe extern "C" fn pointer_axis(
+    data: *mut c_void,
+    _pointer: *mut sys::wl_pointer,
+    _time: u32,
+    axis: u32,
+    value: sys::wl_fixed_t,
+) {
+    let mut state = WlState::from_data(data);
+
+    let surface = state.pointer_focus;
+    if surface.is_null() {
+        return;
+    }
+
+    let delta = sys::wl_fixed_to_double(value) / SCROLL_STEP;
+    match axis {
+        sys::WL_POINTER_AXIS_VERTICAL_SCROLL => {
+            state.queue.push(WlEvent::ScrollWheel(surface, 0.0, -delta));
+        }
+        sys::WL_POINTER_AXIS_HORIZONTAL_SCROLL => {
+            state.queue.push(WlEvent::ScrollWheel(surface, delta, 0.0));
+        }
+        _ => {}
+    }
+}
+
+/// Ignored, as every Pointer event is reported as soon as it is received.
+unsafe extern "C" fn pointer_frame(_data: *mut c_void, _pointer: *mut sys::wl_pointer) {}
+
+/// Ignored, as the source of Scroll events is not reported.
+unsafe extern "C" fn pointer_axis_source(
+    _data: *mut c_void,
+    _pointer: *mut sys::wl_pointer,
+    _source: u32,
+) {
+}
+
+/// Ignored, as kinetic scrolling is not reported.
+unsafe extern "C" fn pointer_axis_stop(
+    _data: *mut c_void,
+    _pointer: *mut sys::wl_pointer,
+    _time: u32,
+    _axis: u32,
+) {
+}
+
+/// Ignored, as the continuous `axis` values are used instead.
+unsafe extern "C" fn pointer_axis_discrete(
+    _data: *mut c_void,
+    _pointer: *mut sys::wl_pointer,
+    _axis: u32,
+    _discrete: i32,
+) {
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Listener for the Keyboard.
+static KEYBOARD_LISTENER: sys::wl_keyboard_listener = sys::wl_keyboard_listener {
+    keymap: Some(keyboard_keymap),
+    enter: Some(keyboard_enter),
+    leave: Some(keyboard_leave),
+    key: Some(keyboard_key),
+    modifiers: Some(keyboard_modifiers),
+    repeat_info: Some(keyboard_repeat_info),
+};
+
+/// Compiles the Keymap sent by the Compositor (whenever the keyboard layout changes).
+unsafe extern "C" fn keyboard_keymap(
+    data: *mut c_void,
+    _keyboard: *mut sys::wl_keyboard,
+    format: u32,
+    fd: i32,
+    size: u32,
+) {
+    // The File Descriptor is owned by the client, and must be closed in every case.
+    let fd = OwnedFd::from_raw_fd(fd);
+
+    let mut state = WlState::from_data(data);
+    let xkb = match state.xkb.as_mut() {
+        Some(xkb) => xkb,
+        None => return,
+    };
+    if format != sys::WL_KEYBOARD_KEYMAP_FORMAT_XKB_V1 {
+        return;
+    }
+
+    let size = size as usize;
+    let map = sys::mmap(
+        null_mut(),
+        size,
+        sys::PROT_READ,
+        sys::MAP_PRIVATE,
+        fd.as_raw_fd(),
+        0,
+    );
+    if map == sys::MAP_FAILED {
+        return;
+    }
+
+    // The Keymap is a NUL-terminated string.
+    let keymap = sys::xkb_keymap_new_from_string(
+        xkb.context(),
+        map as *const c_char,
+        sys::XKB_KEYMAP_FORMAT_TEXT_V1,
+        sys::XKB_KEYMAP_COMPILE_NO_FLAGS,
+    );
+    sys::munmap(map, size);
+    if keymap.is_null() {
+        return;
+    }
+
+    let xkb_state = sys::xkb_state_new(keymap);
+    if xkb_state.is_null() {
+        sys::xkb_keymap_unref(keymap);
+        return;
+    }
+
+    xkb.set_keymap(keymap, xkb_state);
+}
+
+/// A Window gained Keyboard Focus.
+unsafe extern "C" fn keyboard_enter(
+    data: *mut c_void,
+    _keyboard: *mut sys::wl_keyboard,
+    _serial: u32,
+    surface: *mut sys::wl_surface,
+    _keys: *mut sys::wl_array,
+) {
+    let mut state = WlState::from_data(data);
+
+    state.keyboard_focus = surface;
+    if !surface.is_null() {
+        state.queue.push(WlEvent::WindowFocus(surface, true));
+    }
+}
+
+/// A Window lost Keyboard Focus.
+unsafe extern "C" fn keyboard_leave(
+    data: *mut c_void,
+    _keyboard: *mut sys::wl_keyboard,
+    _serial: u32,
+    surface: *mut sys::wl_surface,
+) {
+    let mut state = WlState::from_data(data);
+
+    state.keyboard_focus = null_mut();
+    if !surface.is_null() {
+        state.queue.push(WlEvent::WindowFocus(surface, false));
+    }
+}
+
+/// A Key was pressed/released while a Window had Keyboard Focus.
+unsafe extern "C" fn keyboard_key(
+    data: *mut c_void,
+    _keyboard: *mut sys::wl_keyboard,
+    _serial: u32,
+    _time: u32,
+    key: u32,
+    key_state: u32,
+) {
+    let mut state = WlState::from_data(data);
+    let state = &mut *state;
+
+    let surface = state.keyboard_focus;
+    let xkb = match state.xkb.as_mut() {
+        Some(xkb) if !surface.is_null() => xkb,
+        _ => return,
+    };
+
+    let keycode = key + XKB_KEYCODE_OFFSET;
+    let pressed = key_state == sys::WL_KEYBOARD_KEY_STATE_PRESSED;
+
+    // The Compositor sends the new Modifiers after the key, so they do not include the key itself yet.
+    // Auto-Repeat is left to Clients, and is not implemented yet.
+    let key = xkb.key(keycode);
+    let mut modifiers = xkb.modifiers();
+    modifiers.set(Modifiers::of(key), pressed);
+
+    let event = KeyEvent {
+        scancode: keycode as ScanCode,
+        key,
+        modifiers,
+        repeat: false,
+        location: keysym_location(xkb.keysym(keycode)),
+    };
+    state.queue.push(WlEvent::KeyPress(surface, event, pressed));
+
+    if pressed {
+        for character in xkb.key_text(keycode).chars() {
+            state
+                .queue
+                .push(WlEvent::CharacterInput(surface, character));
+        }
+    }
+}
+
+/// Updates the Modifier/Layout state.
+unsafe extern "C" fn keyboard_modifiers(
+    data: *mut c_void,
+    _keyboard: *mut sys::wl_keyboard,
+    _serial: u32,
+    mods_depressed: u32,
+    mods_latched: u32,
+    mods_locked: u32,
+    group: u32,
+) {
+    let mut state = WlState::from_data(data);
+
+    if let Some(xkb) = state.xkb.as_mut() {
+        xkb.update_mask(mods_depressed, mods_latched, mods_locked, 0, 0, group);
+    }
+}
+
+/// Ignored, as Key-Repeat is not synthesized (Wayland Compositors leave it to the client).
+unsafe extern "C" fn keyboard_repeat_info(
+    _data: *mut c_void,
+    _keyboard: *mut sys::wl_keyboard,
+    _rate: i32,
+    _delay: i32,
+) {
+}
+
+// ================================================================================================================================ //
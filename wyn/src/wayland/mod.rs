@@ -0,0 +1,35 @@
+/*
+ *  Crate: Wyn
+ * Module: Wayland
+ */
+
+//! The implementation of Wyn for Linux, using the Wayland backend.
+//!
+//! Selected by the `wayland` feature, in place of the X11 backend.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+// ================================================================================================================================ //
+
+pub mod errors;
+
+pub mod events;
+
+pub mod event_loop;
+
+pub mod screen;
+
+pub mod window;
+
+pub mod types;
+
+pub mod inputs;
+
+pub mod clipboard;
+
+pub mod cursor;
+
+// ================================================================================================================================ //
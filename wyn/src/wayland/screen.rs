@@ -0,0 +1,370 @@
+/*
+ *  Crate: Wyn
+ * Module: Wayland - Screen
+ */
+
+//! Functionality for querying the state of Monitors/Screens, as announced through `wl_output` Globals.
+//!
+//! Compositors only announce the current Video Mode of each Output, and do not let clients switch it.\
+//! They also keep panels to themselves, so the Work Area of every Screen is the whole Screen.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::event_loop::{EventLoop, WlState};
+use super::types::{Point, Rect, ScaleFactor, Size};
+
+use std::ffi::CStr;
+
+pub use crate::common::screen::*;
+
+// ================================================================================================================================ //
+
+/// Native OS Representation for Screens.
+///
+/// Outputs are uniquely identified by the name of their Global in the Registry.
+pub type NativeScreen = u32;
+
+/// Nonzero wrapper for Native Screens.
+type NonzeroScreen = NonZero<NativeScreen>;
+
+/// Wrapper type for Screen Handles.
+pub struct Screen(NonzeroScreen);
+
+/// Holds all information about a Screen.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct ScreenInfo {
+    /// The Bounding-Rectangle.
+    pub rect: Rect,
+
+    /// The Textual Name.
+    pub name: String,
+
+    /// The Scale Factor, which UIs on the Screen should be scaled by.
+    pub scale_factor: ScaleFactor,
+
+    /// The Refresh Rate of the current Video Mode, in Hertz (`0.0` if it is unknown).
+    pub refresh_rate: f64,
+
+    /// The Work Area, which excludes panels and taskbars.
+    pub work_area: Rect,
+
+    /// The Physical Size, in millimeters (zero if it is unknown).
+    pub size_mm: Size,
+
+    /// The Rotation.
+    pub rotation: Rotation,
+
+    /// The supported Video Modes.
+    pub video_modes: Vec<VideoMode>,
+}
+
+/// The client-side state of a `wl_output`, as reported by the Compositor.
+pub(crate) struct WlOutput {
+    /// The name of the Output's Global in the Registry.
+    pub(crate) global: NativeScreen,
+
+    /// The bound Output.
+    output: *mut sys::wl_output,
+
+    /// The Bounding-Rectangle of the Output, in the Compositor's global space.
+    rect: Rect,
+
+    /// The Textual Name of the Output (e.g. `"HDMI-A-1"`), or its model on older Compositors.
+    name: String,
+
+    /// The integer Scale Factor of the Output.
+    scale: i32,
+
+    /// The current Video Mode of the Output.
+    mode: VideoMode,
+
+    /// The Physical Size of the Output, in millimeters.
+    size_mm: Size,
+
+    /// The Rotation of the Output.
+    rotation: Rotation,
+}
+
+// ================================================================================================================================ //
+
+impl Screen {
+    /// Returns the bounding-rectangle of the Screen.
+    pub fn rect(&self, events: &EventLoop) -> Rect {
+        self.internal_info(events).unwrap_or_default().rect
+    }
+
+    /// Returns the textual-name of the Screen.
+    pub fn name(&self, events: &EventLoop) -> String {
+        self.internal_info(events).unwrap_or_default().name
+    }
+
+    /// Returns the Scale Factor of the Screen.\
+    /// Compositors only report integer Scale Factors (through `wl_output.scale`).
+    pub fn scale_factor(&self, events: &EventLoop) -> ScaleFactor {
+        self.internal_info(events)
+            .map_or(1.0, |info| info.scale_factor)
+    }
+
+    /// Returns the Refresh Rate of the Screen's current Video Mode, in Hertz.
+    pub fn refresh_rate(&self, events: &EventLoop) -> f64 {
+        self.internal_info(events).unwrap_or_default().refresh_rate
+    }
+
+    /// Returns the Work Area of the Screen, which is the whole Screen (as the Compositor does not report panels).
+    pub fn work_area(&self, events: &EventLoop) -> Rect {
+        self.internal_info(events).unwrap_or_default().work_area
+    }
+
+    /// Returns the Physical Size of the Screen, in millimeters.
+    pub fn size_mm(&self, events: &EventLoop) -> Size {
+        self.internal_info(events).unwrap_or_default().size_mm
+    }
+
+    /// Returns the Rotation of the Screen.
+    pub fn rotation(&self, events: &EventLoop) -> Rotation {
+        self.internal_info(events).unwrap_or_default().rotation
+    }
+
+    /// Returns the Video Modes of the Screen, which is only its current Video Mode.
+    pub fn video_modes(&self, events: &EventLoop) -> Vec<VideoMode> {
+        self.internal_info(events).unwrap_or_default().video_modes
+    }
+
+    /// Queries all the Information about the Screen.
+    pub fn info(&self, events: &EventLoop) -> ScreenInfo {
+        self.internal_info(events).unwrap_or_default()
+    }
+}
+
+// ================================================================================================================================ //
+
+impl Screen {
+    /// Creates a new Screen object with the given handle.
+    pub(crate) fn new(handle: NativeScreen) -> Option<Screen> {
+        NonzeroScreen::new(handle).map(Self)
+    }
+
+    /// Returns the Primary Screen.
+    ///
+    /// Wayland has no concept of a Primary Screen, so the first Output announced by the Compositor is used instead.
+    pub fn primary(events: &EventLoop) -> Self {
+        let state = events.state.lock().unwrap();
+
+        state
+            .outputs
+            .first()
+            .and_then(|output| Screen::new(output.global))
+            .expect("The Primary Screen should always exist.")
+    }
+
+    /// Collects a list of all the Screens.
+    pub fn collect(events: &EventLoop) -> Vec<Self> {
+        let state = events.state.lock().unwrap();
+
+        state
+            .outputs
+            .iter()
+            .filter_map(|output| Screen::new(output.global))
+            .collect()
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Wayland-exclusive functionality.
+impl Screen {
+    /// Internal function that retrieves the tracked information of the Output, if it still exists.
+    fn internal_info(&self, events: &EventLoop) -> Option<ScreenInfo> {
+        let state = events.state.lock().unwrap();
+
+        let output = state
+            .outputs
+            .iter()
+            .find(|output| output.global == self.0.get())?;
+
+        Some(ScreenInfo {
+            rect: output.rect,
+            name: output.name.clone(),
+            scale_factor: f64::from(output.scale),
+            refresh_rate: output.mode.refresh_rate,
+            work_area: output.rect,
+            size_mm: output.size_mm,
+            rotation: output.rotation,
+            video_modes: vec![output.mode],
+        })
+    }
+
+    /// Internal function that retrieves the Screen of a bound Output, if it still exists.
+    pub(crate) fn of_output(state: &WlState, output: *mut sys::wl_output) -> Option<Screen> {
+        state
+            .outputs
+            .iter()
+            .find(|bound| bound.output == output)
+            .and_then(|bound| Screen::new(bound.global))
+    }
+
+    /// Internal function that retrieves the bound Output, along with its current Video Mode, if it still exists.
+    pub(crate) fn internal_output(
+        &self,
+        events: &EventLoop,
+    ) -> Option<(*mut sys::wl_output, VideoMode)> {
+        let state = events.state.lock().unwrap();
+
+        state
+            .outputs
+            .iter()
+            .find(|output| output.global == self.0.get())
+            .map(|output| (output.output, output.mode))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl WlOutput {
+    /// Starts tracking a newly bound Output.
+    pub(crate) fn new(global: NativeScreen, output: *mut sys::wl_output) -> Self {
+        Self {
+            global,
+            output,
+            rect: Rect::default(),
+            name: String::new(),
+            scale: 1,
+            mode: VideoMode::default(),
+            size_mm: Size::default(),
+            rotation: Rotation::Deg0,
+        }
+    }
+
+    /// Releases the bound Output.
+    pub(crate) fn release(self) {
+        unsafe {
+            let version = sys::wl_proxy_get_version(self.output as *mut sys::wl_proxy);
+            if version >= sys::WL_OUTPUT_RELEASE_SINCE_VERSION {
+                sys::wl_output_release(self.output);
+            } else {
+                sys::wl_output_destroy(self.output);
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Listener for Outputs.
+pub(crate) static OUTPUT_LISTENER: sys::wl_output_listener = sys::wl_output_listener {
+    geometry: Some(output_geometry),
+    mode: Some(output_mode),
+    done: Some(output_done),
+    scale: Some(output_scale),
+    name: Some(output_name),
+    description: Some(output_description),
+};
+
+/// Runs the callback on the tracked state of the Output, if it is known.
+unsafe fn with_output(
+    data: *mut c_void,
+    output: *mut sys::wl_output,
+    callback: impl FnOnce(&mut WlOutput),
+) {
+    let mut state = WlState::from_data(data);
+    if let Some(output) = state
+        .outputs
+        .iter_mut()
+        .find(|entry| entry.output == output)
+    {
+        callback(output);
+    }
+}
+
+/// Receives the position, physical size, and transform of the Output, along with its make and model.
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn output_geometry(
+    data: *mut c_void,
+    output: *mut sys::wl_output,
+    x: i32,
+    y: i32,
+    physical_width: i32,
+    physical_height: i32,
+    _subpixel: i32,
+    _make: *const c_char,
+    model: *const c_char,
+    transform: i32,
+) {
+    // Transforms rotate counter-clockwise, and the flipped ones are treated like their unflipped counterparts.
+    let rotation = match transform & 3 {
+        1 => Rotation::Deg270,
+        2 => Rotation::Deg180,
+        3 => Rotation::Deg90,
+        _ => Rotation::Deg0,
+    };
+
+    let model = CStr::from_ptr(model).to_string_lossy().to_string();
+    with_output(data, output, |output| {
+        output.rect.origin = Point::new(x as _, y as _);
+        output.size_mm = Size::new(physical_width.max(0) as _, physical_height.max(0) as _);
+        output.rotation = rotation;
+
+        // The `name` event (version 4) is preferred, as it matches the names used by other backends.
+        if output.name.is_empty() {
+            output.name = model;
+        }
+    });
+}
+
+/// Receives the size and refresh rate (in millihertz) of the Output, from its current Mode.
+unsafe extern "C" fn output_mode(
+    data: *mut c_void,
+    output: *mut sys::wl_output,
+    flags: u32,
+    width: i32,
+    height: i32,
+    refresh: i32,
+) {
+    if (flags & sys::WL_OUTPUT_MODE_CURRENT) == 0 {
+        return;
+    }
+
+    with_output(data, output, |output| {
+        output.rect.size = Size::new(width as _, height as _);
+
+        // The pixel format of an Output is not announced.
+        output.mode = VideoMode {
+            size: output.rect.size,
+            refresh_rate: f64::from(refresh.max(0)) / 1000.0,
+            bit_depth: 0,
+        };
+    });
+}
+
+/// Reports the change, once every property of the Output was received (they are applied as soon as they are received).
+unsafe extern "C" fn output_done(data: *mut c_void, _output: *mut sys::wl_output) {
+    WlState::from_data(data).queue_screens_changed();
+}
+
+/// Receives the integer Scale Factor of the Output.
+unsafe extern "C" fn output_scale(data: *mut c_void, output: *mut sys::wl_output, factor: i32) {
+    with_output(data, output, |output| output.scale = factor.max(1));
+}
+
+/// Receives the name of the Output (e.g. `"HDMI-A-1"`).
+unsafe extern "C" fn output_name(
+    data: *mut c_void,
+    output: *mut sys::wl_output,
+    name: *const c_char,
+) {
+    let name = CStr::from_ptr(name).to_string_lossy().to_string();
+    with_output(data, output, |output| output.name = name);
+}
+
+/// Ignored, as the human-readable description is not part of `ScreenInfo`.
+unsafe extern "C" fn output_description(
+    _data: *mut c_void,
+    _output: *mut sys::wl_output,
+    _description: *const c_char,
+) {
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,124 @@
+/*
+ *  Crate: Wyn
+ * Module: Wayland - Types
+ */
+
+//! Native types for handling Points, Sizes, and Rectangles, and conversions between Wyn and Native types.
+//!
+//! The Wayland protocol passes coordinates as separate integers (in Surface-Local coordinates),
+//! so the Native types are plain structs holding those integers.
+
+// ================================================================================================================================ //
+
+#![allow(clippy::useless_conversion)]
+
+#[allow(unused_imports)]
+use super::*;
+
+pub use crate::common::types::*;
+
+// ================================================================================================================================ //
+
+/// Native type for (x, y) Coordinates.
+pub type NativeCoord = i32;
+
+/// Native type for (w, h) Extents.
+pub type NativeExtent = i32;
+
+/// Native type for 2-D Points.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct NativePoint {
+    /// The X-coordinate.
+    pub x: NativeCoord,
+    /// The Y-coordinate.
+    pub y: NativeCoord,
+}
+
+/// Native type for 2-D Sizes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct NativeSize {
+    /// The Width.
+    pub width: NativeExtent,
+    /// The Height.
+    pub height: NativeExtent,
+}
+
+/// Native type for 2-D Rectangles.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct NativeRect {
+    /// The X-coordinate of the Top-Left corner.
+    pub x: NativeCoord,
+    /// The Y-coordinate of the Top-Left corner.
+    pub y: NativeCoord,
+    /// The Width.
+    pub width: NativeExtent,
+    /// The Height.
+    pub height: NativeExtent,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl From<NativePoint> for Point {
+    fn from(value: NativePoint) -> Self {
+        let x = value.x as _;
+        let y = value.y as _;
+        Self { x, y }
+    }
+}
+
+impl From<Point> for NativePoint {
+    fn from(value: Point) -> Self {
+        let x = value.x as _;
+        let y = value.y as _;
+        Self { x, y }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl From<NativeSize> for Size {
+    fn from(value: NativeSize) -> Self {
+        let w = value.width as _;
+        let h = value.height as _;
+        Self { w, h }
+    }
+}
+
+impl From<Size> for NativeSize {
+    fn from(value: Size) -> Self {
+        let width = value.w as _;
+        let height = value.h as _;
+        Self { width, height }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl From<NativeRect> for Rect {
+    fn from(value: NativeRect) -> Self {
+        let x = value.x as _;
+        let y = value.y as _;
+        let w = value.width as _;
+        let h = value.height as _;
+        let origin = Point { x, y };
+        let size = Size { w, h };
+        Self { origin, size }
+    }
+}
+
+impl From<Rect> for NativeRect {
+    fn from(value: Rect) -> Self {
+        let x = value.origin.x as _;
+        let y = value.origin.y as _;
+        let width = value.size.w as _;
+        let height = value.size.h as _;
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,933 @@
+/*
+ *  Crate: Wyn
+ * Module: Wayland - Window
+ */
+
+//! Windows, implemented as `xdg_toplevel` Surfaces.
+//!
+//! Wayland Windows have no content of their own, so they are only mapped (shown) by the Compositor
+//! once content is presented to them (e.g. through a Vulkan Swapchain).\
+//! Clients also cannot query or choose the position of their Windows, nor take Focus.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::cursor::Cursor;
+use super::errors::WlConnectionResult;
+use super::event_loop::{EventLoop, WlEvent, WlState};
+use super::screen::{Screen, VideoMode};
+use super::types::{NativeRect, NativeSize, Point, Rect, ScaleFactor};
+
+// ================================================================================================================================ //
+
+/// Underlying OS Handle to a Window.
+pub type WindowHandle = *mut sys::wl_surface;
+
+/// Nonzero wrapper for Window Handles.
+type NonZeroWindowHandle = NonNull<sys::wl_surface>;
+
+/// Wrapper for Window objects.\
+/// Opening and Closing are always routed through the Event Thread (so the `EventHandler` observes them there),
+/// while every other request may be issued from any thread, as Wayland Proxies are thread-safe.
+#[repr(transparent)]
+pub struct Window(NonZeroWindowHandle);
+
+unsafe impl Send for Window {}
+unsafe impl Sync for Window {}
+
+/// The size of newly opened Windows.
+const DEFAULT_SIZE: NativeSize = NativeSize {
+    width: 640,
+    height: 480,
+};
+
+// ================================================================================================================================ //
+
+impl Window {
+    /// Returns the Native OS Handle to this Window.
+    pub fn handle(&self) -> WindowHandle {
+        self.0.as_ptr()
+    }
+}
+
+impl From<NonZeroWindowHandle> for Window {
+    fn from(handle: NonZeroWindowHandle) -> Self {
+        Self(handle)
+    }
+}
+
+impl TryFrom<WindowHandle> for Window {
+    type Error = ();
+
+    fn try_from(handle: WindowHandle) -> Result<Self, Self::Error> {
+        match NonZeroWindowHandle::new(handle) {
+            Some(handle) => Ok(Self(handle)),
+            None => Err(()),
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Open - Close
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Attempts to open a new Window.
+    pub fn open(events: &EventLoop) -> WlConnectionResult<Window> {
+        // SAFETY: Must be called on the Event Thread.
+        let fut = events.execute(move || unsafe {
+            let events = event_loop::EVENTLOOP.unwrap();
+
+            let window = Self::internal_open(events);
+            events.connection.flush();
+            if let Err(err) = events.connection.status() {
+                Self::internal_destroy(events, window.handle());
+                return Err(err);
+            }
+
+            events.handler.window_open(events, window.handle());
+            Ok(window)
+        });
+        fut.wait()
+    }
+
+    /// Returns whether or not the Window is Open.
+    pub fn is_open(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |_| ()).is_some()
+    }
+
+    /// Opens a window, without flushing.
+    fn internal_open(events: &EventLoop) -> Window {
+        let data = events.listener_data();
+        let mut state = events.state.lock().unwrap();
+
+        let surface = unsafe { sys::wl_compositor_create_surface(state.compositor) };
+        let window = Window::try_from(surface).expect("Invalid Window Handle.");
+
+        let xdg_surface = unsafe { sys::xdg_wm_base_get_xdg_surface(state.wm_base, surface) };
+        unsafe { sys::xdg_surface_add_listener(xdg_surface, &XDG_SURFACE_LISTENER, data) };
+
+        unsafe { sys::wl_surface_add_listener(surface, &SURFACE_LISTENER, data) };
+
+        let toplevel = unsafe { sys::xdg_surface_get_toplevel(xdg_surface) };
+        unsafe { sys::xdg_toplevel_add_listener(toplevel, &XDG_TOPLEVEL_LISTENER, data) };
+
+        // The initial commit (without content) asks the Compositor for the first `configure`.
+        unsafe { sys::wl_surface_commit(surface) };
+
+        state
+            .windows
+            .push(WlWindow::new(surface, xdg_surface, toplevel));
+        window
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Closes the Window.
+    pub fn close(self, events: &EventLoop) -> WlConnectionResult<()> {
+        // SAFETY: Must be called on the Event Thread.
+        let fut = events.execute(move || unsafe {
+            let events = event_loop::EVENTLOOP.unwrap();
+
+            Self::internal_destroy(events, self.handle());
+            events.connection.status()
+        });
+        fut.wait()
+    }
+
+    /// Returns whether or not the Window is Closed.
+    pub fn is_closed(&self, events: &EventLoop) -> bool {
+        !self.is_open(events)
+    }
+
+    /// Destroys a Window (if it is still open), and flushes.
+    pub(crate) fn internal_destroy(events: &EventLoop, handle: WindowHandle) {
+        let window = events.state.lock().unwrap().remove_window(handle);
+        if let Some(window) = window {
+            window.destroy();
+            events.connection.flush();
+        }
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Focus - Show - Hide
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Makes the Window visible and gives it focus.
+    ///
+    /// Wayland does not allow clients to take Focus, so this only makes the Window visible.
+    pub fn focus(&self, events: &EventLoop) -> WlConnectionResult<()> {
+        self.show(events)
+    }
+
+    /// Returns whether or not the Window is Focused.
+    pub fn is_focused(&self, events: &EventLoop) -> bool {
+        events.state.lock().unwrap().keyboard_focus == self.handle()
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Makes the Window visible.
+    ///
+    /// The Window is mapped once content is presented to it.
+    pub fn show(&self, events: &EventLoop) -> WlConnectionResult<()> {
+        self.internal_with(events, |window| {
+            if !window.visible {
+                window.visible = true;
+
+                // Hiding unmapped the Surface, so it must go through the initial commit again.
+                unsafe { sys::wl_surface_commit(window.surface) };
+            }
+        });
+
+        events.connection.flush();
+        events.connection.status()
+    }
+
+    /// Returns whether or not the Window is Visible.
+    pub fn is_visible(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.visible)
+            .unwrap_or(false)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Makes the Window invisible.
+    ///
+    /// Content must not be presented to the Window while it is hidden.
+    pub fn hide(&self, events: &EventLoop) -> WlConnectionResult<()> {
+        self.internal_with(events, |window| {
+            window.visible = false;
+
+            // Removing the content of a Surface unmaps it.
+            unsafe {
+                sys::wl_surface_attach(window.surface, null_mut(), 0, 0);
+                sys::wl_surface_commit(window.surface);
+            }
+        });
+
+        events.connection.flush();
+        events.connection.status()
+    }
+
+    /// Returns whether or not the Window is Hidden.
+    pub fn is_hidden(&self, events: &EventLoop) -> bool {
+        !self.is_visible(events)
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Rename
+impl Window {
+    /// Sets the Name of the Window.
+    pub fn rename(&self, events: &EventLoop, name: &str) -> WlConnectionResult<()> {
+        // Titles cannot contain NUL characters, so they are removed.
+        let title = CString::new(name.replace('\0', "")).unwrap_or_default();
+
+        self.internal_with(events, |window| {
+            unsafe { sys::xdg_toplevel_set_title(window.toplevel, title.as_ptr()) };
+            window.name = name.to_string();
+        });
+
+        events.connection.flush();
+        events.connection.status()
+    }
+
+    /// Gets the Name of the Window.
+    pub fn name(&self, events: &EventLoop) -> WlConnectionResult<String> {
+        let name = self.internal_with(events, |window| window.name.clone());
+        events.connection.status()?;
+        Ok(name.unwrap_or_default())
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Reposition
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Sets the rectangle of the Inner-Content of the Window.
+    ///
+    /// Wayland does not allow clients to position their Windows, so only the size is applied.
+    pub fn reposition_content(&self, events: &EventLoop, rect: Rect) -> WlConnectionResult<()> {
+        let rect = NativeRect::from(rect);
+        let size = NativeSize {
+            width: rect.width.max(1),
+            height: rect.height.max(1),
+        };
+
+        {
+            let mut state = events.state.lock().unwrap();
+            let state = &mut *state;
+
+            // The client decides on its own size, so the change is reported as soon as it is made.
+            if let Some(window) = state
+                .windows
+                .iter_mut()
+                .find(|w| w.surface == self.handle())
+            {
+                if window.size != size {
+                    window.size = size;
+                    state.queue.push(WlEvent::WindowReposition(window.surface));
+                    state.queue.push(WlEvent::WindowRedraw(window.surface));
+                }
+            }
+        }
+        events.wake();
+
+        events.connection.status()
+    }
+
+    /// Gets the rectangle of the Inner-Content of the Window.
+    ///
+    /// Wayland does not reveal the position of Windows, so the origin is always `(0, 0)`.
+    pub fn content_rect(&self, events: &EventLoop) -> WlConnectionResult<Rect> {
+        let size = self.internal_with(events, |window| window.size);
+        events.connection.status()?;
+        let size = size.unwrap_or_default();
+
+        Ok(Rect::from(NativeRect {
+            x: 0,
+            y: 0,
+            width: size.width,
+            height: size.height,
+        }))
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Sets the rectangle of the Outer-Border of the Window.
+    ///
+    /// Decorations are drawn by the Compositor, without telling their size, so this is the same as `reposition_content`.
+    pub fn reposition_border(&self, events: &EventLoop, rect: Rect) -> WlConnectionResult<()> {
+        self.reposition_content(events, rect)
+    }
+
+    /// Gets the rectangle of the Outer-Border of the Window.
+    ///
+    /// Decorations are drawn by the Compositor, without telling their size, so this is the same as `content_rect`.
+    pub fn border_rect(&self, events: &EventLoop) -> WlConnectionResult<Rect> {
+        self.content_rect(events)
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Minimize - Maximize - Fullscreen - Restore
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Minimizes the Window.
+    pub fn minimize(&self, events: &EventLoop) -> WlConnectionResult<()> {
+        self.internal_with(events, |window| {
+            unsafe { sys::xdg_toplevel_set_minimized(window.toplevel) };
+            window.minimized = true;
+        });
+
+        events.connection.flush();
+        events.connection.status()
+    }
+
+    /// Returns whether or not the Window is Minimized.
+    ///
+    /// Wayland does not report Minimization, so a Window counts as Minimized from `minimize` until it is activated again.
+    pub fn is_minimized(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.minimized)
+            .unwrap_or(false)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Maximizes the Window.
+    pub fn maximize(&self, events: &EventLoop) -> WlConnectionResult<()> {
+        self.internal_with(events, |window| unsafe {
+            sys::xdg_toplevel_set_maximized(window.toplevel);
+        });
+
+        events.connection.flush();
+        events.connection.status()
+    }
+
+    /// Returns whether or not the Window is Maximized.
+    pub fn is_maximized(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.states.maximized)
+            .unwrap_or(false)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Fullscreens the Window. (Borderless Fullscreen)
+    pub fn fullscreen(&self, events: &EventLoop) -> WlConnectionResult<()> {
+        // The Compositor chooses the Output.
+        self.internal_with(events, |window| unsafe {
+            sys::xdg_toplevel_set_fullscreen(window.toplevel, null_mut());
+        });
+
+        events.connection.flush();
+        events.connection.status()
+    }
+
+    /// Fullscreens the Window over the Screen, if the Screen is at the Video Mode. (Exclusive Fullscreen)\
+    /// Compositors do not let clients switch Video Modes, so `false` is returned for any other Video Mode.
+    pub fn fullscreen_exclusive(
+        &self,
+        events: &EventLoop,
+        screen: &Screen,
+        mode: &VideoMode,
+    ) -> WlConnectionResult<bool> {
+        let Some((output, current)) = screen.internal_output(events) else {
+            return Ok(false);
+        };
+        if mode.closest_in(&[current]).is_none() {
+            return Ok(false);
+        }
+
+        self.internal_with(events, |window| unsafe {
+            sys::xdg_toplevel_set_fullscreen(window.toplevel, output);
+        });
+
+        events.connection.flush();
+        events.connection.status().map(|()| true)
+    }
+
+    /// Returns whether or not the Window is Fullscreened.
+    pub fn is_fullscreen(&self, events: &EventLoop) -> bool {
+        self.internal_with(events, |window| window.states.fullscreen)
+            .unwrap_or(false)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Restores the Window from Minimized/Maximized/Fullscreen state.
+    ///
+    /// Only the most recent state is undone, so restoring a Minimized Window that was Maximized leaves it Maximized.\
+    /// Wayland does not allow clients to restore Minimized Windows, which the user must activate instead.
+    pub fn restore(&self, events: &EventLoop) -> WlConnectionResult<()> {
+        self.internal_with(events, |window| unsafe {
+            if window.minimized {
+                return;
+            }
+
+            if window.states.fullscreen {
+                sys::xdg_toplevel_unset_fullscreen(window.toplevel);
+            } else if window.states.maximized {
+                sys::xdg_toplevel_unset_maximized(window.toplevel);
+            }
+        });
+
+        events.connection.flush();
+        events.connection.status()
+    }
+
+    /// Returns whether or not the Window isn't Minimized, Maximized, or Fullscreened.
+    pub fn is_normal(&self, events: &EventLoop) -> bool {
+        !self.is_minimized(events) && !self.is_maximized(events) && !self.is_fullscreen(events)
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Screens
+impl Window {
+    /// Returns the closest Screen the Window is currently occupying.\
+    /// That is the first Output the Window entered (and has not left since), or the Primary Screen if there is none.
+    pub fn screen(&self, events: &EventLoop) -> Screen {
+        let screen = {
+            let state = events.state.lock().unwrap();
+            let outputs = state
+                .window(self.handle())
+                .map(|window| window.outputs.as_slice())
+                .unwrap_or_default();
+
+            // Outputs that were removed since are skipped.
+            outputs
+                .iter()
+                .find_map(|&output| Screen::of_output(&state, output))
+        };
+
+        screen.unwrap_or_else(|| Screen::primary(events))
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Scale Factor
+impl Window {
+    /// Returns the Scale Factor of the Window, which its UI should be scaled by.\
+    /// Always `1.0`, as Wyn does not set a buffer scale, so the Compositor scales Windows up on HiDPI Outputs itself.
+    pub fn scale_factor(&self, _events: &EventLoop) -> ScaleFactor {
+        1.0
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Redraw
+impl Window {
+    /// Requests the Window to redraw its contents.\
+    /// Wyn does not present content to Windows itself, so the redraw is reported without involving the Compositor.
+    pub fn request_redraw(&self, events: &EventLoop) {
+        {
+            let mut state = events.state.lock().unwrap();
+            if state.window(self.handle()).is_some() {
+                state.queue.push(WlEvent::WindowRedraw(self.handle()));
+            }
+        }
+        events.wake();
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Cursor
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Sets the appearance of the Mouse-Cursor while it is over the Window.\
+    /// Has no effect, as the Cursor-Shape protocol is not implemented yet.
+    pub fn set_cursor(&self, _events: &EventLoop, _cursor: &Cursor) -> WlConnectionResult<()> {
+        Ok(())
+    }
+
+    /// Shows or hides the Mouse-Cursor while it is over the Window.\
+    /// Has no effect, as the Cursor-Shape protocol is not implemented yet.
+    pub fn set_cursor_visible(
+        &self,
+        _events: &EventLoop,
+        _visible: bool,
+    ) -> WlConnectionResult<()> {
+        Ok(())
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Moves the Mouse-Cursor to the point, relative to the Inner-Content of the Window.\
+    /// Has no effect, as Clients cannot move the Cursor without the Pointer-Constraints protocol.
+    pub fn warp_cursor(&self, _events: &EventLoop, _point: Point) -> WlConnectionResult<()> {
+        Ok(())
+    }
+
+    /// Grabs the Mouse-Cursor, confining it to the Inner-Content of the Window (or releases it).\
+    /// Has no effect, as the Pointer-Constraints protocol is not implemented yet.
+    pub fn grab_cursor(&self, _events: &EventLoop, _grab: bool) -> WlConnectionResult<()> {
+        Ok(())
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Input Method
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Allows (or disallows) Input Methods to compose text in the Window.\
+    /// Has no effect, as the Text-Input protocol is not implemented yet.
+    pub fn set_ime_allowed(&self, _events: &EventLoop, _allowed: bool) -> WlConnectionResult<()> {
+        Ok(())
+    }
+
+    /// Sets the area being edited, relative to the Inner-Content of the Window.\
+    /// Has no effect, as the Text-Input protocol is not implemented yet.
+    pub fn set_ime_position(&self, _events: &EventLoop, _rect: Rect) -> WlConnectionResult<()> {
+        Ok(())
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// The appearance and actions of a Window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WindowType {
+    /// The Window Style.
+    pub style: WindowStyle,
+    /// The Window Actions.
+    pub actions: WindowActions,
+}
+
+/// The visual appearance of a Window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WindowStyle {
+    /// The Window has a Titlebar.
+    Captioned,
+    /// The Window has a Thin Frame.
+    Bordered,
+    /// The Window has No Frame.
+    Borderless,
+}
+
+/// The possible user-interactions on a Window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WindowActions {
+    /// Whether or not the user can move the window.
+    pub movable: bool,
+    /// Whether or not the user can close the window.
+    pub closeable: bool,
+    /// Whether or not the user can resize the window.
+    pub resizable: bool,
+}
+
+// ---------------------------------------------------------------- //
+
+/// Styles - Actions
+impl Window {
+    /// Sets the Style of a Window.
+    ///
+    /// Decorations are chosen by the Compositor, so the Style is only recorded.
+    pub fn set_style(&self, events: &EventLoop, style: WindowStyle) -> WlConnectionResult<()> {
+        self.internal_with(events, |window| window.wtype.style = style);
+        events.connection.status()
+    }
+
+    /// Gets the Style of a Window.
+    pub fn style(&self, events: &EventLoop) -> WlConnectionResult<WindowStyle> {
+        let style = self.internal_with(events, |window| window.wtype.style);
+        events.connection.status()?;
+        Ok(style.unwrap_or(WindowStyle::Captioned))
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Sets the Actions of a Window.
+    ///
+    /// Only resizing can be restricted (by fixing the size); moving and closing are always allowed by the Compositor.
+    pub fn set_actions(
+        &self,
+        events: &EventLoop,
+        actions: WindowActions,
+    ) -> WlConnectionResult<()> {
+        self.internal_with(events, |window| {
+            // Equal min/max sizes prevent resizing, while zero means unlimited.
+            let size = if actions.resizable {
+                NativeSize::default()
+            } else {
+                window.size
+            };
+
+            unsafe {
+                sys::xdg_toplevel_set_min_size(window.toplevel, size.width, size.height);
+                sys::xdg_toplevel_set_max_size(window.toplevel, size.width, size.height);
+                sys::wl_surface_commit(window.surface);
+            }
+            window.wtype.actions = actions;
+        });
+
+        events.connection.flush();
+        events.connection.status()
+    }
+
+    /// Gets the Actions of a Window.
+    pub fn actions(&self, events: &EventLoop) -> WlConnectionResult<WindowActions> {
+        let actions = self.internal_with(events, |window| window.wtype.actions);
+        events.connection.status()?;
+        Ok(actions.unwrap_or(DEFAULT_TYPE.actions))
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Sets the Type of a Window.
+    pub fn set_type(&self, events: &EventLoop, wtype: WindowType) -> WlConnectionResult<()> {
+        self.set_style(events, wtype.style)?;
+        self.set_actions(events, wtype.actions)
+    }
+
+    /// Gets the Type of a Window.
+    pub fn get_type(&self, events: &EventLoop) -> WlConnectionResult<WindowType> {
+        Ok(WindowType {
+            style: self.style(events)?,
+            actions: self.actions(events)?,
+        })
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Wayland-exclusive functionality.
+impl Window {
+    /// Runs the callback on the client-side state of the Window, if it is still open.
+    fn internal_with<T>(
+        &self,
+        events: &EventLoop,
+        callback: impl FnOnce(&mut WlWindow) -> T,
+    ) -> Option<T> {
+        let mut state = events.state.lock().unwrap();
+        state.window_mut(self.handle()).map(callback)
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The Type of newly opened Windows, matching what Compositors draw by default.
+const DEFAULT_TYPE: WindowType = WindowType {
+    style: WindowStyle::Captioned,
+    actions: WindowActions {
+        movable: true,
+        closeable: true,
+        resizable: true,
+    },
+};
+
+/// The `xdg_toplevel` States that Wyn tracks.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+struct WlToplevelStates {
+    /// Whether or not the Window is Maximized.
+    maximized: bool,
+    /// Whether or not the Window is Fullscreened.
+    fullscreen: bool,
+    /// Whether or not the Window is Activated (typically, Focused).
+    activated: bool,
+}
+
+/// The client-side state of a Window.\
+/// Wayland does not allow querying Windows, so everything is tracked from requests and `configure` events.
+pub(crate) struct WlWindow {
+    /// The Surface, which is also the Window Handle.
+    pub(crate) surface: WindowHandle,
+
+    /// The `xdg_surface` role of the Surface.
+    xdg_surface: *mut sys::xdg_surface,
+
+    /// The `xdg_toplevel` role of the Surface.
+    toplevel: *mut sys::xdg_toplevel,
+
+    /// The Name (Title).
+    name: String,
+
+    /// The current size of the content.
+    size: NativeSize,
+
+    /// The size suggested by the latest `xdg_toplevel.configure` (zero if left to the client).
+    pending_size: NativeSize,
+
+    /// The States sent by the latest `xdg_toplevel.configure`.
+    pending_states: WlToplevelStates,
+
+    /// The current States.
+    states: WlToplevelStates,
+
+    /// Whether or not the first `configure` was received.
+    configured: bool,
+
+    /// Whether or not the Window was shown (and not hidden since).
+    visible: bool,
+
+    /// Whether or not the Window was minimized (and not activated since).
+    minimized: bool,
+
+    /// The recorded Window Type.
+    wtype: WindowType,
+
+    /// The Outputs the Window is on, in the order it entered them.
+    outputs: Vec<*mut sys::wl_output>,
+}
+
+impl WlWindow {
+    /// Starts tracking a newly opened Window.
+    fn new(
+        surface: WindowHandle,
+        xdg_surface: *mut sys::xdg_surface,
+        toplevel: *mut sys::xdg_toplevel,
+    ) -> Self {
+        Self {
+            surface,
+            xdg_surface,
+            toplevel,
+            name: String::new(),
+            size: DEFAULT_SIZE,
+            pending_size: NativeSize::default(),
+            pending_states: WlToplevelStates::default(),
+            states: WlToplevelStates::default(),
+            configured: false,
+            visible: false,
+            minimized: false,
+            wtype: DEFAULT_TYPE,
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Destroys the Window's objects, without flushing.
+    pub(crate) fn destroy(self) {
+        unsafe {
+            sys::xdg_toplevel_destroy(self.toplevel);
+            sys::xdg_surface_destroy(self.xdg_surface);
+            sys::wl_surface_destroy(self.surface);
+        }
+    }
+
+    /// Applies the latest `xdg_toplevel.configure`.\
+    /// Returns whether or not the Window must be reported as repositioned.
+    fn apply_configure(&mut self) -> bool {
+        let size = self.pending_size;
+        let resized = (size.width > 0) && (size.height > 0) && (size != self.size);
+        if resized {
+            self.size = size;
+        }
+
+        self.states = self.pending_states;
+        if self.states.activated {
+            self.minimized = false;
+        }
+
+        let first = !self.configured;
+        self.configured = true;
+
+        resized || first
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Listener for the `wl_surface`s of Windows.
+static SURFACE_LISTENER: sys::wl_surface_listener = sys::wl_surface_listener {
+    enter: Some(surface_enter),
+    leave: Some(surface_leave),
+};
+
+/// Listener for `xdg_surface`s.
+static XDG_SURFACE_LISTENER: sys::xdg_surface_listener = sys::xdg_surface_listener {
+    configure: Some(xdg_surface_configure),
+};
+
+/// Listener for `xdg_toplevel`s.
+static XDG_TOPLEVEL_LISTENER: sys::xdg_toplevel_listener = sys::xdg_toplevel_listener {
+    configure: Some(xdg_toplevel_configure),
+    close: Some(xdg_toplevel_close),
+    configure_bounds: Some(xdg_toplevel_configure_bounds),
+    wm_capabilities: Some(xdg_toplevel_wm_capabilities),
+};
+
+/// Records that the Window is now on the Output.
+unsafe extern "C" fn surface_enter(
+    data: *mut c_void,
+    surface: *mut sys::wl_surface,
+    output: *mut sys::wl_output,
+) {
+    let mut state = WlState::from_data(data);
+
+    if let Some(window) = state.window_mut(surface) {
+        window.outputs.push(output);
+    }
+}
+
+/// Records that the Window is no longer on the Output.
+unsafe extern "C" fn surface_leave(
+    data: *mut c_void,
+    surface: *mut sys::wl_surface,
+    output: *mut sys::wl_output,
+) {
+    let mut state = WlState::from_data(data);
+
+    if let Some(window) = state.window_mut(surface) {
+        window.outputs.retain(|&entered| entered != output);
+    }
+}
+
+/// Acknowledges and applies a sequence of `configure` events, and asks for the Window to be redrawn.
+unsafe extern "C" fn xdg_surface_configure(
+    data: *mut c_void,
+    xdg_surface: *mut sys::xdg_surface,
+    serial: u32,
+) {
+    let mut state = WlState::from_data(data);
+    let state = &mut *state;
+
+    let window = state
+        .windows
+        .iter_mut()
+        .find(|window| window.xdg_surface == xdg_surface);
+
+    if let Some(window) = window {
+        sys::xdg_surface_ack_configure(xdg_surface, serial);
+
+        if window.apply_configure() {
+            state.queue.push(WlEvent::WindowReposition(window.surface));
+        }
+        state.queue.push(WlEvent::WindowRedraw(window.surface));
+    }
+}
+
+/// Records the size and States suggested by the Compositor, until the `xdg_surface.configure` that applies them.
+unsafe extern "C" fn xdg_toplevel_configure(
+    data: *mut c_void,
+    toplevel: *mut sys::xdg_toplevel,
+    width: i32,
+    height: i32,
+    states: *mut sys::wl_array,
+) {
+    let mut state = WlState::from_data(data);
+
+    let window = state
+        .windows
+        .iter_mut()
+        .find(|window| window.toplevel == toplevel);
+
+    if let Some(window) = window {
+        let states = match states.as_ref() {
+            Some(array) if !array.data.is_null() => {
+                let len = array.size / size_of::<u32>();
+                core::slice::from_raw_parts(array.data as *const u32, len)
+            }
+            _ => &[],
+        };
+
+        window.pending_size = NativeSize { width, height };
+        window.pending_states = WlToplevelStates {
+            maximized: states.contains(&sys::XDG_TOPLEVEL_STATE_MAXIMIZED),
+            fullscreen: states.contains(&sys::XDG_TOPLEVEL_STATE_FULLSCREEN),
+            activated: states.contains(&sys::XDG_TOPLEVEL_STATE_ACTIVATED),
+        };
+    }
+}
+
+/// The Compositor requested the Window to close.
+unsafe extern "C" fn xdg_toplevel_close(data: *mut c_void, toplevel: *mut sys::xdg_toplevel) {
+    let mut state = WlState::from_data(data);
+    let state = &mut *state;
+
+    let window = state
+        .windows
+        .iter()
+        .find(|window| window.toplevel == toplevel);
+
+    if let Some(window) = window {
+        state.queue.push(WlEvent::WindowClose(window.surface));
+    }
+}
+
+/// Ignored, as Wyn does not limit the size of Windows.
+unsafe extern "C" fn xdg_toplevel_configure_bounds(
+    _data: *mut c_void,
+    _toplevel: *mut sys::xdg_toplevel,
+    _width: i32,
+    _height: i32,
+) {
+}
+
+/// Ignored, as Wyn does not draw its own decorations.
+unsafe extern "C" fn xdg_toplevel_wm_capabilities(
+    _data: *mut c_void,
+    _toplevel: *mut sys::xdg_toplevel,
+    _capabilities: *mut sys::wl_array,
+) {
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,185 @@
+/*
+ *  Crate: Wyn
+ * Module: Win32 - Clipboard
+ */
+
+//! Reading and writing the Clipboard, using the Win32 Clipboard API.
+//!
+//! Text is stored as `CF_UNICODETEXT` (UTF-16), and every other MIME type is stored under a Clipboard Format registered by name.
+//!
+//! ## References
+//! * <https://learn.microsoft.com/en-us/windows/win32/dataxchg/using-the-clipboard>
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::*;
+use super::event_loop::EventLoop;
+
+use std::time::Duration;
+
+pub use crate::common::clipboard::*;
+
+// ================================================================================================================================ //
+
+/// How many times to attempt opening the Clipboard, while another application holds it open.
+const OPEN_ATTEMPTS: u32 = 10;
+
+/// How long to wait between attempts to open the Clipboard.
+const OPEN_RETRY_DELAY: Duration = Duration::from_millis(10);
+
+// ================================================================================================================================ //
+
+impl Clipboard {
+    /// Returns the text on the Clipboard, or `None` if it is empty (or does not hold text).
+    pub fn get_text(self, _events: &EventLoop) -> WinResult<Option<String>> {
+        let data = match self.read(u32::from(sys::CF_UNICODETEXT))? {
+            Some(data) => data,
+            None => return Ok(None),
+        };
+
+        let wide: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|pair| u16::from_ne_bytes([pair[0], pair[1]]))
+            .take_while(|&unit| unit != 0)
+            .collect();
+
+        let text = OsString::from_wide(&wide);
+        Ok(Some(text.to_string_lossy().into_owned()))
+    }
+
+    /// Places the text on the Clipboard, replacing its previous contents.
+    pub fn set_text(self, _events: &EventLoop, text: &str) -> WinResult<()> {
+        // Convert the text into null-terminated UTF-16 Code Points.
+        let os_str = OsStr::new(text);
+        let mut wide: Vec<u16> = os_str.encode_wide().collect();
+        wide.push(0);
+
+        let data: Vec<u8> = wide.iter().flat_map(|unit| unit.to_ne_bytes()).collect();
+        self.write(u32::from(sys::CF_UNICODETEXT), &data)
+    }
+
+    /// Returns the data of the given MIME type on the Clipboard, or `None` if it does not hold that type.
+    pub fn get(self, events: &EventLoop, mime: &str) -> WinResult<Option<Vec<u8>>> {
+        if mime == MIME_TEXT {
+            let text = self.get_text(events)?;
+            return Ok(text.map(String::into_bytes));
+        }
+
+        self.read(clipboard_format(mime)?)
+    }
+
+    /// Places data of the given MIME type on the Clipboard, replacing its previous contents.
+    pub fn set(self, events: &EventLoop, mime: &str, data: &[u8]) -> WinResult<()> {
+        if mime == MIME_TEXT {
+            return self.set_text(events, &String::from_utf8_lossy(data));
+        }
+
+        self.write(clipboard_format(mime)?, data)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Returns a copy of the data in the given Clipboard Format, or `None` if it is not available.
+    fn read(self, format: u32) -> WinResult<Option<Vec<u8>>> {
+        // Windows has no equivalent of the X11 Primary Selection.
+        if self == Self::Primary {
+            return Ok(None);
+        }
+
+        let _clipboard = ClipboardGuard::new()?;
+
+        // SAFETY: The Clipboard is open.
+        if unsafe { sys::IsClipboardFormatAvailable(format) } == 0 {
+            return Ok(None);
+        }
+
+        // SAFETY: The Clipboard is open, and the Format is available.
+        let hmem = sys_verify! { sys::GetClipboardData(format) }?;
+
+        // SAFETY: The Memory Object is owned by the Clipboard, and is only read while the Clipboard is open.
+        let size = unsafe { sys::GlobalSize(hmem) };
+        let ptr = sys_verify! { sys::GlobalLock(hmem) as usize }? as *const u8;
+        let data = unsafe { std::slice::from_raw_parts(ptr, size) }.to_vec();
+        unsafe { sys::GlobalUnlock(hmem) };
+
+        Ok(Some(data))
+    }
+
+    /// Replaces the contents of the Clipboard with data in the given Clipboard Format.
+    fn write(self, format: u32, data: &[u8]) -> WinResult<()> {
+        // Windows has no equivalent of the X11 Primary Selection.
+        if self == Self::Primary {
+            return Ok(());
+        }
+
+        // Copy the data into a Global Memory Object (at least one byte, as empty objects cannot be locked).
+        let hmem = sys_verify! { sys::GlobalAlloc(sys::GMEM_MOVEABLE, data.len().max(1)) }?;
+        let res = sys_verify! { sys::GlobalLock(hmem) as usize };
+        match res {
+            Ok(ptr) => unsafe {
+                std::ptr::copy_nonoverlapping(data.as_ptr(), ptr as *mut u8, data.len());
+                sys::GlobalUnlock(hmem);
+            },
+            Err(err) => {
+                unsafe { sys::GlobalFree(hmem) };
+                return Err(err);
+            }
+        }
+
+        let res = ClipboardGuard::new().and_then(|_clipboard| {
+            sys_verify! { sys::EmptyClipboard() }?;
+
+            // SAFETY: On success, the Clipboard takes ownership of the Memory Object.
+            sys_verify! { sys::SetClipboardData(format, hmem) }
+        });
+
+        if res.is_err() {
+            unsafe { sys::GlobalFree(hmem) };
+        }
+        res.map(|_| ())
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Returns the Clipboard Format for the given MIME type, registering it if necessary.
+fn clipboard_format(mime: &str) -> WinResult<u32> {
+    // Convert the MIME type into null-terminated UTF-16 Code Points.
+    let os_str = OsStr::new(mime);
+    let mut buf: Vec<u16> = os_str.encode_wide().collect();
+    buf.push(0);
+
+    // SAFETY: The buffer is guaranteed to be null-terminated.
+    sys_verify! { sys::RegisterClipboardFormatW(buf.as_ptr()) }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Guard that holds the Clipboard open, and closes it when dropped.
+struct ClipboardGuard(());
+
+impl ClipboardGuard {
+    /// Opens the Clipboard, retrying briefly if another application holds it open.
+    fn new() -> WinResult<Self> {
+        let mut attempt = 1;
+        loop {
+            match sys_verify! { sys::OpenClipboard(0) } {
+                Ok(_) => return Ok(Self(())),
+                Err(err) if attempt >= OPEN_ATTEMPTS => return Err(err),
+                Err(_) => std::thread::sleep(OPEN_RETRY_DELAY),
+            }
+            attempt += 1;
+        }
+    }
+}
+
+impl Drop for ClipboardGuard {
+    fn drop(&mut self) {
+        unsafe { sys::CloseClipboard() };
+    }
+}
+
+// ================================================================================================================================ //
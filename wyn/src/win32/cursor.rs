@@ -0,0 +1,330 @@
+/*
+ *  Crate: Wyn
+ * Module: Win32 - Cursor
+ */
+
+//! Changing the appearance of the Mouse-Cursor, and receiving Raw Mouse Motion.
+//!
+//! Win32 asks each Window for its Cursor with `WM_SETCURSOR` (whenever the Cursor moves over it),
+//! so the Cursor of every Window is kept here, and applied when asked.\
+//! Grabbing confines the Cursor with `ClipCursor`, which is released while the Window is unfocused.\
+//! Raw Mouse Motion is received as `WM_INPUT` messages, from the Raw Input API.\
+//! Win32 only reports the Cursor leaving a Window (with `WM_MOUSELEAVE`) once asked to by `TrackMouseEvent`,
+//! so the Cursor entering a Window is its first `WM_MOUSEMOVE` since it last left.
+//!
+//! ## References
+//! * <https://learn.microsoft.com/en-us/windows/win32/menurc/using-cursors>
+//! * <https://learn.microsoft.com/en-us/windows/win32/inputdev/raw-input>
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::*;
+use super::window::WindowHandle;
+
+use std::collections::hash_map::{Entry, HashMap};
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+pub use crate::common::cursor::*;
+
+// ================================================================================================================================ //
+
+/// A native Cursor, which is destroyed when dropped (if it was created by Wyn, rather than shared from the system).
+pub(crate) struct WinCursorHandle {
+    /// The native Cursor.
+    hcursor: sys::HCURSOR,
+
+    /// Whether or not the Cursor was created by Wyn.
+    owned: bool,
+}
+
+impl Drop for WinCursorHandle {
+    fn drop(&mut self) {
+        if self.owned {
+            // SAFETY: The Cursor was created with `CreateIconIndirect`, and is no longer shown by any Window.
+            unsafe { sys::DestroyIcon(self.hcursor) };
+        }
+    }
+}
+
+/// The Cursor state of a single Window.
+struct WinCursor {
+    /// The Cursor shown over the Window.
+    handle: WinCursorHandle,
+
+    /// Whether or not the Cursor is shown over the Window.
+    visible: bool,
+
+    /// Whether or not the Cursor is confined to the Window.
+    grabbed: bool,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The Cursor state of every Window that changed its Cursor.\
+/// Windows without an entry show the Arrow of the Window Class.
+#[derive(Default)]
+pub(crate) struct WinCursors(Mutex<HashMap<WindowHandle, WinCursor>>);
+
+impl WinCursors {
+    /// Replaces the Cursor shown over the Window, returning the previous one.\
+    /// The previous Cursor must only be dropped once the Window stopped showing it.
+    pub(crate) fn set_cursor(
+        &self,
+        hwnd: WindowHandle,
+        handle: WinCursorHandle,
+    ) -> WinResult<WinCursorHandle> {
+        self.update(hwnd, |cursor| std::mem::replace(&mut cursor.handle, handle))
+    }
+
+    /// Shows or hides the Cursor over the Window.
+    pub(crate) fn set_visible(&self, hwnd: WindowHandle, visible: bool) -> WinResult<()> {
+        self.update(hwnd, |cursor| cursor.visible = visible)
+    }
+
+    /// Sets whether or not the Cursor is confined to the Window.
+    pub(crate) fn set_grabbed(&self, hwnd: WindowHandle, grabbed: bool) -> WinResult<()> {
+        self.update(hwnd, |cursor| cursor.grabbed = grabbed)
+    }
+
+    /// Returns the Cursor to show over the Window (`0` if it is hidden), or `None` if the Window did not change its Cursor.
+    pub(crate) fn current(&self, hwnd: WindowHandle) -> Option<sys::HCURSOR> {
+        let cursors = self.0.lock().unwrap();
+        let cursor = cursors.get(&hwnd)?;
+
+        let hcursor = if cursor.visible {
+            cursor.handle.hcursor
+        } else {
+            0
+        };
+        Some(hcursor)
+    }
+
+    /// Returns whether or not the Cursor is confined to the Window.
+    pub(crate) fn is_grabbed(&self, hwnd: WindowHandle) -> bool {
+        let cursors = self.0.lock().unwrap();
+        cursors.get(&hwnd).map_or(false, |cursor| cursor.grabbed)
+    }
+
+    /// Forgets the Cursor state of a Window that is being destroyed, releasing its grab.
+    pub(crate) fn remove(&self, hwnd: WindowHandle) {
+        let removed = self.0.lock().unwrap().remove(&hwnd);
+
+        if removed.map_or(false, |cursor| cursor.grabbed) {
+            release_clip();
+        }
+    }
+
+    /// Updates the Cursor state of the Window, starting from the Arrow if it did not change its Cursor yet.
+    fn update<T>(
+        &self,
+        hwnd: WindowHandle,
+        callback: impl FnOnce(&mut WinCursor) -> T,
+    ) -> WinResult<T> {
+        let mut cursors = self.0.lock().unwrap();
+
+        let cursor = match cursors.entry(hwnd) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(WinCursor {
+                handle: load(&Cursor::default())?,
+                visible: true,
+                grabbed: false,
+            }),
+        };
+
+        Ok(callback(cursor))
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The Windows that the Cursor is currently over, which are tracked for the Cursor leaving them.
+#[derive(Default)]
+pub(crate) struct WinHovers(Mutex<HashSet<WindowHandle>>);
+
+impl WinHovers {
+    /// Marks the Cursor as over the Window, asking for a `WM_MOUSELEAVE` once it leaves.\
+    /// Returns `true` if the Cursor just entered the Window.
+    pub(crate) fn enter(&self, hwnd: WindowHandle) -> bool {
+        if !self.0.lock().unwrap().insert(hwnd) {
+            return false;
+        }
+
+        let mut tme = sys::TRACKMOUSEEVENT {
+            cbSize: size_of::<sys::TRACKMOUSEEVENT>() as u32,
+            dwFlags: sys::TME_LEAVE,
+            hwndTrack: hwnd,
+            dwHoverTime: 0,
+        };
+
+        // SAFETY: The structure is fully initialized.
+        let _ = unsafe { sys::TrackMouseEvent(addr_of_mut!(tme)) };
+        true
+    }
+
+    /// Marks the Cursor as no longer over the Window (which also forgets a Window that is being destroyed).\
+    /// Returns `true` if the Cursor was over the Window.
+    pub(crate) fn leave(&self, hwnd: WindowHandle) -> bool {
+        self.0.lock().unwrap().remove(&hwnd)
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Creates (or loads) the native Cursor for a `Cursor`.
+pub(crate) fn load(cursor: &Cursor) -> WinResult<WinCursorHandle> {
+    match cursor {
+        Cursor::Shape(shape) => Ok(WinCursorHandle {
+            hcursor: load_shape(*shape)?,
+            owned: false,
+        }),
+        Cursor::Custom(image) => Ok(WinCursorHandle {
+            hcursor: create_image(image)?,
+            owned: true,
+        }),
+    }
+}
+
+/// Loads a shared Cursor from the system.
+#[rustfmt::skip]
+fn load_shape(shape: CursorShape) -> WinResult<sys::HCURSOR> {
+    let idc = match shape {
+        CursorShape::Arrow              => sys::IDC_ARROW,
+        CursorShape::Text               => sys::IDC_IBEAM,
+        CursorShape::Crosshair          => sys::IDC_CROSS,
+        CursorShape::Hand               => sys::IDC_HAND,
+        CursorShape::Wait               => sys::IDC_WAIT,
+        CursorShape::Help               => sys::IDC_HELP,
+        CursorShape::NotAllowed         => sys::IDC_NO,
+        CursorShape::Move               => sys::IDC_SIZEALL,
+        CursorShape::ResizeHorizontal   => sys::IDC_SIZEWE,
+        CursorShape::ResizeVertical     => sys::IDC_SIZENS,
+        CursorShape::ResizeDiagonal     => sys::IDC_SIZENWSE,
+        CursorShape::ResizeAntiDiagonal => sys::IDC_SIZENESW,
+    };
+
+    // SAFETY: Loading a predefined Cursor does not require a Module Instance.
+    sys_verify! { sys::LoadCursorW(0, idc) }
+}
+
+/// Creates a Cursor from a custom image, using a 32-bit color bitmap (whose alpha channel replaces the mask).
+fn create_image(image: &CursorImage) -> WinResult<sys::HCURSOR> {
+    let (width, height) = (image.width() as i32, image.height() as i32);
+    let (hotspot_x, hotspot_y) = image.hotspot();
+
+    // 32-bit pixels are stored as little-endian `0xAARRGGBB` values (BGRA in memory).
+    let pixels: Vec<u32> = image.argb(false).collect();
+    let color = sys_verify! { sys::CreateBitmap(width, height, 1, 32, pixels.as_ptr().cast()) }?;
+    let _color = defer(move || unsafe {
+        sys::DeleteObject(color);
+    });
+
+    // The mask is still required, but is ignored. Each of its rows is padded to a multiple of 16 bits.
+    let stride = (image.width() as usize).div_ceil(16) * 2;
+    let mask_bits = vec![0u8; stride * image.height() as usize];
+    let mask = sys_verify! { sys::CreateBitmap(width, height, 1, 1, mask_bits.as_ptr().cast()) }?;
+    let _mask = defer(move || unsafe {
+        sys::DeleteObject(mask);
+    });
+
+    let info = sys::ICONINFO {
+        fIcon: sys::FALSE,
+        xHotspot: hotspot_x,
+        yHotspot: hotspot_y,
+        hbmMask: mask,
+        hbmColor: color,
+    };
+
+    // SAFETY: The bitmaps are copied, so they can be deleted afterwards.
+    sys_verify! { sys::CreateIconIndirect(addr_of!(info)) }
+}
+
+// ================================================================================================================================ //
+
+/// Confines the Cursor to the Inner-Content of the Window.
+pub(crate) fn clip_to(hwnd: WindowHandle) -> WinResult<()> {
+    // SAFETY: C-Structs are safe to zero-initialize.
+    let mut nt_rc: sys::RECT = unsafe { zeroed() };
+    let _ = sys_verify! { sys::GetClientRect(hwnd, addr_of_mut!(nt_rc)) }?;
+
+    // SAFETY: The `RECT` is made of two `POINT`s (top-left and bottom-right).
+    let corners = addr_of_mut!(nt_rc).cast::<sys::POINT>();
+    let _ = sys_verify! { sys::ClientToScreen(hwnd, corners) }?;
+    let _ = sys_verify! { sys::ClientToScreen(hwnd, corners.add(1)) }?;
+
+    let _ = sys_verify! { sys::ClipCursor(addr_of!(nt_rc)) }?;
+    Ok(())
+}
+
+/// Releases the Cursor, so that it can move across the whole desktop.
+pub(crate) fn release_clip() {
+    // SAFETY: NULL releases the Cursor.
+    unsafe { sys::ClipCursor(null()) };
+}
+
+/// Asks the Window for its Cursor again, if the Cursor is currently over it.
+pub(crate) fn refresh(hwnd: WindowHandle) {
+    // SAFETY: C-Structs are safe to zero-initialize.
+    let mut nt_pt: sys::POINT = unsafe { zeroed() };
+
+    // SAFETY: The point is only used if it was retrieved.
+    unsafe {
+        if sys::GetCursorPos(addr_of_mut!(nt_pt)) != 0 && sys::WindowFromPoint(nt_pt) == hwnd {
+            let lparam =
+                (sys::HTCLIENT as sys::LPARAM) | ((sys::WM_MOUSEMOVE as sys::LPARAM) << 16);
+            sys::SendMessageW(hwnd, sys::WM_SETCURSOR, hwnd as sys::WPARAM, lparam);
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Registers the Event Thread for Raw Mouse Input, which is sent as `WM_INPUT` to whichever of its Windows is focused.
+pub(crate) fn register_raw_input() -> WinResult<()> {
+    let device = sys::RAWINPUTDEVICE {
+        usUsagePage: sys::HID_USAGE_PAGE_GENERIC,
+        usUsage: sys::HID_USAGE_GENERIC_MOUSE,
+        dwFlags: 0,
+        hwndTarget: 0,
+    };
+
+    let size = size_of::<sys::RAWINPUTDEVICE>() as u32;
+    let _ = sys_verify! { sys::RegisterRawInputDevices(addr_of!(device), 1, size) }?;
+    Ok(())
+}
+
+/// Reads the relative motion of a Mouse from a `WM_INPUT` message.\
+/// Returns `None` for other devices, and for Mice that report absolute positions (e.g. Tablets or Remote Desktops).
+pub(crate) fn read_raw_motion(lparam: sys::LPARAM) -> Option<(f64, f64)> {
+    // SAFETY: C-Structs are safe to zero-initialize.
+    let mut raw: sys::RAWINPUT = unsafe { zeroed() };
+    let mut size = size_of::<sys::RAWINPUT>() as u32;
+    let header_size = size_of::<sys::RAWINPUTHEADER>() as u32;
+
+    // SAFETY: The buffer is large enough for Mouse Input, and larger inputs (from other devices) fail without writing to it.
+    let res = unsafe {
+        sys::GetRawInputData(
+            lparam,
+            sys::RID_INPUT,
+            addr_of_mut!(raw).cast(),
+            addr_of_mut!(size),
+            header_size,
+        )
+    };
+    if res == u32::MAX || raw.header.dwType != sys::RIM_TYPEMOUSE {
+        return None;
+    }
+
+    // SAFETY: The input came from a Mouse, so the `mouse` variant is the one that was written.
+    let mouse = unsafe { raw.data.mouse };
+    if mouse.usFlags & sys::MOUSE_MOVE_ABSOLUTE != 0 {
+        return None;
+    }
+
+    Some((f64::from(mouse.lLastX), f64::from(mouse.lLastY)))
+}
+
+// ================================================================================================================================ //
@@ -10,11 +10,16 @@
 #[allow(unused_imports)]
 use super::*;
 
+use super::cursor::{self, WinCursors, WinHovers};
 use super::errors::*;
 use super::event_loop::*;
+use super::ime::WinImes;
+use super::inputs::{Gamepad, WinGamepadProbes};
+use super::pointer::WinTouches;
+use super::screen::{ScreenInfo, ScreenSnapshot, WinVideoModes};
 
 use std::sync::atomic::AtomicBool;
-use std::sync::Condvar;
+use std::sync::{Condvar, Mutex};
 
 // ================================================================================================================================ //
 
@@ -36,6 +41,33 @@ pub struct EventData {
     /// Win32 Window Class.
     #[allow(unused)]
     pub(crate) class: WinClass,
+
+    /// Win32 Thread Timer, used to poll the XInput Controllers.
+    pub(crate) timer: WinTimer,
+
+    /// The Gamepads as of the last poll.
+    pub(crate) gamepads: Mutex<Vec<Gamepad>>,
+
+    /// The XInput User Indices without a Controller, and when they were last probed.
+    pub(crate) gamepad_probes: WinGamepadProbes,
+
+    /// The Cursor state of every Window that changed its Cursor.
+    pub(crate) cursors: WinCursors,
+
+    /// The Windows that the Cursor is currently over.
+    pub(crate) hovers: WinHovers,
+
+    /// The active touches of every Window.
+    pub(crate) touches: WinTouches,
+
+    /// The Input Method state of every Window that changed it.
+    pub(crate) imes: WinImes,
+
+    /// The Screens whose Video Mode was switched by an Exclusive Fullscreen Window.
+    pub(crate) video_modes: WinVideoModes,
+
+    /// The Screens, as they were last reported by `EventHandler::screens_changed`.
+    pub(crate) screens: ScreenSnapshot<ScreenInfo>,
 }
 
 impl EventData {
@@ -50,16 +82,51 @@ impl EventData {
         // SAFETY: This function cannot fail.
         let thread_id = unsafe { sys::GetCurrentThreadId() };
 
+        // Windows report their DPI (and are not stretched by the system) as they move across Screens.
+        // Fails if the application already set its DPI Awareness (e.g. in its manifest), which is then kept as-is.
+        // SAFETY: This function has no preconditions.
+        let _ = unsafe {
+            sys::SetProcessDpiAwarenessContext(sys::DPI_AWARENESS_CONTEXT_PER_MONITOR_AWARE_V2)
+        };
+
         let hook = WinHook::new(thread_id)?;
 
         let class = WinClass::new()?;
 
+        let timer = WinTimer::new(GAMEPAD_POLL_INTERVAL)?;
+
+        let gamepads = Mutex::new(Vec::new());
+        let gamepad_probes = WinGamepadProbes::default();
+
+        let cursors = WinCursors::default();
+
+        let hovers = WinHovers::default();
+        let touches = WinTouches::default();
+
+        let imes = WinImes::default();
+
+        let video_modes = WinVideoModes::default();
+
+        let screens = ScreenSnapshot::default();
+
+        // Raw Mouse Motion is reported for as long as the Event Thread runs.
+        cursor::register_raw_input()?;
+
         Ok(Self {
             running,
             condvar,
             thread_id,
             hook,
             class,
+            timer,
+            gamepads,
+            gamepad_probes,
+            cursors,
+            hovers,
+            touches,
+            imes,
+            video_modes,
+            screens,
         })
     }
 
@@ -159,3 +226,31 @@ impl Drop for WinHook {
 }
 
 // ================================================================================================================================ //
+
+/// The interval (in milliseconds) at which the connected XInput Controllers are polled for changes.\
+/// XInput does not notify about changes, so Gamepad events are generated by comparing Packet-Numbers.\
+/// Disconnected User Indices are probed far less often (see `WinGamepadProbes`).
+const GAMEPAD_POLL_INTERVAL: sys::UINT = 8;
+
+/// Win32 Thread Timer wrapper.\
+/// The Timer posts `WM_TIMER` messages (with the Timer ID as `wParam`) to the Event Thread.
+pub(crate) struct WinTimer(pub usize);
+
+impl WinTimer {
+    /// Starts a new Timer on the Calling Thread.
+    fn new(interval: sys::UINT) -> WinResult<Self> {
+        let res = sys_verify! { sys::SetTimer(0, 0, interval, None) };
+        let timer = res?;
+
+        Ok(Self(timer))
+    }
+}
+
+impl Drop for WinTimer {
+    fn drop(&mut self) {
+        let res = sys_verify! { sys::KillTimer(0, self.0) };
+        res.expect("Win32 TIMER Removal should not fail.");
+    }
+}
+
+// ================================================================================================================================ //
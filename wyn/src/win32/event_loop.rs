@@ -10,13 +10,19 @@
 #[allow(unused_imports)]
 use super::*;
 
+use super::cursor;
 use super::errors::*;
 use super::event_data::EventData;
-use super::events::EventHandler;
+use super::events::{Capability, EventHandler, GamepadUpdate};
+use super::ime;
 use super::inputs::*;
+use super::pointer::WinPointerInput;
+use super::screen::Screen;
 use super::types::*;
+use super::window::WindowHandle;
 use crate::tasks::{ExecFuture, Task};
 
+use std::path::PathBuf;
 use std::sync::{Mutex, MutexGuard, TryLockError};
 
 // ================================================================================================================================ //
@@ -194,6 +200,32 @@ impl EventLoop<'_> {
         self.data.get_running()
     }
 
+    /// Returns whether or not the optional functionality is supported by this Event Loop.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::WindowRedraw => true,
+            Capability::WindowVisibility => true,
+            Capability::CharacterInput => true,
+            Capability::Gamepads => true,
+            Capability::Clipboard => true,
+            // Windows has no equivalent of the X11 Primary Selection.
+            Capability::PrimarySelection => false,
+            Capability::FileDrop => true,
+            // `WM_DROPFILES` is only sent once the files are dropped.
+            Capability::FileDropHover => false,
+            Capability::CursorControl => true,
+            Capability::RawMotion => true,
+            Capability::ScaleFactorChanged => true,
+            Capability::ScreensChanged => true,
+            Capability::InputMethod => true,
+            Capability::VideoModes => true,
+            Capability::InputState => true,
+            Capability::CursorEnterLeave => true,
+            Capability::Touch => true,
+            Capability::Pen => true,
+        }
+    }
+
     /// If an Event/Message Loop is running, then request it to terminate.
     pub fn request_stop(&self) {
         let event_thread = self.data.thread_id;
@@ -242,6 +274,63 @@ impl EventLoop<'_> {
 
 // ================================================================================================================================ //
 
+/// Functionality relating to querying the current state of the Keyboard and Mouse.
+///
+/// Unlike the `EventHandler` callbacks, these ask Windows for the state when they are called,
+/// so they stay correct when the Focus changes while Keys or Mouse-Buttons are held down.
+impl EventLoop<'_> {
+    /// Returns whether or not the Key is currently held down.
+    pub fn key_state(&self, key: KeyCode) -> bool {
+        key.native().is_some_and(is_held)
+    }
+
+    /// Returns every Key that is currently held down.\
+    /// Held Modifier Keys are returned as both their generic and sided `Key`s (e.g. `Key::Shift` and `Key::LShift`).
+    pub fn pressed_keys(&self) -> Vec<KeyCode> {
+        KEY_MAP
+            .iter()
+            .filter(|(_, vk)| is_held(*vk))
+            .map(|(key, _)| *key)
+            .collect()
+    }
+
+    /// Returns every Mouse-Button that is currently held down.\
+    /// `GetAsyncKeyState` reports the physical Mouse-Buttons, so they are swapped back if the user swapped the Left and Right Buttons.
+    pub fn mouse_buttons(&self) -> Vec<MouseButton> {
+        // SAFETY: `GetSystemMetrics` has no error cases.
+        let swapped = unsafe { sys::GetSystemMetrics(sys::SM_SWAPBUTTON) } != 0;
+
+        MOUSE_MAP
+            .iter()
+            .filter(|(_, vk)| is_held(*vk))
+            .map(|(button, _)| match *button {
+                MouseButton::Left if swapped => MouseButton::Right,
+                MouseButton::Right if swapped => MouseButton::Left,
+                button => button,
+            })
+            .collect()
+    }
+
+    /// Returns the position of the Mouse-Cursor, relative to the Client Area of the Window.\
+    /// The Cursor does not need to be over the Window, but `None` is returned if the Window is gone.
+    pub fn cursor_position(&self, handle: WindowHandle) -> Option<Point> {
+        // SAFETY: C-Structs are safe to zero-initialize.
+        let mut nt_pt: sys::POINT = unsafe { zeroed() };
+
+        // SAFETY: The point is only used if it was retrieved and converted.
+        unsafe {
+            if sys::GetCursorPos(addr_of_mut!(nt_pt)) == 0
+                || sys::ScreenToClient(handle, addr_of_mut!(nt_pt)) == 0
+            {
+                return None;
+            }
+        }
+        Some(Point::from(nt_pt))
+    }
+}
+
+// ================================================================================================================================ //
+
 /// Functionality relating to executing code on the Event Thread.
 ///
 /// Sometimes, there are functions will only work if executed on the Main/Event Thread.
@@ -335,6 +424,21 @@ impl EventLoop<'_> {
         res.expect("EventLoop::Execute Signal-Tasks should not fail.");
     }
 
+    /// Polls the XInput Controllers, and reports any changes since the last poll to the Event Handler.
+    fn poll_gamepads(&self) {
+        // Must be called from the Event Thread.
+        assert!(self.is_this_thread());
+
+        // Cannot hold the Gamepads-lock while dispatching, otherwise a user-callback might cause a deadlock.
+        let prev = self.data.gamepads.lock().unwrap().clone();
+        let gamepads = self.data.gamepad_probes.poll(&prev);
+        *self.data.gamepads.lock().unwrap() = gamepads.clone();
+
+        for update in GamepadUpdate::diff(&prev, &gamepads) {
+            update.dispatch(self);
+        }
+    }
+
     /// If there are any Tasks waiting to be executed, remove them from the list and call them.
     fn clear_tasks(&self) {
         // Must be called from the Event Thread.
@@ -408,12 +512,26 @@ impl EventLoop<'_> {
             match umsg {
                 // https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-create
                 sys::WM_CREATE => {
+                    // Files dragged onto the Window are reported with `WM_DROPFILES`.
+                    sys::DragAcceptFiles(hwnd, sys::TRUE);
+
+                    // Input Methods are disallowed until the Window allows them.
+                    ime::associate(hwnd, false);
+
                     events.handler.window_open(events, hwnd);
                 }
                 // https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-close
                 sys::WM_CLOSE => {
                     events.handler.window_close(events, hwnd);
                 }
+                // https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-destroy
+                sys::WM_DESTROY => {
+                    events.data.cursors.remove(hwnd);
+                    events.data.hovers.leave(hwnd);
+                    events.data.touches.remove(hwnd);
+                    events.data.imes.remove(hwnd);
+                    events.data.video_modes.restore(hwnd);
+                }
                 // https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-erasebkgnd
                 sys::WM_ERASEBKGND => {
                     return 1;
@@ -438,6 +556,16 @@ impl EventLoop<'_> {
                 // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-activate
                 sys::WM_ACTIVATE => {
                     let focused = (wparam & 0xFFFF) != 0;
+
+                    // The Cursor is only confined while its Window is focused.
+                    if events.data.cursors.is_grabbed(hwnd) {
+                        if focused {
+                            let _ = cursor::clip_to(hwnd);
+                        } else {
+                            cursor::release_clip();
+                        }
+                    }
+
                     events.handler.window_focus(events, hwnd, focused);
                 }
                 // https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-showwindow
@@ -466,17 +594,90 @@ impl EventLoop<'_> {
                 // https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-windowposchanged
                 sys::WM_WINDOWPOSCHANGED => {
                     if let Some(_wpos) = (lparam as *const sys::WINDOWPOS).as_ref() {
+                        // A confined Cursor follows its Window.
+                        if events.data.cursors.is_grabbed(hwnd) {
+                            let _ = cursor::clip_to(hwnd);
+                        }
+
                         events.handler.window_reposition(events, hwnd);
                     }
                     return 0;
                 }
+                // https://learn.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged
+                sys::WM_DPICHANGED => {
+                    // The horizontal and vertical DPI are always identical.
+                    let dpi = sys::LOWORD(wparam as sys::DWORD);
+                    let scale_factor = dpi_to_scale_factor(u32::from(dpi));
+                    events
+                        .handler
+                        .scale_factor_changed(events, hwnd, scale_factor);
+
+                    // The suggested rectangle keeps the Window at the same relative size, on its new Screen.
+                    if let Some(rect) = (lparam as *const sys::RECT).as_ref() {
+                        let flags =
+                            sys::SWP_NOZORDER | sys::SWP_NOOWNERZORDER | sys::SWP_NOACTIVATE;
+                        sys::SetWindowPos(
+                            hwnd,
+                            0,
+                            rect.left,
+                            rect.top,
+                            rect.right - rect.left,
+                            rect.bottom - rect.top,
+                            flags,
+                        );
+                    }
+                    return 0;
+                }
+                // https://learn.microsoft.com/en-us/windows/win32/devio/wm-devicechange
+                sys::WM_DEVICECHANGE => {
+                    // A Controller may have been plugged in, so it is probed for without waiting.
+                    events.data.gamepad_probes.reset();
+                }
+                // https://learn.microsoft.com/en-us/windows/win32/gdi/wm-displaychange
+                sys::WM_DISPLAYCHANGE => {
+                    // Every top-level Window receives the change, but it is only reported once.
+                    let screens = Screen::collect(events)
+                        .iter()
+                        .map(|screen| screen.info(events))
+                        .collect();
+                    if events.data.screens.update(screens) {
+                        events.handler.screens_changed(events);
+                    }
+                }
                 // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mousemove
                 sys::WM_MOUSEMOVE => {
                     let x = sys::GET_X_LPARAM(lparam);
                     let y = sys::GET_Y_LPARAM(lparam);
                     let point = Point::new(x as _, y as _);
+
+                    if events.data.hovers.enter(hwnd) {
+                        events.handler.cursor_enter(events, hwnd);
+                    }
                     events.handler.cursor_move(events, hwnd, point);
                 }
+                // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mouseleave
+                sys::WM_MOUSELEAVE => {
+                    if events.data.hovers.leave(hwnd) {
+                        events.handler.cursor_leave(events, hwnd);
+                    }
+                }
+                // https://learn.microsoft.com/en-us/windows/win32/menurc/wm-setcursor
+                sys::WM_SETCURSOR => {
+                    // Only the Inner-Content shows the Window's Cursor, while the frame shows its resizing arrows.
+                    let hit = sys::LOWORD(lparam as sys::DWORD);
+                    if u32::from(hit) == sys::HTCLIENT {
+                        if let Some(hcursor) = events.data.cursors.current(hwnd) {
+                            sys::SetCursor(hcursor);
+                            return sys::TRUE as sys::LRESULT;
+                        }
+                    }
+                }
+                // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-input
+                sys::WM_INPUT => {
+                    if let Some((delta_x, delta_y)) = cursor::read_raw_motion(lparam) {
+                        events.handler.raw_motion(events, delta_x, delta_y);
+                    }
+                }
                 // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-lbuttondown
                 sys::WM_LBUTTONDOWN => {
                     let x = sys::GET_X_LPARAM(lparam);
@@ -527,10 +728,31 @@ impl EventLoop<'_> {
 
                     events.handler.button_press(events, hwnd, MB_RIGHT, false)
                 }
+                // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-xbuttondown
+                sys::WM_XBUTTONDOWN => {
+                    let button = match sys::GET_XBUTTON_WPARAM(wparam) {
+                        sys::XBUTTON1 => MB_X1,
+                        _ => MB_X2,
+                    };
+
+                    events.handler.button_press(events, hwnd, button, true);
+                    return sys::TRUE as sys::LRESULT;
+                }
+                // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-xbuttonup
+                sys::WM_XBUTTONUP => {
+                    let button = match sys::GET_XBUTTON_WPARAM(wparam) {
+                        sys::XBUTTON1 => MB_X1,
+                        _ => MB_X2,
+                    };
+
+                    events.handler.button_press(events, hwnd, button, false);
+                    return sys::TRUE as sys::LRESULT;
+                }
                 // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-mousewheel
                 sys::WM_MOUSEWHEEL => {
                     let _keys = sys::GET_KEYSTATE_WPARAM(wparam);
 
+                    // High-resolution Wheels (and Touchpads) send fractions of a `WHEEL_DELTA` notch.
                     let delta = sys::GET_WHEEL_DELTA_WPARAM(wparam);
                     let norm_delta = (delta as ScrollDelta) / (sys::WHEEL_DELTA as ScrollDelta);
 
@@ -553,25 +775,44 @@ impl EventLoop<'_> {
 
                     events.handler.scroll_wheel(events, hwnd, norm_delta, 0.0);
                 }
+                // https://learn.microsoft.com/en-us/windows/win32/inputmsg/wm-pointerdown
+                sys::WM_POINTERDOWN | sys::WM_POINTERUPDATE | sys::WM_POINTERUP => {
+                    // Not returning lets `DefWindowProcW` emulate the Mouse from the primary Pointer.
+                    match events.data.touches.read(hwnd, umsg, wparam) {
+                        Some(WinPointerInput::Touch(touch)) => touch.dispatch(events),
+                        Some(WinPointerInput::Pen(point, pressure, tilt, buttons)) => {
+                            events
+                                .handler
+                                .pen(events, hwnd, point, pressure, tilt, buttons);
+                        }
+                        None => {}
+                    }
+                }
+                // https://learn.microsoft.com/en-us/windows/win32/inputmsg/wm-pointercapturechanged
+                sys::WM_POINTERCAPTURECHANGED => {
+                    if let Some(touch) = events.data.touches.cancel(hwnd, wparam) {
+                        touch.dispatch(events);
+                    }
+                }
                 // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-keydown
                 sys::WM_KEYDOWN => {
-                    let keycode = KeyCode(wparam as sys::VIRTUAL_KEY);
-                    events.handler.key_press(events, hwnd, keycode, true);
+                    let event = key_event(wparam, lparam, true);
+                    events.handler.key_press(events, hwnd, event, true);
                 }
                 // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-keyup
                 sys::WM_KEYUP => {
-                    let keycode = KeyCode(wparam as sys::VIRTUAL_KEY);
-                    events.handler.key_press(events, hwnd, keycode, false);
+                    let event = key_event(wparam, lparam, false);
+                    events.handler.key_press(events, hwnd, event, false);
                 }
                 // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-syskeydown
                 sys::WM_SYSKEYDOWN => {
-                    let keycode = KeyCode(wparam as sys::VIRTUAL_KEY);
-                    events.handler.key_press(events, hwnd, keycode, true);
+                    let event = key_event(wparam, lparam, true);
+                    events.handler.key_press(events, hwnd, event, true);
                 }
                 // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-syskeyup
                 sys::WM_SYSKEYUP => {
-                    let keycode = KeyCode(wparam as sys::VIRTUAL_KEY);
-                    events.handler.key_press(events, hwnd, keycode, false);
+                    let event = key_event(wparam, lparam, false);
+                    events.handler.key_press(events, hwnd, event, false);
                 }
                 // https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-char
                 sys::WM_CHAR => {
@@ -582,12 +823,89 @@ impl EventLoop<'_> {
                         events.handler.character_input(events, hwnd, chr);
                     }
                 }
+                // https://learn.microsoft.com/en-us/windows/win32/intl/wm-ime-setcontext
+                sys::WM_IME_SETCONTEXT => {
+                    // The composed text is drawn by the application, rather than by the Input Method.
+                    let lparam = lparam & !(sys::ISC_SHOWUICOMPOSITIONWINDOW as sys::LPARAM);
+                    return sys::DefWindowProcW(hwnd, umsg, wparam, lparam);
+                }
+                // https://learn.microsoft.com/en-us/windows/win32/intl/wm-ime-startcomposition
+                sys::WM_IME_STARTCOMPOSITION => {
+                    if let Some(position) = events.data.imes.position(hwnd) {
+                        ime::place(hwnd, position);
+                    }
+                    return 0;
+                }
+                // https://learn.microsoft.com/en-us/windows/win32/intl/wm-ime-composition
+                sys::WM_IME_COMPOSITION => {
+                    let (result, preedit) = ime::composition(hwnd, lparam as u32);
+
+                    if let Some(text) = result {
+                        if events.data.imes.set_composing(hwnd, false) {
+                            events
+                                .handler
+                                .ime_preedit(events, hwnd, String::new(), None);
+                        }
+                        events.handler.ime_commit(events, hwnd, text);
+                    }
+                    if let Some((text, cursor_range)) = preedit {
+                        let composing = !text.is_empty();
+                        if events.data.imes.set_composing(hwnd, composing) || composing {
+                            events.handler.ime_preedit(events, hwnd, text, cursor_range);
+                        }
+                    }
+
+                    // Committed text is not also sent as `WM_IME_CHAR` (and `WM_CHAR`).
+                    return 0;
+                }
+                // https://learn.microsoft.com/en-us/windows/win32/intl/wm-ime-endcomposition
+                sys::WM_IME_ENDCOMPOSITION => {
+                    if events.data.imes.set_composing(hwnd, false) {
+                        events
+                            .handler
+                            .ime_preedit(events, hwnd, String::new(), None);
+                    }
+                }
+                // https://learn.microsoft.com/en-us/windows/win32/shell/wm-dropfiles
+                sys::WM_DROPFILES => {
+                    let hdrop = wparam as sys::HDROP;
+
+                    // Releases the memory for the dropped files, just in case the User callback `panic`s.
+                    let _defer = defer(|| unsafe { sys::DragFinish(hdrop) });
+                    let (paths, point) = Self::internal_dropped_files(hdrop);
+
+                    events.handler.file_drop(events, hwnd, paths, point);
+                    return 0;
+                }
                 _ => {}
             }
         }
         sys::DefWindowProcW(hwnd, umsg, wparam, lparam)
     }
 
+    /// Returns the paths of the dropped files, and the point (in Client coordinates) where they were dropped.
+    unsafe fn internal_dropped_files(hdrop: sys::HDROP) -> (Vec<PathBuf>, Point) {
+        // An index of `0xFFFFFFFF` queries the number of files.
+        let count = sys::DragQueryFileW(hdrop, u32::MAX, null_mut(), 0);
+
+        let paths = (0..count)
+            .map(|index| {
+                // The length excludes the null-terminator.
+                let len = sys::DragQueryFileW(hdrop, index, null_mut(), 0);
+                let mut buf = vec![0; len as usize + 1];
+                let len = sys::DragQueryFileW(hdrop, index, buf.as_mut_ptr(), buf.len() as u32);
+                PathBuf::from(OsString::from_wide(&buf[..len as usize]))
+            })
+            .collect();
+
+        // SAFETY: C-Structs are safe to zero-initialize.
+        let mut pt: sys::POINT = zeroed();
+        sys::DragQueryPoint(hdrop, addr_of_mut!(pt));
+        let point = Point::new(pt.x as _, pt.y as _);
+
+        (paths, point)
+    }
+
     // -------------------------------------------------------------------------------------------------------------------------------- //
 
     /// This is a Windows Hook procedure to catch all messages.
@@ -634,6 +952,14 @@ impl EventLoop<'_> {
                     events.clear_tasks();
                 }
             }
+
+            if msg.message == sys::WM_TIMER && msg.hwnd == 0 {
+                if let Some(events) = EVENTLOOP {
+                    if msg.wParam == events.data.timer.0 {
+                        events.poll_gamepads();
+                    }
+                }
+            }
         }
 
         sys::CallNextHookEx(0, code, wparam, lparam)
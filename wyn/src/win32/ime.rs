@@ -0,0 +1,210 @@
+/*
+ *  Crate: Wyn
+ * Module: Win32 - Input Method
+ */
+
+//! Composing text with an Input Method, through the Input Method Manager (IMM32).
+//!
+//! Windows are created without an Input Context, so that Key presses go straight to the Window (e.g. for game controls),
+//! and are given the default Input Context while they allow Input Methods.\
+//! The composed text is read from `WM_IME_COMPOSITION`, and drawn by the application rather than by the Input Method.
+//!
+//! ## References
+//! * <https://learn.microsoft.com/en-us/windows/win32/intl/input-method-manager>
+//! * <https://learn.microsoft.com/en-us/windows/win32/intl/wm-ime-composition>
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::types::*;
+use super::window::WindowHandle;
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Mutex;
+
+// ================================================================================================================================ //
+
+/// The Input Method state of a single Window.
+#[derive(Clone, Copy, Default)]
+struct WinIme {
+    /// Whether or not Input Methods are allowed.
+    allowed: bool,
+
+    /// The area being edited, relative to the Client Area.
+    position: Rect,
+
+    /// Whether or not composed text was reported (and has not been cleared since).
+    composing: bool,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The Input Method state of every Window that changed it.\
+/// Windows without an entry do not allow Input Methods.
+#[derive(Default)]
+pub(crate) struct WinImes(Mutex<HashMap<WindowHandle, WinIme>>);
+
+impl WinImes {
+    /// Allows (or disallows) Input Methods in the Window, returning whether or not it changed.
+    pub(crate) fn set_allowed(&self, hwnd: WindowHandle, allowed: bool) -> bool {
+        let mut imes = self.0.lock().unwrap();
+        let ime = imes.entry(hwnd).or_default();
+
+        let changed = ime.allowed != allowed;
+        ime.allowed = allowed;
+        changed
+    }
+
+    /// Sets the area being edited in the Window, returning whether or not it changed.
+    pub(crate) fn set_position(&self, hwnd: WindowHandle, position: Rect) -> bool {
+        let mut imes = self.0.lock().unwrap();
+        let ime = imes.entry(hwnd).or_default();
+
+        let changed = ime.position != position;
+        ime.position = position;
+        changed
+    }
+
+    /// Returns the area being edited in the Window, if it allows Input Methods.
+    pub(crate) fn position(&self, hwnd: WindowHandle) -> Option<Rect> {
+        let imes = self.0.lock().unwrap();
+        imes.get(&hwnd)
+            .filter(|ime| ime.allowed)
+            .map(|ime| ime.position)
+    }
+
+    /// Records whether or not composed text is reported for the Window, returning whether or not it was before.
+    pub(crate) fn set_composing(&self, hwnd: WindowHandle, composing: bool) -> bool {
+        let mut imes = self.0.lock().unwrap();
+        let ime = imes.entry(hwnd).or_default();
+        std::mem::replace(&mut ime.composing, composing)
+    }
+
+    /// Forgets the Input Method state of a Window that is being destroyed.
+    pub(crate) fn remove(&self, hwnd: WindowHandle) {
+        self.0.lock().unwrap().remove(&hwnd);
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Gives the Window the default Input Context (or takes its Input Context away).
+/// ## SAFETY
+/// Must be called on the Event Thread.
+pub(crate) unsafe fn associate(hwnd: WindowHandle, allowed: bool) {
+    let flags = if allowed { sys::IACE_DEFAULT } else { 0 };
+    sys::ImmAssociateContextEx(hwnd, 0, flags);
+}
+
+/// Places the composition and candidate windows of the Input Method next to the area being edited (in Client coordinates).
+/// ## SAFETY
+/// Must be called on the Event Thread.
+pub(crate) unsafe fn place(hwnd: WindowHandle, position: Rect) {
+    let himc = sys::ImmGetContext(hwnd);
+    if himc == 0 {
+        return;
+    }
+    let _himc_release = defer(|| {
+        sys::ImmReleaseContext(hwnd, himc);
+    });
+
+    let rect = NativeRect::from(position);
+    let point = sys::POINT {
+        x: rect.left,
+        y: rect.top,
+    };
+
+    let mut composition: sys::COMPOSITIONFORM = zeroed();
+    composition.dwStyle = sys::CFS_POINT;
+    composition.ptCurrentPos = point;
+    sys::ImmSetCompositionWindow(himc, addr_of!(composition));
+
+    // The candidate window is kept from covering the area being edited.
+    let mut candidate: sys::CANDIDATEFORM = zeroed();
+    candidate.dwIndex = 0;
+    candidate.dwStyle = sys::CFS_EXCLUDE;
+    candidate.ptCurrentPos = point;
+    candidate.rcArea = rect;
+    sys::ImmSetCandidateWindow(himc, addr_of!(candidate));
+}
+
+/// Reads the committed text, and the composed text (with the byte range to highlight), that a `WM_IME_COMPOSITION` announced.
+/// ## SAFETY
+/// Must be called on the Event Thread, while handling `WM_IME_COMPOSITION`.
+pub(crate) unsafe fn composition(
+    hwnd: WindowHandle,
+    flags: u32,
+) -> (Option<String>, Option<(String, Option<Range<usize>>)>) {
+    let himc = sys::ImmGetContext(hwnd);
+    if himc == 0 {
+        return (None, None);
+    }
+    let _himc_release = defer(|| {
+        sys::ImmReleaseContext(hwnd, himc);
+    });
+
+    let result = (flags & sys::GCS_RESULTSTR != 0).then(|| {
+        let units = composition_string::<u16>(himc, sys::GCS_RESULTSTR);
+        String::from_utf16_lossy(&units)
+    });
+
+    let preedit = (flags & sys::GCS_COMPSTR != 0).then(|| {
+        let units = composition_string::<u16>(himc, sys::GCS_COMPSTR);
+        let text = String::from_utf16_lossy(&units);
+
+        // The target clause (being converted) is highlighted, otherwise the cursor is shown as a caret.
+        let attrs = composition_string::<u8>(himc, sys::GCS_COMPATTR);
+        let target = |attr: &u8| {
+            let attr = *attr as u32;
+            attr == sys::ATTR_TARGET_CONVERTED || attr == sys::ATTR_TARGET_NOTCONVERTED
+        };
+        let clause = attrs
+            .iter()
+            .position(target)
+            .map(|start| start..attrs.iter().rposition(target).unwrap() + 1);
+
+        let range = clause.or_else(|| {
+            let cursor = sys::ImmGetCompositionStringW(himc, sys::GCS_CURSORPOS, null_mut(), 0);
+            let cursor = usize::try_from(cursor).ok()?;
+            Some(cursor..cursor)
+        });
+
+        let range =
+            range.map(|range| utf8_offset(&units, range.start)..utf8_offset(&units, range.end));
+        (text, range)
+    });
+
+    (result, preedit)
+}
+
+/// Reads one of the strings of the Input Context, in units of `T` (UTF-16 for text, or bytes for attributes).
+unsafe fn composition_string<T: Copy + Default>(himc: sys::HIMC, index: u32) -> Vec<T> {
+    // The length is given in bytes.
+    let len = sys::ImmGetCompositionStringW(himc, index, null_mut(), 0);
+    let Ok(len) = usize::try_from(len) else {
+        return Vec::new();
+    };
+
+    let mut buf = vec![T::default(); len / size_of::<T>()];
+    let len = sys::ImmGetCompositionStringW(
+        himc,
+        index,
+        buf.as_mut_ptr() as *mut c_void,
+        (buf.len() * size_of::<T>()) as u32,
+    );
+    buf.truncate(usize::try_from(len).unwrap_or(0) / size_of::<T>());
+    buf
+}
+
+/// Converts an offset in UTF-16 units into a byte offset in the (UTF-8) String.
+fn utf8_offset(units: &[u16], offset: usize) -> usize {
+    let offset = offset.min(units.len());
+    char::decode_utf16(units[..offset].iter().copied())
+        .map(|chr| chr.map_or(3, char::len_utf8))
+        .sum()
+}
+
+// ================================================================================================================================ //
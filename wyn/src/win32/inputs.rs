@@ -13,6 +13,9 @@ use super::*;
 use super::errors::*;
 use super::event_loop::EventLoop;
 
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
 // ================================================================================================================================ //
 
 /// Native OS Representation for Mouse Buttons.
@@ -26,298 +29,228 @@ pub type ScrollDelta = f64;
 
 // -------------------------------------------------------------------------------------------------------------------------------- //
 
-/// A button on a mouse.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-#[repr(transparent)]
-pub struct MouseButton(pub NativeMouseButton);
+pub use crate::common::inputs::*;
 
-/// A key on a keyboard.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-#[repr(transparent)]
-pub struct KeyCode(pub NativeKeyCode);
+/// Translation table between `MouseButton`s and Native Mouse Buttons.
+#[rustfmt::skip]
+pub(crate) const MOUSE_MAP: &[(MouseButton, NativeMouseButton)] = &[
+    (MouseButton::Left,    sys::VK_LBUTTON), // 1
+    (MouseButton::Right,   sys::VK_RBUTTON), // 2
+    (MouseButton::Middle,  sys::VK_MBUTTON), // 4
+    (MouseButton::X1,      sys::VK_XBUTTON1), // 5
+    (MouseButton::X2,      sys::VK_XBUTTON2), // 6
+];
+
+/// Translation table between `Key`s and Native Key Codes.
+#[rustfmt::skip]
+pub(crate) const KEY_MAP: &[(Key, NativeKeyCode)] = &[
+    (Key::Backspace,       sys::VK_BACK),           //   8
+    (Key::Tab,             sys::VK_TAB),            //   9
+    (Key::Clear,           sys::VK_CLEAR),          //  12
+    (Key::Enter,           sys::VK_RETURN),         //  13
+    (Key::Shift,           sys::VK_SHIFT),          //  16
+    (Key::Control,         sys::VK_CONTROL),        //  17
+    (Key::Alt,             sys::VK_MENU),           //  18
+    (Key::Pause,           sys::VK_PAUSE),          //  19
+    (Key::CapsLock,        sys::VK_CAPITAL),        //  20
+    (Key::Escape,          sys::VK_ESCAPE),         //  27
+    (Key::Space,           sys::VK_SPACE),          //  32
+    (Key::PageUp,          sys::VK_PRIOR),          //  33
+    (Key::PageDown,        sys::VK_NEXT),           //  34
+    (Key::End,             sys::VK_END),            //  35
+    (Key::Home,            sys::VK_HOME),           //  36
+    (Key::Left,            sys::VK_LEFT),           //  37
+    (Key::Up,              sys::VK_UP),             //  38
+    (Key::Right,           sys::VK_RIGHT),          //  39
+    (Key::Down,            sys::VK_DOWN),           //  40
+    (Key::PrintScreen,     sys::VK_SNAPSHOT),       //  44
+    (Key::Insert,          sys::VK_INSERT),         //  45
+    (Key::Delete,          sys::VK_DELETE),         //  46
+    (Key::Help,            sys::VK_HELP),           //  47
+    (Key::Num0,            sys::VK_0),              //  48
+    (Key::Num1,            sys::VK_1),              //  49
+    (Key::Num2,            sys::VK_2),              //  50
+    (Key::Num3,            sys::VK_3),              //  51
+    (Key::Num4,            sys::VK_4),              //  52
+    (Key::Num5,            sys::VK_5),              //  53
+    (Key::Num6,            sys::VK_6),              //  54
+    (Key::Num7,            sys::VK_7),              //  55
+    (Key::Num8,            sys::VK_8),              //  56
+    (Key::Num9,            sys::VK_9),              //  57
+    (Key::A,               sys::VK_A),              //  65
+    (Key::B,               sys::VK_B),              //  66
+    (Key::C,               sys::VK_C),              //  67
+    (Key::D,               sys::VK_D),              //  68
+    (Key::E,               sys::VK_E),              //  69
+    (Key::F,               sys::VK_F),              //  70
+    (Key::G,               sys::VK_G),              //  71
+    (Key::H,               sys::VK_H),              //  72
+    (Key::I,               sys::VK_I),              //  73
+    (Key::J,               sys::VK_J),              //  74
+    (Key::K,               sys::VK_K),              //  75
+    (Key::L,               sys::VK_L),              //  76
+    (Key::M,               sys::VK_M),              //  77
+    (Key::N,               sys::VK_N),              //  78
+    (Key::O,               sys::VK_O),              //  79
+    (Key::P,               sys::VK_P),              //  80
+    (Key::Q,               sys::VK_Q),              //  81
+    (Key::R,               sys::VK_R),              //  82
+    (Key::S,               sys::VK_S),              //  83
+    (Key::T,               sys::VK_T),              //  84
+    (Key::U,               sys::VK_U),              //  85
+    (Key::V,               sys::VK_V),              //  86
+    (Key::W,               sys::VK_W),              //  87
+    (Key::X,               sys::VK_X),              //  88
+    (Key::Y,               sys::VK_Y),              //  89
+    (Key::Z,               sys::VK_Z),              //  90
+    (Key::LStart,          sys::VK_LWIN),           //  91
+    (Key::RStart,          sys::VK_RWIN),           //  92
+    (Key::Menu,            sys::VK_APPS),           //  93
+    (Key::Numpad0,         sys::VK_NUMPAD0),        //  96
+    (Key::Numpad1,         sys::VK_NUMPAD1),        //  97
+    (Key::Numpad2,         sys::VK_NUMPAD2),        //  98
+    (Key::Numpad3,         sys::VK_NUMPAD3),        //  99
+    (Key::Numpad4,         sys::VK_NUMPAD4),        // 100
+    (Key::Numpad5,         sys::VK_NUMPAD5),        // 101
+    (Key::Numpad6,         sys::VK_NUMPAD6),        // 102
+    (Key::Numpad7,         sys::VK_NUMPAD7),        // 103
+    (Key::Numpad8,         sys::VK_NUMPAD8),        // 104
+    (Key::Numpad9,         sys::VK_NUMPAD9),        // 105
+    (Key::Multiply,        sys::VK_MULTIPLY),       // 106
+    (Key::Add,             sys::VK_ADD),            // 107
+    (Key::Separator,       sys::VK_SEPARATOR),      // 108
+    (Key::Subtract,        sys::VK_SUBTRACT),       // 109
+    (Key::Decimal,         sys::VK_DECIMAL),        // 110
+    (Key::Divide,          sys::VK_DIVIDE),         // 111
+    (Key::F1,              sys::VK_F1),             // 112
+    (Key::F2,              sys::VK_F2),             // 113
+    (Key::F3,              sys::VK_F3),             // 114
+    (Key::F4,              sys::VK_F4),             // 115
+    (Key::F5,              sys::VK_F5),             // 116
+    (Key::F6,              sys::VK_F6),             // 117
+    (Key::F7,              sys::VK_F7),             // 118
+    (Key::F8,              sys::VK_F8),             // 119
+    (Key::F9,              sys::VK_F9),             // 120
+    (Key::F10,             sys::VK_F10),            // 121
+    (Key::F11,             sys::VK_F11),            // 122
+    (Key::F12,             sys::VK_F12),            // 123
+    (Key::F13,             sys::VK_F13),            // 124
+    (Key::F14,             sys::VK_F14),            // 125
+    (Key::F15,             sys::VK_F15),            // 126
+    (Key::F16,             sys::VK_F16),            // 127
+    (Key::F17,             sys::VK_F17),            // 128
+    (Key::F18,             sys::VK_F18),            // 129
+    (Key::F19,             sys::VK_F19),            // 130
+    (Key::F20,             sys::VK_F20),            // 131
+    (Key::F21,             sys::VK_F21),            // 132
+    (Key::F22,             sys::VK_F22),            // 133
+    (Key::F23,             sys::VK_F23),            // 134
+    (Key::F24,             sys::VK_F24),            // 135
+    (Key::NumLock,         sys::VK_NUMLOCK),        // 144
+    (Key::ScrollLock,      sys::VK_SCROLL),         // 145
+    (Key::LShift,          sys::VK_LSHIFT),         // 160
+    (Key::RShift,          sys::VK_RSHIFT),         // 161
+    (Key::LControl,        sys::VK_LCONTROL),       // 162
+    (Key::RControl,        sys::VK_RCONTROL),       // 163
+    (Key::LAlt,            sys::VK_LMENU),          // 164
+    (Key::RAlt,            sys::VK_RMENU),          // 165
+    (Key::VolumeMute,      sys::VK_VOLUME_MUTE),    // 173
+    (Key::VolumeDown,      sys::VK_VOLUME_DOWN),    // 174
+    (Key::VolumeUp,        sys::VK_VOLUME_UP),      // 175
+    (Key::Colon,           sys::VK_OEM_1),          // 186
+    (Key::Plus,            sys::VK_OEM_PLUS),       // 187
+    (Key::Comma,           sys::VK_OEM_COMMA),      // 188
+    (Key::Minus,           sys::VK_OEM_MINUS),      // 189
+    (Key::Period,          sys::VK_OEM_PERIOD),     // 190
+    (Key::ForwardSlash,    sys::VK_OEM_2),          // 191
+    (Key::Backtick,        sys::VK_OEM_3),          // 192
+    (Key::LBracket,        sys::VK_OEM_4),          // 219
+    (Key::Backslash,       sys::VK_OEM_5),          // 220
+    (Key::RBracket,        sys::VK_OEM_6),          // 221
+    (Key::Quote,           sys::VK_OEM_7),          // 222
+];
 
-pub use kc_constants::*;
-pub use mb_constants::*;
+// ================================================================================================================================ //
 
-// -------------------------------------------------------------------------------------------------------------------------------- //
+/// Translates the parameters of a `WM_KEYDOWN`/`WM_KEYUP` (or `WM_SYSKEYDOWN`/`WM_SYSKEYUP`) message into a `KeyEvent`.
+/// ## SAFETY
+/// Must be called on the Event Thread, while handling the message (so that the Key States match it).
+pub(crate) unsafe fn key_event(
+    wparam: sys::WPARAM,
+    lparam: sys::LPARAM,
+    pressed: bool,
+) -> KeyEvent {
+    // The Scan-Code, Extended and Previous-State flags are in the high word of the `lParam`.
+    let flags = sys::HIWORD(lparam as sys::DWORD) as u32;
+    let extended = flags & sys::KF_EXTENDED != 0;
+    let scancode = (flags & 0xff) | if extended { 0xe000 } else { 0 };
+
+    let vk = wparam as sys::VIRTUAL_KEY;
+    let key = Key::from_native(vk);
+    let location = match vk {
+        // Both Shift keys are reported as `VK_SHIFT`, and only their Scan-Codes differ.
+        sys::VK_SHIFT => {
+            let sided = sys::MapVirtualKeyW(flags & 0xff, sys::MAPVK_VSC_TO_VK_EX);
+            if sided == sys::VK_RSHIFT as u32 {
+                KeyLocation::Right
+            } else {
+                KeyLocation::Left
+            }
+        }
+        sys::VK_CONTROL | sys::VK_MENU if extended => KeyLocation::Right,
+        sys::VK_CONTROL | sys::VK_MENU => KeyLocation::Left,
+        sys::VK_RETURN if extended => KeyLocation::Numpad,
+
+        // Without Num-Lock, the Numpad reports navigation keys, which (unlike the dedicated ones) are not Extended.
+        sys::VK_INSERT
+        | sys::VK_DELETE
+        | sys::VK_HOME
+        | sys::VK_END
+        | sys::VK_PRIOR
+        | sys::VK_NEXT
+        | sys::VK_LEFT
+        | sys::VK_RIGHT
+        | sys::VK_UP
+        | sys::VK_DOWN
+        | sys::VK_CLEAR
+            if !extended =>
+        {
+            KeyLocation::Numpad
+        }
 
-// The Mouse-Button and Key-Code constants do not have doc comments, as the name itself is the documentation.
+        _ => KeyLocation::of(key),
+    };
 
-#[rustfmt::skip]
-#[allow(missing_docs)]
-#[allow(clippy::missing_docs_in_private_items)]
-mod mb_constants {
-    use super::MouseButton;
-
-    pub const MB_LEFT  : MouseButton = MouseButton(sys::VK_LBUTTON); // 1
-    pub const MB_RIGHT : MouseButton = MouseButton(sys::VK_RBUTTON); // 2
-    pub const MB_MIDDLE: MouseButton = MouseButton(sys::VK_MBUTTON); // 4
+    KeyEvent {
+        scancode: scancode as ScanCode,
+        key,
+        modifiers: key_modifiers(),
+        repeat: pressed && flags & sys::KF_REPEAT != 0,
+        location,
+    }
 }
 
-// -------------------------------------------------------------------------------------------------------------------------------- //
+/// Returns the Modifiers as of the message being handled.
+/// ## SAFETY
+/// Must be called on the Event Thread.
+unsafe fn key_modifiers() -> Modifiers {
+    let held = |vk: sys::VIRTUAL_KEY| sys::GetKeyState(vk as i32) < 0;
+    let toggled = |vk: sys::VIRTUAL_KEY| sys::GetKeyState(vk as i32) & 1 != 0;
+
+    let mut modifiers = Modifiers::NONE;
+    modifiers.set(Modifiers::SHIFT, held(sys::VK_SHIFT));
+    modifiers.set(Modifiers::CONTROL, held(sys::VK_CONTROL));
+    modifiers.set(Modifiers::ALT, held(sys::VK_MENU));
+    modifiers.set(Modifiers::SUPER, held(sys::VK_LWIN) || held(sys::VK_RWIN));
+    modifiers.set(Modifiers::CAPS_LOCK, toggled(sys::VK_CAPITAL));
+    modifiers.set(Modifiers::NUM_LOCK, toggled(sys::VK_NUMLOCK));
+    modifiers
+}
 
-#[rustfmt::skip]
-#[allow(missing_docs)]
-#[allow(clippy::missing_docs_in_private_items)]
-mod kc_constants {
-    use super::KeyCode;
-    
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(0);                                       //   0
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_LBUTTON);                         //   1
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_RBUTTON);                         //   2
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_CANCEL);                          //   3
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_MBUTTON);                         //   4
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_XBUTTON1);                        //   5
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_XBUTTON2);                        //   6
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(7 /* UNDEFINED */);                       //   7
-    pub const KC_BACKSPACE    : KeyCode            = KeyCode(sys::VK_BACK);                            //   8
-    pub const KC_TAB          : KeyCode            = KeyCode(sys::VK_TAB);                             //   9
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(10 /* RESERVED */);                       //  10
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(11 /* RESERVED */);                       //  11
-    pub const KC_CLEAR        : KeyCode            = KeyCode(sys::VK_CLEAR);                           //  12
-    pub const KC_ENTER        : KeyCode            = KeyCode(sys::VK_RETURN);                          //  13
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(14 /* UNDEFINED */);                      //  14
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(15 /* UNDEFINED */);                      //  15
-    pub const KC_SHIFT        : KeyCode            = KeyCode(sys::VK_SHIFT);                           //  16
-    pub const KC_CONTROL      : KeyCode            = KeyCode(sys::VK_CONTROL);                         //  17
-    pub const KC_ALT          : KeyCode            = KeyCode(sys::VK_MENU);                            //  18
-    pub const KC_PAUSE        : KeyCode            = KeyCode(sys::VK_PAUSE);                           //  19
-    pub const KC_CAPSLOCK     : KeyCode            = KeyCode(sys::VK_CAPITAL);                         //  20
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_KANA);                            //  21
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_IME_ON);                          //  22
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_JUNJA);                           //  23
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_FINAL);                           //  24
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_KANJI);                           //  25
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_IME_OFF);                         //  26
-    pub const KC_ESCAPE       : KeyCode            = KeyCode(sys::VK_ESCAPE);                          //  27
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_CONVERT);                         //  28
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_NONCONVERT);                      //  29
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_ACCEPT);                          //  30
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_MODECHANGE);                      //  31
-    pub const KC_SPACE        : KeyCode            = KeyCode(sys::VK_SPACE);                           //  32
-    pub const KC_PAGEUP       : KeyCode            = KeyCode(sys::VK_PRIOR);                           //  33
-    pub const KC_PAGEDOWN     : KeyCode            = KeyCode(sys::VK_NEXT);                            //  34
-    pub const KC_END          : KeyCode            = KeyCode(sys::VK_END);                             //  35
-    pub const KC_HOME         : KeyCode            = KeyCode(sys::VK_HOME);                            //  36
-    pub const KC_LEFT         : KeyCode            = KeyCode(sys::VK_LEFT);                            //  37
-    pub const KC_UP           : KeyCode            = KeyCode(sys::VK_UP);                              //  38
-    pub const KC_RIGHT        : KeyCode            = KeyCode(sys::VK_RIGHT);                           //  39
-    pub const KC_DOWN         : KeyCode            = KeyCode(sys::VK_DOWN);                            //  40
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_SELECT);                          //  41
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_PRINT);                           //  42
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_EXECUTE);                         //  43
-    pub const KC_PRINTSCREEN  : KeyCode            = KeyCode(sys::VK_SNAPSHOT);                        //  44
-    pub const KC_INSERT       : KeyCode            = KeyCode(sys::VK_INSERT);                          //  45
-    pub const KC_DELETE       : KeyCode            = KeyCode(sys::VK_DELETE);                          //  46
-    pub const KC_HELP         : KeyCode            = KeyCode(sys::VK_HELP);                            //  47
-    pub const KC_NUM0         : KeyCode            = KeyCode(sys::VK_0);                               //  48
-    pub const KC_NUM1         : KeyCode            = KeyCode(sys::VK_1);                               //  49
-    pub const KC_NUM2         : KeyCode            = KeyCode(sys::VK_2);                               //  50
-    pub const KC_NUM3         : KeyCode            = KeyCode(sys::VK_3);                               //  51
-    pub const KC_NUM4         : KeyCode            = KeyCode(sys::VK_4);                               //  52
-    pub const KC_NUM5         : KeyCode            = KeyCode(sys::VK_5);                               //  53
-    pub const KC_NUM6         : KeyCode            = KeyCode(sys::VK_6);                               //  54
-    pub const KC_NUM7         : KeyCode            = KeyCode(sys::VK_7);                               //  55
-    pub const KC_NUM8         : KeyCode            = KeyCode(sys::VK_8);                               //  56
-    pub const KC_NUM9         : KeyCode            = KeyCode(sys::VK_9);                               //  57
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(58 /* UNDEFINED */);                      //  58
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(59 /* UNDEFINED */);                      //  59
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(60 /* UNDEFINED */);                      //  60
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(61 /* UNDEFINED */);                      //  61
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(62 /* UNDEFINED */);                      //  62
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(63 /* UNDEFINED */);                      //  63
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(64 /* UNDEFINED */);                      //  64
-    pub const KC_A            : KeyCode            = KeyCode(sys::VK_A);                               //  65
-    pub const KC_B            : KeyCode            = KeyCode(sys::VK_B);                               //  66
-    pub const KC_C            : KeyCode            = KeyCode(sys::VK_C);                               //  67
-    pub const KC_D            : KeyCode            = KeyCode(sys::VK_D);                               //  68
-    pub const KC_E            : KeyCode            = KeyCode(sys::VK_E);                               //  69
-    pub const KC_F            : KeyCode            = KeyCode(sys::VK_F);                               //  70
-    pub const KC_G            : KeyCode            = KeyCode(sys::VK_G);                               //  71
-    pub const KC_H            : KeyCode            = KeyCode(sys::VK_H);                               //  72
-    pub const KC_I            : KeyCode            = KeyCode(sys::VK_I);                               //  73
-    pub const KC_J            : KeyCode            = KeyCode(sys::VK_J);                               //  74
-    pub const KC_K            : KeyCode            = KeyCode(sys::VK_K);                               //  75
-    pub const KC_L            : KeyCode            = KeyCode(sys::VK_L);                               //  76
-    pub const KC_M            : KeyCode            = KeyCode(sys::VK_M);                               //  77
-    pub const KC_N            : KeyCode            = KeyCode(sys::VK_N);                               //  78
-    pub const KC_O            : KeyCode            = KeyCode(sys::VK_O);                               //  79
-    pub const KC_P            : KeyCode            = KeyCode(sys::VK_P);                               //  80
-    pub const KC_Q            : KeyCode            = KeyCode(sys::VK_Q);                               //  81
-    pub const KC_R            : KeyCode            = KeyCode(sys::VK_R);                               //  82
-    pub const KC_S            : KeyCode            = KeyCode(sys::VK_S);                               //  83
-    pub const KC_T            : KeyCode            = KeyCode(sys::VK_T);                               //  84
-    pub const KC_U            : KeyCode            = KeyCode(sys::VK_U);                               //  85
-    pub const KC_V            : KeyCode            = KeyCode(sys::VK_V);                               //  86
-    pub const KC_W            : KeyCode            = KeyCode(sys::VK_W);                               //  87
-    pub const KC_X            : KeyCode            = KeyCode(sys::VK_X);                               //  88
-    pub const KC_Y            : KeyCode            = KeyCode(sys::VK_Y);                               //  89
-    pub const KC_Z            : KeyCode            = KeyCode(sys::VK_Z);                               //  90
-    pub const KC_LSTART       : KeyCode            = KeyCode(sys::VK_LWIN);                            //  91
-    pub const KC_RSTART       : KeyCode            = KeyCode(sys::VK_RWIN);                            //  92
-    pub const KC_MENU         : KeyCode            = KeyCode(sys::VK_APPS);                            //  93
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(94 /* RESERVED */);                       //  94
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_SLEEP);                           //  95
-    pub const KC_NUMPAD0      : KeyCode            = KeyCode(sys::VK_NUMPAD0);                         //  96
-    pub const KC_NUMPAD1      : KeyCode            = KeyCode(sys::VK_NUMPAD1);                         //  97
-    pub const KC_NUMPAD2      : KeyCode            = KeyCode(sys::VK_NUMPAD2);                         //  98
-    pub const KC_NUMPAD3      : KeyCode            = KeyCode(sys::VK_NUMPAD3);                         //  99
-    pub const KC_NUMPAD4      : KeyCode            = KeyCode(sys::VK_NUMPAD4);                         // 100
-    pub const KC_NUMPAD5      : KeyCode            = KeyCode(sys::VK_NUMPAD5);                         // 101
-    pub const KC_NUMPAD6      : KeyCode            = KeyCode(sys::VK_NUMPAD6);                         // 102
-    pub const KC_NUMPAD7      : KeyCode            = KeyCode(sys::VK_NUMPAD7);                         // 103
-    pub const KC_NUMPAD8      : KeyCode            = KeyCode(sys::VK_NUMPAD8);                         // 104
-    pub const KC_NUMPAD9      : KeyCode            = KeyCode(sys::VK_NUMPAD9);                         // 105
-    pub const KC_MULTIPLY     : KeyCode            = KeyCode(sys::VK_MULTIPLY);                        // 106
-    pub const KC_ADD          : KeyCode            = KeyCode(sys::VK_ADD);                             // 107
-    pub const KC_SEPARATOR    : KeyCode            = KeyCode(sys::VK_SEPARATOR);                       // 108
-    pub const KC_SUBTRACT     : KeyCode            = KeyCode(sys::VK_SUBTRACT);                        // 109
-    pub const KC_DECIMAL      : KeyCode            = KeyCode(sys::VK_DECIMAL);                         // 110
-    pub const KC_DIVIDE       : KeyCode            = KeyCode(sys::VK_DIVIDE);                          // 111
-    pub const KC_F1           : KeyCode            = KeyCode(sys::VK_F1);                              // 112
-    pub const KC_F2           : KeyCode            = KeyCode(sys::VK_F2);                              // 113
-    pub const KC_F3           : KeyCode            = KeyCode(sys::VK_F3);                              // 114
-    pub const KC_F4           : KeyCode            = KeyCode(sys::VK_F4);                              // 115
-    pub const KC_F5           : KeyCode            = KeyCode(sys::VK_F5);                              // 116
-    pub const KC_F6           : KeyCode            = KeyCode(sys::VK_F6);                              // 117
-    pub const KC_F7           : KeyCode            = KeyCode(sys::VK_F7);                              // 118
-    pub const KC_F8           : KeyCode            = KeyCode(sys::VK_F8);                              // 119
-    pub const KC_F9           : KeyCode            = KeyCode(sys::VK_F9);                              // 120
-    pub const KC_F10          : KeyCode            = KeyCode(sys::VK_F10);                             // 121
-    pub const KC_F11          : KeyCode            = KeyCode(sys::VK_F11);                             // 122
-    pub const KC_F12          : KeyCode            = KeyCode(sys::VK_F12);                             // 123
-    pub const KC_F13          : KeyCode            = KeyCode(sys::VK_F13);                             // 124
-    pub const KC_F14          : KeyCode            = KeyCode(sys::VK_F14);                             // 125
-    pub const KC_F15          : KeyCode            = KeyCode(sys::VK_F15);                             // 126
-    pub const KC_F16          : KeyCode            = KeyCode(sys::VK_F16);                             // 127
-    pub const KC_F17          : KeyCode            = KeyCode(sys::VK_F17);                             // 128
-    pub const KC_F18          : KeyCode            = KeyCode(sys::VK_F18);                             // 129
-    pub const KC_F19          : KeyCode            = KeyCode(sys::VK_F19);                             // 130
-    pub const KC_F20          : KeyCode            = KeyCode(sys::VK_F20);                             // 131
-    pub const KC_F21          : KeyCode            = KeyCode(sys::VK_F21);                             // 132
-    pub const KC_F22          : KeyCode            = KeyCode(sys::VK_F22);                             // 133
-    pub const KC_F23          : KeyCode            = KeyCode(sys::VK_F23);                             // 134
-    pub const KC_F24          : KeyCode            = KeyCode(sys::VK_F24);                             // 135
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_NAVIGATION_VIEW);                 // 136
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_NAVIGATION_MENU);                 // 137
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_NAVIGATION_UP);                   // 138
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_NAVIGATION_DOWN);                 // 139
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_NAVIGATION_LEFT);                 // 140
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_NAVIGATION_RIGHT);                // 141
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_NAVIGATION_ACCEPT);               // 142
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_NAVIGATION_CANCEL);               // 143
-    pub const KC_NUMLOCK      : KeyCode            = KeyCode(sys::VK_NUMLOCK);                         // 144
-    pub const KC_SCROLLLOCK   : KeyCode            = KeyCode(sys::VK_SCROLL);                          // 145
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_FJ_JISHO);                    // 146
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_FJ_MASSHOU);                  // 147
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_FJ_TOUROKU);                  // 148
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_FJ_LOYA);                     // 149
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_FJ_ROYA);                     // 150
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(151 /* UNASSIGNED */);                    // 151
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(152 /* UNASSIGNED */);                    // 152
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(153 /* UNASSIGNED */);                    // 153
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(154 /* UNASSIGNED */);                    // 154
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(155 /* UNASSIGNED */);                    // 155
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(156 /* UNASSIGNED */);                    // 156
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(157 /* UNASSIGNED */);                    // 157
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(158 /* UNASSIGNED */);                    // 158
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(159 /* UNASSIGNED */);                    // 159
-    pub const KC_LSHIFT       : KeyCode            = KeyCode(sys::VK_LSHIFT);                          // 160
-    pub const KC_RSHIFT       : KeyCode            = KeyCode(sys::VK_RSHIFT);                          // 161
-    pub const KC_LCONTROL     : KeyCode            = KeyCode(sys::VK_LCONTROL);                        // 162
-    pub const KC_RCONTROL     : KeyCode            = KeyCode(sys::VK_RCONTROL);                        // 163
-    pub const KC_LALT         : KeyCode            = KeyCode(sys::VK_LMENU);                           // 164
-    pub const KC_RALT         : KeyCode            = KeyCode(sys::VK_RMENU);                           // 165
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_BROWSER_BACK);                    // 166
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_BROWSER_FORWARD);                 // 167
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_BROWSER_REFRESH);                 // 168
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_BROWSER_STOP);                    // 169
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_BROWSER_SEARCH);                  // 170
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_BROWSER_FAVORITES);               // 171
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_BROWSER_HOME);                    // 172
-    pub const KC_VOLUMEMUTE   : KeyCode            = KeyCode(sys::VK_VOLUME_MUTE);                     // 173
-    pub const KC_VOLUMEDOWN   : KeyCode            = KeyCode(sys::VK_VOLUME_DOWN);                     // 174
-    pub const KC_VOLUMEUP     : KeyCode            = KeyCode(sys::VK_VOLUME_UP);                       // 175
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_MEDIA_NEXT_TRACK);                // 176
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_MEDIA_PREV_TRACK);                // 177
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_MEDIA_STOP);                      // 178
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_MEDIA_PLAY_PAUSE);                // 179
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_LAUNCH_MAIL);                     // 180
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_LAUNCH_MEDIA_SELECT);             // 181
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_LAUNCH_APP1);                     // 182
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_LAUNCH_APP2);                     // 183
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(184 /* RESERVED */);                      // 184
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(185 /* RESERVED */);                      // 185
-    pub const KC_COLON        : KeyCode            = KeyCode(sys::VK_OEM_1);                           // 186
-    pub const KC_PLUS         : KeyCode            = KeyCode(sys::VK_OEM_PLUS);                        // 187
-    pub const KC_COMMA        : KeyCode            = KeyCode(sys::VK_OEM_COMMA);                       // 188
-    pub const KC_MINUS        : KeyCode            = KeyCode(sys::VK_OEM_MINUS);                       // 189
-    pub const KC_PERIOD       : KeyCode            = KeyCode(sys::VK_OEM_PERIOD);                      // 190
-    pub const KC_FORWARDSLASH : KeyCode            = KeyCode(sys::VK_OEM_2);                           // 191
-    pub const KC_BACKTICK     : KeyCode            = KeyCode(sys::VK_OEM_3);                           // 192
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(193 /* RESERVED */);                      // 193
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(194 /* RESERVED */);                      // 194
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_A);                       // 195
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_B);                       // 196
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_X);                       // 197
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_Y);                       // 198
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_RIGHT_SHOULDER);          // 199
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_LEFT_SHOULDER);           // 200
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_LEFT_TRIGGER);            // 201
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_RIGHT_TRIGGER);           // 202
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_DPAD_UP);                 // 203
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_DPAD_DOWN);               // 204
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_DPAD_LEFT);               // 205
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_DPAD_RIGHT);              // 206
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_MENU);                    // 207
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_VIEW);                    // 208
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_LEFT_THUMBSTICK_BUTTON);  // 209
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_RIGHT_THUMBSTICK_BUTTON); // 210
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_LEFT_THUMBSTICK_UP);      // 211
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_LEFT_THUMBSTICK_DOWN);    // 212
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_LEFT_THUMBSTICK_RIGHT);   // 213
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_LEFT_THUMBSTICK_LEFT);    // 214
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_RIGHT_THUMBSTICK_UP);     // 215
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_RIGHT_THUMBSTICK_DOWN);   // 216
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_RIGHT_THUMBSTICK_RIGHT);  // 217
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_GAMEPAD_RIGHT_THUMBSTICK_LEFT);   // 218
-    pub const KC_LBRACKET     : KeyCode            = KeyCode(sys::VK_OEM_4);                           // 219
-    pub const KC_BACKSLASH    : KeyCode            = KeyCode(sys::VK_OEM_5);                           // 220
-    pub const KC_RBRACKET     : KeyCode            = KeyCode(sys::VK_OEM_6);                           // 221
-    pub const KC_QUOTE        : KeyCode            = KeyCode(sys::VK_OEM_7);                           // 222
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_8);                           // 223
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(224 /* RESERVED */);                      // 224
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_AX);                          // 225
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_102);                         // 226
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_ICO_HELP);                        // 227
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_ICO_00);                          // 228
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_PROCESSKEY);                      // 229
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_ICO_CLEAR);                       // 230
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_PACKET);                          // 231
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(232 /* UNASSIGNED */);                    // 232
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_RESET);                       // 233
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_JUMP);                        // 234
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_PA1);                         // 235
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_PA2);                         // 236
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_PA3);                         // 237
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_WSCTRL);                      // 238
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_CUSEL);                       // 239
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_ATTN);                        // 240
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_FINISH);                      // 241
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_COPY);                        // 242
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_AUTO);                        // 243
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_ENLW);                        // 244
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_BACKTAB);                     // 245
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_ATTN);                            // 246
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_CRSEL);                           // 247
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_EXSEL);                           // 248
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_EREOF);                           // 249
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_PLAY);                            // 250
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_ZOOM);                            // 251
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_NONAME);                          // 252
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_PA1);                             // 253
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(sys::VK_OEM_CLEAR);                       // 254
-  //pub const KC_UNKNOWN      : KeyCode            = KeyCode(255 /* UNDOCUMENTED */);                  // 255
+/// Returns whether or not the Virtual-Key (or Mouse-Button) is physically held down right now, regardless of any messages.
+pub(crate) fn is_held(vk: sys::VIRTUAL_KEY) -> bool {
+    // SAFETY: `GetAsyncKeyState` may be called from any thread, and sets the most significant bit while the key is held.
+    unsafe { sys::GetAsyncKeyState(vk as i32) < 0 }
 }
 
 // ================================================================================================================================ //
@@ -344,30 +277,28 @@ impl XInputController {
         let mut controllers: [Option<XInputController>; MAX_CONTROLLERS] = Default::default();
 
         for (i, controller) in controllers.iter_mut().enumerate() {
-            // SAFETY: C-structs are safe to zero-initialize.
-            let mut state = unsafe { zeroed() };
-            // SAFETY: The pointer is guaranteed to be valid.
-            let res = unsafe { sys::XInputGetState(i as u32, addr_of_mut!(state)) };
-
-            match WinError::new(res) {
-                None => {
-                    // No Error, controller data is valid
-                    *controller = Some(XInputController { state })
-                }
-                Some(err) => {
-                    if err.code() == sys::ERROR_DEVICE_NOT_CONNECTED {
-                        // No Controller
-                        *controller = None;
-                    } else {
-                        // Error reading data
-                        return Err(err);
-                    }
-                }
-            }
+            *controller = XInputController::poll(i)?;
         }
 
         Ok(controllers)
     }
+
+    /// Returns the controller at the given User Index, or `None` if it is not connected.
+    pub fn poll(index: usize) -> WinResult<Option<XInputController>> {
+        // SAFETY: C-structs are safe to zero-initialize.
+        let mut state = unsafe { zeroed() };
+        // SAFETY: The pointer is guaranteed to be valid.
+        let res = unsafe { sys::XInputGetState(index as u32, addr_of_mut!(state)) };
+
+        match WinError::new(res) {
+            // No Error, controller data is valid
+            None => Ok(Some(XInputController { state })),
+            // No Controller
+            Some(err) if err.code() == sys::ERROR_DEVICE_NOT_CONNECTED => Ok(None),
+            // Error reading data
+            Some(err) => Err(err),
+        }
+    }
 }
 
 // -------------------------------------------------------------------------------------------------------------------------------- //
@@ -482,3 +413,100 @@ impl XInputController {
 }
 
 // ================================================================================================================================ //
+
+impl XInputController {
+    /// Converts the controller state into a platform-independent `Gamepad` snapshot.
+    pub fn gamepad(&self, id: GamepadId) -> Gamepad {
+        let mut gamepad = Gamepad::new(id);
+
+        gamepad.set_button(GamepadButton::A, self.a());
+        gamepad.set_button(GamepadButton::B, self.b());
+        gamepad.set_button(GamepadButton::X, self.x());
+        gamepad.set_button(GamepadButton::Y, self.y());
+        gamepad.set_button(GamepadButton::Back, self.back());
+        gamepad.set_button(GamepadButton::Start, self.start());
+        gamepad.set_button(GamepadButton::DpadUp, self.dpad_u());
+        gamepad.set_button(GamepadButton::DpadDown, self.dpad_d());
+        gamepad.set_button(GamepadButton::DpadLeft, self.dpad_l());
+        gamepad.set_button(GamepadButton::DpadRight, self.dpad_r());
+        gamepad.set_button(GamepadButton::BumperL, self.bumper_l());
+        gamepad.set_button(GamepadButton::BumperR, self.bumper_r());
+        gamepad.set_button(GamepadButton::ThumbL, self.thumb_l());
+        gamepad.set_button(GamepadButton::ThumbR, self.thumb_r());
+
+        let (lx, ly) = self.stick_l();
+        let (rx, ry) = self.stick_r();
+        gamepad.set_axis(GamepadAxis::StickLX, lx);
+        gamepad.set_axis(GamepadAxis::StickLY, ly);
+        gamepad.set_axis(GamepadAxis::StickRX, rx);
+        gamepad.set_axis(GamepadAxis::StickRY, ry);
+        gamepad.set_axis(GamepadAxis::TriggerL, self.trigger_l().0);
+        gamepad.set_axis(GamepadAxis::TriggerR, self.trigger_r().0);
+
+        gamepad.set_packet(self.packet());
+        gamepad
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl Gamepad {
+    /// Returns a snapshot of every connected Gamepad.\
+    /// The ID of a Gamepad is its XInput User Index.
+    pub fn collect(events: &EventLoop) -> Vec<Gamepad> {
+        // A failure to read the controllers is treated the same as no controllers being connected.
+        let controllers = XInputController::collect(events).unwrap_or_default();
+
+        controllers
+            .iter()
+            .enumerate()
+            .filter_map(|(i, con)| con.as_ref().map(|con| con.gamepad(i as GamepadId)))
+            .collect()
+    }
+}
+
+// ================================================================================================================================ //
+
+/// The interval at which the User Indices without a Controller are probed for a newly connected one.\
+/// `XInputGetState` is slow for a disconnected User Index (it looks for new devices), so these are not polled as often.
+const DISCONNECTED_PROBE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The time at which each disconnected User Index was last probed (`None` for connected Controllers).
+#[derive(Default)]
+pub(crate) struct WinGamepadProbes(Mutex<[Option<Instant>; MAX_CONTROLLERS]>);
+
+impl WinGamepadProbes {
+    /// Returns a snapshot of every connected Gamepad, given the snapshot of the last poll.\
+    /// Connected Controllers are read on every poll, while disconnected User Indices are only probed once in a while.
+    pub(crate) fn poll(&self, prev: &[Gamepad]) -> Vec<Gamepad> {
+        let now = Instant::now();
+        let mut probes = self.0.lock().unwrap();
+        let mut gamepads = Vec::new();
+
+        for (i, probed) in probes.iter_mut().enumerate() {
+            let id = i as GamepadId;
+            let connected = prev.iter().any(|gamepad| gamepad.id() == id);
+            if !connected && probed.is_some_and(|at| now - at < DISCONNECTED_PROBE_INTERVAL) {
+                continue;
+            }
+
+            // A failure to read a controller is treated the same as it not being connected.
+            match XInputController::poll(i).ok().flatten() {
+                Some(controller) => {
+                    *probed = None;
+                    gamepads.push(controller.gamepad(id));
+                }
+                None => *probed = Some(now),
+            }
+        }
+
+        gamepads
+    }
+
+    /// Makes every disconnected User Index be probed on the next poll (e.g. after a device was plugged in).
+    pub(crate) fn reset(&self) {
+        *self.0.lock().unwrap() = Default::default();
+    }
+}
+
+// ================================================================================================================================ //
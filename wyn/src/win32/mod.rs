@@ -30,4 +30,14 @@ pub mod types;
 
 pub mod inputs;
 
+pub mod clipboard;
+
+pub mod cursor;
+
+pub(crate) mod ime;
+
+pub(crate) mod pointer;
+
+pub mod testing;
+
 // ================================================================================================================================ //
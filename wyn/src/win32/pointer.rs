@@ -0,0 +1,187 @@
+/*
+ *  Crate: Wyn
+ * Module: Win32 - Pointer
+ */
+
+//! Receiving Touch and Pen input, through the Pointer Input messages (`WM_POINTER*`).
+//!
+//! Every finger on a Touchscreen (and every Pen) is a Pointer of its own, whose ID is the low word of the message's `WPARAM`.\
+//! The messages are still passed on to `DefWindowProcW`, which emulates the Mouse from the primary Pointer.\
+//! A touch that loses its capture (e.g. to a system gesture) is cancelled, at the last point that it was reported at,
+//! so the active touches of every Window are kept here.
+//!
+//! ## References
+//! * <https://learn.microsoft.com/en-us/windows/win32/inputmsg/wm-pointerdown>
+//! * <https://learn.microsoft.com/en-us/windows/win32/inputmsg/wm-pointercapturechanged>
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::events::TouchUpdate;
+use super::inputs::{PenButtons, TouchId, TouchPhase};
+use super::types::Point;
+use super::window::WindowHandle;
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+// ================================================================================================================================ //
+
+/// The maximum pressure of a Pen, as reported by `GetPointerPenInfo`.
+const MAX_PRESSURE: f64 = 1024.0;
+
+// ================================================================================================================================ //
+
+/// The input of a single Pointer, read from a `WM_POINTERDOWN`, `WM_POINTERUPDATE` or `WM_POINTERUP` message.
+pub(crate) enum WinPointerInput {
+    /// A finger on a Touchscreen.
+    Touch(TouchUpdate),
+
+    /// A Pen, with its position, pressure, tilt and pressed Buttons.
+    Pen(Point, f64, (f64, f64), PenButtons),
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The active touches of every Window, with the last point that each was reported at.
+#[derive(Default)]
+pub(crate) struct WinTouches(Mutex<HashMap<(WindowHandle, TouchId), Point>>);
+
+impl WinTouches {
+    /// Reads the Touch or Pen input of a `WM_POINTERDOWN`, `WM_POINTERUPDATE` or `WM_POINTERUP` message.\
+    /// Returns `None` for other Pointers (e.g. a Touchpad or a Mouse), and for updates of touches that are not active.
+    pub(crate) fn read(
+        &self,
+        hwnd: WindowHandle,
+        umsg: sys::UINT,
+        wparam: sys::WPARAM,
+    ) -> Option<WinPointerInput> {
+        let pointer_id = u32::from(sys::GET_POINTERID_WPARAM(wparam));
+
+        let mut pointer_type = 0;
+        // SAFETY: The Pointer ID is the one of the message being processed.
+        if unsafe { sys::GetPointerType(pointer_id, addr_of_mut!(pointer_type)) } == 0 {
+            return None;
+        }
+
+        match pointer_type {
+            sys::PT_TOUCH => self.touch(hwnd, umsg, pointer_id),
+            sys::PT_PEN => pen(hwnd, pointer_id),
+            _ => None,
+        }
+    }
+
+    /// Cancels the touch that lost its capture with a `WM_POINTERCAPTURECHANGED` message.\
+    /// Returns `None` if the Pointer is not an active touch.
+    pub(crate) fn cancel(&self, hwnd: WindowHandle, wparam: sys::WPARAM) -> Option<TouchUpdate> {
+        let id = u32::from(sys::GET_POINTERID_WPARAM(wparam));
+        let point = self.0.lock().unwrap().remove(&(hwnd, id))?;
+
+        Some(TouchUpdate {
+            handle: hwnd,
+            phase: TouchPhase::Cancel,
+            id,
+            point,
+        })
+    }
+
+    /// Forgets the touches of a Window that is being destroyed.
+    pub(crate) fn remove(&self, hwnd: WindowHandle) {
+        self.0
+            .lock()
+            .unwrap()
+            .retain(|(handle, _), _| *handle != hwnd);
+    }
+
+    /// Reads a touch, and tracks it until it ends.
+    fn touch(&self, hwnd: WindowHandle, umsg: sys::UINT, id: TouchId) -> Option<WinPointerInput> {
+        let mut info: sys::POINTER_INFO = unsafe { zeroed() };
+        // SAFETY: The structure is written by the function.
+        if unsafe { sys::GetPointerInfo(id, addr_of_mut!(info)) } == 0 {
+            return None;
+        }
+
+        let point = client_point(hwnd, info.ptPixelLocation)?;
+        let phase = if info.pointerFlags & sys::POINTER_FLAG_CANCELED != 0 {
+            TouchPhase::Cancel
+        } else if umsg == sys::WM_POINTERDOWN {
+            TouchPhase::Begin
+        } else if umsg == sys::WM_POINTERUP {
+            TouchPhase::End
+        } else {
+            TouchPhase::Move
+        };
+
+        let mut touches = self.0.lock().unwrap();
+        match phase {
+            TouchPhase::Begin => {
+                touches.insert((hwnd, id), point);
+            }
+            TouchPhase::Move => {
+                *touches.get_mut(&(hwnd, id))? = point;
+            }
+            TouchPhase::End | TouchPhase::Cancel => {
+                touches.remove(&(hwnd, id))?;
+            }
+        }
+
+        Some(WinPointerInput::Touch(TouchUpdate {
+            handle: hwnd,
+            phase,
+            id,
+            point,
+        }))
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Reads the position, pressure, tilt and pressed Buttons of a Pen.\
+/// Pens without a pressure sensor have full pressure while their tip touches the surface.
+fn pen(hwnd: WindowHandle, pointer_id: u32) -> Option<WinPointerInput> {
+    let mut info: sys::POINTER_PEN_INFO = unsafe { zeroed() };
+    // SAFETY: The structure is written by the function.
+    if unsafe { sys::GetPointerPenInfo(pointer_id, addr_of_mut!(info)) } == 0 {
+        return None;
+    }
+
+    let point = client_point(hwnd, info.pointerInfo.ptPixelLocation)?;
+
+    let mut buttons = PenButtons::NONE;
+    let in_contact = info.pointerInfo.pointerFlags & sys::POINTER_FLAG_INCONTACT != 0;
+    buttons.set(PenButtons::TIP, in_contact);
+    buttons.set(
+        PenButtons::BARREL,
+        info.penFlags & sys::PEN_FLAG_BARREL != 0,
+    );
+    buttons.set(
+        PenButtons::ERASER,
+        info.penFlags & (sys::PEN_FLAG_INVERTED | sys::PEN_FLAG_ERASER) != 0,
+    );
+
+    let pressure = if info.penMask & sys::PEN_MASK_PRESSURE != 0 {
+        f64::from(info.pressure) / MAX_PRESSURE
+    } else if in_contact {
+        1.0
+    } else {
+        0.0
+    };
+
+    // The tilt is zero unless the Pen reports it.
+    let tilt = (f64::from(info.tiltX), f64::from(info.tiltY));
+
+    Some(WinPointerInput::Pen(point, pressure, tilt, buttons))
+}
+
+/// Converts a point from Screen coordinates (in pixels) to the Client Area of a Window.
+fn client_point(hwnd: WindowHandle, mut nt_pt: sys::POINT) -> Option<Point> {
+    // SAFETY: The point is a valid, initialized structure.
+    if unsafe { sys::ScreenToClient(hwnd, addr_of_mut!(nt_pt)) } == 0 {
+        return None;
+    }
+    Some(Point::new(nt_pt.x as _, nt_pt.y as _))
+}
+
+// ================================================================================================================================ //
@@ -4,6 +4,9 @@
  */
 
 //! Functionality for querying the state of Monitors/Screens and the Desktop environment.
+//!
+//! Video Modes are enumerated with `EnumDisplaySettingsW`, and switched with `ChangeDisplaySettingsExW`.\
+//! Switched Video Modes are never saved to the registry, so the system restores them if the process exits.
 
 // ================================================================================================================================ //
 
@@ -11,7 +14,13 @@
 use super::*;
 
 use super::event_loop::EventLoop;
-use super::types::Rect;
+use super::types::{self, Rect, ScaleFactor, Size};
+use super::window::WindowHandle;
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+pub use crate::common::screen::*;
 
 // ================================================================================================================================ //
 
@@ -32,8 +41,29 @@ pub struct ScreenInfo {
 
     /// The Textual Name.
     pub name: String,
+
+    /// The Scale Factor, which UIs on the Screen should be scaled by.
+    pub scale_factor: ScaleFactor,
+
+    /// The Refresh Rate of the current Video Mode, in Hertz (`0.0` if it is unknown).
+    pub refresh_rate: f64,
+
+    /// The Work Area, which excludes panels and taskbars.
+    pub work_area: Rect,
+
+    /// The Physical Size, in millimeters (zero if it is unknown).
+    pub size_mm: Size,
+
+    /// The Rotation.
+    pub rotation: Rotation,
+
+    /// The supported Video Modes.
+    pub video_modes: Vec<VideoMode>,
 }
 
+/// The number of millimeters in an inch.
+const MM_PER_INCH: f64 = 25.4;
+
 // ================================================================================================================================ //
 
 impl Screen {
@@ -56,11 +86,105 @@ impl Screen {
         trimmed.to_owned()
     }
 
+    /// Returns the Scale Factor of the Screen, from its effective DPI (which includes the user's scaling setting).
+    pub fn scale_factor(&self, _events: &EventLoop) -> ScaleFactor {
+        let (mut dpi_x, mut dpi_y) = (0, 0);
+
+        // SAFETY: The pointers are valid, and the DPI is only used if it was retrieved.
+        let res = unsafe {
+            sys::GetDpiForMonitor(
+                self.0.get(),
+                sys::MDT_EFFECTIVE_DPI,
+                addr_of_mut!(dpi_x),
+                addr_of_mut!(dpi_y),
+            )
+        };
+
+        // The horizontal and vertical DPI are always identical.
+        let dpi = if res >= 0 { dpi_x } else { 0 };
+        types::dpi_to_scale_factor(dpi)
+    }
+
+    /// Returns the Refresh Rate of the Screen's current Video Mode, in Hertz.
+    pub fn refresh_rate(&self, _events: &EventLoop) -> f64 {
+        self.internal_current_settings()
+            .map_or(0.0, |devmode| video_mode_from(&devmode).refresh_rate)
+    }
+
+    /// Returns the Work Area of the Screen, which excludes the Taskbar (and other Application Desktop Toolbars).
+    pub fn work_area(&self, _events: &EventLoop) -> Rect {
+        let info = Self::internal_monitor_info(self.0.get()).unwrap();
+
+        let win_rect = info.monitorInfo.rcWork;
+        Rect::from(win_rect)
+    }
+
+    /// Returns the Physical Size of the Screen in millimeters, from its raw DPI (as reported by the Monitor itself).
+    pub fn size_mm(&self, events: &EventLoop) -> Size {
+        let (mut dpi_x, mut dpi_y) = (0, 0);
+
+        // SAFETY: The pointers are valid, and the DPI is only used if it was retrieved.
+        let res = unsafe {
+            sys::GetDpiForMonitor(
+                self.0.get(),
+                sys::MDT_RAW_DPI,
+                addr_of_mut!(dpi_x),
+                addr_of_mut!(dpi_y),
+            )
+        };
+        if res < 0 || dpi_x == 0 || dpi_y == 0 {
+            return Size::default();
+        }
+
+        let size = self.rect(events).size;
+        Size::new(
+            size.w * MM_PER_INCH / f64::from(dpi_x),
+            size.h * MM_PER_INCH / f64::from(dpi_y),
+        )
+    }
+
+    /// Returns the Rotation of the Screen.
+    pub fn rotation(&self, _events: &EventLoop) -> Rotation {
+        let Some(devmode) = self.internal_current_settings() else {
+            return Rotation::Deg0;
+        };
+
+        // SAFETY: Display devices always fill in the display variant of the union.
+        let orientation = unsafe { devmode.Anonymous1.Anonymous2.dmDisplayOrientation };
+        match orientation {
+            sys::DMDO_90 => Rotation::Deg90,
+            sys::DMDO_180 => Rotation::Deg180,
+            sys::DMDO_270 => Rotation::Deg270,
+            _ => Rotation::Deg0,
+        }
+    }
+
+    /// Returns the Video Modes that the Screen supports.
+    pub fn video_modes(&self, _events: &EventLoop) -> Vec<VideoMode> {
+        let mut modes = Vec::new();
+
+        for devmode in self.internal_all_settings() {
+            // Modes that only differ in settings Wyn does not report (e.g. Scaling) are listed once.
+            let mode = video_mode_from(&devmode);
+            if !modes.contains(&mode) {
+                modes.push(mode);
+            }
+        }
+
+        modes
+    }
+
     /// Queries all the Information about the Screen.
     pub fn info(&self, events: &EventLoop) -> ScreenInfo {
         ScreenInfo {
             rect: self.rect(events),
             name: self.name(events),
+            scale_factor: self.scale_factor(events),
+            refresh_rate: self.refresh_rate(events),
+            work_area: self.work_area(events),
+            size_mm: self.size_mm(events),
+            rotation: self.rotation(events),
+            video_modes: self.video_modes(events),
         }
     }
 }
@@ -137,6 +261,122 @@ impl Screen {
             Ok(info)
         }
     }
+
+    /// Internal function that retrieves the null-terminated name of the Screen's display device (e.g. `"\\.\DISPLAY1"`).
+    fn internal_device(&self) -> Option<Vec<u16>> {
+        let info = Self::internal_monitor_info(self.0.get()).ok()?;
+        Some(info.szDevice.to_vec())
+    }
+
+    /// Internal function that retrieves the current Display Settings of the Screen.
+    fn internal_current_settings(&self) -> Option<sys::DEVMODEW> {
+        let device = self.internal_device()?;
+        display_settings(&device, sys::ENUM_CURRENT_SETTINGS)
+    }
+
+    /// Internal function that retrieves every Display Setting that the Screen supports.
+    fn internal_all_settings(&self) -> Vec<sys::DEVMODEW> {
+        let Some(device) = self.internal_device() else {
+            return Vec::new();
+        };
+
+        (0..)
+            .map_while(|index| display_settings(&device, index))
+            .collect()
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Retrieves a Display Setting of a display device, by its index (or `ENUM_CURRENT_SETTINGS`).
+fn display_settings(device: &[u16], index: u32) -> Option<sys::DEVMODEW> {
+    // SAFETY: C-Structs are safe to zero-initialize.
+    let mut devmode: sys::DEVMODEW = unsafe { zeroed() };
+    devmode.dmSize = size_of::<sys::DEVMODEW>() as u16;
+
+    // SAFETY: The device name is null-terminated, and the `.dmSize` member was set above.
+    let res = unsafe { sys::EnumDisplaySettingsW(device.as_ptr(), index, addr_of_mut!(devmode)) };
+    (res != 0).then_some(devmode)
+}
+
+/// Converts a Display Setting into a Video Mode.
+fn video_mode_from(devmode: &sys::DEVMODEW) -> VideoMode {
+    // Frequencies of `0` and `1` stand for the hardware's default.
+    let refresh_rate = if devmode.dmDisplayFrequency > 1 {
+        f64::from(devmode.dmDisplayFrequency)
+    } else {
+        0.0
+    };
+
+    VideoMode {
+        size: Size::new(devmode.dmPelsWidth as _, devmode.dmPelsHeight as _),
+        refresh_rate,
+        bit_depth: devmode.dmBitsPerPel,
+    }
+}
+
+// ================================================================================================================================ //
+
+/// The display devices whose Video Mode was switched by an Exclusive Fullscreen Window, by Window.
+#[derive(Default)]
+pub(crate) struct WinVideoModes(Mutex<HashMap<WindowHandle, Vec<u16>>>);
+
+impl WinVideoModes {
+    /// Switches the Screen to its closest Video Mode on behalf of the Window, returning whether or not it succeeded.\
+    /// Any Video Mode that the Window switched before is restored first.
+    pub(crate) fn switch(&self, hwnd: WindowHandle, screen: &Screen, mode: &VideoMode) -> bool {
+        self.restore(hwnd);
+
+        let Some(device) = screen.internal_device() else {
+            return false;
+        };
+
+        let settings = screen.internal_all_settings();
+        let modes: Vec<VideoMode> = settings.iter().map(video_mode_from).collect();
+        let Some(closest) = mode.closest_in(&modes) else {
+            return false;
+        };
+
+        let Some(mut devmode) = settings
+            .into_iter()
+            .find(|devmode| video_mode_from(devmode) == *closest)
+        else {
+            return false;
+        };
+        devmode.dmFields =
+            sys::DM_PELSWIDTH | sys::DM_PELSHEIGHT | sys::DM_BITSPERPEL | sys::DM_DISPLAYFREQUENCY;
+
+        // SAFETY: The device name is null-terminated, and the Display Setting came from the same device.
+        let res = unsafe {
+            sys::ChangeDisplaySettingsExW(
+                device.as_ptr(),
+                addr_of!(devmode),
+                0,
+                sys::CDS_FULLSCREEN,
+                null(),
+            )
+        };
+        if res != sys::DISP_CHANGE_SUCCESSFUL {
+            return false;
+        }
+
+        self.0.lock().unwrap().insert(hwnd, device);
+        true
+    }
+
+    /// Restores the Video Mode that the Window switched, if any.
+    pub(crate) fn restore(&self, hwnd: WindowHandle) {
+        let device = self.0.lock().unwrap().remove(&hwnd);
+        if let Some(device) = device {
+            restore_device(&device);
+        }
+    }
+}
+
+/// Restores the Video Mode of a display device, to the one saved in the registry.
+fn restore_device(device: &[u16]) {
+    // SAFETY: The device name is null-terminated, and NULL restores the registry's Display Settings.
+    unsafe { sys::ChangeDisplaySettingsExW(device.as_ptr(), null(), 0, 0, null()) };
 }
 
 // ================================================================================================================================ //
@@ -0,0 +1,163 @@
+/*
+ *  Crate: Wyn
+ * Module: Win32 - Testing
+ */
+
+//! Utilities for driving Windows from automated tests, by posting synthetic User-Input events to them.
+//!
+//! The events are injected into the system input stream with `SendInput`, so they are only delivered to the Foreground Window.\
+//! Thus, the Window is brought to the foreground before each event is sent.\
+//! They are reported to the `EventHandler` just like real User-Input.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::*;
+use super::event_loop::EventLoop;
+use super::inputs::*;
+use super::types::Point;
+use super::window::WindowHandle;
+
+// ================================================================================================================================ //
+
+/// Moves the Cursor over the Window, where the `point` is relative to the Window's Content.
+pub fn cursor_move(_events: &EventLoop, handle: WindowHandle, point: Point) -> WinResult<()> {
+    foreground(handle);
+
+    let mut pos = sys::POINT {
+        x: point.x as _,
+        y: point.y as _,
+    };
+
+    let res = sys_verify! { sys::ClientToScreen(handle, addr_of_mut!(pos)) };
+    res?;
+
+    let res = sys_verify! { sys::SetCursorPos(pos.x, pos.y) };
+    res?;
+
+    Ok(())
+}
+
+/// Posts a synthetic Scroll-Wheel event to the Window.\
+/// A delta of `1.0` is one notch (`WHEEL_DELTA`) of the Scroll-Wheel.
+pub fn scroll_wheel(
+    _events: &EventLoop,
+    handle: WindowHandle,
+    delta_x: ScrollDelta,
+    delta_y: ScrollDelta,
+) -> WinResult<()> {
+    let notches = |delta: ScrollDelta| (delta * sys::WHEEL_DELTA as ScrollDelta).round() as i32;
+
+    let mut inputs = Vec::new();
+    if delta_y != 0.0 {
+        inputs.push(mouse_input(sys::MOUSEEVENTF_WHEEL, notches(delta_y)));
+    }
+    if delta_x != 0.0 {
+        inputs.push(mouse_input(sys::MOUSEEVENTF_HWHEEL, notches(delta_x)));
+    }
+
+    send_inputs(handle, &inputs)
+}
+
+/// Posts a synthetic Button-Press (or Button-Release) event to the Window.\
+/// Buttons without an equivalent Mouse-Event are ignored.
+pub fn button_press(
+    _events: &EventLoop,
+    handle: WindowHandle,
+    button: MouseButton,
+    pressed: bool,
+) -> WinResult<()> {
+    #[rustfmt::skip]
+    let (flags, data) = match button {
+        MouseButton::Left   => ((sys::MOUSEEVENTF_LEFTDOWN,   sys::MOUSEEVENTF_LEFTUP),   0),
+        MouseButton::Middle => ((sys::MOUSEEVENTF_MIDDLEDOWN, sys::MOUSEEVENTF_MIDDLEUP), 0),
+        MouseButton::Right  => ((sys::MOUSEEVENTF_RIGHTDOWN,  sys::MOUSEEVENTF_RIGHTUP),  0),
+        MouseButton::X1     => ((sys::MOUSEEVENTF_XDOWN,      sys::MOUSEEVENTF_XUP),      sys::XBUTTON1),
+        MouseButton::X2     => ((sys::MOUSEEVENTF_XDOWN,      sys::MOUSEEVENTF_XUP),      sys::XBUTTON2),
+        MouseButton::Other(_) => return Ok(()),
+    };
+
+    let flag = if pressed { flags.0 } else { flags.1 };
+
+    send_inputs(handle, &[mouse_input(flag, data as _)])
+}
+
+/// Posts a synthetic Key-Press (or Key-Release) event to the Window.\
+/// Keys without a Virtual-Key Code are ignored.
+pub fn key_press(
+    _events: &EventLoop,
+    handle: WindowHandle,
+    key: Key,
+    pressed: bool,
+) -> WinResult<()> {
+    match key.native() {
+        Some(vk) => send_inputs(handle, &[key_input(vk, pressed)]),
+        None => Ok(()),
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Brings the Window to the foreground, so that it receives the injected events.
+fn foreground(handle: WindowHandle) {
+    // SAFETY: This function does not set the Win32 Thread Error.
+    let _res = unsafe { sys::SetForegroundWindow(handle) };
+}
+
+/// Constructs a Mouse Input, with the given Mouse-Event flags.
+fn mouse_input(flags: sys::MOUSE_EVENT_FLAGS, data: i32) -> sys::INPUT {
+    let mi = sys::MOUSEINPUT {
+        dx: 0,
+        dy: 0,
+        mouseData: data as _,
+        dwFlags: flags,
+        time: 0,
+        dwExtraInfo: 0,
+    };
+
+    sys::INPUT {
+        r#type: sys::INPUT_MOUSE,
+        Anonymous: sys::INPUT_0 { mi },
+    }
+}
+
+/// Constructs a Keyboard Input for the Virtual-Key, along with its Scan-Code.
+fn key_input(vk: NativeKeyCode, pressed: bool) -> sys::INPUT {
+    // SAFETY: This function does not indicate any error conditions (unmapped keys have a Scan-Code of zero).
+    let scan = unsafe { sys::MapVirtualKeyW(vk as _, sys::MAPVK_VK_TO_VSC) };
+
+    let ki = sys::KEYBDINPUT {
+        wVk: vk,
+        wScan: scan as _,
+        dwFlags: if pressed { 0 } else { sys::KEYEVENTF_KEYUP },
+        time: 0,
+        dwExtraInfo: 0,
+    };
+
+    sys::INPUT {
+        r#type: sys::INPUT_KEYBOARD,
+        Anonymous: sys::INPUT_0 { ki },
+    }
+}
+
+/// Injects the Inputs into the system input stream, after bringing the Window to the foreground.
+fn send_inputs(handle: WindowHandle, inputs: &[sys::INPUT]) -> WinResult<()> {
+    if inputs.is_empty() {
+        return Ok(());
+    }
+
+    foreground(handle);
+
+    let count = inputs.len() as u32;
+    let size = size_of::<sys::INPUT>() as i32;
+
+    // Returns zero if the Inputs were blocked by another thread (or by UIPI).
+    let res = sys_verify! { sys::SendInput(count, inputs.as_ptr(), size) };
+    res?;
+
+    Ok(())
+}
+
+// ================================================================================================================================ //
@@ -97,3 +97,18 @@ impl From<Rect> for NativeRect {
 }
 
 // ================================================================================================================================ //
+
+/// The DPI at which the Scale Factor is `1.0`.
+pub(crate) const DEFAULT_DPI: u32 = 96;
+
+/// Converts a DPI (from `GetDpiForWindow` or `GetDpiForMonitor`) into a Scale Factor.\
+/// Both functions report a DPI of `0` on failure, which is treated as the default DPI.
+pub(crate) fn dpi_to_scale_factor(dpi: u32) -> ScaleFactor {
+    if dpi == 0 {
+        1.0
+    } else {
+        f64::from(dpi) / f64::from(DEFAULT_DPI)
+    }
+}
+
+// ================================================================================================================================ //
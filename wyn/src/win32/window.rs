@@ -13,10 +13,12 @@
 #[allow(unused_imports)]
 use super::*;
 
+use super::cursor::{self, Cursor};
 use super::errors::*;
 use super::event_loop::EventLoop;
-use super::screen::Screen;
-use super::types::{NativeRect, Rect};
+use super::ime;
+use super::screen::{Screen, VideoMode};
+use super::types::{self, NativePoint, NativeRect, Point, Rect, ScaleFactor};
 
 // ================================================================================================================================ //
 
@@ -240,6 +242,27 @@ impl Window {
         Ok(())
     }
 
+    /// Switches the Screen to the Video Mode, and Fullscreens the Window over it. (Exclusive Fullscreen)\
+    /// The closest Video Mode of the Screen with the same resolution is used, and `false` is returned if there is none.\
+    /// The previous Video Mode is restored once the Window is restored, or is closed.
+    pub fn fullscreen_exclusive(
+        &self,
+        events: &EventLoop,
+        screen: &Screen,
+        mode: &VideoMode,
+    ) -> WinResult<bool> {
+        self.restore(events)?;
+        if !events.data.video_modes.switch(self.handle(), screen, mode) {
+            return Ok(false);
+        }
+
+        // The Window is maximized over whichever Screen it is on, so it is first moved onto the switched Screen.
+        let rect = screen.rect(events);
+        self.reposition_border(events, rect)?;
+        self.fullscreen(events)?;
+        Ok(true)
+    }
+
     /// Returns whether or not the Window is Fullscreened.
     pub fn is_fullscreen(&self, events: &EventLoop) -> bool {
         if let Ok(style) = self.style(events) {
@@ -251,10 +274,18 @@ impl Window {
 
     // ---------------------------------------------------------------- //
 
-    /// Restores the Window from Minimized/Maximized/Fullscreen state.
-    pub fn restore(&self, _events: &EventLoop) -> WinResult<()> {
+    /// Restores the Window from Minimized/Maximized/Fullscreen state.\
+    /// Leaving Exclusive Fullscreen also restores the Video Mode of the Screen.
+    pub fn restore(&self, events: &EventLoop) -> WinResult<()> {
+        // A Minimized Window is restored to the state it had before it was Minimized.
+        let minimized = self.is_minimized(events);
+
         // SAFETY: This function does not indicate any error conditions.
         unsafe { sys::ShowWindow(self.handle(), sys::SW_RESTORE) };
+
+        if !minimized {
+            events.data.video_modes.restore(self.handle());
+        }
         Ok(())
     }
 
@@ -532,10 +563,105 @@ impl Window {
 
 // ================================================================================================================================ //
 
+/// Cursor
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Sets the appearance of the Mouse-Cursor while it is over the Window.
+    pub fn set_cursor(&self, events: &EventLoop, cursor: &Cursor) -> WinResult<()> {
+        let handle = cursor::load(cursor)?;
+        let prev = events.data.cursors.set_cursor(self.handle(), handle)?;
+
+        // The previous Cursor may only be destroyed once the Window stopped showing it.
+        cursor::refresh(self.handle());
+        drop(prev);
+        Ok(())
+    }
+
+    /// Shows or hides the Mouse-Cursor while it is over the Window.
+    pub fn set_cursor_visible(&self, events: &EventLoop, visible: bool) -> WinResult<()> {
+        events.data.cursors.set_visible(self.handle(), visible)?;
+        cursor::refresh(self.handle());
+        Ok(())
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Moves the Mouse-Cursor to the point, relative to the Inner-Content of the Window.
+    pub fn warp_cursor(&self, _events: &EventLoop, point: Point) -> WinResult<()> {
+        let mut nt_pt = NativePoint::from(point);
+
+        // SAFETY: This function's result-code is checked below by `sys_verify`.
+        let res = sys_verify! { sys::ClientToScreen(self.handle(), addr_of_mut!(nt_pt)) };
+        let _ = res?;
+
+        // SAFETY: This function's result-code is checked below by `sys_verify`.
+        let res = sys_verify! { sys::SetCursorPos(nt_pt.x, nt_pt.y) };
+        res.map(|_| ())
+    }
+
+    /// Grabs the Mouse-Cursor, confining it to the Inner-Content of the Window (or releases it).\
+    /// The Cursor is released while the Window is unfocused, and confined again once it is refocused.\
+    /// Combined with hiding the Cursor and `EventHandler::raw_motion`, this suits First-Person camera controls.
+    pub fn grab_cursor(&self, events: &EventLoop, grab: bool) -> WinResult<()> {
+        events.data.cursors.set_grabbed(self.handle(), grab)?;
+
+        if !grab {
+            cursor::release_clip();
+        } else if self.is_focused(events) {
+            cursor::clip_to(self.handle())?;
+        }
+        Ok(())
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Input Method
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Allows (or disallows) Input Methods to compose text in the Window.\
+    /// Input Methods are disallowed by default, since they would intercept Key presses (e.g. for game controls).
+    pub fn set_ime_allowed(&self, events: &EventLoop, allowed: bool) -> WinResult<()> {
+        if !events.data.imes.set_allowed(self.handle(), allowed) {
+            return Ok(());
+        }
+
+        // SAFETY: Must be called on the Event Thread.
+        let handle = self.handle();
+        let fut = events.execute(move || unsafe { ime::associate(handle, allowed) });
+        fut.wait();
+        Ok(())
+    }
+
+    /// Sets the area being edited, relative to the Inner-Content of the Window.\
+    /// The Input Method places its candidate window next to it, without covering it.
+    pub fn set_ime_position(&self, events: &EventLoop, rect: Rect) -> WinResult<()> {
+        // The Input Method windows are placed again once the composition starts.
+        let imes = &events.data.imes;
+        if !imes.set_position(self.handle(), rect) || imes.position(self.handle()).is_none() {
+            return Ok(());
+        }
+
+        // SAFETY: Must be called on the Event Thread.
+        let handle = self.handle();
+        let fut = events.execute(move || unsafe { ime::place(handle, rect) });
+        fut.wait();
+        Ok(())
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
 /// Screens
 impl Window {
     /// Returns the the closest Screen the Window is currently occupying.
-    pub fn screen(&self) -> Screen {
+    pub fn screen(&self, _events: &EventLoop) -> Screen {
         // SAFETY: All the arguments are guaranteed to be valid.
         let handle =
             unsafe { sys::MonitorFromWindow(self.handle(), sys::MONITOR_DEFAULTTONEAREST) };
@@ -546,6 +672,19 @@ impl Window {
 
 // ================================================================================================================================ //
 
+/// Scale Factor
+impl Window {
+    /// Returns the Scale Factor of the Window, which its UI should be scaled by.\
+    /// Follows the DPI of the Screen that the Window is (mostly) on.
+    pub fn scale_factor(&self, _events: &EventLoop) -> ScaleFactor {
+        // SAFETY: This function does not set the WIN32-ERROR Code, and returns `0` for invalid Windows.
+        let dpi = unsafe { sys::GetDpiForWindow(self.handle()) };
+        types::dpi_to_scale_factor(dpi)
+    }
+}
+
+// ================================================================================================================================ //
+
 /// Redraw
 impl Window {
     /// Requests the Window to redraw its contents.
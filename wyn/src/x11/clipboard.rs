@@ -0,0 +1,667 @@
+/*
+ *  Crate: Wyn
+ * Module: X11 - Clipboard
+ */
+
+//! Reading and writing the Clipboards, following the ICCCM Selection protocol.
+//!
+//! Selections are transferred between clients by request, so Wyn must answer `SelectionRequest` events for as long as it owns one.\
+//! To avoid blocking the Event Loop (and so that the Event Thread can paste too), Selections are handled on a separate XCB Connection,
+//! whose File Descriptor is watched by the Event Loop.\
+//! Large transfers (in either direction) are split into chunks with the `INCR` protocol.
+//!
+//! ## References
+//! * <https://x.org/releases/X11R7.7/doc/xorg-docs/icccm/icccm.html#Peer_to_Peer_Communication_by_Means_of_Selections>
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::*;
+use super::event_loop::{EventLoop, NonzeroXcbAtom, XcbConnection, XcbEvent, XcbEventRef};
+use crate::linux::epoll::Epoller;
+
+use std::os::fd::RawFd;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+pub use crate::common::clipboard::*;
+
+// ================================================================================================================================ //
+
+/// How long to wait on the Selection Owner, before assuming it is not going to respond.
+const TRANSFER_TIMEOUT: Duration = Duration::from_secs(2);
+
+// ================================================================================================================================ //
+
+impl Clipboard {
+    /// Returns the text on the Clipboard, or `None` if it is empty (or does not hold text).
+    pub fn get_text(self, events: &EventLoop) -> XcbGenericResult<Option<String>> {
+        let clipboard = &events.clipboard;
+        let atoms = &clipboard.atoms;
+
+        for target in [atoms.utf8_string.get(), atoms.mime_text.get()] {
+            if let Some(data) = clipboard.receive(self, target)? {
+                return Ok(Some(String::from_utf8_lossy(&data).into_owned()));
+            }
+        }
+
+        // `STRING` is always encoded as Latin-1.
+        match clipboard.receive(self, sys::XCB_ATOM_STRING)? {
+            Some(data) => Ok(Some(data.into_iter().map(char::from).collect())),
+            None => Ok(None),
+        }
+    }
+
+    /// Places the text on the Clipboard, replacing its previous contents.
+    pub fn set_text(self, events: &EventLoop, text: &str) -> XcbGenericResult<()> {
+        let clipboard = &events.clipboard;
+        let atoms = &clipboard.atoms;
+
+        let utf8 = text.as_bytes().to_vec();
+        let latin1 = text
+            .chars()
+            .map(|c| u8::try_from(c).unwrap_or(b'?'))
+            .collect();
+
+        let contents = vec![
+            (atoms.utf8_string.get(), utf8.clone()),
+            (atoms.mime_text.get(), utf8),
+            (sys::XCB_ATOM_STRING, latin1),
+        ];
+        clipboard.own(self, contents)
+    }
+
+    /// Returns the data of the given MIME type on the Clipboard, or `None` if it does not hold that type.
+    pub fn get(self, events: &EventLoop, mime: &str) -> XcbGenericResult<Option<Vec<u8>>> {
+        let clipboard = &events.clipboard;
+
+        // If no client has registered the MIME type, then no client can be offering it.
+        match clipboard.connection.query_atom(mime, false)? {
+            Some(target) => clipboard.receive(self, target.get()),
+            None => Ok(None),
+        }
+    }
+
+    /// Places data of the given MIME type on the Clipboard, replacing its previous contents.
+    pub fn set(self, events: &EventLoop, mime: &str, data: &[u8]) -> XcbGenericResult<()> {
+        let clipboard = &events.clipboard;
+
+        match clipboard.connection.query_atom(mime, true)? {
+            Some(target) => clipboard.own(self, vec![(target.get(), data.to_vec())]),
+            None => Ok(()),
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+/// The Atoms used by the Selection protocol.
+struct ClipboardAtoms {
+    /// The `CLIPBOARD` Selection.
+    clipboard: NonzeroXcbAtom,
+
+    /// The `TARGETS` Target, which lists the other available Targets.
+    targets: NonzeroXcbAtom,
+
+    /// The `UTF8_STRING` Target.
+    utf8_string: NonzeroXcbAtom,
+
+    /// The `text/plain;charset=utf-8` Target.
+    mime_text: NonzeroXcbAtom,
+
+    /// The `INCR` Type, which announces an incremental transfer.
+    incr: NonzeroXcbAtom,
+
+    /// The Property on the Clipboard Window that Selections are converted into.
+    property: NonzeroXcbAtom,
+}
+
+impl ClipboardAtoms {
+    /// Queries the Atoms.
+    fn new(connection: &XcbConnection) -> Self {
+        let query = |name| {
+            connection
+                .query_atom(name, true)
+                .expect("XCB ATOM QUERY FAILURE")
+                .expect("XCB ATOM NULL FAILURE")
+        };
+
+        Self {
+            clipboard: query("CLIPBOARD"),
+            targets: query("TARGETS"),
+            utf8_string: query("UTF8_STRING"),
+            mime_text: query(MIME_TEXT),
+            incr: query("INCR"),
+            property: query("_WYN_SELECTION"),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// An incremental transfer of Selection data to another client.
+struct IncrTransfer {
+    /// The Window that requested the data.
+    requestor: sys::xcb_window_t,
+
+    /// The Property on the requestor that the chunks are written to.
+    property: sys::xcb_atom_t,
+
+    /// The Target (and Type) of the data.
+    target: sys::xcb_atom_t,
+
+    /// The data being transferred.
+    data: Vec<u8>,
+
+    /// How much of the data has been written so far.
+    offset: usize,
+}
+
+/// The state of the Selections owned by Wyn.
+#[derive(Default)]
+struct ClipboardState {
+    /// The data for each Target, for each owned Selection (indexed by `Clipboard::index`).
+    owned: [Vec<(sys::xcb_atom_t, Vec<u8>)>; 2],
+
+    /// The incremental transfers that are still in progress.
+    transfers: Vec<IncrTransfer>,
+}
+
+/// The Selection (Clipboard) state for an `EventLoop`.
+pub(crate) struct XcbClipboard {
+    /// A separate connection, so that Selection events are not mixed up with Window events.
+    connection: XcbConnection,
+
+    /// The hidden Window that owns Selections, and receives converted data.
+    window: sys::xcb_window_t,
+
+    /// The Atoms used by the Selection protocol.
+    atoms: ClipboardAtoms,
+
+    /// Data smaller than this is sent in a single Property, and larger data is sent with `INCR` (in chunks of this size).
+    chunk_size: usize,
+
+    /// Only one thread may read events from the connection at a time, so this is locked while doing so.
+    state: Mutex<ClipboardState>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl XcbClipboard {
+    /// Opens the Clipboard connection and Window, and registers the connection with the `Epoller`.
+    pub(crate) fn new(epoller: &Epoller) -> XcbResult<Self> {
+        let connection = XcbConnection::new()?;
+        let atoms = ClipboardAtoms::new(&connection);
+
+        let screen = connection.screen();
+        let window = unsafe { sys::xcb_generate_id(connection.handle) };
+
+        // Property changes are needed to receive `INCR` transfers.
+        let value_mask = sys::XCB_CW_EVENT_MASK;
+        let value_list = [sys::XCB_EVENT_MASK_PROPERTY_CHANGE];
+
+        let cookie = unsafe {
+            sys::xcb_create_window_checked(
+                connection.handle,
+                sys::XCB_COPY_FROM_PARENT as u8,
+                window,
+                screen.root,
+                0,
+                0,
+                1,
+                1,
+                0,
+                sys::XCB_WINDOW_CLASS_INPUT_ONLY as u16,
+                screen.root_visual,
+                value_mask,
+                void_of!(value_list),
+            )
+        };
+        connection.check_cookie(cookie)?;
+
+        // The maximum request length is given in 4-byte units, and a quarter of it leaves plenty of room for the request itself.
+        let max_request = unsafe { sys::xcb_get_maximum_request_length(connection.handle) };
+        let chunk_size = max_request as usize;
+
+        epoller.register(connection.fd());
+
+        Ok(Self {
+            connection,
+            window,
+            atoms,
+            chunk_size,
+            state: Mutex::new(ClipboardState::default()),
+        })
+    }
+
+    /// Returns whether or not the File Descriptor belongs to the Clipboard connection.
+    pub(crate) fn is_fd(&self, fd: RawFd) -> bool {
+        self.connection.fd() == fd
+    }
+
+    /// Answers any pending requests from other clients.\
+    /// If another thread is already reading events (while pasting), it answers them instead.
+    pub(crate) fn process(&self) {
+        if let Ok(mut state) = self.state.try_lock() {
+            self.drain(&mut state);
+        }
+    }
+
+    /// Returns the Atom of the Selection.
+    fn selection(&self, clipboard: Clipboard) -> sys::xcb_atom_t {
+        match clipboard {
+            Clipboard::Standard => self.atoms.clipboard.get(),
+            Clipboard::Primary => sys::XCB_ATOM_PRIMARY,
+        }
+    }
+
+    /// Returns the Clipboard for the Atom of the Selection, if it is one that Wyn uses.
+    fn clipboard(&self, selection: sys::xcb_atom_t) -> Option<Clipboard> {
+        if selection == self.atoms.clipboard.get() {
+            Some(Clipboard::Standard)
+        } else if selection == sys::XCB_ATOM_PRIMARY {
+            Some(Clipboard::Primary)
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for XcbClipboard {
+    fn drop(&mut self) {
+        unsafe { sys::xcb_destroy_window(self.connection.handle, self.window) };
+        self.connection.flush();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl XcbClipboard {
+    /// Takes ownership of the Selection, and stores the data to hand out for each Target.
+    fn own(
+        &self,
+        clipboard: Clipboard,
+        contents: Vec<(sys::xcb_atom_t, Vec<u8>)>,
+    ) -> XcbGenericResult<()> {
+        let mut state = self.state.lock().unwrap();
+        state.owned[clipboard.index()] = contents;
+
+        let selection = self.selection(clipboard);
+        let cookie = unsafe {
+            sys::xcb_set_selection_owner_checked(
+                self.connection.handle,
+                self.window,
+                selection,
+                sys::XCB_CURRENT_TIME,
+            )
+        };
+        self.connection.check_cookie(cookie)
+    }
+
+    /// Returns the current owner of the Selection.
+    fn owner(&self, selection: sys::xcb_atom_t) -> XcbGenericResult<sys::xcb_window_t> {
+        let mut error = null_mut();
+        let cookie = unsafe { sys::xcb_get_selection_owner(self.connection.handle, selection) };
+        let reply = unsafe {
+            sys::xcb_get_selection_owner_reply(self.connection.handle, cookie, addr_of_mut!(error))
+        };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+
+        if let Some(err) = XcbGenericError::new(error) {
+            return Err(err);
+        }
+
+        match unsafe { reply.as_ref() } {
+            Some(rep) => Ok(rep.owner),
+            None => Ok(sys::XCB_NONE),
+        }
+    }
+
+    /// Requests the Selection in the format of the Target, and waits for the data.\
+    /// Returns `None` if the Selection has no owner, the owner refused the conversion, or the owner stopped responding.
+    fn receive(
+        &self,
+        clipboard: Clipboard,
+        target: sys::xcb_atom_t,
+    ) -> XcbGenericResult<Option<Vec<u8>>> {
+        let mut state = self.state.lock().unwrap();
+        let selection = self.selection(clipboard);
+
+        let owner = self.owner(selection)?;
+        if owner == sys::XCB_NONE {
+            return Ok(None);
+        }
+
+        // If Wyn owns the Selection, then there is no need to go through the X Server.
+        if owner == self.window {
+            let contents = &state.owned[clipboard.index()];
+            let data = contents.iter().find(|(atom, _)| *atom == target);
+            return Ok(data.map(|(_, data)| data.clone()));
+        }
+
+        // Any events that arrive while waiting are handled here, as the Event Loop cannot read them while the lock is held.
+        // Once finished, anything that was read (but not handled) is drained, since the File Descriptor will not signal it again.
+        let res = self.internal_receive(&mut state, selection, target, sys::XCB_CURRENT_TIME);
+        self.drain(&mut state);
+        res
+    }
+
+    /// Requests any Selection (e.g. `XdndSelection`) in the format of the Target, and waits for the data.\
+    /// The time should be that of the event which prompted the request, as some owners refuse requests for other times.
+    pub(crate) fn convert(
+        &self,
+        selection: sys::xcb_atom_t,
+        target: sys::xcb_atom_t,
+        time: sys::xcb_timestamp_t,
+    ) -> XcbGenericResult<Option<Vec<u8>>> {
+        let mut state = self.state.lock().unwrap();
+        let res = self.internal_receive(&mut state, selection, target, time);
+        self.drain(&mut state);
+        res
+    }
+
+    /// Implementation of `receive`, which must be called while holding the lock.
+    fn internal_receive(
+        &self,
+        state: &mut ClipboardState,
+        selection: sys::xcb_atom_t,
+        target: sys::xcb_atom_t,
+        time: sys::xcb_timestamp_t,
+    ) -> XcbGenericResult<Option<Vec<u8>>> {
+        let property = self.atoms.property.get();
+
+        let cookie = unsafe {
+            sys::xcb_convert_selection_checked(
+                self.connection.handle,
+                self.window,
+                selection,
+                target,
+                property,
+                time,
+            )
+        };
+        self.connection.check_cookie(cookie)?;
+
+        // Wait for the owner to finish the conversion.
+        let deadline = Instant::now() + TRANSFER_TIMEOUT;
+        let converted = loop {
+            let event = match self.next_event(deadline) {
+                Some(event) => event,
+                None => return Ok(None),
+            };
+            match event.enumerate() {
+                XcbEventRef::SelectionNotify(evt)
+                    if evt.requestor == self.window && evt.selection == selection =>
+                {
+                    break evt.property != sys::XCB_NONE;
+                }
+                _ => self.handle_event(state, &event),
+            }
+        };
+        if !converted {
+            return Ok(None);
+        }
+
+        let (kind, data) = self.take_property()?;
+        if kind != self.atoms.incr.get() {
+            return Ok(Some(data));
+        }
+
+        // The data is sent in chunks, each of which is written once the previous one was deleted (by reading it).
+        // A chunk of length zero marks the end of the transfer.
+        let mut buffer = Vec::new();
+        let mut deadline = Instant::now() + TRANSFER_TIMEOUT;
+        loop {
+            let event = match self.next_event(deadline) {
+                Some(event) => event,
+                None => return Ok(None),
+            };
+            match event.enumerate() {
+                XcbEventRef::PropertyNotify(evt)
+                    if evt.window == self.window
+                        && evt.atom == property
+                        && evt.state == sys::XCB_PROPERTY_NEW_VALUE as u8 =>
+                {
+                    let (_, chunk) = self.take_property()?;
+                    if chunk.is_empty() {
+                        return Ok(Some(buffer));
+                    }
+                    buffer.extend_from_slice(&chunk);
+                    deadline = Instant::now() + TRANSFER_TIMEOUT;
+                }
+                _ => self.handle_event(state, &event),
+            }
+        }
+    }
+
+    /// Reads (and deletes) the Selection Property from the Clipboard Window, returning its Type and data.
+    fn take_property(&self) -> XcbGenericResult<(sys::xcb_atom_t, Vec<u8>)> {
+        let mut error = null_mut();
+        let cookie = unsafe {
+            sys::xcb_get_property(
+                self.connection.handle,
+                true as u8,
+                self.window,
+                self.atoms.property.get(),
+                sys::XCB_ATOM_ANY,
+                0,
+                u32::MAX / 4,
+            )
+        };
+        let reply = unsafe {
+            sys::xcb_get_property_reply(self.connection.handle, cookie, addr_of_mut!(error))
+        };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+
+        if let Some(err) = XcbGenericError::new(error) {
+            return Err(err);
+        }
+
+        match unsafe { reply.as_ref() } {
+            Some(rep) => {
+                let len = unsafe { sys::xcb_get_property_value_length(reply) } as usize;
+                let dat = unsafe { sys::xcb_get_property_value(reply) } as *const u8;
+                let data = match len {
+                    0 => Vec::new(),
+                    _ => unsafe { std::slice::from_raw_parts(dat, len) }.to_vec(),
+                };
+                Ok((rep.type_, data))
+            }
+            None => Ok((sys::XCB_NONE, Vec::new())),
+        }
+    }
+
+    /// Returns the next event on the Clipboard connection, or `None` if the deadline passes first.
+    fn next_event(&self, deadline: Instant) -> Option<XcbEvent> {
+        loop {
+            let event = unsafe { sys::xcb_poll_for_event(self.connection.handle) };
+            if let Some(event) = XcbEvent::new(event) {
+                return Some(event);
+            }
+
+            let now = Instant::now();
+            if now >= deadline || self.connection.status().is_err() {
+                return None;
+            }
+
+            let mut fd = sys::pollfd {
+                fd: self.connection.fd(),
+                events: sys::POLLIN,
+                revents: 0,
+            };
+            let timeout = (deadline - now).as_millis().clamp(1, i32::MAX as u128) as i32;
+            unsafe { sys::poll(addr_of_mut!(fd), 1, timeout) };
+        }
+    }
+
+    /// Handles every event that has already been received.
+    fn drain(&self, state: &mut ClipboardState) {
+        let poll = || XcbEvent::new(unsafe { sys::xcb_poll_for_event(self.connection.handle) });
+        while let Some(event) = poll() {
+            self.handle_event(state, &event);
+        }
+        self.connection.flush();
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl XcbClipboard {
+    /// Handles an event sent to the Clipboard connection, by another client that wants (or took) an owned Selection.
+    fn handle_event(&self, state: &mut ClipboardState, event: &XcbEvent) {
+        match event.enumerate() {
+            XcbEventRef::SelectionRequest(evt) => self.handle_request(state, evt),
+            XcbEventRef::SelectionClear(evt) => {
+                if let Some(clipboard) = self.clipboard(evt.selection) {
+                    state.owned[clipboard.index()].clear();
+                }
+            }
+            XcbEventRef::PropertyNotify(evt) if evt.state == sys::XCB_PROPERTY_DELETE as u8 => {
+                self.continue_transfer(state, evt.window, evt.atom);
+            }
+            _ => {}
+        }
+    }
+
+    /// Converts an owned Selection into the requested Target, and notifies the requestor.
+    fn handle_request(&self, state: &mut ClipboardState, evt: &sys::xcb_selection_request_event_t) {
+        // Obsolete clients do not name a Property, and expect the Target to be used instead.
+        let property = match evt.property {
+            sys::XCB_NONE => evt.target,
+            property => property,
+        };
+
+        let contents: &[_] = match self.clipboard(evt.selection) {
+            Some(clipboard) if evt.owner == self.window => &state.owned[clipboard.index()],
+            _ => &[],
+        };
+
+        let requested = contents.iter().find(|(target, _)| *target == evt.target);
+
+        let converted = if contents.is_empty() {
+            false
+        } else if evt.target == self.atoms.targets.get() {
+            let mut targets = vec![self.atoms.targets.get()];
+            targets.extend(contents.iter().map(|(target, _)| *target));
+            self.write_property(evt.requestor, property, sys::XCB_ATOM_ATOM, 32, &targets);
+            true
+        } else if let Some((target, data)) = requested {
+            if data.len() < self.chunk_size {
+                self.write_property(evt.requestor, property, *target, 8, data);
+            } else {
+                // The requestor's Property changes are needed to know when to send the next chunk.
+                let value_list = [sys::XCB_EVENT_MASK_PROPERTY_CHANGE];
+                unsafe {
+                    sys::xcb_change_window_attributes(
+                        self.connection.handle,
+                        evt.requestor,
+                        sys::XCB_CW_EVENT_MASK,
+                        void_of!(value_list),
+                    )
+                };
+
+                let len = [data.len() as u32];
+                self.write_property(evt.requestor, property, self.atoms.incr.get(), 32, &len);
+
+                let transfer = IncrTransfer {
+                    requestor: evt.requestor,
+                    property,
+                    target: *target,
+                    data: data.clone(),
+                    offset: 0,
+                };
+                state.transfers.push(transfer);
+            }
+            true
+        } else {
+            false
+        };
+
+        let notify = sys::xcb_selection_notify_event_t {
+            response_type: sys::XCB_SELECTION_NOTIFY as u8,
+            pad0: 0,
+            sequence: 0,
+            time: evt.time,
+            requestor: evt.requestor,
+            selection: evt.selection,
+            target: evt.target,
+            property: if converted { property } else { sys::XCB_NONE },
+        };
+
+        unsafe {
+            sys::xcb_send_event(
+                self.connection.handle,
+                false as u8,
+                evt.requestor,
+                sys::XCB_EVENT_MASK_NO_EVENT,
+                bytes_of!(notify),
+            )
+        };
+        self.connection.flush();
+    }
+
+    /// Writes the next chunk of an incremental transfer, once the requestor has deleted the previous one.
+    fn continue_transfer(
+        &self,
+        state: &mut ClipboardState,
+        requestor: sys::xcb_window_t,
+        property: sys::xcb_atom_t,
+    ) {
+        let index = state
+            .transfers
+            .iter()
+            .position(|transfer| transfer.requestor == requestor && transfer.property == property);
+        let index = match index {
+            Some(index) => index,
+            None => return,
+        };
+
+        let transfer = &mut state.transfers[index];
+        let end = (transfer.offset + self.chunk_size).min(transfer.data.len());
+        let chunk = &transfer.data[transfer.offset..end];
+        self.write_property(requestor, property, transfer.target, 8, chunk);
+        self.connection.flush();
+
+        // The final (empty) chunk ends the transfer.
+        if chunk.is_empty() {
+            state.transfers.swap_remove(index);
+        } else {
+            transfer.offset = end;
+        }
+    }
+
+    /// Replaces a Property on another client's Window, ignoring errors (since that Window may be destroyed at any time).
+    fn write_property<T>(
+        &self,
+        window: sys::xcb_window_t,
+        property: sys::xcb_atom_t,
+        kind: sys::xcb_atom_t,
+        format: u8,
+        data: &[T],
+    ) {
+        unsafe {
+            sys::xcb_change_property(
+                self.connection.handle,
+                sys::XCB_PROP_MODE_REPLACE as u8,
+                window,
+                property,
+                kind,
+                format,
+                data.len() as u32,
+                data.as_ptr() as *const c_void,
+            )
+        };
+    }
+}
+
+// ================================================================================================================================ //
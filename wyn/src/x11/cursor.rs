@@ -0,0 +1,665 @@
+/*
+ *  Crate: Wyn
+ * Module: X11 - Cursor
+ */
+
+//! Changing the appearance of the Mouse-Cursor, and receiving Raw Mouse Motion.
+//!
+//! Standard shapes come from the core `cursor` font, while custom images are turned into Cursors with the Render extension.\
+//! Hiding swaps in a blank Cursor, since XFixes' `HideCursor` hides the Cursor across the whole Screen.\
+//! Grabbing actively grabs the Pointer (confined to the Window), which is released while the Window is unfocused.\
+//! Raw Mouse Motion is selected on the Root Window with XInput2, and is only reported while one of Wyn's Windows is focused.
+//!
+//! ## References
+//! * <https://www.x.org/releases/current/doc/xproto/x11protocol.html#requests:CreateGlyphCursor>
+//! * <https://gitlab.freedesktop.org/xorg/proto/xorgproto/-/blob/master/renderproto.txt>
+//! * <https://gitlab.freedesktop.org/xorg/proto/xorgproto/-/blob/master/specs/XI2proto.txt>
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::*;
+use super::event_loop::XcbConnection;
+use super::window::WindowHandle;
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+pub use crate::common::cursor::*;
+
+// ================================================================================================================================ //
+
+/// The Cursor state of a single Window.
+struct XcbCursorState {
+    /// The Cursor shown over the Window (`XCB_NONE` to inherit the Cursor of its parent).
+    cursor: sys::xcb_cursor_t,
+
+    /// Whether or not the Cursor is shown over the Window.
+    visible: bool,
+
+    /// Whether or not the Cursor is confined to the Window.
+    grabbed: bool,
+}
+
+impl Default for XcbCursorState {
+    fn default() -> Self {
+        Self {
+            cursor: sys::XCB_NONE,
+            visible: true,
+            grabbed: false,
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The Cursor state of every Window that changed its Cursor, along with the extensions that back it.
+pub(crate) struct XcbCursors {
+    /// The blank Cursor, shown over Windows that hide the Cursor.
+    blank: sys::xcb_cursor_t,
+
+    /// The Picture Format of 32-bit ARGB images, if the X Server supports the Render extension.
+    argb_format: Option<sys::xcb_render_pictformat_t>,
+
+    /// The Major Opcode and the negotiated minor version of the XInput extension, if Raw Motion was selected with XInput2.
+    xinput: Option<(u8, u16)>,
+
+    /// The Window that currently has focus, if it is one of Wyn's Windows.
+    focused: Mutex<Option<WindowHandle>>,
+
+    /// The Cursor state of every Window that changed its Cursor.
+    windows: Mutex<HashMap<WindowHandle, XcbCursorState>>,
+}
+
+impl XcbCursors {
+    /// Sets up the blank Cursor, and the Render and XInput2 extensions (if the X Server supports them).
+    pub(crate) fn new(connection: &XcbConnection) -> Self {
+        Self {
+            blank: create_blank(connection).expect("XCB BLANK CURSOR FAILURE"),
+            argb_format: query_argb_format(connection),
+            xinput: select_raw_motion(connection),
+            focused: Mutex::new(None),
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns whether or not Raw Motion is reported (which requires XInput2).
+    pub(crate) fn has_raw_motion(&self) -> bool {
+        self.xinput.is_some()
+    }
+
+    /// Returns the Major Opcode and the negotiated minor version of XInput2, if Raw Motion was selected with it.
+    pub(crate) fn xinput(&self) -> Option<(u8, u16)> {
+        self.xinput
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Replaces the Cursor shown over the Window, freeing the previous one.
+    pub(crate) fn set_cursor(
+        &self,
+        connection: &XcbConnection,
+        window: WindowHandle,
+        cursor: &Cursor,
+    ) -> XcbGenericResult<()> {
+        let xcursor = self.create(connection, cursor)?;
+
+        let mut windows = self.windows.lock().unwrap();
+        let state = windows.entry(window).or_default();
+        let prev = std::mem::replace(&mut state.cursor, xcursor);
+        let res = self.apply(connection, window, state);
+
+        // The X Server keeps the previous Cursor alive for as long as the Window still shows it.
+        if prev != sys::XCB_NONE {
+            unsafe { sys::xcb_free_cursor(connection.handle, prev) };
+        }
+        res
+    }
+
+    /// Shows or hides the Cursor over the Window.
+    pub(crate) fn set_visible(
+        &self,
+        connection: &XcbConnection,
+        window: WindowHandle,
+        visible: bool,
+    ) -> XcbGenericResult<()> {
+        let mut windows = self.windows.lock().unwrap();
+        let state = windows.entry(window).or_default();
+        state.visible = visible;
+        self.apply(connection, window, state)
+    }
+
+    /// Sets whether or not the Cursor is confined to the Window.\
+    /// The Pointer is only grabbed while the Window is focused.
+    pub(crate) fn set_grabbed(
+        &self,
+        connection: &XcbConnection,
+        window: WindowHandle,
+        grabbed: bool,
+    ) -> XcbGenericResult<()> {
+        let mut windows = self.windows.lock().unwrap();
+        windows.entry(window).or_default().grabbed = grabbed;
+
+        if !grabbed {
+            ungrab(connection);
+            Ok(())
+        } else if *self.focused.lock().unwrap() == Some(window) {
+            grab(connection, window).map(|_| ())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns whether or not the Cursor is confined to the Window.
+    pub(crate) fn is_grabbed(&self, window: WindowHandle) -> bool {
+        let windows = self.windows.lock().unwrap();
+        windows.get(&window).map_or(false, |state| state.grabbed)
+    }
+
+    /// Returns whether or not the Pointer is currently grabbed by the Window, which then only receives core events.
+    pub(crate) fn is_grab_active(&self, window: WindowHandle) -> bool {
+        *self.focused.lock().unwrap() == Some(window) && self.is_grabbed(window)
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Tracks the focus of Wyn's Windows, grabbing the Pointer for a focused Window that grabbed the Cursor.
+    pub(crate) fn focus(&self, connection: &XcbConnection, window: WindowHandle, focused: bool) {
+        {
+            let mut current = self.focused.lock().unwrap();
+            if focused {
+                *current = Some(window);
+            } else if *current == Some(window) {
+                *current = None;
+            }
+        }
+
+        if !self.is_grabbed(window) {
+            return;
+        }
+
+        if focused {
+            // The Window Manager may still hold its own grab, in which case the Pointer is grabbed on the next focus.
+            let _ = grab(connection, window);
+        } else {
+            ungrab(connection);
+        }
+    }
+
+    /// Forgets the Cursor state of a Window that was destroyed, freeing its Cursor.
+    pub(crate) fn remove(&self, connection: &XcbConnection, window: WindowHandle) {
+        {
+            let mut current = self.focused.lock().unwrap();
+            if *current == Some(window) {
+                *current = None;
+            }
+        }
+
+        let removed = self.windows.lock().unwrap().remove(&window);
+        if let Some(state) = removed {
+            if state.cursor != sys::XCB_NONE {
+                unsafe { sys::xcb_free_cursor(connection.handle, state.cursor) };
+            }
+        }
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Reads the relative motion of the Mouse from an XInput2 `RawMotion` event.\
+    /// Returns `None` for other Generic Events, and while none of Wyn's Windows are focused.
+    pub(crate) fn raw_motion(&self, event: &sys::xcb_ge_generic_event_t) -> Option<(f64, f64)> {
+        let (opcode, _) = self.xinput?;
+        if event.extension != opcode || event.event_type != sys::XCB_INPUT_RAW_MOTION as u16 {
+            return None;
+        }
+        self.focused.lock().unwrap().as_ref()?;
+
+        let evt = (event as *const sys::xcb_ge_generic_event_t)
+            .cast::<sys::xcb_input_raw_motion_event_t>();
+
+        // SAFETY: Generic Events are read whole, so the Valuator Mask and Values follow the event.
+        let (mask, values) = unsafe {
+            let mask_len = sys::xcb_input_raw_button_press_valuator_mask_length(evt) as usize;
+            let mask_ptr = sys::xcb_input_raw_button_press_valuator_mask(evt);
+            let values_len = sys::xcb_input_raw_button_press_axisvalues_raw_length(evt) as usize;
+            let values_ptr = sys::xcb_input_raw_button_press_axisvalues_raw(evt);
+            (
+                core::slice::from_raw_parts(mask_ptr, mask_len),
+                core::slice::from_raw_parts(values_ptr, values_len),
+            )
+        };
+
+        // Values are only sent for the Axes set in the mask, where Axes 0 and 1 are the horizontal and vertical motion.
+        let is_set = |axis: usize| mask.first().map_or(false, |bits| bits & (1 << axis) != 0);
+        let mut values = values.iter().map(fp3232_to_f64);
+
+        let delta_x = if is_set(0) { values.next()? } else { 0.0 };
+        let delta_y = if is_set(1) { values.next()? } else { 0.0 };
+
+        if delta_x == 0.0 && delta_y == 0.0 {
+            return None;
+        }
+        Some((delta_x, delta_y))
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Creates the native Cursor for a `Cursor`.\
+    /// Custom images fall back to the Arrow if the X Server does not support the Render extension.
+    fn create(
+        &self,
+        connection: &XcbConnection,
+        cursor: &Cursor,
+    ) -> XcbGenericResult<sys::xcb_cursor_t> {
+        match (cursor, self.argb_format) {
+            (Cursor::Shape(shape), _) => create_shape(connection, *shape),
+            (Cursor::Custom(image), Some(format)) => create_image(connection, format, image),
+            (Cursor::Custom(_), None) => create_shape(connection, CursorShape::Arrow),
+        }
+    }
+
+    /// Shows the Cursor of the Window (or the blank Cursor, if it is hidden).
+    fn apply(
+        &self,
+        connection: &XcbConnection,
+        window: WindowHandle,
+        state: &XcbCursorState,
+    ) -> XcbGenericResult<()> {
+        let cursor = if state.visible {
+            state.cursor
+        } else {
+            self.blank
+        };
+
+        let value_list = [cursor];
+        let cookie = unsafe {
+            sys::xcb_change_window_attributes_checked(
+                connection.handle,
+                window,
+                sys::XCB_CW_CURSOR,
+                void_of!(value_list),
+            )
+        };
+        connection.check_cookie(cookie)
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Creates a Cursor from the core `cursor` font.
+fn create_shape(
+    connection: &XcbConnection,
+    shape: CursorShape,
+) -> XcbGenericResult<sys::xcb_cursor_t> {
+    // Glyph indices from `X11/cursorfont.h`, where the mask of each glyph is the glyph that follows it.
+    #[rustfmt::skip]
+    let glyph: u16 = match shape {
+        CursorShape::Arrow              => 68,  // XC_left_ptr
+        CursorShape::Text               => 152, // XC_xterm
+        CursorShape::Crosshair          => 34,  // XC_crosshair
+        CursorShape::Hand               => 60,  // XC_hand2
+        CursorShape::Wait               => 150, // XC_watch
+        CursorShape::Help               => 92,  // XC_question_arrow
+        CursorShape::NotAllowed         => 24,  // XC_circle
+        CursorShape::Move               => 52,  // XC_fleur
+        CursorShape::ResizeHorizontal   => 108, // XC_sb_h_double_arrow
+        CursorShape::ResizeVertical     => 116, // XC_sb_v_double_arrow
+        CursorShape::ResizeDiagonal     => 14,  // XC_bottom_right_corner
+        CursorShape::ResizeAntiDiagonal => 12,  // XC_bottom_left_corner
+    };
+
+    let name = "cursor";
+    let font = unsafe { sys::xcb_generate_id(connection.handle) };
+    let cookie = unsafe {
+        sys::xcb_open_font_checked(
+            connection.handle,
+            font,
+            name.len() as u16,
+            name.as_ptr().cast(),
+        )
+    };
+    connection.check_cookie(cookie)?;
+    let _font_close = defer(|| unsafe {
+        sys::xcb_close_font(connection.handle, font);
+    });
+
+    // Black on White, like the Cursors of the Root Window.
+    let cursor = unsafe { sys::xcb_generate_id(connection.handle) };
+    let cookie = unsafe {
+        sys::xcb_create_glyph_cursor_checked(
+            connection.handle,
+            cursor,
+            font,
+            font,
+            glyph,
+            glyph + 1,
+            0,
+            0,
+            0,
+            0xFFFF,
+            0xFFFF,
+            0xFFFF,
+        )
+    };
+    connection.check_cookie(cookie)?;
+    Ok(cursor)
+}
+
+/// Creates a Cursor from a custom image, using a 32-bit ARGB Picture.
+fn create_image(
+    connection: &XcbConnection,
+    format: sys::xcb_render_pictformat_t,
+    image: &CursorImage,
+) -> XcbGenericResult<sys::xcb_cursor_t> {
+    let (width, height) = (image.width() as u16, image.height() as u16);
+    let (hotspot_x, hotspot_y) = image.hotspot();
+
+    // Render expects premultiplied alpha, and the pixels are sent in the client's byte order.
+    let pixels: Vec<u32> = image.argb(true).collect();
+    let bytes = unsafe { core::slice::from_raw_parts(pixels.as_ptr().cast(), pixels.len() * 4) };
+
+    let pixmap = create_pixmap(connection, 32, width, height, bytes)?;
+    let _pixmap_free = defer(|| unsafe {
+        sys::xcb_free_pixmap(connection.handle, pixmap);
+    });
+
+    let picture = unsafe { sys::xcb_generate_id(connection.handle) };
+    let cookie = unsafe {
+        sys::xcb_render_create_picture_checked(
+            connection.handle,
+            picture,
+            pixmap,
+            format,
+            0,
+            null(),
+        )
+    };
+    connection.check_cookie(cookie)?;
+    let _picture_free = defer(|| unsafe {
+        sys::xcb_render_free_picture(connection.handle, picture);
+    });
+
+    let cursor = unsafe { sys::xcb_generate_id(connection.handle) };
+    let cookie = unsafe {
+        sys::xcb_render_create_cursor_checked(
+            connection.handle,
+            cursor,
+            picture,
+            hotspot_x as u16,
+            hotspot_y as u16,
+        )
+    };
+    connection.check_cookie(cookie)?;
+    Ok(cursor)
+}
+
+/// Creates a Cursor whose mask is empty, so that nothing is drawn.
+fn create_blank(connection: &XcbConnection) -> XcbGenericResult<sys::xcb_cursor_t> {
+    // A single 1-bit pixel, padded to 32 bits.
+    let pixmap = create_pixmap(connection, 1, 1, 1, &[0; 4])?;
+    let _pixmap_free = defer(|| unsafe {
+        sys::xcb_free_pixmap(connection.handle, pixmap);
+    });
+
+    let cursor = unsafe { sys::xcb_generate_id(connection.handle) };
+    let cookie = unsafe {
+        sys::xcb_create_cursor_checked(
+            connection.handle,
+            cursor,
+            pixmap,
+            pixmap,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+        )
+    };
+    connection.check_cookie(cookie)?;
+    Ok(cursor)
+}
+
+/// Creates a Pixmap filled with Z-Format image data.\
+/// (The contents of a new Pixmap are undefined, so it must always be filled.)
+fn create_pixmap(
+    connection: &XcbConnection,
+    depth: u8,
+    width: u16,
+    height: u16,
+    data: &[u8],
+) -> XcbGenericResult<sys::xcb_pixmap_t> {
+    let root = connection.screen().root;
+
+    let pixmap = unsafe { sys::xcb_generate_id(connection.handle) };
+    let cookie = unsafe {
+        sys::xcb_create_pixmap_checked(connection.handle, depth, pixmap, root, width, height)
+    };
+    connection.check_cookie(cookie)?;
+
+    let gc = unsafe { sys::xcb_generate_id(connection.handle) };
+    let cookie = unsafe { sys::xcb_create_gc_checked(connection.handle, gc, pixmap, 0, null()) };
+    let res = connection.check_cookie(cookie).and_then(|_| {
+        let cookie = unsafe {
+            sys::xcb_put_image_checked(
+                connection.handle,
+                sys::XCB_IMAGE_FORMAT_Z_PIXMAP as u8,
+                pixmap,
+                gc,
+                width,
+                height,
+                0,
+                0,
+                0,
+                depth,
+                data.len() as u32,
+                data.as_ptr(),
+            )
+        };
+        let res = connection.check_cookie(cookie);
+        unsafe { sys::xcb_free_gc(connection.handle, gc) };
+        res
+    });
+
+    match res {
+        Ok(()) => Ok(pixmap),
+        Err(err) => {
+            unsafe { sys::xcb_free_pixmap(connection.handle, pixmap) };
+            Err(err)
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Actively grabs the Pointer, confining it to the Window.\
+/// Returns `false` if the Pointer could not be grabbed (e.g. because the Window is not viewable, or another client grabbed it).
+fn grab(connection: &XcbConnection, window: WindowHandle) -> XcbGenericResult<bool> {
+    // Events keep being reported to the Windows they occur in, as if the Pointer was not grabbed.
+    let event_mask = sys::XCB_EVENT_MASK_BUTTON_PRESS
+        | sys::XCB_EVENT_MASK_BUTTON_RELEASE
+        | sys::XCB_EVENT_MASK_POINTER_MOTION;
+
+    let mut error = null_mut();
+    let cookie = unsafe {
+        sys::xcb_grab_pointer(
+            connection.handle,
+            true as u8,
+            window,
+            event_mask as u16,
+            sys::XCB_GRAB_MODE_ASYNC as u8,
+            sys::XCB_GRAB_MODE_ASYNC as u8,
+            window,
+            sys::XCB_NONE,
+            sys::XCB_CURRENT_TIME,
+        )
+    };
+    let reply =
+        unsafe { sys::xcb_grab_pointer_reply(connection.handle, cookie, addr_of_mut!(error)) };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+
+    match XcbGenericError::new(error) {
+        Some(err) => Err(err),
+        None => Ok(unsafe { reply.as_ref() }.map_or(false, |rep| {
+            rep.status == sys::XCB_GRAB_STATUS_SUCCESS as u8
+        })),
+    }
+}
+
+/// Releases the Pointer, if Wyn grabbed it.
+fn ungrab(connection: &XcbConnection) {
+    unsafe { sys::xcb_ungrab_pointer(connection.handle, sys::XCB_CURRENT_TIME) };
+    connection.flush();
+}
+
+// ================================================================================================================================ //
+
+/// Returns the Major Opcode of an extension, if the X Server supports it.
+fn query_extension(connection: &XcbConnection, name: &str) -> Option<u8> {
+    let cookie = unsafe {
+        sys::xcb_query_extension(connection.handle, name.len() as u16, name.as_ptr().cast())
+    };
+    let reply = unsafe { sys::xcb_query_extension_reply(connection.handle, cookie, null_mut()) };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+
+    let rep = unsafe { reply.as_ref() }?;
+    (rep.present != 0).then_some(rep.major_opcode)
+}
+
+/// Finds the Picture Format of 32-bit ARGB images.\
+/// Returns `None` if the X Server does not support the Render extension (0.5 or later, for Cursors).
+fn query_argb_format(connection: &XcbConnection) -> Option<sys::xcb_render_pictformat_t> {
+    query_extension(connection, "RENDER")?;
+
+    // The version must be negotiated before any other Render request.
+    let cookie = unsafe { sys::xcb_render_query_version(connection.handle, 0, 11) };
+    let reply =
+        unsafe { sys::xcb_render_query_version_reply(connection.handle, cookie, null_mut()) };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+    let version = unsafe { reply.as_ref() }.map(|rep| (rep.major_version, rep.minor_version))?;
+    if version < (0, 5) {
+        return None;
+    }
+
+    let cookie = unsafe { sys::xcb_render_query_pict_formats(connection.handle) };
+    let reply =
+        unsafe { sys::xcb_render_query_pict_formats_reply(connection.handle, cookie, null_mut()) };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+    if reply.is_null() {
+        return None;
+    }
+
+    let mut iter = unsafe { sys::xcb_render_query_pict_formats_formats_iterator(reply) };
+    while iter.rem > 0 {
+        let info = unsafe { &*iter.data };
+        let direct = &info.direct;
+
+        let is_direct = info.type_ == sys::XCB_RENDER_PICT_TYPE_DIRECT as u8 && info.depth == 32;
+        let shifts = [
+            direct.alpha_shift,
+            direct.red_shift,
+            direct.green_shift,
+            direct.blue_shift,
+        ];
+        let masks = [
+            direct.alpha_mask,
+            direct.red_mask,
+            direct.green_mask,
+            direct.blue_mask,
+        ];
+        if is_direct && shifts == [24, 16, 8, 0] && masks == [0xFF; 4] {
+            return Some(info.id);
+        }
+
+        unsafe { sys::xcb_render_pictforminfo_next(addr_of_mut!(iter)) };
+    }
+    None
+}
+
+/// The `XIEventMask` for a single 32-bit mask.
+#[repr(C)]
+struct XiEventMask {
+    /// The Device and the length of the mask (in 32-bit units).
+    head: sys::xcb_input_event_mask_t,
+
+    /// The mask of selected XInput2 events.
+    mask: u32,
+}
+
+/// Selects Raw Motion from every Master Pointer on the Root Window, where raw events are always delivered.\
+/// Returns the Major Opcode and minor version of the XInput extension, or `None` if the X Server does not support XInput2.
+fn select_raw_motion(connection: &XcbConnection) -> Option<(u8, u16)> {
+    let opcode = query_extension(connection, "XInputExtension")?;
+
+    // The version must be negotiated (only once) before any other XInput2 request.
+    // XInput 2.1 adds the Smooth Scrolling of `XcbScrolling`, and 2.2 adds the Touch events of `XcbTouch`.
+    // The X Server replies with the lower of both versions.
+    let cookie = unsafe { sys::xcb_input_xi_query_version(connection.handle, 2, 2) };
+    let reply =
+        unsafe { sys::xcb_input_xi_query_version_reply(connection.handle, cookie, null_mut()) };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+    let version = unsafe { reply.as_ref() }.map(|rep| (rep.major_version, rep.minor_version))?;
+    if version.0 < 2 {
+        return None;
+    }
+
+    let root = connection.screen().root;
+    let deviceid = sys::XCB_INPUT_DEVICE_ALL_MASTER as u16;
+    let mask = sys::XCB_INPUT_XI_EVENT_MASK_RAW_MOTION as u32;
+    select_xi_events(connection, root, deviceid, mask).ok()?;
+
+    Some((opcode, version.1))
+}
+
+/// Selects XInput2 events from a Device (or every Device, or every Master Device) on a Window.\
+/// Replaces the previous selection for the same Device, while selections for other Devices are kept.
+pub(crate) fn select_xi_events(
+    connection: &XcbConnection,
+    window: sys::xcb_window_t,
+    deviceid: u16,
+    mask: u32,
+) -> XcbGenericResult<()> {
+    let mask = XiEventMask {
+        head: sys::xcb_input_event_mask_t {
+            deviceid,
+            mask_len: 1,
+        },
+        mask,
+    };
+
+    let cookie = unsafe {
+        sys::xcb_input_xi_select_events_checked(connection.handle, window, 1, addr_of!(mask).cast())
+    };
+    connection.check_cookie(cookie)
+}
+
+/// Converts a 32.32 Fixed-Point value.
+pub(crate) fn fp3232_to_f64(value: &sys::xcb_input_fp3232_t) -> f64 {
+    f64::from(value.integral) + f64::from(value.frac) / 4294967296.0
+}
+
+// ================================================================================================================================ //
@@ -80,6 +80,9 @@ pub struct XcbGenericError(NonzeroXcbGenericError);
 /// A result for XCB Connections.
 pub type XcbGenericResult<T> = Result<T, XcbGenericError>;
 
+// SAFETY: The error is owned by this wrapper, and is never mutated, so it can be moved to (and read from) any thread.
+unsafe impl Send for XcbGenericError {}
+
 impl XcbGenericError {
     /// Attempts to construct a new `XcbGenericError`.
     pub(crate) fn new(code: NativeXcbGenericError) -> Option<Self> {
@@ -115,4 +118,50 @@ impl Display for XcbGenericError {
     }
 }
 
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Either kind of XCB error, for operations that open a Connection and then make requests on it.
+pub enum XcbError {
+    /// The XCB Connection could not be opened, or it was shut down.
+    Connection(XcbConnectionError),
+
+    /// A request on the XCB Connection failed.
+    Generic(XcbGenericError),
+}
+
+/// A result for operations that can fail with either kind of XCB error.
+pub type XcbResult<T> = Result<T, XcbError>;
+
+impl From<XcbConnectionError> for XcbError {
+    fn from(err: XcbConnectionError) -> Self {
+        Self::Connection(err)
+    }
+}
+
+impl From<XcbGenericError> for XcbError {
+    fn from(err: XcbGenericError) -> Self {
+        Self::Generic(err)
+    }
+}
+
+impl Error for XcbError {}
+
+impl Debug for XcbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Connection(err) => Debug::fmt(err, f),
+            Self::Generic(err) => Debug::fmt(err, f),
+        }
+    }
+}
+
+impl Display for XcbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Connection(err) => Display::fmt(err, f),
+            Self::Generic(err) => Display::fmt(err, f),
+        }
+    }
+}
+
 // ================================================================================================================================ //
@@ -7,19 +7,31 @@
 
 // ================================================================================================================================ //
 
-use crate::inputs::KeyCode;
-
 #[allow(unused_imports)]
 use super::*;
 
+use super::clipboard::XcbClipboard;
+use super::cursor::XcbCursors;
 use super::errors::*;
-use super::events::EventHandler;
+use super::events::{Capability, EventHandler, GamepadUpdate};
+use super::file_drop::XdndReceiver;
+use super::ime::XcbIme;
 use super::inputs::*;
+use super::screen::{XcbScaleFactors, XcbScreenChanges, XcbVideoModes};
+use super::scroll::XcbScrolling;
+use super::touch::XcbTouch;
 use super::types::*;
+use super::window::{Window, WindowHandle};
+use crate::linux::epoll::{EpollResult, Epoller};
+use crate::linux::evdev::GamepadMonitor;
+use crate::tasks::{ExecFuture, Task};
 
 use std::cell::UnsafeCell;
-use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
-use std::sync::{Mutex, MutexGuard, TryLockError};
+use std::collections::HashSet;
+use std::os::fd::RawFd;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard, TryLockError};
+use std::thread::ThreadId;
 
 // ================================================================================================================================ //
 
@@ -31,9 +43,63 @@ pub struct EventLoop<'a> {
     /// The Event Handler that responds to events.
     pub(crate) handler: &'a dyn EventHandler,
 
+    /// A list of callback functions awaiting to be executed.
+    pub(crate) tasks: Mutex<Vec<Task>>,
+
+    /// Flag to indicate whether the Event Loop is running or not.
+    running: AtomicBool,
+
+    /// Condition Variable for other threads to await Event Loop startup/termination.
+    condvar: Condvar,
+
+    /// The Thread-ID for the Event Thread.
+    thread_id: ThreadId,
+
     /// List of available Atoms.
     pub(crate) atoms: XcbAtomList,
 
+    /// The current Keyboard Mapping.
+    pub(crate) keymap: Mutex<KeyboardMapping>,
+
+    /// The Hardware Keycodes that are held down, to tell Auto-Repeat presses apart from real ones.
+    pub(crate) held_keys: Mutex<HashSet<sys::xcb_keycode_t>>,
+
+    /// The XKB Keyboard State, used for Character Input (if the X Server supports XKB).
+    pub(crate) xkb: Mutex<Option<XkbInput>>,
+
+    /// The Windows that requested Focus before they were mapped, and are focused once they are.
+    pub(crate) pending_focus: Mutex<HashSet<WindowHandle>>,
+
+    /// The connected Gamepads.
+    pub(crate) gamepads: Mutex<GamepadMonitor>,
+
+    /// The Clipboards (Selections), which have their own connection.
+    pub(crate) clipboard: XcbClipboard,
+
+    /// The state of files being dragged onto Windows.
+    pub(crate) file_drop: XdndReceiver,
+
+    /// The Cursor state of Windows, and the source of Raw Mouse Motion.
+    pub(crate) cursors: XcbCursors,
+
+    /// The Scroll Valuators of the Pointers, which back Smooth Scrolling.
+    pub(crate) scrolling: XcbScrolling,
+
+    /// The source of Touch events from Touchscreens.
+    pub(crate) touch: XcbTouch,
+
+    /// The Scale Factors of Windows, derived from the `Xft.dpi` resource or the physical size of Monitors.
+    pub(crate) scale_factors: XcbScaleFactors,
+
+    /// The Video Modes that were switched by Exclusive Fullscreen Windows.
+    pub(crate) video_modes: XcbVideoModes,
+
+    /// The RandR changes to the Monitors.
+    pub(crate) screen_changes: XcbScreenChanges,
+
+    /// The Input Method state.
+    pub(crate) ime: XcbIme,
+
     /// Allows signalling from other threads while waiting on events.
     pub(crate) epoller: Epoller,
 
@@ -59,7 +125,7 @@ static EVENTLOOP_MUTEX: Mutex<()> = Mutex::new(());
 /// ## SAFETY
 /// Should only ever be accessed by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.\
 /// Treat as Mutable in `EventLoopGuard` code, and Immutable elsewhere.
-static mut EVENTLOOP: Option<&'static EventLoop<'static>> = None;
+pub(crate) static mut EVENTLOOP: Option<&'static EventLoop<'static>> = None;
 
 /// Drop-Guard for an `EventLoop`.\
 /// Initializes the static `EventLoop` on creation, and Removes it when dropped.
@@ -73,9 +139,9 @@ impl<'a> EventLoopGuard<'a> {
     /// ## PANICS
     /// * Panics if the Calling Thread is not the Event Thread.
     fn new(events: &'a EventLoop<'a>) -> Self {
-        // if !events.is_this_thread() {
-        //     panic!("Attempted to run Event Loop while not on the Event Thread!");
-        // }
+        if !events.is_this_thread() {
+            panic!("Attempted to run Event Loop while not on the Event Thread!");
+        }
 
         // `transmute` in order to cast to `'static` lifetime.
         // SAFETY: This reference is tied to the lifetime of this object, and is removed when this object is dropped.
@@ -90,6 +156,8 @@ impl<'a> EventLoopGuard<'a> {
 
 impl<'a> Drop for EventLoopGuard<'a> {
     fn drop(&mut self) {
+        self.events.set_running(false);
+
         // SAFETY: We are on the Event Thread and have Exclusive-Access (via the EventLoop's lock).
         let _ = unsafe { EVENTLOOP.take() };
     }
@@ -103,18 +171,28 @@ impl<'a> Drop for EventLoopGuard<'a> {
 /// Should only ever be accessed by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.
 static mut PANIC: Option<BoxedPanic> = None;
 
+/// Flag to indicate a Panic is occuring to other threads.
+static PANIC_FLAG: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether or not there was a Panic stored.
+fn is_panicking() -> bool {
+    PANIC_FLAG.load(Ordering::Acquire)
+}
+
 /// Stores a `panic` to carry across FFI-boundaries.\
 /// If a second `panic` is attempted to be stored while a previous one is stored, the process will abort.
 /// ## SAFETY
 /// Should only ever be called by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.
-#[allow(unused)]
 unsafe fn store_panic(err: BoxedPanic) {
     match &mut PANIC {
         // There was not a previous `panic`, so store this one and tell the Event Loop to shut down.
         None => {
             let _ = PANIC.insert(err);
+            PANIC_FLAG.store(true, Ordering::Release);
+
             if let Some(events) = EVENTLOOP {
                 events.request_stop();
+                events.condvar.notify_all();
             }
         }
         // There was already a previous `panic`, so abort the process.
@@ -125,7 +203,6 @@ unsafe fn store_panic(err: BoxedPanic) {
 /// Resumes a `panic` that was caught earlier, if there is one.
 /// ## SAFETY
 /// Should only ever be called by the Event Thread, and only while the EVENTLOOP_MUTEX is acquired by the current thread.
-#[allow(unused)]
 unsafe fn resume_if_panicking() {
     if let Some(err) = PANIC.take() {
         std::panic::resume_unwind(err);
@@ -136,11 +213,12 @@ unsafe fn resume_if_panicking() {
 
 impl<'a> EventLoop<'a> {
     /// Constructs a new `EventLoop`, using the provided `EventHandler`.\
+    /// Returns an error if the X Server cannot be connected to (e.g. `DISPLAY` is not set).
     /// ## PANICS
     /// * Panics if another `EventLoop` object exists and holds the Event Thread lock.
     /// * Panics if a previous `EventLoop` panicked while holding the Event Thread lock.
     /// * MacOS: Panics if the Calling Thread is not the Main Thread.
-    pub fn new(handler: &'a dyn EventHandler) -> Self {
+    pub fn new(handler: &'a dyn EventHandler) -> XcbResult<Self> {
         let lock = match EVENTLOOP_MUTEX.try_lock() {
             Ok(lock) => lock,
             Err(TryLockError::WouldBlock) => {
@@ -151,22 +229,63 @@ impl<'a> EventLoop<'a> {
             }
         };
 
-        let connection = XcbConnection::new();
+        let tasks = Mutex::new(Vec::new());
+        let running = AtomicBool::new(false);
+        let condvar = Condvar::new();
+        let thread_id = std::thread::current().id();
+
+        let connection = XcbConnection::new()?;
         let epoller = Epoller::new(connection.fd());
-        let atoms = XcbAtomList::new(&connection).unwrap();
+        let atoms = XcbAtomList::new(&connection)?;
+        let keymap = Mutex::new(KeyboardMapping::new(&connection)?);
+        let held_keys = Mutex::new(HashSet::new());
+        let xkb = Mutex::new(XkbInput::new(&connection));
+        let pending_focus = Mutex::new(HashSet::new());
+        let gamepads = Mutex::new(GamepadMonitor::new(&epoller));
+        let clipboard = XcbClipboard::new(&epoller)?;
+        let file_drop = XdndReceiver::new(&connection);
+        let cursors = XcbCursors::new(&connection);
+        let scrolling = XcbScrolling::new(&connection, cursors.xinput());
+        let touch = XcbTouch::new(cursors.xinput());
+        let scale_factors = XcbScaleFactors::new(&connection);
+        let video_modes = XcbVideoModes::default();
+        let screen_changes = XcbScreenChanges::new(&connection);
+        let ime = XcbIme::new(&epoller);
 
-        Self {
+        Ok(Self {
             handler,
+            tasks,
+            running,
+            condvar,
+            thread_id,
             atoms,
+            keymap,
+            held_keys,
+            xkb,
+            pending_focus,
+            gamepads,
+            clipboard,
+            file_drop,
+            cursors,
+            scrolling,
+            touch,
+            scale_factors,
+            video_modes,
+            screen_changes,
+            ime,
             epoller,
             connection,
             lock,
-        }
+        })
     }
 }
 
 impl Drop for EventLoop<'_> {
-    fn drop(&mut self) {}
+    fn drop(&mut self) {
+        // The X Server keeps switched Video Modes after the connection closes.
+        self.video_modes.restore_all(&self.connection);
+        self.connection.flush();
+    }
 }
 
 // ================================================================================================================================ //
@@ -181,9 +300,17 @@ impl EventLoop<'_> {
         let guard = EventLoopGuard::new(self);
 
         // SAFETY: This function is called while holding an `EventLoopGuard`.
-        unsafe { guard.events.internal_run() };
+        let func = || unsafe { guard.events.internal_run() };
 
-        // SAFETY: This function is called while holding an `EventLoopGuard`.
+        // Catch the `panic` so that other threads waiting on the Event Loop are woken up before it resumes.
+        if let Err(err) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(func)) {
+            // SAFETY: This function is called while holding an `EventLoopGuard`.
+            unsafe { store_panic(err) };
+        }
+
+        drop(guard);
+
+        // SAFETY: This function is called on the Event Thread, which still holds the EVENTLOOP_MUTEX.
         unsafe { resume_if_panicking() };
     }
 
@@ -193,8 +320,37 @@ impl EventLoop<'_> {
     /// * Must be called while holding an `EventLoopGuard` to ensure the validity of the static EVENTLOOP.
     unsafe fn internal_run(&self) {
         self.handler.start(self);
+        self.set_running(true);
+
+        let on_exec = || self.clear_tasks();
+        let on_other = |fd| {
+            if self.clipboard.is_fd(fd) {
+                self.clipboard.process();
+                return;
+            }
+
+            if self.ime.is_fd(fd) {
+                self.ime.process(self);
+                return;
+            }
+
+            // The lock must be released before dispatching, since the Event Handler may collect the Gamepads.
+            let updates = self.gamepads.lock().unwrap().process(&self.epoller, fd);
+            match updates {
+                Some(updates) => updates.into_iter().for_each(|update| update.dispatch(self)),
+                None => eprintln!("[EPOLL UNKNOWN FD {fd}]"),
+            }
+        };
+
+        // Gamepads that were connected before the Event Loop started are reported first.
+        for gamepad in Gamepad::collect(self) {
+            GamepadUpdate::Connected(gamepad).dispatch(self);
+        }
 
-        while let Some(event) = self.connection.next_xcb_event(&self.epoller) {
+        while let Some(event) = self
+            .connection
+            .next_xcb_event(&self.epoller, on_exec, on_other)
+        {
             let ev_type = event.variant();
             let ev_flag = event.flag();
             let ev_char = if ev_flag { '*' } else { ' ' };
@@ -202,15 +358,48 @@ impl EventLoop<'_> {
             let ev_name = log::xcb_generic_event_name(event.as_ref());
             eprintln!("-- [EVENT] [{ev_char}{ev_type:2}] \"{ev_name}\" : {event:?}");
 
+            // XKB events share a single (dynamically assigned) Event Code, so they are dispatched separately.
+            if let Some(xkb) = self.xkb.lock().unwrap().as_mut() {
+                if event.variant() == xkb.base_event {
+                    xkb.handle_event(&self.connection, event.as_ref());
+                    continue;
+                }
+            }
+
+            // RandR events are also dynamically assigned, and a single change sends several of them.
+            if self.screen_changes.is_change(ev_type) {
+                if self.screen_changes.update(self) {
+                    self.handler.screens_changed(self);
+
+                    // Windows may now be on Monitors with different Scale Factors.
+                    let changed = self.scale_factors.update_all(&self.connection);
+                    for (window, scale_factor) in changed {
+                        self.handler
+                            .scale_factor_changed(self, window, scale_factor);
+                    }
+                }
+                continue;
+            }
+
             match event.enumerate() {
                 XcbEventRef::ClientMessage(evt) => {
                     let p1 = (event.as_ref() as *const _) as usize;
                     let p2 = (evt as *const _ as *const _) as usize;
                     assert_eq!(p1, p2);
 
+                    if self.file_drop.handle_message(self, evt) {
+                        continue;
+                    }
+
                     if evt.data.data32[0] == self.atoms.wm_delete_window.get() {
                         // Closes window, just in case the User callback `panic`s.
                         let closer = defer(|| {
+                            // The User callback may have closed the Window itself.
+                            let window = Window::try_from(evt.window);
+                            if !window.is_ok_and(|window| window.is_open(self)) {
+                                return;
+                            }
+
                             let cookie = unsafe {
                                 sys::xcb_destroy_window_checked(self.connection.handle, evt.window)
                             };
@@ -225,13 +414,56 @@ impl EventLoop<'_> {
                         drop(closer);
                     }
                 }
+                XcbEventRef::Expose(evt) => {
+                    // Only the last Expose event in a series is reported, as the whole Window is redrawn anyway.
+                    if evt.count == 0 {
+                        self.handler.window_redraw(self, evt.window);
+                    }
+                }
                 XcbEventRef::ConfigureNotify(evt) => {
                     self.handler.window_reposition(self, evt.window);
+                    self.ime.reposition(self, evt.window);
+
+                    // Moving a Window onto another Monitor may change its Scale Factor.
+                    let changed = self.scale_factors.update(&self.connection, evt.window);
+                    if let Some(scale_factor) = changed {
+                        self.handler
+                            .scale_factor_changed(self, evt.window, scale_factor);
+                    }
+                }
+                XcbEventRef::MapNotify(evt) => {
+                    Window::internal_focus_pending(self, evt.window);
+                    self.handler.window_visibility(self, evt.window, true);
+                }
+                XcbEventRef::UnmapNotify(evt) => {
+                    self.handler.window_visibility(self, evt.window, false);
+                }
+                XcbEventRef::DestroyNotify(evt) => {
+                    self.pending_focus.lock().unwrap().remove(&evt.window);
+                    self.cursors.remove(&self.connection, evt.window);
+                    self.scale_factors.remove(evt.window);
+                    self.video_modes.restore(&self.connection, evt.window);
+                    self.ime.remove(self, evt.window);
+                }
+                XcbEventRef::PropertyNotify(evt) => {
+                    // Changing the `Xft.dpi` resource changes the Scale Factor of every Window.
+                    let changed = self.scale_factors.handle_property(&self.connection, evt);
+                    for (window, scale_factor) in changed {
+                        self.handler
+                            .scale_factor_changed(self, window, scale_factor);
+                    }
                 }
                 XcbEventRef::FocusIn(evt) => {
+                    self.cursors.focus(&self.connection, evt.event, true);
+                    self.ime.focus(self, evt.event, true);
                     self.handler.window_focus(self, evt.event, true);
                 }
                 XcbEventRef::FocusOut(evt) => {
+                    self.cursors.focus(&self.connection, evt.event, false);
+                    self.ime.focus(self, evt.event, false);
+
+                    // Keys released while unfocused are not reported, so they would look repeated once pressed again.
+                    self.held_keys.lock().unwrap().clear();
                     self.handler.window_focus(self, evt.event, false);
                 }
                 XcbEventRef::MotionNotify(evt) => {
@@ -242,14 +474,37 @@ impl EventLoop<'_> {
                     let pt = Point::from(xcb_pt);
                     self.handler.cursor_move(self, evt.event, pt);
                 }
+                XcbEventRef::EnterNotify(evt) => {
+                    // Grabbing (and releasing) the Pointer sends crossings, even though the Cursor did not move.
+                    if evt.mode != sys::XCB_NOTIFY_MODE_GRAB as u8
+                        && evt.mode != sys::XCB_NOTIFY_MODE_UNGRAB as u8
+                    {
+                        self.scrolling.reset();
+                        self.handler.cursor_enter(self, evt.event);
+                    }
+                }
+                XcbEventRef::LeaveNotify(evt) => {
+                    if evt.mode != sys::XCB_NOTIFY_MODE_GRAB as u8
+                        && evt.mode != sys::XCB_NOTIFY_MODE_UNGRAB as u8
+                    {
+                        self.handler.cursor_leave(self, evt.event);
+                    }
+                }
                 XcbEventRef::ButtonPress(evt) => {
+                    // Smooth Scrolling already reported the Buttons 4-7 that the X Server emulates from it.
+                    // Synthetic events (from `SendEvent`) are not emulated, and a grabbed Pointer only receives core events.
+                    let is_emulated = self.scrolling.is_smooth()
+                        && evt.response_type & 0x80 == 0
+                        && !self.cursors.is_grab_active(evt.event);
+
                     match evt.detail {
+                        4..=7 if is_emulated => {}
                         4 => self.handler.scroll_wheel(self, evt.event, 0.0, 1.0),
                         5 => self.handler.scroll_wheel(self, evt.event, 0.0, -1.0),
                         6 => self.handler.scroll_wheel(self, evt.event, -1.0, 0.0),
                         7 => self.handler.scroll_wheel(self, evt.event, 1.0, 0.0),
                         _ => {
-                            let button = MouseButton(evt.detail as _);
+                            let button = MouseButton::from_native(evt.detail);
                             self.handler.button_press(self, evt.event, button, true);
                         }
                     };
@@ -258,18 +513,63 @@ impl EventLoop<'_> {
                     match evt.detail {
                         4..=7 => {}
                         _ => {
-                            let button = MouseButton(evt.detail as _);
+                            let button = MouseButton::from_native(evt.detail);
                             self.handler.button_press(self, evt.event, button, false);
                         }
                     };
                 }
                 XcbEventRef::KeyPress(evt) => {
-                    let keycode = KeyCode(evt.detail as _);
-                    self.handler.key_press(self, evt.event, keycode, true);
+                    let event = self.key_event(evt.detail, evt.state, true);
+                    self.handler.key_press(self, evt.event, event, true);
+
+                    let text = match self.xkb.lock().unwrap().as_mut() {
+                        Some(xkb) => xkb.key_text(evt.detail),
+                        None => String::new(),
+                    };
+
+                    // Key presses offered to the Input Method are input once it lets them through, and are otherwise
+                    // reported through `ime_preedit` and `ime_commit` instead.
+                    if self.filter_ime_key(evt.event, evt.detail, evt.state, true, &text) {
+                        continue;
+                    }
+
+                    for character in text.chars() {
+                        self.handler.character_input(self, evt.event, character);
+                    }
                 }
                 XcbEventRef::KeyRelease(evt) => {
-                    let keycode = KeyCode(evt.detail as _);
-                    self.handler.key_press(self, evt.event, keycode, false);
+                    let event = self.key_event(evt.detail, evt.state, false);
+                    self.handler.key_press(self, evt.event, event, false);
+
+                    self.filter_ime_key(evt.event, evt.detail, evt.state, false, "");
+                }
+                XcbEventRef::GeGeneric(evt) => {
+                    if let Some((delta_x, delta_y)) = self.cursors.raw_motion(evt) {
+                        self.handler.raw_motion(self, delta_x, delta_y);
+                    } else if let Some(motion) = self.scrolling.motion(evt) {
+                        if let Some(point) = motion.point {
+                            self.handler.cursor_move(self, motion.window, point);
+                        }
+                        if let Some((delta_x, delta_y)) = motion.scroll {
+                            self.handler
+                                .scroll_wheel(self, motion.window, delta_x, delta_y);
+                        }
+                        if let Some((point, pressure, tilt, buttons)) = motion.pen {
+                            self.handler
+                                .pen(self, motion.window, point, pressure, tilt, buttons);
+                        }
+                    } else if let Some(touch) = self.touch.touch(evt) {
+                        touch.dispatch(self);
+                    } else {
+                        self.scrolling.handle_device_changed(&self.connection, evt);
+                    }
+                }
+                XcbEventRef::MappingNotify(evt) => {
+                    // The keyboard layout changed, so the translation table must be reloaded.
+                    if evt.request == sys::XCB_MAPPING_KEYBOARD as u8 {
+                        let keymap = KeyboardMapping::new(&self.connection).unwrap();
+                        *self.keymap.lock().unwrap() = keymap;
+                    }
                 }
                 _ => {}
             }
@@ -285,157 +585,430 @@ impl EventLoop<'_> {
 impl EventLoop<'_> {
     /// Returns a boolean indicating whether or not the Calling Thread is the Event Thread.
     pub fn is_this_thread(&self) -> bool {
-        todo!()
+        self.thread_id == std::thread::current().id()
     }
 
     /// Returns a boolean indicating whether or not the Event Thread is running an Event/Message Loop.
     pub fn is_running(&self) -> bool {
-        self.connection.status().is_ok()
+        self.running.load(Ordering::Acquire) && self.connection.status().is_ok()
+    }
+
+    /// Returns whether or not the optional functionality is supported by this Event Loop.
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::WindowRedraw => true,
+            Capability::WindowVisibility => true,
+            // Character Input requires the XKB extension.
+            Capability::CharacterInput => self.xkb.lock().unwrap().is_some(),
+            // Gamepads require access to the evdev device directory.
+            Capability::Gamepads => self.gamepads.lock().unwrap().is_available(),
+            Capability::Clipboard => true,
+            Capability::PrimarySelection => true,
+            Capability::FileDrop => true,
+            Capability::FileDropHover => true,
+            Capability::CursorControl => true,
+            // Raw Motion requires the XInput2 extension.
+            Capability::RawMotion => self.cursors.has_raw_motion(),
+            Capability::ScaleFactorChanged => true,
+            // Screen changes require the RandR extension.
+            Capability::ScreensChanged => self.screen_changes.is_supported(),
+            // Input Methods require the `ibus` feature, a Session Bus, and an XKB Keymap.
+            Capability::InputMethod => {
+                self.ime.is_available() && self.xkb.lock().unwrap().is_some()
+            }
+            Capability::VideoModes => true,
+            Capability::InputState => true,
+            Capability::CursorEnterLeave => true,
+            // Touch events require XInput 2.2, and Pens are read from the Valuators of XInput 2.1.
+            Capability::Touch => self.touch.is_supported(),
+            Capability::Pen => self.scrolling.is_pen_supported(),
+        }
     }
 
     /// If an Event/Message Loop is running, then request it to terminate.
     pub fn request_stop(&self) {
         self.epoller.signal_quit();
     }
+
+    /// Sleep the current thread until the Event Loop is running or it panicked.\
+    /// Returns `false` if the Event Thread panicked.
+    pub fn await_startup(&self) -> bool {
+        /// Dummy Mutex for `CondVar` functions.
+        static MUTEX: Mutex<()> = Mutex::new(());
+
+        let guard = MUTEX.lock().unwrap();
+        let _guard = self.condvar.wait_while(guard, |_| {
+            !is_panicking() && !self.running.load(Ordering::Acquire)
+        });
+
+        !is_panicking()
+    }
+
+    /// Sleep the current thread until the Event Loop is not running or it panicked.\
+    /// Returns `false` if the Event Thread panicked.
+    pub fn await_termination(&self) -> bool {
+        /// Dummy Mutex for `CondVar` functions.
+        static MUTEX: Mutex<()> = Mutex::new(());
+
+        let guard = MUTEX.lock().unwrap();
+        let _guard = self.condvar.wait_while(guard, |_| {
+            !is_panicking() && self.running.load(Ordering::Acquire)
+        });
+
+        !is_panicking()
+    }
+
+    /// Atomically sets the Running-Flag, and wakes any threads awaiting a change.
+    fn set_running(&self, status: bool) {
+        self.running.store(status, Ordering::Release);
+        self.condvar.notify_all();
+    }
 }
 
-// ================================================================================================================================ //
-// ================================================================================================================================ //
-// ================================================================================================================================ //
-// ================================================================================================================================ //
 // ================================================================================================================================ //
 
-/// Watches multiple File Descriptors and can wait until any of them have data to read.
-pub(crate) struct Epoller {
-    /// The File Descriptor that can call `epoll` functions.
-    epoll_fd: OwnedFd,
-    /// Signals the Event Loop should Quit.
-    quit_fd: OwnedFd,
-    /// Signals the Event Loop should Execute a user-callback.
-    exec_fd: OwnedFd,
-    /// Signals the Xcb Connection may have new events.
-    conn_fd: RawFd,
-}
+/// Functionality relating to querying the current state of the Keyboard and Mouse.
+///
+/// Unlike the `EventHandler` callbacks, these ask the X Server for the state when they are called,
+/// so they stay correct when the Focus changes while Keys or Mouse-Buttons are held down.
+impl EventLoop<'_> {
+    /// Returns whether or not the Key is currently held down.
+    pub fn key_state(&self, key: KeyCode) -> bool {
+        self.pressed_keys().contains(&key)
+    }
 
-/// The result of waiting on a set of File Descriptors.
-pub(crate) enum EpollResult {
-    /// No events were written (or too many), but the wait returned.
-    Failure,
-    /// Some event was written, but was unrecognized.
-    Unknown,
-    /// The Xcb Connection has data.
-    Conn,
-    /// The `execute` command was signaled.
-    Exec,
-    /// The `quit` command was signaled.
-    Quit,
-}
+    /// Returns every Key that is currently held down.\
+    /// Held Modifier Keys are returned as both their generic and sided `Key`s (e.g. `Key::Shift` and `Key::LShift`).
+    pub fn pressed_keys(&self) -> Vec<KeyCode> {
+        let Some(keys) = self.query_keymap() else {
+            return Vec::new();
+        };
 
-impl Epoller {
-    /// Constructs a new Epoller and associated File Descriptors.
-    pub fn new(conn_fd: RawFd) -> Self {
-        let epoll_fd = unsafe { OwnedFd::from_raw_fd(sys::epoll_create1(0)) };
-        let quit_fd = unsafe { OwnedFd::from_raw_fd(sys::eventfd(0, sys::EFD_NONBLOCK)) };
-        let exec_fd = unsafe { OwnedFd::from_raw_fd(sys::eventfd(0, sys::EFD_NONBLOCK)) };
-
-        let this = Self {
-            epoll_fd,
-            quit_fd,
-            exec_fd,
-            conn_fd,
+        let keymap = self.keymap.lock().unwrap();
+        let mut pressed = Vec::new();
+        for keycode in (0..=u8::MAX).filter(|keycode| keycode_held(&keys, *keycode)) {
+            // Several Hardware Keycodes may translate into the same Key (e.g. both Shift Keys).
+            for key in keymap.keys(keycode) {
+                if !pressed.contains(&key) {
+                    pressed.push(key);
+                }
+            }
+        }
+        pressed
+    }
+
+    /// Returns every Mouse-Button that is currently held down.\
+    /// XInput2 reports every Button (including the Side-Buttons), while the Core Protocol only reports Buttons 1 to 5.\
+    /// Buttons 4 to 7 are Scroll-Wheel steps rather than held Buttons.
+    pub fn mouse_buttons(&self) -> Vec<MouseButton> {
+        let root = self.connection.screen().root;
+
+        if self.cursors.xinput().is_some() {
+            if let Some(buttons) = self.query_xi_buttons(root) {
+                return buttons
+                    .into_iter()
+                    .filter(|button| !(4..=7).contains(button))
+                    .map(MouseButton::from_native)
+                    .collect();
+            }
+        }
+
+        let Some(pointer) = self.query_pointer(root) else {
+            return Vec::new();
         };
 
-        this.register(this.conn_fd.as_raw_fd());
-        this.register(this.exec_fd.as_raw_fd());
-        this.register(this.quit_fd.as_raw_fd());
+        let masks = [
+            (sys::XCB_KEY_BUT_MASK_BUTTON_1, sys::XCB_BUTTON_INDEX_1),
+            (sys::XCB_KEY_BUT_MASK_BUTTON_2, sys::XCB_BUTTON_INDEX_2),
+            (sys::XCB_KEY_BUT_MASK_BUTTON_3, sys::XCB_BUTTON_INDEX_3),
+        ];
+        masks
+            .into_iter()
+            .filter(|(mask, _)| pointer.mask as u32 & *mask as u32 != 0)
+            .map(|(_, button)| MouseButton::from_native(button as _))
+            .collect()
+    }
 
-        this
+    /// Returns the position of the Mouse-Cursor, relative to the Inner-Content of the Window.\
+    /// The Cursor does not need to be over the Window, but `None` is returned if it is on another Screen (or the Window is gone).
+    pub fn cursor_position(&self, handle: WindowHandle) -> Option<Point> {
+        let pointer = self.query_pointer(handle)?;
+        if pointer.same_screen == 0 {
+            return None;
+        }
+
+        let xcb_pt = sys::xcb_point_t {
+            x: pointer.win_x,
+            y: pointer.win_y,
+        };
+        Some(Point::from(xcb_pt))
     }
 
-    /// Registers a File Descriptor to be available for watching.
-    fn register(&self, fd: RawFd) {
-        let mut event = sys::epoll_event {
-            events: sys::EPOLLIN as u32,
-            data: sys::epoll_data_t { fd },
+    // ---------------------------------------------------------------- //
+
+    /// Internal function that queries which Buttons of the Client Pointer are held down, through XInput2.\
+    /// Unlike the Core Protocol, the Button Mask of XInput2 covers every Button of the Pointer.
+    fn query_xi_buttons(&self, window: WindowHandle) -> Option<Vec<NativeMouseButton>> {
+        let mut error = null_mut();
+        let cookie = unsafe { sys::xcb_input_xi_get_client_pointer(self.connection.handle, 0) };
+        let reply = unsafe {
+            sys::xcb_input_xi_get_client_pointer_reply(
+                self.connection.handle,
+                cookie,
+                addr_of_mut!(error),
+            )
         };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+        if XcbGenericError::new(error).is_some() {
+            return None;
+        }
+        let device = unsafe { reply.as_ref() }?.deviceid;
 
-        let res = unsafe {
-            sys::epoll_ctl(
-                self.epoll_fd.as_raw_fd(),
-                sys::EPOLL_CTL_ADD,
-                fd,
-                event.as_libc_mut(),
+        let mut error = null_mut();
+        let cookie =
+            unsafe { sys::xcb_input_xi_query_pointer(self.connection.handle, window, device) };
+        let reply = unsafe {
+            sys::xcb_input_xi_query_pointer_reply(
+                self.connection.handle,
+                cookie,
+                addr_of_mut!(error),
             )
         };
-        assert_ne!(res, -1);
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+        if XcbGenericError::new(error).is_some() || reply.is_null() {
+            return None;
+        }
+
+        // The mask holds one bit per Button, where bit `N` is Button `N` (and bit `0` is unused).
+        let mask = unsafe {
+            let data = sys::xcb_input_xi_query_pointer_buttons(reply);
+            let len = sys::xcb_input_xi_query_pointer_buttons_length(reply);
+            core::slice::from_raw_parts(data, len as usize)
+        };
+        let buttons = (1..mask.len() * 32)
+            .filter(|bit| mask[bit / 32] & (1 << (bit % 32)) != 0)
+            .filter_map(|bit| NativeMouseButton::try_from(bit).ok())
+            .collect();
+
+        Some(buttons)
     }
-}
 
-impl Epoller {
-    /// Writes `data` into an EventFd.
-    fn write(&self, fd: RawFd, data: u64) {
-        let res = unsafe { sys::write(fd, void_of!(data), 8) };
-        assert_ne!(res, -1)
+    /// Internal function that queries which Hardware Keycodes are held down, as a bit vector.
+    fn query_keymap(&self) -> Option<[u8; 32]> {
+        let mut error = null_mut();
+        let cookie = unsafe { sys::xcb_query_keymap(self.connection.handle) };
+        let reply = unsafe {
+            sys::xcb_query_keymap_reply(self.connection.handle, cookie, addr_of_mut!(error))
+        };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+
+        if XcbGenericError::new(error).is_some() || reply.is_null() {
+            return None;
+        }
+        Some(unsafe { (*reply).keys })
     }
 
-    /// Reads `data` from an EventFd.
-    fn read(&self, fd: RawFd) -> u64 {
-        let mut data = 0u64;
-        let res = unsafe { sys::read(fd, void_of_mut!(data), 8) };
-        assert_eq!(res, 8);
-        data
+    /// Internal function that queries the state of the Pointer, relative to the Window.
+    fn query_pointer(&self, window: WindowHandle) -> Option<sys::xcb_query_pointer_reply_t> {
+        let mut error = null_mut();
+        let cookie = unsafe { sys::xcb_query_pointer(self.connection.handle, window) };
+        let reply = unsafe {
+            sys::xcb_query_pointer_reply(self.connection.handle, cookie, addr_of_mut!(error))
+        };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+
+        if XcbGenericError::new(error).is_some() || reply.is_null() {
+            return None;
+        }
+        Some(unsafe { *reply })
     }
+}
 
-    /// Signals the ExecFd.
-    #[allow(unused)]
-    pub fn signal_exec(&self) {
-        self.write(self.exec_fd.as_raw_fd(), 1)
+/// Returns whether or not the Hardware Keycode is set in the bit vector of `QueryKeymap`.
+fn keycode_held(keys: &[u8; 32], keycode: sys::xcb_keycode_t) -> bool {
+    keys[keycode as usize / 8] & (1 << (keycode % 8)) != 0
+}
+
+// ================================================================================================================================ //
+
+/// Functionality relating to executing code on the Event Thread.
+///
+/// Sometimes, there are functions will only work if executed on the Main/Event Thread.
+/// Normally, said thread is under control of the library, and user-code can only execute during `EventHandler` callbacks (which can be limiting).
+/// These functions provide a way for users to temporarily take control of the Main/Event Thread
+/// from a separate thread, allowing them to execute any code that must be run on said thread.
+impl EventLoop<'_> {
+    /// Executes the provided callback function on the Event Thread, and returns the result.
+    pub fn execute<T, F>(&self, callback: F) -> ExecFuture<T>
+    where
+        T: Send + 'static,
+        F: Send + 'static + FnOnce() -> T,
+    {
+        if self.is_this_thread() {
+            let val = callback();
+            ExecFuture::new_sync(Some(val))
+        } else {
+            let (fut_recv, fut_send) = ExecFuture::new_async(None);
+
+            let task = Box::new(move || {
+                let val = callback();
+                fut_send.notify(val);
+            });
+
+            {
+                let mut tasks = self.tasks.lock().unwrap();
+                tasks.push(task);
+            }
+
+            self.signal_tasks();
+
+            fut_recv
+        }
     }
 
-    /// Resets the ExecFd.
-    fn reset_exec(&self) {
-        let _ = self.read(self.exec_fd.as_raw_fd());
+    /// Executes the provided callback function on the Event Thread, but discards the result.\
+    /// Because the return value is discarded, this removes some limitations (such as requiring `T` to be `Send + 'static`).
+    pub fn execute_discard<T, F>(&self, callback: F) -> ExecFuture<()>
+    where
+        F: Send + 'static + FnOnce() -> T,
+    {
+        if self.is_this_thread() {
+            let _ = callback();
+            ExecFuture::new_sync(Some(()))
+        } else {
+            let (fut_recv, fut_send) = ExecFuture::new_async(None);
+
+            let task = Box::new(move || {
+                let _ = callback();
+                fut_send.notify(());
+            });
+
+            {
+                let mut tasks = self.tasks.lock().unwrap();
+                tasks.push(task);
+            }
+
+            self.signal_tasks();
+
+            fut_recv
+        }
     }
 
-    /// Signals the QuitFd.
-    pub fn signal_quit(&self) {
-        self.write(self.quit_fd.as_raw_fd(), 1)
+    /// Executes the provided callback function on the Event Thread, but discards the result.\
+    /// Because the return value is discarded, this removes some limitations (such as requiring `T` to be `Send + 'static`).\
+    /// By not synchronizing the Calling Thread with the Event Thread, a lot of synchronization overhead is removed.\
+    /// This function will queue the callback function and immediately return, even if run on the Event Thread.
+    pub fn execute_detached<T, F>(&self, callback: F)
+    where
+        F: Send + 'static + FnOnce() -> T,
+    {
+        let task = Box::new(move || {
+            let _ = callback();
+        });
+
+        {
+            let mut tasks = self.tasks.lock().unwrap();
+            tasks.push(task);
+        }
+
+        self.signal_tasks();
     }
 
-    /// Signals the QuitFd.
-    fn reset_quit(&self) {
-        let _ = self.read(self.quit_fd.as_raw_fd());
+    /// Signals to the Event Thread that there are Tasks that need executed.
+    fn signal_tasks(&self) {
+        self.epoller.signal_exec();
     }
 
-    /// Waits for one of the File Descriptors to be ready, and returns which one has data.
-    fn wait(&self) -> EpollResult {
-        let mut event: sys::epoll_event = unsafe { zeroed() };
+    /// If there are any Tasks waiting to be executed, remove them from the list and call them.
+    fn clear_tasks(&self) {
+        // Must be called from the Event Thread.
+        assert!(self.is_this_thread());
+
+        // Cannot hold the Tasks-lock while executing a task, otherwise a user-callback might cause a deadlock.
+        // As such, the lock must be re-acquired/released for each task.
+        let get_task = || {
+            let mut tasks = self.tasks.lock().unwrap();
+            tasks.pop()
+        };
 
-        let count =
-            unsafe { sys::epoll_wait(self.epoll_fd.as_raw_fd(), event.as_libc_mut(), 1, -1) };
-        assert_ne!(count, -1);
+        // Remove all tasks from the list.
+        while let Some(task) = get_task() {
+            task();
+        }
+    }
 
-        if count == 1 {
-            let fd = unsafe { event.data.fd };
+    /// Translates a key press/release into a `KeyEvent`, keeping track of which keys are held down.\
+    /// Auto-Repeat is only told apart when the X Server supports XKB, otherwise it looks like release/press pairs.
+    fn key_event(&self, keycode: sys::xcb_keycode_t, state: u16, pressed: bool) -> KeyEvent {
+        let (key, location) = {
+            let keymap = self.keymap.lock().unwrap();
+            (keymap.translate(keycode), keymap.location(keycode))
+        };
 
-            if fd == self.conn_fd.as_raw_fd() {
-                EpollResult::Conn
-            } else if fd == self.exec_fd.as_raw_fd() {
-                self.reset_exec();
-                EpollResult::Exec
-            } else if fd == self.quit_fd.as_raw_fd() {
-                self.reset_quit();
-                EpollResult::Quit
-            } else {
-                EpollResult::Unknown
-            }
+        // A key that is pressed again while it is held down is being repeated.
+        let mut held_keys = self.held_keys.lock().unwrap();
+        let repeat = if pressed {
+            !held_keys.insert(keycode)
         } else {
-            EpollResult::Failure
+            held_keys.remove(&keycode);
+            false
+        };
+
+        // The Modifier Mask is the state from before the event, so it does not include the key itself yet.
+        let mut modifiers = translate_modifiers(state);
+        modifiers.set(Modifiers::of(key), pressed);
+
+        KeyEvent {
+            scancode: keycode as ScanCode,
+            key,
+            modifiers,
+            repeat,
+            location,
         }
     }
+
+    /// Offers a key press/release in a Window to the Input Method, along with its KeySym (in the current Layout/Modifiers)
+    /// and the text it produces.\
+    /// Returns `true` if it was offered, in which case its text is input once the Input Method lets it through.
+    fn filter_ime_key(
+        &self,
+        window: WindowHandle,
+        keycode: sys::xcb_keycode_t,
+        modifiers: u16,
+        pressed: bool,
+        text: &str,
+    ) -> bool {
+        let keysym = match self.xkb.lock().unwrap().as_ref() {
+            Some(xkb) => xkb.keysym(keycode),
+            None => return false,
+        };
+
+        self.ime
+            .filter_key(self, window, keysym, keycode, modifiers, pressed, text)
+    }
 }
 
-// -------------------------------------------------------------------------------------------------------------------------------- //
+// ================================================================================================================================ //
+// ================================================================================================================================ //
+// ================================================================================================================================ //
+// ================================================================================================================================ //
+// ================================================================================================================================ //
 
 /// Wrapper for XCB Connections.
 #[allow(unused)]
@@ -454,19 +1027,23 @@ pub(crate) struct XcbConnection {
 
 impl XcbConnection {
     /// Opens a new XCB Connection.
-    fn new() -> Self {
+    pub(crate) fn new() -> XcbConnectionResult<Self> {
         let mut screen_id = 0;
         let handle = unsafe { sys::xcb_connect(null(), addr_of_mut!(screen_id)) };
-        assert!(Self::connection_status(handle).is_ok());
+        if let Err(err) = Self::connection_status(handle) {
+            // The connection object must be freed, even if it could not connect.
+            unsafe { sys::xcb_disconnect(handle) };
+            return Err(err);
+        }
 
         let data = unsafe { sys::xcb_get_setup(handle) };
         assert!(!data.is_null());
 
-        Self {
+        Ok(Self {
             handle,
             screen_id,
             data,
-        }
+        })
     }
 }
 
@@ -554,8 +1131,15 @@ impl XcbConnection {
         }
     }
 
-    /// Retrieves the next Xcb Event.
-    fn next_xcb_event(&self, epoller: &Epoller) -> Option<XcbEvent> {
+    /// Retrieves the next Xcb Event.\
+    /// The `on_exec` callback is invoked on the calling thread whenever the `Epoller` signals that Tasks are pending.\
+    /// The `on_other` callback is invoked on the calling thread whenever any other registered File Descriptor has data.
+    fn next_xcb_event(
+        &self,
+        epoller: &Epoller,
+        on_exec: impl Fn(),
+        on_other: impl Fn(RawFd),
+    ) -> Option<XcbEvent> {
         //return XcbEvent::new(unsafe { sys::xcb_wait_for_event(self.handle) });
 
         if let Some(event) = XcbEvent::new(unsafe { sys::xcb_poll_for_event(self.handle) }) {
@@ -565,14 +1149,20 @@ impl XcbConnection {
         loop {
             let res = epoller.wait();
             match res {
-                EpollResult::Failure => unimplemented!(),
-                EpollResult::Unknown => unimplemented!(),
+                EpollResult::Failure(_) => unimplemented!(),
+                EpollResult::Other(fd) => on_other(fd),
                 EpollResult::Quit => {
                     eprintln!("[EPOLL QUIT]");
                     return None;
                 }
                 EpollResult::Exec => {
-                    eprintln!("[EPOLL EXEC]");
+                    on_exec();
+
+                    // Tasks may have read events into the queue while waiting on replies, which won't signal the fd again.
+                    let event = unsafe { sys::xcb_poll_for_event(self.handle) };
+                    if let Some(event) = XcbEvent::new(event) {
+                        return Some(event);
+                    }
                 }
                 EpollResult::Conn => {
                     let event = unsafe { sys::xcb_poll_for_event(self.handle) };
@@ -615,6 +1205,12 @@ pub(crate) struct XcbAtomList {
 
     /// WM_DELETE_WINDOW Atom.
     pub wm_delete_window: NonzeroXcbAtom,
+
+    /// WM_CHANGE_STATE Atom.
+    pub wm_change_state: NonzeroXcbAtom,
+
+    /// _MOTIF_WM_HINTS Atom.
+    pub motif_wm_hints: NonzeroXcbAtom,
 }
 
 impl XcbAtomList {
@@ -653,10 +1249,22 @@ impl XcbAtomList {
             .expect("XCB ATOM QUERY FAILURE")
             .expect("XCB ATOM NULL FAILURE");
 
+        let wm_change_state = connection
+            .query_atom("WM_CHANGE_STATE", true)
+            .expect("XCB ATOM QUERY FAILURE")
+            .expect("XCB ATOM NULL FAILURE");
+
+        let motif_wm_hints = connection
+            .query_atom("_MOTIF_WM_HINTS", true)
+            .expect("XCB ATOM QUERY FAILURE")
+            .expect("XCB ATOM NULL FAILURE");
+
         Ok(Self {
             _wyn_xid,
             _ewmh,
             wm_delete_window,
+            wm_change_state,
+            motif_wm_hints,
         })
     }
 }
@@ -674,12 +1282,12 @@ type NonzeroXcbEventHandle = NonNull<NativeXcbEvent>;
 
 /// Wrapper for XCB Events.
 #[repr(transparent)]
-struct XcbEvent(NonzeroXcbEventHandle);
+pub(crate) struct XcbEvent(NonzeroXcbEventHandle);
 
 /// A reference to the underlying XCB Event.
 #[repr(u8)]
 #[allow(clippy::missing_docs_in_private_items)]
-enum XcbEventRef<'a> {
+pub(crate) enum XcbEventRef<'a> {
     Generic(&'a sys::xcb_generic_event_t) = 0u8,
     KeyPress(&'a sys::xcb_key_press_event_t) = sys::XCB_KEY_PRESS as u8,
     KeyRelease(&'a sys::xcb_key_release_event_t) = sys::XCB_KEY_RELEASE as u8,
@@ -777,7 +1385,7 @@ impl Debug for XcbEvent {
 
 impl XcbEvent {
     /// Constructs a new `XcbEvent`.
-    fn new(event: NativeXcbEventHandle) -> Option<Self> {
+    pub(crate) fn new(event: NativeXcbEventHandle) -> Option<Self> {
         NonzeroXcbEventHandle::new(event).map(Self)
     }
 
@@ -797,7 +1405,7 @@ impl XcbEvent {
     }
 
     /// Gets the actual underlying event, if known.
-    fn enumerate(&self) -> XcbEventRef {
+    pub(crate) fn enumerate(&self) -> XcbEventRef {
         let ptr = self.0.as_ptr();
 
         let event = unsafe {
@@ -0,0 +1,497 @@
+/*
+ *  Crate: Wyn
+ * Module: X11 - File Drop
+ */
+
+//! Receiving files that are dragged onto Windows, following the XDND protocol.
+//!
+//! The drag source announces itself to the Window under the cursor with `XdndEnter`, and then reports the cursor position
+//! with `XdndPosition`, until it sends `XdndLeave` or `XdndDrop`.\
+//! The dragged files are read by converting the `XdndSelection` to `text/uri-list` (on the Clipboard connection),
+//! and every `XdndPosition` and `XdndDrop` is answered, with `XdndStatus` and `XdndFinished` respectively.
+//!
+//! ## References
+//! * <https://www.freedesktop.org/wiki/Specifications/XDND/>
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::*;
+use super::event_loop::{EventLoop, NonzeroXcbAtom, XcbConnection};
+use super::types::Point;
+use super::window::WindowHandle;
+
+use std::os::unix::ffi::OsStringExt;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// ================================================================================================================================ //
+
+/// The version of the XDND protocol that Wyn implements.
+const XDND_VERSION: u32 = 5;
+
+/// The MIME type of a list of URIs, which is how files are dragged.
+const MIME_URI_LIST: &str = "text/uri-list";
+
+// ================================================================================================================================ //
+
+/// The Atoms used by the XDND protocol.
+struct XdndAtoms {
+    /// The `XdndAware` Property, which marks Windows that accept drops.
+    aware: NonzeroXcbAtom,
+
+    /// The `XdndEnter` Message.
+    enter: NonzeroXcbAtom,
+
+    /// The `XdndPosition` Message.
+    position: NonzeroXcbAtom,
+
+    /// The `XdndStatus` Message.
+    status: NonzeroXcbAtom,
+
+    /// The `XdndLeave` Message.
+    leave: NonzeroXcbAtom,
+
+    /// The `XdndDrop` Message.
+    drop: NonzeroXcbAtom,
+
+    /// The `XdndFinished` Message.
+    finished: NonzeroXcbAtom,
+
+    /// The `XdndSelection` Selection, which holds the dragged data.
+    selection: NonzeroXcbAtom,
+
+    /// The `XdndTypeList` Property, which lists the offered types when there are more than three.
+    type_list: NonzeroXcbAtom,
+
+    /// The `XdndActionCopy` Action.
+    action_copy: NonzeroXcbAtom,
+
+    /// The `text/uri-list` Target.
+    uri_list: NonzeroXcbAtom,
+}
+
+impl XdndAtoms {
+    /// Queries the Atoms.
+    fn new(connection: &XcbConnection) -> Self {
+        let query = |name| {
+            connection
+                .query_atom(name, true)
+                .expect("XCB ATOM QUERY FAILURE")
+                .expect("XCB ATOM NULL FAILURE")
+        };
+
+        Self {
+            aware: query("XdndAware"),
+            enter: query("XdndEnter"),
+            position: query("XdndPosition"),
+            status: query("XdndStatus"),
+            leave: query("XdndLeave"),
+            drop: query("XdndDrop"),
+            finished: query("XdndFinished"),
+            selection: query("XdndSelection"),
+            type_list: query("XdndTypeList"),
+            action_copy: query("XdndActionCopy"),
+            uri_list: query(MIME_URI_LIST),
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// A drag that has entered one of Wyn's Windows.
+struct XdndDrag {
+    /// The Window of the drag source.
+    source: sys::xcb_window_t,
+
+    /// The Window being dragged over.
+    window: WindowHandle,
+
+    /// Whether or not the source offers `text/uri-list`.
+    offers_files: bool,
+
+    /// The dragged files, once they have been read.
+    paths: Option<Vec<PathBuf>>,
+
+    /// The last position of the cursor, relative to the Window.
+    point: Point,
+
+    /// Whether or not `EventHandler::file_drop_hover` was reported (so that a cancellation must be reported too).
+    hovering: bool,
+}
+
+impl XdndDrag {
+    /// Whether or not the drop would be accepted.
+    fn accepted(&self) -> bool {
+        matches!(&self.paths, Some(paths) if !paths.is_empty())
+    }
+}
+
+/// The XDND state for an `EventLoop`.
+pub(crate) struct XdndReceiver {
+    /// The Atoms used by the XDND protocol.
+    atoms: XdndAtoms,
+
+    /// The drag that is in progress, if any.
+    drag: Mutex<Option<XdndDrag>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl XdndReceiver {
+    /// Queries the XDND Atoms.
+    pub(crate) fn new(connection: &XcbConnection) -> Self {
+        Self {
+            atoms: XdndAtoms::new(connection),
+            drag: Mutex::new(None),
+        }
+    }
+
+    /// Marks the Window as accepting drops.
+    pub(crate) fn set_aware(&self, events: &EventLoop, handle: WindowHandle) {
+        let version = [XDND_VERSION];
+        let cookie = unsafe {
+            sys::xcb_change_property_checked(
+                events.connection.handle,
+                sys::XCB_PROP_MODE_REPLACE as u8,
+                handle,
+                self.atoms.aware.get(),
+                sys::XCB_ATOM_ATOM,
+                32,
+                version.len() as u32,
+                void_of!(version),
+            )
+        };
+        events.connection.check_cookie(cookie).unwrap();
+    }
+
+    /// Handles a Client Message, if it is part of the XDND protocol.\
+    /// Returns `false` if the message is unrelated.
+    pub(crate) fn handle_message(
+        &self,
+        events: &EventLoop,
+        evt: &sys::xcb_client_message_event_t,
+    ) -> bool {
+        let kind = evt.type_;
+        let data = unsafe { evt.data.data32 };
+
+        if kind == self.atoms.enter.get() {
+            self.on_enter(events, evt.window, data);
+        } else if kind == self.atoms.position.get() {
+            self.on_position(events, evt.window, data);
+        } else if kind == self.atoms.leave.get() {
+            self.on_leave(events, evt.window, data);
+        } else if kind == self.atoms.drop.get() {
+            self.on_drop(events, evt.window, data);
+        } else {
+            return false;
+        }
+        true
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl XdndReceiver {
+    /// A drag entered the Window, announcing the types it offers.
+    fn on_enter(&self, events: &EventLoop, window: WindowHandle, data: [u32; 5]) {
+        let source = data[0];
+
+        // The top byte holds the version of the source, and bit 0 is set if there are more than three types.
+        let version = data[1] >> 24;
+        if version > XDND_VERSION {
+            return;
+        }
+
+        let uri_list = self.atoms.uri_list.get();
+        let offers_files = match data[1] & 1 {
+            0 => data[2..5].contains(&uri_list),
+            _ => self.type_list(events, source).contains(&uri_list),
+        };
+
+        // A previous drag that never left (e.g. because its source crashed) is replaced.
+        let prev = self.drag.lock().unwrap().replace(XdndDrag {
+            source,
+            window,
+            offers_files,
+            paths: None,
+            point: Point::new(0.0, 0.0),
+            hovering: false,
+        });
+
+        if let Some(prev) = prev {
+            if prev.hovering {
+                events.handler.file_drop_cancel(events, prev.window);
+            }
+        }
+    }
+
+    /// The cursor moved across the Window, while dragging.
+    fn on_position(&self, events: &EventLoop, window: WindowHandle, data: [u32; 5]) {
+        let source = data[0];
+        let root_x = (data[2] >> 16) as i16;
+        let root_y = (data[2] & 0xFFFF) as i16;
+        let time = data[3];
+
+        let point = self.translate(events, window, root_x, root_y);
+
+        let (paths, accepted) = {
+            let mut lock = self.drag.lock().unwrap();
+            let drag = match lock.as_mut() {
+                Some(drag) if drag.source == source && drag.window == window => drag,
+                _ => return,
+            };
+
+            // The files are only read once per drag, as converting the Selection is a round-trip to the source.
+            if drag.paths.is_none() {
+                let paths = if drag.offers_files {
+                    self.read_paths(events, time)
+                } else {
+                    Vec::new()
+                };
+                drag.paths = Some(paths);
+            }
+
+            drag.point = point;
+            drag.hovering |= drag.accepted();
+            (drag.paths.clone().unwrap_or_default(), drag.accepted())
+        };
+
+        self.send_status(events, window, source, accepted);
+
+        if accepted {
+            events.handler.file_drop_hover(events, window, paths, point);
+        }
+    }
+
+    /// The drag left the Window, or was cancelled.
+    fn on_leave(&self, events: &EventLoop, window: WindowHandle, data: [u32; 5]) {
+        let source = data[0];
+
+        let drag = self.take_drag(source, window);
+        if let Some(drag) = drag {
+            if drag.hovering {
+                events.handler.file_drop_cancel(events, window);
+            }
+        }
+    }
+
+    /// The files were dropped on the Window.
+    fn on_drop(&self, events: &EventLoop, window: WindowHandle, data: [u32; 5]) {
+        let source = data[0];
+        let time = data[2];
+
+        let mut drag = match self.take_drag(source, window) {
+            Some(drag) => drag,
+            None => return,
+        };
+
+        // The files were not read yet if the source did not send a position first.
+        if drag.paths.is_none() && drag.offers_files {
+            drag.paths = Some(self.read_paths(events, time));
+        }
+
+        let accepted = drag.accepted();
+        self.send_finished(events, window, source, accepted);
+
+        match drag.paths {
+            Some(paths) if accepted => events.handler.file_drop(events, window, paths, drag.point),
+            _ if drag.hovering => events.handler.file_drop_cancel(events, window),
+            _ => {}
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl XdndReceiver {
+    /// Ends the drag, if it is the one from the source over the Window.
+    fn take_drag(&self, source: sys::xcb_window_t, window: WindowHandle) -> Option<XdndDrag> {
+        let mut lock = self.drag.lock().unwrap();
+        let is_current =
+            matches!(lock.as_ref(), Some(drag) if drag.source == source && drag.window == window);
+        if is_current {
+            lock.take()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the types listed in the `XdndTypeList` Property of the source.
+    fn type_list(&self, events: &EventLoop, source: sys::xcb_window_t) -> Vec<sys::xcb_atom_t> {
+        let mut error = null_mut();
+        let cookie = unsafe {
+            sys::xcb_get_property(
+                events.connection.handle,
+                false as u8,
+                source,
+                self.atoms.type_list.get(),
+                sys::XCB_ATOM_ATOM,
+                0,
+                u32::MAX / 4,
+            )
+        };
+        let reply = unsafe {
+            sys::xcb_get_property_reply(events.connection.handle, cookie, addr_of_mut!(error))
+        };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+
+        if XcbGenericError::new(error).is_some() || reply.is_null() {
+            return Vec::new();
+        }
+
+        let len = unsafe { sys::xcb_get_property_value_length(reply) } as usize;
+        let dat = unsafe { sys::xcb_get_property_value(reply) } as *const sys::xcb_atom_t;
+        match len / size_of::<sys::xcb_atom_t>() {
+            0 => Vec::new(),
+            count => unsafe { std::slice::from_raw_parts(dat, count) }.to_vec(),
+        }
+    }
+
+    /// Reads the dragged files from the `XdndSelection`.\
+    /// Returns an empty list if the source does not respond, or does not drag any local files.
+    fn read_paths(&self, events: &EventLoop, time: sys::xcb_timestamp_t) -> Vec<PathBuf> {
+        let selection = self.atoms.selection.get();
+        let target = self.atoms.uri_list.get();
+
+        match events.clipboard.convert(selection, target, time) {
+            Ok(Some(data)) => parse_uri_list(&data),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Translates a point from Root-Window coordinates to Window coordinates.
+    fn translate(&self, events: &EventLoop, window: WindowHandle, x: i16, y: i16) -> Point {
+        let root = events.connection.screen().root;
+
+        let mut error = null_mut();
+        let cookie =
+            unsafe { sys::xcb_translate_coordinates(events.connection.handle, root, window, x, y) };
+        let reply = unsafe {
+            sys::xcb_translate_coordinates_reply(
+                events.connection.handle,
+                cookie,
+                addr_of_mut!(error),
+            )
+        };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+
+        match unsafe { reply.as_ref() } {
+            Some(rep) if XcbGenericError::new(error).is_none() => {
+                Point::new(rep.dst_x as f64, rep.dst_y as f64)
+            }
+            _ => Point::new(x as f64, y as f64),
+        }
+    }
+
+    /// Tells the source whether or not the drop would be accepted.
+    fn send_status(
+        &self,
+        events: &EventLoop,
+        window: WindowHandle,
+        source: sys::xcb_window_t,
+        accepted: bool,
+    ) {
+        // Bit 1 is left clear, so that the source keeps sending positions as the cursor moves.
+        let action = if accepted {
+            self.atoms.action_copy.get()
+        } else {
+            sys::XCB_NONE
+        };
+        let data = [window, accepted as u32, 0, 0, action];
+        self.send_message(events, source, self.atoms.status.get(), data);
+    }
+
+    /// Tells the source that the drop is finished.
+    fn send_finished(
+        &self,
+        events: &EventLoop,
+        window: WindowHandle,
+        source: sys::xcb_window_t,
+        accepted: bool,
+    ) {
+        let action = if accepted {
+            self.atoms.action_copy.get()
+        } else {
+            sys::XCB_NONE
+        };
+        let data = [window, accepted as u32, action, 0, 0];
+        self.send_message(events, source, self.atoms.finished.get(), data);
+    }
+
+    /// Sends a Client Message to the source.
+    fn send_message(
+        &self,
+        events: &EventLoop,
+        source: sys::xcb_window_t,
+        kind: sys::xcb_atom_t,
+        data32: [u32; 5],
+    ) {
+        let message = sys::xcb_client_message_event_t {
+            response_type: sys::XCB_CLIENT_MESSAGE as u8,
+            format: 32,
+            sequence: 0,
+            window: source,
+            type_: kind,
+            data: sys::xcb_client_message_data_t { data32 },
+        };
+
+        unsafe {
+            sys::xcb_send_event(
+                events.connection.handle,
+                false as u8,
+                source,
+                sys::XCB_EVENT_MASK_NO_EVENT,
+                bytes_of!(message),
+            )
+        };
+        events.connection.flush();
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Parses a `text/uri-list`, returning the paths of its local files.\
+/// Comments, and URIs that are not `file://` URIs, are skipped.
+fn parse_uri_list(data: &[u8]) -> Vec<PathBuf> {
+    data.split(|&byte| byte == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty() && !line.starts_with(b"#"))
+        .filter_map(|line| line.strip_prefix(b"file://"))
+        .filter_map(|rest| {
+            // The host (usually empty, or `localhost`) is followed by the absolute path.
+            let start = rest.iter().position(|&byte| byte == b'/')?;
+            let path = percent_decode(&rest[start..])?;
+            Some(PathBuf::from(OsString::from_vec(path)))
+        })
+        .collect()
+}
+
+/// Decodes the `%XX` escapes in a URI, returning `None` if they are malformed.
+fn percent_decode(uri: &[u8]) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(uri.len());
+    let mut rest = uri;
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    Some(bytes)
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,947 @@
+/*
+ *  Crate: Wyn
+ * Module: X11 - Input Method
+ */
+
+//! Composing text with an Input Method, as a client of IBus (or of Fcitx 5, which also implements the IBus protocol).
+//!
+//! The Input Context is created through the IBus Portal on the D-Bus Session Bus, which is also reachable from sandboxes.\
+//! Key presses in Windows that allow Input Methods are first offered to the Input Context, which either consumes them
+//! (announcing changes to the composed text with D-Bus signals) or lets them through.\
+//! The Event Loop does not wait on the answer: the key is queued until the reply is read from the connection, and its text
+//! is only input then (as the IBus modules of GTK and Qt do).\
+//! A single Input Context is shared between Windows, and follows the focused Window.
+//!
+//! ## References
+//! * <https://ibus.github.io/docs/ibus-1.5/IBusInputContext.html>
+//! * <https://dbus.freedesktop.org/doc/dbus-specification.html#message-protocol-signatures>
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::*;
+use super::event_loop::EventLoop;
+use super::types::*;
+use super::window::WindowHandle;
+use crate::linux::epoll::Epoller;
+
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
+use std::os::fd::RawFd;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// ================================================================================================================================ //
+
+/// The Bus Name of the IBus Portal.
+const PORTAL_NAME: &CStr = c"org.freedesktop.portal.IBus";
+
+/// The Object Path of the IBus Portal.
+const PORTAL_PATH: &CStr = c"/org/freedesktop/IBus";
+
+/// The Interface of the IBus Portal.
+const PORTAL_INTERFACE: &CStr = c"org.freedesktop.IBus.Portal";
+
+/// The Interface of Input Contexts.
+const CONTEXT_INTERFACE: &CStr = c"org.freedesktop.IBus.InputContext";
+
+/// How long to wait on the Input Method, before assuming it is not going to respond (in milliseconds).
+const CALL_TIMEOUT: c_int = 300;
+
+/// The Input Context draws no pre-edit text itself (`IBUS_CAP_PREEDIT_TEXT`), and follows the focus (`IBUS_CAP_FOCUS`).
+const CAPABILITIES: u32 = (1 << 0) | (1 << 3);
+
+/// The Modifier bit that marks a key release (`IBUS_RELEASE_MASK`).
+const RELEASE_MASK: u32 = 1 << 30;
+
+/// X11 Hardware Keycodes are offset by 8 from the Linux Keycodes that IBus expects.
+const KEYCODE_OFFSET: u32 = 8;
+
+// ================================================================================================================================ //
+
+/// A change reported by the Input Method.
+enum ImeEvent {
+    /// The composed text (and the byte range of its cursor) changed.
+    Preedit(String, Option<Range<usize>>),
+
+    /// Text was committed.
+    Commit(String),
+
+    /// A key press was let through (or forwarded back), and produced a character.
+    Character(char),
+}
+
+/// The Input Method state of a single Window.
+#[derive(Default)]
+struct ImeWindow {
+    /// Whether or not Input Methods are allowed.
+    allowed: bool,
+
+    /// The area being edited, relative to the Inner-Content.
+    position: Rect,
+}
+
+/// A key press/release that was offered to the Input Method, and awaits its answer.
+struct PendingKey {
+    /// The Window the key was pressed/released in.
+    window: WindowHandle,
+
+    /// The text the key produces if it is let through.\
+    /// This is determined when the key is pressed, since the Modifiers may have changed by the time the Input Method answers.
+    text: String,
+
+    /// When the key was offered.
+    offered: Instant,
+
+    /// The call to `ProcessKeyEvent`.
+    call: *mut sys::DBusPendingCall,
+}
+
+impl PendingKey {
+    /// Returns whether or not the Input Method consumed the key, or `None` if it has yet to answer.\
+    /// Keys that were not answered in time are let through.
+    fn answer(&self) -> Option<bool> {
+        if unsafe { sys::dbus_pending_call_get_completed(self.call) } == 0 {
+            let timeout = Duration::from_millis(CALL_TIMEOUT as u64);
+            return (self.offered.elapsed() >= timeout).then_some(false);
+        }
+
+        let reply = unsafe { sys::dbus_pending_call_steal_reply(self.call) };
+        if reply.is_null() {
+            return Some(false);
+        }
+        let reply = DBusMessageRef(reply);
+
+        // Error replies have no `b` argument.
+        let mut args = unsafe { MessageArgs::new(reply.0) };
+        Some(args.bool().unwrap_or(false))
+    }
+}
+
+impl Drop for PendingKey {
+    fn drop(&mut self) {
+        // Cancelling a call that was already answered has no effect.
+        unsafe {
+            sys::dbus_pending_call_cancel(self.call);
+            sys::dbus_pending_call_unref(self.call);
+        }
+    }
+}
+
+/// The state of the Input Context, and of the Windows that changed their Input Method state.
+#[derive(Default)]
+struct ImeState {
+    /// The Input Method state of each Window.
+    windows: HashMap<WindowHandle, ImeWindow>,
+
+    /// The focused Window.
+    focused: Option<WindowHandle>,
+
+    /// The Window that the Input Context is currently focused on (the focused Window, if it allows Input Methods).
+    target: Option<WindowHandle>,
+
+    /// The composed text, as last announced by the Input Method.
+    preedit: String,
+
+    /// The byte offset of the cursor in the composed text.
+    cursor: usize,
+
+    /// Whether or not the composed text is shown.
+    visible: bool,
+
+    /// The composed text (and cursor range), as last reported to the Event Handler.
+    reported: (String, Option<Range<usize>>),
+
+    /// The changes that are yet to be reported, with the Window they belong to.
+    pending: Vec<(WindowHandle, ImeEvent)>,
+
+    /// The keys offered to the Input Method, in the order they were pressed/released.
+    keys: VecDeque<PendingKey>,
+}
+
+impl ImeState {
+    /// Returns whether or not the Window allows Input Methods.
+    fn is_allowed(&self, window: WindowHandle) -> bool {
+        self.windows.get(&window).is_some_and(|ime| ime.allowed)
+    }
+
+    /// Reports the composed text to the target Window, if it differs from what was last reported.
+    fn update_preedit(&mut self) {
+        let current = if self.visible && !self.preedit.is_empty() {
+            let cursor = self.cursor.min(self.preedit.len());
+            (self.preedit.clone(), Some(cursor..cursor))
+        } else {
+            (String::new(), None)
+        };
+
+        if current == self.reported {
+            return;
+        }
+        self.reported = current.clone();
+
+        if let Some(window) = self.target {
+            self.pending
+                .push((window, ImeEvent::Preedit(current.0, current.1)));
+        }
+    }
+
+    /// Takes the keys that the Input Method answered (in order), and reports the text of those it let through.\
+    /// Stops at the first key that is yet to be answered, so that text is never input out of order.
+    fn complete_keys(&mut self) {
+        while let Some(handled) = self.keys.front().and_then(PendingKey::answer) {
+            let Some(key) = self.keys.pop_front() else {
+                break;
+            };
+
+            if !handled {
+                let characters = key.text.chars().map(ImeEvent::Character);
+                self.pending
+                    .extend(characters.map(|event| (key.window, event)));
+            }
+        }
+    }
+
+    /// Clears the composed text, reporting that it ended to the Window it was reported to.
+    fn clear_preedit(&mut self, window: WindowHandle) {
+        self.preedit.clear();
+        self.cursor = 0;
+        self.visible = false;
+
+        if !self.reported.0.is_empty() {
+            self.reported = (String::new(), None);
+            self.pending
+                .push((window, ImeEvent::Preedit(String::new(), None)));
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The Input Method state for an `EventLoop`.
+pub(crate) struct XcbIme {
+    /// The private connection to the Session Bus (NULL if no Input Method is available).
+    connection: *mut sys::DBusConnection,
+
+    /// The File Descriptor of the connection (`-1` if no Input Method is available).
+    fd: RawFd,
+
+    /// The Object Path of the Input Context.
+    context: CString,
+
+    /// Only one thread may talk to the Input Context at a time, so this is locked while doing so.
+    state: Mutex<ImeState>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl XcbIme {
+    /// Creates an Input Context, and registers its connection with the `Epoller`.\
+    /// Input Methods are unavailable if there is no Session Bus, or nothing implements the IBus Portal.
+    pub(crate) fn new(epoller: &Epoller) -> Self {
+        let mut this = Self {
+            connection: null_mut(),
+            fd: -1,
+            context: CString::default(),
+            state: Mutex::new(ImeState::default()),
+        };
+
+        if let Some((connection, context)) = unsafe { connect() } {
+            let mut fd = -1;
+            unsafe { sys::dbus_connection_get_unix_fd(connection, addr_of_mut!(fd)) };
+            epoller.register(fd);
+
+            this.connection = connection;
+            this.fd = fd;
+            this.context = context;
+        }
+
+        this
+    }
+
+    /// Returns whether or not an Input Method is available.
+    pub(crate) fn is_available(&self) -> bool {
+        !self.connection.is_null()
+    }
+
+    /// Returns whether or not the File Descriptor belongs to the Input Method connection.
+    pub(crate) fn is_fd(&self, fd: RawFd) -> bool {
+        self.is_available() && self.fd == fd
+    }
+
+    /// Reads the signals of the Input Context and its answers to key presses, and reports the changes to the Event Handler.
+    pub(crate) fn process(&self, events: &EventLoop) {
+        {
+            let mut state = self.state.lock().unwrap();
+            if !self.drain(&mut state) {
+                // The Input Method went away, so its File Descriptor would otherwise wake the Event Loop forever.
+                events.epoller.unregister(self.fd);
+            }
+        }
+
+        self.dispatch(events);
+    }
+
+    /// Offers a key press/release in a Window to the Input Method, along with the text it produces.\
+    /// Returns `true` if it was offered, in which case its text is only input once the Input Method lets it through.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn filter_key(
+        &self,
+        events: &EventLoop,
+        window: WindowHandle,
+        keysym: sys::xkb_keysym_t,
+        keycode: sys::xcb_keycode_t,
+        modifiers: u16,
+        pressed: bool,
+        text: &str,
+    ) -> bool {
+        let offered = {
+            let mut state = self.state.lock().unwrap();
+            if state.target != Some(window) {
+                return false;
+            }
+
+            let modifiers = if pressed {
+                modifiers as u32
+            } else {
+                modifiers as u32 | RELEASE_MASK
+            };
+            let keycode = (keycode as u32).saturating_sub(KEYCODE_OFFSET);
+
+            let Some(call) = self.call_process_key(keysym, keycode, modifiers) else {
+                return false;
+            };
+            state.keys.push_back(PendingKey {
+                window,
+                text: text.to_owned(),
+                offered: Instant::now(),
+                call,
+            });
+
+            // Sending may have read the answers to earlier keys, which would then not wake the Event Loop.
+            self.drain(&mut state);
+            true
+        };
+
+        self.dispatch(events);
+        offered
+    }
+
+    /// Moves the Input Context to (or away from) a Window that gained (or lost) focus.
+    pub(crate) fn focus(&self, events: &EventLoop, window: WindowHandle, focused: bool) {
+        {
+            let mut state = self.state.lock().unwrap();
+            if focused {
+                state.focused = Some(window);
+            } else if state.focused == Some(window) {
+                state.focused = None;
+            }
+
+            self.retarget(events, &mut state);
+        }
+
+        self.dispatch(events);
+    }
+
+    /// Moves the candidate window of the Input Method along with a Window that moved.
+    pub(crate) fn reposition(&self, events: &EventLoop, window: WindowHandle) {
+        let state = self.state.lock().unwrap();
+        if state.target == Some(window) {
+            let _ = self.update_location(events, &state, window);
+        }
+    }
+
+    /// Allows (or disallows) Input Methods in a Window.\
+    /// Nothing is sent to the Input Method if it is unchanged.
+    pub(crate) fn set_allowed(&self, events: &EventLoop, window: WindowHandle, allowed: bool) {
+        {
+            let mut state = self.state.lock().unwrap();
+            if state.is_allowed(window) == allowed {
+                return;
+            }
+
+            state.windows.entry(window).or_default().allowed = allowed;
+            self.retarget(events, &mut state);
+        }
+
+        // Changes can only be reported on the Event Thread, otherwise they are reported along with the next signal.
+        if events.is_this_thread() {
+            self.dispatch(events);
+        }
+    }
+
+    /// Sets the area being edited in a Window, relative to its Inner-Content.\
+    /// Nothing is sent to the Input Method if it is unchanged.
+    pub(crate) fn set_position(
+        &self,
+        events: &EventLoop,
+        window: WindowHandle,
+        position: Rect,
+    ) -> XcbGenericResult<()> {
+        let mut state = self.state.lock().unwrap();
+        let ime = state.windows.entry(window).or_default();
+        if ime.position == position {
+            return Ok(());
+        }
+        ime.position = position;
+
+        if state.target == Some(window) {
+            self.update_location(events, &state, window)?;
+        }
+
+        Ok(())
+    }
+
+    /// Forgets the Input Method state of a Window that was destroyed.
+    pub(crate) fn remove(&self, events: &EventLoop, window: WindowHandle) {
+        let mut state = self.state.lock().unwrap();
+        state.windows.remove(&window);
+        if state.focused == Some(window) {
+            state.focused = None;
+        }
+
+        self.retarget(events, &mut state);
+
+        // The Window can no longer receive the changes, nor the text of its keys.
+        state.pending.retain(|(handle, _)| *handle != window);
+        state.keys.retain(|key| key.window != window);
+    }
+}
+
+/// X11-exclusive functionality.
+impl XcbIme {
+    /// Focuses the Input Context on the focused Window if it allows Input Methods, or unfocuses it.
+    fn retarget(&self, events: &EventLoop, state: &mut ImeState) {
+        let target = state.focused.filter(|&window| state.is_allowed(window));
+        if state.target == target {
+            return;
+        }
+
+        if let Some(window) = state.target.take() {
+            // Anything still being composed is discarded, rather than committed to another Window.
+            self.call(c"Reset", |_| {});
+            self.call(c"FocusOut", |_| {});
+            state.clear_preedit(window);
+        }
+
+        if let Some(window) = target {
+            state.target = Some(window);
+            self.call(c"FocusIn", |_| {});
+            let _ = self.update_location(events, state, window);
+        }
+
+        self.flush();
+    }
+
+    /// Tells the Input Method where the area being edited in the Window is, in Root Window coordinates.
+    fn update_location(
+        &self,
+        events: &EventLoop,
+        state: &ImeState,
+        window: WindowHandle,
+    ) -> XcbGenericResult<()> {
+        if !self.is_available() {
+            return Ok(());
+        }
+
+        let position = state
+            .windows
+            .get(&window)
+            .map(|ime| ime.position)
+            .unwrap_or_default();
+        let rect = NativeRect::from(position);
+
+        let connection = &events.connection;
+        let root = connection.screen().root;
+
+        let mut error = null_mut();
+        let cookie = unsafe {
+            sys::xcb_translate_coordinates(connection.handle, window, root, rect.x, rect.y)
+        };
+        let trans = unsafe {
+            sys::xcb_translate_coordinates_reply(connection.handle, cookie, addr_of_mut!(error))
+        };
+        let _trans_free = defer(|| {
+            if !trans.is_null() {
+                unsafe { sys::free(trans as *mut c_void) };
+            }
+        });
+        if let Some(error) = XcbGenericError::new(error) {
+            return Err(error);
+        }
+        let Some(trans) = (unsafe { trans.as_ref() }) else {
+            return Ok(());
+        };
+
+        self.call(c"SetCursorLocation", |call| {
+            call.append_i32(trans.dst_x as i32);
+            call.append_i32(trans.dst_y as i32);
+            call.append_i32(rect.width as i32);
+            call.append_i32(rect.height as i32);
+        });
+        self.flush();
+
+        Ok(())
+    }
+
+    /// Reports the pending changes to the Event Handler.\
+    /// The lock is released before dispatching, since the Event Handler may change the Input Method state of Windows.
+    fn dispatch(&self, events: &EventLoop) {
+        let pending = std::mem::take(&mut self.state.lock().unwrap().pending);
+
+        for (window, event) in pending {
+            match event {
+                ImeEvent::Preedit(text, cursor_range) => {
+                    events
+                        .handler
+                        .ime_preedit(events, window, text, cursor_range);
+                }
+                ImeEvent::Commit(text) => {
+                    events.handler.ime_commit(events, window, text);
+                }
+                ImeEvent::Character(character) => {
+                    events.handler.character_input(events, window, character);
+                }
+            }
+        }
+    }
+
+    /// Reads (without blocking) and handles every signal and reply that is available on the connection, in order.\
+    /// Returns `false` if the connection was closed.
+    fn drain(&self, state: &mut ImeState) -> bool {
+        if !self.is_available() {
+            return true;
+        }
+
+        let connected = unsafe { sys::dbus_connection_read_write(self.connection, 0) } != 0;
+
+        loop {
+            let message = unsafe { sys::dbus_connection_borrow_message(self.connection) };
+            if message.is_null() {
+                break;
+            }
+
+            // Replies are handed to the calls awaiting them (and dropped for calls that are not awaited).
+            if unsafe { sys::dbus_message_get_reply_serial(message) } != 0 {
+                unsafe {
+                    sys::dbus_connection_return_message(self.connection, message);
+                    sys::dbus_connection_dispatch(self.connection);
+                }
+                state.complete_keys();
+                continue;
+            }
+
+            unsafe { sys::dbus_connection_steal_borrowed_message(self.connection, message) };
+            let _message_free = defer(|| unsafe { sys::dbus_message_unref(message) });
+
+            // Signals of Input Contexts that belonged to a previous connection are not for Wyn.
+            let path = unsafe { sys::dbus_message_get_path(message) };
+            if path.is_null() || unsafe { CStr::from_ptr(path) } != self.context.as_c_str() {
+                continue;
+            }
+
+            unsafe { self.handle_signal(state, message) };
+        }
+
+        // Keys that the Input Method did not answer in time are let through.
+        state.complete_keys();
+
+        connected
+    }
+
+    /// Handles a signal of the Input Context.
+    unsafe fn handle_signal(&self, state: &mut ImeState, message: *mut sys::DBusMessage) {
+        let is_signal = |name: &CStr| {
+            sys::dbus_message_is_signal(message, CONTEXT_INTERFACE.as_ptr(), name.as_ptr()) != 0
+        };
+        let mut args = MessageArgs::new(message);
+
+        if is_signal(c"CommitText") {
+            let Some(text) = args.text() else {
+                return;
+            };
+
+            if let Some(window) = state.target {
+                state.clear_preedit(window);
+                state.pending.push((window, ImeEvent::Commit(text)));
+            }
+        } else if is_signal(c"UpdatePreeditText") {
+            let (Some(text), Some(cursor), Some(visible)) = (args.text(), args.u32(), args.bool())
+            else {
+                return;
+            };
+
+            // The cursor is given in characters.
+            state.cursor = text
+                .char_indices()
+                .nth(cursor as usize)
+                .map_or(text.len(), |(index, _)| index);
+            state.preedit = text;
+            state.visible = visible;
+            state.update_preedit();
+        } else if is_signal(c"ShowPreeditText") {
+            state.visible = true;
+            state.update_preedit();
+        } else if is_signal(c"HidePreeditText") {
+            state.visible = false;
+            state.update_preedit();
+        } else if is_signal(c"ForwardKeyEvent") {
+            let (Some(keysym), Some(_keycode), Some(modifiers)) =
+                (args.u32(), args.u32(), args.u32())
+            else {
+                return;
+            };
+
+            // Forwarded key presses were not turned into text by the Input Method, so they are input as-is.
+            let utf32 = sys::xkb_keysym_to_utf32(keysym);
+            let character = char::from_u32(utf32).filter(|character| !character.is_control());
+
+            if let (Some(window), Some(character)) = (state.target, character) {
+                if modifiers & RELEASE_MASK == 0 {
+                    state.pending.push((window, ImeEvent::Character(character)));
+                }
+            }
+        }
+    }
+
+    /// Offers a key to the Input Context, without waiting on its answer.\
+    /// Returns the call that awaits the answer, or `None` if the key could not be offered.
+    fn call_process_key(
+        &self,
+        keysym: u32,
+        keycode: u32,
+        modifiers: u32,
+    ) -> Option<*mut sys::DBusPendingCall> {
+        if !self.is_available() {
+            return None;
+        }
+
+        let mut call = MethodCall::new(&self.context, c"ProcessKeyEvent")?;
+        call.append_u32(keysym);
+        call.append_u32(keycode);
+        call.append_u32(modifiers);
+
+        let pending = unsafe { call.send_with_reply(self.connection) };
+        self.flush();
+        pending
+    }
+
+    /// Calls a method of the Input Context, without waiting on its answer.
+    fn call(&self, method: &CStr, append: impl FnOnce(&mut MethodCall)) {
+        if !self.is_available() {
+            return;
+        }
+
+        if let Some(mut call) = MethodCall::new(&self.context, method) {
+            append(&mut call);
+            unsafe { sys::dbus_connection_send(self.connection, call.message, null_mut()) };
+        }
+    }
+
+    /// Sends any queued messages.
+    fn flush(&self) {
+        if self.is_available() {
+            unsafe { sys::dbus_connection_flush(self.connection) };
+        }
+    }
+}
+
+impl Drop for XcbIme {
+    fn drop(&mut self) {
+        // The Input Context is destroyed along with the connection that created it.
+        if self.is_available() {
+            unsafe {
+                sys::dbus_connection_close(self.connection);
+                sys::dbus_connection_unref(self.connection);
+            }
+        }
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Opens a private connection to the Session Bus, and creates an Input Context through the IBus Portal.\
+/// Returns the connection and the Object Path of the Input Context.
+unsafe fn connect() -> Option<(*mut sys::DBusConnection, CString)> {
+    // The connection is used from every thread that changes the Input Method state of Windows.
+    if sys::dbus_threads_init_default() == 0 {
+        return None;
+    }
+
+    let mut error = DBusErrorGuard::new();
+    let connection = sys::dbus_bus_get_private(sys::DBUS_BUS_SESSION, error.as_mut_ptr());
+    if connection.is_null() || error.is_set() {
+        return None;
+    }
+    let closer = defer(|| {
+        sys::dbus_connection_close(connection);
+        sys::dbus_connection_unref(connection);
+    });
+
+    // Losing the Session Bus would otherwise exit the whole process.
+    sys::dbus_connection_set_exit_on_disconnect(connection, 0);
+
+    let mut call = MethodCall::new_portal(c"CreateInputContext")?;
+    call.append_str(c"Wyn");
+    let reply = call.send_blocking(connection)?;
+
+    let mut args = MessageArgs::new(reply.0);
+    let context = args.basic::<*const c_char>(sys::DBUS_TYPE_OBJECT_PATH)?;
+    let context = CStr::from_ptr(context).to_owned();
+
+    let mut call = MethodCall::new(&context, c"SetCapabilities")?;
+    call.append_u32(CAPABILITIES);
+    sys::dbus_connection_send(connection, call.message, null_mut());
+
+    let rule = format!(
+        "type='signal',interface='{}',path='{}'",
+        CONTEXT_INTERFACE.to_string_lossy(),
+        context.to_string_lossy(),
+    );
+    let rule = CString::new(rule).ok()?;
+    sys::dbus_bus_add_match(connection, rule.as_ptr(), error.as_mut_ptr());
+    if error.is_set() {
+        return None;
+    }
+
+    std::mem::forget(closer);
+    Some((connection, context))
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// A D-Bus Error, which is freed when dropped.
+struct DBusErrorGuard(sys::DBusError);
+
+impl DBusErrorGuard {
+    /// Initializes an empty D-Bus Error.
+    fn new() -> Self {
+        let mut error = unsafe { zeroed() };
+        unsafe { sys::dbus_error_init(addr_of_mut!(error)) };
+        Self(error)
+    }
+
+    /// Returns a pointer to the D-Bus Error, to be filled in by `libdbus`.
+    fn as_mut_ptr(&mut self) -> *mut sys::DBusError {
+        addr_of_mut!(self.0)
+    }
+
+    /// Returns whether or not an error was set.
+    fn is_set(&self) -> bool {
+        unsafe { sys::dbus_error_is_set(addr_of!(self.0)) != 0 }
+    }
+}
+
+impl Drop for DBusErrorGuard {
+    fn drop(&mut self) {
+        unsafe { sys::dbus_error_free(addr_of_mut!(self.0)) };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// A D-Bus Message, which is unreferenced when dropped.
+struct DBusMessageRef(*mut sys::DBusMessage);
+
+impl Drop for DBusMessageRef {
+    fn drop(&mut self) {
+        unsafe { sys::dbus_message_unref(self.0) };
+    }
+}
+
+/// A D-Bus Method Call, whose arguments are appended in order.
+struct MethodCall {
+    /// The Method Call Message.
+    message: *mut sys::DBusMessage,
+
+    /// The iterator that appends arguments to the end of the Message.
+    iter: sys::DBusMessageIter,
+}
+
+impl MethodCall {
+    /// Creates a call to a method of an Input Context.
+    fn new(context: &CStr, method: &CStr) -> Option<Self> {
+        Self::with_destination(PORTAL_NAME, context, CONTEXT_INTERFACE, method)
+    }
+
+    /// Creates a call to a method of the IBus Portal.
+    fn new_portal(method: &CStr) -> Option<Self> {
+        Self::with_destination(PORTAL_NAME, PORTAL_PATH, PORTAL_INTERFACE, method)
+    }
+
+    /// Creates a call to a method of an object on the Session Bus.
+    fn with_destination(name: &CStr, path: &CStr, iface: &CStr, method: &CStr) -> Option<Self> {
+        let message = unsafe {
+            sys::dbus_message_new_method_call(
+                name.as_ptr(),
+                path.as_ptr(),
+                iface.as_ptr(),
+                method.as_ptr(),
+            )
+        };
+        if message.is_null() {
+            return None;
+        }
+
+        let mut iter = unsafe { zeroed() };
+        unsafe { sys::dbus_message_iter_init_append(message, addr_of_mut!(iter)) };
+
+        Some(Self { message, iter })
+    }
+
+    /// Appends a `u` argument.
+    fn append_u32(&mut self, value: u32) {
+        self.append(sys::DBUS_TYPE_UINT32, void_of!(value));
+    }
+
+    /// Appends an `i` argument.
+    fn append_i32(&mut self, value: i32) {
+        self.append(sys::DBUS_TYPE_INT32, void_of!(value));
+    }
+
+    /// Appends an `s` argument.
+    fn append_str(&mut self, value: &CStr) {
+        let value = value.as_ptr();
+        self.append(sys::DBUS_TYPE_STRING, void_of!(value));
+    }
+
+    /// Appends an argument of a basic type.
+    fn append(&mut self, type_: c_int, value: *const c_void) {
+        unsafe { sys::dbus_message_iter_append_basic(addr_of_mut!(self.iter), type_, value) };
+    }
+
+    /// Sends the Method Call, without waiting on its reply.\
+    /// Returns the call that awaits the reply, or `None` if it could not be sent.
+    unsafe fn send_with_reply(
+        self,
+        connection: *mut sys::DBusConnection,
+    ) -> Option<*mut sys::DBusPendingCall> {
+        let mut pending = null_mut();
+        let sent = sys::dbus_connection_send_with_reply(
+            connection,
+            self.message,
+            addr_of_mut!(pending),
+            CALL_TIMEOUT,
+        ) != 0;
+
+        // The call is not sent (without failing) if the connection was closed.
+        (sent && !pending.is_null()).then_some(pending)
+    }
+
+    /// Sends the Method Call, and waits on its reply.\
+    /// Returns `None` if the method failed, or did not answer in time.
+    unsafe fn send_blocking(self, connection: *mut sys::DBusConnection) -> Option<DBusMessageRef> {
+        let mut error = DBusErrorGuard::new();
+        let reply = sys::dbus_connection_send_with_reply_and_block(
+            connection,
+            self.message,
+            CALL_TIMEOUT,
+            error.as_mut_ptr(),
+        );
+
+        if reply.is_null() || error.is_set() {
+            None
+        } else {
+            Some(DBusMessageRef(reply))
+        }
+    }
+}
+
+impl Drop for MethodCall {
+    fn drop(&mut self) {
+        unsafe { sys::dbus_message_unref(self.message) };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Reads the arguments of a D-Bus Message in order.
+struct MessageArgs {
+    /// The iterator over the arguments.
+    iter: sys::DBusMessageIter,
+
+    /// Whether or not the Message has any (further) arguments.
+    valid: bool,
+}
+
+impl MessageArgs {
+    /// Starts reading the arguments of the Message.
+    unsafe fn new(message: *mut sys::DBusMessage) -> Self {
+        let mut iter = zeroed();
+        let valid = sys::dbus_message_iter_init(message, addr_of_mut!(iter)) != 0;
+        Self { iter, valid }
+    }
+
+    /// Reads the value the iterator points at into a container (e.g. a Variant or a Struct), and advances past it.
+    fn recurse(&mut self, type_: c_int) -> Option<Self> {
+        if !self.valid
+            || unsafe { sys::dbus_message_iter_get_arg_type(addr_of_mut!(self.iter)) } != type_
+        {
+            return None;
+        }
+
+        let mut sub = unsafe { zeroed() };
+        unsafe { sys::dbus_message_iter_recurse(addr_of_mut!(self.iter), addr_of_mut!(sub)) };
+        self.advance();
+
+        let valid = unsafe { sys::dbus_message_iter_get_arg_type(addr_of_mut!(sub)) }
+            != sys::DBUS_TYPE_INVALID;
+        Some(Self { iter: sub, valid })
+    }
+
+    /// Reads an argument of a basic type, and advances past it.
+    fn basic<T: Copy>(&mut self, type_: c_int) -> Option<T> {
+        if !self.valid
+            || unsafe { sys::dbus_message_iter_get_arg_type(addr_of_mut!(self.iter)) } != type_
+        {
+            return None;
+        }
+
+        let mut value = MaybeUninit::<T>::zeroed();
+        unsafe {
+            sys::dbus_message_iter_get_basic(
+                addr_of_mut!(self.iter),
+                value.as_mut_ptr() as *mut c_void,
+            )
+        };
+        self.advance();
+
+        Some(unsafe { value.assume_init() })
+    }
+
+    /// Reads a `u` argument.
+    fn u32(&mut self) -> Option<u32> {
+        self.basic(sys::DBUS_TYPE_UINT32)
+    }
+
+    /// Reads a `b` argument.
+    fn bool(&mut self) -> Option<bool> {
+        self.basic::<sys::dbus_bool_t>(sys::DBUS_TYPE_BOOLEAN)
+            .map(|value| value != 0)
+    }
+
+    /// Reads an `s` argument.
+    fn str(&mut self) -> Option<String> {
+        let value = self.basic::<*const c_char>(sys::DBUS_TYPE_STRING)?;
+        let value = unsafe { CStr::from_ptr(value) };
+        Some(value.to_string_lossy().into_owned())
+    }
+
+    /// Reads an `IBusText`, which is serialized as a Variant holding `(sa{sv}sv)`: its type name, attachments, text, and attributes.
+    fn text(&mut self) -> Option<String> {
+        let mut variant = self.recurse(sys::DBUS_TYPE_VARIANT)?;
+        let mut text = variant.recurse(sys::DBUS_TYPE_STRUCT)?;
+
+        let _name = text.str()?;
+        let _attachments = text.recurse(sys::DBUS_TYPE_ARRAY)?;
+        text.str()
+    }
+
+    /// Advances to the next argument.
+    fn advance(&mut self) {
+        self.valid = unsafe { sys::dbus_message_iter_next(addr_of_mut!(self.iter)) } != 0;
+    }
+}
+
+// ================================================================================================================================ //
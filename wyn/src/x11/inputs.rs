@@ -3,23 +3,314 @@
  * Module: X11 - Inputs
  */
 
-//! ...
+//! Types for handling User-Input, such as through Mice/Pointers and Keyboards.
 
 // ================================================================================================================================ //
 
 #[allow(unused_imports)]
 use super::*;
 
+use super::errors::*;
+use super::event_loop::{EventLoop, XcbConnection};
+use crate::linux::xkb::{keysym_location, translate_keysyms, XkbKeyboard};
+
+// ================================================================================================================================ //
+
+/// Native OS Representation for Mouse Buttons.
+pub type NativeMouseButton = sys::xcb_button_t;
+
+/// Native OS Representation for Key Codes.\
+/// X11 Hardware Keycodes are translated into the KeySym in the first column of the Keyboard Mapping.
+pub type NativeKeyCode = sys::xcb_keysym_t;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+pub use crate::common::inputs::*;
+
+/// Translation table between `MouseButton`s and Native Mouse Buttons.
+#[rustfmt::skip]
+pub(crate) const MOUSE_MAP: &[(MouseButton, NativeMouseButton)] = &[
+    (MouseButton::Left,   sys::XCB_BUTTON_INDEX_1 as _), // 1
+    (MouseButton::Middle, sys::XCB_BUTTON_INDEX_2 as _), // 2
+    (MouseButton::Right,  sys::XCB_BUTTON_INDEX_3 as _), // 3
+    (MouseButton::X1,     8),                            // 8 (4-7 are the Scroll-Wheel)
+    (MouseButton::X2,     9),                            // 9
+];
+
+// The translation table between `Key`s and Native Key Codes is shared with the Wayland backend.
+pub(crate) use crate::linux::xkb::KEY_MAP;
+
 // ================================================================================================================================ //
 
-/// A button on a mouse.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
-#[repr(transparent)]
-pub struct MouseButton(pub u32);
+/// The Core-Protocol Keyboard Mapping, used to translate Hardware Keycodes into `Key`s.\
+/// Must be reloaded whenever a `MappingNotify` event is received, to follow keyboard layout changes.
+pub(crate) struct KeyboardMapping {
+    /// The first Hardware Keycode in the mapping.
+    min_keycode: sys::xcb_keycode_t,
+
+    /// The number of KeySyms (columns) for each Hardware Keycode.
+    keysyms_per_keycode: usize,
+
+    /// The flattened table of KeySyms.
+    keysyms: Vec<sys::xcb_keysym_t>,
+}
+
+impl KeyboardMapping {
+    /// Queries the current Keyboard Mapping from the X Server.
+    pub(crate) fn new(connection: &XcbConnection) -> XcbGenericResult<Self> {
+        let setup = unsafe { &*connection.data };
+        let min_keycode = setup.min_keycode;
+        let count = setup.max_keycode - setup.min_keycode + 1;
+
+        let mut error = null_mut();
+        let cookie =
+            unsafe { sys::xcb_get_keyboard_mapping(connection.handle, min_keycode, count) };
+        let reply = unsafe {
+            sys::xcb_get_keyboard_mapping_reply(connection.handle, cookie, addr_of_mut!(error))
+        };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+
+        if let Some(err) = XcbGenericError::new(error) {
+            return Err(err);
+        }
+        assert!(!reply.is_null());
+
+        let keysyms_per_keycode = unsafe { (*reply).keysyms_per_keycode } as usize;
+
+        let len = unsafe { sys::xcb_get_keyboard_mapping_keysyms_length(reply) } as usize;
+        let dat = unsafe { sys::xcb_get_keyboard_mapping_keysyms(reply) };
+        assert!(!dat.is_null());
+        let keysyms = unsafe { core::slice::from_raw_parts(dat, len) }.to_vec();
+
+        Ok(Self {
+            min_keycode,
+            keysyms_per_keycode,
+            keysyms,
+        })
+    }
+
+    /// Translates a Hardware Keycode into a `Key`.
+    pub(crate) fn translate(&self, keycode: sys::xcb_keycode_t) -> Key {
+        translate_keysyms(self.keysym(keycode, 0), self.keysym(keycode, 1))
+    }
+
+    /// Returns where the key of a Hardware Keycode is on the keyboard.
+    pub(crate) fn location(&self, keycode: sys::xcb_keycode_t) -> KeyLocation {
+        keysym_location(self.keysym(keycode, 0))
+    }
+
+    /// Translates a Hardware Keycode into every `Key` it stands for.\
+    /// Modifier Keys translate into their generic `Key` (e.g. `Key::Shift`), so their sided `Key` (e.g. `Key::LShift`) is added.
+    pub(crate) fn keys(&self, keycode: sys::xcb_keycode_t) -> impl Iterator<Item = Key> {
+        let generic = self.translate(keycode);
+        let keysym = self.keysym(keycode, 0);
+        let sided = match keysym_location(keysym) {
+            KeyLocation::Left | KeyLocation::Right => Some(Key::from_native(keysym)),
+            _ => None,
+        };
+        std::iter::once(generic).chain(sided.filter(|sided| *sided != generic))
+    }
+
+    /// Finds a Hardware Keycode that translates into the `Key`, if there is one on the Keyboard Mapping.
+    pub(crate) fn keycode(&self, key: Key) -> Option<sys::xcb_keycode_t> {
+        let count = self.keysyms.len() / self.keysyms_per_keycode.max(1);
+        (0..count)
+            .map(|offset| self.min_keycode.wrapping_add(offset as _))
+            .find(|keycode| self.translate(*keycode) == key)
+    }
+
+    /// Internal function that returns the KeySym in a column of the Keyboard Mapping (`0` if there is none).
+    fn keysym(&self, keycode: sys::xcb_keycode_t, col: usize) -> sys::xcb_keysym_t {
+        let index = keycode.wrapping_sub(self.min_keycode) as usize * self.keysyms_per_keycode;
+        if col < self.keysyms_per_keycode {
+            self.keysyms.get(index + col).copied().unwrap_or(0)
+        } else {
+            0
+        }
+    }
+}
+
+/// Translates the Modifier Mask of a Core-Protocol event into `Modifiers`.\
+/// This is the state from before the event, so a Modifier Key does not include itself.
+pub(crate) fn translate_modifiers(state: u16) -> Modifiers {
+    let state = state as u32;
+    let mut modifiers = Modifiers::NONE;
+    modifiers.set(Modifiers::SHIFT, state & sys::XCB_MOD_MASK_SHIFT != 0);
+    modifiers.set(Modifiers::CAPS_LOCK, state & sys::XCB_MOD_MASK_LOCK != 0);
+    modifiers.set(Modifiers::CONTROL, state & sys::XCB_MOD_MASK_CONTROL != 0);
+    modifiers.set(Modifiers::ALT, state & sys::XCB_MOD_MASK_1 != 0);
+    modifiers.set(Modifiers::NUM_LOCK, state & sys::XCB_MOD_MASK_2 != 0);
+    modifiers.set(Modifiers::SUPER, state & sys::XCB_MOD_MASK_4 != 0);
+    modifiers
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The XKB Keyboard State, which translates key presses into text (including Compose sequences and Dead Keys).
+pub(crate) struct XkbInput {
+    /// The first Event Code of the XKB extension.
+    pub(crate) base_event: u8,
+
+    /// The Device-ID of the Core Keyboard.
+    device_id: i32,
+
+    /// The Keyboard, whose Keymap and State follow the Core Keyboard.
+    keyboard: XkbKeyboard,
+}
+
+impl XkbInput {
+    /// Sets up the XKB extension for the Core Keyboard.\
+    /// Returns `None` if the X Server does not support the XKB extension.
+    pub(crate) fn new(connection: &XcbConnection) -> Option<Self> {
+        let mut base_event = 0;
+        let res = unsafe {
+            sys::xkb_x11_setup_xkb_extension(
+                connection.handle,
+                sys::XKB_X11_MIN_MAJOR_XKB_VERSION,
+                sys::XKB_X11_MIN_MINOR_XKB_VERSION,
+                sys::XKB_X11_SETUP_XKB_EXTENSION_NO_FLAGS,
+                null_mut(),
+                null_mut(),
+                addr_of_mut!(base_event),
+                null_mut(),
+            )
+        };
+        if res == 0 {
+            return None;
+        }
+
+        let device_id = unsafe { sys::xkb_x11_get_core_keyboard_device_id(connection.handle) };
+        if device_id == -1 {
+            return None;
+        }
+
+        let keyboard = XkbKeyboard::new()?;
+
+        let mut this = Self {
+            base_event,
+            device_id,
+            keyboard,
+        };
+
+        if !this.reload_keymap(connection) {
+            return None;
+        }
+
+        // The Keymap and State must be kept up to date with the X Server, otherwise Modifiers/Layouts are ignored.
+        let events = (sys::XCB_XKB_EVENT_TYPE_NEW_KEYBOARD_NOTIFY
+            | sys::XCB_XKB_EVENT_TYPE_MAP_NOTIFY
+            | sys::XCB_XKB_EVENT_TYPE_STATE_NOTIFY) as u16;
+        let cookie = unsafe {
+            sys::xcb_xkb_select_events_checked(
+                connection.handle,
+                device_id as _,
+                events,
+                0,
+                events,
+                0,
+                0,
+                null(),
+            )
+        };
+        connection.check_cookie(cookie).ok()?;
+
+        // Auto-Repeat is reported as repeated presses, rather than as release/press pairs (which look like real presses).
+        let flag = sys::XCB_XKB_PER_CLIENT_FLAG_DETECTABLE_AUTO_REPEAT;
+        let cookie = unsafe {
+            sys::xcb_xkb_per_client_flags(connection.handle, device_id as _, flag, flag, 0, 0, 0)
+        };
+        let reply =
+            unsafe { sys::xcb_xkb_per_client_flags_reply(connection.handle, cookie, null_mut()) };
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+
+        Some(this)
+    }
+
+    /// Handles an event belonging to the XKB extension.
+    pub(crate) fn handle_event(
+        &mut self,
+        connection: &XcbConnection,
+        event: &sys::xcb_generic_event_t,
+    ) {
+        // All XKB events share the same header, where the XKB event type is stored in place of `pad0`.
+        // SAFETY: Every XCB event is (at least) 32 bytes, which covers the whole `xcb_xkb_state_notify_event_t`.
+        let evt = unsafe { &*(event as *const _ as *const sys::xcb_xkb_state_notify_event_t) };
+        if evt.deviceID as i32 != self.device_id {
+            return;
+        }
+
+        match evt.xkbType as u32 {
+            sys::XCB_XKB_NEW_KEYBOARD_NOTIFY | sys::XCB_XKB_MAP_NOTIFY => {
+                self.reload_keymap(connection);
+            }
+            sys::XCB_XKB_STATE_NOTIFY => self.keyboard.update_mask(
+                evt.baseMods as _,
+                evt.latchedMods as _,
+                evt.lockedMods as _,
+                evt.baseGroup as _,
+                evt.latchedGroup as _,
+                evt.lockedGroup as _,
+            ),
+            _ => {}
+        }
+    }
+
+    /// Feeds a key press through the Keyboard State and the Compose State, returning the text it produced (if any).
+    pub(crate) fn key_text(&mut self, keycode: sys::xcb_keycode_t) -> String {
+        self.keyboard.key_text(keycode as sys::xkb_keycode_t)
+    }
+
+    /// Returns the KeySym of a key, in the current Layout and with the current Modifiers.
+    pub(crate) fn keysym(&self, keycode: sys::xcb_keycode_t) -> sys::xkb_keysym_t {
+        self.keyboard.keysym(keycode as sys::xkb_keycode_t)
+    }
+}
+
+/// X11-exclusive functionality.
+impl XkbInput {
+    /// Internal function that (re)compiles the Keymap and State from the Core Keyboard.\
+    /// Returns `false` (keeping the previous ones) if compilation failed.
+    fn reload_keymap(&mut self, connection: &XcbConnection) -> bool {
+        let keymap = unsafe {
+            sys::xkb_x11_keymap_new_from_device(
+                self.keyboard.context(),
+                connection.handle,
+                self.device_id,
+                sys::XKB_KEYMAP_COMPILE_NO_FLAGS,
+            )
+        };
+        if keymap.is_null() {
+            return false;
+        }
+
+        let state = unsafe {
+            sys::xkb_x11_state_new_from_device(keymap, connection.handle, self.device_id)
+        };
+        if state.is_null() {
+            unsafe { sys::xkb_keymap_unref(keymap) };
+            return false;
+        }
+
+        self.keyboard.set_keymap(keymap, state);
+
+        true
+    }
+}
+
+// ================================================================================================================================ //
 
-/// A key on a keyboard.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
-#[repr(transparent)]
-pub struct KeyCode(pub u32);
+impl Gamepad {
+    /// Returns a snapshot of every connected Gamepad.\
+    /// The ID of a Gamepad is assigned when it connects, and is not reused.
+    pub fn collect(events: &EventLoop) -> Vec<Gamepad> {
+        events.gamepads.lock().unwrap().collect()
+    }
+}
 
 // ================================================================================================================================ //
@@ -29,4 +29,22 @@ pub mod types;
 
 pub mod inputs;
 
+pub mod clipboard;
+
+pub mod cursor;
+
+pub(crate) mod scroll;
+
+pub(crate) mod touch;
+
+#[cfg(feature = "ibus")]
+pub(crate) mod ime;
+#[cfg(not(feature = "ibus"))]
+#[path = "no_ime.rs"]
+pub(crate) mod ime;
+
+pub(crate) mod file_drop;
+
+pub mod testing;
+
 // ================================================================================================================================ //
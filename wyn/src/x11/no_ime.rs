@@ -0,0 +1,86 @@
+/*
+ *  Crate: Wyn
+ * Module: X11 - Input Method (Disabled)
+ */
+
+//! Stands in for the IBus client when the `ibus` feature is disabled, so that Wyn does not link against `libdbus-1`.
+//!
+//! No Input Method is ever available, so `Capability::InputMethod` is not supported,
+//! and every Key press is translated into Characters directly.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::*;
+use super::event_loop::EventLoop;
+use super::types::*;
+use super::window::WindowHandle;
+use crate::linux::epoll::Epoller;
+
+use std::os::fd::RawFd;
+
+// ================================================================================================================================ //
+
+/// The Input Method state for an `EventLoop`, which never has an Input Method.
+pub(crate) struct XcbIme;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl XcbIme {
+    /// Input Methods are unavailable without the `ibus` feature.
+    pub(crate) fn new(_epoller: &Epoller) -> Self {
+        Self
+    }
+
+    /// Returns whether or not an Input Method is available.
+    pub(crate) fn is_available(&self) -> bool {
+        false
+    }
+
+    /// Returns whether or not the File Descriptor belongs to the Input Method connection.
+    pub(crate) fn is_fd(&self, _fd: RawFd) -> bool {
+        false
+    }
+
+    /// Does nothing, since there is no Input Method connection.
+    pub(crate) fn process(&self, _events: &EventLoop) {}
+
+    /// Lets every key press/release through.
+    pub(crate) fn filter_key(
+        &self,
+        _events: &EventLoop,
+        _window: WindowHandle,
+        _keysym: sys::xkb_keysym_t,
+        _keycode: sys::xcb_keycode_t,
+        _modifiers: u16,
+        _pressed: bool,
+    ) -> bool {
+        false
+    }
+
+    /// Does nothing, since there is no Input Context to move.
+    pub(crate) fn focus(&self, _events: &EventLoop, _window: WindowHandle, _focused: bool) {}
+
+    /// Does nothing, since there is no candidate window to move.
+    pub(crate) fn reposition(&self, _events: &EventLoop, _window: WindowHandle) {}
+
+    /// Does nothing, since there is no Input Method to allow.
+    pub(crate) fn set_allowed(&self, _events: &EventLoop, _window: WindowHandle, _allowed: bool) {}
+
+    /// Does nothing, since there is no candidate window to place.
+    pub(crate) fn set_position(
+        &self,
+        _events: &EventLoop,
+        _window: WindowHandle,
+        _position: Rect,
+    ) -> XcbGenericResult<()> {
+        Ok(())
+    }
+
+    /// Does nothing, since no Window has Input Method state.
+    pub(crate) fn remove(&self, _events: &EventLoop, _window: WindowHandle) {}
+}
+
+// ================================================================================================================================ //
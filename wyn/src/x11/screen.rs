@@ -3,50 +3,1019 @@
  * Module: X11 - Screen
  */
 
-//! ...
+//! Functionality for querying the state of Monitors/Screens via the RandR extension.
+//!
+//! X11 has no notion of Scale Factors. Desktop Environments publish the user's scaling setting as the `Xft.dpi` resource
+//! (in the `RESOURCE_MANAGER` property of the Root Window), which applies to every Screen.\
+//! Without it, the Scale Factor of each Screen is estimated from the physical size that RandR reports for it.
+//!
+//! The Video Mode of a Screen is the RandR Mode of the CRTC that drives its (first) Output.\
+//! The X Server keeps switched Video Modes after clients disconnect, so they are restored when the `EventLoop` is dropped.
 
 // ================================================================================================================================ //
 
 #[allow(unused_imports)]
 use super::*;
 
-use super::event_loop::EventLoop;
-use super::types::Rect;
+use super::errors::XcbGenericError;
+use super::event_loop::{EventLoop, XcbConnection};
+use super::types::{Point, Rect, ScaleFactor, Size};
+use super::window::WindowHandle;
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+pub use crate::common::screen::*;
 
 // ================================================================================================================================ //
 
 /// Native OS Representation for Screens.
-pub type NativeScreen = *mut sys::xcb_screen_t;
+///
+/// RandR Monitors are uniquely identified by the Atom of their name (e.g. `"HDMI-1"`).
+pub type NativeScreen = sys::xcb_atom_t;
+
+/// Nonzero wrapper for Native Screens.
+type NonzeroScreen = NonZero<NativeScreen>;
+
+/// Wrapper type for Screen Handles.
+pub struct Screen(NonzeroScreen);
+
+/// Holds all information about a Screen.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct ScreenInfo {
+    /// The Bounding-Rectangle.
+    pub rect: Rect,
+
+    /// The Textual Name.
+    pub name: String,
+
+    /// The Scale Factor, which UIs on the Screen should be scaled by.
+    pub scale_factor: ScaleFactor,
+
+    /// The Refresh Rate of the current Video Mode, in Hertz (`0.0` if it is unknown).
+    pub refresh_rate: f64,
+
+    /// The Work Area, which excludes panels and taskbars.
+    pub work_area: Rect,
+
+    /// The Physical Size, in millimeters (zero if it is unknown).
+    pub size_mm: Size,
+
+    /// The Rotation.
+    pub rotation: Rotation,
+
+    /// The supported Video Modes.
+    pub video_modes: Vec<VideoMode>,
+}
+
+/// Owned copy of the relevant fields of a RandR Monitor.
+struct MonitorInfo {
+    /// The Atom of the Monitor's name.
+    name: NativeScreen,
+
+    /// Whether or not the Monitor is the Primary Monitor.
+    primary: bool,
 
-/// Wrapper type that holds Info about Screens/
-pub struct Screen(pub NativeScreen);
+    /// The Bounding-Rectangle of the Monitor.
+    rect: Rect,
+
+    /// The physical width of the Monitor, in millimeters (`0` if it is unknown).
+    width_mm: u32,
+
+    /// The physical height of the Monitor, in millimeters (`0` if it is unknown).
+    height_mm: u32,
+
+    /// The RandR Outputs that make up the Monitor.
+    outputs: Vec<sys::xcb_randr_output_t>,
+}
+
+/// The DPI at which the Scale Factor is `1.0`.
+const DEFAULT_DPI: f64 = 96.0;
+
+/// The number of millimeters in an inch.
+const MM_PER_INCH: f64 = 25.4;
 
 // ================================================================================================================================ //
 
 impl Screen {
-    /// Returns the bounding rectangle of the Screen.
-    pub fn rect(&self, _events: &EventLoop) -> Rect {
-        todo!();
+    /// Returns the bounding-rectangle of the Screen.
+    pub fn rect(&self, events: &EventLoop) -> Rect {
+        self.internal_monitor(events)
+            .map(|monitor| monitor.rect)
+            .unwrap_or_default()
+    }
+
+    /// Returns the textual-name of the Screen.
+    pub fn name(&self, events: &EventLoop) -> String {
+        let mut error = null_mut();
+
+        let cookie = unsafe { sys::xcb_get_atom_name(events.connection.handle, self.0.get()) };
+        let reply = unsafe {
+            sys::xcb_get_atom_name_reply(events.connection.handle, cookie, addr_of_mut!(error))
+        };
+        let _reply_free = defer(|| unsafe {
+            sys::free(reply as *mut c_void);
+        });
+        assert!(XcbGenericError::new(error).is_none());
+        assert!(!reply.is_null());
+
+        let len = unsafe { sys::xcb_get_atom_name_name_length(reply) } as usize;
+        let dat = unsafe { sys::xcb_get_atom_name_name(reply) } as *const u8;
+        assert!(!dat.is_null());
+
+        let slice = unsafe { core::slice::from_raw_parts(dat, len) };
+        String::from_utf8_lossy(slice).to_string()
     }
 
-    /// Returns the name of the Screen.
-    pub fn name(&self, _events: &EventLoop) -> String {
-        todo!();
+    /// Returns the Scale Factor of the Screen.
+    pub fn scale_factor(&self, events: &EventLoop) -> ScaleFactor {
+        self.internal_monitor(events)
+            .map(|monitor| events.scale_factors.monitor_scale_factor(&monitor))
+            .unwrap_or(1.0)
+    }
+
+    /// Returns the Refresh Rate of the Screen's current Video Mode, in Hertz.
+    pub fn refresh_rate(&self, events: &EventLoop) -> f64 {
+        self.internal_output(events)
+            .and_then(|output| output.current_mode())
+            .map_or(0.0, |mode| mode.refresh_rate)
+    }
+
+    /// Returns the Work Area of the Screen, which excludes panels (as reported by the Window Manager).\
+    /// `_NET_WORKAREA` spans every Screen, so it is clipped to the Screen.
+    pub fn work_area(&self, events: &EventLoop) -> Rect {
+        let rect = self.rect(events);
+
+        query_work_area(events)
+            .and_then(|work_area| intersection(rect, work_area))
+            .unwrap_or(rect)
+    }
+
+    /// Returns the Physical Size of the Screen, in millimeters.
+    pub fn size_mm(&self, events: &EventLoop) -> Size {
+        self.internal_monitor(events)
+            .map(|monitor| Size::new(monitor.width_mm as _, monitor.height_mm as _))
+            .unwrap_or_default()
+    }
+
+    /// Returns the Rotation of the Screen.
+    pub fn rotation(&self, events: &EventLoop) -> Rotation {
+        self.internal_output(events)
+            .map_or(Rotation::Deg0, |output| {
+                rotation_from(output.crtc_config.rotation)
+            })
+    }
+
+    /// Returns the Video Modes that the Screen supports.
+    pub fn video_modes(&self, events: &EventLoop) -> Vec<VideoMode> {
+        let mut modes = Vec::new();
+
+        for (_, mode) in self
+            .internal_output(events)
+            .map(|output| output.modes)
+            .unwrap_or_default()
+        {
+            // Modes that only differ in timings Wyn does not report are listed once.
+            if !modes.contains(&mode) {
+                modes.push(mode);
+            }
+        }
+
+        modes
+    }
+
+    /// Queries all the Information about the Screen.
+    pub fn info(&self, events: &EventLoop) -> ScreenInfo {
+        ScreenInfo {
+            rect: self.rect(events),
+            name: self.name(events),
+            scale_factor: self.scale_factor(events),
+            refresh_rate: self.refresh_rate(events),
+            work_area: self.work_area(events),
+            size_mm: self.size_mm(events),
+            rotation: self.rotation(events),
+            video_modes: self.video_modes(events),
+        }
     }
 }
 
 // ================================================================================================================================ //
 
 impl Screen {
+    /// Creates a new Screen object with the given handle.
+    pub(crate) fn new(handle: NativeScreen) -> Option<Screen> {
+        NonzeroScreen::new(handle).map(Self)
+    }
+
     /// Returns the Primary Screen.
-    pub fn primary(_events: &EventLoop) -> Screen {
-        todo!();
+    pub fn primary(events: &EventLoop) -> Self {
+        let monitors = internal_monitors(&events.connection);
+
+        // Not every setup designates a primary output, in which case the first monitor is used instead.
+        let primary = monitors
+            .iter()
+            .find(|monitor| monitor.primary)
+            .or_else(|| monitors.first());
+
+        primary
+            .and_then(|monitor| Screen::new(monitor.name))
+            .expect("The Primary Screen should always exist.")
+    }
+
+    /// Collects a list of all the Screens.
+    pub fn collect(events: &EventLoop) -> Vec<Self> {
+        internal_monitors(&events.connection)
+            .into_iter()
+            .filter_map(|monitor| Screen::new(monitor.name))
+            .collect()
+    }
+
+    /// Returns the Screen that contains the center of a Window (or else, the Primary Screen).
+    pub(crate) fn of_window(events: &EventLoop, window: WindowHandle) -> Self {
+        let monitors = internal_monitors(&events.connection);
+
+        window_monitor(&events.connection, &monitors, window)
+            .and_then(|monitor| Screen::new(monitor.name))
+            .expect("The Nearest Screen should always exist.")
+    }
+}
+
+// ================================================================================================================================ //
+
+/// X11-exclusive functionality.
+impl Screen {
+    /// Internal function that retrieves the RandR Monitor of the Screen, if it is still active.
+    fn internal_monitor(&self, events: &EventLoop) -> Option<MonitorInfo> {
+        internal_monitors(&events.connection)
+            .into_iter()
+            .find(|monitor| monitor.name == self.0.get())
+    }
+
+    /// Internal function that retrieves the RandR configuration of the Screen's first Output, if it is driven by a CRTC.
+    fn internal_output(&self, events: &EventLoop) -> Option<OutputConfig> {
+        let monitor = self.internal_monitor(events)?;
+        let output = *monitor.outputs.first()?;
+        query_output_config(&events.connection, output)
     }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// Internal function that retrieves the list of all active RandR Monitors.
+fn internal_monitors(connection: &XcbConnection) -> Vec<MonitorInfo> {
+    let root = connection.screen().root;
+
+    let mut error = null_mut();
+
+    // Only request the active monitors (those that are currently driven by a CRTC).
+    let cookie = unsafe { sys::xcb_randr_get_monitors(connection.handle, root, true as u8) };
+    let reply = unsafe {
+        sys::xcb_randr_get_monitors_reply(connection.handle, cookie, addr_of_mut!(error))
+    };
+    let _reply_free = defer(|| unsafe {
+        sys::free(reply as *mut c_void);
+    });
+    assert!(XcbGenericError::new(error).is_none());
+    assert!(!reply.is_null());
+
+    let mut monitors = Vec::new();
+
+    let mut iter = unsafe { sys::xcb_randr_get_monitors_monitors_iterator(reply) };
+    while iter.rem > 0 {
+        let info = unsafe { iter.data.as_ref() }.expect("Monitor should not be NULL.");
+
+        let outputs = unsafe {
+            copy_list(
+                sys::xcb_randr_monitor_info_outputs(iter.data),
+                sys::xcb_randr_monitor_info_outputs_length(iter.data),
+            )
+        };
+
+        monitors.push(MonitorInfo {
+            name: info.name,
+            primary: info.primary != 0,
+            rect: Rect::new(info.x as _, info.y as _, info.width as _, info.height as _),
+            width_mm: info.width_in_millimeters,
+            height_mm: info.height_in_millimeters,
+            outputs,
+        });
+
+        unsafe { sys::xcb_randr_monitor_info_next(addr_of_mut!(iter)) };
+    }
+
+    monitors
+}
+
+impl MonitorInfo {
+    /// Estimates the Scale Factor from the physical width of the Monitor, rounded to a quarter (and at least `1.0`).\
+    /// Monitors that do not report their physical size (e.g. Projectors and Virtual Machines) are not scaled.
+    fn estimated_scale_factor(&self) -> ScaleFactor {
+        if self.width_mm == 0 {
+            return 1.0;
+        }
+
+        let dpi = self.rect.size.w * MM_PER_INCH / f64::from(self.width_mm);
+        let scale_factor = (dpi / DEFAULT_DPI * 4.0).round() / 4.0;
+        scale_factor.max(1.0)
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Derives the Scale Factors of Screens and Windows, and tracks those of Windows to report when they change.
+pub(crate) struct XcbScaleFactors {
+    /// The `Xft.dpi` resource, if it is set.
+    xft_dpi: Mutex<Option<f64>>,
+
+    /// The last known Scale Factor of every open Window.
+    windows: Mutex<HashMap<WindowHandle, ScaleFactor>>,
+}
+
+impl XcbScaleFactors {
+    /// Reads the `Xft.dpi` resource, and starts watching the Root Window for changes to it.
+    pub(crate) fn new(connection: &XcbConnection) -> Self {
+        let root = connection.screen().root;
+
+        let value_list = [sys::XCB_EVENT_MASK_PROPERTY_CHANGE];
+        let cookie = unsafe {
+            sys::xcb_change_window_attributes_checked(
+                connection.handle,
+                root,
+                sys::XCB_CW_EVENT_MASK,
+                void_of!(value_list),
+            )
+        };
+        connection.check_cookie(cookie).unwrap();
+
+        Self {
+            xft_dpi: Mutex::new(query_xft_dpi(connection)),
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the Scale Factor of a Monitor.
+    fn monitor_scale_factor(&self, monitor: &MonitorInfo) -> ScaleFactor {
+        let xft_dpi = *self.xft_dpi.lock().unwrap();
+
+        match xft_dpi {
+            Some(dpi) => dpi / DEFAULT_DPI,
+            None => monitor.estimated_scale_factor(),
+        }
+    }
+
+    /// Returns the Scale Factor of a Window, from the Monitor that contains its center (or else, the Primary Monitor).
+    pub(crate) fn window_scale_factor(
+        &self,
+        connection: &XcbConnection,
+        window: WindowHandle,
+    ) -> ScaleFactor {
+        let monitors = internal_monitors(connection);
+        let monitor = window_monitor(connection, &monitors, window);
+
+        monitor.map_or(1.0, |monitor| self.monitor_scale_factor(monitor))
+    }
+
+    /// Starts tracking the Scale Factor of a newly opened Window.
+    pub(crate) fn track(&self, connection: &XcbConnection, window: WindowHandle) {
+        let scale_factor = self.window_scale_factor(connection, window);
+        self.windows.lock().unwrap().insert(window, scale_factor);
+    }
+
+    /// Stops tracking the Scale Factor of a destroyed Window.
+    pub(crate) fn remove(&self, window: WindowHandle) {
+        self.windows.lock().unwrap().remove(&window);
+    }
+
+    /// Recomputes the Scale Factor of a Window (e.g. after it moved), returning it if it changed.
+    pub(crate) fn update(
+        &self,
+        connection: &XcbConnection,
+        window: WindowHandle,
+    ) -> Option<ScaleFactor> {
+        if !self.windows.lock().unwrap().contains_key(&window) {
+            return None;
+        }
+
+        // The lock is not held while querying the X Server.
+        let scale_factor = self.window_scale_factor(connection, window);
+
+        let mut windows = self.windows.lock().unwrap();
+        let previous = windows.get_mut(&window)?;
+        if *previous == scale_factor {
+            None
+        } else {
+            *previous = scale_factor;
+            Some(scale_factor)
+        }
+    }
+
+    /// Re-reads the `Xft.dpi` resource, if the `PropertyNotify` event changed the resources.\
+    /// Returns the Windows whose Scale Factor changed as a result.
+    pub(crate) fn handle_property(
+        &self,
+        connection: &XcbConnection,
+        event: &sys::xcb_property_notify_event_t,
+    ) -> Vec<(WindowHandle, ScaleFactor)> {
+        let root = connection.screen().root;
+        if event.window != root || event.atom != sys::XCB_ATOM_RESOURCE_MANAGER {
+            return Vec::new();
+        }
+
+        let xft_dpi = query_xft_dpi(connection);
+        let previous = std::mem::replace(&mut *self.xft_dpi.lock().unwrap(), xft_dpi);
+        if previous == xft_dpi {
+            return Vec::new();
+        }
+
+        self.update_all(connection)
+    }
+
+    /// Recomputes the Scale Factor of every Window (e.g. after the Monitors changed), returning those that changed.
+    pub(crate) fn update_all(
+        &self,
+        connection: &XcbConnection,
+    ) -> Vec<(WindowHandle, ScaleFactor)> {
+        let windows: Vec<WindowHandle> = self.windows.lock().unwrap().keys().copied().collect();
+        windows
+            .into_iter()
+            .filter_map(|window| Some((window, self.update(connection, window)?)))
+            .collect()
+    }
+}
+
+// ---------------------------------------------------------------- //
+
+/// Reads the `Xft.dpi` resource from the `RESOURCE_MANAGER` property of the Root Window.\
+/// The resources are lines of `name:\tvalue` pairs (as loaded by `xrdb`).
+fn query_xft_dpi(connection: &XcbConnection) -> Option<f64> {
+    /// The maximum length of the resources to read, in 32-bit units.
+    const MAX_LEN: u32 = 16384;
+
+    let root = connection.screen().root;
+
+    let mut error = null_mut();
+    let cookie = unsafe {
+        sys::xcb_get_property(
+            connection.handle,
+            false as u8,
+            root,
+            sys::XCB_ATOM_RESOURCE_MANAGER,
+            sys::XCB_ATOM_STRING,
+            0,
+            MAX_LEN,
+        )
+    };
+    let reply =
+        unsafe { sys::xcb_get_property_reply(connection.handle, cookie, addr_of_mut!(error)) };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+
+    if XcbGenericError::new(error).is_some() || reply.is_null() {
+        return None;
+    }
+
+    let len = unsafe { sys::xcb_get_property_value_length(reply) } as usize;
+    let dat = unsafe { sys::xcb_get_property_value(reply) } as *const u8;
+    if dat.is_null() {
+        return None;
+    }
+
+    let slice = unsafe { core::slice::from_raw_parts(dat, len) };
+    String::from_utf8_lossy(slice).lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        if name.trim() != "Xft.dpi" {
+            return None;
+        }
+
+        let dpi: f64 = value.trim().parse().ok()?;
+        (dpi > 0.0).then_some(dpi)
+    })
+}
+
+/// Returns the Monitor that contains the center of a Window (or else, the Primary Monitor).
+fn window_monitor<'a>(
+    connection: &XcbConnection,
+    monitors: &'a [MonitorInfo],
+    window: WindowHandle,
+) -> Option<&'a MonitorInfo> {
+    let center = window_center(connection, window);
+
+    center
+        .and_then(|center| {
+            monitors
+                .iter()
+                .find(|monitor| monitor.rect.contains(center))
+        })
+        .or_else(|| monitors.iter().find(|monitor| monitor.primary))
+        .or_else(|| monitors.first())
+}
+
+/// Returns the center of a Window, relative to the Root Window.
+fn window_center(connection: &XcbConnection, window: WindowHandle) -> Option<Point> {
+    let root = connection.screen().root;
+
+    let mut error = null_mut();
+    let cookie = unsafe { sys::xcb_get_geometry(connection.handle, window) };
+    let geom =
+        unsafe { sys::xcb_get_geometry_reply(connection.handle, cookie, addr_of_mut!(error)) };
+    let _geom_free = defer(|| {
+        if !geom.is_null() {
+            unsafe { sys::free(geom as *mut c_void) };
+        }
+    });
+    if XcbGenericError::new(error).is_some() {
+        return None;
+    }
+    let geom = unsafe { geom.as_ref() }?;
+
+    let (half_w, half_h) = ((geom.width / 2) as i16, (geom.height / 2) as i16);
+
+    let mut error = null_mut();
+    let cookie =
+        unsafe { sys::xcb_translate_coordinates(connection.handle, window, root, half_w, half_h) };
+    let trans = unsafe {
+        sys::xcb_translate_coordinates_reply(connection.handle, cookie, addr_of_mut!(error))
+    };
+    let _trans_free = defer(|| {
+        if !trans.is_null() {
+            unsafe { sys::free(trans as *mut c_void) };
+        }
+    });
+    if XcbGenericError::new(error).is_some() {
+        return None;
+    }
+    let trans = unsafe { trans.as_ref() }?;
+
+    Some(Point::new(trans.dst_x as _, trans.dst_y as _))
+}
+
+// ================================================================================================================================ //
+
+/// Watches the Root Window for RandR changes to the Monitors, and reports each change once.\
+/// A single change is announced by several RandR events (one per affected Screen, CRTC, and Output).
+pub(crate) struct XcbScreenChanges {
+    /// The first Event Code of the RandR extension, if the X Server supports it.
+    base_event: Option<u8>,
+
+    /// The Screens, as they were last reported.
+    screens: ScreenSnapshot<ScreenInfo>,
+}
+
+impl XcbScreenChanges {
+    /// Starts watching the Root Window for RandR changes, if the X Server supports it.
+    pub(crate) fn new(connection: &XcbConnection) -> Self {
+        let ext = unsafe {
+            sys::xcb_get_extension_data(connection.handle, addr_of_mut!(sys::xcb_randr_id))
+        };
+        let base_event = unsafe { ext.as_ref() }
+            .filter(|ext| ext.present != 0)
+            .map(|ext| ext.first_event);
+
+        if base_event.is_some() {
+            let root = connection.screen().root;
+            let mask = sys::XCB_RANDR_NOTIFY_MASK_SCREEN_CHANGE
+                | sys::XCB_RANDR_NOTIFY_MASK_CRTC_CHANGE
+                | sys::XCB_RANDR_NOTIFY_MASK_OUTPUT_CHANGE;
+
+            let cookie = unsafe {
+                sys::xcb_randr_select_input_checked(connection.handle, root, mask as u16)
+            };
+            connection.check_cookie(cookie).unwrap();
+        }
+
+        Self {
+            base_event,
+            screens: ScreenSnapshot::default(),
+        }
+    }
+
+    /// Returns whether or not RandR changes are reported.
+    pub(crate) fn is_supported(&self) -> bool {
+        self.base_event.is_some()
+    }
+
+    /// Returns whether or not the Event Code belongs to a RandR change event.
+    pub(crate) fn is_change(&self, event_type: u8) -> bool {
+        self.base_event.is_some_and(|base| {
+            let screen_change = base + sys::XCB_RANDR_SCREEN_CHANGE_NOTIFY as u8;
+            let notify = base + sys::XCB_RANDR_NOTIFY as u8;
+            event_type == screen_change || event_type == notify
+        })
+    }
+
+    /// Queries the Screens again, returning whether or not they changed since they were last reported.
+    pub(crate) fn update(&self, events: &EventLoop) -> bool {
+        let screens = Screen::collect(events)
+            .iter()
+            .map(|screen| screen.info(events))
+            .collect();
+        self.screens.update(screens)
+    }
+}
+
+// ================================================================================================================================ //
+
+/// The RandR configuration of an Output, which determines the Video Mode of its Monitor.
+struct OutputConfig {
+    /// The timestamp of the Screen configuration, which requests to change it must match.
+    config_timestamp: sys::xcb_timestamp_t,
+
+    /// The CRTC that drives the Output.
+    crtc: sys::xcb_randr_crtc_t,
+
+    /// The current configuration of the CRTC.
+    crtc_config: CrtcConfig,
+
+    /// The RandR Modes that the Output supports, along with their Video Modes.
+    modes: Vec<(sys::xcb_randr_mode_t, VideoMode)>,
+}
+
+/// The configuration of a CRTC, which is saved to restore it after its Video Mode was switched.
+#[derive(Clone)]
+struct CrtcConfig {
+    /// The horizontal position, in the Root Window.
+    x: i16,
+
+    /// The vertical position, in the Root Window.
+    y: i16,
+
+    /// The RandR Mode.
+    mode: sys::xcb_randr_mode_t,
+
+    /// The rotation and reflection bits.
+    rotation: u16,
+
+    /// The Outputs driven by the CRTC.
+    outputs: Vec<sys::xcb_randr_output_t>,
+}
+
+impl OutputConfig {
+    /// Returns the Video Mode that the CRTC is currently driven at.
+    fn current_mode(&self) -> Option<VideoMode> {
+        self.modes
+            .iter()
+            .find(|(id, _)| *id == self.crtc_config.mode)
+            .map(|(_, mode)| *mode)
+    }
+}
+
+// ---------------------------------------------------------------- //
+
+/// The CRTCs whose Video Mode was switched by an Exclusive Fullscreen Window, along with their original configuration.
+#[derive(Default)]
+pub(crate) struct XcbVideoModes(Mutex<HashMap<WindowHandle, (sys::xcb_randr_crtc_t, CrtcConfig)>>);
+
+impl XcbVideoModes {
+    /// Switches the Screen to its closest Video Mode on behalf of the Window, returning whether or not it succeeded.\
+    /// Any Video Mode that the Window switched before is restored first.
+    pub(crate) fn switch(
+        &self,
+        events: &EventLoop,
+        window: WindowHandle,
+        screen: &Screen,
+        mode: &VideoMode,
+    ) -> bool {
+        self.restore(&events.connection, window);
+
+        let Some(output) = screen.internal_output(events) else {
+            return false;
+        };
+
+        let modes: Vec<VideoMode> = output.modes.iter().map(|(_, mode)| *mode).collect();
+        let Some(closest) = mode.closest_in(&modes) else {
+            return false;
+        };
+        let Some(&(id, _)) = output.modes.iter().find(|(_, mode)| mode == closest) else {
+            return false;
+        };
+
+        let config = CrtcConfig {
+            mode: id,
+            ..output.crtc_config.clone()
+        };
+        if !set_crtc_config(
+            &events.connection,
+            output.crtc,
+            output.config_timestamp,
+            &config,
+        ) {
+            return false;
+        }
+
+        let original = (output.crtc, output.crtc_config);
+        self.0.lock().unwrap().insert(window, original);
+        true
+    }
+
+    /// Restores the Video Mode that the Window switched, if any.
+    pub(crate) fn restore(&self, connection: &XcbConnection, window: WindowHandle) {
+        let saved = self.0.lock().unwrap().remove(&window);
+        if let Some((crtc, config)) = saved {
+            restore_crtc(connection, crtc, &config);
+        }
+    }
+
+    /// Restores every Video Mode that was switched, as the X Server would otherwise keep them.
+    pub(crate) fn restore_all(&self, connection: &XcbConnection) {
+        let saved: Vec<_> = self.0.lock().unwrap().drain().collect();
+        for (_, (crtc, config)) in saved {
+            restore_crtc(connection, crtc, &config);
+        }
+    }
+}
+
+// ---------------------------------------------------------------- //
+
+/// Retrieves the RandR configuration of an Output, if it is driven by a CRTC.
+fn query_output_config(
+    connection: &XcbConnection,
+    output: sys::xcb_randr_output_t,
+) -> Option<OutputConfig> {
+    let (config_timestamp, mode_infos) = query_mode_infos(connection)?;
+
+    let mut error = null_mut();
+    let cookie =
+        unsafe { sys::xcb_randr_get_output_info(connection.handle, output, config_timestamp) };
+    let reply = unsafe {
+        sys::xcb_randr_get_output_info_reply(connection.handle, cookie, addr_of_mut!(error))
+    };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+    if XcbGenericError::new(error).is_some() {
+        return None;
+    }
+    let info = unsafe { reply.as_ref() }?;
+
+    let mode_ids = unsafe {
+        copy_list(
+            sys::xcb_randr_get_output_info_modes(reply),
+            sys::xcb_randr_get_output_info_modes_length(reply),
+        )
+    };
+
+    let crtc = info.crtc;
+    let crtc_config = query_crtc_config(connection, crtc, config_timestamp)?;
+
+    // Modes are listed in their natural orientation, while Screens report their rotated size.
+    let sideways = rotation_from(crtc_config.rotation).is_sideways();
+    let bit_depth = u32::from(connection.screen().root_depth);
+
+    let modes = mode_ids
+        .into_iter()
+        .filter_map(|id| {
+            let mode_info = mode_infos.iter().find(|mode_info| mode_info.id == id)?;
+            Some((id, video_mode_from(mode_info, sideways, bit_depth)))
+        })
+        .collect();
+
+    Some(OutputConfig {
+        config_timestamp,
+        crtc,
+        crtc_config,
+        modes,
+    })
+}
+
+/// Retrieves the timestamp of the Screen configuration, along with every RandR Mode that it knows of.
+fn query_mode_infos(
+    connection: &XcbConnection,
+) -> Option<(sys::xcb_timestamp_t, Vec<sys::xcb_randr_mode_info_t>)> {
+    let root = connection.screen().root;
+
+    let mut error = null_mut();
+    let cookie = unsafe { sys::xcb_randr_get_screen_resources_current(connection.handle, root) };
+    let reply = unsafe {
+        sys::xcb_randr_get_screen_resources_current_reply(
+            connection.handle,
+            cookie,
+            addr_of_mut!(error),
+        )
+    };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+    if XcbGenericError::new(error).is_some() {
+        return None;
+    }
+    let resources = unsafe { reply.as_ref() }?;
+
+    let mode_infos = unsafe {
+        copy_list(
+            sys::xcb_randr_get_screen_resources_current_modes(reply),
+            sys::xcb_randr_get_screen_resources_current_modes_length(reply),
+        )
+    };
+
+    Some((resources.config_timestamp, mode_infos))
+}
+
+/// Retrieves the configuration of a CRTC, if it is enabled.
+fn query_crtc_config(
+    connection: &XcbConnection,
+    crtc: sys::xcb_randr_crtc_t,
+    config_timestamp: sys::xcb_timestamp_t,
+) -> Option<CrtcConfig> {
+    if crtc == sys::XCB_NONE {
+        return None;
+    }
+
+    let mut error = null_mut();
+    let cookie = unsafe { sys::xcb_randr_get_crtc_info(connection.handle, crtc, config_timestamp) };
+    let reply = unsafe {
+        sys::xcb_randr_get_crtc_info_reply(connection.handle, cookie, addr_of_mut!(error))
+    };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+    if XcbGenericError::new(error).is_some() {
+        return None;
+    }
+    let info = unsafe { reply.as_ref() }?;
+
+    let outputs = unsafe {
+        copy_list(
+            sys::xcb_randr_get_crtc_info_outputs(reply),
+            sys::xcb_randr_get_crtc_info_outputs_length(reply),
+        )
+    };
+
+    Some(CrtcConfig {
+        x: info.x,
+        y: info.y,
+        mode: info.mode,
+        rotation: info.rotation,
+        outputs,
+    })
+}
+
+/// Applies a configuration to a CRTC, returning whether or not the X Server accepted it.\
+/// Video Modes larger than the Root Window are refused, as the Root Window is never resized.
+fn set_crtc_config(
+    connection: &XcbConnection,
+    crtc: sys::xcb_randr_crtc_t,
+    config_timestamp: sys::xcb_timestamp_t,
+    config: &CrtcConfig,
+) -> bool {
+    let mut error = null_mut();
+    let cookie = unsafe {
+        sys::xcb_randr_set_crtc_config(
+            connection.handle,
+            crtc,
+            sys::XCB_CURRENT_TIME,
+            config_timestamp,
+            config.x,
+            config.y,
+            config.mode,
+            config.rotation,
+            config.outputs.len() as u32,
+            config.outputs.as_ptr(),
+        )
+    };
+    let reply = unsafe {
+        sys::xcb_randr_set_crtc_config_reply(connection.handle, cookie, addr_of_mut!(error))
+    };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+
+    XcbGenericError::new(error).is_none()
+        && unsafe { reply.as_ref() }
+            .is_some_and(|rep| rep.status == sys::XCB_RANDR_SET_CONFIG_SUCCESS as u8)
+}
+
+/// Restores the original configuration of a CRTC, against the current Screen configuration.
+fn restore_crtc(connection: &XcbConnection, crtc: sys::xcb_randr_crtc_t, config: &CrtcConfig) {
+    if let Some((config_timestamp, _)) = query_mode_infos(connection) {
+        let _ = set_crtc_config(connection, crtc, config_timestamp, config);
+    }
+}
+
+// ---------------------------------------------------------------- //
+
+/// Converts a RandR Mode into a Video Mode, swapping its width and height if the CRTC is rotated sideways.
+fn video_mode_from(
+    mode_info: &sys::xcb_randr_mode_info_t,
+    sideways: bool,
+    bit_depth: u32,
+) -> VideoMode {
+    let flags = mode_info.mode_flags;
+
+    // Double-Scan Modes draw every line twice, while Interlaced Modes draw every other line.
+    let mut vtotal = f64::from(mode_info.vtotal);
+    if flags & (sys::XCB_RANDR_MODE_FLAG_DOUBLE_SCAN as u32) != 0 {
+        vtotal *= 2.0;
+    }
+    if flags & (sys::XCB_RANDR_MODE_FLAG_INTERLACE as u32) != 0 {
+        vtotal /= 2.0;
+    }
+
+    let htotal = f64::from(mode_info.htotal);
+    let refresh_rate = if htotal > 0.0 && vtotal > 0.0 {
+        f64::from(mode_info.dot_clock) / (htotal * vtotal)
+    } else {
+        0.0
+    };
+
+    let (width, height) = (f64::from(mode_info.width), f64::from(mode_info.height));
+    let size = if sideways {
+        Size::new(height, width)
+    } else {
+        Size::new(width, height)
+    };
+
+    VideoMode {
+        size,
+        refresh_rate,
+        bit_depth,
+    }
+}
+
+/// Converts the rotation bits of a CRTC into a Rotation (RandR rotates counter-clockwise, and reflections are ignored).
+fn rotation_from(bits: u16) -> Rotation {
+    if bits & (sys::XCB_RANDR_ROTATION_ROTATE_90 as u16) != 0 {
+        Rotation::Deg270
+    } else if bits & (sys::XCB_RANDR_ROTATION_ROTATE_180 as u16) != 0 {
+        Rotation::Deg180
+    } else if bits & (sys::XCB_RANDR_ROTATION_ROTATE_270 as u16) != 0 {
+        Rotation::Deg90
+    } else {
+        Rotation::Deg0
+    }
+}
+
+// ---------------------------------------------------------------- //
+
+/// Reads the Work Area of the current Desktop from `_NET_WORKAREA`, if the Window Manager sets it.
+fn query_work_area(events: &EventLoop) -> Option<Rect> {
+    let ewmh = events.atoms.ewmh_ptr();
+    let screen_nbr = events.connection.screen_id;
+
+    // Every Desktop has its own Work Area, and the first one is used if the current Desktop is unknown.
+    let mut desktop = 0;
+    let mut error = null_mut();
+    let cookie = unsafe { sys::xcb_ewmh_get_current_desktop(ewmh, screen_nbr) };
+    let reply = unsafe {
+        sys::xcb_ewmh_get_current_desktop_reply(
+            ewmh,
+            cookie,
+            addr_of_mut!(desktop),
+            addr_of_mut!(error),
+        )
+    };
+    if XcbGenericError::new(error).is_some() || reply != 1 {
+        desktop = 0;
+    }
+
+    let mut error = null_mut();
+    let mut workarea: sys::xcb_ewmh_get_workarea_reply_t = unsafe { zeroed() };
+    let cookie = unsafe { sys::xcb_ewmh_get_workarea(ewmh, screen_nbr) };
+    let reply = unsafe {
+        sys::xcb_ewmh_get_workarea_reply(ewmh, cookie, addr_of_mut!(workarea), addr_of_mut!(error))
+    };
+    if XcbGenericError::new(error).is_some() || reply != 1 {
+        return None;
+    }
+
+    let areas = unsafe { copy_list(workarea.workarea, workarea.workarea_len as _) };
+    unsafe { sys::xcb_ewmh_get_workarea_reply_wipe(addr_of_mut!(workarea)) };
+
+    let area = areas.get(desktop as usize).or_else(|| areas.first())?;
+    Some(Rect::new(
+        area.x as _,
+        area.y as _,
+        area.width as _,
+        area.height as _,
+    ))
+}
+
+/// Returns the overlapping part of two rectangles, if they overlap.
+fn intersection(a: Rect, b: Rect) -> Option<Rect> {
+    let left = a.origin.x.max(b.origin.x);
+    let top = a.origin.y.max(b.origin.y);
+    let right = (a.origin.x + a.size.w).min(b.origin.x + b.size.w);
+    let bottom = (a.origin.y + a.size.h).min(b.origin.y + b.size.h);
+
+    (right > left && bottom > top).then(|| Rect::new(left, top, right - left, bottom - top))
+}
 
-    /// Collects a list of all the available Screens.
-    pub fn collect(_events: &EventLoop) -> Vec<Screen> {
-        todo!();
+/// Copies a list out of an XCB reply (which may be NULL if it is empty).
+unsafe fn copy_list<T: Copy>(dat: *const T, len: c_int) -> Vec<T> {
+    if dat.is_null() || len <= 0 {
+        return Vec::new();
     }
+    core::slice::from_raw_parts(dat, len as usize).to_vec()
 }
 
 // ================================================================================================================================ //
@@ -0,0 +1,417 @@
+/*
+ *  Crate: Wyn
+ * Module: X11 - Scroll
+ */
+
+//! Receiving Smooth Scrolling and Pens from XInput 2.1.
+//!
+//! The core protocol reports each notch of a Scroll-Wheel as a click of the Buttons 4 to 7, so it cannot scroll by less than a notch.\
+//! XInput 2.1 reports scrolling through the Scroll Valuators (axes) of a Pointer instead, whose change is divided by the increment of a notch.\
+//! Selecting XInput2 `Motion` on Wyn's Windows replaces their core `MotionNotify`, and the Buttons 4 to 7 (still emulated) are then ignored.\
+//! The Valuators are absolute, so their last values are forgotten whenever the Cursor enters a Window, having missed the scrolling elsewhere.
+//!
+//! A Master Pointer that follows a Pen (Stylus) has the Valuators labelled "Abs Pressure", "Abs Tilt X" and "Abs Tilt Y" as well.\
+//! These are reported along with every `Motion` of the Pen, where Button 1 is its tip and Buttons 2 and 3 are its Barrel-Buttons.\
+//! Tablet drivers add the Eraser end of a Pen as a Slave Device of its own, whose name contains "eraser".
+//!
+//! ## References
+//! * <https://gitlab.freedesktop.org/xorg/proto/xorgproto/-/blob/master/specs/XI2proto.txt>
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::cursor::{fp3232_to_f64, select_xi_events};
+use super::event_loop::XcbConnection;
+use super::inputs::PenButtons;
+use super::types::Point;
+use super::window::WindowHandle;
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+// ================================================================================================================================ //
+
+/// A Scroll Valuator of a Master Pointer.
+struct ScrollValuator {
+    /// Whether the Valuator scrolls horizontally (rather than vertically).
+    horizontal: bool,
+
+    /// The change of the Valuator that amounts to one notch of a Scroll-Wheel.
+    increment: f64,
+
+    /// The last value of the Valuator, unless the Cursor entered a Window since.
+    last: Option<f64>,
+}
+
+/// An axis of a Pen, which is reported through a Valuator.
+#[derive(Clone, Copy, PartialEq)]
+enum PenAxis {
+    /// The pressure of the tip, from `0.0` to `1.0`.
+    Pressure,
+
+    /// The tilt towards the right (in degrees, for most tablet drivers).
+    TiltX,
+
+    /// The tilt towards the user (in degrees, for most tablet drivers).
+    TiltY,
+}
+
+/// A Pen Valuator of a Master Pointer.
+struct PenValuator {
+    /// The axis of the Pen that the Valuator reports.
+    axis: PenAxis,
+
+    /// The minimum and maximum values of the Valuator.
+    range: (f64, f64),
+
+    /// The last value of the Valuator (Valuators that did not change are left out of `Motion` events).
+    value: f64,
+}
+
+/// The Valuators of every Master Pointer, by Master Pointer and Valuator number.
+#[derive(Default)]
+struct XcbValuators {
+    /// The Scroll Valuators, which back Smooth Scrolling.
+    scroll: HashMap<(u16, u16), ScrollValuator>,
+
+    /// The Pen Valuators, of the Master Pointers that currently follow a Pen.
+    pen: HashMap<(u16, u16), PenValuator>,
+
+    /// The Slave Devices that are the Eraser end of a Pen.
+    erasers: HashSet<u16>,
+}
+
+/// The motion and scrolling of the Pointer, read from an XInput2 `Motion` event.
+pub(crate) struct XcbMotion {
+    /// The Window that the Cursor is over.
+    pub window: WindowHandle,
+
+    /// The position of the Cursor, if it moved.
+    pub point: Option<Point>,
+
+    /// The horizontal and vertical Scroll-Wheel deltas, if the Pointer scrolled.
+    pub scroll: Option<(f64, f64)>,
+
+    /// The position, pressure, tilt and pressed Buttons of the Pen, if the Pointer follows one.
+    pub pen: Option<(Point, f64, (f64, f64), PenButtons)>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The Scroll and Pen Valuators of every Master Pointer, which back Smooth Scrolling and Pens.
+pub(crate) struct XcbScrolling {
+    /// The Major Opcode of the XInput extension, if the X Server supports XInput 2.1.
+    xinput_opcode: Option<u8>,
+
+    /// The Scroll and Pen Valuators.
+    valuators: Mutex<XcbValuators>,
+}
+
+impl XcbScrolling {
+    /// Starts watching the Pointers for changes to their Scroll Valuators, if the X Server supports XInput 2.1.\
+    /// Takes the XInput2 version that was negotiated by `XcbCursors`.
+    pub(crate) fn new(connection: &XcbConnection, xinput: Option<(u8, u16)>) -> Self {
+        let xinput_opcode = xinput
+            .filter(|(_, minor)| *minor >= 1)
+            .map(|(opcode, _)| opcode);
+
+        // A Master Pointer takes the Valuators of whichever Slave Device it last followed.
+        if xinput_opcode.is_some() {
+            let root = connection.screen().root;
+            let deviceid = sys::XCB_INPUT_DEVICE_ALL as u16;
+            let mask = sys::XCB_INPUT_XI_EVENT_MASK_DEVICE_CHANGED as u32;
+            let _ = select_xi_events(connection, root, deviceid, mask);
+        }
+
+        let valuators = match xinput_opcode {
+            Some(_) => query_valuators(connection),
+            None => XcbValuators::default(),
+        };
+
+        Self {
+            xinput_opcode,
+            valuators: Mutex::new(valuators),
+        }
+    }
+
+    /// Returns whether or not scrolling is reported through Scroll Valuators, instead of the Buttons 4 to 7.
+    pub(crate) fn is_smooth(&self) -> bool {
+        !self.valuators.lock().unwrap().scroll.is_empty()
+    }
+
+    /// Returns whether or not Pens are reported, which requires XInput 2.1 (to select `Motion` events on Windows).
+    pub(crate) fn is_pen_supported(&self) -> bool {
+        self.xinput_opcode.is_some()
+    }
+
+    /// Returns the mask of XInput2 events to select on Windows, where `Motion` replaces their core `MotionNotify`.
+    pub(crate) fn window_mask(&self) -> u32 {
+        if self.xinput_opcode.is_none() {
+            return 0;
+        }
+
+        sys::XCB_INPUT_XI_EVENT_MASK_MOTION as u32
+    }
+
+    /// Forgets the last values of the Scroll Valuators, once the Cursor enters a Window.
+    pub(crate) fn reset(&self) {
+        let mut valuators = self.valuators.lock().unwrap();
+        for valuator in valuators.scroll.values_mut() {
+            valuator.last = None;
+        }
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Queries the Valuators again, if the event is an XInput2 `DeviceChanged` event.\
+    /// A Master Pointer changes when it starts following another Slave Device (e.g. a Pen instead of a Mouse).
+    pub(crate) fn handle_device_changed(
+        &self,
+        connection: &XcbConnection,
+        event: &sys::xcb_ge_generic_event_t,
+    ) {
+        let is_changed = self.xinput_opcode.is_some_and(|opcode| {
+            event.extension == opcode && event.event_type == sys::XCB_INPUT_DEVICE_CHANGED as u16
+        });
+
+        if is_changed {
+            *self.valuators.lock().unwrap() = query_valuators(connection);
+        }
+    }
+
+    /// Reads the motion, scrolling and Pen of the Pointer from an XInput2 `Motion` event.\
+    /// Returns `None` for other Generic Events.
+    pub(crate) fn motion(&self, event: &sys::xcb_ge_generic_event_t) -> Option<XcbMotion> {
+        let opcode = self.xinput_opcode?;
+        if event.extension != opcode || event.event_type != sys::XCB_INPUT_MOTION as u16 {
+            return None;
+        }
+
+        let evt =
+            (event as *const sys::xcb_ge_generic_event_t).cast::<sys::xcb_input_motion_event_t>();
+
+        // SAFETY: Generic Events are read whole, so the Button and Valuator Masks and the Values follow the event.
+        let (buttons, mask, values) = unsafe {
+            let buttons_len = sys::xcb_input_button_press_button_mask_length(evt) as usize;
+            let buttons_ptr = sys::xcb_input_button_press_button_mask(evt);
+            let mask_len = sys::xcb_input_button_press_valuator_mask_length(evt) as usize;
+            let mask_ptr = sys::xcb_input_button_press_valuator_mask(evt);
+            let values_len = sys::xcb_input_button_press_axisvalues_length(evt) as usize;
+            let values_ptr = sys::xcb_input_button_press_axisvalues(evt);
+            (
+                core::slice::from_raw_parts(buttons_ptr, buttons_len),
+                core::slice::from_raw_parts(mask_ptr, mask_len),
+                core::slice::from_raw_parts(values_ptr, values_len),
+            )
+        };
+        let evt = unsafe { &*evt };
+
+        let mut point = None;
+        let (mut delta_x, mut delta_y) = (0.0, 0.0);
+
+        // Values are only sent for the Valuators set in the mask, where Valuators 0 and 1 are the position of the Pointer.
+        let mut valuators = self.valuators.lock().unwrap();
+        for (number, value) in set_valuators(mask).zip(values.iter().map(fp3232_to_f64)) {
+            if number < 2 {
+                point = Some(Point::new(
+                    fp1616_to_f64(evt.event_x),
+                    fp1616_to_f64(evt.event_y),
+                ));
+                continue;
+            }
+
+            if let Some(valuator) = valuators.pen.get_mut(&(evt.deviceid, number)) {
+                valuator.value = value;
+                continue;
+            }
+
+            let Some(valuator) = valuators.scroll.get_mut(&(evt.deviceid, number)) else {
+                continue;
+            };
+            let Some(last) = valuator.last.replace(value) else {
+                continue;
+            };
+
+            // The vertical Valuator grows while scrolling down, whereas positive deltas scroll up.
+            let delta = (value - last) / valuator.increment;
+            if valuator.horizontal {
+                delta_x += delta;
+            } else {
+                delta_y -= delta;
+            }
+        }
+
+        let scroll = (delta_x != 0.0 || delta_y != 0.0).then_some((delta_x, delta_y));
+
+        let pen = valuators.pen_state(evt.deviceid).map(|(pressure, tilt)| {
+            let point = Point::new(fp1616_to_f64(evt.event_x), fp1616_to_f64(evt.event_y));
+            let held = |button: usize| {
+                buttons
+                    .get(button / 32)
+                    .is_some_and(|bits| bits & (1 << (button % 32)) != 0)
+            };
+
+            let mut pen_buttons = PenButtons::NONE;
+            pen_buttons.set(PenButtons::TIP, held(1));
+            pen_buttons.set(PenButtons::BARREL, held(2) || held(3));
+            pen_buttons.set(
+                PenButtons::ERASER,
+                valuators.erasers.contains(&evt.sourceid),
+            );
+            (point, pressure, tilt, pen_buttons)
+        });
+
+        Some(XcbMotion {
+            window: evt.event,
+            point,
+            scroll,
+            pen,
+        })
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl XcbValuators {
+    /// Returns the pressure and tilt of the Pen that a Master Pointer follows, if it follows one (with a pressure sensor).
+    fn pen_state(&self, deviceid: u16) -> Option<(f64, (f64, f64))> {
+        let value = |axis: PenAxis| {
+            self.pen
+                .iter()
+                .find(|((device, _), valuator)| *device == deviceid && valuator.axis == axis)
+                .map(|(_, valuator)| valuator)
+        };
+
+        let pressure = value(PenAxis::Pressure)?;
+        let (min, max) = pressure.range;
+        let pressure = if max > min {
+            ((pressure.value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        // The tilt is zero unless the Pen reports it.
+        let tilt_x = value(PenAxis::TiltX).map_or(0.0, |valuator| valuator.value);
+        let tilt_y = value(PenAxis::TiltY).map_or(0.0, |valuator| valuator.value);
+
+        Some((pressure, (tilt_x, tilt_y)))
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Queries the Scroll and Pen Valuators of every Master Pointer (with the last Scroll values forgotten), and the Erasers.
+fn query_valuators(connection: &XcbConnection) -> XcbValuators {
+    let mut valuators = XcbValuators::default();
+
+    // The labels only exist once a driver has created them, so a missing label is never matched.
+    let label = |name: &str| {
+        connection
+            .query_atom(name, false)
+            .ok()
+            .flatten()
+            .map_or(0, |atom| atom.get())
+    };
+    let pen_labels = [
+        (label("Abs Pressure"), PenAxis::Pressure),
+        (label("Abs Tilt X"), PenAxis::TiltX),
+        (label("Abs Tilt Y"), PenAxis::TiltY),
+    ];
+
+    let deviceid = sys::XCB_INPUT_DEVICE_ALL as u16;
+    let cookie = unsafe { sys::xcb_input_xi_query_device(connection.handle, deviceid) };
+    let reply =
+        unsafe { sys::xcb_input_xi_query_device_reply(connection.handle, cookie, null_mut()) };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+    if reply.is_null() {
+        return valuators;
+    }
+
+    let mut infos = unsafe { sys::xcb_input_xi_query_device_infos_iterator(reply) };
+    while infos.rem > 0 {
+        let info = unsafe { &*infos.data };
+
+        if info.type_ != sys::XCB_INPUT_DEVICE_TYPE_MASTER_POINTER as u16 {
+            // SAFETY: The name follows the Device Info, and is not null-terminated.
+            let name = unsafe {
+                let name_ptr = sys::xcb_input_xi_device_info_name(infos.data) as *const u8;
+                core::slice::from_raw_parts(name_ptr, info.name_len as usize)
+            };
+            if String::from_utf8_lossy(name)
+                .to_lowercase()
+                .contains("eraser")
+            {
+                valuators.erasers.insert(info.deviceid);
+            }
+
+            unsafe { sys::xcb_input_xi_device_info_next(addr_of_mut!(infos)) };
+            continue;
+        }
+
+        let mut classes = unsafe { sys::xcb_input_xi_device_info_classes_iterator(infos.data) };
+        while classes.rem > 0 {
+            let class = unsafe { &*classes.data };
+
+            if class.type_ == sys::XCB_INPUT_DEVICE_CLASS_TYPE_SCROLL as u16 {
+                // SAFETY: Scroll Classes start with the same header as every Device Class.
+                let scroll = unsafe { &*classes.data.cast::<sys::xcb_input_scroll_class_t>() };
+                let increment = fp3232_to_f64(&scroll.increment);
+
+                if increment != 0.0 {
+                    let valuator = ScrollValuator {
+                        horizontal: scroll.scroll_type
+                            == sys::XCB_INPUT_SCROLL_TYPE_HORIZONTAL as u16,
+                        increment,
+                        last: None,
+                    };
+                    valuators
+                        .scroll
+                        .insert((info.deviceid, scroll.number), valuator);
+                }
+            } else if class.type_ == sys::XCB_INPUT_DEVICE_CLASS_TYPE_VALUATOR as u16 {
+                // SAFETY: Valuator Classes start with the same header as every Device Class.
+                let class = unsafe { &*classes.data.cast::<sys::xcb_input_valuator_class_t>() };
+                let axis = pen_labels
+                    .iter()
+                    .find(|(label, _)| *label != 0 && *label == class.label)
+                    .map(|(_, axis)| *axis);
+
+                if let Some(axis) = axis {
+                    let valuator = PenValuator {
+                        axis,
+                        range: (fp3232_to_f64(&class.min), fp3232_to_f64(&class.max)),
+                        value: fp3232_to_f64(&class.value),
+                    };
+                    valuators
+                        .pen
+                        .insert((info.deviceid, class.number), valuator);
+                }
+            }
+
+            unsafe { sys::xcb_input_device_class_next(addr_of_mut!(classes)) };
+        }
+
+        unsafe { sys::xcb_input_xi_device_info_next(addr_of_mut!(infos)) };
+    }
+    valuators
+}
+
+/// Returns the numbers of the Valuators that are set in a Valuator Mask, in ascending order.
+fn set_valuators(mask: &[u32]) -> impl Iterator<Item = u16> + '_ {
+    (0..mask.len() * 32)
+        .filter(|number| mask[number / 32] & (1 << (number % 32)) != 0)
+        .map(|number| number as u16)
+}
+
+/// Converts a 16.16 Fixed-Point value.
+pub(crate) fn fp1616_to_f64(value: sys::xcb_input_fp1616_t) -> f64 {
+    f64::from(value) / 65536.0
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,182 @@
+/*
+ *  Crate: Wyn
+ * Module: X11 - Testing
+ */
+
+//! Utilities for driving Windows from automated tests, by posting synthetic User-Input events to them.
+//!
+//! The events are sent straight to the Window with `xcb_send_event`, so they are delivered even if the Window is not focused,
+//! or if there is no Window Manager at all (e.g. under Xvfb).\
+//! They are reported to the `EventHandler` just like real User-Input.
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::errors::{XcbGenericError, XcbGenericResult};
+use super::event_loop::EventLoop;
+use super::inputs::*;
+use super::types::Point;
+use super::window::WindowHandle;
+
+// ================================================================================================================================ //
+
+/// Posts a synthetic Cursor-Move event to the Window, where the `point` is relative to the Window's Content.
+pub fn cursor_move(events: &EventLoop, handle: WindowHandle, point: Point) -> XcbGenericResult<()> {
+    let event = pointer_event(events, handle, sys::XCB_MOTION_NOTIFY, 0, point)?;
+    send_event(events, handle, event)
+}
+
+/// Posts a synthetic Scroll-Wheel event to the Window.\
+/// X11 only reports whole steps, so each delta is rounded to the nearest number of steps.
+pub fn scroll_wheel(
+    events: &EventLoop,
+    handle: WindowHandle,
+    delta_x: f64,
+    delta_y: f64,
+) -> XcbGenericResult<()> {
+    // Scroll-Wheels are Buttons 4-7 on X11, which the Event Loop translates back into deltas.
+    let steps = |delta: f64, negative: u8, positive: u8| {
+        let detail = if delta < 0.0 { negative } else { positive };
+        std::iter::repeat(detail).take(delta.abs().round() as usize)
+    };
+
+    for detail in steps(delta_y, 5, 4).chain(steps(delta_x, 6, 7)) {
+        internal_button(events, handle, detail, true)?;
+        internal_button(events, handle, detail, false)?;
+    }
+    Ok(())
+}
+
+/// Posts a synthetic Button-Press (or Button-Release) event to the Window.\
+/// Buttons without a native equivalent are ignored.
+pub fn button_press(
+    events: &EventLoop,
+    handle: WindowHandle,
+    button: MouseButton,
+    pressed: bool,
+) -> XcbGenericResult<()> {
+    match button.native() {
+        Some(detail) => internal_button(events, handle, detail, pressed),
+        None => Ok(()),
+    }
+}
+
+/// Posts a synthetic Key-Press (or Key-Release) event to the Window.\
+/// Keys that are not on the current Keyboard Mapping are ignored.
+pub fn key_press(
+    events: &EventLoop,
+    handle: WindowHandle,
+    key: Key,
+    pressed: bool,
+) -> XcbGenericResult<()> {
+    let keycode = events.keymap.lock().unwrap().keycode(key);
+
+    let detail = match keycode {
+        Some(detail) => detail,
+        None => return Ok(()),
+    };
+
+    let response_type = if pressed {
+        sys::XCB_KEY_PRESS
+    } else {
+        sys::XCB_KEY_RELEASE
+    };
+    let event = pointer_event(events, handle, response_type, detail, Point::default())?;
+    send_event(events, handle, event)
+}
+
+// ================================================================================================================================ //
+
+/// Posts a synthetic Button event, for the raw X11 Button.
+fn internal_button(
+    events: &EventLoop,
+    handle: WindowHandle,
+    detail: NativeMouseButton,
+    pressed: bool,
+) -> XcbGenericResult<()> {
+    let response_type = if pressed {
+        sys::XCB_BUTTON_PRESS
+    } else {
+        sys::XCB_BUTTON_RELEASE
+    };
+    let event = pointer_event(events, handle, response_type, detail, Point::default())?;
+    send_event(events, handle, event)
+}
+
+/// Constructs a Key, Button, or Motion event, which all share the same layout on X11.\
+/// The `point` is relative to the Window, so it is translated for the Root-relative fields.
+fn pointer_event(
+    events: &EventLoop,
+    handle: WindowHandle,
+    response_type: u32,
+    detail: u8,
+    point: Point,
+) -> XcbGenericResult<sys::xcb_button_press_event_t> {
+    let root = events.connection.screen().root;
+    let event_x = point.x as i16;
+    let event_y = point.y as i16;
+
+    let mut error = null_mut();
+    let cookie = unsafe {
+        sys::xcb_translate_coordinates(events.connection.handle, handle, root, event_x, event_y)
+    };
+    let reply = unsafe {
+        sys::xcb_translate_coordinates_reply(events.connection.handle, cookie, addr_of_mut!(error))
+    };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+
+    if let Some(err) = XcbGenericError::new(error) {
+        return Err(err);
+    }
+    assert!(!reply.is_null());
+
+    Ok(sys::xcb_button_press_event_t {
+        response_type: response_type as u8,
+        detail,
+        sequence: 0,
+        time: sys::XCB_CURRENT_TIME,
+        root,
+        event: handle,
+        child: sys::XCB_NONE,
+        root_x: unsafe { (*reply).dst_x },
+        root_y: unsafe { (*reply).dst_y },
+        event_x,
+        event_y,
+        state: 0,
+        same_screen: true as u8,
+        pad0: 0,
+    })
+}
+
+/// Sends the event to the Window.\
+/// Without an Event Mask, the event is delivered to the Client that created the Window, whether or not it is focused.
+fn send_event(
+    events: &EventLoop,
+    handle: WindowHandle,
+    event: sys::xcb_button_press_event_t,
+) -> XcbGenericResult<()> {
+    let prop = false as u8;
+    let event_mask = sys::XCB_EVENT_MASK_NO_EVENT;
+
+    let event_data = bytes_of!(event);
+
+    let cookie = unsafe {
+        sys::xcb_send_event_checked(
+            events.connection.handle,
+            prop,
+            handle,
+            event_mask,
+            event_data,
+        )
+    };
+
+    events.connection.check_cookie(cookie)
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,91 @@
+/*
+ *  Crate: Wyn
+ * Module: X11 - Touch
+ */
+
+//! Receiving Touch events from XInput 2.2.
+//!
+//! Each finger on a Touchscreen is reported through `TouchBegin`, `TouchUpdate` and `TouchEnd`, with the Touch ID as their detail.\
+//! Selecting them on Wyn's Windows stops the X Server from emulating Mouse events from the first finger on these Windows.\
+//! XInput2 has no way to cancel a touch that was delivered to a client, so `EventHandler::touch_cancel` is never reported.
+//!
+//! ## References
+//! * <https://gitlab.freedesktop.org/xorg/proto/xorgproto/-/blob/master/specs/XI2proto.txt>
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use super::*;
+
+use super::events::TouchUpdate;
+use super::inputs::TouchPhase;
+use super::scroll::fp1616_to_f64;
+use super::types::Point;
+
+// ================================================================================================================================ //
+
+/// The source of Touch events from Touchscreens.
+pub(crate) struct XcbTouch {
+    /// The Major Opcode of the XInput extension, if the X Server supports XInput 2.2.
+    xinput_opcode: Option<u8>,
+}
+
+impl XcbTouch {
+    /// Takes the XInput2 version that was negotiated by `XcbCursors`.
+    pub(crate) fn new(xinput: Option<(u8, u16)>) -> Self {
+        let xinput_opcode = xinput
+            .filter(|(_, minor)| *minor >= 2)
+            .map(|(opcode, _)| opcode);
+
+        Self { xinput_opcode }
+    }
+
+    /// Returns whether or not the X Server supports Touch events.
+    pub(crate) fn is_supported(&self) -> bool {
+        self.xinput_opcode.is_some()
+    }
+
+    /// Returns the mask of XInput2 events to select on Windows, which must include every Touch event (or none).
+    pub(crate) fn window_mask(&self) -> u32 {
+        if self.xinput_opcode.is_none() {
+            return 0;
+        }
+
+        (sys::XCB_INPUT_XI_EVENT_MASK_TOUCH_BEGIN
+            | sys::XCB_INPUT_XI_EVENT_MASK_TOUCH_UPDATE
+            | sys::XCB_INPUT_XI_EVENT_MASK_TOUCH_END) as u32
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Reads a touch from an XInput2 `TouchBegin`, `TouchUpdate` or `TouchEnd` event.\
+    /// Returns `None` for other Generic Events.
+    pub(crate) fn touch(&self, event: &sys::xcb_ge_generic_event_t) -> Option<TouchUpdate> {
+        let opcode = self.xinput_opcode?;
+        if event.extension != opcode {
+            return None;
+        }
+
+        let phase = match event.event_type as u32 {
+            sys::XCB_INPUT_TOUCH_BEGIN => TouchPhase::Begin,
+            sys::XCB_INPUT_TOUCH_UPDATE => TouchPhase::Move,
+            sys::XCB_INPUT_TOUCH_END => TouchPhase::End,
+            _ => return None,
+        };
+
+        // SAFETY: Touch events share the layout of `ButtonPress` events, and Generic Events are read whole.
+        let evt = unsafe {
+            &*(event as *const sys::xcb_ge_generic_event_t)
+                .cast::<sys::xcb_input_touch_begin_event_t>()
+        };
+
+        Some(TouchUpdate {
+            handle: evt.event,
+            phase,
+            id: evt.detail,
+            point: Point::new(fp1616_to_f64(evt.event_x), fp1616_to_f64(evt.event_y)),
+        })
+    }
+}
+
+// ================================================================================================================================ //
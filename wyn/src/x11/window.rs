@@ -10,9 +10,11 @@
 #[allow(unused_imports)]
 use super::*;
 
-use super::errors::XcbGenericError;
+use super::cursor::{select_xi_events, Cursor};
+use super::errors::{XcbGenericError, XcbGenericResult};
 use super::event_loop::EventLoop;
-use super::types::{Coord, Extent, Rect};
+use super::screen::{Screen, VideoMode};
+use super::types::{Coord, Extent, NativePoint, Point, Rect, ScaleFactor};
 
 // ================================================================================================================================ //
 
@@ -52,7 +54,9 @@ pub type WindowHandle = sys::xcb_window_t;
 /// Nonzero wrapper for Window Handles.
 type NonZeroWindowHandle = NonZero<sys::xcb_window_t>;
 
-/// Wrapper for Window objects.
+/// Wrapper for Window objects.\
+/// Opening and Closing are always routed through the Event Thread (so the `EventHandler` observes them there),
+/// while every other request may be issued from any thread, as XCB Connections are thread-safe.
 #[repr(transparent)]
 pub struct Window(NonZeroWindowHandle);
 
@@ -89,19 +93,44 @@ impl Window {
     // ---------------------------------------------------------------- //
 
     /// Attempts to open a new Window.
-    pub fn open(events: &EventLoop) -> Window {
-        let window = Self::internal_open(events);
-        window.internal_set_attributes(events);
-        window
+    pub fn open(events: &EventLoop) -> XcbGenericResult<Window> {
+        // SAFETY: Must be called on the Event Thread.
+        let fut = events.execute(move || unsafe {
+            let events = event_loop::EVENTLOOP.unwrap();
+
+            let window = Self::internal_open(events)?;
+            if let Err(err) = window.internal_set_attributes(events) {
+                let _ = window.internal_close(events);
+                events.connection.flush();
+                return Err(err);
+            }
+            events.connection.flush();
+
+            events.handler.window_open(events, window.handle());
+            Ok(window)
+        });
+        fut.wait()
     }
 
     /// Returns whether or not the Window is Open.
-    pub fn is_open(&self, _events: &EventLoop) -> bool {
-        todo!()
+    pub fn is_open(&self, events: &EventLoop) -> bool {
+        let cookie =
+            unsafe { sys::xcb_get_window_attributes(events.connection.handle, self.handle()) };
+        let reply = unsafe {
+            sys::xcb_get_window_attributes_reply(events.connection.handle, cookie, null_mut())
+        };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+
+        // The request fails once the Window was destroyed.
+        !reply.is_null()
     }
 
     /// Opens a window, without modifying attributes/properties.
-    fn internal_open(events: &EventLoop) -> Window {
+    fn internal_open(events: &EventLoop) -> XcbGenericResult<Window> {
         let screen = events.connection.screen();
 
         let handle = unsafe { sys::xcb_generate_id(events.connection.handle) };
@@ -126,13 +155,13 @@ impl Window {
                 void_of!(value_list),
             )
         };
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)?;
 
-        Window::try_from(handle).expect("Invalid Window Handle.")
+        Ok(Window::try_from(handle).expect("Invalid Window Handle."))
     }
 
     /// Sets the atributes/properties of a newly created Window.
-    fn internal_set_attributes(&self, events: &EventLoop) {
+    fn internal_set_attributes(&self, events: &EventLoop) -> XcbGenericResult<()> {
         let screen = events.connection.screen();
 
         let cookie = unsafe {
@@ -151,7 +180,20 @@ impl Window {
                 data,
             )
         };
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)?;
+
+        events.file_drop.set_aware(events, self.handle());
+
+        // XInput2 selections for the same Device replace each other, so every XInput2 event is selected at once.
+        let xi_mask = events.scrolling.window_mask() | events.touch.window_mask();
+        if xi_mask != 0 {
+            let deviceid = sys::XCB_INPUT_DEVICE_ALL_MASTER as u16;
+            select_xi_events(&events.connection, self.handle(), deviceid, xi_mask)?;
+        }
+
+        events
+            .scale_factors
+            .track(&events.connection, self.handle());
 
         let value_mask = sys::XCB_CW_BACK_PIXEL | sys::XCB_CW_BACKING_PIXEL;
         let value_list = [screen.black_pixel, screen.white_pixel];
@@ -163,27 +205,41 @@ impl Window {
                 void_of!(value_list),
             )
         };
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)
     }
 
     // ---------------------------------------------------------------- //
 
-    /// Closes the Window.
-    pub fn close(self, events: &EventLoop) {
-        self.internal_close(events);
-        events.connection.flush();
+    /// Closes the Window, after reporting it to the Event Handler (like the other backends do).
+    pub fn close(self, events: &EventLoop) -> XcbGenericResult<()> {
+        // SAFETY: Must be called on the Event Thread.
+        let fut = events.execute(move || unsafe {
+            let events = event_loop::EVENTLOOP.unwrap();
+
+            events.handler.window_close(events, self.handle());
+
+            // The Event Handler may have closed the Window itself.
+            if self.is_closed(events) {
+                return Ok(());
+            }
+
+            let res = self.internal_close(events);
+            events.connection.flush();
+            res
+        });
+        fut.wait()
     }
 
     /// Returns whether or not the Window is Closed.
-    pub fn is_closed(&self, _events: &EventLoop) -> bool {
-        todo!()
+    pub fn is_closed(&self, events: &EventLoop) -> bool {
+        !self.is_open(events)
     }
 
     /// Closes the Window, without flushing.
-    fn internal_close(&self, events: &EventLoop) {
+    fn internal_close(&self, events: &EventLoop) -> XcbGenericResult<()> {
         let cookie =
             unsafe { sys::xcb_destroy_window_checked(events.connection.handle, self.handle()) };
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)
     }
 
     // ---------------------------------------------------------------- //
@@ -195,64 +251,77 @@ impl Window {
 impl Window {
     // ---------------------------------------------------------------- //
 
-    /// Makes the Window visible and gives it focus.
-    pub fn focus(&self, events: &EventLoop) {
-        self.internal_show(events);
-        self.internal_raise(events);
-
-        if let Some(atom) =
-            event_loop::NonzeroXcbAtom::new(events.atoms.ewmh_ref()._NET_ACTIVE_WINDOW)
+    /// Makes the Window visible and gives it focus.\
+    /// The Window Manager may map and focus the Window later on, which is reported through `window_visibility` and `window_focus`.
+    pub fn focus(&self, events: &EventLoop) -> XcbGenericResult<()> {
+        self.internal_show(events)?;
+        self.internal_raise(events)?;
+
+        // Window Managers ignore (and the X Server rejects) focusing a Window before it is mapped,
+        // so a Window that is not mapped yet is focused once its `MapNotify` arrives instead.
+        let handle = self.handle();
+        events.pending_focus.lock().unwrap().insert(handle);
+        if self.internal_is_viewable(events) && events.pending_focus.lock().unwrap().remove(&handle)
         {
-            self.internal_focus_wm(events, atom.get())
-        } else {
-            self.internal_focus_fallback(events);
+            self.internal_focus(events)?;
         }
 
         events.connection.flush();
+        Ok(())
     }
 
     /// Returns whether or not the Window is Focused.
-    pub fn is_focused(&self, _events: &EventLoop) -> bool {
-        todo!()
-    }
-
-    /// Focuses the Window, with help from the Window Manager, without flushing.
-    fn internal_focus_wm(&self, events: &EventLoop, net_active_window: sys::xcb_atom_t) {
-        let screen = events.connection.screen();
+    pub fn is_focused(&self, events: &EventLoop) -> bool {
+        let cookie = unsafe { sys::xcb_get_input_focus(events.connection.handle) };
+        let reply =
+            unsafe { sys::xcb_get_input_focus_reply(events.connection.handle, cookie, null_mut()) };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
 
-        let event = sys::xcb_client_message_event_t {
-            response_type: sys::XCB_CLIENT_MESSAGE as u8,
-            window: self.handle(),
-            format: 32,
-            type_: net_active_window,
-            data: sys::xcb_client_message_data_t {
-                data32: [1, 0, 0, 0, 0],
-            },
-            sequence: 0,
-        };
+        unsafe { reply.as_ref() }.map_or(false, |rep| rep.focus == self.handle())
+    }
 
-        let prop = false as u8;
-        let dest = screen.root;
-        let event_mask =
-            sys::XCB_EVENT_MASK_SUBSTRUCTURE_NOTIFY | sys::XCB_EVENT_MASK_SUBSTRUCTURE_REDIRECT;
+    /// Focuses a Window that was mapped after `focus` was called, and flushes.\
+    /// Errors are ignored, since the Window may have been closed in the meantime.
+    pub(crate) fn internal_focus_pending(events: &EventLoop, handle: WindowHandle) {
+        if !events.pending_focus.lock().unwrap().remove(&handle) {
+            return;
+        }
 
-        let event_data = bytes_of!(event);
+        if let Ok(window) = Window::try_from(handle) {
+            let _ = window.internal_focus(events);
+            events.connection.flush();
+        }
+    }
 
-        let cookie = unsafe {
-            sys::xcb_send_event_checked(
-                events.connection.handle,
-                prop,
-                dest,
-                event_mask,
-                event_data,
-            )
-        };
+    /// Focuses the Window through the Window Manager if there is one, or directly otherwise, without flushing.
+    fn internal_focus(&self, events: &EventLoop) -> XcbGenericResult<()> {
+        let net_active_window = events.atoms.ewmh_ref()._NET_ACTIVE_WINDOW;
+        if internal_wm_supports(events, net_active_window) {
+            self.internal_focus_wm(events, net_active_window)
+        } else {
+            self.internal_focus_fallback(events)
+        }
+    }
 
-        events.connection.check_cookie(cookie).unwrap();
+    /// Focuses the Window, with help from the Window Manager, without flushing.
+    fn internal_focus_wm(
+        &self,
+        events: &EventLoop,
+        net_active_window: sys::xcb_atom_t,
+    ) -> XcbGenericResult<()> {
+        /// Source Indication for requests from normal applications.
+        const SOURCE_APPLICATION: u32 = 1;
+
+        let data = [SOURCE_APPLICATION, sys::XCB_CURRENT_TIME, 0, 0, 0];
+        self.internal_send_wm_message(events, net_active_window, data)
     }
 
     /// Focuses the Window, without flushing.
-    fn internal_focus_fallback(&self, events: &EventLoop) {
+    fn internal_focus_fallback(&self, events: &EventLoop) -> XcbGenericResult<()> {
         let cookie = unsafe {
             sys::xcb_set_input_focus_checked(
                 events.connection.handle,
@@ -262,32 +331,54 @@ impl Window {
             )
         };
 
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)
     }
 
     // ---------------------------------------------------------------- //
 
-    /// Makes the Window visible.
-    pub fn show(&self, events: &EventLoop) {
-        self.internal_show(events);
-        self.internal_raise(events);
+    /// Makes the Window visible.\
+    /// The Window Manager may map the Window later on, which is reported through `window_visibility`.
+    pub fn show(&self, events: &EventLoop) -> XcbGenericResult<()> {
+        self.internal_show(events)?;
+        self.internal_raise(events)?;
+
         events.connection.flush();
+        Ok(())
+    }
+
+    /// Returns whether or not the Window is Visible.\
+    /// Minimized Windows are unmapped by the Window Manager, but still count as Visible (as they do on Win32).
+    pub fn is_visible(&self, events: &EventLoop) -> bool {
+        self.internal_is_viewable(events) || self.is_minimized(events)
     }
 
-    /// Returns whether or not the Window is Visible.
-    pub fn is_visible(&self, _events: &EventLoop) -> bool {
-        todo!()
+    /// Returns whether or not the Window is mapped (along with its ancestors).
+    fn internal_is_viewable(&self, events: &EventLoop) -> bool {
+        let cookie =
+            unsafe { sys::xcb_get_window_attributes(events.connection.handle, self.handle()) };
+        let reply = unsafe {
+            sys::xcb_get_window_attributes_reply(events.connection.handle, cookie, null_mut())
+        };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+
+        unsafe { reply.as_ref() }.map_or(false, |rep| {
+            rep.map_state == sys::XCB_MAP_STATE_VIEWABLE as u8
+        })
     }
 
     /// Shows the window, without flushing.
-    fn internal_show(&self, events: &EventLoop) {
+    fn internal_show(&self, events: &EventLoop) -> XcbGenericResult<()> {
         let cookie =
             unsafe { sys::xcb_map_window_checked(events.connection.handle, self.handle()) };
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)
     }
 
     /// Raises the window, without flushing.
-    fn internal_raise(&self, events: &EventLoop) {
+    fn internal_raise(&self, events: &EventLoop) -> XcbGenericResult<()> {
         let cookie = unsafe {
             sys::xcb_circulate_window_checked(
                 events.connection.handle,
@@ -295,27 +386,31 @@ impl Window {
                 self.handle(),
             )
         };
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)
     }
 
     // ---------------------------------------------------------------- //
 
     /// Makes the Window invisible.
-    pub fn hide(&self, events: &EventLoop) {
-        self.internal_hide(events);
+    pub fn hide(&self, events: &EventLoop) -> XcbGenericResult<()> {
+        // A Window that is hidden before it was mapped must not be focused once it is mapped later on.
+        events.pending_focus.lock().unwrap().remove(&self.handle());
+        self.internal_hide(events)?;
+
         events.connection.flush();
+        Ok(())
     }
 
     /// Returns whether or not the Window is Hidden.
-    pub fn is_hidden(&self, _events: &EventLoop) -> bool {
-        todo!()
+    pub fn is_hidden(&self, events: &EventLoop) -> bool {
+        !self.is_visible(events)
     }
 
     /// Hides the window, without flushing.
-    fn internal_hide(&self, events: &EventLoop) {
+    fn internal_hide(&self, events: &EventLoop) -> XcbGenericResult<()> {
         let cookie =
             unsafe { sys::xcb_unmap_window_checked(events.connection.handle, self.handle()) };
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)
     }
 
     // ---------------------------------------------------------------- //
@@ -326,13 +421,14 @@ impl Window {
 /// Rename
 impl Window {
     /// Sets the Name of the Window.
-    pub fn rename(&self, events: &EventLoop, name: &str) {
-        self.internal_rename(events, name);
+    pub fn rename(&self, events: &EventLoop, name: &str) -> XcbGenericResult<()> {
+        self.internal_rename(events, name)?;
         events.connection.flush();
+        Ok(())
     }
 
     /// Renames the window, without flushing.
-    pub fn internal_rename(&self, events: &EventLoop, name: &str) {
+    pub fn internal_rename(&self, events: &EventLoop, name: &str) -> XcbGenericResult<()> {
         let cookie = unsafe {
             sys::xcb_icccm_set_wm_name_checked(
                 events.connection.handle,
@@ -343,11 +439,11 @@ impl Window {
                 name.as_ptr() as *const c_char,
             )
         };
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)
     }
 
     /// Gets the Name of the Window.
-    pub fn name(&self, events: &EventLoop) -> String {
+    pub fn name(&self, events: &EventLoop) -> XcbGenericResult<String> {
         let mut error = null_mut();
         let mut prop = unsafe { zeroed() };
 
@@ -360,8 +456,14 @@ impl Window {
                 addr_of_mut!(error),
             )
         };
-        assert!(XcbGenericError::new(error).is_none());
-        assert_eq!(reply, 1);
+        if let Some(err) = XcbGenericError::new(error) {
+            return Err(err);
+        }
+
+        // The property does not exist until the Window is named for the first time.
+        if reply != 1 {
+            return Ok(String::new());
+        }
 
         assert_eq!(prop.encoding, events.atoms.ewmh_ref().UTF8_STRING);
 
@@ -370,7 +472,7 @@ impl Window {
         assert!(!dat.is_null());
 
         let slice = unsafe { core::slice::from_raw_parts(dat, len) };
-        String::from_utf8_lossy(slice).to_string()
+        Ok(String::from_utf8_lossy(slice).to_string())
     }
 }
 
@@ -381,7 +483,7 @@ impl Window {
     // ---------------------------------------------------------------- //
 
     /// Sets the rectangle of the Inner-Content of the Window.
-    pub fn reposition_content(&self, events: &EventLoop, rect: Rect) {
+    pub fn reposition_content(&self, events: &EventLoop, rect: Rect) -> XcbGenericResult<()> {
         let mask = sys::XCB_CONFIG_WINDOW_X
             | sys::XCB_CONFIG_WINDOW_Y
             | sys::XCB_CONFIG_WINDOW_WIDTH
@@ -407,9 +509,10 @@ impl Window {
                 void_of!(list),
             )
         };
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)?;
 
         events.connection.flush();
+        Ok(())
     }
 
     /// Retrieves [Left, Right, Top, Bottom] Window Margins.
@@ -464,20 +567,26 @@ impl Window {
             )
         };
         assert!(XcbGenericError::new(error).is_none());
-        assert_eq!(reply, 1);
+
+        // The property does not exist without a (reparenting) Window Manager, in which case there is no frame.
+        if reply != 1 {
+            return None;
+        }
 
         Some([extents.left, extents.right, extents.top, extents.bottom])
     }
 
     /// Gets the rectangle of the Inner-Content of the Window.
-    pub fn content_rect(&self, events: &EventLoop) -> Rect {
+    pub fn content_rect(&self, events: &EventLoop) -> XcbGenericResult<Rect> {
         let geom = {
             let mut error = null_mut();
             let cookie = unsafe { sys::xcb_get_geometry(events.connection.handle, self.handle()) };
             let reply = unsafe {
                 sys::xcb_get_geometry_reply(events.connection.handle, cookie, addr_of_mut!(error))
             };
-            assert!(XcbGenericError::new(error).is_none());
+            if let Some(err) = XcbGenericError::new(error) {
+                return Err(err);
+            }
             assert!(!reply.is_null());
             reply
         };
@@ -491,7 +600,9 @@ impl Window {
             let reply = unsafe {
                 sys::xcb_query_tree_reply(events.connection.handle, cookie, addr_of_mut!(error))
             };
-            assert!(XcbGenericError::new(error).is_none());
+            if let Some(err) = XcbGenericError::new(error) {
+                return Err(err);
+            }
             assert!(!reply.is_null());
             reply
         };
@@ -517,7 +628,9 @@ impl Window {
                     addr_of_mut!(error),
                 )
             };
-            assert!(XcbGenericError::new(error).is_none());
+            if let Some(err) = XcbGenericError::new(error) {
+                return Err(err);
+            }
             assert!(!reply.is_null());
             reply
         };
@@ -532,14 +645,14 @@ impl Window {
             let h = (*geom).height;
             let _bw = (*geom).border_width;
 
-            Rect::new(x as _, y as _, w as _, h as _)
+            Ok(Rect::new(x as _, y as _, w as _, h as _))
         }
     }
 
     // ---------------------------------------------------------------- //
 
     /// Sets the rectangle of the Outer-Border of the Window.
-    pub fn reposition_border(&self, events: &EventLoop, rect: Rect) {
+    pub fn reposition_border(&self, events: &EventLoop, rect: Rect) -> XcbGenericResult<()> {
         let mask = sys::XCB_CONFIG_WINDOW_X
             | sys::XCB_CONFIG_WINDOW_Y
             | sys::XCB_CONFIG_WINDOW_WIDTH
@@ -565,21 +678,22 @@ impl Window {
                 void_of!(list),
             )
         };
-        events.connection.check_cookie(cookie).unwrap();
+        events.connection.check_cookie(cookie)?;
 
         events.connection.flush();
+        Ok(())
     }
 
     /// Gets the rectangle of the Outer-Border of the Window.
-    pub fn border_rect(&self, events: &EventLoop) -> Rect {
-        let mut rect = self.content_rect(events);
+    pub fn border_rect(&self, events: &EventLoop) -> XcbGenericResult<Rect> {
+        let mut rect = self.content_rect(events)?;
         if let Some(margins) = self.internal_margins(events) {
             rect.origin.x -= margins[0] as Coord;
             rect.origin.y -= margins[2] as Coord;
             rect.size.w += (margins[0] + margins[1]) as Extent;
             rect.size.h += (margins[2] + margins[3]) as Extent;
         }
-        rect
+        Ok(rect)
     }
 
     // ---------------------------------------------------------------- //
@@ -592,44 +706,124 @@ impl Window {
     // ---------------------------------------------------------------- //
 
     /// Minimizes the Window.
-    pub fn minimize(&self, _events: &EventLoop) {
-        todo!()
+    pub fn minimize(&self, events: &EventLoop) -> XcbGenericResult<()> {
+        // ICCCM 4.1.4: Iconify requests are sent to the Window Manager through `WM_CHANGE_STATE`.
+        let data = [sys::XCB_ICCCM_WM_STATE_ICONIC, 0, 0, 0, 0];
+        self.internal_send_wm_message(events, events.atoms.wm_change_state.get(), data)?;
+
+        events.connection.flush();
+        Ok(())
     }
 
     /// Returns whether or not the Window is Minimized.
-    pub fn is_minimized(&self, _events: &EventLoop) -> bool {
-        todo!()
+    pub fn is_minimized(&self, events: &EventLoop) -> bool {
+        let hidden = events.atoms.ewmh_ref()._NET_WM_STATE_HIDDEN;
+        self.internal_wm_state(events).contains(&hidden)
     }
 
     // ---------------------------------------------------------------- //
 
     /// Maximizes the Window.
-    pub fn maximize(&self, _events: &EventLoop) {
-        todo!()
+    pub fn maximize(&self, events: &EventLoop) -> XcbGenericResult<()> {
+        let ewmh = events.atoms.ewmh_ref();
+        self.internal_change_wm_state(
+            events,
+            sys::XCB_EWMH_WM_STATE_ADD,
+            ewmh._NET_WM_STATE_MAXIMIZED_VERT,
+            ewmh._NET_WM_STATE_MAXIMIZED_HORZ,
+        )?;
+
+        events.connection.flush();
+        Ok(())
     }
 
     /// Returns whether or not the Window is Maximized.
-    pub fn is_maximized(&self, _events: &EventLoop) -> bool {
-        todo!()
+    pub fn is_maximized(&self, events: &EventLoop) -> bool {
+        let ewmh = events.atoms.ewmh_ref();
+        let state = self.internal_wm_state(events);
+        state.contains(&ewmh._NET_WM_STATE_MAXIMIZED_VERT)
+            && state.contains(&ewmh._NET_WM_STATE_MAXIMIZED_HORZ)
     }
 
     // ---------------------------------------------------------------- //
 
-    /// Fullscreens the Window.
-    pub fn fullscreen(&self, _events: &EventLoop) {
-        todo!()
+    /// Fullscreens the Window. (Borderless Fullscreen)
+    pub fn fullscreen(&self, events: &EventLoop) -> XcbGenericResult<()> {
+        let ewmh = events.atoms.ewmh_ref();
+        self.internal_change_wm_state(
+            events,
+            sys::XCB_EWMH_WM_STATE_ADD,
+            ewmh._NET_WM_STATE_FULLSCREEN,
+            sys::XCB_ATOM_NONE,
+        )?;
+
+        events.connection.flush();
+        Ok(())
+    }
+
+    /// Switches the Screen to the Video Mode, and Fullscreens the Window over it. (Exclusive Fullscreen)\
+    /// The closest Video Mode of the Screen with the same resolution is used, and `false` is returned if there is none.\
+    /// The previous Video Mode is restored once the Window is restored, or is closed.
+    pub fn fullscreen_exclusive(
+        &self,
+        events: &EventLoop,
+        screen: &Screen,
+        mode: &VideoMode,
+    ) -> XcbGenericResult<bool> {
+        self.restore(events)?;
+        if !events
+            .video_modes
+            .switch(events, self.handle(), screen, mode)
+        {
+            return Ok(false);
+        }
+
+        // The Window Manager fullscreens the Window over whichever Screen it is on, so it is first moved onto the switched Screen.
+        self.reposition_border(events, screen.rect(events))?;
+        self.fullscreen(events)?;
+        Ok(true)
     }
 
     /// Returns whether or not the Window is Fullscreened.
-    pub fn is_fullscreen(&self, _events: &EventLoop) -> bool {
-        todo!()
+    pub fn is_fullscreen(&self, events: &EventLoop) -> bool {
+        let fullscreen = events.atoms.ewmh_ref()._NET_WM_STATE_FULLSCREEN;
+        self.internal_wm_state(events).contains(&fullscreen)
     }
 
     // ---------------------------------------------------------------- //
 
     /// Restores the Window from Minimized/Maximized/Fullscreen state.
-    pub fn restore(&self, _events: &EventLoop) {
-        todo!()
+    ///
+    /// Only the most recent state is undone, so restoring a Minimized Window that was Maximized leaves it Maximized.\
+    /// Leaving Exclusive Fullscreen also restores the Video Mode of the Screen.
+    pub fn restore(&self, events: &EventLoop) -> XcbGenericResult<()> {
+        let ewmh = events.atoms.ewmh_ref();
+
+        if self.is_minimized(events) {
+            // ICCCM 4.1.4: Mapping an Iconic Window returns it to the Normal state.
+            self.internal_show(events)?;
+            self.internal_raise(events)?;
+        } else if self.is_fullscreen(events) {
+            self.internal_change_wm_state(
+                events,
+                sys::XCB_EWMH_WM_STATE_REMOVE,
+                ewmh._NET_WM_STATE_FULLSCREEN,
+                sys::XCB_ATOM_NONE,
+            )?;
+            events
+                .video_modes
+                .restore(&events.connection, self.handle());
+        } else if self.is_maximized(events) {
+            self.internal_change_wm_state(
+                events,
+                sys::XCB_EWMH_WM_STATE_REMOVE,
+                ewmh._NET_WM_STATE_MAXIMIZED_VERT,
+                ewmh._NET_WM_STATE_MAXIMIZED_HORZ,
+            )?;
+        }
+
+        events.connection.flush();
+        Ok(())
     }
 
     /// Returns whether or not the Window isn't Minimized, Maximized, or Fullscreened.
@@ -638,12 +832,266 @@ impl Window {
     }
 
     // ---------------------------------------------------------------- //
+
+    /// Requests the Window Manager to add/remove/toggle up to two `_NET_WM_STATE` properties, without flushing.
+    fn internal_change_wm_state(
+        &self,
+        events: &EventLoop,
+        action: sys::xcb_ewmh_wm_state_action_t,
+        first: sys::xcb_atom_t,
+        second: sys::xcb_atom_t,
+    ) -> XcbGenericResult<()> {
+        /// Source Indication for requests from normal applications.
+        const SOURCE_APPLICATION: u32 = 1;
+
+        let net_wm_state = events.atoms.ewmh_ref()._NET_WM_STATE;
+        let data = [action, first, second, SOURCE_APPLICATION, 0];
+        self.internal_send_wm_message(events, net_wm_state, data)
+    }
+
+    /// Sends a Client-Message about this Window to the Window Manager (via the Root Window), without flushing.
+    fn internal_send_wm_message(
+        &self,
+        events: &EventLoop,
+        message_type: sys::xcb_atom_t,
+        data: [u32; 5],
+    ) -> XcbGenericResult<()> {
+        let screen = events.connection.screen();
+
+        let event = sys::xcb_client_message_event_t {
+            response_type: sys::XCB_CLIENT_MESSAGE as u8,
+            window: self.handle(),
+            format: 32,
+            type_: message_type,
+            data: sys::xcb_client_message_data_t { data32: data },
+            sequence: 0,
+        };
+
+        let prop = false as u8;
+        let dest = screen.root;
+        let event_mask =
+            sys::XCB_EVENT_MASK_SUBSTRUCTURE_NOTIFY | sys::XCB_EVENT_MASK_SUBSTRUCTURE_REDIRECT;
+
+        let event_data = bytes_of!(event);
+
+        let cookie = unsafe {
+            sys::xcb_send_event_checked(
+                events.connection.handle,
+                prop,
+                dest,
+                event_mask,
+                event_data,
+            )
+        };
+
+        events.connection.check_cookie(cookie)
+    }
+
+    /// Reads back the list of `_NET_WM_STATE` Atoms currently set on the Window.
+    fn internal_wm_state(&self, events: &EventLoop) -> Vec<sys::xcb_atom_t> {
+        let ewmh = events.atoms.ewmh_ptr();
+
+        let mut error = null_mut();
+        let mut state: sys::xcb_ewmh_get_atoms_reply_t = unsafe { zeroed() };
+
+        let cookie = unsafe { sys::xcb_ewmh_get_wm_state(ewmh, self.handle()) };
+        let reply = unsafe {
+            sys::xcb_ewmh_get_wm_state_reply(ewmh, cookie, addr_of_mut!(state), addr_of_mut!(error))
+        };
+        assert!(XcbGenericError::new(error).is_none());
+
+        // The property does not exist until the Window Manager sets a state for the first time.
+        if reply != 1 {
+            return Vec::new();
+        }
+        let _state_wipe = defer(|| unsafe {
+            sys::xcb_ewmh_get_atoms_reply_wipe(addr_of_mut!(state));
+        });
+
+        let len = state.atoms_len as usize;
+        let dat = state.atoms as *const sys::xcb_atom_t;
+        assert!(!dat.is_null());
+
+        unsafe { core::slice::from_raw_parts(dat, len) }.to_vec()
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Returns whether or not a Window Manager is running, which lists the Atom in `_NET_SUPPORTED`.
+fn internal_wm_supports(events: &EventLoop, atom: sys::xcb_atom_t) -> bool {
+    let screen = events.connection.screen();
+    let net_supported = events.atoms.ewmh_ref()._NET_SUPPORTED;
+
+    let cookie = unsafe {
+        sys::xcb_get_property(
+            events.connection.handle,
+            false as u8,
+            screen.root,
+            net_supported,
+            sys::XCB_ATOM_ATOM,
+            0,
+            u32::MAX,
+        )
+    };
+    let reply =
+        unsafe { sys::xcb_get_property_reply(events.connection.handle, cookie, null_mut()) };
+    let _reply_free = defer(|| {
+        if !reply.is_null() {
+            unsafe { sys::free(reply as *mut c_void) };
+        }
+    });
+
+    match unsafe { reply.as_ref() } {
+        Some(rep) if (rep.format == 32) && (atom != sys::XCB_ATOM_NONE) => {
+            let len = unsafe { sys::xcb_get_property_value_length(reply) } as usize;
+            let dat = unsafe { sys::xcb_get_property_value(reply) } as *const sys::xcb_atom_t;
+            assert!(!dat.is_null());
+
+            let supported = unsafe { core::slice::from_raw_parts(dat, len / size_of::<u32>()) };
+            supported.contains(&atom)
+        }
+        _ => false,
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Screens
+impl Window {
+    /// Returns the the closest Screen the Window is currently occupying.\
+    /// That is the Screen which contains the center of the Window.
+    pub fn screen(&self, events: &EventLoop) -> Screen {
+        Screen::of_window(events, self.handle())
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Scale Factor
+impl Window {
+    /// Returns the Scale Factor of the Window, which its UI should be scaled by.\
+    /// Follows the Monitor that contains the center of the Window.
+    pub fn scale_factor(&self, events: &EventLoop) -> ScaleFactor {
+        events
+            .scale_factors
+            .window_scale_factor(&events.connection, self.handle())
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Redraw
+impl Window {
+    /// Requests the Window to redraw its contents.\
+    /// Clearing the whole Window (with exposures) makes the X Server send it an `Expose` event.
+    pub fn request_redraw(&self, events: &EventLoop) {
+        // An error (e.g. for a closed Window) only means that there is nothing to redraw.
+        unsafe {
+            sys::xcb_clear_area(
+                events.connection.handle,
+                true as u8,
+                self.handle(),
+                0,
+                0,
+                0,
+                0,
+            )
+        };
+        events.connection.flush();
+    }
+}
+
+// ================================================================================================================================ //
+
+/// Cursor
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Sets the appearance of the Mouse-Cursor while it is over the Window.
+    pub fn set_cursor(&self, events: &EventLoop, cursor: &Cursor) -> XcbGenericResult<()> {
+        let res = events
+            .cursors
+            .set_cursor(&events.connection, self.handle(), cursor);
+
+        events.connection.flush();
+        res
+    }
+
+    /// Shows or hides the Mouse-Cursor while it is over the Window.
+    pub fn set_cursor_visible(&self, events: &EventLoop, visible: bool) -> XcbGenericResult<()> {
+        let res = events
+            .cursors
+            .set_visible(&events.connection, self.handle(), visible);
+
+        events.connection.flush();
+        res
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Moves the Mouse-Cursor to the point, relative to the Inner-Content of the Window.
+    pub fn warp_cursor(&self, events: &EventLoop, point: Point) -> XcbGenericResult<()> {
+        let xcb_pt = NativePoint::from(point);
+        let cookie = unsafe {
+            sys::xcb_warp_pointer_checked(
+                events.connection.handle,
+                sys::XCB_NONE,
+                self.handle(),
+                0,
+                0,
+                0,
+                0,
+                xcb_pt.x,
+                xcb_pt.y,
+            )
+        };
+        events.connection.check_cookie(cookie)
+    }
+
+    /// Grabs the Mouse-Cursor, confining it to the Inner-Content of the Window (or releases it).\
+    /// The Cursor is released while the Window is unfocused, and confined again once it is refocused.\
+    /// Combined with hiding the Cursor and `EventHandler::raw_motion`, this suits First-Person camera controls.
+    pub fn grab_cursor(&self, events: &EventLoop, grab: bool) -> XcbGenericResult<()> {
+        let res = events
+            .cursors
+            .set_grabbed(&events.connection, self.handle(), grab);
+
+        events.connection.flush();
+        res
+    }
+
+    // ---------------------------------------------------------------- //
+}
+
+// ================================================================================================================================ //
+
+/// Input Method
+impl Window {
+    // ---------------------------------------------------------------- //
+
+    /// Allows (or disallows) Input Methods to compose text in the Window.\
+    /// Input Methods are disallowed by default, since they would intercept Key presses (e.g. for game controls).
+    pub fn set_ime_allowed(&self, events: &EventLoop, allowed: bool) -> XcbGenericResult<()> {
+        events.ime.set_allowed(events, self.handle(), allowed);
+        Ok(())
+    }
+
+    /// Sets the area being edited, relative to the Inner-Content of the Window.\
+    /// The Input Method places its candidate window next to it, without covering it.
+    pub fn set_ime_position(&self, events: &EventLoop, rect: Rect) -> XcbGenericResult<()> {
+        events.ime.set_position(events, self.handle(), rect)
+    }
+
+    // ---------------------------------------------------------------- //
 }
 
 // ================================================================================================================================ //
 
 /// The appearance and actions of a Window.
-#[allow(unused)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct WindowType {
     /// The Window Style.
     pub style: WindowStyle,
@@ -652,16 +1100,18 @@ pub struct WindowType {
 }
 
 /// The visual appearance of a Window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum WindowStyle {
-    /// The Window has a Caption/Title-bar.
+    /// The Window has a Titlebar.
     Captioned,
-    /// The Window has a thin frame.
+    /// The Window has a Thin Frame.
     Bordered,
-    /// The Window has no frame.
+    /// The Window has No Frame.
     Borderless,
 }
 
 /// The possible user-interactions on a Window.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct WindowActions {
     /// Whether or not the user can move the window.
     pub movable: bool,
@@ -671,18 +1121,308 @@ pub struct WindowActions {
     pub resizable: bool,
 }
 
+/// The layout of the `_MOTIF_WM_HINTS` property, which most Window Managers still honor for decorations.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct MotifHints {
+    /// Which of the following fields are valid.
+    flags: u32,
+    /// The functions the Window Manager should offer.
+    functions: u32,
+    /// The decorations the Window Manager should draw.
+    decorations: u32,
+    /// The input mode (unused).
+    input_mode: i32,
+    /// The status (unused).
+    status: u32,
+}
+
+#[allow(clippy::missing_docs_in_private_items)]
+impl MotifHints {
+    const FLAG_FUNCTIONS: u32 = 1 << 0;
+    const FLAG_DECORATIONS: u32 = 1 << 1;
+
+    const FUNC_ALL: u32 = 1 << 0;
+    const FUNC_RESIZE: u32 = 1 << 1;
+    const FUNC_MOVE: u32 = 1 << 2;
+    const FUNC_MINIMIZE: u32 = 1 << 3;
+    const FUNC_MAXIMIZE: u32 = 1 << 4;
+    const FUNC_CLOSE: u32 = 1 << 5;
+
+    const DECOR_ALL: u32 = 1 << 0;
+    const DECOR_BORDER: u32 = 1 << 1;
+    const DECOR_TITLE: u32 = 1 << 3;
+
+    /// The number of 32-bit elements in the property.
+    const LEN: u32 = (size_of::<Self>() / size_of::<u32>()) as u32;
+}
+
 // ---------------------------------------------------------------- //
 
 /// Styles - Actions
 impl Window {
+    /// Sets the Style of a Window.
+    pub fn set_style(&self, events: &EventLoop, style: WindowStyle) -> XcbGenericResult<()> {
+        let mut hints = self.internal_motif_hints(events)?.unwrap_or_default();
+
+        hints.flags |= MotifHints::FLAG_DECORATIONS;
+        hints.decorations = match style {
+            WindowStyle::Captioned => MotifHints::DECOR_ALL,
+            WindowStyle::Bordered => MotifHints::DECOR_BORDER,
+            WindowStyle::Borderless => 0,
+        };
+
+        self.internal_set_motif_hints(events, hints)?;
+
+        events.connection.flush();
+        Ok(())
+    }
+
+    /// Gets the Style of a Window.
+    pub fn style(&self, events: &EventLoop) -> XcbGenericResult<WindowStyle> {
+        let hints = self.internal_motif_hints(events)?.unwrap_or_default();
+
+        // Without decoration hints, the Window Manager draws the full frame.
+        if (hints.flags & MotifHints::FLAG_DECORATIONS) == 0 {
+            return Ok(WindowStyle::Captioned);
+        }
+
+        let has_caption =
+            (hints.decorations & (MotifHints::DECOR_ALL | MotifHints::DECOR_TITLE)) != 0;
+        let has_border = (hints.decorations & MotifHints::DECOR_BORDER) != 0;
+
+        if has_caption {
+            Ok(WindowStyle::Captioned)
+        } else if has_border {
+            Ok(WindowStyle::Bordered)
+        } else {
+            Ok(WindowStyle::Borderless)
+        }
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Sets the Actions of a Window.\
+    /// They are requested through `_MOTIF_WM_HINTS` and `WM_NORMAL_HINTS`, since `_NET_WM_ALLOWED_ACTIONS` belongs to the Window Manager.
+    pub fn set_actions(&self, events: &EventLoop, actions: WindowActions) -> XcbGenericResult<()> {
+        // Motif Functions, for Window Managers that only understand the older hints.
+        let mut hints = self.internal_motif_hints(events)?.unwrap_or_default();
+
+        hints.flags |= MotifHints::FLAG_FUNCTIONS;
+        hints.functions = MotifHints::FUNC_MINIMIZE;
+        if actions.movable {
+            hints.functions |= MotifHints::FUNC_MOVE;
+        }
+        if actions.closeable {
+            hints.functions |= MotifHints::FUNC_CLOSE;
+        }
+        if actions.resizable {
+            hints.functions |= MotifHints::FUNC_RESIZE | MotifHints::FUNC_MAXIMIZE;
+        }
+
+        self.internal_set_motif_hints(events, hints)?;
+
+        // ICCCM Normal Hints: A Window with equal min/max sizes cannot be resized.
+        let mut size_hints = self.internal_normal_hints(events)?;
+
+        size_hints.flags &=
+            !(sys::XCB_ICCCM_SIZE_HINT_P_MIN_SIZE | sys::XCB_ICCCM_SIZE_HINT_P_MAX_SIZE);
+        if !actions.resizable {
+            let rect = self.content_rect(events)?;
+            let w = rect.size.w as i32;
+            let h = rect.size.h as i32;
+            unsafe {
+                sys::xcb_icccm_size_hints_set_min_size(addr_of_mut!(size_hints), w, h);
+                sys::xcb_icccm_size_hints_set_max_size(addr_of_mut!(size_hints), w, h);
+            }
+        }
+
+        let cookie = unsafe {
+            sys::xcb_icccm_set_wm_normal_hints_checked(
+                events.connection.handle,
+                self.handle(),
+                addr_of_mut!(size_hints),
+            )
+        };
+        events.connection.check_cookie(cookie)?;
+
+        events.connection.flush();
+        Ok(())
+    }
+
+    /// Gets the Actions of a Window.
+    pub fn actions(&self, events: &EventLoop) -> XcbGenericResult<WindowActions> {
+        // Prefer the Window Manager's own view of the allowed actions, when it publishes one (clients never set it).
+        if let Some(allowed) = self.internal_allowed_actions(events) {
+            let ewmh = events.atoms.ewmh_ref();
+            return Ok(WindowActions {
+                movable: allowed.contains(&ewmh._NET_WM_ACTION_MOVE),
+                closeable: allowed.contains(&ewmh._NET_WM_ACTION_CLOSE),
+                resizable: allowed.contains(&ewmh._NET_WM_ACTION_RESIZE),
+            });
+        }
+
+        let hints = self.internal_motif_hints(events)?.unwrap_or_default();
+        let has_func = |func: u32| {
+            let all = (hints.functions & MotifHints::FUNC_ALL) != 0;
+            let bit = (hints.functions & func) != 0;
+            // Without function hints, the Window Manager allows everything.
+            // Otherwise, `FUNC_ALL` inverts the meaning of the other bits.
+            (hints.flags & MotifHints::FLAG_FUNCTIONS) == 0 || (all != bit)
+        };
+
+        let size_hints = self.internal_normal_hints(events)?;
+        let min_max = sys::XCB_ICCCM_SIZE_HINT_P_MIN_SIZE | sys::XCB_ICCCM_SIZE_HINT_P_MAX_SIZE;
+        let is_fixed = ((size_hints.flags & min_max) == min_max)
+            && (size_hints.min_width == size_hints.max_width)
+            && (size_hints.min_height == size_hints.max_height);
+
+        Ok(WindowActions {
+            movable: has_func(MotifHints::FUNC_MOVE),
+            closeable: has_func(MotifHints::FUNC_CLOSE),
+            resizable: has_func(MotifHints::FUNC_RESIZE) && !is_fixed,
+        })
+    }
+
+    // ---------------------------------------------------------------- //
+
     /// Sets the Type of a Window.
-    pub fn set_type(&self, _events: &EventLoop, _wtype: WindowType) {
-        todo!()
+    pub fn set_type(&self, events: &EventLoop, wtype: WindowType) -> XcbGenericResult<()> {
+        self.set_style(events, wtype.style)?;
+        self.set_actions(events, wtype.actions)
     }
 
     /// Gets the Type of a Window.
-    pub fn get_type(&self, _events: &EventLoop) -> WindowType {
-        todo!()
+    pub fn get_type(&self, events: &EventLoop) -> XcbGenericResult<WindowType> {
+        Ok(WindowType {
+            style: self.style(events)?,
+            actions: self.actions(events)?,
+        })
+    }
+
+    // ---------------------------------------------------------------- //
+
+    /// Reads the `_MOTIF_WM_HINTS` property, if it is set on the Window.
+    fn internal_motif_hints(&self, events: &EventLoop) -> XcbGenericResult<Option<MotifHints>> {
+        let motif_wm_hints = events.atoms.motif_wm_hints.get();
+
+        let mut error = null_mut();
+        let cookie = unsafe {
+            sys::xcb_get_property(
+                events.connection.handle,
+                false as u8,
+                self.handle(),
+                motif_wm_hints,
+                motif_wm_hints,
+                0,
+                MotifHints::LEN,
+            )
+        };
+        let reply = unsafe {
+            sys::xcb_get_property_reply(events.connection.handle, cookie, addr_of_mut!(error))
+        };
+        let _reply_free = defer(|| {
+            if !reply.is_null() {
+                unsafe { sys::free(reply as *mut c_void) };
+            }
+        });
+
+        if let Some(err) = XcbGenericError::new(error) {
+            return Err(err);
+        }
+
+        match unsafe { reply.as_ref() } {
+            Some(rep) if (rep.format == 32) && (rep.value_len >= MotifHints::LEN) => {
+                let dat = unsafe { sys::xcb_get_property_value(reply) } as *const MotifHints;
+                assert!(!dat.is_null());
+                Ok(Some(unsafe { dat.read_unaligned() }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Writes the `_MOTIF_WM_HINTS` property, without flushing.
+    fn internal_set_motif_hints(
+        &self,
+        events: &EventLoop,
+        hints: MotifHints,
+    ) -> XcbGenericResult<()> {
+        let motif_wm_hints = events.atoms.motif_wm_hints.get();
+
+        let cookie = unsafe {
+            sys::xcb_change_property_checked(
+                events.connection.handle,
+                sys::XCB_PROP_MODE_REPLACE as u8,
+                self.handle(),
+                motif_wm_hints,
+                motif_wm_hints,
+                32,
+                MotifHints::LEN,
+                void_of!(hints),
+            )
+        };
+        events.connection.check_cookie(cookie)
+    }
+
+    /// Reads the `WM_NORMAL_HINTS` property, or empty hints if it is not set on the Window.
+    fn internal_normal_hints(
+        &self,
+        events: &EventLoop,
+    ) -> XcbGenericResult<sys::xcb_icccm_size_hints_t> {
+        let mut error = null_mut();
+        let mut hints: sys::xcb_icccm_size_hints_t = unsafe { zeroed() };
+
+        let cookie =
+            unsafe { sys::xcb_icccm_get_wm_normal_hints(events.connection.handle, self.handle()) };
+        let reply = unsafe {
+            sys::xcb_icccm_get_wm_normal_hints_reply(
+                events.connection.handle,
+                cookie,
+                addr_of_mut!(hints),
+                addr_of_mut!(error),
+            )
+        };
+
+        if let Some(err) = XcbGenericError::new(error) {
+            return Err(err);
+        }
+
+        if reply != 1 {
+            hints = unsafe { zeroed() };
+        }
+        Ok(hints)
+    }
+
+    /// Reads back the list of `_NET_WM_ALLOWED_ACTIONS` Atoms, if the Window Manager has set them on the Window.
+    fn internal_allowed_actions(&self, events: &EventLoop) -> Option<Vec<sys::xcb_atom_t>> {
+        let ewmh = events.atoms.ewmh_ptr();
+
+        let mut error = null_mut();
+        let mut actions: sys::xcb_ewmh_get_atoms_reply_t = unsafe { zeroed() };
+
+        let cookie = unsafe { sys::xcb_ewmh_get_wm_allowed_actions(ewmh, self.handle()) };
+        let reply = unsafe {
+            sys::xcb_ewmh_get_wm_allowed_actions_reply(
+                ewmh,
+                cookie,
+                addr_of_mut!(actions),
+                addr_of_mut!(error),
+            )
+        };
+        assert!(XcbGenericError::new(error).is_none());
+
+        if reply != 1 {
+            return None;
+        }
+        let _actions_wipe = defer(|| unsafe {
+            sys::xcb_ewmh_get_atoms_reply_wipe(addr_of_mut!(actions));
+        });
+
+        let len = actions.atoms_len as usize;
+        let dat = actions.atoms as *const sys::xcb_atom_t;
+        assert!(!dat.is_null());
+
+        Some(unsafe { core::slice::from_raw_parts(dat, len) }.to_vec())
     }
 }
 
@@ -0,0 +1,111 @@
+/*
+ *  Crate: Wyn
+ *   Test: Clipboard
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! Text and other MIME types are placed on the Clipboard, and then read back (from the Event Thread and another thread).\
+//! The Primary Selection is unsupported by the Headless backend, so it must always be empty.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn clipboard() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let paste_thread = scope.spawn(|| app.paste(&events));
+        events.run();
+        paste_thread.join().unwrap();
+    });
+
+    assert!(app.copied.load(Ordering::Relaxed));
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{clipboard::*, errors::*, event_loop::*, events::*, inputs::*, types::*, window::*, *};
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+const TEXT: &str = "Hello, Clipboard! \u{1F4CB}";
+
+const MIME_PNG: &str = "image/png";
+const PNG: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+struct TestApp {
+    copied: AtomicBool,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let copied = AtomicBool::new(false);
+        Self { copied }
+    }
+
+    pub fn paste(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+
+        let clipboard = Clipboard::Standard;
+        assert_eq!(clipboard.get_text(events).unwrap().as_deref(), Some(TEXT));
+        assert_eq!(
+            clipboard.get(events, MIME_TEXT).unwrap().as_deref(),
+            Some(TEXT.as_bytes())
+        );
+        assert_eq!(clipboard.get(events, MIME_PNG).unwrap(), None);
+
+        // Replacing the contents discards the text.
+        clipboard.set(events, MIME_PNG, PNG).unwrap();
+        assert_eq!(
+            clipboard.get(events, MIME_PNG).unwrap().as_deref(),
+            Some(PNG)
+        );
+        assert_eq!(clipboard.get_text(events).unwrap(), None);
+
+        events.request_stop();
+        assert!(events.await_termination());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        assert!(!events.supports(Capability::Clipboard));
+        assert!(!events.supports(Capability::PrimarySelection));
+
+        assert_eq!(Clipboard::default(), Clipboard::Standard);
+        assert_eq!(Clipboard::Standard.get_text(events).unwrap(), None);
+
+        Clipboard::Standard.set_text(events, TEXT).unwrap();
+        assert_eq!(
+            Clipboard::Standard.get_text(events).unwrap().as_deref(),
+            Some(TEXT)
+        );
+
+        Clipboard::Primary.set_text(events, TEXT).unwrap();
+        assert_eq!(Clipboard::Primary.get_text(events).unwrap(), None);
+
+        self.copied.store(true, Ordering::Relaxed);
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,158 @@
+/*
+ *  Crate: Wyn
+ *   Test: Cursor
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! The Cursor of a Window is changed to a standard shape and a custom image, hidden, grabbed, and warped.\
+//! Warping must be reported as a Cursor-Move, and injected Raw Motion must be reported regardless of the Cursor.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn cursor() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events));
+        events.run();
+        input_thread.join().unwrap();
+    });
+
+    let log = app.log.lock().unwrap();
+    let expected = [
+        Logged::Move(Point::new(320.0, 240.0)),
+        Logged::Raw(3.0, -4.5),
+        Logged::Raw(-1.0, 0.0),
+    ];
+    assert_eq!(log.as_slice(), expected.as_slice());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{cursor::*, errors::*, event_loop::*, events::*, inputs::*, types::*, window::*, *};
+
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Logged {
+    Move(Point),
+    Raw(f64, f64),
+}
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    log: Mutex<Vec<Logged>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let log = Mutex::new(Vec::new());
+        Self { window, log }
+    }
+
+    /// A 2x2 image, with its Hotspot in the bottom-right corner.
+    fn image() -> CursorImage {
+        #[rustfmt::skip]
+        let rgba = vec![
+            255, 0, 0, 255,    0, 255, 0, 255,
+            0, 0, 255, 255,    0, 0, 0, 0,
+        ];
+        CursorImage::new(2, 2, (1, 1), rgba).unwrap()
+    }
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+        assert!(events.supports(Capability::CursorControl));
+        assert!(events.supports(Capability::RawMotion));
+
+        let guard = self.window.lock().unwrap();
+        let window = guard.as_ref().unwrap();
+        let handle = window.handle();
+
+        // Shapes
+        assert_eq!(window.cursor(events).unwrap(), Cursor::default());
+        window.set_cursor(events, &CursorShape::Hand.into()).unwrap();
+        assert_eq!(
+            window.cursor(events).unwrap(),
+            Cursor::Shape(CursorShape::Hand)
+        );
+
+        window.set_cursor(events, &Self::image().into()).unwrap();
+        assert_eq!(window.cursor(events).unwrap(), Cursor::Custom(Self::image()));
+
+        // Visibility and Grabbing
+        assert!(window.is_cursor_visible(events));
+        window.set_cursor_visible(events, false).unwrap();
+        assert!(!window.is_cursor_visible(events));
+
+        assert!(!window.is_cursor_grabbed(events));
+        window.grab_cursor(events, true).unwrap();
+        assert!(window.is_cursor_grabbed(events));
+
+        // Motion
+        window.warp_cursor(events, Point::new(320.0, 240.0)).unwrap();
+        events.inject(HeadlessInput::RawMotion(3.0, -4.5));
+        events.inject(HeadlessInput::RawMotion(-1.0, 0.0));
+
+        drop(guard);
+        events.inject(HeadlessInput::WindowClose(handle));
+        assert!(events.await_termination());
+    }
+
+    fn log(&self, logged: Logged) {
+        self.log.lock().unwrap().push(logged);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        // Images must have exactly `width * height * 4` bytes, and a Hotspot inside of them.
+        assert_eq!(CursorImage::new(2, 2, (0, 0), vec![0; 15]), None);
+        assert_eq!(CursorImage::new(2, 2, (2, 0), vec![0; 16]), None);
+        assert_eq!(CursorImage::new(0, 0, (0, 0), Vec::new()), None);
+
+        let image = Self::image();
+        assert_eq!((image.width(), image.height()), (2, 2));
+        assert_eq!(image.hotspot(), (1, 1));
+
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.window.lock().unwrap().take();
+        events.request_stop();
+    }
+
+    fn cursor_move(&self, _events: &EventLoop, _handle: WindowHandle, point: Point) {
+        self.log(Logged::Move(point));
+    }
+
+    fn raw_motion(&self, _events: &EventLoop, delta_x: f64, delta_y: f64) {
+        self.log(Logged::Raw(delta_x, delta_y));
+    }
+}
+
+// ================================================================================================================================ //
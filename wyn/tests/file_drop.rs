@@ -0,0 +1,163 @@
+/*
+ *  Crate: Wyn
+ *   Test: File Drop
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! Files are dragged over a Window and cancelled, and then dragged again and dropped.\
+//! The Event Handler must receive the hover, cancel, and drop callbacks (in order), with the paths and points that were injected.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn file_drop() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events));
+        events.run();
+        input_thread.join().unwrap();
+    });
+
+    let paths = TestApp::paths();
+    let log = app.log.lock().unwrap();
+    let expected = [
+        Logged::Hover(paths.clone(), Point::new(5.0, 5.0)),
+        Logged::Cancel,
+        Logged::Hover(paths.clone(), Point::new(10.0, 20.0)),
+        Logged::Hover(paths.clone(), Point::new(30.0, 40.0)),
+        Logged::Drop(paths, Point::new(30.0, 40.0)),
+    ];
+    assert_eq!(log.as_slice(), expected.as_slice());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, types::*, window::*, *};
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[derive(Clone, PartialEq, Debug)]
+enum Logged {
+    Hover(Vec<PathBuf>, Point),
+    Drop(Vec<PathBuf>, Point),
+    Cancel,
+}
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    log: Mutex<Vec<Logged>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let log = Mutex::new(Vec::new());
+        Self { window, log }
+    }
+
+    fn paths() -> Vec<PathBuf> {
+        vec![
+            PathBuf::from("/levels/level 1.map"),
+            PathBuf::from("/images/sprite.png"),
+        ]
+    }
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+        assert!(events.supports(Capability::FileDrop));
+        assert!(events.supports(Capability::FileDropHover));
+
+        let handle = self.window.lock().unwrap().as_ref().unwrap().handle();
+        let paths = Self::paths();
+
+        events.inject(HeadlessInput::FileDropHover(
+            handle,
+            paths.clone(),
+            Point::new(5.0, 5.0),
+        ));
+        events.inject(HeadlessInput::FileDropCancel(handle));
+
+        events.inject(HeadlessInput::FileDropHover(
+            handle,
+            paths.clone(),
+            Point::new(10.0, 20.0),
+        ));
+        events.inject(HeadlessInput::FileDropHover(
+            handle,
+            paths.clone(),
+            Point::new(30.0, 40.0),
+        ));
+        events.inject(HeadlessInput::FileDrop(
+            handle,
+            paths,
+            Point::new(30.0, 40.0),
+        ));
+
+        events.inject(HeadlessInput::WindowClose(handle));
+        assert!(events.await_termination());
+    }
+
+    fn log(&self, logged: Logged) {
+        self.log.lock().unwrap().push(logged);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.window.lock().unwrap().take();
+        events.request_stop();
+    }
+
+    fn file_drop_hover(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+        self.log(Logged::Hover(paths, point));
+    }
+
+    fn file_drop(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+        self.log(Logged::Drop(paths, point));
+    }
+
+    fn file_drop_cancel(&self, _events: &EventLoop, _handle: WindowHandle) {
+        self.log(Logged::Cancel);
+    }
+}
+
+// ================================================================================================================================ //
@@ -5,7 +5,8 @@
 
 //! In this test, a window will be created and shown to the user.
 //!
-//! The user should plug in at least one XInput-compatible controller.
+//! The user should plug in at least one controller (XInput-compatible on Windows, any evdev Gamepad on Linux).
+//! They may also plug controllers in/out while the test is running, which should be reported as it happens.
 //! They should press and release buttons, move the sticks, etc...
 //! As they do so, the state of the controllers will be printed to the console.
 //!
@@ -89,57 +90,64 @@ impl TestApp {
 
                 writeln!(stdout, "v ======== Frame {iter} ======== v\n").unwrap();
 
-                let controllers = XInputController::collect(events).unwrap();
-
-                for (i, opt_con) in controllers.iter().enumerate() {
-                    match opt_con {
-                        None => {
-                            writeln!(stdout, "[Controller {i}]\n* DISCONNECTED\n").unwrap();
-                        }
-                        Some(con) => {
-                            let button_flag = |flag: bool| match flag {
-                                true => 'O',
-                                false => '_',
-                            };
-
-                            let flag_ba = button_flag(con.a());
-                            let flag_bb = button_flag(con.b());
-                            let flag_bx = button_flag(con.x());
-                            let flag_by = button_flag(con.y());
-
-                            let flag_du = button_flag(con.dpad_u());
-                            let flag_dd = button_flag(con.dpad_d());
-                            let flag_dl = button_flag(con.dpad_l());
-                            let flag_dr = button_flag(con.dpad_r());
-
-                            let flag_lb = button_flag(con.bumper_l());
-                            let flag_rb = button_flag(con.bumper_r());
-                            let (analog_lt, _) = con.trigger_l();
-                            let (analog_rt, _) = con.trigger_r();
-
-                            let flag_ls = button_flag(con.thumb_l());
-                            let flag_rs = button_flag(con.thumb_r());
-                            let flag_back = button_flag(con.back());
-                            let flag_start = button_flag(con.start());
-
-                            let (lx, ly) = con.stick_l();
-                            let (rx, ry) = con.stick_r();
-
-                            let packet = con.packet();
-
-                            let line1 = format!("[Controller {i}] <{packet}>");
-                            let line2 = format!("* |  A: {flag_ba} |  B: {flag_bb} |  X: {flag_bx} |  Y: {flag_by} |");
-                            let line3 = format!("* |  U: {flag_du} |  D: {flag_dd} |  L: {flag_dl} |  R: {flag_dr} |");
-                            let line4 = format!("* | LB: {flag_lb} | RB: {flag_rb} | LS: {flag_ls} | RS: {flag_rs} |");
-                            let line5 = format!("* |  BACK: {flag_back}  |  START: {flag_start} | LT: {analog_lt:7.5} | RS: {analog_rt:7.5} |");
-                            let line6 = format!("* | Left-Stick: ({lx:8.5}, {ly:8.5}) | Right-Stick: ({rx:8.5}, {ry:8.5})");
-                            writeln!(
-                                stdout,
-                                "{line1}\n{line2}\n{line3}\n{line4}\n{line5}\n{line6}\n"
-                            )
-                            .unwrap();
-                        }
-                    }
+                let controllers = Gamepad::collect(events);
+
+                if controllers.is_empty() {
+                    writeln!(stdout, "* NO CONTROLLERS CONNECTED\n").unwrap();
+                }
+
+                for con in controllers.iter() {
+                    let i = con.id();
+
+                    let button_flag = |flag: bool| match flag {
+                        true => 'O',
+                        false => '_',
+                    };
+
+                    let flag_ba = button_flag(con.a());
+                    let flag_bb = button_flag(con.b());
+                    let flag_bx = button_flag(con.x());
+                    let flag_by = button_flag(con.y());
+
+                    let flag_du = button_flag(con.dpad_u());
+                    let flag_dd = button_flag(con.dpad_d());
+                    let flag_dl = button_flag(con.dpad_l());
+                    let flag_dr = button_flag(con.dpad_r());
+
+                    let flag_lb = button_flag(con.bumper_l());
+                    let flag_rb = button_flag(con.bumper_r());
+                    let (analog_lt, _) = con.trigger_l();
+                    let (analog_rt, _) = con.trigger_r();
+
+                    let flag_ls = button_flag(con.thumb_l());
+                    let flag_rs = button_flag(con.thumb_r());
+                    let flag_back = button_flag(con.back());
+                    let flag_start = button_flag(con.start());
+
+                    let (lx, ly) = con.stick_l();
+                    let (rx, ry) = con.stick_r();
+
+                    let packet = con.packet();
+
+                    let line1 = format!("[Controller {i}] <{packet}>");
+                    let line2 = format!(
+                        "* |  A: {flag_ba} |  B: {flag_bb} |  X: {flag_bx} |  Y: {flag_by} |"
+                    );
+                    let line3 = format!(
+                        "* |  U: {flag_du} |  D: {flag_dd} |  L: {flag_dl} |  R: {flag_dr} |"
+                    );
+                    let line4 = format!(
+                        "* | LB: {flag_lb} | RB: {flag_rb} | LS: {flag_ls} | RS: {flag_rs} |"
+                    );
+                    let line5 = format!("* |  BACK: {flag_back}  |  START: {flag_start} | LT: {analog_lt:7.5} | RS: {analog_rt:7.5} |");
+                    let line6 = format!(
+                        "* | Left-Stick: ({lx:8.5}, {ly:8.5}) | Right-Stick: ({rx:8.5}, {ry:8.5})"
+                    );
+                    writeln!(
+                        stdout,
+                        "{line1}\n{line2}\n{line3}\n{line4}\n{line5}\n{line6}\n"
+                    )
+                    .unwrap();
                 }
             }
             std::thread::sleep(std::time::Duration::from_secs_f64(1.0 / 6.0));
@@ -171,6 +179,24 @@ impl EventHandler for TestApp {
         self.opened.store(true, Ordering::Relaxed);
     }
 
+    fn gamepad_connected(&self, _events: &EventLoop, id: GamepadId) {
+        println!("[Controller {id}] CONNECTED");
+    }
+
+    fn gamepad_disconnected(&self, _events: &EventLoop, id: GamepadId) {
+        println!("[Controller {id}] DISCONNECTED");
+    }
+
+    fn gamepad_button(
+        &self,
+        _events: &EventLoop,
+        id: GamepadId,
+        button: GamepadButton,
+        pressed: bool,
+    ) {
+        println!("[Controller {id}] {button:?}: {pressed}");
+    }
+
     fn window_close(&self, events: &EventLoop, handle: WindowHandle) {
         self.closed.store(true, Ordering::Relaxed);
 
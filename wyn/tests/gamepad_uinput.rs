@@ -0,0 +1,253 @@
+/*
+ *  Crate: Wyn
+ *   Test: Gamepad-Uinput
+ */
+
+//! This test is the automatic counterpart of `gamepad_info`, and runs only on Linux with the X11 backend.
+//!
+//! A virtual Gamepad is created through `uinput`, which presses and releases its A Button, pushes its Left Stick to the right,
+//! and is then removed again.\
+//! Its connection, Button, Axis and disconnection must be reported to the `EventHandler` (in order).
+//!
+//! On Linux, this test runs under Xvfb. It is skipped if `/dev/uinput` cannot be opened for writing.
+
+#![cfg(all(
+    target_os = "linux",
+    not(feature = "headless"),
+    not(feature = "wayland")
+))]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn gamepad_uinput() {
+    utils::timeout::test_deadline(5.0);
+
+    let Some(gamepad) = VirtualGamepad::open() else {
+        eprintln!("Skipped: `/dev/uinput` is not accessible.");
+        return;
+    };
+    test_main(gamepad);
+}
+
+// ================================================================================================================================ //
+
+fn test_main(gamepad: VirtualGamepad) {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events, gamepad));
+        events.run();
+        input_thread.join().unwrap();
+    });
+
+    // Other Gamepads may be plugged in, so only the virtual one is checked.
+    let log = app.log.lock().unwrap();
+    let id = log
+        .iter()
+        .find_map(|logged| match logged {
+            Logged::Connected(id) => Some(*id),
+            _ => None,
+        })
+        .expect("The virtual Gamepad should be connected.");
+    let logged: Vec<Logged> = log
+        .iter()
+        .copied()
+        .filter(|logged| logged.id() == id)
+        .collect();
+
+    let expected = [
+        Logged::Connected(id),
+        Logged::Button(id, GamepadButton::A, true),
+        Logged::Button(id, GamepadButton::A, false),
+        Logged::Axis(id, GamepadAxis::StickLX, 1.0),
+        Logged::Disconnected(id),
+    ];
+    assert_eq!(logged.as_slice(), expected.as_slice());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, types::*, window::*, *};
+
+use gui_sys as sys;
+
+use std::ffi::CString;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::sync::Mutex;
+use std::time::Duration;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Logged {
+    Connected(GamepadId),
+    Button(GamepadId, GamepadButton, bool),
+    Axis(GamepadId, GamepadAxis, f32),
+    Disconnected(GamepadId),
+}
+
+impl Logged {
+    fn id(&self) -> GamepadId {
+        match *self {
+            Self::Connected(id) | Self::Disconnected(id) => id,
+            Self::Button(id, ..) | Self::Axis(id, ..) => id,
+        }
+    }
+}
+
+struct TestApp {
+    log: Mutex<Vec<Logged>>,
+}
+
+/// A virtual Gamepad, which exists from `create` until it is destroyed (or its `uinput` File Descriptor is closed).
+struct VirtualGamepad(OwnedFd);
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let log = Mutex::new(Vec::new());
+        Self { log }
+    }
+
+    pub fn inject(&self, events: &EventLoop, gamepad: VirtualGamepad) {
+        assert!(events.await_startup());
+        assert!(events.supports(Capability::Gamepads));
+
+        // Gamepads that were plugged in before are not reported as connected.
+        gamepad.create();
+        self.await_logged(|logged| matches!(logged, Logged::Connected(_)));
+
+        gamepad.emit(sys::EV_KEY, sys::BTN_SOUTH, 1);
+        gamepad.emit(sys::EV_SYN, sys::SYN_REPORT, 0);
+        gamepad.emit(sys::EV_KEY, sys::BTN_SOUTH, 0);
+        gamepad.emit(sys::EV_SYN, sys::SYN_REPORT, 0);
+        gamepad.emit(sys::EV_ABS, sys::ABS_X, 32767);
+        gamepad.emit(sys::EV_SYN, sys::SYN_REPORT, 0);
+        self.await_logged(|logged| matches!(logged, Logged::Axis(..)));
+
+        drop(gamepad);
+        self.await_logged(|logged| matches!(logged, Logged::Disconnected(_)));
+
+        events.request_stop();
+        assert!(events.await_termination());
+    }
+
+    fn await_logged(&self, found: impl Fn(&Logged) -> bool) {
+        while !self.log.lock().unwrap().iter().any(&found) {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn log(&self, logged: Logged) {
+        self.log.lock().unwrap().push(logged);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl VirtualGamepad {
+    /// Opens `/dev/uinput`, or returns `None` if it is not accessible.
+    fn open() -> Option<Self> {
+        let path = CString::new("/dev/uinput").unwrap();
+        let flags = sys::O_WRONLY | sys::O_NONBLOCK | sys::O_CLOEXEC;
+
+        let raw_fd = unsafe { sys::open(path.as_ptr(), flags) };
+        if raw_fd == -1 {
+            return None;
+        }
+        Some(Self(unsafe { OwnedFd::from_raw_fd(raw_fd) }))
+    }
+
+    /// Creates a Gamepad with the standard layout, with an A Button and a Left Stick.
+    fn create(&self) {
+        let fd = self.0.as_raw_fd();
+        let ioctl = |request: sys::c_ulong, value: sys::c_int| {
+            assert_ne!(unsafe { sys::ioctl(fd, request as _, value) }, -1);
+        };
+
+        ioctl(sys::UI_SET_EVBIT, sys::EV_KEY as _);
+        ioctl(sys::UI_SET_EVBIT, sys::EV_ABS as _);
+        ioctl(sys::UI_SET_KEYBIT, sys::BTN_SOUTH as _);
+        ioctl(sys::UI_SET_ABSBIT, sys::ABS_X as _);
+        ioctl(sys::UI_SET_ABSBIT, sys::ABS_Y as _);
+
+        for code in [sys::ABS_X, sys::ABS_Y] {
+            let mut abs_setup: sys::uinput_abs_setup = unsafe { std::mem::zeroed() };
+            abs_setup.code = code;
+            abs_setup.absinfo.minimum = -32768;
+            abs_setup.absinfo.maximum = 32767;
+            let res =
+                unsafe { sys::ioctl(fd, sys::UI_ABS_SETUP as _, std::ptr::addr_of!(abs_setup)) };
+            assert_ne!(res, -1);
+        }
+
+        let mut setup: sys::uinput_setup = unsafe { std::mem::zeroed() };
+        setup.id.bustype = sys::BUS_USB;
+        setup.id.vendor = 0x1234;
+        setup.id.product = 0x5678;
+        for (dst, src) in setup.name.iter_mut().zip(b"Wyn Virtual Gamepad") {
+            *dst = *src as _;
+        }
+        assert_ne!(
+            unsafe { sys::ioctl(fd, sys::UI_DEV_SETUP as _, std::ptr::addr_of!(setup)) },
+            -1
+        );
+        assert_ne!(unsafe { sys::ioctl(fd, sys::UI_DEV_CREATE as _) }, -1);
+    }
+
+    /// Emits a single input event from the Gamepad.
+    fn emit(&self, type_: u16, code: u16, value: i32) {
+        let mut event: sys::input_event = unsafe { std::mem::zeroed() };
+        event.type_ = type_;
+        event.code = code;
+        event.value = value;
+
+        let size = std::mem::size_of::<sys::input_event>();
+        let buffer = std::ptr::addr_of!(event).cast();
+        assert_eq!(
+            unsafe { sys::write(self.0.as_raw_fd(), buffer, size) },
+            size as isize
+        );
+    }
+}
+
+impl Drop for VirtualGamepad {
+    fn drop(&mut self) {
+        // Fails if the Gamepad was never created, in which case there is nothing to remove.
+        let _ = unsafe { sys::ioctl(self.0.as_raw_fd(), sys::UI_DEV_DESTROY as _) };
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn gamepad_connected(&self, _events: &EventLoop, id: GamepadId) {
+        self.log(Logged::Connected(id));
+    }
+
+    fn gamepad_disconnected(&self, _events: &EventLoop, id: GamepadId) {
+        self.log(Logged::Disconnected(id));
+    }
+
+    fn gamepad_button(
+        &self,
+        _events: &EventLoop,
+        id: GamepadId,
+        button: GamepadButton,
+        pressed: bool,
+    ) {
+        self.log(Logged::Button(id, button, pressed));
+    }
+
+    fn gamepad_axis(&self, _events: &EventLoop, id: GamepadId, axis: GamepadAxis, value: f32) {
+        self.log(Logged::Axis(id, axis, value));
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,243 @@
+/*
+ *  Crate: Wyn
+ *   Test: Headless
+ */
+
+//! This test runs only with the `headless` feature, and ensures that injected inputs and Window state changes
+//! are reported to the Event Handler (in order), without a display server.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn headless() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let app_thread = scope.spawn(|| app.run(&events));
+        events.run();
+        app_thread.join().unwrap();
+    });
+
+    let log = app.log.lock().unwrap();
+    let expected = [
+        Logged::Focus(true),
+        Logged::Cursor(Point::new(10.0, 20.0)),
+        Logged::Button(MouseButton::Left, true),
+        Logged::Key(Key::A, true),
+        Logged::Character('a'),
+        Logged::Key(Key::A, false),
+        Logged::Scroll(0.0, -1.0),
+        Logged::GamepadConnected(7),
+        Logged::GamepadButton(7, GamepadButton::A, true),
+        Logged::GamepadAxis(7, GamepadAxis::TriggerL, 0.5),
+        Logged::GamepadDisconnected(7),
+        Logged::Close,
+    ];
+    assert_eq!(log.as_slice(), &expected[..]);
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, screen::*, types::*, window::*, *};
+
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Logged {
+    Focus(bool),
+    Cursor(Point),
+    Button(MouseButton, bool),
+    Key(Key, bool),
+    Character(char),
+    Scroll(f64, f64),
+    GamepadConnected(GamepadId),
+    GamepadDisconnected(GamepadId),
+    GamepadButton(GamepadId, GamepadButton, bool),
+    GamepadAxis(GamepadId, GamepadAxis, f32),
+    Close,
+}
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    log: Mutex<Vec<Logged>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let log = Mutex::new(Vec::new());
+        Self { window, log }
+    }
+
+    pub fn run(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+
+        let handle = self.window.lock().unwrap().as_ref().unwrap().handle();
+
+        events.inject(HeadlessInput::CursorMove(handle, Point::new(10.0, 20.0)));
+        events.inject(HeadlessInput::ButtonPress(handle, MouseButton::Left, true));
+        events.inject(HeadlessInput::KeyPress(handle, KeyEvent::new(Key::A), true));
+        events.inject(HeadlessInput::CharacterInput(handle, 'a'));
+        events.inject(HeadlessInput::KeyPress(
+            handle,
+            KeyEvent::new(Key::A),
+            false,
+        ));
+        events.inject(HeadlessInput::ScrollWheel(handle, 0.0, -1.0));
+
+        // Inputs on Windows that do not exist are ignored.
+        events.inject(HeadlessInput::KeyPress(
+            handle + 1,
+            KeyEvent::new(Key::B),
+            true,
+        ));
+
+        events.inject(HeadlessInput::GamepadConnected(7));
+        events.inject(HeadlessInput::GamepadButton(7, GamepadButton::A, true));
+        events.inject(HeadlessInput::GamepadAxis(7, GamepadAxis::TriggerL, 0.5));
+        events.inject(HeadlessInput::GamepadDisconnected(7));
+
+        events.inject(HeadlessInput::WindowClose(handle));
+
+        assert!(events.await_termination());
+    }
+
+    fn log(&self, logged: Logged) {
+        self.log.lock().unwrap().push(logged);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        assert!(window.is_hidden(events));
+        assert!(!window.is_focused(events));
+
+        // Screens.
+        {
+            let screen = Screen::primary(events);
+            let sc_rect = screen.rect(events);
+            assert_eq!(Screen::collect(events).len(), 1);
+            assert_eq!(window.screen(events).info(events), screen.info(events));
+
+            window.maximize(events).unwrap();
+            assert!(window.is_maximized(events));
+            assert_eq!(window.border_rect(events).unwrap(), sc_rect);
+
+            window.fullscreen(events).unwrap();
+            assert!(window.is_fullscreen(events));
+            assert_eq!(window.content_rect(events).unwrap(), sc_rect);
+        }
+
+        // Restore.
+        {
+            let rect = Rect::new(5.0, 6.0, 256.0, 240.0);
+            window.reposition_content(events, rect).unwrap();
+            assert!(window.is_normal(events));
+
+            window.maximize(events).unwrap();
+            window.minimize(events).unwrap();
+            assert!(window.is_minimized(events));
+
+            window.restore(events).unwrap();
+            assert!(window.is_maximized(events));
+
+            window.restore(events).unwrap();
+            assert!(window.is_normal(events));
+            assert_eq!(window.content_rect(events).unwrap(), rect);
+        }
+
+        window.focus(events).unwrap();
+        assert!(window.is_visible(events));
+        assert!(window.is_focused(events));
+
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, handle: WindowHandle) {
+        if let Some(window) = self.window.lock().unwrap().take() {
+            assert_eq!(window.handle(), handle);
+            self.log(Logged::Close);
+        }
+        events.request_stop();
+    }
+
+    fn window_focus(&self, _events: &EventLoop, _handle: WindowHandle, focused: bool) {
+        self.log(Logged::Focus(focused));
+    }
+
+    fn cursor_move(&self, _events: &EventLoop, _handle: WindowHandle, point: Point) {
+        self.log(Logged::Cursor(point));
+    }
+
+    fn scroll_wheel(&self, _events: &EventLoop, _handle: WindowHandle, delta_x: f64, delta_y: f64) {
+        self.log(Logged::Scroll(delta_x, delta_y));
+    }
+
+    fn button_press(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        button: MouseButton,
+        pressed: bool,
+    ) {
+        self.log(Logged::Button(button, pressed));
+    }
+
+    fn key_press(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        event: KeyEvent,
+        pressed: bool,
+    ) {
+        self.log(Logged::Key(event.key, pressed));
+    }
+
+    fn character_input(&self, _events: &EventLoop, _handle: WindowHandle, character: char) {
+        self.log(Logged::Character(character));
+    }
+
+    fn gamepad_connected(&self, _events: &EventLoop, id: GamepadId) {
+        self.log(Logged::GamepadConnected(id));
+    }
+
+    fn gamepad_disconnected(&self, _events: &EventLoop, id: GamepadId) {
+        self.log(Logged::GamepadDisconnected(id));
+    }
+
+    fn gamepad_button(
+        &self,
+        _events: &EventLoop,
+        id: GamepadId,
+        button: GamepadButton,
+        pressed: bool,
+    ) {
+        self.log(Logged::GamepadButton(id, button, pressed));
+    }
+
+    fn gamepad_axis(&self, _events: &EventLoop, id: GamepadId, axis: GamepadAxis, value: f32) {
+        self.log(Logged::GamepadAxis(id, axis, value));
+    }
+}
+
+// ================================================================================================================================ //
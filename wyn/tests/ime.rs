@@ -0,0 +1,156 @@
+/*
+ *  Crate: Wyn
+ *   Test: IME
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! Input Method events are injected before and after the Window allows Input Methods, and after it disallows them again.\
+//! Inputs are queued, so Input Methods are allowed and disallowed by the Event Handler, when it receives marker Characters.\
+//! Only the events injected while Input Methods are allowed must be reported, with their pre-edit cursor.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn ime() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events));
+        events.run();
+        input_thread.join().unwrap();
+    });
+
+    let log = app.log.lock().unwrap();
+    let expected = [
+        Logged::Preedit("n".into(), Some(1..1)),
+        Logged::Preedit("你好".into(), Some(0..3)),
+        Logged::Preedit(String::new(), None),
+        Logged::Commit("你好".into()),
+    ];
+    assert_eq!(log.as_slice(), expected.as_slice());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, types::*, window::*, *};
+
+use std::ops::Range;
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[derive(Clone, PartialEq, Debug)]
+enum Logged {
+    Preedit(String, Option<Range<usize>>),
+    Commit(String),
+}
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    log: Mutex<Vec<Logged>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let log = Mutex::new(Vec::new());
+        Self { window, log }
+    }
+
+    /// The area being edited, once Input Methods are allowed.
+    const POSITION: Rect = Rect::new(10.0, 20.0, 100.0, 16.0);
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+        assert!(events.supports(Capability::InputMethod));
+
+        let handle = {
+            let guard = self.window.lock().unwrap();
+            let window = guard.as_ref().unwrap();
+            assert!(!window.is_ime_allowed(events));
+            window.handle()
+        };
+
+        // Disallowed by default.
+        events.inject(HeadlessInput::ImePreedit(handle, "x".into(), None));
+        events.inject(HeadlessInput::ImeCommit(handle, "x".into()));
+
+        // Allowed
+        events.inject(HeadlessInput::CharacterInput(handle, '+'));
+        events.inject(HeadlessInput::ImePreedit(handle, "n".into(), Some(1..1)));
+        events.inject(HeadlessInput::ImePreedit(handle, "你好".into(), Some(0..3)));
+        events.inject(HeadlessInput::ImePreedit(handle, String::new(), None));
+        events.inject(HeadlessInput::ImeCommit(handle, "你好".into()));
+
+        // Disallowed again.
+        events.inject(HeadlessInput::CharacterInput(handle, '-'));
+        events.inject(HeadlessInput::ImeCommit(handle, "y".into()));
+
+        events.inject(HeadlessInput::WindowClose(handle));
+        assert!(events.await_termination());
+    }
+
+    fn log(&self, logged: Logged) {
+        self.log.lock().unwrap().push(logged);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.window.lock().unwrap().take();
+        events.request_stop();
+    }
+
+    fn character_input(&self, events: &EventLoop, _handle: WindowHandle, character: char) {
+        let guard = self.window.lock().unwrap();
+        let window = guard.as_ref().unwrap();
+
+        let allowed = character == '+';
+        window.set_ime_allowed(events, allowed).unwrap();
+        assert_eq!(window.is_ime_allowed(events), allowed);
+
+        if allowed {
+            window.set_ime_position(events, Self::POSITION).unwrap();
+            assert_eq!(window.ime_position(events).unwrap(), Self::POSITION);
+        }
+    }
+
+    fn ime_preedit(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        text: String,
+        cursor_range: Option<Range<usize>>,
+    ) {
+        self.log(Logged::Preedit(text, cursor_range));
+    }
+
+    fn ime_commit(&self, _events: &EventLoop, _handle: WindowHandle, text: String) {
+        self.log(Logged::Commit(text));
+    }
+}
+
+// ================================================================================================================================ //
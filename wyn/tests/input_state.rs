@@ -0,0 +1,161 @@
+/*
+ *  Crate: Wyn
+ *   Test: Input-State
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! Keys and Mouse-Buttons are pressed (and repeated), and the Mouse-Cursor is moved, before they are all released again.\
+//! The Event Handler queries the state when it receives marker Characters, and it must match the Inputs injected before them.\
+//! The Cursor stays put on the Screen, so moving the Window moves the Cursor position relative to it.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn input_state() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events));
+        events.run();
+        input_thread.join().unwrap();
+    });
+
+    let log = app.log.lock().unwrap();
+    let expected = [
+        Snapshot {
+            keys: vec![],
+            buttons: vec![],
+            cursor: None,
+        },
+        Snapshot {
+            keys: vec![Key::A, Key::LShift],
+            buttons: vec![MouseButton::Left],
+            cursor: Some(Point::new(10.0, 20.0)),
+        },
+        Snapshot {
+            keys: vec![Key::LShift],
+            buttons: vec![],
+            cursor: Some(Point::new(5.0, 15.0)),
+        },
+        Snapshot {
+            keys: vec![],
+            buttons: vec![],
+            cursor: Some(Point::new(5.0, 15.0)),
+        },
+    ];
+    assert_eq!(log.as_slice(), expected.as_slice());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, types::*, window::*, *};
+
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// The state of the Keyboard and Mouse, as queried by the Event Handler.
+#[derive(Clone, PartialEq, Debug)]
+struct Snapshot {
+    keys: Vec<Key>,
+    buttons: Vec<MouseButton>,
+    cursor: Option<Point>,
+}
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    log: Mutex<Vec<Snapshot>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let log = Mutex::new(Vec::new());
+        Self { window, log }
+    }
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+        assert!(events.supports(Capability::InputState));
+
+        let handle = self.window.lock().unwrap().as_ref().unwrap().handle();
+        let key =
+            |key: Key, pressed: bool| HeadlessInput::KeyPress(handle, KeyEvent::new(key), pressed);
+        let button = |pressed: bool| HeadlessInput::ButtonPress(handle, MouseButton::Left, pressed);
+
+        // Nothing is held yet, and the Cursor was never moved.
+        events.inject(HeadlessInput::CharacterInput(handle, '?'));
+
+        // Held Keys are only reported once, even while they repeat.
+        events.inject(key(Key::A, true));
+        events.inject(key(Key::LShift, true));
+        events.inject(key(Key::A, true));
+        events.inject(button(true));
+        events.inject(HeadlessInput::CursorMove(handle, Point::new(10.0, 20.0)));
+        events.inject(HeadlessInput::CharacterInput(handle, '?'));
+
+        // The Window moves away from the Cursor.
+        events.inject(key(Key::A, false));
+        events.inject(button(false));
+        events.inject(HeadlessInput::CharacterInput(handle, '+'));
+
+        events.inject(key(Key::LShift, false));
+        events.inject(HeadlessInput::CharacterInput(handle, '?'));
+
+        events.inject(HeadlessInput::WindowClose(handle));
+        assert!(events.await_termination());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.window.lock().unwrap().take();
+        events.request_stop();
+    }
+
+    fn character_input(&self, events: &EventLoop, handle: WindowHandle, character: char) {
+        if character == '+' {
+            let guard = self.window.lock().unwrap();
+            let window = guard.as_ref().unwrap();
+            let mut rect = window.content_rect(events).unwrap();
+            rect.origin = Point::new(rect.origin.x + 5.0, rect.origin.y + 5.0);
+            window.reposition_content(events, rect).unwrap();
+        }
+
+        let keys = events.pressed_keys();
+        for key in [Key::A, Key::LShift] {
+            assert_eq!(events.key_state(key), keys.contains(&key));
+        }
+
+        self.log.lock().unwrap().push(Snapshot {
+            keys,
+            buttons: events.mouse_buttons(),
+            cursor: events.cursor_position(handle),
+        });
+    }
+}
+
+// ================================================================================================================================ //
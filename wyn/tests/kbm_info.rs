@@ -117,11 +117,11 @@ impl EventHandler for TestApp {
         &self,
         _events: &EventLoop,
         _handle: WindowHandle,
-        keycode: KeyCode,
+        event: KeyEvent,
         pressed: bool,
     ) {
         let pstr = if pressed { "PRESSED " } else { "RELEASED" };
-        println!("[KEY {pstr}] {keycode:?}")
+        println!("[KEY {pstr}] {event:?}")
     }
 
     fn character_input(&self, _events: &EventLoop, _handle: WindowHandle, character: char) {
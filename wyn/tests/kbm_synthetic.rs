@@ -0,0 +1,165 @@
+/*
+ *  Crate: Wyn
+ *   Test: Keyboard/Mouse-Synthetic
+ */
+
+//! This test is the automatic counterpart of `kbm_info`.
+//!
+//! A window will be created and shown, and synthetic inputs will be posted to it with `wyn::testing`.
+//! Every input must be delivered to the `EventHandler` (in order), after which the window closes itself.
+//!
+//! On Linux, this test runs under Xvfb, as it does not need a Window Manager or a human at the keyboard.
+
+#![cfg(any(
+    feature = "headless",
+    target_os = "windows",
+    all(target_os = "linux", not(feature = "wayland"))
+))]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn kbm_synthetic() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let app_thread = scope.spawn(|| app.run(&events));
+        events.run();
+        app_thread.join().unwrap();
+    });
+
+    assert!(app.closed.load(Ordering::Relaxed));
+    assert!(app.remaining.lock().unwrap().is_empty());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, screen::*, types::*, window::*, *};
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+/// An input that is expected to be delivered to the `EventHandler`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Delivered {
+    Cursor(Point),
+    Button(MouseButton, bool),
+    Key(Key, bool),
+    Scroll(f64, f64),
+}
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    remaining: Mutex<VecDeque<Delivered>>,
+    closed: AtomicBool,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let remaining = Mutex::new(VecDeque::from([
+            Delivered::Cursor(Point::new(10.0, 20.0)),
+            Delivered::Button(MB_LEFT, true),
+            Delivered::Button(MB_LEFT, false),
+            Delivered::Key(KC_A, true),
+            Delivered::Key(KC_A, false),
+            Delivered::Scroll(0.0, 1.0),
+        ]));
+        let closed = AtomicBool::new(false);
+        Self {
+            window,
+            remaining,
+            closed,
+        }
+    }
+
+    pub fn run(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+
+        let handle = self.window.lock().unwrap().as_ref().unwrap().handle();
+
+        testing::cursor_move(events, handle, Point::new(10.0, 20.0)).unwrap();
+        testing::button_press(events, handle, MB_LEFT, true).unwrap();
+        testing::button_press(events, handle, MB_LEFT, false).unwrap();
+        testing::key_press(events, handle, KC_A, true).unwrap();
+        testing::key_press(events, handle, KC_A, false).unwrap();
+        testing::scroll_wheel(events, handle, 0.0, 1.0).unwrap();
+
+        assert!(events.await_termination());
+    }
+
+    /// Checks off the next expected input, once it has been delivered.\
+    /// Other inputs (e.g. from the real cursor) are ignored.
+    fn deliver(&self, events: &EventLoop, delivered: Delivered) {
+        let mut remaining = self.remaining.lock().unwrap();
+
+        if remaining.front() == Some(&delivered) {
+            remaining.pop_front();
+        }
+
+        if remaining.is_empty() {
+            if let Some(window) = self.window.lock().unwrap().take() {
+                window.close(events).unwrap();
+            }
+        }
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        window
+            .rename(events, "Test Keyboard/Mouse-Synthetic")
+            .unwrap();
+        window.focus(events).unwrap();
+
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.closed.store(true, Ordering::Relaxed);
+        events.request_stop();
+    }
+
+    fn cursor_move(&self, events: &EventLoop, _handle: WindowHandle, point: Point) {
+        self.deliver(events, Delivered::Cursor(point));
+    }
+
+    fn scroll_wheel(&self, events: &EventLoop, _handle: WindowHandle, delta_x: f64, delta_y: f64) {
+        self.deliver(events, Delivered::Scroll(delta_x, delta_y));
+    }
+
+    fn button_press(
+        &self,
+        events: &EventLoop,
+        _handle: WindowHandle,
+        button: MouseButton,
+        pressed: bool,
+    ) {
+        self.deliver(events, Delivered::Button(button, pressed));
+    }
+
+    fn key_press(&self, events: &EventLoop, _handle: WindowHandle, event: KeyEvent, pressed: bool) {
+        self.deliver(events, Delivered::Key(event.key, pressed));
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,169 @@
+/*
+ *  Crate: Wyn
+ *   Test: Key-Event
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! Key Events with Modifiers, Auto-Repeat and sided/Numpad locations are injected, and must be reported as-is.\
+//! The locations and Modifiers implied by Keys themselves are checked as well.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn key_event() {
+    utils::timeout::test_deadline(5.0);
+    test_types();
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_types() {
+    // Keys imply their own locations.
+    assert_eq!(KeyLocation::of(Key::LShift), KeyLocation::Left);
+    assert_eq!(KeyLocation::of(Key::RAlt), KeyLocation::Right);
+    assert_eq!(KeyLocation::of(Key::Numpad7), KeyLocation::Numpad);
+    assert_eq!(KeyLocation::of(Key::Shift), KeyLocation::Standard);
+    assert_eq!(KeyEvent::new(Key::Divide).location, KeyLocation::Numpad);
+
+    // Modifier Keys imply their own Modifiers, but Lock Keys do not.
+    assert_eq!(Modifiers::of(Key::RControl), Modifiers::CONTROL);
+    assert_eq!(Modifiers::of(Key::LStart), Modifiers::SUPER);
+    assert_eq!(Modifiers::of(Key::CapsLock), Modifiers::NONE);
+
+    // Sets
+    let mut modifiers = Modifiers::SHIFT | Modifiers::CAPS_LOCK;
+    assert!(modifiers.contains(Modifiers::SHIFT));
+    assert!(!modifiers.contains(Modifiers::SHIFT | Modifiers::ALT));
+    modifiers.set(Modifiers::SHIFT, false);
+    assert_eq!(modifiers, Modifiers::CAPS_LOCK);
+    modifiers.set(Modifiers::CAPS_LOCK, false);
+    assert!(modifiers.is_empty());
+
+    let modifiers = Modifiers::CONTROL | Modifiers::SUPER;
+    assert_eq!(format!("{modifiers:?}"), "Modifiers(CONTROL | SUPER)");
+    assert_eq!(format!("{:?}", Modifiers::NONE), "Modifiers()");
+}
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events));
+        events.run();
+        input_thread.join().unwrap();
+    });
+
+    let log = app.log.lock().unwrap();
+    assert_eq!(log.as_slice(), TestApp::EVENTS.as_slice());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, types::*, window::*, *};
+
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    log: Mutex<Vec<(KeyEvent, bool)>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    /// Right-Shift is held down (and repeated), while Numpad-Enter is pressed with Num-Lock on.
+    const EVENTS: [(KeyEvent, bool); 5] = [
+        (
+            Self::event(Key::Shift, Modifiers::SHIFT, false, KeyLocation::Right),
+            true,
+        ),
+        (
+            Self::event(Key::Shift, Modifiers::SHIFT, true, KeyLocation::Right),
+            true,
+        ),
+        (
+            Self::event(Key::Enter, Self::SHIFT_NUM, false, KeyLocation::Numpad),
+            true,
+        ),
+        (
+            Self::event(Key::Enter, Self::SHIFT_NUM, false, KeyLocation::Numpad),
+            false,
+        ),
+        (
+            Self::event(Key::Shift, Modifiers::NONE, false, KeyLocation::Right),
+            false,
+        ),
+    ];
+
+    /// Shift is held, and Num-Lock is toggled on.
+    const SHIFT_NUM: Modifiers = Modifiers::SHIFT.union(Modifiers::NUM_LOCK);
+
+    const fn event(
+        key: Key,
+        modifiers: Modifiers,
+        repeat: bool,
+        location: KeyLocation,
+    ) -> KeyEvent {
+        KeyEvent {
+            scancode: 0,
+            key,
+            modifiers,
+            repeat,
+            location,
+        }
+    }
+
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let log = Mutex::new(Vec::new());
+        Self { window, log }
+    }
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+
+        let handle = self.window.lock().unwrap().as_ref().unwrap().handle();
+        for (event, pressed) in Self::EVENTS {
+            events.inject(HeadlessInput::KeyPress(handle, event, pressed));
+        }
+
+        events.inject(HeadlessInput::WindowClose(handle));
+        assert!(events.await_termination());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.window.lock().unwrap().take();
+        events.request_stop();
+    }
+
+    fn key_press(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        event: KeyEvent,
+        pressed: bool,
+    ) {
+        self.log.lock().unwrap().push((event, pressed));
+    }
+}
+
+// ================================================================================================================================ //
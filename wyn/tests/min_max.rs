@@ -50,6 +50,7 @@ use wyn::{errors::*, event_loop::*, events::*, inputs::*, screen::*, types::*, w
 
 use std::sync::atomic::{AtomicBool, Ordering};
 use utils::rwopt::RwOpt;
+use utils::wm::await_wm;
 
 // -------------------------------------------------------------------------------------------------------------------------------- //
 
@@ -101,7 +102,7 @@ impl TestApp {
                 self.window.read(|window| {
                     println!("[STEP 1]: MAXIMIZE");
                     window.maximize(events).unwrap();
-                    assert!(window.is_maximized(events));
+                    assert!(await_wm(|| window.is_maximized(events)));
                 });
                 self.sleep(1.0);
             }
@@ -114,7 +115,7 @@ impl TestApp {
                 self.window.read(|window| {
                     println!("[STEP 2]: RESTORE");
                     window.restore(events).unwrap();
-                    assert!(window.is_normal(events));
+                    assert!(await_wm(|| window.is_normal(events)));
                 });
                 self.sleep(1.0);
             }
@@ -127,7 +128,7 @@ impl TestApp {
                 self.window.read(|window| {
                     println!("[STEP 3]: MINIMIZE");
                     window.minimize(events).unwrap();
-                    assert!(window.is_minimized(events));
+                    assert!(await_wm(|| window.is_minimized(events)));
                 });
                 self.sleep(1.0);
             }
@@ -140,7 +141,7 @@ impl TestApp {
                 self.window.read(|window| {
                     println!("[STEP 4]: RESTORE");
                     window.restore(events).unwrap();
-                    assert!(window.is_normal(events));
+                    assert!(await_wm(|| window.is_normal(events)));
                 });
                 self.sleep(1.0);
             }
@@ -153,7 +154,7 @@ impl TestApp {
                 self.window.read(|window| {
                     println!("[STEP 5]: MAXIMIZE");
                     window.maximize(events).unwrap();
-                    assert!(window.is_maximized(events));
+                    assert!(await_wm(|| window.is_maximized(events)));
                 });
                 self.sleep(1.0);
             }
@@ -166,7 +167,7 @@ impl TestApp {
                 self.window.read(|window| {
                     println!("[STEP 6]: MINIMIZE");
                     window.minimize(events).unwrap();
-                    assert!(window.is_minimized(events));
+                    assert!(await_wm(|| window.is_minimized(events)));
                 });
                 self.sleep(1.0);
             }
@@ -179,7 +180,7 @@ impl TestApp {
                 self.window.read(|window| {
                     println!("[STEP 7]: RESTORE");
                     window.restore(events).unwrap();
-                    assert!(window.is_maximized(events));
+                    assert!(await_wm(|| window.is_maximized(events)));
                 });
                 self.sleep(1.0);
             }
@@ -189,7 +190,7 @@ impl TestApp {
                 self.window.read(|window| {
                     println!("[STEP 8]: RESTORE");
                     window.restore(events).unwrap();
-                    assert!(window.is_normal(events));
+                    assert!(await_wm(|| window.is_normal(events)));
                 });
                 self.sleep(1.0);
             }
@@ -212,10 +213,10 @@ impl EventHandler for TestApp {
             assert!(window.is_hidden(events));
 
             window.show(events).unwrap();
-            assert!(window.is_visible(events));
+            assert!(await_wm(|| window.is_visible(events)));
 
             window.focus(events).unwrap();
-            assert!(window.is_focused(events));
+            assert!(await_wm(|| window.is_focused(events)));
         });
     }
 
@@ -0,0 +1,152 @@
+/*
+ *  Crate: Wyn
+ *   Test: Mouse
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! The Mouse-Cursor enters a Window, presses its side Buttons, scrolls by fractions of a notch, and leaves again.\
+//! The side Buttons must be reported as `X1`/`X2` (and held while pressed), and the fractional deltas must be kept as-is.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn mouse() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events));
+        events.run();
+        input_thread.join().unwrap();
+    });
+
+    let log = app.log.lock().unwrap();
+    let expected = [
+        Logged::Enter(true),
+        Logged::Button(MouseButton::X1, true, vec![MouseButton::X1]),
+        Logged::Button(
+            MouseButton::X2,
+            true,
+            vec![MouseButton::X1, MouseButton::X2],
+        ),
+        Logged::Button(MouseButton::X1, false, vec![MouseButton::X2]),
+        Logged::Button(MouseButton::X2, false, vec![]),
+        Logged::Scroll(0.0, 0.25),
+        Logged::Scroll(-1.5, -0.125),
+        Logged::Enter(false),
+        Logged::Close,
+    ];
+    assert_eq!(log.as_slice(), expected.as_slice());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, types::*, window::*, *};
+
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[derive(Clone, PartialEq, Debug)]
+enum Logged {
+    Enter(bool),
+    Button(MouseButton, bool, Vec<MouseButton>),
+    Scroll(f64, f64),
+    Close,
+}
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    log: Mutex<Vec<Logged>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let log = Mutex::new(Vec::new());
+        Self { window, log }
+    }
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+        assert!(events.supports(Capability::CursorEnterLeave));
+
+        let handle = self.window.lock().unwrap().as_ref().unwrap().handle();
+        let button = |button: MouseButton, pressed: bool| {
+            HeadlessInput::ButtonPress(handle, button, pressed)
+        };
+
+        events.inject(HeadlessInput::CursorEnter(handle));
+
+        events.inject(button(MouseButton::X1, true));
+        events.inject(button(MouseButton::X2, true));
+        events.inject(button(MouseButton::X1, false));
+        events.inject(button(MouseButton::X2, false));
+
+        events.inject(HeadlessInput::ScrollWheel(handle, 0.0, 0.25));
+        events.inject(HeadlessInput::ScrollWheel(handle, -1.5, -0.125));
+
+        events.inject(HeadlessInput::CursorLeave(handle));
+
+        events.inject(HeadlessInput::WindowClose(handle));
+        assert!(events.await_termination());
+    }
+
+    fn log(&self, logged: Logged) {
+        self.log.lock().unwrap().push(logged);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.window.lock().unwrap().take();
+        self.log(Logged::Close);
+        events.request_stop();
+    }
+
+    fn cursor_enter(&self, _events: &EventLoop, _handle: WindowHandle) {
+        self.log(Logged::Enter(true));
+    }
+
+    fn cursor_leave(&self, _events: &EventLoop, _handle: WindowHandle) {
+        self.log(Logged::Enter(false));
+    }
+
+    fn button_press(
+        &self,
+        events: &EventLoop,
+        _handle: WindowHandle,
+        button: MouseButton,
+        pressed: bool,
+    ) {
+        self.log(Logged::Button(button, pressed, events.mouse_buttons()));
+    }
+
+    fn scroll_wheel(&self, _events: &EventLoop, _handle: WindowHandle, delta_x: f64, delta_y: f64) {
+        self.log(Logged::Scroll(delta_x, delta_y));
+    }
+}
+
+// ================================================================================================================================ //
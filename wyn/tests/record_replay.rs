@@ -0,0 +1,376 @@
+/*
+ *  Crate: Wyn
+ *   Test: Record-Replay
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! Injected inputs are recorded with a `Recorder`, and the Recording is written out and read back.\
+//! The Recording is then replayed (as fast as possible) into a fresh Event Handler, which must receive the same callbacks.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn record_replay() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    // Record.
+    let recorded = TestApp::new();
+    let mut output = Vec::new();
+    {
+        let recorder = Recorder::new(&recorded, &mut output);
+        {
+            let events = EventLoop::new(&recorder).unwrap();
+
+            std::thread::scope(|scope| {
+                let input_thread = scope.spawn(|| recorded.inject(&events));
+                events.run();
+                input_thread.join().unwrap();
+            });
+        }
+        recorder.finish().unwrap();
+    }
+
+    let text = String::from_utf8(output).unwrap();
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" key_press 1 A true 0 Standard - false")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" key_press 1 Shift true 54 Right SHIFT|NUM_LOCK true")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" character_input 1 U+0061")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" raw_motion 3 -4.5")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" button_press 1 X1 true")));
+    assert!(text.lines().any(|line| line.ends_with(" cursor_leave 1")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" touch_cancel 1 7 30 40.5")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" pen 1 5 6 0.5 -30 15 TIP|BARREL")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" pen 1 5 6 0 0 0 -")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" scale_factor_changed 1 1.5")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" file_drop 1 2 /tmp/a%20b%25c.txt /tmp/tab%09.png 1 2")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" ime_preedit 1 \"你%20好\" 0..3")));
+    assert!(text
+        .lines()
+        .any(|line| line.ends_with(" ime_preedit 1 \"\" -")));
+
+    // Round-Trip.
+    let recording = Recording::read(text.as_bytes()).unwrap();
+    let mut rewritten = Vec::new();
+    recording.write(&mut rewritten).unwrap();
+    assert_eq!(Recording::read(&rewritten[..]).unwrap(), recording);
+
+    let err =
+        Recording::read("12 key_press 1 NotAKey true 0 Standard - false".as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let err = Recording::read("12 key_press 1 A true 0 Standard SHIFT|Meta false".as_bytes())
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let err = Recording::read("12 pen 1 5 6 0.5 0 0 TIP|SHIFT".as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    // Replay.
+    let replayed = TestApp::new();
+    {
+        let replay = ReplayEventLoop::new(&replayed, recording, f64::INFINITY);
+        let events = EventLoop::new(&replay).unwrap();
+        replay.run(&events);
+    }
+
+    let recorded = recorded.log.lock().unwrap();
+    let replayed = replayed.log.lock().unwrap();
+    assert_eq!(recorded.last(), Some(&Logged::Close));
+    assert_eq!(replayed.as_slice(), recorded.as_slice());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, recording::*, types::*, window::*, *};
+
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[derive(Clone, PartialEq, Debug)]
+enum Logged {
+    Cursor(Point),
+    Enter(bool),
+    Button(MouseButton, bool),
+    Touch(TouchPhase, TouchId, Point),
+    Pen(Point, f64, (f64, f64), PenButtons),
+    Key(KeyEvent, bool),
+    Character(char),
+    Scroll(f64, f64),
+    Raw(f64, f64),
+    Scale(ScaleFactor),
+    FileDrop(Vec<PathBuf>, Point),
+    Preedit(String, Option<Range<usize>>),
+    Commit(String),
+    GamepadButton(GamepadId, GamepadButton, bool),
+    GamepadAxis(GamepadId, GamepadAxis, f32),
+    Close,
+}
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    log: Mutex<Vec<Logged>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let log = Mutex::new(Vec::new());
+        Self { window, log }
+    }
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+
+        let guard = self.window.lock().unwrap();
+        let window = guard.as_ref().unwrap();
+        let handle = window.handle();
+        window.set_ime_allowed(events, true).unwrap();
+        drop(guard);
+
+        events.inject(HeadlessInput::CursorEnter(handle));
+        events.inject(HeadlessInput::CursorMove(handle, Point::new(10.5, 20.25)));
+        events.inject(HeadlessInput::ButtonPress(handle, MouseButton::Left, true));
+        events.inject(HeadlessInput::ButtonPress(handle, MouseButton::X1, true));
+        events.inject(HeadlessInput::ButtonPress(
+            handle,
+            MouseButton::Other(9),
+            true,
+        ));
+        events.inject(HeadlessInput::KeyPress(handle, KeyEvent::new(Key::A), true));
+        events.inject(HeadlessInput::CharacterInput(handle, 'a'));
+        events.inject(HeadlessInput::KeyPress(
+            handle,
+            KeyEvent::new(Key::A),
+            false,
+        ));
+
+        // Every part of a Key Event survives the round-trip.
+        let shift = KeyEvent {
+            scancode: 54,
+            key: Key::Shift,
+            modifiers: Modifiers::SHIFT | Modifiers::NUM_LOCK,
+            repeat: true,
+            location: KeyLocation::Right,
+        };
+        events.inject(HeadlessInput::KeyPress(handle, shift, true));
+        events.inject(HeadlessInput::ScrollWheel(handle, 0.0, -1.0));
+        events.inject(HeadlessInput::ScrollWheel(handle, 0.125, 0.0));
+        events.inject(HeadlessInput::CursorLeave(handle));
+        events.inject(HeadlessInput::RawMotion(3.0, -4.5));
+
+        for phase in TouchPhase::ALL {
+            let point = Point::new(30.0, 40.5);
+            events.inject(HeadlessInput::Touch(handle, phase, 7, point));
+        }
+        let tip_barrel = PenButtons::TIP | PenButtons::BARREL;
+        let pen = |pressure, tilt, buttons| {
+            HeadlessInput::Pen(handle, Point::new(5.0, 6.0), pressure, tilt, buttons)
+        };
+        events.inject(pen(0.5, (-30.0, 15.0), tip_barrel));
+        events.inject(pen(0.0, (0.0, 0.0), PenButtons::NONE));
+
+        events.inject(HeadlessInput::ScaleFactorChanged(handle, 1.5));
+
+        // Paths are escaped, so that whitespace and `%` survive the round-trip.
+        let paths = vec![
+            PathBuf::from("/tmp/a b%c.txt"),
+            PathBuf::from("/tmp/tab\t.png"),
+        ];
+        events.inject(HeadlessInput::FileDrop(handle, paths, Point::new(1.0, 2.0)));
+
+        // Text is escaped like paths, and may be empty.
+        events.inject(HeadlessInput::ImePreedit(
+            handle,
+            "你 好".into(),
+            Some(0..3),
+        ));
+        events.inject(HeadlessInput::ImePreedit(handle, String::new(), None));
+        events.inject(HeadlessInput::ImeCommit(handle, "你 好".into()));
+
+        events.inject(HeadlessInput::GamepadButton(3, GamepadButton::Start, true));
+        events.inject(HeadlessInput::GamepadAxis(3, GamepadAxis::StickLX, -0.75));
+
+        events.inject(HeadlessInput::WindowClose(handle));
+
+        assert!(events.await_termination());
+    }
+
+    fn log(&self, logged: Logged) {
+        self.log.lock().unwrap().push(logged);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, handle: WindowHandle) {
+        if let Some(window) = self.window.lock().unwrap().take() {
+            assert_eq!(window.handle(), handle);
+            self.log(Logged::Close);
+        }
+        events.request_stop();
+    }
+
+    fn cursor_move(&self, _events: &EventLoop, _handle: WindowHandle, point: Point) {
+        self.log(Logged::Cursor(point));
+    }
+
+    fn cursor_enter(&self, _events: &EventLoop, _handle: WindowHandle) {
+        self.log(Logged::Enter(true));
+    }
+
+    fn cursor_leave(&self, _events: &EventLoop, _handle: WindowHandle) {
+        self.log(Logged::Enter(false));
+    }
+
+    fn scroll_wheel(&self, _events: &EventLoop, _handle: WindowHandle, delta_x: f64, delta_y: f64) {
+        self.log(Logged::Scroll(delta_x, delta_y));
+    }
+
+    fn raw_motion(&self, _events: &EventLoop, delta_x: f64, delta_y: f64) {
+        self.log(Logged::Raw(delta_x, delta_y));
+    }
+
+    fn scale_factor_changed(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        scale_factor: ScaleFactor,
+    ) {
+        self.log(Logged::Scale(scale_factor));
+    }
+
+    fn button_press(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        button: MouseButton,
+        pressed: bool,
+    ) {
+        self.log(Logged::Button(button, pressed));
+    }
+
+    fn touch_begin(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        self.log(Logged::Touch(TouchPhase::Begin, id, point));
+    }
+
+    fn touch_move(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        self.log(Logged::Touch(TouchPhase::Move, id, point));
+    }
+
+    fn touch_end(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        self.log(Logged::Touch(TouchPhase::End, id, point));
+    }
+
+    fn touch_cancel(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        self.log(Logged::Touch(TouchPhase::Cancel, id, point));
+    }
+
+    fn pen(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        point: Point,
+        pressure: f64,
+        tilt: (f64, f64),
+        buttons: PenButtons,
+    ) {
+        self.log(Logged::Pen(point, pressure, tilt, buttons));
+    }
+
+    fn key_press(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        event: KeyEvent,
+        pressed: bool,
+    ) {
+        self.log(Logged::Key(event, pressed));
+    }
+
+    fn character_input(&self, _events: &EventLoop, _handle: WindowHandle, character: char) {
+        self.log(Logged::Character(character));
+    }
+
+    fn file_drop(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        paths: Vec<PathBuf>,
+        point: Point,
+    ) {
+        self.log(Logged::FileDrop(paths, point));
+    }
+
+    fn ime_preedit(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        text: String,
+        cursor_range: Option<Range<usize>>,
+    ) {
+        self.log(Logged::Preedit(text, cursor_range));
+    }
+
+    fn ime_commit(&self, _events: &EventLoop, _handle: WindowHandle, text: String) {
+        self.log(Logged::Commit(text));
+    }
+
+    fn gamepad_button(
+        &self,
+        _events: &EventLoop,
+        id: GamepadId,
+        button: GamepadButton,
+        pressed: bool,
+    ) {
+        self.log(Logged::GamepadButton(id, button, pressed));
+    }
+
+    fn gamepad_axis(&self, _events: &EventLoop, id: GamepadId, axis: GamepadAxis, value: f32) {
+        self.log(Logged::GamepadAxis(id, axis, value));
+    }
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,126 @@
+/*
+ *  Crate: Wyn
+ *   Test: Scale Factor
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! Coordinates are converted between Physical and Logical Pixels, and the Scale Factor of a Window is changed.\
+//! Only actual changes must be reported, and the Window must keep the Scale Factor it was changed to.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn scale_factor() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events));
+        events.run();
+        input_thread.join().unwrap();
+    });
+
+    let log = app.log.lock().unwrap();
+    assert_eq!(log.as_slice(), [2.0, 1.0].as_slice());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, screen::*, types::*, window::*, *};
+
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    log: Mutex<Vec<ScaleFactor>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let log = Mutex::new(Vec::new());
+        Self { window, log }
+    }
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+        assert!(events.supports(Capability::ScaleFactorChanged));
+
+        let guard = self.window.lock().unwrap();
+        let window = guard.as_ref().unwrap();
+        let handle = window.handle();
+
+        let screen = Screen::primary(events);
+        assert_eq!(screen.scale_factor(events), 1.0);
+        assert_eq!(screen.info(events).scale_factor, 1.0);
+        assert_eq!(window.scale_factor(events), 1.0);
+        drop(guard);
+
+        // Changes
+        events.inject(HeadlessInput::ScaleFactorChanged(handle, 2.0));
+        events.inject(HeadlessInput::ScaleFactorChanged(handle, 2.0));
+        events.inject(HeadlessInput::ScaleFactorChanged(handle, 1.0));
+
+        events.inject(HeadlessInput::WindowClose(handle));
+        assert!(events.await_termination());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        // Conversions
+        let point = Point::new(300.0, 150.0);
+        assert_eq!(point.to_logical(1.5), Point::new(200.0, 100.0));
+        assert_eq!(point.to_logical(1.5).to_physical(1.5), point);
+
+        let size = Size::new(1920.0, 1080.0);
+        assert_eq!(size.to_logical(2.0), Size::new(960.0, 540.0));
+
+        let rect = Rect::new(100.0, 50.0, 400.0, 200.0);
+        assert_eq!(rect.to_physical(2.0), Rect::new(200.0, 100.0, 800.0, 400.0));
+        assert!(rect.contains(Point::new(100.0, 50.0)));
+        assert!(!rect.contains(Point::new(500.0, 250.0)));
+
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.window.lock().unwrap().take();
+        events.request_stop();
+    }
+
+    fn scale_factor_changed(
+        &self,
+        events: &EventLoop,
+        _handle: WindowHandle,
+        scale_factor: ScaleFactor,
+    ) {
+        let guard = self.window.lock().unwrap();
+        let window = guard.as_ref().unwrap();
+        assert_eq!(window.scale_factor(events), scale_factor);
+
+        self.log.lock().unwrap().push(scale_factor);
+    }
+}
+
+// ================================================================================================================================ //
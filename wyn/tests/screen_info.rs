@@ -4,7 +4,7 @@
  */
 
 //! This test collects a list of Displays/Monitors/Screens.
-//! It then prints their names, coordinates, and sizes to the console.
+//! It then prints their names, coordinates, sizes, and scale factors to the console.
 //!
 //! The user should verify this information is correct, and if it is, then the Test has PASSED.
 
@@ -31,21 +31,37 @@ fn test_main() {
     for (i, screen) in screens.iter().enumerate() {
         let name = screen.name(&events);
         let rect = screen.rect(&events);
+        let scale = screen.scale_factor(&events);
+        let info = screen.info(&events);
+        let work = info.work_area;
 
         println!(
-            "[SCREEN {i}]\n* Name: \"{}\"\n* Rect: [ Origin: ({}, {}), Size: ({}, {}) ]\n",
-            name, rect.origin.x, rect.origin.y, rect.size.w, rect.size.h,
+            "[SCREEN {i}]\n* Name: \"{}\"\n* Rect: [ Origin: ({}, {}), Size: ({}, {}) ]\n* Scale: {}",
+            name, rect.origin.x, rect.origin.y, rect.size.w, rect.size.h, scale,
         );
+        println!(
+            "* Refresh Rate: {} Hz\n* Work Area: [ Origin: ({}, {}), Size: ({}, {}) ]\n* Physical Size: ({} mm, {} mm)",
+            info.refresh_rate, work.origin.x, work.origin.y, work.size.w, work.size.h, info.size_mm.w, info.size_mm.h,
+        );
+        println!("* Rotation: {:?}\n* Video Modes:", info.rotation);
+        for mode in &info.video_modes {
+            println!(
+                "  - {} x {} @ {} Hz ({} bits)",
+                mode.size.w, mode.size.h, mode.refresh_rate, mode.bit_depth,
+            );
+        }
+        println!();
     }
 
     {
         let screen = Screen::primary(&events);
         let name = screen.name(&events);
         let rect = screen.rect(&events);
+        let scale = screen.scale_factor(&events);
 
         println!(
-            "[PRIMARY SCREEN]\n* Name: \"{}\"\n* Rect: [ Origin: ({}, {}), Size: ({}, {}) ]\n",
-            name, rect.origin.x, rect.origin.y, rect.size.w, rect.size.h,
+            "[PRIMARY SCREEN]\n* Name: \"{}\"\n* Rect: [ Origin: ({}, {}), Size: ({}, {}) ]\n* Scale: {}\n",
+            name, rect.origin.x, rect.origin.y, rect.size.w, rect.size.h, scale,
         );
     }
 }
@@ -0,0 +1,115 @@
+/*
+ *  Crate: Wyn
+ *   Test: Screens Changed
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! A Window switches the Video Mode of the virtual Screen with Exclusive Fullscreen.\
+//! Every actual change of the Screen must be reported exactly once.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn screens_changed() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events));
+        events.run();
+        input_thread.join().unwrap();
+    });
+
+    assert_eq!(*app.changes.lock().unwrap(), 2);
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, screen::*, types::*, window::*, *};
+
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    changes: Mutex<usize>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let changes = Mutex::new(0);
+        Self { window, changes }
+    }
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+        assert!(events.supports(Capability::ScreensChanged));
+
+        let guard = self.window.lock().unwrap();
+        let window = guard.as_ref().unwrap();
+        let handle = window.handle();
+
+        let screen = Screen::primary(events);
+        let small = VideoMode {
+            size: Size::new(1280.0, 720.0),
+            refresh_rate: 60.0,
+            bit_depth: 32,
+        };
+
+        // Switching to the same Video Mode again is not a change.
+        assert!(window
+            .fullscreen_exclusive(events, &screen, &small)
+            .unwrap());
+        assert!(window
+            .fullscreen_exclusive(events, &screen, &small)
+            .unwrap());
+
+        // Leaving Fullscreen restores the previous Video Mode.
+        window.restore(events).unwrap();
+
+        // A Window without Exclusive Fullscreen does not change the Screen.
+        window.restore(events).unwrap();
+        drop(guard);
+
+        events.inject(HeadlessInput::WindowClose(handle));
+        assert!(events.await_termination());
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn screens_changed(&self, events: &EventLoop) {
+        assert_eq!(Screen::collect(events).len(), 1);
+        *self.changes.lock().unwrap() += 1;
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.window.lock().unwrap().take();
+        events.request_stop();
+    }
+}
+
+// ================================================================================================================================ //
@@ -49,6 +49,7 @@ use wyn::{errors::*, event_loop::*, events::*, inputs::*, screen::*, types::*, w
 
 use std::sync::atomic::{AtomicBool, Ordering};
 use utils::rwopt::RwOpt;
+use utils::wm::await_wm;
 
 // -------------------------------------------------------------------------------------------------------------------------------- //
 
@@ -149,7 +150,7 @@ impl TestApp {
                 self.window.read(|window| {
                     println!("[STEP 4]: FULLSCREEN");
                     window.fullscreen(events).unwrap();
-                    assert!(window.is_fullscreen(events));
+                    assert!(await_wm(|| window.is_fullscreen(events)));
                 });
                 self.sleep(1.0);
             }
@@ -172,10 +173,10 @@ impl EventHandler for TestApp {
             assert!(window.is_hidden(events));
 
             window.show(events).unwrap();
-            assert!(window.is_visible(events));
+            assert!(await_wm(|| window.is_visible(events)));
 
             window.focus(events).unwrap();
-            assert!(window.is_focused(events));
+            assert!(await_wm(|| window.is_focused(events)));
         });
     }
 
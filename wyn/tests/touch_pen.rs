@@ -0,0 +1,160 @@
+/*
+ *  Crate: Wyn
+ *   Test: Touch-Pen
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! Two fingers touch a Window at once, where one is lifted and the other is cancelled, and then a Pen hovers, draws and erases.\
+//! Every touch must be reported to the callback of its phase with its own ID, and the Pen with its pressure, tilt and Buttons.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn touch_pen() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events));
+        events.run();
+        input_thread.join().unwrap();
+    });
+
+    let log = app.log.lock().unwrap();
+    let tip = PenButtons::TIP;
+    let eraser = PenButtons::TIP | PenButtons::ERASER;
+    let expected = [
+        Logged::Touch(TouchPhase::Begin, 1, Point::new(10.0, 10.0)),
+        Logged::Touch(TouchPhase::Begin, 2, Point::new(50.0, 10.0)),
+        Logged::Touch(TouchPhase::Move, 1, Point::new(12.5, 11.0)),
+        Logged::Touch(TouchPhase::Move, 2, Point::new(48.0, 9.5)),
+        Logged::Touch(TouchPhase::End, 1, Point::new(12.5, 11.0)),
+        Logged::Touch(TouchPhase::Cancel, 2, Point::new(48.0, 9.5)),
+        Logged::Pen(Point::new(20.0, 30.0), 0.0, (0.0, 0.0), PenButtons::NONE),
+        Logged::Pen(Point::new(20.0, 30.0), 0.25, (10.0, -5.0), tip),
+        Logged::Pen(Point::new(21.0, 31.0), 1.0, (-45.0, 0.0), eraser),
+        Logged::Close,
+    ];
+    assert_eq!(log.as_slice(), expected.as_slice());
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, types::*, window::*, *};
+
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+#[derive(Clone, PartialEq, Debug)]
+enum Logged {
+    Touch(TouchPhase, TouchId, Point),
+    Pen(Point, f64, (f64, f64), PenButtons),
+    Close,
+}
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+    log: Mutex<Vec<Logged>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        let log = Mutex::new(Vec::new());
+        Self { window, log }
+    }
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+        assert!(events.supports(Capability::Touch));
+        assert!(events.supports(Capability::Pen));
+
+        let handle = self.window.lock().unwrap().as_ref().unwrap().handle();
+        let touch = |phase: TouchPhase, id: TouchId, x: f64, y: f64| {
+            HeadlessInput::Touch(handle, phase, id, Point::new(x, y))
+        };
+        let pen = |x: f64, y: f64, pressure: f64, tilt: (f64, f64), buttons: PenButtons| {
+            HeadlessInput::Pen(handle, Point::new(x, y), pressure, tilt, buttons)
+        };
+
+        events.inject(touch(TouchPhase::Begin, 1, 10.0, 10.0));
+        events.inject(touch(TouchPhase::Begin, 2, 50.0, 10.0));
+        events.inject(touch(TouchPhase::Move, 1, 12.5, 11.0));
+        events.inject(touch(TouchPhase::Move, 2, 48.0, 9.5));
+        events.inject(touch(TouchPhase::End, 1, 12.5, 11.0));
+        events.inject(touch(TouchPhase::Cancel, 2, 48.0, 9.5));
+
+        let eraser = PenButtons::TIP | PenButtons::ERASER;
+        events.inject(pen(20.0, 30.0, 0.0, (0.0, 0.0), PenButtons::NONE));
+        events.inject(pen(20.0, 30.0, 0.25, (10.0, -5.0), PenButtons::TIP));
+        events.inject(pen(21.0, 31.0, 1.0, (-45.0, 0.0), eraser));
+
+        events.inject(HeadlessInput::WindowClose(handle));
+        assert!(events.await_termination());
+    }
+
+    fn log(&self, logged: Logged) {
+        self.log.lock().unwrap().push(logged);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.window.lock().unwrap().take();
+        self.log(Logged::Close);
+        events.request_stop();
+    }
+
+    fn touch_begin(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        self.log(Logged::Touch(TouchPhase::Begin, id, point));
+    }
+
+    fn touch_move(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        self.log(Logged::Touch(TouchPhase::Move, id, point));
+    }
+
+    fn touch_end(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        self.log(Logged::Touch(TouchPhase::End, id, point));
+    }
+
+    fn touch_cancel(&self, _events: &EventLoop, _handle: WindowHandle, id: TouchId, point: Point) {
+        self.log(Logged::Touch(TouchPhase::Cancel, id, point));
+    }
+
+    fn pen(
+        &self,
+        _events: &EventLoop,
+        _handle: WindowHandle,
+        point: Point,
+        pressure: f64,
+        tilt: (f64, f64),
+        buttons: PenButtons,
+    ) {
+        self.log(Logged::Pen(point, pressure, tilt, buttons));
+    }
+}
+
+// ================================================================================================================================ //
@@ -5,3 +5,4 @@
 
 pub mod rwopt;
 pub mod timeout;
+pub mod wm;
@@ -0,0 +1,35 @@
+/*
+ *  Crate: Wyn
+ * Module: Tests - Utils - WM
+ */
+
+//! This module provides a helper-function to await changes that are applied by the Window Manager.
+//!
+//! On some platforms (e.g. X11), requests such as Maximizing a Window only ask the Window Manager to apply them.
+//! As such, the new state can only be queried once the Window Manager got around to it, shortly after the request returned.
+
+// ================================================================================================================================ //
+
+/// How long to wait for the Window Manager to apply a requested change, before giving up on it.
+#[allow(unused)]
+const WM_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// How often to check whether the Window Manager applied a requested change.
+#[allow(unused)]
+const WM_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(5);
+
+/// Polls the condition until it holds, or until `WM_TIMEOUT` elapsed.
+/// Returns whether or not the condition holds.
+#[allow(unused)]
+pub fn await_wm(mut applied: impl FnMut() -> bool) -> bool {
+    let deadline = std::time::Instant::now() + WM_TIMEOUT;
+    while !applied() {
+        if std::time::Instant::now() >= deadline {
+            return false;
+        }
+        std::thread::sleep(WM_POLL_INTERVAL);
+    }
+    true
+}
+
+// ================================================================================================================================ //
@@ -0,0 +1,148 @@
+/*
+ *  Crate: Wyn
+ *   Test: Video Modes
+ */
+
+//! This test runs only with the `headless` feature.
+//!
+//! The extended information of the virtual Screen is checked, and a Window switches its Video Mode with Exclusive Fullscreen.\
+//! The previous Video Mode must be restored once the Window leaves Fullscreen.
+
+#![cfg(feature = "headless")]
+
+mod utils;
+
+// ================================================================================================================================ //
+
+#[test]
+pub fn video_modes() {
+    utils::timeout::test_deadline(5.0);
+    test_main();
+}
+
+// ================================================================================================================================ //
+
+fn test_main() {
+    let app = TestApp::new();
+    let events = EventLoop::new(&app).unwrap();
+
+    std::thread::scope(|scope| {
+        let input_thread = scope.spawn(|| app.inject(&events));
+        events.run();
+        input_thread.join().unwrap();
+    });
+}
+
+// ================================================================================================================================ //
+
+#[allow(unused_imports)]
+use wyn::{errors::*, event_loop::*, events::*, inputs::*, screen::*, types::*, window::*, *};
+
+use std::sync::Mutex;
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+struct TestApp {
+    window: Mutex<Option<Window>>,
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl TestApp {
+    pub fn new() -> Self {
+        let window = Mutex::new(None);
+        Self { window }
+    }
+
+    pub fn inject(&self, events: &EventLoop) {
+        assert!(events.await_startup());
+        assert!(events.supports(Capability::VideoModes));
+
+        let guard = self.window.lock().unwrap();
+        let window = guard.as_ref().unwrap();
+        let handle = window.handle();
+
+        // Information
+        let screen = Screen::primary(events);
+        let info = screen.info(events);
+        assert_eq!(info.refresh_rate, 60.0);
+        assert_eq!(info.work_area, info.rect);
+        assert!(info.size_mm.w > 0.0 && info.size_mm.h > 0.0);
+        assert_eq!(info.rotation, Rotation::Deg0);
+        assert!(!info.rotation.is_sideways());
+        assert_eq!(info.video_modes.len(), 4);
+
+        let default = info.video_modes[0];
+        assert_eq!(default.size, info.rect.size);
+
+        // Closest Video Modes
+        let wanted = VideoMode {
+            size: default.size,
+            refresh_rate: 120.0,
+            bit_depth: 32,
+        };
+        let closest = wanted.closest_in(&info.video_modes).unwrap();
+        assert_eq!(closest.refresh_rate, 144.0);
+
+        let unsupported = VideoMode {
+            size: Size::new(1024.0, 768.0),
+            ..default
+        };
+        assert!(unsupported.closest_in(&info.video_modes).is_none());
+
+        // Exclusive Fullscreen
+        assert!(!window
+            .fullscreen_exclusive(events, &screen, &unsupported)
+            .unwrap());
+        assert!(!window.is_fullscreen(events));
+        assert_eq!(screen.rect(events), info.rect);
+
+        let small = VideoMode {
+            size: Size::new(1280.0, 720.0),
+            refresh_rate: 59.94,
+            bit_depth: 32,
+        };
+        assert!(window
+            .fullscreen_exclusive(events, &screen, &small)
+            .unwrap());
+        assert!(window.is_fullscreen(events));
+        assert_eq!(screen.rect(events).size, small.size);
+        assert_eq!(screen.refresh_rate(events), 60.0);
+        assert_eq!(window.content_rect(events).unwrap().size, small.size);
+
+        // Restore
+        window.restore(events).unwrap();
+        assert!(window.is_normal(events));
+        assert_eq!(screen.rect(events), info.rect);
+        assert_eq!(screen.refresh_rate(events), 60.0);
+
+        // Closing the Window also restores the Video Mode.
+        let fast = VideoMode {
+            refresh_rate: 144.0,
+            ..default
+        };
+        assert!(window.fullscreen_exclusive(events, &screen, &fast).unwrap());
+        assert_eq!(screen.refresh_rate(events), 144.0);
+        drop(guard);
+
+        events.inject(HeadlessInput::WindowClose(handle));
+        assert!(events.await_termination());
+        assert_eq!(screen.refresh_rate(events), 60.0);
+    }
+}
+
+// -------------------------------------------------------------------------------------------------------------------------------- //
+
+impl EventHandler for TestApp {
+    fn start(&self, events: &EventLoop) {
+        let window = Window::open(events).unwrap();
+        *self.window.lock().unwrap() = Some(window);
+    }
+
+    fn window_close(&self, events: &EventLoop, _handle: WindowHandle) {
+        self.window.lock().unwrap().take();
+        events.request_stop();
+    }
+}
+
+// ================================================================================================================================ //
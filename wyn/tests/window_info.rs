@@ -73,7 +73,7 @@ impl EventHandler for TestApp {
 
         self.window
             .read(|window| {
-                let screen = window.screen();
+                let screen = window.screen(events);
                 let name = screen.name(events);
                 println!("Window opened on Screen: \"{name}\"");
             })